@@ -22,8 +22,14 @@ fn event_label(event: &str) -> &'static str {
         "match_event" => "对局事件",
         "champion_kill" => "英雄击杀",
         "turret_destroyed" => "防御塔被摧毁",
+        "turret_plate_destroyed" => "防御塔镀层被击破",
         "cs_threshold" => "补刀里程碑",
         "time_progress" => "对局时间推进",
+        "dragon_killed" => "元素龙被击杀",
+        "dragon_soul" => "龙魂",
+        "elder_dragon_killed" => "远古龙被击杀",
+        "rift_herald_killed" => "峡谷先锋被击杀",
+        "baron_killed" => "纳什男爵被击杀",
         _ => "事件",
     }
 }
@@ -38,8 +38,18 @@ pub(super) fn event_label(ev: &MatchEvent) -> String {
     let p = &ev.payload;
     let team = |key: &str| team_label(p.get(key).and_then(|v| v.as_str()));
     match p.get("event_type").and_then(|v| v.as_str()) {
-        Some("champion_kill") => format!("{} 击杀一名英雄", team("killer_team")),
+        Some("champion_kill") => {
+            let bounty = p.get("bounty").and_then(|v| v.as_f64()).unwrap_or(0.0);
+            let shutdown = p.get("shutdown").and_then(|v| v.as_bool()).unwrap_or(false);
+            format!(
+                "{} 击杀一名英雄（赏金 {}{}）",
+                team("killer_team"),
+                bounty.round() as i64,
+                if shutdown { "，终结" } else { "" }
+            )
+        }
         Some("turret_destroyed") => format!("{} 摧毁防御塔", team("killer_team")),
+        Some("turret_plate_destroyed") => format!("{} 击破防御塔镀层", team("killer_team")),
         Some("cs_threshold") => format!(
             "{} 补刀达到 {} 触发阈值",
             team("team"),
@@ -52,6 +62,19 @@ pub(super) fn event_label(ev: &MatchEvent) -> String {
                 .unwrap_or(0.0);
             format!("对局进行中 · {} 秒", secs.round() as i64)
         }
+        Some("dragon_killed") => format!(
+            "{} 击杀元素龙（{}）",
+            team("killer_team"),
+            p.get("element").and_then(|v| v.as_str()).unwrap_or("未知")
+        ),
+        Some("dragon_soul") => format!(
+            "{} 获得龙魂（{}）",
+            team("team"),
+            p.get("element").and_then(|v| v.as_str()).unwrap_or("未知")
+        ),
+        Some("elder_dragon_killed") => format!("{} 击杀远古龙", team("killer_team")),
+        Some("rift_herald_killed") => format!("{} 击杀峡谷先锋", team("killer_team")),
+        Some("baron_killed") => format!("{} 击杀纳什男爵", team("killer_team")),
         Some("agent_join") => format!(
             "{}（{}）加入对局",
             p.get("name")
@@ -274,6 +274,7 @@ impl ProcessService {
                 cwd: install_root(),
                 rust_log: Some(rust_log()),
                 log_db: Some(log_db),
+                ws_auth: None, // 桌面端本地调试保持开放
             };
             let input = StartGameInput {
                 id,
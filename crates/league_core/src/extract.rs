@@ -567,6 +567,7 @@ pub struct CharacterRecord {
     pub minimap_icon_override: Option<String>,
     pub minion_flags: Option<u32>,
     pub minion_score_value: Option<f32>,
+    pub mr_per_level: Option<ModifiableFloat>,
     pub name: Option<String>,
     pub occluded_unit_selectable_distance: Option<f32>,
     pub on_kill_event: Option<u32>,
@@ -603,7 +604,6 @@ pub struct CharacterRecord {
     pub tower_targeting_priority_boost: Option<f32>,
     pub treat_auto_attacks_as_normal_spells: Option<TreatAutoAttacksAsNormalSpells>,
     pub unit_tags_string: Option<String>,
-    pub unk_0x1262a25: Option<ModifiableFloat>,
     pub unk_0x1abb82c0: Option<Unk0xef35eacc>,
     pub unk_0x2290fc9a: Option<ModifiableFloat>,
     pub unk_0x3f975e4a: Option<bool>,
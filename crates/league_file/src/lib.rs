@@ -9,4 +9,5 @@ pub mod mesh_skinned;
 pub mod mesh_static;
 pub mod shader;
 pub mod skeleton;
+pub mod stringtable;
 pub mod wpk;
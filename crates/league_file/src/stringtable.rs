@@ -0,0 +1,101 @@
+//! Riot stringtable（RST）本地化字符串表解析。
+//!
+//! 布局如下（全部小端）：
+//! - magic: `RST`（3 字节）
+//! - version: u8（已知 2~5）
+//! - v2：has_font_config(u8)，为真时跟 len(u32) + 字体配置字符串
+//! - count: u32
+//! - entries: [u64; count]，低 `hash_bits` 位为键哈希，其余高位为字符串在数据区的偏移
+//! - v2~v4：mode(u8)
+//! - 数据区：以 `\0` 结尾的 UTF-8 字符串
+//!
+//! 键哈希位数随版本缩短：v2/v3 为 40，v4 为 39，v5 为 38。
+
+use std::collections::HashMap;
+
+use league_utils::{hash_stringtable, hash_stringtable_xxh3};
+use nom::IResult;
+use nom::number::complete::{le_u8, le_u32, le_u64};
+
+pub const MAGIC: &[u8; 3] = b"RST";
+
+#[derive(Debug, Clone, Default)]
+pub struct StringTable {
+    /// 键哈希位数
+    pub hash_bits: u32,
+    /// 键哈希 -> 字符串
+    pub entries: HashMap<u64, String>,
+}
+
+impl StringTable {
+    /// 解析整个 stringtable；`input` 必须是完整文件内容（字符串使用数据区内偏移）。
+    pub fn parse(input: &[u8]) -> IResult<&[u8], Self> {
+        let (i, magic) = nom::bytes::complete::take(3usize)(input)?;
+        if magic != MAGIC {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                nom::error::ErrorKind::Tag,
+            )));
+        }
+        let (mut i, version) = le_u8(i)?;
+        let hash_bits = match version {
+            2 | 3 => 40,
+            4 => 39,
+            5 => 38,
+            _ => {
+                return Err(nom::Err::Error(nom::error::Error::new(
+                    input,
+                    nom::error::ErrorKind::Verify,
+                )));
+            }
+        };
+
+        if version == 2 {
+            let (rest, has_font_config) = le_u8(i)?;
+            i = rest;
+            if has_font_config != 0 {
+                let (rest, len) = le_u32(i)?;
+                let (rest, _font_config) = nom::bytes::complete::take(len as usize)(rest)?;
+                i = rest;
+            }
+        }
+
+        let (mut i, count) = le_u32(i)?;
+        let mut raw_entries = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let (rest, value) = le_u64(i)?;
+            raw_entries.push(value);
+            i = rest;
+        }
+        if version < 5 {
+            let (rest, _mode) = le_u8(i)?;
+            i = rest;
+        }
+
+        let data = i;
+        let mask = (1u64 << hash_bits) - 1;
+        let mut entries = HashMap::with_capacity(raw_entries.len());
+        for value in raw_entries {
+            let offset = (value >> hash_bits) as usize;
+            let Some(bytes) = data.get(offset..) else {
+                continue;
+            };
+            let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+            let text = String::from_utf8_lossy(&bytes[..end]).into_owned();
+            entries.insert(value & mask, text);
+        }
+
+        Ok((&input[input.len()..], StringTable { hash_bits, entries }))
+    }
+
+    /// 按本地化键查字符串（忽略大小写），兼容 xxh64 与 xxh3 两种键哈希
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .get(&hash_stringtable(key, self.hash_bits))
+            .or_else(|| {
+                self.entries
+                    .get(&hash_stringtable_xxh3(key, self.hash_bits))
+            })
+            .map(String::as_str)
+    }
+}
@@ -2,7 +2,10 @@ use std::collections::HashMap;
 
 use bevy::ecs::archetype;
 use bevy::prelude::*;
-use league_core::extract::{AbilityResourceSlotInfo, CharacterRecord, SpellObject};
+use league_core::extract::{
+    AbilityResourceSlotInfo, CharacterRecord, ModifiableFloat, SpellObject,
+};
+use league_file::stringtable::StringTable;
 use league_loader::game::{LeagueLoader, PropGroup};
 use league_utils::hash_bin;
 use lol_base::spell::Spell;
@@ -11,6 +14,7 @@ use lol_core::base::ability_resource::{AbilityResource, AbilityResourceType};
 use lol_core::base::bounding::Bounding;
 use lol_core::base::gold::{Gold, GoldDrop};
 use lol_core::base::level::{ExperienceDrop, Level};
+use lol_core::base::stat_modifier::StatGrowth;
 use lol_core::base::stats::ChampionStats;
 use lol_core::character::Character;
 use lol_core::damage::{Armor, Damage};
@@ -193,6 +197,22 @@ pub fn create_champion_components_from_record(
     )
 }
 
+/// 从 CharacterRecord 提取每级属性成长
+pub fn create_stat_growth_from_record(record: &CharacterRecord) -> StatGrowth {
+    let per_level =
+        |value: &Option<ModifiableFloat>| value.as_ref().map(|v| v.base_value).unwrap_or(0.0);
+    StatGrowth {
+        health: per_level(&record.hp_per_level_modifiable),
+        damage: per_level(&record.damage_per_level_modifiable),
+        armor: per_level(&record.armor_per_level_modifiable),
+        magic_resist: per_level(&record.mr_per_level),
+        // bin 中攻速成长为百分数（如 2.5 = 每级 +2.5%）
+        attack_speed: per_level(&record.attack_speed_per_level_modifiable) / 100.0,
+        // 英雄无法强成长；法力成长随资源槽导出到 AbilityResource::per_level
+        ..Default::default()
+    }
+}
+
 /// 从 AbilityResourceSlotInfo 创建 AbilityResource 组件
 fn create_ability_resource_from_record(
     slot_info: &Option<AbilityResourceSlotInfo>,
@@ -240,6 +260,7 @@ pub fn extract_character_from_record(
     char_record_path: Option<&str>,
     skin_bin_path: Option<&str>,
     hashes: &HashMap<u32, String>,
+    strings: Option<&StringTable>,
 ) -> bool {
     let bin_path = format!("data/characters/{}/{}.bin", character_name, character_name);
 
@@ -260,7 +281,8 @@ pub fn extract_character_from_record(
     };
 
     // 提取技能数据到文件，获取所有技能名称列表
-    let all_spell_names = extract_spells_for_champion(loader, character_name, &prop_group, hashes);
+    let all_spell_names =
+        extract_spells_for_champion(loader, character_name, &prop_group, hashes, strings);
 
     // 创建 App 用于获取 AssetServer
     let mut app = App::new();
@@ -279,6 +301,7 @@ pub fn extract_character_from_record(
 
     let (attack, health, damage, armor, movement, experience_drop, gold_drop, ability_resource) =
         create_champion_components_from_record(&record);
+    let stat_growth = create_stat_growth_from_record(&record);
 
     let basic_attack_name = format!("{}BasicAttack", character_name);
     let attack = if all_spell_names.contains(&basic_attack_name) {
@@ -315,6 +338,7 @@ pub fn extract_character_from_record(
         damage,
         armor,
         movement,
+        stat_growth,
         lol_spells,
     ));
 
@@ -9,15 +9,17 @@ use league_core::extract::{
 };
 use league_file::grid::AiMeshNGrid;
 use league_file::mapgeo::LeagueMapGeo;
+use league_file::stringtable::StringTable;
 use league_loader::game::LeagueLoader;
 use league_loader::prop_bin::LeagueWadLoaderTrait;
+use league_loader::wad::LeagueWadLoader;
 use league_property::extract::get_hashes;
 use lol_base::character::{ConfigCharacterRecord, ConfigSkin};
 use lol_base::map::MapPaths;
 use lol_core::entities::barrack::BarrackConfigHandler;
 use lol_core::entities::inhibitor::Inhibitor;
 use lol_core::entities::nexus::Nexus;
-use lol_core::entities::turret::Turret;
+use lol_core::entities::turret::{Turret, TurretTier};
 use lol_core::lane::Lane;
 use lol_core::map::MinionPath;
 use lol_core::navigation::grid::ResourceGrid;
@@ -31,10 +33,17 @@ use crate::extract::champion::{
     ChampionRecordData, extract_character_from_record, skin_path_to_skin_bin_path,
 };
 use crate::extract::item::extract_item_data;
+use crate::extract::spell::{extract_summoner_spells, load_string_table, write_spell_calc_report};
 use crate::extract::utils::write_to_file;
 use crate::gltf_export::export_mapgeo_to_gltf;
 use crate::navgrid::load_league_nav_grid;
 
+/// 可选加载的本地化 WAD（字符串表）
+const LOCALIZED_WAD_FILES: [&str; 2] = [
+    "DATA/FINAL/Localized/Global.zh_CN.wad.client",
+    "DATA/FINAL/Localized/Global.en_US.wad.client",
+];
+
 /// 完整的地图提取结果
 pub struct MapExtractResult {
     pub minion_path: MinionPath,
@@ -52,11 +61,23 @@ pub fn extract_phase_1_create_loader(game_path: &str) -> LeagueLoader {
         "DATA/FINAL/Bootstrap.windows.wad.client",
     ];
 
-    LeagueLoader::from_relative_path(game_path, wad_files).with_all_champions()
+    let mut loader = LeagueLoader::from_relative_path(game_path, wad_files).with_all_champions();
+
+    // 本地化字符串表所在 WAD 随客户端语言而定，缺失时只影响技能提示文本
+    for wad in LOCALIZED_WAD_FILES {
+        if let Ok(wad_loader) = LeagueWadLoader::from_relative_path(game_path, wad) {
+            loader.wads.push(wad_loader);
+        }
+    }
+    loader
 }
 
 /// Phase 2: 提取所有英雄
-pub fn extract_phase_2_champions(loader: &LeagueLoader, hashes: &HashMap<u32, String>) {
+pub fn extract_phase_2_champions(
+    loader: &LeagueLoader,
+    hashes: &HashMap<u32, String>,
+    strings: Option<&StringTable>,
+) {
     println!("[2/7] Phase 2: 提取所有英雄...");
     let champions_path = std::path::Path::new(&loader.root_dir).join("DATA/FINAL/Champions");
     let Ok(entries) = std::fs::read_dir(&champions_path) else {
@@ -99,6 +120,7 @@ pub fn extract_phase_2_champions(loader: &LeagueLoader, hashes: &HashMap<u32, St
                 None,
                 skin_bin_path.as_deref(),
                 hashes,
+                strings,
             );
             (character_name, success)
         })
@@ -118,6 +140,13 @@ pub fn extract_phase_2_champions(loader: &LeagueLoader, hashes: &HashMap<u32, St
         "[SUMMARY] 英雄提取完成: 成功 {} 个, 跳过 {} 个",
         success_count, skip_count
     );
+
+    let extracted: Vec<String> = results
+        .into_iter()
+        .filter(|(_, success)| *success)
+        .map(|(character_name, _)| character_name)
+        .collect();
+    write_spell_calc_report(&extracted);
 }
 
 /// Phase 3: 提取地图块数据（兵线、兵营、角色记录）
@@ -304,6 +333,7 @@ pub fn extract_phase_6_map_character_records(
     loader: &LeagueLoader,
     map_character_records: &std::collections::HashMap<String, Vec<ChampionRecordData>>,
     hashes: &HashMap<u32, String>,
+    strings: Option<&StringTable>,
 ) {
     if map_character_records.is_empty() {
         return;
@@ -335,6 +365,7 @@ pub fn extract_phase_6_map_character_records(
                     Some(&record_data.char_record_path),
                     skin_bin_path.as_deref(),
                     hashes,
+                    strings,
                 );
                 results.push((character_name.clone(), success));
             }
@@ -455,6 +486,9 @@ pub fn spawn_character_record<B: Bundle>(
             match attackable_unit.r#type {
                 Some(0) => {
                     entity_mut.insert(Turret);
+                    if let Some(tier) = TurretTier::from_character_name(character_name) {
+                        entity_mut.insert(tier);
+                    }
                 }
                 Some(1) => {
                     entity_mut.insert(Inhibitor);
@@ -492,6 +526,7 @@ pub fn extract_with_options(game_path: &str, hashes_dir: &str, options: ExtractO
         format!("{}/hashes.bintypes.txt", hashes_dir),
     ];
     let hashes = get_hashes(&hash_paths.iter().map(|s| s.as_str()).collect::<Vec<_>>());
+    let strings = load_string_table(&loader);
 
     let mut app = App::new();
     app.add_plugins(AssetPlugin::default());
@@ -507,7 +542,9 @@ pub fn extract_with_options(game_path: &str, hashes_dir: &str, options: ExtractO
     let world = app.world_mut();
 
     // Phase 2: 提取英雄
-    extract_phase_2_champions(&loader, &hashes);
+    extract_phase_2_champions(&loader, &hashes, strings.as_ref());
+    // 召唤师技能不属于任何英雄，单独从全局与地图 WAD 导出
+    extract_summoner_spells(&loader, &hashes, strings.as_ref());
 
     // Phase 3: 提取地图块
     let map_character_records = extract_phase_3_map_chunks(world, &loader, &map_paths);
@@ -521,7 +558,12 @@ pub fn extract_with_options(game_path: &str, hashes_dir: &str, options: ExtractO
     }
 
     // Phase 6: 从地图提取角色记录
-    extract_phase_6_map_character_records(&loader, &map_character_records, &hashes);
+    extract_phase_6_map_character_records(
+        &loader,
+        &map_character_records,
+        &hashes,
+        strings.as_ref(),
+    );
 
     // Phase 7: 序列化 World
     extract_phase_7_serialize_world(world, &map_paths);
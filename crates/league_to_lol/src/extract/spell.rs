@@ -1,10 +1,17 @@
 use std::collections::{BTreeMap, HashMap};
+use std::io::Read;
+use std::path::Path;
 
 use league_core::extract::{
+    EnumAbilityResourceByCoefficientCalculationPart, EnumAboveHealthPercentCastRequirement,
     EnumGameCalculation, GameCalculation, SpellDataResource, SpellDataValue, SpellEffectAmount,
     SpellObject,
 };
-use league_loader::game::PropGroup;
+use league_file::stringtable::StringTable;
+use league_loader::game::{LeagueLoader, PropGroup};
+use league_loader::prop_bin::LeagueWadLoaderTrait;
+use league_loader::wad_parse::WadDataFormat;
+use league_property::prop::PropFile;
 use league_utils::hash_to_field_name;
 use lol_base::movement::{
     HeightSolver, MissileBehavior, MissileSpecification, MovementType, MovementTypeFixedSpeed,
@@ -12,21 +19,67 @@ use lol_base::movement::{
 };
 use lol_base::spell::{DataSpell, Spell, ValuesData, ValuesEffect};
 use lol_base::spell_calc::{
-    CalculationPart, CalculationPartEffectValue, CalculationPartNamedDataValue,
-    CalculationPartStatCoefficient, CalculationPartStatNamedDataValue, CalculationPartStatSub,
+    CalculationBreakpoint, CalculationConditional, CalculationModified, CalculationPart,
+    CalculationPartBuffCounterCoefficient, CalculationPartBuffCounterNamedDataValue,
+    CalculationPartByCharLevelBreakpoints, CalculationPartByCharLevelFormula,
+    CalculationPartByCharLevelInterpolation, CalculationPartClamp, CalculationPartEffectValue,
+    CalculationPartExponent, CalculationPartNamedDataValue, CalculationPartNumber,
+    CalculationPartProduct, CalculationPartStatCoefficient, CalculationPartStatNamedDataValue,
+    CalculationPartStatSub, CalculationPartSum, CalculationPartUnsupported, CalculationRequirement,
     CalculationSpell, CalculationType,
 };
+use lol_core::summoner::SummonerSpellKind;
 
 use crate::data::Data;
 use crate::extract::utils::{extract_texture, write_to_file};
 
+/// 本地化字符串表候选路径：优先简体中文，缺失时退回英文；新客户端合并为 `lol.stringtable`
+const STRING_TABLE_PATHS: [&str; 4] = [
+    "data/menu/zh_cn/lol.stringtable",
+    "data/menu/zh_cn/main.stringtable",
+    "data/menu/en_us/lol.stringtable",
+    "data/menu/en_us/main.stringtable",
+];
+
+/// 召唤师技能对象所在的 WAD：不挂在英雄 bin 上，需在全局与地图 WAD 中查找
+const SUMMONER_SPELL_WADS: [&str; 2] = [
+    "DATA/FINAL/Global.wad.client",
+    "DATA/FINAL/Maps/Shipping/Map11.wad.client",
+];
+
+/// 字符串表 `{{ key }}` 引用的最大展开深度，防止数据成环
+const MAX_STRING_REF_DEPTH: u32 = 4;
+
+/// 加载本地化字符串表，供技能提示文本模板查表；找不到时不导出提示文本
+pub fn load_string_table(loader: &LeagueLoader) -> Option<StringTable> {
+    for path in STRING_TABLE_PATHS {
+        let Ok(buf) = loader.get_wad_entry_buffer_by_path(path) else {
+            continue;
+        };
+        match StringTable::parse(&buf) {
+            Ok((_, table)) => {
+                println!(
+                    "[INFO] 已加载本地化字符串表: {} ({} 条)",
+                    path,
+                    table.entries.len()
+                );
+                return Some(table);
+            }
+            Err(_) => println!("[WARN] 无法解析本地化字符串表: {}", path),
+        }
+    }
+    println!("[WARN] 未找到本地化字符串表，技能提示文本不会导出");
+    None
+}
+
 /// 从 CharacterRecord 所在 bin 文件提取所有 SpellObject，转换为 DataSpell 并导出
 /// 返回所有技能对象名称列表
 pub fn extract_spells_for_champion(
-    loader: &league_loader::game::LeagueLoader,
+    loader: &LeagueLoader,
     champ_name: &str,
     prop_group: &PropGroup,
     hashes: &HashMap<u32, String>,
+    strings: Option<&StringTable>,
 ) -> Vec<String> {
     let spells = prop_group.get_all_by_class::<SpellObject>();
 
@@ -37,50 +90,129 @@ pub fn extract_spells_for_champion(
         if object_name.is_empty() {
             continue;
         }
+        let output_path = format!("characters/{}/spells/{}.ron", champ_name, object_name);
+        if export_spell_object(loader, &spell_obj, &output_path, hashes, strings) {
+            spell_names.push(object_name.clone());
+        }
+    }
 
-        let Some(spell_data) = &spell_obj.m_spell else {
-            continue;
-        };
+    spell_names
+}
 
-        let mut data_spell = convert_spell_data_resource(spell_data, hashes);
+/// 扫描全局与地图 WAD 中的 bin，按对象名导出召唤师技能到 `spells/<对象名>.ron`，
+/// 运行时 `SummonerSpells` 据此读取冷却与施法距离；返回已导出的对象名
+pub fn extract_summoner_spells(
+    loader: &LeagueLoader,
+    hashes: &HashMap<u32, String>,
+    strings: Option<&StringTable>,
+) -> Vec<String> {
+    let wanted: Vec<&str> = SummonerSpellKind::ALL
+        .iter()
+        .map(|kind| kind.spell_name())
+        .collect();
+    let mut exported: Vec<String> = Vec::new();
 
-        // 导出技能图标并更新路径
-        if let Some(icons) = &mut data_spell.icon_path {
-            for icon in icons {
-                *icon = extract_texture(loader, icon);
+    let wads = loader
+        .wads
+        .iter()
+        .filter(|wad| SUMMONER_SPELL_WADS.contains(&wad.relative_path.as_str()));
+    for wad in wads {
+        for (hash, entry) in &wad.wad.entries {
+            if matches!(
+                entry.format,
+                WadDataFormat::Gzip | WadDataFormat::Redirection
+            ) {
+                continue;
+            }
+            // 只读文件头判断是否为 bin，避免解压贴图等大文件
+            let Ok(mut reader) = wad.get_wad_entry_reader_by_hash(*hash) else {
+                continue;
+            };
+            let mut buf = vec![0u8; 4];
+            if reader.read_exact(&mut buf).is_err() || buf != b"PROP" {
+                continue;
+            }
+            if reader.read_to_end(&mut buf).is_err() {
+                continue;
+            }
+            let Ok((_, prop)) = PropFile::parse(&buf) else {
+                continue;
+            };
+            let prop_group = PropGroup::new(vec![prop]);
+            for spell_obj in prop_group.get_all_by_class::<SpellObject>() {
+                let object_name = &spell_obj.object_name;
+                if !wanted.contains(&object_name.as_str()) || exported.contains(object_name) {
+                    continue;
+                }
+                let output_path = format!("spells/{}.ron", object_name);
+                if export_spell_object(loader, &spell_obj, &output_path, hashes, strings) {
+                    exported.push(object_name.clone());
+                }
             }
         }
+    }
 
-        let spell = Spell {
-            spell_data: Some(data_spell),
-        };
+    for name in wanted {
+        if !exported.iter().any(|e| e == name) {
+            println!("[WARN] 未找到召唤师技能: {}", name);
+        }
+    }
+    exported
+}
 
-        let output_dir = format!("characters/{}/spells", champ_name);
-        let output_path = format!("{}/{}.ron", output_dir, object_name);
+/// 转换单个 SpellObject（连同图标）并写到 `output_path`，成功返回 true
+fn export_spell_object(
+    loader: &LeagueLoader,
+    spell_obj: &SpellObject,
+    output_path: &str,
+    hashes: &HashMap<u32, String>,
+    strings: Option<&StringTable>,
+) -> bool {
+    let Some(spell_data) = &spell_obj.m_spell else {
+        return false;
+    };
 
-        let serialized = ron::ser::to_string_pretty(&spell, ron::ser::PrettyConfig::default())
-            .map_err(|e| format!("序列化失败: {}", e));
+    let mut data_spell = convert_spell_data_resource(spell_data, hashes, strings);
 
-        match serialized {
-            Ok(s) => {
-                write_to_file(&output_path, s);
-                // println!("[INFO] 已导出技能: {}", output_path);
-                spell_names.push(object_name.clone());
-            }
-            Err(e) => {
-                println!("[WARN] 序列化 {} 失败: {}", object_name, e);
-            }
+    // 导出技能图标并更新路径
+    if let Some(icons) = &mut data_spell.icon_path {
+        for icon in icons {
+            *icon = extract_texture(loader, icon);
         }
     }
 
-    spell_names
+    let spell = Spell {
+        spell_data: Some(data_spell),
+    };
+
+    match ron::ser::to_string_pretty(&spell, ron::ser::PrettyConfig::default()) {
+        Ok(s) => {
+            write_to_file(output_path, s);
+            true
+        }
+        Err(e) => {
+            println!("[WARN] 序列化 {} 失败: {}", spell_obj.object_name, e);
+            false
+        }
+    }
 }
 
 /// 将 SpellDataResource 转换为 DataSpell
 fn convert_spell_data_resource(
     spell: &SpellDataResource,
     hashes: &HashMap<u32, String>,
+    strings: Option<&StringTable>,
 ) -> DataSpell {
+    let tooltip_loc_keys = spell
+        .m_client_data
+        .as_ref()
+        .and_then(|client| client.m_tooltip_data.as_ref())
+        .and_then(|tooltip| tooltip.m_loc_keys.clone());
+    let tooltip_templates = tooltip_loc_keys
+        .as_ref()
+        .zip(strings)
+        .and_then(|(loc_keys, strings)| convert_tooltip_templates(loc_keys, strings));
+
     DataSpell {
         calculations: spell
             .m_spell_calculations
@@ -111,9 +243,50 @@ fn convert_spell_data_resource(
         selection_priority: spell.selection_priority,
         icon_path: spell.m_img_icon_name.clone(),
         use_animator_framerate: spell.use_animator_framerate,
+        tooltip_loc_keys,
+        tooltip_templates,
     }
 }
 
+/// 按本地化键查出提示文本模板（键名如 `keyTooltip` -> 文本）
+fn convert_tooltip_templates(
+    loc_keys: &BTreeMap<String, String>,
+    strings: &StringTable,
+) -> Option<BTreeMap<String, String>> {
+    let templates: BTreeMap<String, String> = loc_keys
+        .iter()
+        .filter_map(|(name, key)| {
+            let text = strings.get(key)?;
+            Some((name.clone(), expand_string_refs(text, strings, 0)))
+        })
+        .collect();
+    (!templates.is_empty()).then_some(templates)
+}
+
+/// 展开 `{{ key }}` 形式的字符串表引用；查不到的保留原样，留给提示渲染按计算名求值
+fn expand_string_refs(text: &str, strings: &StringTable, depth: u32) -> String {
+    if depth >= MAX_STRING_REF_DEPTH {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let inner = &rest[start + 2..];
+        let Some(end) = inner.find("}}") else {
+            rest = &rest[start..];
+            break;
+        };
+        match strings.get(inner[..end].trim()) {
+            Some(value) => out.push_str(&expand_string_refs(value, strings, depth + 1)),
+            None => out.push_str(&rest[start..start + end + 4]),
+        }
+        rest = &inner[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
 /// 转换技能效果值
 fn convert_effect_amounts(effects: &Option<Vec<SpellEffectAmount>>) -> Option<Vec<ValuesEffect>> {
     effects.as_ref().map(|effects| {
@@ -226,10 +399,26 @@ fn convert_enum_calculation(
         EnumGameCalculation::GameCalculation(gc) => Some(CalculationType::CalculationSpell(
             convert_game_calculation(gc, hashes),
         )),
-        _ => {
-            // 暂不支持 Conditional 和 Modified
-            None
+        EnumGameCalculation::GameCalculationConditional(gc) => Some(
+            CalculationType::CalculationConditional(CalculationConditional {
+                requirement: convert_requirement(
+                    &gc.m_conditional_calculation_requirements,
+                    hashes,
+                ),
+                conditional: hash_to_field_name(&gc.m_conditional_game_calculation, hashes),
+                default: gc
+                    .m_default_game_calculation
+                    .map(|h| hash_to_field_name(&h, hashes)),
+            }),
+        ),
+        EnumGameCalculation::GameCalculationModified(gc) => {
+            Some(CalculationType::CalculationModified(CalculationModified {
+                calculation: hash_to_field_name(&gc.m_modified_game_calculation, hashes),
+                multiplier: convert_calculation_part(&gc.m_multiplier, hashes),
+                override_spell_level: gc.m_override_spell_level,
+            }))
         }
+        _ => None,
     }
 }
 
@@ -242,65 +431,265 @@ fn convert_game_calculation(
         formula_parts: gc.m_formula_parts.as_ref().map(|parts| {
             parts
                 .iter()
-                .filter_map(|p| convert_calculation_part(p, hashes))
+                .map(|p| convert_calculation_part(p, hashes))
                 .collect()
         }),
         multiplier: gc
             .m_multiplier
             .as_ref()
-            .and_then(|m| convert_calculation_part(m, hashes)),
+            .map(|m| convert_calculation_part(m, hashes)),
         precision: gc.m_precision,
     }
 }
 
-/// 转换计算部件
+/// 枚举变体名（Debug 输出中括号前的部分），用于记录不支持的类型
+fn variant_name(value: &impl std::fmt::Debug) -> String {
+    let debug = format!("{:?}", value);
+    debug
+        .split(|c: char| c == '(' || c == ' ' || c == '{')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// 转换条件计算的前置条件，不支持的条件保留类型名
+fn convert_requirement(
+    requirement: &EnumAboveHealthPercentCastRequirement,
+    hashes: &HashMap<u32, String>,
+) -> CalculationRequirement {
+    use EnumAboveHealthPercentCastRequirement as R;
+
+    let convert_all = |subs: &Vec<Box<R>>| {
+        subs.iter()
+            .map(|sub| convert_requirement(sub, hashes))
+            .collect()
+    };
+
+    match requirement {
+        R::AboveHealthPercentCastRequirement(r) => CalculationRequirement::AboveHealthPercent {
+            percent: r.m_current_percent_health,
+            data_value: r.data_value.map(|h| hash_to_field_name(&h, hashes)),
+            invert: r.m_invert_result,
+        },
+        R::HasBuffCastRequirement(r) => CalculationRequirement::HasBuff {
+            buff_name: r.m_buff_name.map(|h| hash_to_field_name(&h, hashes)),
+            invert: r.m_invert_result.unwrap_or(false),
+        },
+        R::SpellSlotEqualOrGreaterThanLevelRequirement(r) => {
+            CalculationRequirement::SpellLevelAtLeast { level: r.level }
+        }
+        R::IsRangedCastRequirement => CalculationRequirement::IsRanged,
+        R::HasAllSubRequirementsCastRequirement(r) => CalculationRequirement::All {
+            requirements: convert_all(&r.m_sub_requirements),
+        },
+        R::HasAtleastNSubRequirementsCastRequirement(r) => CalculationRequirement::AtLeast {
+            requirements: convert_all(&r.m_sub_requirements),
+            count: r.m_successes_required,
+        },
+        other => CalculationRequirement::Unsupported {
+            kind: variant_name(other),
+        },
+    }
+}
+
+/// 转换计算部件，不支持的部件保留类型名（求值为 0）
 fn convert_calculation_part(
-    part: &league_core::extract::EnumAbilityResourceByCoefficientCalculationPart,
+    part: &EnumAbilityResourceByCoefficientCalculationPart,
     hashes: &HashMap<u32, String>,
-) -> Option<CalculationPart> {
+) -> CalculationPart {
+    use EnumAbilityResourceByCoefficientCalculationPart as P;
+
+    let convert_all = |parts: &Vec<Box<P>>| {
+        parts
+            .iter()
+            .map(|p| convert_calculation_part(p, hashes))
+            .collect()
+    };
+
     match part {
-        league_core::extract::EnumAbilityResourceByCoefficientCalculationPart::EffectValueCalculationPart(
-            p,
-        ) => Some(CalculationPart::CalculationPartEffectValue(
-            CalculationPartEffectValue {
+        P::EffectValueCalculationPart(p) => {
+            CalculationPart::CalculationPartEffectValue(CalculationPartEffectValue {
                 effect_index: p.m_effect_index,
-            },
-        )),
-        league_core::extract::EnumAbilityResourceByCoefficientCalculationPart::StatByCoefficientCalculationPart(
-            p,
-        ) => Some(CalculationPart::CalculationPartStatCoefficient(
-            CalculationPartStatCoefficient {
+            })
+        }
+        P::StatByCoefficientCalculationPart(p) => {
+            CalculationPart::CalculationPartStatCoefficient(CalculationPartStatCoefficient {
                 stat: p.m_stat,
                 coefficient: p.m_coefficient,
                 stat_formula: p.m_stat_formula,
-            },
-        )),
-        league_core::extract::EnumAbilityResourceByCoefficientCalculationPart::NamedDataValueCalculationPart(
-            p,
-        ) => Some(CalculationPart::CalculationPartNamedDataValue(
-            CalculationPartNamedDataValue {
+            })
+        }
+        P::NamedDataValueCalculationPart(p) => {
+            CalculationPart::CalculationPartNamedDataValue(CalculationPartNamedDataValue {
                 data_value: hash_to_field_name(&p.m_data_value, hashes),
-            },
-        )),
-        league_core::extract::EnumAbilityResourceByCoefficientCalculationPart::StatBySubPartCalculationPart(
-            p,
-        ) => {
-            let subpart = convert_calculation_part(&p.m_subpart, hashes);
-            Some(CalculationPart::CalculationPartStatSub(
-                CalculationPartStatSub {
-                    stat: p.m_stat,
-                    subpart: subpart.map(Box::new),
-                },
-            ))
+            })
         }
-        league_core::extract::EnumAbilityResourceByCoefficientCalculationPart::StatByNamedDataValueCalculationPart(
-            p,
-        ) => Some(CalculationPart::CalculationPartStatNamedDataValue(
-            CalculationPartStatNamedDataValue {
+        P::StatBySubPartCalculationPart(p) => {
+            CalculationPart::CalculationPartStatSub(CalculationPartStatSub {
+                stat: p.m_stat,
+                subpart: Some(Box::new(convert_calculation_part(&p.m_subpart, hashes))),
+            })
+        }
+        P::StatByNamedDataValueCalculationPart(p) => {
+            CalculationPart::CalculationPartStatNamedDataValue(CalculationPartStatNamedDataValue {
                 stat: p.m_stat,
                 data_value: hash_to_field_name(&p.m_data_value, hashes),
-            },
-        )),
-        _ => None,
+            })
+        }
+        P::NumberCalculationPart(p) => {
+            CalculationPart::CalculationPartNumber(CalculationPartNumber { number: p.m_number })
+        }
+        P::ByCharLevelBreakpointsCalculationPart(p) => {
+            CalculationPart::CalculationPartByCharLevelBreakpoints(
+                CalculationPartByCharLevelBreakpoints {
+                    level1_value: p.m_level1_value,
+                    initial_bonus_per_level: p.m_initial_bonus_per_level,
+                    breakpoints: p
+                        .m_breakpoints
+                        .iter()
+                        .flatten()
+                        .map(|b| CalculationBreakpoint {
+                            level: b.m_level,
+                            additional_bonus_at_this_level: b.m_additional_bonus_at_this_level,
+                            bonus_per_level_at_and_after: b.m_bonus_per_level_at_and_after,
+                        })
+                        .collect(),
+                },
+            )
+        }
+        P::ByCharLevelInterpolationCalculationPart(p) => {
+            CalculationPart::CalculationPartByCharLevelInterpolation(
+                CalculationPartByCharLevelInterpolation {
+                    start_value: p.m_start_value,
+                    end_value: p.m_end_value,
+                    scale_by_stat_progression: p.m_scale_by_stat_progression_multiplier,
+                    scale_past_default_max_level: p.m_scale_past_default_max_level,
+                },
+            )
+        }
+        P::ByCharLevelFormulaCalculationPart(p) => {
+            CalculationPart::CalculationPartByCharLevelFormula(CalculationPartByCharLevelFormula {
+                values: p.values.clone(),
+            })
+        }
+        P::BuffCounterByCoefficientCalculationPart(p) => {
+            CalculationPart::CalculationPartBuffCounterCoefficient(
+                CalculationPartBuffCounterCoefficient {
+                    buff_name: hash_to_field_name(&p.m_buff_name, hashes),
+                    coefficient: p.m_coefficient,
+                },
+            )
+        }
+        P::BuffCounterByNamedDataValueCalculationPart(p) => {
+            CalculationPart::CalculationPartBuffCounterNamedDataValue(
+                CalculationPartBuffCounterNamedDataValue {
+                    buff_name: hash_to_field_name(&p.m_buff_name, hashes),
+                    data_value: hash_to_field_name(&p.m_data_value, hashes),
+                },
+            )
+        }
+        P::ClampSubPartsCalculationPart(p) => {
+            CalculationPart::CalculationPartClamp(CalculationPartClamp {
+                floor: p.m_floor,
+                ceiling: p.m_ceiling,
+                subparts: convert_all(&p.m_subparts),
+            })
+        }
+        P::SumOfSubPartsCalculationPart(p) => {
+            CalculationPart::CalculationPartSum(CalculationPartSum {
+                subparts: convert_all(&p.m_subparts),
+            })
+        }
+        P::ProductOfSubPartsCalculationPart(p) => {
+            CalculationPart::CalculationPartProduct(CalculationPartProduct {
+                part1: Box::new(convert_calculation_part(&p.m_part1, hashes)),
+                part2: Box::new(convert_calculation_part(&p.m_part2, hashes)),
+            })
+        }
+        P::ExponentSubPartsCalculationPart(p) => {
+            CalculationPart::CalculationPartExponent(CalculationPartExponent {
+                part1: Box::new(convert_calculation_part(&p.part1, hashes)),
+                part2: Box::new(convert_calculation_part(&p.part2, hashes)),
+            })
+        }
+        other => CalculationPart::CalculationPartUnsupported(CalculationPartUnsupported {
+            kind: variant_name(other),
+        }),
     }
 }
+
+/// 汇总已导出英雄技能中仍含不支持部件 / 条件的计算，写入 `spell_calc_report.txt`。
+///
+/// 读取 `assets/characters/<英雄>/spells/*.ron`，每行一条 `英雄/技能 计算名: 类型...`，
+/// 末尾按类型统计出现次数，便于按影响面排序补齐求值。
+pub fn write_spell_calc_report(character_names: &[String]) {
+    let mut lines = Vec::new();
+    let mut kind_counts: BTreeMap<String, usize> = BTreeMap::new();
+
+    let mut character_names = character_names.to_vec();
+    character_names.sort();
+    for character_name in &character_names {
+        let spells_dir = Path::new("assets")
+            .join("characters")
+            .join(character_name)
+            .join("spells");
+        let Ok(entries) = std::fs::read_dir(&spells_dir) else {
+            continue;
+        };
+        let mut paths: Vec<_> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|ext| ext == "ron"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let Ok(content) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let Ok(spell) = ron::from_str::<Spell>(&content) else {
+                println!("[WARN] 无法解析技能: {}", path.display());
+                continue;
+            };
+            let spell_name = path.file_stem().unwrap_or_default().to_string_lossy();
+            let Some(calculations) = spell
+                .spell_data
+                .as_ref()
+                .and_then(|d| d.calculations.as_ref())
+            else {
+                continue;
+            };
+            for (calc_name, calc) in calculations {
+                let kinds = calc.unsupported_parts(calculations);
+                if kinds.is_empty() {
+                    continue;
+                }
+                for kind in &kinds {
+                    *kind_counts.entry(kind.clone()).or_default() += 1;
+                }
+                lines.push(format!(
+                    "{}/{} {}: {}",
+                    character_name,
+                    spell_name,
+                    calc_name,
+                    kinds.join(", ")
+                ));
+            }
+        }
+    }
+
+    let mut report = lines.join("\n");
+    report.push_str("\n\n# 按类型统计\n");
+    let mut counts: Vec<_> = kind_counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    for (kind, count) in &counts {
+        report.push_str(&format!("{}: {}\n", kind, count));
+    }
+    write_to_file("spell_calc_report.txt", report);
+
+    println!(
+        "[SUMMARY] 技能计算报告: {} 条计算含不支持部件，涉及 {} 种类型",
+        lines.len(),
+        counts.len()
+    );
+}
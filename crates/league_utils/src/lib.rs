@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::hash::Hasher;
 
 use heck::{ToPascalCase, ToSnakeCase};
-use twox_hash::XxHash64;
+use twox_hash::{XxHash3_64, XxHash64};
 
 pub fn hash_wad(s: &str) -> u64 {
     let mut h = XxHash64::with_seed(0);
@@ -16,6 +16,17 @@ pub fn hash_shader(s: &str) -> u64 {
     h.finish()
 }
 
+/// stringtable（RST）键哈希：小写键的 xxh64 截取低 `bits` 位。
+/// 较新的客户端改用 xxh3，见 [`hash_stringtable_xxh3`]；查表时两者都要尝试。
+pub fn hash_stringtable(s: &str, bits: u32) -> u64 {
+    hash_wad(s) & ((1 << bits) - 1)
+}
+
+/// 同 [`hash_stringtable`]，但使用 xxh3-64
+pub fn hash_stringtable_xxh3(s: &str, bits: u32) -> u64 {
+    XxHash3_64::oneshot(s.to_ascii_lowercase().as_bytes()) & ((1 << bits) - 1)
+}
+
 pub fn hash_bin(s: &str) -> u32 {
     s.to_ascii_lowercase().bytes().fold(0x811c9dc5_u32, |h, b| {
         (h ^ b as u32).wrapping_mul(0x01000193)
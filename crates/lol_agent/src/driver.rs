@@ -9,18 +9,28 @@
 //!   - **沙盒**：QuickJS 默认不暴露任何文件/网络 I/O，只注入我们显式绑定的宿主函数；
 //!   - **时间片熔断**：通过 QuickJS 中断回调限制单 tick 的 CPU 时长，死循环会被强制中断而非挂起引擎；
 //!   - **热重载**：脚本源码可随时替换，`globalThis.state` 跨 tick 持久，重载不丢状态。
+//!
+//! 宿主 API（声明见 [`script_type_declarations`](crate::script_types::script_type_declarations)，经 RPC `script_types` 下发）：
+//!   - 观测/动作：`observe()` / `action()` / `wait_ticks()`；
+//!   - 几何查询：`find_path()` / `has_line_of_sight()`，基于 `lol_core::navigation` 的静态地形；
+//!   - 技能数值：`get_skill_value()` / `get_skill_range()`，按当前等级与属性求值；
+//!   - 持久存储：`store.get/set/delete/keys`，Agent 作用域，不随热重载或脚本异常丢失；
+//!   - 日志：`console.*` / `log()`，以 [`ScriptLog`] 结构经 `take_logs` 取出。
 
 use std::cell::{Cell, RefCell};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 use std::time::{Duration, Instant};
 
 use bevy::prelude::{Component, Entity, Vec2};
 use lol_core::action::Action;
+use lol_core::skill::SkillTarget;
 use rquickjs::{Context, Ctx, Function, Runtime};
 use serde::Deserialize;
+use serde_json::Value;
 
 use crate::models::Observe;
+use crate::script_host::{ScriptHost, ScriptLog, ScriptLogLevel};
 
 /// 单 tick 默认 CPU 预算（超出即被中断回调强制熔断）。
 pub const DEFAULT_TICK_BUDGET: Duration = Duration::from_millis(5);
@@ -34,7 +44,11 @@ pub struct ScriptAgent {
 
 /// 对局内所有 Script 驱动的持有者。`ScriptDriver` 非 `Send`，故以 `NonSend` 资源存放。
 #[derive(Default)]
-pub struct ScriptRuntimes(pub HashMap<Entity, ScriptDriver>);
+pub struct ScriptRuntimes {
+    pub drivers: HashMap<Entity, ScriptDriver>,
+    /// 驱动被回收（实体移除了 `ScriptAgent`）时暂存的持久存储，重建驱动时恢复。
+    pub stores: HashMap<Entity, BTreeMap<String, Value>>,
+}
 
 /// Agent 决策驱动类型。
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -73,8 +87,8 @@ pub trait AgentDriver {
     fn actions(&mut self) -> Vec<Action>;
     /// 运行时热重载策略（仅 Script 有意义；其余默认空实现）。
     fn reload(&mut self, _source: &str) {}
-    /// 取出脚本/驱动产生的日志（供调试面板展示）。
-    fn take_logs(&mut self) -> Vec<String> {
+    /// 取出脚本/驱动产生的结构化日志（供调试面板展示）。
+    fn take_logs(&mut self) -> Vec<ScriptLog> {
         Vec::new()
     }
     /// 上一次执行的错误（编译/运行/熔断），无则 None。
@@ -135,30 +149,53 @@ impl AgentDriver for RlDriver {
 
 // ════════════════════════ Script 驱动（rquickjs 沙盒） ════════════════════════
 
-/// 宿主与 JS 之间共享的缓冲：观测输入、动作/日志输出、wait 计数。
+/// 宿主与 JS 之间共享的缓冲：观测输入、宿主上下文、动作/日志输出、wait 计数与持久存储。
 #[derive(Default)]
 struct Shared {
     observe_json: String,
+    time: f32,
+    host: ScriptHost,
     actions: Vec<String>,
-    logs: Vec<String>,
+    logs: Vec<ScriptLog>,
     wait: i32,
+    store: BTreeMap<String, Value>,
 }
 
 /// 注入到沙盒的 JS 前导：把底层原生绑定（`__*`）包装成对脚本友好的
-/// `observe()` / `action()` / `log()` / `wait_ticks()`，并初始化跨 tick 持久的 `state`。
+/// `observe()` / `action()` / `log()` / `wait_ticks()`、几何/技能查询、`store` 与 `console`，
+/// 并初始化跨 tick 持久的 `state`。复杂值统一以 JSON 字符串跨越宿主边界。
 const PRELUDE: &str = r#"
 globalThis.state = globalThis.state || {};
 function observe() { return JSON.parse(__observe()); }
 function action(a) { __push_action(JSON.stringify(a)); }
-function log() {
+function __format(args) {
   var parts = [];
-  for (var i = 0; i < arguments.length; i++) {
-    var x = arguments[i];
+  for (var i = 0; i < args.length; i++) {
+    var x = args[i];
     parts.push(typeof x === 'object' && x !== null ? JSON.stringify(x) : String(x));
   }
-  __log(parts.join(' '));
+  return parts.join(' ');
 }
+function log() { __log('log', __format(arguments)); }
+globalThis.console = {};
+['debug', 'log', 'info', 'warn', 'error'].forEach(function (level) {
+  console[level] = function () { __log(level, __format(arguments)); };
+});
 function wait_ticks(n) { __wait(n | 0); }
+function find_path(from, to) {
+  return JSON.parse(__find_path(+from[0], +from[1], +to[0], +to[1]));
+}
+function has_line_of_sight(from, to) {
+  return JSON.parse(__has_line_of_sight(+from[0], +from[1], +to[0], +to[1]));
+}
+function get_skill_value(index, name) { return JSON.parse(__skill_value(index | 0, String(name))); }
+function get_skill_range(index) { return JSON.parse(__skill_range(index | 0)); }
+globalThis.store = {
+  get: function (key) { return JSON.parse(__store_get(String(key))); },
+  set: function (key, value) { __store_set(String(key), JSON.stringify(value === undefined ? null : value)); },
+  delete: function (key) { __store_delete(String(key)); },
+  keys: function () { return JSON.parse(__store_keys()); },
+};
 "#;
 
 /// 脚本动作的反序列化镜像（与 `lol_core::action::Action` 的 serde 外部标签一致），
@@ -166,16 +203,63 @@ function wait_ticks(n) { __wait(n | 0); }
 #[derive(Deserialize)]
 enum ScriptAction {
     Stop,
+    Recall,
     Attack(u64),
     Move([f32; 2]),
-    Skill { index: usize, point: [f32; 2] },
+    Skill {
+        index: usize,
+        point: [f32; 2],
+    },
+    SkillUnit {
+        index: usize,
+        target: u64,
+    },
+    SkillSelf {
+        index: usize,
+    },
+    SkillVector {
+        index: usize,
+        start: [f32; 2],
+        end: [f32; 2],
+    },
     SkillLevelUp(usize),
+    SummonerSpell {
+        slot: usize,
+        target: ScriptTarget,
+    },
+}
+
+/// 施法目标的反序列化镜像（与 `lol_core::skill::SkillTarget` 同形）。
+#[derive(Deserialize)]
+enum ScriptTarget {
+    Point([f32; 2]),
+    Unit(u64),
+    SelfCast,
+    Vector { start: [f32; 2], end: [f32; 2] },
+}
+
+impl ScriptTarget {
+    fn into_target(self) -> SkillTarget {
+        match self {
+            ScriptTarget::Point([x, z]) => SkillTarget::Point(Vec2::new(x, z)),
+            ScriptTarget::Unit(bits) => SkillTarget::Unit(Entity::from_bits(bits)),
+            ScriptTarget::SelfCast => SkillTarget::SelfCast,
+            ScriptTarget::Vector {
+                start: [sx, sz],
+                end: [ex, ez],
+            } => SkillTarget::Vector {
+                start: Vec2::new(sx, sz),
+                end: Vec2::new(ex, ez),
+            },
+        }
+    }
 }
 
 impl ScriptAction {
     fn into_action(self) -> Action {
         match self {
             ScriptAction::Stop => Action::Stop,
+            ScriptAction::Recall => Action::Recall,
             ScriptAction::Attack(bits) => Action::Attack(Entity::from_bits(bits)),
             ScriptAction::Move([x, z]) => Action::Move(Vec2::new(x, z)),
             ScriptAction::Skill {
@@ -185,7 +269,25 @@ impl ScriptAction {
                 index,
                 point: Vec2::new(x, z),
             },
+            ScriptAction::SkillUnit { index, target } => Action::SkillUnit {
+                index,
+                target: Entity::from_bits(target),
+            },
+            ScriptAction::SkillSelf { index } => Action::SkillSelf { index },
+            ScriptAction::SkillVector {
+                index,
+                start: [sx, sz],
+                end: [ex, ez],
+            } => Action::SkillVector {
+                index,
+                start: Vec2::new(sx, sz),
+                end: Vec2::new(ex, ez),
+            },
             ScriptAction::SkillLevelUp(index) => Action::SkillLevelUp(index),
+            ScriptAction::SummonerSpell { slot, target } => Action::SummonerSpell {
+                slot,
+                target: target.into_target(),
+            },
         }
     }
 }
@@ -246,8 +348,14 @@ impl ScriptDriver {
                 let s = shared.clone();
                 g.set(
                     "__log",
-                    Function::new(ctx.clone(), move |m: String| {
-                        s.borrow_mut().logs.push(m);
+                    Function::new(ctx.clone(), move |level: String, message: String| {
+                        let mut sh = s.borrow_mut();
+                        let time = sh.time;
+                        sh.logs.push(ScriptLog {
+                            level: ScriptLogLevel::from_name(&level),
+                            message,
+                            time,
+                        });
                     })?,
                 )?;
 
@@ -259,6 +367,92 @@ impl ScriptDriver {
                     })?,
                 )?;
 
+                let s = shared.clone();
+                g.set(
+                    "__find_path",
+                    Function::new(
+                        ctx.clone(),
+                        move |fx: f64, fz: f64, tx: f64, tz: f64| -> String {
+                            let path = s.borrow().host.find_path(
+                                Vec2::new(fx as f32, fz as f32),
+                                Vec2::new(tx as f32, tz as f32),
+                            );
+                            let path =
+                                path.map(|p| p.iter().map(|v| [v.x, v.y]).collect::<Vec<_>>());
+                            to_json(&path)
+                        },
+                    )?,
+                )?;
+
+                let s = shared.clone();
+                g.set(
+                    "__has_line_of_sight",
+                    Function::new(
+                        ctx.clone(),
+                        move |fx: f64, fz: f64, tx: f64, tz: f64| -> String {
+                            to_json(&s.borrow().host.has_line_of_sight(
+                                Vec2::new(fx as f32, fz as f32),
+                                Vec2::new(tx as f32, tz as f32),
+                            ))
+                        },
+                    )?,
+                )?;
+
+                let s = shared.clone();
+                g.set(
+                    "__skill_value",
+                    Function::new(ctx.clone(), move |index: i32, name: String| -> String {
+                        let value = usize::try_from(index)
+                            .ok()
+                            .and_then(|i| s.borrow().host.skill_value(i, &name));
+                        to_json(&value)
+                    })?,
+                )?;
+
+                let s = shared.clone();
+                g.set(
+                    "__skill_range",
+                    Function::new(ctx.clone(), move |index: i32| -> String {
+                        let value = usize::try_from(index)
+                            .ok()
+                            .and_then(|i| s.borrow().host.skill_range(i));
+                        to_json(&value)
+                    })?,
+                )?;
+
+                let s = shared.clone();
+                g.set(
+                    "__store_get",
+                    Function::new(ctx.clone(), move |key: String| -> String {
+                        to_json(&s.borrow().store.get(&key))
+                    })?,
+                )?;
+
+                let s = shared.clone();
+                g.set(
+                    "__store_set",
+                    Function::new(ctx.clone(), move |key: String, value: String| {
+                        let value = serde_json::from_str(&value).unwrap_or(Value::Null);
+                        s.borrow_mut().store.insert(key, value);
+                    })?,
+                )?;
+
+                let s = shared.clone();
+                g.set(
+                    "__store_delete",
+                    Function::new(ctx.clone(), move |key: String| {
+                        s.borrow_mut().store.remove(&key);
+                    })?,
+                )?;
+
+                let s = shared.clone();
+                g.set(
+                    "__store_keys",
+                    Function::new(ctx.clone(), move || -> String {
+                        to_json(&s.borrow().store.keys().collect::<Vec<_>>())
+                    })?,
+                )?;
+
                 ctx.eval::<(), _>(PRELUDE.as_bytes().to_vec())?;
                 Ok(())
             })
@@ -282,8 +476,23 @@ impl ScriptDriver {
         self.last_reload
     }
 
+    /// 注入下一次执行时宿主函数可查询的世界上下文。
+    pub fn set_host(&mut self, host: ScriptHost) {
+        self.shared.borrow_mut().host = host;
+    }
+
+    /// 导出 Agent 作用域的持久存储（用于跨驱动重建保留）。
+    pub fn store(&self) -> BTreeMap<String, Value> {
+        self.shared.borrow().store.clone()
+    }
+
+    /// 用外部快照替换持久存储。
+    pub fn restore_store(&mut self, store: BTreeMap<String, Value>) {
+        self.shared.borrow_mut().store = store;
+    }
+
     /// 以给定观测 JSON 跑一次脚本（设置观测 -> 执行 -> 收集动作/日志/wait）。
-    fn run(&mut self, observe_json: &str) {
+    fn run(&mut self, observe_json: &str, time: f32) {
         self.last_error = None;
 
         // 协作式让出：wait_ticks 期间跳过执行。
@@ -295,6 +504,7 @@ impl ScriptDriver {
         {
             let mut sh = self.shared.borrow_mut();
             sh.observe_json = observe_json.to_string();
+            sh.time = time;
             sh.actions.clear();
             sh.wait = 0;
         }
@@ -336,7 +546,7 @@ impl AgentDriver for ScriptDriver {
 
     fn observe(&mut self, observe: &Observe) {
         match serde_json::to_string(observe) {
-            Ok(json) => self.run(&json),
+            Ok(json) => self.run(&json, observe.time),
             Err(e) => self.last_error = Some(format!("观测序列化失败: {e}")),
         }
     }
@@ -351,7 +561,7 @@ impl AgentDriver for ScriptDriver {
         self.last_reload = Some(Instant::now());
     }
 
-    fn take_logs(&mut self) -> Vec<String> {
+    fn take_logs(&mut self) -> Vec<ScriptLog> {
         let mut sh = self.shared.borrow_mut();
         std::mem::take(&mut sh.logs)
     }
@@ -361,6 +571,11 @@ impl AgentDriver for ScriptDriver {
     }
 }
 
+/// 宿主函数返回值统一序列化为 JSON 字符串，由 `PRELUDE` 在 JS 侧解析。
+fn to_json<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "null".to_string())
+}
+
 /// 把 rquickjs 的执行错误转成可读字符串（尽量取异常 message）。
 fn describe_error(ctx: &Ctx, err: rquickjs::Error) -> String {
     if err.is_exception() {
@@ -387,6 +602,48 @@ mod tests {
         d
     }
 
+    fn messages(logs: Vec<ScriptLog>) -> Vec<String> {
+        logs.into_iter().map(|l| l.message).collect()
+    }
+
+    /// 10x10 格、格宽 10 的全可走地图，`x == 5` 一列（除最上一行外）为墙。
+    fn walled_host() -> ScriptHost {
+        use lol_base::grid::{
+            ConfigNavigationGrid, ConfigNavigationGridCell, GridFlagsVisionPathing,
+        };
+
+        let size = 10;
+        let open = ConfigNavigationGridCell {
+            heuristic: 1.0,
+            vision_pathing_flags: GridFlagsVisionPathing::Walkable,
+            ..Default::default()
+        };
+        let wall = ConfigNavigationGridCell {
+            vision_pathing_flags: GridFlagsVisionPathing::Wall,
+            ..open.clone()
+        };
+        let mut cells = vec![vec![open; size]; size];
+        for row in cells.iter_mut().take(size - 1) {
+            row[5] = wall.clone();
+        }
+        ScriptHost {
+            grid: Some(std::sync::Arc::new(ConfigNavigationGrid {
+                min_position: Vec2::ZERO,
+                cell_size: 10.0,
+                x_len: size,
+                y_len: size,
+                cells,
+                height_x_len: size,
+                height_y_len: size,
+                height_samples: vec![vec![0.0; size]; size],
+                occupied_cells: Default::default(),
+                exclude_cells: Default::default(),
+                terrain_cells: Default::default(),
+            })),
+            ..Default::default()
+        }
+    }
+
     fn empty_observe() -> Observe {
         use crate::models::ObserveMyself;
         Observe {
@@ -405,11 +662,13 @@ mod tests {
                 armor: 30.0,
                 skill_points: 1,
                 skills: Vec::new(),
+                summoner_spells: Vec::new(),
                 gold: 0.0,
                 kills: 0,
                 deaths: 0,
                 assists: 0,
                 minion_kills: 0,
+                healing_done: 0.0,
             },
             minions: Vec::new(),
             friendly_heroes: Vec::new(),
@@ -449,6 +708,15 @@ mod tests {
         assert!(d.last_error().is_none());
     }
 
+    #[test]
+    fn script_emits_recall_action() {
+        let mut d = driver("action('Recall');");
+        d.observe(&empty_observe());
+        let actions = d.actions();
+        assert_eq!(actions.len(), 1);
+        assert!(matches!(actions[0], Action::Recall));
+    }
+
     #[test]
     fn script_can_read_observe_and_move() {
         // 读取自身坐标并据此 Move，验证 observe() 宿主绑定贯通。
@@ -480,12 +748,58 @@ mod tests {
         assert!(matches!(actions[2], Action::SkillLevelUp(1)));
     }
 
+    #[test]
+    fn script_parses_targeted_skill_shapes() {
+        let mut d = driver(
+            "action({ SkillUnit: { index: 0, target: 42 } }); action({ SkillSelf: { index: 1 } }); action({ SkillVector: { index: 2, start: [1, 2], end: [3, 4] } });",
+        );
+        d.observe(&empty_observe());
+        let actions = d.actions();
+        assert_eq!(actions.len(), 3);
+        assert!(matches!(actions[0], Action::SkillUnit { index: 0, .. }));
+        assert!(matches!(actions[1], Action::SkillSelf { index: 1 }));
+        match actions[2] {
+            Action::SkillVector { index, start, end } => {
+                assert_eq!(index, 2);
+                assert!((start.x - 1.0).abs() < 1e-3 && (end.y - 4.0).abs() < 1e-3);
+            }
+            _ => panic!("expected SkillVector, got {:?}", actions[2]),
+        }
+    }
+
+    #[test]
+    fn script_parses_summoner_spell() {
+        let mut d = driver(
+            "action({ SummonerSpell: { slot: 0, target: { Point: [7, 8] } } }); action({ SummonerSpell: { slot: 1, target: \"SelfCast\" } });",
+        );
+        d.observe(&empty_observe());
+        let actions = d.actions();
+        assert_eq!(actions.len(), 2);
+        assert!(matches!(
+            actions[0],
+            Action::SummonerSpell {
+                slot: 0,
+                target: SkillTarget::Point(_)
+            }
+        ));
+        assert!(matches!(
+            actions[1],
+            Action::SummonerSpell {
+                slot: 1,
+                target: SkillTarget::SelfCast
+            }
+        ));
+    }
+
     #[test]
     fn log_is_captured() {
         let mut d = driver("log('hello', 42);");
         d.observe(&empty_observe());
         let logs = d.take_logs();
-        assert_eq!(logs, vec!["hello 42".to_string()]);
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].level, ScriptLogLevel::Log);
+        assert_eq!(logs[0].message, "hello 42");
+        assert_eq!(logs[0].time, 1.0);
     }
 
     #[test]
@@ -495,7 +809,7 @@ mod tests {
         for _ in 0..4 {
             d.observe(&empty_observe());
         }
-        let logs = d.take_logs();
+        let logs = messages(d.take_logs());
         // tick1 执行(n=1) -> tick2 跳过 -> tick3 跳过 -> tick4 执行(n=2)
         assert_eq!(logs, vec!["1".to_string(), "2".to_string()]);
     }
@@ -520,7 +834,7 @@ mod tests {
         // 热重载到新脚本，state 应保留。
         d.reload("log('reloaded ' + state.n);");
         d.observe(&empty_observe());
-        let logs = d.take_logs();
+        let logs = messages(d.take_logs());
         assert_eq!(
             logs,
             vec!["1".to_string(), "2".to_string(), "reloaded 2".to_string()]
@@ -528,6 +842,71 @@ mod tests {
         assert!(d.last_reload().is_some());
     }
 
+    #[test]
+    fn console_levels_are_structured() {
+        let mut d = driver("console.warn('low hp', { hp: 10 }); console.error('oops');");
+        d.observe(&empty_observe());
+        let logs = d.take_logs();
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].level, ScriptLogLevel::Warn);
+        assert_eq!(logs[0].message, "low hp {\"hp\":10}");
+        assert_eq!(logs[1].level, ScriptLogLevel::Error);
+    }
+
+    #[test]
+    fn store_survives_reload_and_errors() {
+        let mut d = driver("store.set('plan', { lane: 'mid', n: 3 }); throw new Error('boom');");
+        d.observe(&empty_observe());
+        assert!(d.last_error().is_some());
+
+        d.reload("var p = store.get('plan'); log(p.lane, p.n, store.get('none'), store.keys());");
+        d.observe(&empty_observe());
+        assert_eq!(
+            messages(d.take_logs()),
+            vec!["mid 3 null [\"plan\"]".to_string()]
+        );
+
+        d.reload("store.delete('plan'); log(store.keys().length);");
+        d.observe(&empty_observe());
+        assert_eq!(messages(d.take_logs()), vec!["0".to_string()]);
+        assert!(d.store().is_empty());
+    }
+
+    #[test]
+    fn store_survives_driver_recreation() {
+        let mut d = driver("store.set('seen', (store.get('seen') || 0) + 1);");
+        d.observe(&empty_observe());
+        d.observe(&empty_observe());
+
+        let mut rebuilt = driver("log(store.get('seen'));");
+        rebuilt.restore_store(d.store());
+        rebuilt.observe(&empty_observe());
+        assert_eq!(messages(rebuilt.take_logs()), vec!["2".to_string()]);
+    }
+
+    #[test]
+    fn host_queries_without_map_return_null() {
+        let mut d = driver(
+            "log(find_path([0, 0], [10, 10]), has_line_of_sight([0, 0], [1, 1]), get_skill_value(0, 'TotalDamage'), get_skill_range(0));",
+        );
+        d.observe(&empty_observe());
+        assert_eq!(
+            messages(d.take_logs()),
+            vec!["null null null null".to_string()]
+        );
+    }
+
+    #[test]
+    fn host_pathfinding_routes_around_walls() {
+        let mut d = driver(
+            "var p = find_path([15, 15], [85, 15]); log(has_line_of_sight([15, 15], [85, 15]), has_line_of_sight([15, 15], [35, 15]), p.length > 2);",
+        );
+        d.set_host(walled_host());
+        d.observe(&empty_observe());
+        assert!(d.last_error().is_none(), "{:?}", d.last_error());
+        assert_eq!(messages(d.take_logs()), vec!["false true true".to_string()]);
+    }
+
     #[test]
     fn syntax_error_surfaces_without_panic() {
         let mut d = driver("this is not valid js );");
@@ -1,15 +1,17 @@
 pub mod driver;
 pub mod models;
-pub mod params;
 pub mod rl;
+pub mod script_host;
+pub mod script_types;
 pub mod systems;
 
 use bevy::prelude::*;
 pub use driver::*;
-use lol_rpc::RpcAppExt;
+use lol_rpc::{RpcAppExt, cmd};
 pub use models::*;
-pub use params::*;
 pub use rl::*;
+pub use script_host::*;
+pub use script_types::*;
 pub use systems::*;
 
 pub struct PluginAgentObserver;
@@ -17,22 +19,46 @@ pub struct PluginAgentObserver;
 impl Plugin for PluginAgentObserver {
     fn build(&self, app: &mut App) {
         app.init_non_send::<driver::ScriptRuntimes>();
+        app.init_resource::<ScriptLogBuffer>();
         app.init_resource::<rl::RlEnvs>();
+        app.init_resource::<ObserveSubscriptions>();
+        app.init_resource::<WorldSubscriptions>();
 
         // 注册本模块提供的 RPC 命令
-        app.register_rpc::<ObserveParams>("observe");
-        app.register_rpc::<ActionParams>("action");
-        app.register_rpc::<SetScriptParams>("set_script");
-        app.register_rpc::<RlResetParams>("rl_reset");
-        app.register_rpc::<RlStepParams>("rl_step");
-        app.register_rpc::<GetAgentsParams>("get_agents");
+        app.register_rpc::<cmd::Observe>();
+        app.register_rpc::<cmd::Action>();
+        app.register_rpc::<cmd::SetScript>();
+        app.register_rpc::<cmd::ScriptTypes>();
+        app.register_rpc::<cmd::ScriptLogs>();
+        app.register_rpc::<cmd::RlReset>();
+        app.register_rpc::<cmd::RlStep>();
+        app.register_rpc::<cmd::GetAgents>();
+        app.register_rpc::<cmd::SubscribeObserve>();
+        app.register_rpc::<cmd::UnsubscribeObserve>();
+        app.register_rpc::<cmd::SubscribeWorld>();
+        app.register_rpc::<cmd::UnsubscribeWorld>();
 
         app.add_observer(on_observe)
             .add_observer(on_action)
             .add_observer(on_set_script)
+            .add_observer(on_script_types)
+            .add_observer(on_script_logs)
             .add_observer(on_rl_reset)
             .add_observer(on_rl_step)
-            .add_observer(on_get_agents);
-        app.add_systems(FixedUpdate, drive_script_agents);
+            .add_observer(on_get_agents)
+            .add_observer(on_subscribe_observe)
+            .add_observer(on_unsubscribe_observe)
+            .add_observer(on_subscribe_world)
+            .add_observer(on_unsubscribe_world)
+            .add_observer(on_ws_connection_closed)
+            .add_observer(on_world_connection_closed);
+        app.add_systems(
+            FixedUpdate,
+            (
+                drive_script_agents,
+                push_observe_subscriptions,
+                push_world_snapshots,
+            ),
+        );
     }
 }
@@ -2,20 +2,23 @@ use bevy::prelude::*;
 use lol_champions::fiora::passive::Vital;
 use lol_core::attack::AttackState;
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
 
 #[derive(Component)]
 pub struct AttackTarget;
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, TS)]
 pub struct ObserveHero {
+    #[ts(type = "number")]
     pub entity: Entity,
+    #[ts(type = "[number, number]")]
     pub position: Vec2,
     pub health: f32,
     pub max_health: f32,
     pub distance: f32,
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, TS)]
 pub struct Observe {
     pub time: f32,
     pub myself: ObserveMyself,
@@ -24,27 +27,50 @@ pub struct Observe {
     pub enemy_heroes: Vec<ObserveHero>,
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, TS)]
 pub struct ObserveMinion {
+    #[ts(type = "number")]
     pub entity: Entity,
+    #[ts(type = "[number, number]")]
     pub position: Vec2,
     pub health: f32,
     pub distance: f32,
+    #[ts(
+        type = "{ direction: \"X\" | \"NegX\" | \"Z\" | \"NegZ\"; timeout_red_triggered: boolean } | null"
+    )]
     pub vital: Option<Vital>,
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, TS)]
 pub struct ObserveSkill {
+    #[ts(type = "number")]
     pub index: usize,
+    #[ts(type = "number")]
     pub level: usize,
     /// None=可用, Some(剩余秒数)=冷却中
     pub cooldown_remaining: Option<f32>,
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, TS)]
+pub struct ObserveSummonerSpell {
+    /// 槽位 (0: D, 1: F)
+    #[ts(type = "number")]
+    pub slot: usize,
+    /// 技能种类，如 "Flash" / "Ignite"
+    pub kind: String,
+    /// None=可用, Some(剩余秒数)=冷却中
+    pub cooldown_remaining: Option<f32>,
+}
+
+#[derive(Clone, Serialize, Deserialize, TS)]
 pub struct ObserveMyself {
+    #[ts(type = "[number, number]")]
     pub position: Vec2,
+    #[ts(
+        type = "{ status: { Windup: { target: number; end_time: number } } | { Cooldown: { end_time: number } }; target: number | null } | null"
+    )]
     pub attack_state: Option<AttackState>,
+    #[ts(type = "[number, number] | null")]
     pub run_target: Option<Vec2>,
     pub health: f32,
     pub max_health: f32,
@@ -56,9 +82,12 @@ pub struct ObserveMyself {
     pub armor: f32,
     pub skill_points: u32,
     pub skills: Vec<ObserveSkill>,
+    pub summoner_spells: Vec<ObserveSummonerSpell>,
     pub gold: f32,
     pub kills: u32,
     pub deaths: u32,
     pub assists: u32,
     pub minion_kills: u32,
+    /// 累计实际治疗量（不含溢出）
+    pub healing_done: f32,
 }
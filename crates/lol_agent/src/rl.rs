@@ -259,11 +259,13 @@ mod tests {
             armor: 30.0,
             skill_points: 1,
             skills: Vec::new(),
+            summoner_spells: Vec::new(),
             gold: 0.0,
             kills,
             deaths,
             assists,
             minion_kills,
+            healing_done: 0.0,
         }
     }
 
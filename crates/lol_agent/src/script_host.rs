@@ -0,0 +1,124 @@
+//! Script 驱动的宿主查询接口：寻路、视线、技能数值与结构化日志。
+//!
+//! [`ScriptDriver`](crate::driver::ScriptDriver) 本身不持有 ECS 世界，驱动系统每 tick
+//! 把查询所需的只读上下文打包成 [`ScriptHost`] 注入，脚本里的 `find_path()` /
+//! `has_line_of_sight()` / `get_skill_value()` 等宿主函数都在这份快照上求值。
+
+use std::sync::Arc;
+
+use bevy::prelude::Vec2;
+use lol_base::grid::ConfigNavigationGrid;
+use lol_base::spell::Spell;
+use lol_core::navigation::navigation::{find_path, has_line_of_sight};
+use lol_core::skill::{SkillCalcCaster, get_skill_data_value, get_skill_value_with};
+use serde::{Deserialize, Serialize};
+
+/// 单个技能槽在本 tick 的宿主上下文。
+#[derive(Clone, Default)]
+pub struct ScriptSkillContext {
+    /// 技能资产；尚未加载完成时为 None。
+    pub spell: Option<Arc<Spell>>,
+    /// 当前技能等级（0 = 未学习）。
+    pub level: usize,
+}
+
+/// 脚本宿主函数在本 tick 可查询的世界上下文（只读快照）。
+#[derive(Clone, Default)]
+pub struct ScriptHost {
+    /// 静态地形导航网格（不含单位占位），未加载地图时为 None。
+    pub grid: Option<Arc<ConfigNavigationGrid>>,
+    /// 按技能槽下标排列的技能上下文。
+    pub skills: Vec<ScriptSkillContext>,
+    /// 物理攻击力（spell 公式 `stat == 2`）。
+    pub attack_damage: f32,
+    /// 法术强度（spell 公式 `stat == 0`）。
+    pub ability_power: f32,
+    /// 施法者状态（英雄等级、生命比例、buff），供条件计算项求值。
+    pub caster: SkillCalcCaster,
+}
+
+impl ScriptHost {
+    /// 世界坐标寻路，返回含起终点的折线路点；不可达或无地图时为 None。
+    pub fn find_path(&self, from: Vec2, to: Vec2) -> Option<Vec<Vec2>> {
+        let grid = self.grid.as_deref()?;
+        if self.has_line_of_sight(from, to)? {
+            return Some(vec![from, to]);
+        }
+        find_path(grid, &from, &to).filter(|path| !path.is_empty())
+    }
+
+    /// 世界坐标两点之间是否无地形阻挡；无地图时为 None。
+    pub fn has_line_of_sight(&self, from: Vec2, to: Vec2) -> Option<bool> {
+        let grid = self.grid.as_deref()?;
+        // has_line_of_sight 工作在网格浮点坐标系上，需先换算。
+        let from = (from - grid.min_position) / grid.cell_size;
+        let to = (to - grid.min_position) / grid.cell_size;
+        Some(has_line_of_sight(grid, from, to))
+    }
+
+    /// 按当前技能等级与自身属性求值技能计算项（如 `TotalDamage`）。
+    ///
+    /// 计算项不存在时回退到同名 `dataValues`，技能未学习时按 1 级求值。
+    pub fn skill_value(&self, index: usize, name: &str) -> Option<f32> {
+        let skill = self.skills.get(index)?;
+        let spell = skill.spell.as_deref()?;
+        let level = skill.level.max(1);
+        get_skill_value_with(spell, name, level, &self.caster, |stat| self.stat(stat))
+            .or_else(|| get_skill_data_value(spell, name, level))
+    }
+
+    /// 技能施法距离（`castRange`，按当前等级）。
+    pub fn skill_range(&self, index: usize) -> Option<f32> {
+        let skill = self.skills.get(index)?;
+        let ranges = skill
+            .spell
+            .as_deref()?
+            .spell_data
+            .as_ref()?
+            .cast_range
+            .as_ref()?;
+        let lvl_idx = skill.level.max(1) - 1;
+        ranges.get(lvl_idx).or(ranges.last()).copied()
+    }
+
+    /// spell 公式的属性取值约定：`2` -> AD，`0` -> AP，其余暂按 0 处理。
+    pub fn stat(&self, stat: u8) -> f32 {
+        match stat {
+            0 => self.ability_power,
+            2 => self.attack_damage,
+            _ => 0.0,
+        }
+    }
+}
+
+/// 脚本日志级别（对应 `console.*` 的方法名）。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScriptLogLevel {
+    Debug,
+    Log,
+    Info,
+    Warn,
+    Error,
+}
+
+impl ScriptLogLevel {
+    pub fn from_name(s: &str) -> Self {
+        match s {
+            "debug" => ScriptLogLevel::Debug,
+            "info" => ScriptLogLevel::Info,
+            "warn" => ScriptLogLevel::Warn,
+            "error" => ScriptLogLevel::Error,
+            _ => ScriptLogLevel::Log,
+        }
+    }
+}
+
+/// 一条结构化脚本日志。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScriptLog {
+    pub level: ScriptLogLevel,
+    pub message: String,
+    /// 产生该日志时的对局时间（秒，取自观测 `time`）。
+    pub time: f32,
+}
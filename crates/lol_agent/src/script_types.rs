@@ -0,0 +1,109 @@
+//! Script 沙盒的 TypeScript 类型声明。
+//!
+//! 观测结构的声明由 `ts-rs` 从 [`Observe`](crate::models::Observe) 等 Rust 模型派生，
+//! 模型字段变动会自动反映到声明里；宿主函数部分与 `driver.rs` 的 `PRELUDE` 一一对应。
+
+use ts_rs::TS;
+
+use crate::models::{
+    Observe, ObserveHero, ObserveMinion, ObserveMyself, ObserveSkill, ObserveSummonerSpell,
+};
+
+/// 宿主函数与动作类型的声明（手写，须与 `PRELUDE` 保持一致）。
+const HOST_DECLARATIONS: &str = r#"
+type Vec2 = [number, number];
+
+type ScriptAction =
+  | "Stop"
+  | "Recall"
+  | { Attack: number }
+  | { Move: Vec2 }
+  | { Skill: { index: number; point: Vec2 } }
+  | { SkillUnit: { index: number; target: number } }
+  | { SkillSelf: { index: number } }
+  | { SkillVector: { index: number; start: Vec2; end: Vec2 } }
+  | { SkillLevelUp: number }
+  | { SummonerSpell: { slot: number; target: SkillTarget } };
+
+type SkillTarget =
+  | { Point: Vec2 }
+  | { Unit: number }
+  | "SelfCast"
+  | { Vector: { start: Vec2; end: Vec2 } };
+
+type ScriptLogLevel = "debug" | "log" | "info" | "warn" | "error";
+
+/** 跨 tick、跨热重载持久的脚本状态。 */
+declare var state: Record<string, any>;
+
+/** 读取本 tick 的局势观测。 */
+declare function observe(): Observe;
+/** 下发一个动作到行动队列。 */
+declare function action(a: ScriptAction): void;
+/** 跳过接下来的 n 个 tick。 */
+declare function wait_ticks(n: number): void;
+/** 等价于 console.log。 */
+declare function log(...args: unknown[]): void;
+
+/** 世界坐标寻路，返回含起终点的路点；不可达或无地图时为 null。 */
+declare function find_path(from: Vec2, to: Vec2): Vec2[] | null;
+/** 两点之间是否无地形阻挡；无地图时为 null。 */
+declare function has_line_of_sight(from: Vec2, to: Vec2): boolean | null;
+/** 按当前技能等级与属性求值技能计算项（如 "TotalDamage"）。 */
+declare function get_skill_value(index: number, name: string): number | null;
+/** 技能施法距离（castRange）。 */
+declare function get_skill_range(index: number): number | null;
+
+/** Agent 作用域的持久键值存储，值须可 JSON 序列化。 */
+declare const store: {
+  get<T = unknown>(key: string): T | null;
+  set(key: string, value: unknown): void;
+  delete(key: string): void;
+  keys(): string[];
+};
+
+declare const console: { [L in ScriptLogLevel]: (...args: unknown[]) => void };
+"#;
+
+/// 生成完整的脚本类型声明（`.d.ts` 内容）。
+pub fn script_type_declarations() -> String {
+    let mut out = String::from("// 由 lol_agent::script_types 生成，请勿手改。\n\n");
+    for decl in [
+        ObserveSkill::decl(),
+        ObserveSummonerSpell::decl(),
+        ObserveMinion::decl(),
+        ObserveHero::decl(),
+        ObserveMyself::decl(),
+        Observe::decl(),
+    ] {
+        out.push_str(&decl);
+        out.push('\n');
+    }
+    out.push_str(HOST_DECLARATIONS);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn declarations_cover_observe_model() {
+        let dts = script_type_declarations();
+        for name in [
+            "type Observe =",
+            "type ObserveMyself =",
+            "type ObserveHero =",
+            "type ObserveMinion =",
+            "type ObserveSkill =",
+            "type ObserveSummonerSpell =",
+            "declare function find_path",
+            "declare function get_skill_value",
+        ] {
+            assert!(dts.contains(name), "missing `{name}` in:\n{dts}");
+        }
+        // 实体与坐标按 serde 的 JSON 形态声明。
+        assert!(dts.contains("entity: number"));
+        assert!(dts.contains("position: [number, number]"));
+    }
+}
@@ -1,7 +1,11 @@
 pub mod obs;
 pub mod rpc;
 pub mod script;
+pub mod subscribe;
+pub mod world;
 
 pub use obs::*;
 pub use rpc::*;
 pub use script::*;
+pub use subscribe::*;
+pub use world::*;
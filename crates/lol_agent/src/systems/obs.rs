@@ -5,6 +5,7 @@ use lol_core::base::ability_resource::AbilityResource;
 use lol_core::base::gold::Gold;
 use lol_core::base::level::Level;
 use lol_core::base::stats::ChampionStats;
+use lol_core::buffs::cc_debuffs::Nearsighted;
 use lol_core::damage::{Armor, Damage};
 use lol_core::entities::champion::Champion;
 use lol_core::entities::minion::Minion;
@@ -12,9 +13,12 @@ use lol_core::lane::Lane;
 use lol_core::life::{Death, Health};
 use lol_core::run::{Run, RunTarget};
 use lol_core::skill::{CoolDown, Skill, SkillPoints, Skills};
+use lol_core::summoner::SummonerSpells;
 use lol_core::team::Team;
 
-use crate::models::{Observe, ObserveHero, ObserveMinion, ObserveMyself, ObserveSkill};
+use crate::models::{
+    Observe, ObserveHero, ObserveMinion, ObserveMyself, ObserveSkill, ObserveSummonerSpell,
+};
 
 pub type PlayerQ<'w, 's> = Query<
     'w,
@@ -41,10 +45,15 @@ pub type PlayerQ<'w, 's> = Query<
             Option<&'static Gold>,
             Option<&'static ChampionStats>,
             Option<&'static Skills>,
+            Option<&'static SummonerSpells>,
+            Option<&'static Nearsighted>,
         ),
     ),
 >;
 
+/// 观测半径：超出此距离的小兵与英雄不进入观测；视野缩减时敌方单位只在缩减半径内可见
+pub const OBSERVE_RADIUS: f32 = 2000.0;
+
 pub fn observe(
     player_entity: Entity,
     player_q: &PlayerQ,
@@ -60,7 +69,16 @@ pub fn observe(
     let Ok((
         (_player_entity, transform, attack_state, run, player_team, _controller),
         (health, opt_level, opt_ability, opt_damage),
-        (opt_armor, opt_attack, opt_skill_points, opt_gold, opt_stats, opt_skills),
+        (
+            opt_armor,
+            opt_attack,
+            opt_skill_points,
+            opt_gold,
+            opt_stats,
+            opt_skills,
+            opt_summoners,
+            opt_nearsighted,
+        ),
     )) = player_q.get(player_entity)
     else {
         return None;
@@ -78,20 +96,15 @@ pub fn observe(
     let armor = opt_armor.map(|a| a.0).unwrap_or(0.0);
 
     let (attack_range, attack_speed) = opt_attack
-        .map(|att| {
-            let total_as = att.base_attack_speed
-                * (1.0 + att.bonus_attack_speed + att.buff_bonus_attack_speed);
-            let capped_as = total_as.min(att.attack_speed_cap);
-            (att.range, capped_as)
-        })
+        .map(|att| (att.range, att.current_attack_speed()))
         .unwrap_or((0.0, 0.0));
 
     let skill_points = opt_skill_points.map(|sp| sp.0).unwrap_or(0);
     let gold_value = opt_gold.map(|g| g.current).unwrap_or(0.0);
 
-    let (kills, deaths, assists, minion_kills) = opt_stats
-        .map(|s| (s.kills, s.deaths, s.assists, s.minion_kills))
-        .unwrap_or((0, 0, 0, 0));
+    let (kills, deaths, assists, minion_kills, healing_done) = opt_stats
+        .map(|s| (s.kills, s.deaths, s.assists, s.minion_kills, s.healing_done))
+        .unwrap_or((0, 0, 0, 0, 0.0));
 
     let mut skills = Vec::new();
     if let Some(skills_comp) = opt_skills {
@@ -108,6 +121,21 @@ pub fn observe(
         }
     }
 
+    let summoner_spells = opt_summoners
+        .map(|summoners| {
+            summoners
+                .slots
+                .iter()
+                .enumerate()
+                .map(|(slot, spell)| ObserveSummonerSpell {
+                    slot,
+                    kind: format!("{:?}", spell.kind),
+                    cooldown_remaining: (!spell.is_ready()).then(|| spell.remaining_secs()),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
     let myself = ObserveMyself {
         position: player_pos.xz(),
         attack_state: attack_state.cloned(),
@@ -122,16 +150,25 @@ pub fn observe(
         armor,
         skill_points,
         skills,
+        summoner_spells,
         gold: gold_value,
         kills,
         deaths,
         assists,
         minion_kills,
+        healing_done,
     };
 
-    let minions = get_world_minions(minions_q, player_pos, &player_team);
-    let (friendly_heroes, enemy_heroes) =
-        get_world_heroes(champion_q, player_entity, player_pos, &player_team);
+    let sight_radius =
+        opt_nearsighted.map_or(OBSERVE_RADIUS, |near| near.radius.min(OBSERVE_RADIUS));
+    let minions = get_world_minions(minions_q, player_pos, &player_team, sight_radius);
+    let (friendly_heroes, enemy_heroes) = get_world_heroes(
+        champion_q,
+        player_entity,
+        player_pos,
+        &player_team,
+        sight_radius,
+    );
 
     Some(Observe {
         time,
@@ -160,6 +197,7 @@ pub fn get_world_minions(
     >,
     player_pos: Vec3,
     player_team: &Team,
+    sight_radius: f32,
 ) -> Vec<ObserveMinion> {
     let mut minions = Vec::new();
     for (minion_entity, minion_transform, health, vital, minion_team, _) in minions_q.iter() {
@@ -167,7 +205,7 @@ pub fn get_world_minions(
             continue;
         }
         let distance = player_pos.distance(minion_transform.translation);
-        if distance > 2000.0 {
+        if distance > sight_radius {
             continue;
         }
         minions.push((
@@ -190,6 +228,7 @@ pub fn get_world_heroes(
     player_entity: Entity,
     player_pos: Vec3,
     player_team: &Team,
+    sight_radius: f32,
 ) -> (Vec<ObserveHero>, Vec<ObserveHero>) {
     let mut friendly_heroes = Vec::new();
     let mut enemy_heroes = Vec::new();
@@ -198,7 +237,13 @@ pub fn get_world_heroes(
             continue;
         }
         let distance = player_pos.distance(hero_transform.translation);
-        if distance > 2000.0 {
+        // 视野缩减只收缩自身视野，友方英雄仍按常规半径可见
+        let radius = if hero_team == player_team {
+            OBSERVE_RADIUS
+        } else {
+            sight_radius
+        };
+        if distance > radius {
             continue;
         }
         let observe_hero = ObserveHero {
@@ -299,6 +344,23 @@ pub fn format_observation(obs: &Observe) -> String {
     skills_str.push_str(&format!(" (未分配技能点: {})\n", myself.skill_points));
     out.push_str(&skills_str);
 
+    // 召唤师技能
+    if !myself.summoner_spells.is_empty() {
+        let parts: Vec<String> = myself
+            .summoner_spells
+            .iter()
+            .map(|spell| {
+                let key = if spell.slot == 0 { "D" } else { "F" };
+                let status = match spell.cooldown_remaining {
+                    Some(secs) if secs > 0.0 => format!("冷却中: {:.1}s", secs),
+                    _ => "就绪".to_string(),
+                };
+                format!("{}: {} ({})", key, spell.kind, status)
+            })
+            .collect();
+        out.push_str(&format!("[召唤师技能] {}\n", parts.join(" | ")));
+    }
+
     // 敌方英雄
     out.push_str("[敌方英雄] ");
     if obs.enemy_heroes.is_empty() {
@@ -1,30 +1,109 @@
 use bevy::prelude::*;
-use lol_core::action::CommandAction;
-use lol_rpc::CommandWsRequest as TypedCommandWsRequest;
+use lol_core::action::{Action, CommandAction};
+use lol_core::entities::champion::AgentId;
+use lol_rpc::{CommandWsRequest as TypedCommandWsRequest, cmd};
 use serde_json::{Value, json};
 
-use crate::params::ActionParams;
 use crate::systems::obs::PlayerQ;
+use crate::systems::rpc::resolve_controlled;
 
 pub fn on_action(
-    event: On<TypedCommandWsRequest<ActionParams>>,
+    event: On<TypedCommandWsRequest<cmd::Action>>,
     mut commands: Commands,
     player_q: PlayerQ,
+    agent_id_q: Query<&AgentId>,
 ) {
     let params = &event.params;
     let result = (|| -> Result<Value, String> {
-        let target_entity = lol_rpc::resolve_target(
-            params.entity_id,
-            |e| player_q.get(e).is_ok(),
-            || player_q.iter().next().map(|((entity, ..), ..)| entity),
-        )?;
+        let target_entity =
+            resolve_controlled(&event.scope, params.entity_id, &player_q, &agent_id_q)?;
 
         commands.trigger(CommandAction {
             entity: target_entity,
-            action: params.action.clone(),
+            action: to_core_action(&params.action)?,
         });
 
         Ok(json!({ "status": "success" }))
     })();
     lol_rpc::respond(&event, result);
 }
+
+/// 契约中的动作与 [`Action`] 线上形态一致（见 `lol_client::action`），经 JSON 转换。
+fn to_core_action(action: &lol_client::Action) -> Result<Action, String> {
+    serde_json::to_value(action)
+        .and_then(serde_json::from_value)
+        .map_err(|e| format!("无效动作: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use lol_core::entities::champion::Champion;
+    use lol_core::life::Health;
+    use lol_core::team::Team;
+    use lol_rpc::{ControlScope, LOCAL_CONNECTION, RpcRegistry};
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct Driven(Vec<Entity>);
+
+    #[test]
+    fn agent_cannot_drive_foreign_entity() {
+        let mut world = World::new();
+        let mut registry = RpcRegistry::default();
+        registry.register::<cmd::Action>();
+        world.insert_resource(registry);
+        world.init_resource::<Driven>();
+        world.add_observer(on_action);
+        world.add_observer(|event: On<CommandAction>, mut driven: ResMut<Driven>| {
+            driven.0.push(event.entity);
+        });
+
+        let spawn_champion = |world: &mut World, agent_id: &str, team: Team| {
+            world
+                .spawn((
+                    Transform::default(),
+                    team,
+                    Champion,
+                    Health::new(100.0),
+                    AgentId(agent_id.to_string()),
+                ))
+                .id()
+        };
+        let own = spawn_champion(&mut world, "blue_1", Team::Order);
+        let foreign = spawn_champion(&mut world, "red_1", Team::Chaos);
+        let scope = ControlScope::Agents(vec!["blue_1".to_string()].into());
+
+        let drive = |world: &mut World, id: u64, entity_id: Option<Entity>| {
+            let params = json!({
+                "entity_id": entity_id.map(Entity::to_bits),
+                "action": "Stop",
+            });
+            lol_rpc::dispatch_scoped(world, LOCAL_CONNECTION, scope.clone(), id, "action", params)
+        };
+
+        assert!(!drive(&mut world, 1, Some(foreign)).ok);
+        assert!(world.resource::<Driven>().0.is_empty());
+
+        assert!(drive(&mut world, 2, Some(own)).ok);
+        // 缺省目标只会落在范围内的英雄上。
+        assert!(drive(&mut world, 3, None).ok);
+        assert_eq!(world.resource::<Driven>().0, vec![own, own]);
+    }
+
+    #[test]
+    fn client_action_converts_to_core_action() {
+        let action = to_core_action(&lol_client::Action::Move([10.0, 20.0])).unwrap();
+        match action {
+            Action::Move(pos) => assert_eq!(pos, Vec2::new(10.0, 20.0)),
+            _ => panic!("Expected Action::Move"),
+        }
+
+        let action = to_core_action(&lol_client::Action::Attack(42)).unwrap();
+        match action {
+            Action::Attack(target) => assert_eq!(target, Entity::from_bits(42)),
+            _ => panic!("Expected Action::Attack"),
+        }
+    }
+}
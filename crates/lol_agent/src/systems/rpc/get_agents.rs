@@ -1,12 +1,10 @@
 use bevy::prelude::*;
 use lol_core::entities::champion::AgentId;
-use lol_rpc::CommandWsRequest as TypedCommandWsRequest;
+use lol_rpc::{CommandWsRequest as TypedCommandWsRequest, cmd};
 use serde_json::{Value, json};
 
-use crate::params::GetAgentsParams;
-
 pub fn on_get_agents(
-    event: On<TypedCommandWsRequest<GetAgentsParams>>,
+    event: On<TypedCommandWsRequest<cmd::GetAgents>>,
     agent_id_q: Query<(Entity, &AgentId)>,
 ) {
     let result = (|| -> Result<Value, String> {
@@ -3,11 +3,50 @@ pub mod get_agents;
 pub mod observe;
 pub mod rl_reset;
 pub mod rl_step;
+pub mod script_logs;
+pub mod script_types;
 pub mod set_script;
+pub mod subscribe_observe;
+pub mod subscribe_world;
 
 pub use action::on_action;
 pub use get_agents::on_get_agents;
 pub use observe::on_observe;
 pub use rl_reset::on_rl_reset;
 pub use rl_step::on_rl_step;
+pub use script_logs::on_script_logs;
+pub use script_types::on_script_types;
 pub use set_script::on_set_script;
+pub use subscribe_observe::{on_subscribe_observe, on_unsubscribe_observe};
+pub use subscribe_world::{on_subscribe_world, on_unsubscribe_world};
+
+use bevy::prelude::*;
+use lol_core::entities::champion::AgentId;
+use lol_rpc::ControlScope;
+
+use crate::systems::obs::PlayerQ;
+
+/// 解析操控类与按英雄观测类命令的目标英雄并按连接的操控范围校验：显式 `entity_id` 须在范围内，
+/// 缺省时取范围内的第一个玩家英雄。观测视角带该英雄的视野信息，因此与操控同一范围。
+pub(crate) fn resolve_controlled(
+    scope: &ControlScope,
+    entity_id: Option<u64>,
+    player_q: &PlayerQ,
+    agent_id_q: &Query<&AgentId>,
+) -> Result<Entity, String> {
+    let allowed = |entity: Entity| scope.allows(agent_id_q.get(entity).ok().map(|a| a.0.as_str()));
+    let entity = lol_rpc::resolve_target(
+        entity_id,
+        |e| player_q.get(e).is_ok(),
+        || {
+            player_q
+                .iter()
+                .map(|((entity, ..), ..)| entity)
+                .find(|e| allowed(*e))
+        },
+    )?;
+    if !allowed(entity) {
+        return Err(format!("当前连接无权操控或观测实体 {}", entity.to_bits()));
+    }
+    Ok(entity)
+}
@@ -1,20 +1,21 @@
 use bevy::prelude::*;
 use lol_champions::fiora::passive::Vital;
-use lol_core::entities::champion::Champion;
+use lol_core::entities::champion::{AgentId, Champion};
 use lol_core::entities::minion::Minion;
 use lol_core::lane::Lane;
 use lol_core::life::{Death, Health};
 use lol_core::skill::{CoolDown, Skill};
 use lol_core::team::Team;
-use lol_rpc::CommandWsRequest as TypedCommandWsRequest;
+use lol_rpc::{CommandWsRequest as TypedCommandWsRequest, cmd};
 use serde_json::{Value, to_value};
 
-use crate::params::ObserveParams;
 use crate::systems::obs::{PlayerQ, format_observation, observe};
+use crate::systems::rpc::resolve_controlled;
 
 pub fn on_observe(
-    event: On<TypedCommandWsRequest<ObserveParams>>,
+    event: On<TypedCommandWsRequest<cmd::Observe>>,
     player_q: PlayerQ,
+    agent_id_q: Query<&AgentId>,
     skills_q: Query<(&Skill, Option<&CoolDown>)>,
     minions_q: Query<
         (Entity, &Transform, &Health, Option<&Vital>, &Team, &Lane),
@@ -26,11 +27,8 @@ pub fn on_observe(
 ) {
     let params = &event.params;
     let result = (|| -> Result<Value, String> {
-        let target_entity = lol_rpc::resolve_target(
-            params.entity_id,
-            |e| player_q.get(e).is_ok(),
-            || player_q.iter().next().map(|((entity, ..), ..)| entity),
-        )?;
+        let target_entity =
+            resolve_controlled(&event.scope, params.entity_id, &player_q, &agent_id_q)?;
 
         let obs = observe(
             target_entity,
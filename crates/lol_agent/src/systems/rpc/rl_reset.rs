@@ -1,21 +1,22 @@
 use bevy::prelude::*;
 use lol_champions::fiora::passive::Vital;
-use lol_core::entities::champion::Champion;
+use lol_core::entities::champion::{AgentId, Champion};
 use lol_core::entities::minion::Minion;
 use lol_core::lane::Lane;
 use lol_core::life::{Death, Health};
 use lol_core::skill::{CoolDown, Skill};
 use lol_core::team::Team;
-use lol_rpc::CommandWsRequest as TypedCommandWsRequest;
+use lol_rpc::{CommandWsRequest as TypedCommandWsRequest, cmd};
 use serde_json::{Value, json};
 
-use crate::params::RlResetParams;
 use crate::rl::{MoonLoLEnv, RewardShaper, RlEnvs};
 use crate::systems::obs::{PlayerQ, observe};
+use crate::systems::rpc::resolve_controlled;
 
 pub fn on_rl_reset(
-    event: On<TypedCommandWsRequest<RlResetParams>>,
+    event: On<TypedCommandWsRequest<cmd::RlReset>>,
     player_q: PlayerQ,
+    agent_id_q: Query<&AgentId>,
     skills_q: Query<(&Skill, Option<&CoolDown>)>,
     minions_q: Query<
         (Entity, &Transform, &Health, Option<&Vital>, &Team, &Lane),
@@ -28,11 +29,8 @@ pub fn on_rl_reset(
 ) {
     let params = &event.params;
     let result = (|| -> Result<Value, String> {
-        let target_entity = lol_rpc::resolve_target(
-            params.entity_id,
-            |e| player_q.get(e).is_ok(),
-            || player_q.iter().next().map(|((entity, ..), ..)| entity),
-        )?;
+        let target_entity =
+            resolve_controlled(&event.scope, params.entity_id, &player_q, &agent_id_q)?;
 
         let obs = observe(
             target_entity,
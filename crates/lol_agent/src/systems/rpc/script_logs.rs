@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+use lol_core::entities::champion::AgentId;
+use lol_rpc::{CommandWsRequest as TypedCommandWsRequest, cmd};
+use serde_json::{Value, json};
+
+use crate::systems::obs::PlayerQ;
+use crate::systems::rpc::resolve_controlled;
+use crate::systems::script::ScriptLogBuffer;
+
+pub fn on_script_logs(
+    event: On<TypedCommandWsRequest<cmd::ScriptLogs>>,
+    player_q: PlayerQ,
+    agent_id_q: Query<&AgentId>,
+    mut log_buffer: ResMut<ScriptLogBuffer>,
+) {
+    let params = &event.params;
+    let result = (|| -> Result<Value, String> {
+        let ent = resolve_controlled(&event.scope, Some(params.entity_id), &player_q, &agent_id_q)?;
+        Ok(json!({ "logs": log_buffer.take(ent) }))
+    })();
+    lol_rpc::respond(&event, result);
+}
+
+#[cfg(test)]
+mod tests {
+    use lol_core::entities::champion::Champion;
+    use lol_core::life::Health;
+    use lol_core::team::Team;
+    use lol_rpc::{ControlScope, LOCAL_CONNECTION, RpcRegistry};
+
+    use super::*;
+    use crate::script_host::{ScriptLog, ScriptLogLevel};
+    use crate::systems::script::SCRIPT_LOG_CAPACITY;
+
+    fn log(message: &str) -> ScriptLog {
+        ScriptLog {
+            level: ScriptLogLevel::Info,
+            message: message.to_string(),
+            time: 1.0,
+        }
+    }
+
+    #[test]
+    fn script_logs_are_drained_per_entity() {
+        let mut world = World::new();
+        let mut registry = RpcRegistry::default();
+        registry.register::<cmd::ScriptLogs>();
+        world.insert_resource(registry);
+        world.init_resource::<ScriptLogBuffer>();
+        world.add_observer(on_script_logs);
+
+        let champion = world
+            .spawn((
+                Transform::default(),
+                Team::Order,
+                Champion,
+                Health::new(100.0),
+                AgentId("blue_1".to_string()),
+            ))
+            .id();
+        let mut buffer = world.resource_mut::<ScriptLogBuffer>();
+        for i in 0..=SCRIPT_LOG_CAPACITY {
+            buffer.push(champion, log(&i.to_string()));
+        }
+
+        let read = |world: &mut World, id: u64| {
+            let params = json!({ "entity_id": champion.to_bits() });
+            let scope = ControlScope::Agents(vec!["blue_1".to_string()].into());
+            lol_rpc::dispatch_scoped(world, LOCAL_CONNECTION, scope, id, "script_logs", params)
+        };
+
+        let response = read(&mut world, 1);
+        assert!(response.ok);
+        let logs = response.data.unwrap()["logs"].as_array().unwrap().clone();
+        // 超出容量时丢弃最旧的一条。
+        assert_eq!(logs.len(), SCRIPT_LOG_CAPACITY);
+        assert_eq!(
+            logs[0],
+            json!({ "level": "info", "message": "1", "time": 1.0 })
+        );
+
+        let response = read(&mut world, 2);
+        assert_eq!(response.data.unwrap()["logs"], json!([]));
+    }
+}
@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+use lol_rpc::{CommandWsRequest as TypedCommandWsRequest, cmd};
+use serde_json::Value;
+
+use crate::script_types::script_type_declarations;
+
+pub fn on_script_types(event: On<TypedCommandWsRequest<cmd::ScriptTypes>>) {
+    lol_rpc::respond(&event, Ok(Value::String(script_type_declarations())));
+}
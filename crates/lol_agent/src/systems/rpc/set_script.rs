@@ -1,22 +1,21 @@
 use bevy::prelude::*;
-use lol_rpc::CommandWsRequest as TypedCommandWsRequest;
+use lol_core::entities::champion::AgentId;
+use lol_rpc::{CommandWsRequest as TypedCommandWsRequest, cmd};
 use serde_json::{Value, json};
 
 use crate::driver::ScriptAgent;
-use crate::params::SetScriptParams;
 use crate::systems::obs::PlayerQ;
+use crate::systems::rpc::resolve_controlled;
 
 pub fn on_set_script(
-    event: On<TypedCommandWsRequest<SetScriptParams>>,
+    event: On<TypedCommandWsRequest<cmd::SetScript>>,
     mut commands: Commands,
     player_q: PlayerQ,
+    agent_id_q: Query<&AgentId>,
 ) {
     let params = &event.params;
     let result = (|| -> Result<Value, String> {
-        let ent = Entity::from_bits(params.entity_id);
-        if player_q.get(ent).is_err() {
-            return Err(format!("未找到指定的英雄实体 ID: {}", params.entity_id));
-        }
+        let ent = resolve_controlled(&event.scope, Some(params.entity_id), &player_q, &agent_id_q)?;
         commands.entity(ent).insert(ScriptAgent {
             source: params.source.clone(),
         });
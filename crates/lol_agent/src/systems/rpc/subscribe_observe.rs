@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use lol_core::entities::champion::AgentId;
+use lol_rpc::{CommandWsRequest as TypedCommandWsRequest, cmd};
+use serde_json::{Value, json};
+
+use crate::systems::obs::PlayerQ;
+use crate::systems::rpc::resolve_controlled;
+use crate::systems::subscribe::{DEFAULT_KEYFRAME_EVERY, ObserveSubscriptions};
+
+pub fn on_subscribe_observe(
+    event: On<TypedCommandWsRequest<cmd::SubscribeObserve>>,
+    player_q: PlayerQ,
+    agent_id_q: Query<&AgentId>,
+    mut subscriptions: ResMut<ObserveSubscriptions>,
+) {
+    let params = &event.params;
+    let result = (|| -> Result<Value, String> {
+        let target_entity =
+            resolve_controlled(&event.scope, params.entity_id, &player_q, &agent_id_q)?;
+        let every_ticks = params.every_ticks.unwrap_or(1);
+        if every_ticks == 0 {
+            return Err("every_ticks 必须大于 0".to_string());
+        }
+        let id = subscriptions.add(
+            event.conn_id,
+            target_entity,
+            every_ticks,
+            params.keyframe_every.unwrap_or(DEFAULT_KEYFRAME_EVERY),
+        );
+        Ok(json!({ "subscription_id": id, "entity_id": target_entity.to_bits() }))
+    })();
+    lol_rpc::respond(&event, result);
+}
+
+pub fn on_unsubscribe_observe(
+    event: On<TypedCommandWsRequest<cmd::UnsubscribeObserve>>,
+    mut subscriptions: ResMut<ObserveSubscriptions>,
+) {
+    let id = event.params.subscription_id;
+    let result = if subscriptions.remove(id, event.conn_id) {
+        Ok(json!({ "status": "success" }))
+    } else {
+        Err(format!("未找到本连接的订阅: {}", id))
+    };
+    lol_rpc::respond(&event, result);
+}
+
+#[cfg(test)]
+mod tests {
+    use lol_core::entities::champion::Champion;
+    use lol_core::life::Health;
+    use lol_core::team::Team;
+    use lol_rpc::{ControlScope, LOCAL_CONNECTION, RpcRegistry};
+
+    use super::*;
+
+    #[test]
+    fn agent_cannot_observe_foreign_entity() {
+        let mut world = World::new();
+        let mut registry = RpcRegistry::default();
+        registry.register::<cmd::SubscribeObserve>();
+        world.insert_resource(registry);
+        world.init_resource::<ObserveSubscriptions>();
+        world.add_observer(on_subscribe_observe);
+
+        let spawn_champion = |world: &mut World, agent_id: &str, team: Team| {
+            world
+                .spawn((
+                    Transform::default(),
+                    team,
+                    Champion,
+                    Health::new(100.0),
+                    AgentId(agent_id.to_string()),
+                ))
+                .id()
+        };
+        let own = spawn_champion(&mut world, "blue_1", Team::Order);
+        let foreign = spawn_champion(&mut world, "red_1", Team::Chaos);
+
+        let subscribe = |world: &mut World, scope: ControlScope, id: u64, entity: Entity| {
+            let params = json!({ "entity_id": entity.to_bits() });
+            lol_rpc::dispatch_scoped(
+                world,
+                LOCAL_CONNECTION,
+                scope,
+                id,
+                "subscribe_observe",
+                params,
+            )
+        };
+        let agent = ControlScope::Agents(vec!["blue_1".to_string()].into());
+
+        assert!(!subscribe(&mut world, agent.clone(), 1, foreign).ok);
+        // 未绑定英雄的观众令牌不能按英雄观测。
+        assert!(!subscribe(&mut world, ControlScope::Agents(Vec::new().into()), 2, own).ok);
+        assert!(subscribe(&mut world, agent, 3, own).ok);
+
+        let subscriptions = &world.resource::<ObserveSubscriptions>().subs;
+        assert_eq!(subscriptions.len(), 1);
+        assert_eq!(subscriptions[0].entity, own);
+    }
+}
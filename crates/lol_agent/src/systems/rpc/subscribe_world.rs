@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use lol_rpc::{CommandWsRequest as TypedCommandWsRequest, cmd};
+use serde_json::json;
+
+use crate::systems::world::{DEFAULT_WORLD_EVERY_TICKS, WorldSubscriptions};
+
+pub fn on_subscribe_world(
+    event: On<TypedCommandWsRequest<cmd::SubscribeWorld>>,
+    mut subscriptions: ResMut<WorldSubscriptions>,
+) {
+    let every_ticks = event
+        .params
+        .every_ticks
+        .unwrap_or(DEFAULT_WORLD_EVERY_TICKS);
+    let result = if every_ticks == 0 {
+        Err("every_ticks 必须大于 0".to_string())
+    } else {
+        let id = subscriptions.add(event.conn_id, every_ticks);
+        Ok(json!({ "subscription_id": id }))
+    };
+    lol_rpc::respond(&event, result);
+}
+
+pub fn on_unsubscribe_world(
+    event: On<TypedCommandWsRequest<cmd::UnsubscribeWorld>>,
+    mut subscriptions: ResMut<WorldSubscriptions>,
+) {
+    let id = event.params.subscription_id;
+    let result = if subscriptions.remove(id, event.conn_id) {
+        Ok(json!({ "status": "success" }))
+    } else {
+        Err(format!("未找到本连接的世界快照订阅: {}", id))
+    };
+    lol_rpc::respond(&event, result);
+}
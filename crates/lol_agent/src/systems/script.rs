@@ -1,41 +1,123 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
 use bevy::prelude::*;
+use lol_base::grid::ConfigNavigationGrid;
+use lol_base::spell::Spell;
 use lol_champions::fiora::passive::Vital;
 use lol_core::action::CommandAction;
+use lol_core::damage::{AbilityPower, Damage};
 use lol_core::entities::champion::Champion;
 use lol_core::entities::minion::Minion;
 use lol_core::lane::Lane;
 use lol_core::life::{Death, Health};
-use lol_core::skill::{CoolDown, Skill};
+use lol_core::navigation::grid::ResourceGrid;
+use lol_core::skill::{CoolDown, Skill, SkillCalcQuery, Skills};
 use lol_core::team::Team;
 
 use super::obs::{PlayerQ, observe};
 use crate::driver::{AgentDriver, DEFAULT_TICK_BUDGET, ScriptAgent, ScriptDriver, ScriptRuntimes};
+use crate::script_host::{ScriptHost, ScriptLog, ScriptLogLevel, ScriptSkillContext};
+
+/// 每个实体最多缓存的脚本日志条数，超出时丢弃最旧的。
+pub const SCRIPT_LOG_CAPACITY: usize = 256;
+
+/// 按实体缓存的脚本日志，经 RPC `script_logs` 取出。
+#[derive(Resource, Default)]
+pub struct ScriptLogBuffer {
+    logs: HashMap<Entity, VecDeque<ScriptLog>>,
+}
+
+impl ScriptLogBuffer {
+    pub fn push(&mut self, entity: Entity, log: ScriptLog) {
+        let logs = self.logs.entry(entity).or_default();
+        if logs.len() == SCRIPT_LOG_CAPACITY {
+            logs.pop_front();
+        }
+        logs.push_back(log);
+    }
+
+    /// 取出并清空实体自上次读取以来的日志。
+    pub fn take(&mut self, entity: Entity) -> Vec<ScriptLog> {
+        self.logs.remove(&entity).map(Vec::from).unwrap_or_default()
+    }
+}
+
+/// 宿主查询用的只读资产缓存：地图与技能资产在对局中不变，只克隆一次。
+#[derive(Default)]
+pub struct ScriptHostCache {
+    grid: Option<(AssetId<ConfigNavigationGrid>, Arc<ConfigNavigationGrid>)>,
+    spells: HashMap<AssetId<Spell>, Arc<Spell>>,
+}
+
+impl ScriptHostCache {
+    /// 取静态地形网格；缓存时剔除每帧刷新的单位占位，仅保留墙体。
+    fn grid(
+        &mut self,
+        res_grid: Option<&ResourceGrid>,
+        assets: &Assets<ConfigNavigationGrid>,
+    ) -> Option<Arc<ConfigNavigationGrid>> {
+        let id = res_grid?.0.id();
+        if self.grid.as_ref().is_none_or(|(cached, _)| *cached != id) {
+            let mut grid = assets.get(id)?.clone();
+            grid.occupied_cells.clear();
+            grid.exclude_cells.clear();
+            self.grid = Some((id, Arc::new(grid)));
+        }
+        self.grid.as_ref().map(|(_, grid)| grid.clone())
+    }
+
+    fn spell(&mut self, handle: &Handle<Spell>, assets: &Assets<Spell>) -> Option<Arc<Spell>> {
+        let id = handle.id();
+        if let Some(spell) = self.spells.get(&id) {
+            return Some(spell.clone());
+        }
+        let spell = Arc::new(assets.get(id)?.clone());
+        self.spells.insert(id, spell.clone());
+        Some(spell)
+    }
+}
 
 /// 每 FixedUpdate 驱动所有 Script Agent：构建观测 → 运行脚本 → 下发动作。
 ///
 /// - 首帧为实体创建 [`ScriptDriver`]；`ScriptAgent.source` 变更则热重载（脚本 `state` 保留）。
+/// - 每 tick 注入 [`ScriptHost`]，供寻路/视线/技能数值等宿主函数查询。
 /// - 脚本执行受时间片熔断保护，死循环不会挂起引擎。
-/// - 已不再携带 `ScriptAgent` 的实体（如已销毁）会被清理出运行时表。
+/// - 脚本日志按级别转发到 `tracing`，并缓存到 [`ScriptLogBuffer`] 供 RPC 读取。
+/// - 已不再携带 `ScriptAgent` 的实体会被清理出运行时表；实体仍存活时暂存其持久存储，
+///   重新挂载脚本时恢复，实体销毁后一并丢弃。
 pub fn drive_script_agents(
     mut commands: Commands,
     time: Res<Time>,
     mut runtimes: NonSendMut<ScriptRuntimes>,
+    mut host_cache: Local<ScriptHostCache>,
+    mut log_buffer: ResMut<ScriptLogBuffer>,
+    res_grid: Option<Res<ResourceGrid>>,
+    (assets_grid, assets_spell): (Res<Assets<ConfigNavigationGrid>>, Res<Assets<Spell>>),
     script_q: Query<(Entity, Ref<ScriptAgent>)>,
     player_q: PlayerQ,
     skills_q: Query<(&Skill, Option<&CoolDown>)>,
+    host_stats_q: Query<(Option<&Skills>, Option<&Damage>, Option<&AbilityPower>)>,
     minions_q: Query<
         (Entity, &Transform, &Health, Option<&Vital>, &Team, &Lane),
         (With<Minion>, Without<Death>),
     >,
     champion_q: Query<(Entity, &Transform, &Health, &Team), (With<Champion>, Without<Death>)>,
     transforms_q: Query<&Transform>,
+    entities_q: Query<()>,
+    calc: SkillCalcQuery,
 ) {
+    let grid = host_cache.grid(res_grid.as_deref(), &assets_grid);
+
     for (entity, script) in script_q.iter() {
-        if !runtimes.0.contains_key(&entity) {
+        if !runtimes.drivers.contains_key(&entity) {
             match ScriptDriver::new(DEFAULT_TICK_BUDGET) {
                 Ok(mut d) => {
+                    if let Some(store) = runtimes.stores.remove(&entity) {
+                        d.restore_store(store);
+                    }
                     d.reload(&script.source);
-                    runtimes.0.insert(entity, d);
+                    runtimes.drivers.insert(entity, d);
                 }
                 Err(e) => {
                     warn!("创建 Script 驱动失败 ({entity}): {e}");
@@ -43,12 +125,12 @@ pub fn drive_script_agents(
                 }
             }
         } else if script.is_changed() {
-            if let Some(d) = runtimes.0.get_mut(&entity) {
+            if let Some(d) = runtimes.drivers.get_mut(&entity) {
                 d.reload(&script.source);
             }
         }
 
-        let Some(driver) = runtimes.0.get_mut(&entity) else {
+        let Some(driver) = runtimes.drivers.get_mut(&entity) else {
             continue;
         };
 
@@ -64,15 +146,65 @@ pub fn drive_script_agents(
             continue;
         };
 
+        let mut host = ScriptHost {
+            grid: grid.clone(),
+            caster: calc.caster(entity),
+            ..default()
+        };
+        if let Ok((skills, damage, ap)) = host_stats_q.get(entity) {
+            host.attack_damage = damage.map(|d| d.0).unwrap_or(0.0);
+            host.ability_power = ap.map(|ap| ap.0).unwrap_or(0.0);
+            host.skills = skills
+                .map(|skills| {
+                    skills
+                        .iter()
+                        .map(|skill_entity| match skills_q.get(*skill_entity) {
+                            Ok((skill, _)) => ScriptSkillContext {
+                                spell: host_cache.spell(&skill.spell, &assets_spell),
+                                level: skill.level,
+                            },
+                            Err(_) => ScriptSkillContext::default(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+        }
+        driver.set_host(host);
+
         driver.observe(&obs);
         for action in driver.actions() {
             commands.trigger(CommandAction { entity, action });
         }
+        for log in driver.take_logs() {
+            match log.level {
+                ScriptLogLevel::Debug => debug!("[script {entity}] {}", log.message),
+                ScriptLogLevel::Log | ScriptLogLevel::Info => {
+                    info!("[script {entity}] {}", log.message)
+                }
+                ScriptLogLevel::Warn => warn!("[script {entity}] {}", log.message),
+                ScriptLogLevel::Error => error!("[script {entity}] {}", log.message),
+            }
+            log_buffer.push(entity, log);
+        }
         if let Some(err) = driver.last_error() {
             warn!("Script Agent {entity} 执行错误: {err}");
         }
     }
 
-    // 清理已不存在 ScriptAgent 的实体对应的运行时。
-    runtimes.0.retain(|e, _| script_q.get(*e).is_ok());
+    // 清理已不存在 ScriptAgent 的实体对应的运行时，仍存活的实体保留其持久存储。
+    let stale: Vec<Entity> = runtimes
+        .drivers
+        .keys()
+        .filter(|e| !script_q.contains(**e))
+        .copied()
+        .collect();
+    for e in stale {
+        if let Some(driver) = runtimes.drivers.remove(&e)
+            && entities_q.contains(e)
+        {
+            runtimes.stores.insert(e, driver.store());
+        }
+    }
+    runtimes.stores.retain(|e, _| entities_q.contains(*e));
+    log_buffer.logs.retain(|e, _| entities_q.contains(*e));
 }
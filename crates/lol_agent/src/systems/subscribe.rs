@@ -0,0 +1,228 @@
+use bevy::prelude::*;
+use lol_champions::fiora::passive::Vital;
+use lol_client::codec;
+use lol_core::entities::champion::Champion;
+use lol_core::entities::minion::Minion;
+use lol_core::lane::Lane;
+use lol_core::life::{Death, Health};
+use lol_core::skill::{CoolDown, Skill};
+use lol_core::team::Team;
+use lol_server::protocol::WsEvent;
+use lol_server::server::{DebugWsChannel, WsConnectionClosed};
+use serde_json::Value;
+
+use super::obs::{PlayerQ, observe};
+
+/// 默认每隔多少次推送附带一次完整观测（关键帧），便于丢帧后自愈。
+pub const DEFAULT_KEYFRAME_EVERY: u32 = 50;
+
+/// 一条观测订阅：每 `every_ticks` 个 FixedUpdate tick 向发起订阅的 WS 连接推送一次观测，
+/// 首帧与每 `keyframe_every` 次推送为完整观测，其余为 JSON Merge Patch 增量。
+pub struct ObserveSubscription {
+    pub id: u64,
+    pub conn_id: u64,
+    pub entity: Entity,
+    pub every_ticks: u32,
+    pub keyframe_every: u32,
+    /// 距上次推送经过的 tick 数。
+    ticks: u32,
+    /// 已推送次数，同时作为推送序号。
+    pushes: u64,
+    /// 上一次推送后客户端应持有的完整观测。
+    last: Option<Value>,
+}
+
+impl ObserveSubscription {
+    /// 推进一个 tick，返回本 tick 是否到期需要推送。
+    pub fn tick(&mut self) -> bool {
+        self.ticks += 1;
+        if self.ticks < self.every_ticks {
+            return false;
+        }
+        self.ticks = 0;
+        true
+    }
+
+    /// 基于当前完整观测生成推送内容 `(序号, 是否关键帧, 数据)`；与上次相比无变化时返回 None。
+    pub fn next_payload(&mut self, current: Value) -> Option<(u64, bool, Value)> {
+        let keyframe = self.last.is_none()
+            || (self.keyframe_every > 0 && self.pushes % self.keyframe_every as u64 == 0);
+        let data = if keyframe {
+            current.clone()
+        } else {
+            codec::diff(self.last.as_ref()?, &current)?
+        };
+        let seq = self.pushes;
+        self.pushes += 1;
+        self.last = Some(current);
+        Some((seq, keyframe, data))
+    }
+}
+
+/// 所有活跃的观测订阅。连接断开时随 [`WsConnectionClosed`] 清理，
+/// 被观测实体销毁时在下一次推送前清理。
+#[derive(Resource, Default)]
+pub struct ObserveSubscriptions {
+    next_id: u64,
+    pub subs: Vec<ObserveSubscription>,
+}
+
+impl ObserveSubscriptions {
+    pub fn add(
+        &mut self,
+        conn_id: u64,
+        entity: Entity,
+        every_ticks: u32,
+        keyframe_every: u32,
+    ) -> u64 {
+        self.next_id += 1;
+        self.subs.push(ObserveSubscription {
+            id: self.next_id,
+            conn_id,
+            entity,
+            every_ticks: every_ticks.max(1),
+            keyframe_every,
+            ticks: 0,
+            pushes: 0,
+            last: None,
+        });
+        self.next_id
+    }
+
+    /// 移除订阅；只有发起订阅的连接可以取消。返回是否移除成功。
+    pub fn remove(&mut self, id: u64, conn_id: u64) -> bool {
+        let before = self.subs.len();
+        self.subs
+            .retain(|sub| !(sub.id == id && sub.conn_id == conn_id));
+        self.subs.len() != before
+    }
+
+    pub fn remove_connection(&mut self, conn_id: u64) {
+        self.subs.retain(|sub| sub.conn_id != conn_id);
+    }
+
+    /// 移除被观测实体已不存在的订阅。
+    pub fn retain_entities(&mut self, alive: impl Fn(Entity) -> bool) {
+        self.subs.retain(|sub| alive(sub.entity));
+    }
+}
+
+/// 每 FixedUpdate 推进所有订阅，到期的计算观测并定向推送 `observe_delta` 事件。
+pub fn push_observe_subscriptions(
+    mut subscriptions: ResMut<ObserveSubscriptions>,
+    channel: Option<Res<DebugWsChannel>>,
+    time: Res<Time>,
+    player_q: PlayerQ,
+    skills_q: Query<(&Skill, Option<&CoolDown>)>,
+    minions_q: Query<
+        (Entity, &Transform, &Health, Option<&Vital>, &Team, &Lane),
+        (With<Minion>, Without<Death>),
+    >,
+    champion_q: Query<(Entity, &Transform, &Health, &Team), (With<Champion>, Without<Death>)>,
+    transforms_q: Query<&Transform>,
+    entities_q: Query<()>,
+) {
+    subscriptions.retain_entities(|entity| entities_q.contains(entity));
+
+    let Some(channel) = channel else {
+        return;
+    };
+
+    for sub in subscriptions.subs.iter_mut() {
+        if !sub.tick() {
+            continue;
+        }
+        let Some(obs) = observe(
+            sub.entity,
+            &player_q,
+            &skills_q,
+            &minions_q,
+            &champion_q,
+            &transforms_q,
+            time.elapsed_secs(),
+        ) else {
+            continue;
+        };
+        let Ok(current) = serde_json::to_value(&obs) else {
+            continue;
+        };
+        if let Some((seq, keyframe, data)) = sub.next_payload(current) {
+            channel.send_to(
+                sub.conn_id,
+                WsEvent::observe_delta(sub.id, seq, keyframe, data),
+            );
+        }
+    }
+}
+
+pub fn on_ws_connection_closed(
+    event: On<WsConnectionClosed>,
+    mut subscriptions: ResMut<ObserveSubscriptions>,
+) {
+    subscriptions.remove_connection(event.conn_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn pushes_keyframe_then_deltas() {
+        let mut subs = ObserveSubscriptions::default();
+        let id = subs.add(3, Entity::PLACEHOLDER, 2, 3);
+        let sub = &mut subs.subs[0];
+        assert_eq!(sub.id, id);
+
+        // every_ticks = 2：奇数 tick 不推送。
+        assert!(!sub.tick());
+        assert!(sub.tick());
+
+        let (seq, keyframe, data) = sub.next_payload(json!({ "time": 1.0, "hp": 10 })).unwrap();
+        assert_eq!((seq, keyframe), (0, true));
+        assert_eq!(data, json!({ "time": 1.0, "hp": 10 }));
+
+        let (seq, keyframe, data) = sub.next_payload(json!({ "time": 1.1, "hp": 10 })).unwrap();
+        assert_eq!((seq, keyframe), (1, false));
+        assert_eq!(data, json!({ "time": 1.1 }));
+
+        // 无变化不推送，也不消耗序号。
+        assert!(sub.next_payload(json!({ "time": 1.1, "hp": 10 })).is_none());
+
+        let (seq, keyframe, _) = sub.next_payload(json!({ "time": 1.2, "hp": 9 })).unwrap();
+        assert_eq!((seq, keyframe), (2, false));
+
+        // keyframe_every = 3：第 4 次推送（序号 3）重新发送完整观测。
+        let (seq, keyframe, data) = sub.next_payload(json!({ "time": 1.3, "hp": 9 })).unwrap();
+        assert_eq!((seq, keyframe), (3, true));
+        assert_eq!(data, json!({ "time": 1.3, "hp": 9 }));
+    }
+
+    #[test]
+    fn only_owner_connection_can_unsubscribe() {
+        let mut subs = ObserveSubscriptions::default();
+        let a = subs.add(1, Entity::PLACEHOLDER, 1, 0);
+        let b = subs.add(2, Entity::PLACEHOLDER, 1, 0);
+        assert!(!subs.remove(a, 2));
+        assert!(subs.remove(a, 1));
+        subs.remove_connection(2);
+        assert!(subs.subs.iter().all(|s| s.id != b));
+        assert!(subs.subs.is_empty());
+    }
+
+    #[test]
+    fn despawned_entity_drops_subscription() {
+        let mut world = World::new();
+        let alive = world.spawn_empty().id();
+        let gone = world.spawn_empty().id();
+        world.despawn(gone);
+
+        let mut subs = ObserveSubscriptions::default();
+        let keep = subs.add(1, alive, 1, 0);
+        subs.add(1, gone, 1, 0);
+        subs.retain_entities(|entity| world.get_entity(entity).is_ok());
+        assert_eq!(subs.subs.len(), 1);
+        assert_eq!(subs.subs[0].id, keep);
+    }
+}
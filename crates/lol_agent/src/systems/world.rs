@@ -0,0 +1,198 @@
+use bevy::prelude::*;
+use lol_client::spectate::{EntitySnapshot, SnapshotEntityKind, WorldSnapshot};
+use lol_core::base::level::Level;
+use lol_core::entities::champion::Champion;
+use lol_core::entities::inhibitor::Inhibitor;
+use lol_core::entities::minion::Minion;
+use lol_core::entities::nexus::Nexus;
+use lol_core::entities::turret::Turret;
+use lol_core::life::{Death, Health};
+use lol_core::team::Team;
+use lol_server::protocol::WsEvent;
+use lol_server::server::{DebugWsChannel, WsConnectionClosed};
+
+/// 世界快照默认推送间隔（FixedUpdate tick 数）。观战不需要逐 tick 精度，
+/// 网关侧再按延迟缓冲与增量转播。
+pub const DEFAULT_WORLD_EVERY_TICKS: u32 = 6;
+
+/// 一条世界快照订阅：每 `every_ticks` 个 tick 向发起订阅的连接推送一次全量快照。
+pub struct WorldSubscription {
+    pub id: u64,
+    pub conn_id: u64,
+    pub every_ticks: u32,
+    ticks: u32,
+}
+
+impl WorldSubscription {
+    /// 推进一个 tick，返回本 tick 是否到期需要推送。
+    pub fn tick(&mut self) -> bool {
+        self.ticks += 1;
+        if self.ticks < self.every_ticks {
+            return false;
+        }
+        self.ticks = 0;
+        true
+    }
+}
+
+/// 所有活跃的世界快照订阅。通常每局只有观战网关一条，连接断开时随 [`WsConnectionClosed`] 清理。
+#[derive(Resource, Default)]
+pub struct WorldSubscriptions {
+    next_id: u64,
+    pub subs: Vec<WorldSubscription>,
+}
+
+impl WorldSubscriptions {
+    pub fn add(&mut self, conn_id: u64, every_ticks: u32) -> u64 {
+        self.next_id += 1;
+        self.subs.push(WorldSubscription {
+            id: self.next_id,
+            conn_id,
+            every_ticks: every_ticks.max(1),
+            ticks: 0,
+        });
+        self.next_id
+    }
+
+    /// 移除订阅；只有发起订阅的连接可以取消。返回是否移除成功。
+    pub fn remove(&mut self, id: u64, conn_id: u64) -> bool {
+        let before = self.subs.len();
+        self.subs
+            .retain(|sub| !(sub.id == id && sub.conn_id == conn_id));
+        self.subs.len() != before
+    }
+
+    pub fn remove_connection(&mut self, conn_id: u64) {
+        self.subs.retain(|sub| sub.conn_id != conn_id);
+    }
+}
+
+pub type WorldEntityQ<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        &'static Health,
+        &'static Team,
+        Option<&'static Name>,
+        Option<&'static Level>,
+        (
+            Has<Champion>,
+            Has<Minion>,
+            Has<Turret>,
+            Has<Inhibitor>,
+            Has<Nexus>,
+            Has<Death>,
+        ),
+    ),
+>;
+
+/// 采集所有带生命值的实体（英雄、小兵、防御塔、水晶等）组成世界快照。
+pub fn capture_world(tick: u64, time: f32, entities_q: &WorldEntityQ) -> WorldSnapshot {
+    let entities = entities_q
+        .iter()
+        .map(
+            |(
+                entity,
+                transform,
+                health,
+                team,
+                name,
+                level,
+                (champion, minion, turret, inhibitor, nexus, dead),
+            )| {
+                let kind = if champion {
+                    SnapshotEntityKind::Champion
+                } else if minion {
+                    SnapshotEntityKind::Minion
+                } else if turret {
+                    SnapshotEntityKind::Turret
+                } else if inhibitor {
+                    SnapshotEntityKind::Inhibitor
+                } else if nexus {
+                    SnapshotEntityKind::Nexus
+                } else {
+                    SnapshotEntityKind::Other
+                };
+                let snapshot = EntitySnapshot {
+                    kind,
+                    team: format!("{:?}", team),
+                    name: name.map(|n| n.as_str().to_string()),
+                    position: [transform.translation.x, transform.translation.z],
+                    health: health.value,
+                    max_health: health.max,
+                    level: level.map(|l| l.value),
+                    dead,
+                };
+                (entity.to_bits(), snapshot)
+            },
+        )
+        .collect();
+    WorldSnapshot {
+        tick,
+        time,
+        entities,
+    }
+}
+
+/// 每 FixedUpdate 推进所有订阅；有订阅到期时采集一次快照，定向推送 `world_snapshot` 事件。
+pub fn push_world_snapshots(
+    mut subscriptions: ResMut<WorldSubscriptions>,
+    channel: Option<Res<DebugWsChannel>>,
+    time: Res<Time>,
+    mut tick: Local<u64>,
+    entities_q: WorldEntityQ,
+) {
+    *tick += 1;
+    let Some(channel) = channel else {
+        return;
+    };
+
+    let mut snapshot = None;
+    for sub in subscriptions.subs.iter_mut() {
+        if !sub.tick() {
+            continue;
+        }
+        let snapshot = snapshot.get_or_insert_with(|| {
+            serde_json::to_value(capture_world(*tick, time.elapsed_secs(), &entities_q))
+                .unwrap_or_default()
+        });
+        channel.send_to(
+            sub.conn_id,
+            WsEvent::world_snapshot(sub.id, snapshot.clone()),
+        );
+    }
+}
+
+pub fn on_world_connection_closed(
+    event: On<WsConnectionClosed>,
+    mut subscriptions: ResMut<WorldSubscriptions>,
+) {
+    subscriptions.remove_connection(event.conn_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn world_subscription_respects_interval_and_owner() {
+        let mut subs = WorldSubscriptions::default();
+        let a = subs.add(1, 3);
+        let b = subs.add(2, 0);
+
+        let sub = &mut subs.subs[0];
+        assert!(!sub.tick());
+        assert!(!sub.tick());
+        assert!(sub.tick());
+        // every_ticks = 0 视为每 tick 推送。
+        assert!(subs.subs[1].tick());
+
+        assert!(!subs.remove(a, 2));
+        assert!(subs.remove(a, 1));
+        subs.remove_connection(2);
+        assert!(subs.subs.iter().all(|s| s.id != b));
+        assert!(subs.subs.is_empty());
+    }
+}
@@ -24,6 +24,13 @@ pub struct ConfigSkin {
     pub skin: Handle<DynamicWorld>,
 }
 
+/// 已写入实体的皮肤场景 - 供分身等复制外观时复用
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct LoadedSkin {
+    pub skin: Handle<DynamicWorld>,
+}
+
 /// 角色配置组件 - 逻辑相关
 #[derive(Component, Reflect, Debug, Clone)]
 #[reflect(Component)]
@@ -280,6 +280,9 @@ pub struct ConfigNavigationGrid {
     pub occupied_cells: HashMap<(usize, usize), f32>,
     #[serde(skip)]
     pub exclude_cells: HashSet<(usize, usize)>,
+    /// 技能墙等运行时地形覆盖层占据的格子，视同墙体
+    #[serde(skip)]
+    pub terrain_cells: HashSet<(usize, usize)>,
 }
 
 impl ConfigNavigationGrid {
@@ -414,13 +417,58 @@ impl ConfigNavigationGrid {
         self.occupied_cells.get(&pos).copied().unwrap_or(0.0)
     }
 
+    /// 按 4 邻接把带有指定 POI 标记的格子聚成连通区域，返回每个区域的 `(中心坐标, 格子数)`，
+    /// 按格子数从大到小排序。野怪营地、龙坑等地图点位由此从导航网格推导。
+    pub fn poi_regions(&self, poi: GridFlagsPOI) -> Vec<(Vec2, usize)> {
+        let mut visited = vec![vec![false; self.x_len]; self.y_len];
+        let mut regions = Vec::new();
+
+        for y in 0..self.y_len {
+            for x in 0..self.x_len {
+                if visited[y][x] || self.cells[y][x].poi_flags != poi {
+                    continue;
+                }
+
+                visited[y][x] = true;
+                let mut stack = vec![(x, y)];
+                let mut sum = Vec2::ZERO;
+                let mut count = 0;
+                while let Some((cx, cy)) = stack.pop() {
+                    let center = self.get_cell_center_position_by_xy((cx, cy));
+                    sum += vec2(center.x, center.z);
+                    count += 1;
+
+                    let neighbors = [
+                        (cx.wrapping_sub(1), cy),
+                        (cx + 1, cy),
+                        (cx, cy.wrapping_sub(1)),
+                        (cx, cy + 1),
+                    ];
+                    for (nx, ny) in neighbors {
+                        if nx >= self.x_len || ny >= self.y_len || visited[ny][nx] {
+                            continue;
+                        }
+                        if self.cells[ny][nx].poi_flags == poi {
+                            visited[ny][nx] = true;
+                            stack.push((nx, ny));
+                        }
+                    }
+                }
+                regions.push((sum / count as f32, count));
+            }
+        }
+
+        regions.sort_by(|a, b| b.1.cmp(&a.1));
+        regions
+    }
+
     /// 判断世界坐标是否在网格内且可通行（超出地图边界安全返回不可通行）
     pub fn is_walkable_by_position(&self, position: &Vec2) -> bool {
         self.get_cell_xy_by_position(position)
             .is_some_and(|xy| self.is_walkable_by_xy(xy))
     }
 
-    /// 判断格子是否可通行（静态墙体 + 动态障碍物成本检查）
+    /// 判断格子是否可通行（静态墙体 + 地形覆盖层 + 动态障碍物成本检查）
     pub fn is_walkable_by_xy(&self, (x, y): (usize, usize)) -> bool {
         if x >= self.x_len || y >= self.y_len {
             return false;
@@ -428,6 +476,9 @@ impl ConfigNavigationGrid {
         if !self.get_cell_by_xy((x, y)).is_walkable() {
             return false;
         }
+        if self.terrain_cells.contains(&(x, y)) {
+            return false;
+        }
         if self.exclude_cells.contains(&(x, y)) {
             return true;
         }
@@ -493,6 +544,7 @@ mod tests {
             height_samples: vec![vec![0.0; 10]; 10],
             occupied_cells: Default::default(),
             exclude_cells: Default::default(),
+            terrain_cells: Default::default(),
         }
     }
 
@@ -524,6 +576,22 @@ mod tests {
         assert_eq!(grid.clamp_position_to_grid_xy(&Vec2::new(150.0, 250.0)), (1, 2));
     }
 
+    #[test]
+    fn test_poi_regions_split_disconnected_areas() {
+        let mut grid = make_test_grid();
+        // 左下 2x2 与右上 1 格两块不相连的红 buff 区域
+        for (x, y) in [(0, 0), (1, 0), (0, 1), (1, 1), (8, 8)] {
+            grid.cells[y][x].poi_flags = GridFlagsPOI::CampRedBuff;
+        }
+        grid.cells[5][5].poi_flags = GridFlagsPOI::CampGromp;
+
+        let regions = grid.poi_regions(GridFlagsPOI::CampRedBuff);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[0], (Vec2::new(100.0, 100.0), 4));
+        assert_eq!(regions[1], (Vec2::new(850.0, 850.0), 1));
+        assert_eq!(grid.poi_regions(GridFlagsPOI::CampBlueBuff), vec![]);
+    }
+
     #[test]
     fn test_is_walkable_by_position() {
         let mut grid = make_test_grid();
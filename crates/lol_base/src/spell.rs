@@ -52,6 +52,10 @@ pub struct DataSpell {
     pub selection_priority: Option<u32>,
     pub icon_path: Option<Vec<String>>,
     pub use_animator_framerate: Option<bool>,
+    /// 提示文本本地化键（如 `keyTooltip`），供提示渲染查表
+    pub tooltip_loc_keys: Option<BTreeMap<String, String>>,
+    /// 按本地化键名提取的提示文本模板，`{{ key }}` 文本引用已展开
+    pub tooltip_templates: Option<BTreeMap<String, String>>,
 }
 
 /// 技能对象 (Bevy Asset)
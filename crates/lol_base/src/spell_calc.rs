@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// 计算部件类型
@@ -9,7 +11,18 @@ pub enum CalculationPart {
     CalculationPartNamedDataValue(CalculationPartNamedDataValue),
     CalculationPartStatSub(CalculationPartStatSub),
     CalculationPartStatNamedDataValue(CalculationPartStatNamedDataValue),
-    // ... 其他变体暂不实现
+    CalculationPartNumber(CalculationPartNumber),
+    CalculationPartByCharLevelBreakpoints(CalculationPartByCharLevelBreakpoints),
+    CalculationPartByCharLevelInterpolation(CalculationPartByCharLevelInterpolation),
+    CalculationPartByCharLevelFormula(CalculationPartByCharLevelFormula),
+    CalculationPartBuffCounterCoefficient(CalculationPartBuffCounterCoefficient),
+    CalculationPartBuffCounterNamedDataValue(CalculationPartBuffCounterNamedDataValue),
+    CalculationPartClamp(CalculationPartClamp),
+    CalculationPartSum(CalculationPartSum),
+    CalculationPartProduct(CalculationPartProduct),
+    CalculationPartExponent(CalculationPartExponent),
+    /// 提取时尚不支持的部件，求值为 0，`kind` 为原始类型名
+    CalculationPartUnsupported(CalculationPartUnsupported),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -46,12 +59,108 @@ pub struct CalculationPartStatNamedDataValue {
     pub data_value: String,
 }
 
+/// 常数
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculationPartNumber {
+    pub number: Option<f32>,
+}
+
+/// 英雄等级断点：1 级为 `level1_value`，每升一级加 `initial_bonus_per_level`；
+/// 到达断点等级时额外加 `additional_bonus_at_this_level`，并从该级起改用新的每级加成。
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculationPartByCharLevelBreakpoints {
+    pub level1_value: Option<f32>,
+    pub initial_bonus_per_level: Option<f32>,
+    pub breakpoints: Vec<CalculationBreakpoint>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculationBreakpoint {
+    pub level: Option<u32>,
+    pub additional_bonus_at_this_level: Option<f32>,
+    pub bonus_per_level_at_and_after: Option<f32>,
+}
+
+/// 英雄等级插值：1 级 `start_value`，18 级 `end_value`。
+/// `scale_by_stat_progression` 为真时按属性成长曲线插值，否则线性。
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculationPartByCharLevelInterpolation {
+    pub start_value: Option<f32>,
+    pub end_value: Option<f32>,
+    pub scale_by_stat_progression: Option<bool>,
+    pub scale_past_default_max_level: Option<bool>,
+}
+
+/// 按英雄等级查表，`values[0]` 为 1 级
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculationPartByCharLevelFormula {
+    pub values: Vec<f32>,
+}
+
+/// buff 层数 × 系数
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculationPartBuffCounterCoefficient {
+    pub buff_name: String,
+    pub coefficient: f32,
+}
+
+/// buff 层数 × 数据值
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculationPartBuffCounterNamedDataValue {
+    pub buff_name: String,
+    pub data_value: String,
+}
+
+/// 子部件求和后夹取；只有 `floor` 即取最大值，只有 `ceiling` 即取最小值
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculationPartClamp {
+    pub floor: Option<f32>,
+    pub ceiling: Option<f32>,
+    pub subparts: Vec<CalculationPart>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculationPartSum {
+    pub subparts: Vec<CalculationPart>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculationPartProduct {
+    pub part1: Box<CalculationPart>,
+    pub part2: Box<CalculationPart>,
+}
+
+/// `part1 ^ part2`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculationPartExponent {
+    pub part1: Box<CalculationPart>,
+    pub part2: Box<CalculationPart>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculationPartUnsupported {
+    pub kind: String,
+}
+
 /// 计算类型
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
 pub enum CalculationType {
     CalculationSpell(CalculationSpell),
-    // Conditional 和 Modified 暂不实现
+    CalculationConditional(CalculationConditional),
+    CalculationModified(CalculationModified),
 }
 
 /// 技能计算容器
@@ -62,3 +171,146 @@ pub struct CalculationSpell {
     pub multiplier: Option<CalculationPart>,
     pub precision: Option<i32>,
 }
+
+/// 条件计算：满足 `requirement` 时取 `conditional` 计算，否则取 `default`（缺省为 0）
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculationConditional {
+    pub requirement: CalculationRequirement,
+    pub conditional: String,
+    pub default: Option<String>,
+}
+
+/// 修饰计算：`calculation` 的结果乘以 `multiplier`，可指定按固定技能等级求值
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CalculationModified {
+    pub calculation: String,
+    pub multiplier: CalculationPart,
+    pub override_spell_level: Option<i32>,
+}
+
+/// 条件计算的前置条件
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum CalculationRequirement {
+    /// 施法者生命比例高于阈值（阈值取 `percent` 或数据值）
+    AboveHealthPercent {
+        percent: Option<f32>,
+        data_value: Option<String>,
+        invert: bool,
+    },
+    HasBuff {
+        buff_name: Option<String>,
+        invert: bool,
+    },
+    /// 当前技能等级不低于 `level`
+    SpellLevelAtLeast {
+        level: u32,
+    },
+    IsRanged,
+    All {
+        requirements: Vec<CalculationRequirement>,
+    },
+    AtLeast {
+        requirements: Vec<CalculationRequirement>,
+        count: u32,
+    },
+    /// 提取时尚不支持的条件；求值时整条计算返回 None
+    Unsupported {
+        kind: String,
+    },
+}
+
+impl CalculationPart {
+    /// 收集本部件（含子部件）中不支持的类型名
+    pub fn collect_unsupported(&self, out: &mut Vec<String>) {
+        match self {
+            CalculationPart::CalculationPartUnsupported(part) => out.push(part.kind.clone()),
+            CalculationPart::CalculationPartStatSub(part) => {
+                if let Some(subpart) = &part.subpart {
+                    subpart.collect_unsupported(out);
+                }
+            }
+            CalculationPart::CalculationPartClamp(CalculationPartClamp { subparts, .. })
+            | CalculationPart::CalculationPartSum(CalculationPartSum { subparts }) => {
+                for subpart in subparts {
+                    subpart.collect_unsupported(out);
+                }
+            }
+            CalculationPart::CalculationPartProduct(CalculationPartProduct { part1, part2 })
+            | CalculationPart::CalculationPartExponent(CalculationPartExponent { part1, part2 }) => {
+                part1.collect_unsupported(out);
+                part2.collect_unsupported(out);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl CalculationRequirement {
+    /// 收集本条件（含子条件）中不支持的类型名
+    pub fn collect_unsupported(&self, out: &mut Vec<String>) {
+        match self {
+            CalculationRequirement::Unsupported { kind } => out.push(kind.clone()),
+            CalculationRequirement::All { requirements }
+            | CalculationRequirement::AtLeast { requirements, .. } => {
+                for requirement in requirements {
+                    requirement.collect_unsupported(out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl CalculationType {
+    /// 本计算及其经条件 / 修饰引用的其他计算中不支持的部件 / 条件类型名
+    pub fn unsupported_parts(
+        &self,
+        calculations: &BTreeMap<String, CalculationType>,
+    ) -> Vec<String> {
+        let mut out = Vec::new();
+        self.collect_unsupported(calculations, &mut Vec::new(), &mut out);
+        out
+    }
+
+    fn collect_unsupported<'a>(
+        &'a self,
+        calculations: &'a BTreeMap<String, CalculationType>,
+        visited: &mut Vec<&'a str>,
+        out: &mut Vec<String>,
+    ) {
+        let referenced: Vec<&'a str> = match self {
+            CalculationType::CalculationSpell(calc) => {
+                for part in calc.formula_parts.iter().flatten() {
+                    part.collect_unsupported(out);
+                }
+                if let Some(multiplier) = &calc.multiplier {
+                    multiplier.collect_unsupported(out);
+                }
+                Vec::new()
+            }
+            CalculationType::CalculationConditional(calc) => {
+                calc.requirement.collect_unsupported(out);
+                std::iter::once(calc.conditional.as_str())
+                    .chain(calc.default.as_deref())
+                    .collect()
+            }
+            CalculationType::CalculationModified(calc) => {
+                calc.multiplier.collect_unsupported(out);
+                vec![calc.calculation.as_str()]
+            }
+        };
+        // 被引用的计算只展开一次，防止数据成环
+        for name in referenced {
+            if visited.contains(&name) {
+                continue;
+            }
+            visited.push(name);
+            if let Some(calc) = calculations.get(name) {
+                calc.collect_unsupported(calculations, visited, out);
+            }
+        }
+    }
+}
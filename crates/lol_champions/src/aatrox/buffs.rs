@@ -59,20 +59,20 @@ impl Default for AatroxPassiveState {
     }
 }
 
-/// Aatrox R（世界终结者）状态，直接挂在英雄身上，追踪剩余持续时间与额外 AD。
+/// Aatrox R（世界终结者）buff，追踪剩余持续时间。
 ///
-/// 移速增益由 `BuffMoveSpeed` 自管（到期自移除）；额外 AD 到期后由 `update_aatrox_r` 扣除。
+/// 额外 AD 由同实体上的属性修正结算、随 buff 销毁撤回；
+/// 移速增益由 `BuffMoveSpeed` 自管（到期自移除）。
 #[derive(Component, Debug)]
-pub struct AatroxRState {
+#[require(Buff = Buff { name: "AatroxR" })]
+pub struct BuffAatroxR {
     pub timer: Timer,
-    pub bonus_ad: f32,
 }
 
-impl AatroxRState {
-    pub fn new(duration: f32, bonus_ad: f32) -> Self {
+impl BuffAatroxR {
+    pub fn new(duration: f32) -> Self {
         Self {
             timer: Timer::from_seconds(duration, TimerMode::Once),
-            bonus_ad,
         }
     }
 }
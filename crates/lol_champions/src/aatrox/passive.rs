@@ -7,7 +7,8 @@ use lol_base::render_cmd::{
     CommandSkinParticleDespawn, CommandSkinParticleSpawn, CommandSkinSoundPlay,
 };
 use lol_core::attack::EventAttackEnd;
-use lol_core::damage::{CommandDamageCreate, DamageType};
+use lol_core::damage::{CommandDamageCreate, DamageKind, DamageType};
+use lol_core::heal::{CommandHeal, HealType};
 use lol_core::life::Health;
 use lol_core::team::Team;
 
@@ -51,17 +52,17 @@ pub fn on_aatrox_attack_end(
             damage_type: DamageType::Magic,
             amount: bonus,
             tag: Some(AATROX_P_TAG),
+            kind: DamageKind::Other,
         });
     }
     let heal = bonus * AatroxPassiveState::HEAL_RATIO;
     if heal > 0.0 {
-        commands
-            .entity(attacker)
-            .queue(move |mut e: EntityWorldMut| {
-                if let Some(mut health) = e.get_mut::<Health>() {
-                    health.value = (health.value + heal).min(health.max);
-                }
-            });
+        commands.trigger(CommandHeal {
+            entity: attacker,
+            source: attacker,
+            heal_type: HealType::Heal,
+            amount: heal,
+        });
     }
 
     passive.ready = false;
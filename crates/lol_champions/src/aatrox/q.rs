@@ -8,11 +8,11 @@ use lol_base::render_cmd::{CommandAnimationPlay, CommandSkinParticleSpawn, Comma
 use lol_base::spell::Spell;
 use lol_core::base::buff::BuffOf;
 use lol_core::buffs::cc_debuffs::DebuffKnockup;
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType};
-use lol_core::entities::champion::Champion;
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType};
+use lol_core::entities::champion::TargetableAsChampion;
 use lol_core::skill::{
-    CoolDown, EventSkillCast, Skill, SkillRecastWindow, SkillSlot, get_skill_data_value,
-    get_skill_value,
+    CoolDown, EventSkillCast, Skill, SkillCalcQuery, SkillRecastWindow, SkillSlot,
+    get_skill_data_value, get_skill_value_with,
 };
 use lol_core::team::Team;
 
@@ -34,9 +34,10 @@ pub fn on_aatrox_q(
     q_skill: Query<(&Skill, &CoolDown, Option<&SkillRecastWindow>)>,
     q_transform: Query<&Transform>,
     q_team: Query<&Team>,
-    q_enemies: Query<(Entity, &Transform), With<Champion>>,
+    q_enemies: Query<(Entity, &Transform), With<TargetableAsChampion>>,
     q_damage: Query<&Damage>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     if q_aatrox.get(entity).is_err() {
@@ -88,9 +89,15 @@ pub fn on_aatrox_q(
         });
     }
 
-    let base = get_skill_value(spell_obj, "q_damage", skill.level, |stat| {
-        if stat == 2 { ad } else { 0.0 }
-    })
+    let base = get_skill_value_with(
+        spell_obj,
+        "q_damage",
+        skill.level,
+        &calc.caster(entity),
+        |stat| {
+            if stat == 2 { ad } else { 0.0 }
+        },
+    )
     .unwrap_or(0.0);
     let ramp = get_skill_data_value(spell_obj, "QRampBonus", skill.level).unwrap_or(0.25);
     let sweet = get_skill_data_value(spell_obj, "QSweetSpotBonus", skill.level).unwrap_or(0.7);
@@ -127,6 +134,7 @@ pub fn on_aatrox_q(
                 damage_type: DamageType::Physical,
                 amount: dmg,
                 tag: Some(AATROX_Q_TAG),
+                kind: DamageKind::Ability,
             });
         }
         if is_sweet {
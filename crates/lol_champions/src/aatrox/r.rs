@@ -8,13 +8,13 @@ use lol_base::render_cmd::{
     CommandAnimationPlay, CommandSkinParticleDespawn, CommandSkinParticleSpawn,
 };
 use lol_base::spell::Spell;
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::{BuffCommandsExt, BuffOf};
+use lol_core::base::stat_modifier::{StatKind, StatModifier, StatModifiers};
 use lol_core::buffs::common_buffs::BuffMoveSpeed;
-use lol_core::damage::Damage;
 use lol_core::skill::{EventSkillCast, Skill, SkillSlot, get_skill_data_value};
 
 use crate::aatrox::Aatrox;
-use crate::aatrox::buffs::AatroxRState;
+use crate::aatrox::buffs::BuffAatroxR;
 
 /// R 伤害标签
 pub const AATROX_R_TAG: u32 = 13;
@@ -24,7 +24,6 @@ pub fn on_aatrox_r(
     mut commands: Commands,
     q_aatrox: Query<(), With<Aatrox>>,
     q_skill: Query<&Skill>,
-    q_damage: Query<&Damage>,
     res_spells: Res<Assets<Spell>>,
 ) {
     let entity = trigger.event_target();
@@ -40,7 +39,6 @@ pub fn on_aatrox_r(
     let Some(spell_obj) = res_spells.get(&skill.spell) else {
         return;
     };
-    let ad = q_damage.get(entity).map(|d| d.0).unwrap_or(0.0);
 
     commands.trigger(CommandAnimationPlay {
         entity,
@@ -65,40 +63,30 @@ pub fn on_aatrox_r(
     let ms_bonus =
         get_skill_data_value(spell_obj, "RMovementSpeedBonus", skill.level).unwrap_or(0.4);
     let ad_amp = get_skill_data_value(spell_obj, "RTotalADAmp", skill.level).unwrap_or(0.1);
-    let bonus_ad = ad * ad_amp;
 
-    commands
-        .entity(entity)
-        .insert(AatroxRState::new(duration, bonus_ad));
-    commands.entity(entity).queue(move |mut e: EntityWorldMut| {
-        if let Some(mut damage) = e.get_mut::<Damage>() {
-            damage.0 += bonus_ad;
-        }
-    });
-    commands
-        .entity(entity)
-        .with_related::<BuffOf>(BuffMoveSpeed::new(ms_bonus, duration));
+    commands.entity(entity).with_related::<BuffOf>((
+        BuffAatroxR::new(duration),
+        StatModifiers(vec![StatModifier::percent(StatKind::AttackDamage, ad_amp)]),
+    ));
+    commands.entity(entity).apply_buff(
+        BuffMoveSpeed::descriptor(duration).with_source(entity),
+        BuffMoveSpeed::new(ms_bonus),
+    );
 }
 
-/// R 持续期：到时移除额外 AD 与状态。
+/// R 持续期：到时销毁 buff（额外 AD 随之撤回）。
 pub fn update_aatrox_r(
     time: Res<Time>,
     mut commands: Commands,
-    mut q_r: Query<(Entity, &mut AatroxRState), With<Aatrox>>,
+    mut q_r: Query<(Entity, &BuffOf, &mut BuffAatroxR)>,
 ) {
-    for (entity, mut state) in q_r.iter_mut() {
-        state.timer.tick(time.delta());
-        if state.timer.just_finished() {
-            let bonus_ad = state.bonus_ad;
-            commands.entity(entity).queue(move |mut e: EntityWorldMut| {
-                if let Some(mut damage) = e.get_mut::<Damage>() {
-                    damage.0 = (damage.0 - bonus_ad).max(0.0);
-                }
-            });
-            commands.entity(entity).remove::<AatroxRState>();
+    for (buff_entity, buff_of, mut buff) in q_r.iter_mut() {
+        buff.timer.tick(time.delta());
+        if buff.timer.just_finished() {
+            commands.entity(buff_entity).despawn();
             // R 到期：撤下加速光效
             commands.trigger(CommandSkinParticleDespawn {
-                entity,
+                entity: buff_of.0,
                 hash: "Aatrox_R_SpeedBuff".to_string(),
                 resolver_entity: None,
             });
@@ -11,10 +11,11 @@ use lol_base::spell::Spell;
 use lol_core::action::displace::{ActionDisplace, DisplaceMotion, DisplaceTargetSelection};
 use lol_core::base::buff::BuffOf;
 use lol_core::buffs::cc_debuffs::{DebuffKnockup, DebuffSlow};
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType};
-use lol_core::entities::champion::Champion;
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType};
+use lol_core::entities::champion::TargetableAsChampion;
 use lol_core::skill::{
-    EventSkillCast, Skill, SkillSlot, get_skill_cast_radius, get_skill_data_value, get_skill_value,
+    EventSkillCast, Skill, SkillCalcQuery, SkillSlot, get_skill_cast_radius, get_skill_data_value,
+    get_skill_value_with,
 };
 use lol_core::team::Team;
 
@@ -33,9 +34,10 @@ pub fn on_aatrox_w(
     q_skill: Query<&Skill>,
     q_transform: Query<&Transform>,
     q_team: Query<&Team>,
-    q_enemies: Query<(Entity, &Transform), With<Champion>>,
+    q_enemies: Query<(Entity, &Transform), With<TargetableAsChampion>>,
     q_damage: Query<&Damage>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     if q_aatrox.get(entity).is_err() {
@@ -74,9 +76,15 @@ pub fn on_aatrox_w(
         resolver_entity: None,
     });
 
-    let dmg = get_skill_value(spell_obj, "w_damage", skill.level, |stat| {
-        if stat == 2 { ad } else { 0.0 }
-    })
+    let dmg = get_skill_value_with(
+        spell_obj,
+        "w_damage",
+        skill.level,
+        &calc.caster(entity),
+        |stat| {
+            if stat == 2 { ad } else { 0.0 }
+        },
+    )
     .unwrap_or(0.0);
     let slow_pct = get_skill_data_value(spell_obj, "WSlowPercentage", skill.level)
         .map(|v| v.abs())
@@ -102,6 +110,7 @@ pub fn on_aatrox_w(
                 damage_type: DamageType::Physical,
                 amount: dmg,
                 tag: Some(AATROX_W_TAG),
+                kind: DamageKind::Ability,
             });
         }
         commands
@@ -152,6 +161,7 @@ pub fn update_aatrox_w_marks(
                     damage_type: DamageType::Physical,
                     amount: damage,
                     tag: Some(AATROX_W_TAG),
+                    kind: DamageKind::Ability,
                 });
             }
             commands
@@ -10,11 +10,17 @@ use lol_core::base::buff::BuffOf;
 use lol_core::buffs::cc_debuffs::DebuffSlow;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
+use lol_core::navigation::terrain::{CommandTerrainWallCreate, terrain_wall_segment};
 use lol_core::skill::{CoolDown, EventSkillCast, Skill, SkillRecastWindow, SkillSlot};
 
 use crate::anivia::buffs::BuffAniviaR;
 
 const ANIVIA_Q_RECAST_WINDOW: f32 = 3.0;
+const ANIVIA_W_RANGE: f32 = 1000.0;
+const ANIVIA_W_BASE_LENGTH: f32 = 400.0;
+const ANIVIA_W_LENGTH_PER_LEVEL: f32 = 100.0;
+const ANIVIA_W_THICKNESS: f32 = 50.0;
+const ANIVIA_W_DURATION: f32 = 5.0;
 
 #[derive(Default)]
 pub struct PluginAnivia;
@@ -97,6 +103,7 @@ fn on_anivia_w(
     mut commands: Commands,
     q_anivia: Query<(), With<Anivia>>,
     q_skill: Query<&Skill>,
+    q_transform: Query<&Transform>,
 ) {
     let entity = trigger.event_target();
     if q_anivia.get(entity).is_err() {
@@ -110,13 +117,28 @@ fn on_anivia_w(
         return;
     }
 
+    let Ok(transform) = q_transform.get(entity) else {
+        return;
+    };
+
     commands.trigger(CommandAnimationPlay {
         entity,
         hash: ANIM_SPELL2.to_string(),
         repeat: false,
         duration: None,
     });
-    // W creates a wall that blocks movement;
+
+    // 在目标点生成垂直于施法方向的冰墙，长度随等级增长
+    let caster_pos = transform.translation.xz();
+    let offset = trigger.point - caster_pos;
+    let center = caster_pos + offset.clamp_length_max(ANIVIA_W_RANGE);
+    let length =
+        ANIVIA_W_BASE_LENGTH + ANIVIA_W_LENGTH_PER_LEVEL * skill.level.saturating_sub(1) as f32;
+    commands.trigger(CommandTerrainWallCreate {
+        entity,
+        polygon: terrain_wall_segment(center, offset, length, ANIVIA_W_THICKNESS),
+        duration: ANIVIA_W_DURATION,
+    });
 }
 
 fn on_anivia_e(
@@ -6,14 +6,27 @@ use lol_base::render_cmd::CommandAnimationPlay;
 use lol_core::action::damage::{
     ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
 };
+use lol_core::attack::Attack;
+use lol_core::base::bounding::Bounding;
 use lol_core::base::buff::BuffOf;
 use lol_core::buffs::cc_debuffs::DebuffStun;
-use lol_core::damage::{DamageType, EventDamageCreate};
+use lol_core::damage::{Armor, Damage, DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::entities::pet::{CommandPetOrder, Pet, PetAi, PetOf, PetOrder, Pets};
+use lol_core::life::{Death, Health};
+use lol_core::lifetime::Lifetime;
+use lol_core::movement::Movement;
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillRecastWindow, SkillSlot, SkillTarget, SkillTargetFilter,
+    SkillTargeting, attach_skill_targeting,
+};
+use lol_core::team::Team;
 
 use crate::annie::buffs::{BuffAnniePassive, BuffAnnieShield};
 
+/// 提伯斯存在时长（秒）
+const TIBBERS_DURATION: f32 = 45.0;
+
 #[derive(Default)]
 pub struct PluginAnnie;
 
@@ -24,6 +37,12 @@ impl Plugin for PluginAnnie {
         app.add_observer(on_annie_e);
         app.add_observer(on_annie_r);
         app.add_observer(on_annie_damage_hit);
+        app.add_observer(on_tibbers_death);
+        app.add_observer(on_tibbers_remove);
+        app.add_observer(attach_skill_targeting::<Annie>(
+            SkillSlot::Q,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -32,6 +51,13 @@ impl Plugin for PluginAnnie {
 #[reflect(Component)]
 pub struct Annie;
 
+/// 安妮 R 召唤的提伯斯，记录召唤它的 R 技能实体以便消失时关闭重施窗口
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Tibbers {
+    pub skill: Entity,
+}
+
 fn on_annie_q(
     trigger: On<EventSkillCast>,
     mut commands: Commands,
@@ -57,12 +83,16 @@ fn on_annie_q(
         repeat: false,
         duration: None,
     });
-    // Q is targeted damage
+    // Q 为点选技能：指定单位时只打该目标，否则退化为周身范围
+    let shape = match trigger.target {
+        SkillTarget::Unit(target) => DamageShape::Unit { entity: target },
+        _ => DamageShape::Circle { radius: 625.0 },
+    };
     commands.trigger(ActionDamage {
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Circle { radius: 625.0 },
+            shape,
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::All,
                 amount: "total_damage".to_string(),
@@ -161,13 +191,14 @@ fn on_annie_e(
 fn on_annie_r(
     trigger: On<EventSkillCast>,
     mut commands: Commands,
-    q_annie: Query<(), With<Annie>>,
+    q_annie: Query<(&Team, &Transform, Option<&Pets>), With<Annie>>,
+    q_tibbers: Query<(), (With<Tibbers>, Without<Death>)>,
     q_skill: Query<&Skill>,
 ) {
     let entity = trigger.event_target();
-    if q_annie.get(entity).is_err() {
+    let Ok((team, transform, pets)) = q_annie.get(entity) else {
         return;
-    }
+    };
 
     let Ok(skill) = q_skill.get(trigger.skill_entity) else {
         return;
@@ -176,6 +207,16 @@ fn on_annie_r(
         return;
     }
 
+    // 提伯斯存活时再次施放：指挥提伯斯攻击目标或移动到目标位置
+    if pets.is_some_and(|pets| pets.iter().any(|pet| q_tibbers.contains(*pet))) {
+        let order = match trigger.target {
+            SkillTarget::Unit(target) => PetOrder::Attack(target),
+            _ => PetOrder::MoveTo(trigger.point),
+        };
+        commands.trigger(CommandPetOrder { entity, order });
+        return;
+    }
+
     let skill_spell = skill.spell.clone();
     commands.trigger(CommandAnimationPlay {
         entity,
@@ -202,6 +243,56 @@ fn on_annie_r(
     commands
         .entity(entity)
         .with_related::<BuffOf>(BuffAnniePassive::increment());
+
+    commands.spawn((
+        Name::new("Tibbers"),
+        Tibbers {
+            skill: trigger.skill_entity,
+        },
+        Pet,
+        PetOf(entity),
+        PetAi::controllable(),
+        *team,
+        Transform::from_xyz(trigger.point.x, transform.translation.y, trigger.point.y),
+        Health::new(1200.0),
+        Damage(60.0),
+        Armor(30.0),
+        Attack::new(150.0, 0.3, 1.0),
+        Bounding {
+            radius: 100.0,
+            height: 200.0,
+        },
+        Movement { speed: 350.0 },
+        Lifetime::new_timer(TIBBERS_DURATION),
+    ));
+
+    // 提伯斯存活期间 R 处于持续重施窗口，可绕过冷却、免蓝再次施放以指挥提伯斯
+    commands
+        .entity(trigger.skill_entity)
+        .insert(SkillRecastWindow::sustained(TIBBERS_DURATION));
+}
+
+fn on_tibbers_death(trigger: On<Add, Death>, mut commands: Commands, q_tibbers: Query<&Tibbers>) {
+    if let Ok(tibbers) = q_tibbers.get(trigger.event_target()) {
+        close_tibbers_recast(&mut commands, tibbers.skill);
+    }
+}
+
+fn on_tibbers_remove(
+    trigger: On<Remove, Tibbers>,
+    mut commands: Commands,
+    q_tibbers: Query<&Tibbers>,
+) {
+    if let Ok(tibbers) = q_tibbers.get(trigger.event_target()) {
+        close_tibbers_recast(&mut commands, tibbers.skill);
+    }
+}
+
+/// 提伯斯阵亡或到期后关闭 R 的重施窗口，下一次施放重新召唤并进入冷却
+fn close_tibbers_recast(commands: &mut Commands, skill: Entity) {
+    if let Ok(mut entity) = commands.get_entity(skill) {
+        entity.remove::<SkillRecastWindow>();
+    }
 }
 
 fn on_annie_damage_hit(
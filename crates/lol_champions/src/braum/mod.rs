@@ -10,10 +10,18 @@ use lol_core::base::buff::BuffOf;
 use lol_core::buffs::cc_debuffs::DebuffSlow;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
+use lol_core::missile_block::{BlockerShape, MissileBlocker};
 use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::team::Team;
 
 use crate::braum::buffs::{BuffBraumPassive, BuffBraumW};
 
+const BRAUM_E_DURATION: f32 = 4.0;
+/// 盾墙离布隆的距离与尺寸
+const BRAUM_E_OFFSET: f32 = 100.0;
+const BRAUM_E_HALF_WIDTH: f32 = 150.0;
+const BRAUM_E_THICKNESS: f32 = 60.0;
+
 #[derive(Default)]
 pub struct PluginBraum;
 
@@ -110,13 +118,13 @@ fn on_braum_w(
 fn on_braum_e(
     trigger: On<EventSkillCast>,
     mut commands: Commands,
-    q_braum: Query<(), With<Braum>>,
+    q_braum: Query<(&Team, &Transform), With<Braum>>,
     q_skill: Query<&Skill>,
 ) {
     let entity = trigger.event_target();
-    if q_braum.get(entity).is_err() {
+    let Ok((team, transform)) = q_braum.get(entity) else {
         return;
-    }
+    };
 
     let Ok(skill) = q_skill.get(trigger.skill_entity) else {
         return;
@@ -131,7 +139,24 @@ fn on_braum_e(
         repeat: false,
         duration: None,
     });
-    // E blocks projectiles - no direct damage;
+    // E blocks projectiles - no direct damage; the wall faces the cast point
+    let direction =
+        (trigger.point - transform.translation.xz()).normalize_or(transform.forward().xz());
+    let direction = Vec3::new(direction.x, 0.0, direction.y);
+    let offset = direction * BRAUM_E_OFFSET;
+    commands.spawn((
+        MissileBlocker::new(
+            entity,
+            *team,
+            BlockerShape::Wall {
+                half_length: BRAUM_E_HALF_WIDTH,
+                thickness: BRAUM_E_THICKNESS,
+            },
+            BRAUM_E_DURATION,
+        )
+        .following(entity, offset),
+        Transform::from_translation(transform.translation + offset).looking_to(direction, Vec3::Y),
+    ));
 }
 
 fn on_braum_r(
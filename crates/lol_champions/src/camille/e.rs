@@ -28,8 +28,8 @@ use lol_core::life::Death;
 use lol_core::missile::{CommandMissileCreate, EventMissileHit, MissileCollisionTarget};
 use lol_core::movement::{EventMovementEnd, MovementSource};
 use lol_core::skill::{
-    CoolDown, EventSkillCast, Skill, SkillRecastWindow, SkillSlot, get_skill_data_value,
-    get_skill_value,
+    CoolDown, EventSkillCast, Skill, SkillCalcQuery, SkillRecastWindow, SkillSlot,
+    get_skill_data_value, get_skill_value_with,
 };
 use lol_core::team::Team;
 
@@ -48,7 +48,7 @@ pub const CAMILLE_E_AS_DURATION: f32 = 5.0;
 
 // ── 组件 ──
 
-/// E 攻速加成计时器：到期回收 `BuffAttack`。
+/// E 攻速加成计时器：与 `BuffAttack` 同挂一个 buff 实体，到期销毁即回收攻速。
 #[derive(Component, Debug, Clone)]
 #[require(Buff = Buff { name: "CamilleE" })]
 pub struct BuffCamilleE {
@@ -67,12 +67,12 @@ impl BuffCamilleE {
 
 /// 施放 E2 攻速加成 + 计时 buff。
 pub fn apply_camille_e_as(commands: &mut Commands, entity: Entity, as_percent: f32, duration: f32) {
-    commands.entity(entity).insert(BuffAttack {
-        bonus_attack_speed: as_percent,
-    });
-    commands
-        .entity(entity)
-        .with_related::<BuffOf>(BuffCamilleE::new(duration));
+    commands.entity(entity).with_related::<BuffOf>((
+        BuffCamilleE::new(duration),
+        BuffAttack {
+            bonus_attack_speed: as_percent,
+        },
+    ));
 }
 
 /// 读取技能数据中的命名数值。
@@ -93,6 +93,7 @@ pub fn on_camille_e(
     q_enemies: Query<(Entity, &Team, &Transform), (Without<Camille>, Without<Death>)>,
     q_team: Query<&Team>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     if q_camille.get(entity).is_err() {
@@ -188,9 +189,15 @@ pub fn on_camille_e(
 
         // 读取伤害值
         let ad = q_damage.get(entity).map(|d| d.0).unwrap_or(0.0);
-        let e2_damage = get_skill_value(spell_obj, "total_damage", level, |stat| {
-            if stat == 2 { ad } else { 0.0 }
-        })
+        let e2_damage = get_skill_value_with(
+            spell_obj,
+            "total_damage",
+            level,
+            &calc.caster(entity),
+            |stat| {
+                if stat == 2 { ad } else { 0.0 }
+            },
+        )
         .unwrap_or(0.0);
 
         // 设置冲刺类型与状态
@@ -360,18 +367,17 @@ pub fn on_camille_e_dash_end(
 
 // ── 系统：攻速计时 ──
 
-/// E 攻速计时：到期移除 `BuffAttack` 与计时 buff。
+/// E 攻速计时：到期销毁计时 buff（连同 `BuffAttack`）。
 pub fn update_camille_e(
     mut commands: Commands,
-    mut q: Query<(Entity, &BuffOf, &mut BuffCamilleE)>,
+    mut q: Query<(Entity, &mut BuffCamilleE)>,
     time: Res<Time<Fixed>>,
 ) {
-    for (e, bo, mut buff) in q.iter_mut() {
+    for (e, mut buff) in q.iter_mut() {
         buff.timer.tick(time.delta());
         if !buff.timer.is_finished() {
             continue;
         }
-        commands.entity(bo.0).remove::<BuffAttack>();
         commands.entity(e).despawn();
     }
 }
@@ -117,9 +117,7 @@ fn camille_e2_stuns_enemy_in_path() {
 
     // 冠军应有攻速加成
     let as_bonus = h
-        .app
-        .world()
-        .get::<BuffAttack>(h.champion)
+        .buff::<BuffAttack>(h.champion)
         .expect("E2 后 Camille 应有 BuffAttack")
         .bonus_attack_speed;
     assert!(
@@ -149,9 +147,7 @@ fn camille_e2_no_enemy_dashes_without_stun() {
 
     // 攻速加成应生效
     let as_bonus = h
-        .app
-        .world()
-        .get::<BuffAttack>(h.champion)
+        .buff::<BuffAttack>(h.champion)
         .expect("E2 后 Camille 应有 BuffAttack")
         .bonus_attack_speed;
     assert!(
@@ -174,13 +170,13 @@ fn camille_e_as_expires() {
     h.cast_skill(2, Vec2::new(500.0, 0.0)).advance(0.2);
 
     assert!(
-        h.app.world().get::<BuffAttack>(h.champion).is_some(),
+        h.buff::<BuffAttack>(h.champion).is_some(),
         "E2 后应存在攻速加成"
     );
 
     h.advance(5.3); // 总计 > 5s
     assert!(
-        h.app.world().get::<BuffAttack>(h.champion).is_none(),
+        h.buff::<BuffAttack>(h.champion).is_none(),
         "5s 后攻速加成应消失"
     );
     h.finish();
@@ -8,7 +8,7 @@ use bevy::prelude::*;
 use bevy::time::{Timer, TimerMode};
 use lol_base::render_cmd::{CommandSkinParticleDespawn, CommandSkinParticleSpawn};
 use lol_core::attack::EventAttackEnd;
-use lol_core::base::buff::{Buff, BuffOf};
+use lol_core::base::buff::{Buff, BuffCommandsExt, BuffOf};
 use lol_core::buffs::cc_debuffs::DebuffSlow;
 use lol_core::buffs::shield_white::BuffShieldWhite;
 use lol_core::damage::EventDamageCreate;
@@ -79,7 +79,10 @@ pub fn on_camille_attack_end(
     let shield_amount = hp.max * CAMILLE_PASSIVE_SHIELD_RATIO;
     commands
         .entity(attacker)
-        .with_related::<BuffOf>(BuffShieldWhite::new(shield_amount))
+        .apply_buff(
+            BuffShieldWhite::descriptor().with_source(attacker),
+            BuffShieldWhite::new(shield_amount),
+        )
         .with_related::<BuffOf>(BuffCamillePassiveTimer::new());
 
     // 护盾光效（先撤后挂，刷新时不叠加）
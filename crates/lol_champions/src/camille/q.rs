@@ -11,7 +11,7 @@ use lol_base::render_cmd::{
 };
 use lol_base::spell::Spell;
 use lol_core::attack::{CommandAttackReset, EventAttackEnd};
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::{BuffCommandsExt, BuffOf};
 use lol_core::buffs::on_hit::{BuffOnHitBonusDamage, BuffOnHitCounter};
 use lol_core::skill::{
     CoolDown, EventSkillCast, Skill, SkillRecastWindow, SkillSlot, get_skill_data_value,
@@ -116,7 +116,10 @@ pub fn on_camille_q(
     if stage == 1 {
         commands
             .entity(entity)
-            .with_related::<BuffOf>(BuffOnHitCounter::new(1, q2_duration))
+            .apply_buff(
+                BuffOnHitCounter::descriptor(q2_duration).with_source(entity),
+                BuffOnHitCounter::new(1),
+            )
             .with_related::<BuffOf>(BuffOnHitBonusDamage {
                 flat: 0.0,
                 ratio: tad_ratio,
@@ -127,7 +130,10 @@ pub fn on_camille_q(
     } else {
         commands
             .entity(entity)
-            .with_related::<BuffOf>(BuffOnHitCounter::new(1, q2_duration))
+            .apply_buff(
+                BuffOnHitCounter::descriptor(q2_duration).with_source(entity),
+                BuffOnHitCounter::new(1),
+            )
             .with_related::<BuffOf>(BuffOnHitBonusDamage {
                 flat: 0.0,
                 ratio: tad_ratio * empowered_amp,
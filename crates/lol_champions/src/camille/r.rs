@@ -17,8 +17,8 @@ use lol_core::attack::EventAttackEnd;
 use lol_core::base::buff::{Buff, BuffOf, Buffs};
 use lol_core::buffs::cc_debuffs::ImmuneToCC;
 use lol_core::buffs::damage_reduction::BuffDamageReduction;
-use lol_core::damage::{CommandDamageCreate, DamageType};
-use lol_core::entities::champion::Champion;
+use lol_core::damage::{CommandDamageCreate, DamageKind, DamageType, Untargetable};
+use lol_core::entities::champion::TargetableAsChampion;
 use lol_core::life::Health;
 use lol_core::movement::{EventMovementEnd, MovementSource};
 use lol_core::skill::{EventSkillCast, Skill, SkillSlot, get_skill_data_value};
@@ -78,7 +78,7 @@ pub fn on_camille_r(
     trigger: On<EventSkillCast>,
     mut commands: Commands,
     q_camille: Query<&Team, With<Camille>>,
-    q_enemies: Query<(Entity, &Transform, &Team), With<Champion>>,
+    q_enemies: Query<(Entity, &Transform, &Team), With<TargetableAsChampion>>,
     q_transform: Query<&Transform, With<Camille>>,
     q_skill: Query<&Skill>,
     res_spells: Res<Assets<Spell>>,
@@ -150,7 +150,7 @@ pub fn on_camille_r(
     });
 
     // 跃起不可选中
-    commands.entity(entity).insert(ImmuneToCC);
+    commands.entity(entity).insert((ImmuneToCC, Untargetable));
     commands
         .entity(entity)
         .with_related::<BuffOf>(BuffDamageReduction::new(1.0, None));
@@ -181,7 +181,7 @@ pub fn on_camille_r_arrival(
     q_camille: Query<&Team, With<Camille>>,
     q_pending: Query<&CamilleRLeapPending>,
     q_transform: Query<&Transform, With<Camille>>,
-    q_enemies: Query<(Entity, &Transform, &Team), With<Champion>>,
+    q_enemies: Query<(Entity, &Transform, &Team), With<TargetableAsChampion>>,
     q_buffs: Query<&Buffs>,
     q_damage_reduction: Query<&BuffDamageReduction>,
 ) {
@@ -197,7 +197,9 @@ pub fn on_camille_r_arrival(
     };
 
     // 清除不可选中
-    commands.entity(entity).remove::<ImmuneToCC>();
+    commands
+        .entity(entity)
+        .remove::<(ImmuneToCC, Untargetable)>();
     if let Ok(buffs) = q_buffs.get(entity) {
         for b in buffs.iter() {
             if q_damage_reduction.get(b).is_ok() {
@@ -280,6 +282,7 @@ pub fn on_camille_r_attack_end(
         damage_type: DamageType::Magic,
         amount: bonus,
         tag: None,
+        kind: DamageKind::Ability,
     });
 }
 
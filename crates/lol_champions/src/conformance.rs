@@ -0,0 +1,397 @@
+#![cfg(test)]
+
+//! 英雄一致性测试：对每个英雄插件跑同一套检查，输出英雄 × 检查项矩阵。
+//!
+//! 检查项：
+//! - 升级：Q/W/E/R 都能通过 `CommandSkillLevelUp` 升到 1 级
+//! - 施放：每个技能施放后进入冷却（或开启重施窗口），有消耗的技能扣除资源
+//! - 伤害：英雄造成的伤害数值有限且非负
+//! - 泄漏：全部施放后静置一段时间，实体数回落到施放前
+//! - panic：构建与模拟过程中不 panic
+//!
+//! 缺少提取资源（`characters/<name>/config.ron`）的英雄记为跳过。
+//! 矩阵打印到标准输出并写入 `target/champion_conformance.md`；
+//! 只有 `PluginChampions` 注册的英雄不通过时测试才失败，其余只记录。
+//! 设置 `CONFORMANCE_CHAMPIONS=riven,lux` 可只跑部分英雄。
+
+use std::fmt::Write as _;
+use std::fs;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use bevy::prelude::*;
+use lol_base::spell::Spell;
+use lol_core::base::level::Level;
+use lol_core::damage::{DamageType, EventDamageCreate};
+use lol_core::skill::{
+    CommandSkillLevelUp, CoolDown, Skill, SkillPoints, SkillRecastWindow, Skills,
+};
+
+use crate::test_utils::*;
+
+/// 施放后等待判定冷却 / 消耗的时长
+const CAST_SETTLE: f32 = 0.1;
+/// 相邻两次施放之间的间隔，等待前摇、位移结束
+const CAST_INTERVAL: f32 = 2.0;
+/// 全部施放后静置时长，用于泄漏检查
+const LEAK_SETTLE: f32 = 20.0;
+
+struct ConformanceChampion {
+    name: &'static str,
+    /// `PluginChampions` 注册的英雄必须全部通过
+    registered: bool,
+    config: ChampionHarnessConfig,
+    build: fn(&str, &ChampionHarnessConfig) -> ChampionTestHarness,
+}
+
+macro_rules! conformance_champions {
+    (
+        registered: [$($rm:ident :: $rmarker:ident => $rplugin:ident, $rdir:literal;)*]
+        optional: [$($om:ident :: $omarker:ident => $oplugin:ident, $odir:literal;)*]
+    ) => {
+        fn all_champions() -> Vec<ConformanceChampion> {
+            vec![
+                $(conformance_champion!(true, $rm::$rmarker => $rplugin, $rdir),)*
+                $(conformance_champion!(false, $om::$omarker => $oplugin, $odir),)*
+            ]
+        }
+    };
+}
+
+macro_rules! conformance_champion {
+    ($registered:expr, $module:ident :: $marker:ident => $plugin:ident, $dir:literal) => {
+        ConformanceChampion {
+            name: stringify!($marker),
+            registered: $registered,
+            config: ChampionHarnessConfig {
+                champion_dir: $dir,
+                config_path: concat!("characters/", $dir, "/config.ron"),
+                skin_path: concat!("characters/", $dir, "/skins/skin0.ron"),
+                add_champion_plugin: |app| {
+                    app.add_plugins(crate::$module::$plugin);
+                },
+            },
+            build: |name, config| {
+                ChampionTestHarness::build_with(
+                    name,
+                    HarnessMode::Headless,
+                    config,
+                    crate::$module::$marker,
+                )
+            },
+        }
+    };
+}
+
+crate::champion_roster!(conformance_champions);
+
+/// 单个检查项结果
+#[derive(Debug, Clone, PartialEq)]
+enum Check {
+    Pass,
+    Fail(String),
+    Skipped,
+}
+
+impl Check {
+    fn cell(&self) -> String {
+        match self {
+            Check::Pass => "ok".to_string(),
+            Check::Fail(reason) => reason.clone(),
+            Check::Skipped => "-".to_string(),
+        }
+    }
+}
+
+const SLOTS: [&str; 4] = ["Q", "W", "E", "R"];
+
+struct ChampionReport {
+    name: &'static str,
+    registered: bool,
+    level_up: Check,
+    casts: [Check; 4],
+    damage: Check,
+    leak: Check,
+    panic: Check,
+}
+
+impl ChampionReport {
+    fn skipped(champion: &ConformanceChampion) -> Self {
+        Self {
+            name: champion.name,
+            registered: champion.registered,
+            level_up: Check::Skipped,
+            casts: std::array::from_fn(|_| Check::Skipped),
+            damage: Check::Skipped,
+            leak: Check::Skipped,
+            panic: Check::Skipped,
+        }
+    }
+
+    fn checks(&self) -> impl Iterator<Item = &Check> {
+        [&self.level_up, &self.damage, &self.leak, &self.panic]
+            .into_iter()
+            .chain(self.casts.iter())
+    }
+
+    fn passed(&self) -> bool {
+        self.checks().all(|check| *check == Check::Pass)
+    }
+}
+
+/// 英雄造成的伤害：(类型, 最终伤害, 原始伤害, 护甲减免)
+#[derive(Resource, Default)]
+struct DamageLog {
+    source: Option<Entity>,
+    events: Vec<(DamageType, f32, f32, f32)>,
+}
+
+fn record_damage(trigger: On<EventDamageCreate>, mut log: ResMut<DamageLog>) {
+    if log.source != Some(trigger.source) {
+        return;
+    }
+    let result = &trigger.damage_result;
+    log.events.push((
+        trigger.damage_type,
+        result.final_damage,
+        result.original_damage,
+        result.armor_reduced_damage,
+    ));
+}
+
+fn entity_count(h: &mut ChampionTestHarness) -> usize {
+    let world = h.app.world_mut();
+    world.query::<Entity>().iter(world).count()
+}
+
+fn check_level_up(h: &mut ChampionTestHarness) -> Check {
+    let champion = h.champion;
+    let skills = h.app.world().get::<Skills>(champion).map(|s| s.to_vec());
+    let Some(skills) = skills.filter(|s| s.len() >= 4) else {
+        return Check::Fail("no skills".to_string());
+    };
+
+    for &skill_entity in &skills[..4] {
+        if let Some(mut skill) = h.app.world_mut().get_mut::<Skill>(skill_entity) {
+            skill.level = 0;
+        }
+    }
+    if let Some(mut level) = h.app.world_mut().get_mut::<Level>(champion) {
+        level.value = level.value.max(6);
+    }
+    h.app
+        .world_mut()
+        .entity_mut(champion)
+        .insert(SkillPoints(4));
+
+    for index in 0..4 {
+        h.app.world_mut().trigger(CommandSkillLevelUp {
+            entity: champion,
+            index,
+        });
+    }
+    h.app.update();
+
+    let failed: Vec<&str> = (0..4)
+        .filter(|&index| {
+            h.app
+                .world()
+                .get::<Skill>(skills[index])
+                .is_none_or(|skill| skill.level != 1)
+        })
+        .map(|index| SLOTS[index])
+        .collect();
+    if failed.is_empty() {
+        Check::Pass
+    } else {
+        Check::Fail(format!("lv {}", failed.join("")))
+    }
+}
+
+/// 技能当前等级的消耗（与施法扣费一致按等级索引）
+fn skill_cost(h: &ChampionTestHarness, index: usize) -> f32 {
+    let level = h
+        .app
+        .world()
+        .get::<Skill>(h.skill_entity(index))
+        .map_or(0, |skill| skill.level);
+    h.spell(index)
+        .and_then(|spell: &Spell| spell.spell_data.as_ref())
+        .and_then(|data| data.mana.as_ref())
+        .and_then(|mana| mana.get(level).copied())
+        .unwrap_or(0.0)
+}
+
+fn check_cast(h: &mut ChampionTestHarness, index: usize, target: Vec3) -> Check {
+    let skill_entity = h.skill_entity(index);
+    let cost = skill_cost(h, index);
+    let mana_before = h.mana();
+
+    h.cast_skill(index, target.xz()).advance(CAST_SETTLE);
+
+    let world = h.app.world();
+    let on_cooldown = world
+        .get::<CoolDown>(skill_entity)
+        .is_some_and(|cooldown| cooldown.timer.is_some());
+    let recast = world.get::<SkillRecastWindow>(skill_entity).is_some();
+    let spent = h.mana() < mana_before;
+
+    let result = if !on_cooldown && !recast && !spent {
+        Check::Fail("no cast".to_string())
+    } else if !on_cooldown && !recast {
+        Check::Fail("no cd".to_string())
+    } else if cost > 0.0 && !spent {
+        Check::Fail("no cost".to_string())
+    } else {
+        Check::Pass
+    };
+
+    h.advance(CAST_INTERVAL);
+    result
+}
+
+/// 数值有限且非负；真实伤害不应被护甲减免
+fn check_damage(h: &ChampionTestHarness) -> Check {
+    let log = h.app.world().resource::<DamageLog>();
+    let invalid = log
+        .events
+        .iter()
+        .filter(|(_, final_damage, original, _)| {
+            ![*final_damage, *original]
+                .iter()
+                .all(|v| v.is_finite() && *v >= 0.0)
+        })
+        .count();
+    if invalid > 0 {
+        return Check::Fail(format!("{invalid} bad"));
+    }
+    if log
+        .events
+        .iter()
+        .any(|(damage_type, _, _, armor)| *damage_type == DamageType::True && *armor > 0.0)
+    {
+        return Check::Fail("true reduced".to_string());
+    }
+    Check::Pass
+}
+
+fn run_champion(champion: &ConformanceChampion) -> ChampionReport {
+    let mut report = ChampionReport::skipped(champion);
+
+    let outcome = catch_unwind(AssertUnwindSafe(|| {
+        let mut h = (champion.build)(
+            &format!("conformance_{}", champion.config.champion_dir),
+            &champion.config,
+        );
+        h.app.init_resource::<DamageLog>();
+        h.app.world_mut().resource_mut::<DamageLog>().source = Some(h.champion);
+        h.app.add_observer(record_damage);
+
+        report.level_up = check_level_up(&mut h);
+
+        let enemy_position = Vec3::new(300.0, 0.0, 0.0);
+        h.add_enemy(enemy_position);
+        h.advance(1.0);
+        let baseline = entity_count(&mut h);
+
+        for index in 0..4 {
+            report.casts[index] = check_cast(&mut h, index, enemy_position);
+        }
+
+        h.advance(LEAK_SETTLE);
+        // 静置后再检查伤害，覆盖持续伤害与延迟引爆
+        report.damage = check_damage(&h);
+        let leaked = entity_count(&mut h).saturating_sub(baseline);
+        report.leak = if leaked == 0 {
+            Check::Pass
+        } else {
+            Check::Fail(format!("+{leaked}"))
+        };
+    }));
+
+    report.panic = match outcome {
+        Ok(()) => Check::Pass,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<String>()
+                .cloned()
+                .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+                .unwrap_or_default();
+            eprintln!("[{}] panic: {message}", champion.name);
+            Check::Fail("panic".to_string())
+        }
+    };
+    report
+}
+
+fn render_matrix(reports: &[ChampionReport]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "| Champion | Level | Q | W | E | R | Damage | Leak | Panic |"
+    );
+    let _ = writeln!(out, "|---|---|---|---|---|---|---|---|---|");
+    for report in reports {
+        let _ = writeln!(
+            out,
+            "| {} | {} | {} | {} | {} | {} | {} | {} | {} |",
+            report.name,
+            report.level_up.cell(),
+            report.casts[0].cell(),
+            report.casts[1].cell(),
+            report.casts[2].cell(),
+            report.casts[3].cell(),
+            report.damage.cell(),
+            report.leak.cell(),
+            report.panic.cell(),
+        );
+    }
+    let tested = reports.iter().filter(|r| r.panic != Check::Skipped).count();
+    let passed = reports.iter().filter(|r| r.passed()).count();
+    let _ = writeln!(
+        out,
+        "\n{passed}/{tested} passed, {} skipped (missing assets)",
+        reports.len() - tested
+    );
+    out
+}
+
+#[test]
+fn champion_conformance_matrix() {
+    let filter: Option<Vec<String>> = std::env::var("CONFORMANCE_CHAMPIONS")
+        .ok()
+        .map(|list| list.split(',').map(|s| s.trim().to_lowercase()).collect());
+    let assets = workspace_root().join("assets");
+
+    let mut reports = Vec::new();
+    for champion in all_champions() {
+        if let Some(filter) = &filter {
+            if !filter
+                .iter()
+                .any(|name| name == champion.config.champion_dir)
+            {
+                continue;
+            }
+        }
+        if !assets.join(champion.config.config_path).exists() {
+            reports.push(ChampionReport::skipped(&champion));
+            continue;
+        }
+        reports.push(run_champion(&champion));
+    }
+
+    let matrix = render_matrix(&reports);
+    println!("{matrix}");
+    let target = workspace_root().join("target");
+    let _ = fs::create_dir_all(&target);
+    let _ = fs::write(target.join("champion_conformance.md"), &matrix);
+
+    let failed: Vec<&str> = reports
+        .iter()
+        .filter(|r| r.registered && r.panic != Check::Skipped && !r.passed())
+        .map(|r| r.name)
+        .collect();
+    assert!(
+        failed.is_empty(),
+        "registered champions failed conformance: {failed:?}"
+    );
+}
@@ -105,19 +105,16 @@ impl BuffDariusBleed {
 
 /// 诺手被动 - 诺克萨斯之力（叠满 5 层出血触发），提供 +50% AD。
 ///
-/// 挂在 Darius 自身。`ad_bonus` 记录已叠加到 [`lol_core::damage::Damage`] 上的数值，
-/// 到期时据此还原。
+/// 挂在 Darius 的 buff 实体上，AD 加成由同实体的 `StatModifiers` 承载，到期随实体撤回。
 #[derive(Component, Debug, Clone)]
 #[require(Buff = Buff { name: "DariusMight" })]
 pub struct BuffDariusMight {
-    pub ad_bonus: f32,
     pub timer: Timer,
 }
 
-impl BuffDariusMight {
-    pub fn new(ad_bonus: f32) -> Self {
+impl Default for BuffDariusMight {
+    fn default() -> Self {
         Self {
-            ad_bonus,
             timer: Timer::from_seconds(DARIUS_NOXIAN_MIGHT_DURATION, TimerMode::Once),
         }
     }
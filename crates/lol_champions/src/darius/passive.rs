@@ -3,7 +3,8 @@
 use bevy::prelude::*;
 use lol_base::render_cmd::{CommandSkinParticleDespawn, CommandSkinParticleSpawn};
 use lol_core::base::buff::{BuffOf, Buffs};
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType, EventDamageCreate};
+use lol_core::base::stat_modifier::{StatKind, StatModifier, StatModifiers};
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType, EventDamageCreate};
 
 use crate::darius::buffs::{
     BuffDariusBleed, BuffDariusMight, DARIUS_BLEED_AD_RATIO, DARIUS_BLEED_MAX_STACKS,
@@ -32,7 +33,6 @@ pub fn on_darius_damage_hit(
     trigger: On<EventDamageCreate>,
     mut commands: Commands,
     q_darius: Query<(), With<super::Darius>>,
-    q_damage: Query<&Damage>,
     q_buffs: Query<&Buffs>,
     mut q_bleed: Query<&mut BuffDariusBleed>,
     q_might: Query<&BuffDariusMight>,
@@ -49,7 +49,6 @@ pub fn on_darius_damage_hit(
         return;
     }
     let target = trigger.event_target();
-    let ad = q_damage.get(source).map(|d| d.0).unwrap_or(0.0);
 
     // 查找目标已有的出血 buff：有则叠层，无则新建
     let mut reached_five = false;
@@ -99,11 +98,13 @@ pub fn on_darius_damage_hit(
             .map(|buffs| buffs.iter().any(|b| q_might.get(b).is_ok()))
             .unwrap_or(false);
         if !has_might {
-            let bonus = ad * DARIUS_NOXIAN_MIGHT_AD_RATIO;
-            commands
-                .entity(source)
-                .with_related::<BuffOf>(BuffDariusMight::new(bonus));
-            commands.entity(source).insert(Damage(ad + bonus));
+            commands.entity(source).with_related::<BuffOf>((
+                BuffDariusMight::default(),
+                StatModifiers(vec![StatModifier::percent(
+                    StatKind::AttackDamage,
+                    DARIUS_NOXIAN_MIGHT_AD_RATIO,
+                )]),
+            ));
             // 诺克萨斯之力光效（到期由 update_darius_might 撤除）
             commands.trigger(CommandSkinParticleSpawn {
                 entity: source,
@@ -146,6 +147,7 @@ pub fn update_darius_bleed(
                 damage_type: DamageType::Physical,
                 amount,
                 tag: Some(DARIUS_BLEED_DOT_TAG),
+                kind: DamageKind::Other,
             });
         }
     }
@@ -154,25 +156,21 @@ pub fn update_darius_bleed(
     }
 }
 
-/// 诺克萨斯之力到期：移除 buff 并恢复 AD。
+/// 诺克萨斯之力到期：移除 buff（AD 加成随修正撤回）。
 pub fn update_darius_might(
     mut commands: Commands,
     time: Res<Time<Fixed>>,
     mut q_might: Query<(Entity, &mut BuffDariusMight, &BuffOf)>,
-    mut q_damage: Query<&mut Damage>,
 ) {
     let dt = time.delta();
     let mut expired = Vec::new();
     for (entity, mut might, bo) in q_might.iter_mut() {
         might.timer.tick(dt);
         if might.timer.is_finished() {
-            expired.push((entity, bo.0, might.ad_bonus));
+            expired.push((entity, bo.0));
         }
     }
-    for (entity, darius, bonus) in expired {
-        if let Ok(mut d) = q_damage.get_mut(darius) {
-            d.0 -= bonus;
-        }
+    for (entity, darius) in expired {
         // 到期：撤除诺克萨斯之力光效
         commands.trigger(CommandSkinParticleDespawn {
             entity: darius,
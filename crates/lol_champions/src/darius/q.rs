@@ -14,6 +14,7 @@ use lol_core::action::damage::{ActionDamageEffect, DamageShape, TargetDamage, Ta
 use lol_core::action::delayed_damage::{ActionDelayedDamage, AoEIndicator, AoEOrigin};
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
+use lol_core::heal::{CommandHeal, HealType};
 use lol_core::life::Health;
 use lol_core::skill::{
     EventSkillCast, Skill, SkillSlot, delay_from_cast_frame, get_skill_data_value,
@@ -173,7 +174,7 @@ pub fn apply_darius_q_heal(
     mut commands: Commands,
     darius_query: Query<Entity, With<Darius>>,
     mut q_pending: Query<&mut DariusQHealPending>,
-    mut q_health: Query<&mut Health>,
+    q_health: Query<&Health>,
 ) {
     let Some(darius) = darius_query.iter().next() else {
         return;
@@ -185,13 +186,18 @@ pub fn apply_darius_q_heal(
         return;
     }
 
-    let Ok(mut health) = q_health.get_mut(darius) else {
+    let Ok(health) = q_health.get(darius) else {
         return;
     };
     let missing = health.max - health.value;
     let heal = missing * pending.heal_pct_normalized * (pending.hit_count as f32);
-    health.value = (health.value + heal).min(health.max);
     pending.hit_count = 0;
+    commands.trigger(CommandHeal {
+        entity: darius,
+        source: darius,
+        heal_type: HealType::Heal,
+        amount: heal,
+    });
 
     // 外圈命中英雄回血粒子
     commands.trigger(CommandSkinParticleSpawn {
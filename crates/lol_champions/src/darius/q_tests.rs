@@ -14,7 +14,7 @@
 
 use bevy::math::{Vec2, Vec3};
 use lol_core::base::buff::Buffs;
-use lol_core::damage::{CommandDamageCreate, DamageType};
+use lol_core::damage::{CommandDamageCreate, DamageKind, DamageType};
 
 use super::tests::{build_headless, give_mana};
 use crate::darius::buffs::BuffDariusBleed;
@@ -125,6 +125,7 @@ fn darius_q_heals_on_outer_blade_hit() {
         damage_type: DamageType::True,
         amount: 300.0,
         tag: None,
+        kind: DamageKind::Other,
     });
     h.advance(0.1);
     let darius_hp_damaged = h.health(h.champion);
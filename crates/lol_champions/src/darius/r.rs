@@ -11,13 +11,13 @@ use lol_core::action::dash::{ActionDash, DashMoveType};
 use lol_core::base::buff::{BuffOf, Buffs};
 use lol_core::buffs::cc_debuffs::ImmuneToCC;
 use lol_core::buffs::damage_reduction::BuffDamageReduction;
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType};
-use lol_core::entities::champion::Champion;
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType, Untargetable};
+use lol_core::entities::champion::TargetableAsChampion;
 use lol_core::life::Health;
 use lol_core::movement::{EventMovementEnd, MovementSource};
 use lol_core::skill::{
-    CoolDown, EventSkillCast, Skill, SkillRecastWindow, SkillSlot, get_skill_data_value,
-    get_skill_value,
+    CoolDown, EventSkillCast, Skill, SkillCalcQuery, SkillRecastWindow, SkillSlot,
+    get_skill_data_value, get_skill_value_with,
 };
 use lol_core::team::Team;
 
@@ -39,7 +39,7 @@ pub fn on_darius_r(
     q_skill: Query<&Skill>,
     q_transform: Query<&Transform>,
     q_team: Query<&Team>,
-    q_enemies: Query<(Entity, &Transform), With<Champion>>,
+    q_enemies: Query<(Entity, &Transform), With<TargetableAsChampion>>,
 ) {
     let entity = trigger.event_target();
     if q_darius.get(entity).is_err() {
@@ -91,7 +91,7 @@ pub fn on_darius_r(
     });
 
     // 跃起不可选中
-    commands.entity(entity).insert(ImmuneToCC);
+    commands.entity(entity).insert((ImmuneToCC, Untargetable));
     commands
         .entity(entity)
         .with_related::<BuffOf>(BuffDamageReduction::new(1.0, None));
@@ -127,6 +127,7 @@ pub fn on_darius_r_arrival(
     q_buffs: Query<&Buffs>,
     q_bleed: Query<&BuffDariusBleed>,
     q_damage_reduction: Query<&BuffDamageReduction>,
+    calc: SkillCalcQuery,
 ) {
     if trigger.event().source != MovementSource::Dash {
         return;
@@ -141,7 +142,9 @@ pub fn on_darius_r_arrival(
     let target = pending.target;
 
     // 清除不可选中
-    commands.entity(entity).remove::<ImmuneToCC>();
+    commands
+        .entity(entity)
+        .remove::<(ImmuneToCC, Untargetable)>();
     if let Ok(buffs) = q_buffs.get(entity) {
         for b in buffs.iter() {
             if q_damage_reduction.get(b).is_ok() {
@@ -160,9 +163,15 @@ pub fn on_darius_r_arrival(
         return;
     };
     let ad = q_damage.get(entity).map(|d| d.0).unwrap_or(0.0);
-    let base = get_skill_value(spell_obj, "damage", skill.level, |stat| {
-        if stat == 2 { ad } else { 0.0 }
-    })
+    let base = get_skill_value_with(
+        spell_obj,
+        "damage",
+        skill.level,
+        &calc.caster(entity),
+        |stat| {
+            if stat == 2 { ad } else { 0.0 }
+        },
+    )
     .unwrap_or(0.0);
 
     // 目标出血层数 → 每层 RDamagePercentPerHemoStack
@@ -189,6 +198,7 @@ pub fn on_darius_r_arrival(
         damage_type: DamageType::True,
         amount,
         tag: None,
+        kind: DamageKind::Ability,
     });
 
     // 斜斩命中目标 + 自身溅血粒子
@@ -7,7 +7,7 @@ use lol_core::base::ability_resource::AbilityResource;
 use lol_core::base::buff::Buffs;
 use lol_core::buffs::cc_debuffs::DebuffSlow;
 use lol_core::buffs::on_hit::{BuffOnHitBonusDamage, BuffOnHitCounter, BuffOnHitSlow};
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType};
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType};
 use lol_core::skill::{CoolDown, SkillRecastWindow};
 
 use crate::darius::Darius;
@@ -40,6 +40,7 @@ pub fn darius_hit(h: &mut ChampionTestHarness, enemy: Entity, amount: f32) {
         damage_type: DamageType::Physical,
         amount,
         tag: None,
+        kind: DamageKind::Other,
     });
 }
 
@@ -214,6 +215,7 @@ fn darius_r_resets_on_kill() {
         damage_type: DamageType::True,
         amount: 5950.0,
         tag: None,
+        kind: DamageKind::Other,
     });
     h.advance(0.1);
 
@@ -10,10 +10,12 @@ use lol_base::render_cmd::{CommandAnimationPlay, CommandSkinParticleSpawn};
 use lol_base::spell::Spell;
 use lol_core::attack::{CommandAttackReset, EventAttackEnd};
 use lol_core::base::ability_resource::AbilityResource;
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::{BuffCommandsExt, BuffOf};
 use lol_core::buffs::on_hit::{BuffOnHitBonusDamage, BuffOnHitCounter, BuffOnHitSlow};
 use lol_core::life::Health;
-use lol_core::skill::{CoolDown, EventSkillCast, Skill, SkillSlot, get_skill_value};
+use lol_core::skill::{
+    CoolDown, EventSkillCast, Skill, SkillCalcQuery, SkillSlot, get_skill_value_with,
+};
 
 use crate::darius::Darius;
 use crate::darius::buffs::{DariusWKillPending, DariusWRefundPending};
@@ -24,6 +26,7 @@ pub fn on_darius_w(
     q_darius: Query<(), With<Darius>>,
     q_skill: Query<&Skill>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     if q_darius.get(entity).is_err() {
@@ -58,15 +61,24 @@ pub fn on_darius_w(
 
     // 从 RON 读取总伤害倍率（empowered_attack_damage = total_AD * total_multiplier），
     // 减去 1.0（基础普攻）得到额外伤害比例
-    let total_mult = get_skill_value(spell_obj, "empowered_attack_damage", skill.level, |stat| {
-        if stat == 2 { 1.0 } else { 0.0 }
-    })
+    let total_mult = get_skill_value_with(
+        spell_obj,
+        "empowered_attack_damage",
+        skill.level,
+        &calc.caster(entity),
+        |stat| {
+            if stat == 2 { 1.0 } else { 0.0 }
+        },
+    )
     .unwrap_or(1.5);
     let ratio = total_mult - 1.0;
 
     commands
         .entity(entity)
-        .with_related::<BuffOf>(BuffOnHitCounter::new(1, 1.0))
+        .apply_buff(
+            BuffOnHitCounter::descriptor(1.0).with_source(entity),
+            BuffOnHitCounter::new(1),
+        )
         .with_related::<BuffOf>(BuffOnHitBonusDamage { flat: 0.0, ratio })
         .with_related::<BuffOf>(BuffOnHitSlow {
             percent: 0.5,
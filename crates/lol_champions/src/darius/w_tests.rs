@@ -10,7 +10,7 @@
 
 use bevy::prelude::*;
 use lol_core::attack::EventAttackEnd;
-use lol_core::damage::{CommandDamageCreate, DamageType};
+use lol_core::damage::{CommandDamageCreate, DamageKind, DamageType};
 use lol_core::skill::CoolDown;
 
 use super::tests::{build_headless, give_mana};
@@ -38,6 +38,7 @@ fn darius_w_refunds_mana_on_kill() {
         damage_type: DamageType::True,
         amount: 5980.0,
         tag: None,
+        kind: DamageKind::Other,
     });
     h.advance(0.1);
 
@@ -76,6 +77,7 @@ fn darius_w_reduces_cooldown_on_kill() {
         damage_type: DamageType::True,
         amount: 5980.0,
         tag: None,
+        kind: DamageKind::Other,
     });
     h.advance(0.1);
 
@@ -7,10 +7,22 @@ use lol_core::action::damage::{
     ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
 };
 use lol_core::base::buff::BuffOf;
-use lol_core::buffs::cc_debuffs::DebuffSlow;
-use lol_core::damage::{DamageType, EventDamageCreate};
-use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::buffs::cc_debuffs::DebuffCharm;
+use lol_core::damage::DamageType;
+use lol_core::entities::champion::{Champion, TargetableAsChampion};
+use lol_core::missile::{CommandMissileCreate, EventMissileHitEntity, MissileCollisionTarget};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillSlot, SkillTargetFilter, SkillTargeting, Skills,
+    attach_skill_targeting,
+};
+use lol_core::team::Team;
+
+/// W 魅惑的索敌范围。
+const EVELYNN_W_RANGE: f32 = 1200.0;
+/// W 魅惑时长。
+const EVELYNN_W_CHARM_DURATION: f32 = 1.5;
+/// W 诅咒飞弹速度。
+const EVELYNN_W_MISSILE_SPEED: f32 = 1600.0;
 
 #[derive(Default)]
 pub struct PluginEvelynn;
@@ -19,9 +31,13 @@ impl Plugin for PluginEvelynn {
     fn build(&self, app: &mut App) {
         app.add_observer(on_evelynn_q);
         app.add_observer(on_evelynn_w);
+        app.add_observer(on_evelynn_w_hit);
         app.add_observer(on_evelynn_e);
         app.add_observer(on_evelynn_r);
-        app.add_observer(on_evelynn_damage_hit);
+        app.add_observer(attach_skill_targeting::<Evelynn>(
+            SkillSlot::E,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -78,13 +94,14 @@ fn on_evelynn_q(
 fn on_evelynn_w(
     trigger: On<EventSkillCast>,
     mut commands: Commands,
-    q_evelynn: Query<(), With<Evelynn>>,
+    q_evelynn: Query<(&Transform, &Team), With<Evelynn>>,
+    q_enemies: Query<(Entity, &Transform, &Team), With<TargetableAsChampion>>,
     q_skill: Query<&Skill>,
 ) {
     let entity = trigger.event_target();
-    if q_evelynn.get(entity).is_err() {
+    let Ok((transform, team)) = q_evelynn.get(entity) else {
         return;
-    }
+    };
 
     let Ok(skill) = q_skill.get(trigger.skill_entity) else {
         return;
@@ -99,7 +116,54 @@ fn on_evelynn_w(
         repeat: false,
         duration: None,
     });
-    // W is a charm/slow - handled by damage observer;
+
+    // W 向最近的敌方英雄射出追踪飞弹，命中后魅惑（见 on_evelynn_w_hit）
+    let pos = transform.translation.xz();
+    let target = q_enemies
+        .iter()
+        .filter(|(_, _, enemy_team)| *enemy_team != team)
+        .map(|(enemy, t, _)| (enemy, t.translation.xz().distance(pos)))
+        .filter(|(_, dist)| *dist <= EVELYNN_W_RANGE)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(enemy, _)| enemy);
+    if let Some(target) = target {
+        commands.trigger(CommandMissileCreate {
+            entity,
+            target: Some(target),
+            destination: None,
+            spell: skill.spell.clone(),
+            damage: 0.0,
+            speed: Some(EVELYNN_W_MISSILE_SPEED),
+            particle_key: None,
+            sticky: false,
+            pass_through: false,
+            collision_target: MissileCollisionTarget::EnemyNoDirectDamage,
+            missing_hp_scaling: None,
+        });
+    }
+}
+
+/// W 飞弹命中：魅惑目标。
+fn on_evelynn_w_hit(
+    trigger: On<EventMissileHitEntity>,
+    mut commands: Commands,
+    q_evelynn: Query<&Skills, With<Evelynn>>,
+    q_skill: Query<&Skill>,
+) {
+    let Ok(skills) = q_evelynn.get(trigger.source) else {
+        return;
+    };
+    let is_w = skills.as_slice().iter().any(|&skill_entity| {
+        q_skill
+            .get(skill_entity)
+            .is_ok_and(|skill| skill.slot == SkillSlot::W && skill.spell == trigger.spell)
+    });
+    if !is_w {
+        return;
+    }
+    commands
+        .entity(trigger.target)
+        .with_related::<BuffOf>(DebuffCharm::new(trigger.source, EVELYNN_W_CHARM_DURATION));
 }
 
 fn on_evelynn_e(
@@ -132,9 +196,7 @@ fn on_evelynn_e(
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Nearest {
-                max_distance: 210.0,
-            },
+            shape: DamageShape::unit_or_nearest(trigger.target, 210.0),
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::All,
                 amount: "total_damage".to_string(),
@@ -187,21 +249,3 @@ fn on_evelynn_r(
         }],
     });
 }
-
-fn on_evelynn_damage_hit(
-    trigger: On<EventDamageCreate>,
-    mut commands: Commands,
-    q_evelynn: Query<(), With<Evelynn>>,
-) {
-    let source = trigger.source;
-    if q_evelynn.get(source).is_err() {
-        return;
-    }
-
-    let target = trigger.event_target();
-
-    // W slows then charms
-    commands
-        .entity(target)
-        .with_related::<BuffOf>(DebuffSlow::new(0.45, 2.5));
-}
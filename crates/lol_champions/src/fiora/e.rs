@@ -6,7 +6,7 @@ use lol_base::spell::Spell;
 use lol_core::attack::{BuffAttack, CommandAttackReset, EventAttackEnd};
 use lol_core::base::buff::{Buff, BuffOf, Buffs};
 use lol_core::buffs::cc_debuffs::DebuffSlow;
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType};
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType};
 use lol_core::skill::{EventSkillCast, Skill, SkillSlot, get_skill_data_value};
 
 use crate::fiora::Fiora;
@@ -72,9 +72,6 @@ pub fn on_fiora_e(
         .map(|v| (v - 1.0).max(0.0))
         .unwrap_or(0.5);
 
-    commands.entity(entity).insert(BuffAttack {
-        bonus_attack_speed: as_percent,
-    });
     // E 期间剑身发光（持续型，buff 结束时撤销）：挂到武器骨骼实体上，粒子每帧跟随骨骼
     let mut glow_anchor = Entity::PLACEHOLDER;
     for child in q_children.iter_descendants(entity) {
@@ -96,12 +93,18 @@ pub fn on_fiora_e(
         rotation: None,
         resolver_entity: Some(entity),
     });
-    commands.entity(entity).with_related::<BuffOf>(BuffFioraE {
-        left: 2,
-        crit_bonus_ratio: crit_ratio,
-        timer: Timer::from_seconds(FIORA_E_DURATION, TimerMode::Once),
-        glow_anchor,
-    });
+    // 攻速加成与 E 同挂在一个 buff 实体上，随 buff 销毁撤回
+    commands.entity(entity).with_related::<BuffOf>((
+        BuffFioraE {
+            left: 2,
+            crit_bonus_ratio: crit_ratio,
+            timer: Timer::from_seconds(FIORA_E_DURATION, TimerMode::Once),
+            glow_anchor,
+        },
+        BuffAttack {
+            bonus_attack_speed: as_percent,
+        },
+    ));
     commands.trigger(CommandAttackReset { entity });
 }
 
@@ -164,6 +167,7 @@ pub fn on_event_attack_end(
                         damage_type: DamageType::Physical,
                         amount: bonus,
                         tag: None,
+                        kind: DamageKind::Other,
                     });
                 }
             }
@@ -172,7 +176,6 @@ pub fn on_event_attack_end(
 
         if buff_fiora_e.left <= 0 {
             commands.entity(buff).despawn();
-            commands.entity(entity).remove::<BuffAttack>();
             // 两击耗尽：撤下剑身发光
             let glow_entity = if buff_fiora_e.glow_anchor == Entity::PLACEHOLDER {
                 entity
@@ -188,7 +191,7 @@ pub fn on_event_attack_end(
     }
 }
 
-/// E 计时：到期后移除 buff 与攻速加成。
+/// E 计时：到期后移除 buff（攻速加成随之撤回）。
 pub fn update_fiora_e_buff(
     mut commands: Commands,
     mut q_buff: Query<(Entity, &BuffOf, &mut BuffFioraE)>,
@@ -197,7 +200,6 @@ pub fn update_fiora_e_buff(
     for (buff_entity, buff_of, mut buff) in q_buff.iter_mut() {
         buff.timer.tick(time.delta());
         if buff.timer.is_finished() {
-            commands.entity(buff_of.0).remove::<BuffAttack>();
             commands.entity(buff_entity).despawn();
             // E 到期未用完两击：同样撤下剑身发光
             let glow_entity = if buff.glow_anchor == Entity::PLACEHOLDER {
@@ -7,7 +7,7 @@
 
 use bevy::math::Vec3;
 use bevy::prelude::Entity;
-use lol_core::attack::{BuffAttack, EventAttackEnd};
+use lol_core::attack::{Attack, BuffAttack, EventAttackEnd};
 use lol_core::base::buff::Buffs;
 use lol_core::buffs::cc_debuffs::DebuffSlow;
 
@@ -15,17 +15,23 @@ use super::tests::build_headless;
 use crate::fiora::e::BuffFioraE;
 use crate::test_utils::ChampionTestHarness;
 
+fn bonus_attack_speed(h: &ChampionTestHarness) -> f32 {
+    h.app
+        .world()
+        .get::<Attack>(h.champion)
+        .map_or(0.0, |a| a.bonus_attack_speed)
+}
+
 /// E 应按技能等级赋予额外攻速（ron ASPercent，1 级 = 0.4）。
 #[test]
 fn fiora_e_grants_attack_speed() {
     let mut h = build_headless("fiora_e_as");
     let mana_before = h.mana();
+    let bonus_before = bonus_attack_speed(&h);
     h.cast_skill(2, bevy::math::Vec2::ZERO).advance(0.1);
 
     let as_bonus = h
-        .app
-        .world()
-        .get::<BuffAttack>(h.champion)
+        .buff::<BuffAttack>(h.champion)
         .expect("E 后菲奥娜应有 BuffAttack")
         .bonus_attack_speed;
     assert!(
@@ -33,6 +39,10 @@ fn fiora_e_grants_attack_speed() {
         "E 应按等级赋予 0.4 攻速，实际 {:.3}",
         as_bonus
     );
+    assert!(
+        (bonus_attack_speed(&h) - bonus_before - as_bonus).abs() < 1e-3,
+        "攻速加成应经属性修正层写入额外攻速"
+    );
     assert!(!h.can_cast(2), "E 施放后应进入冷却");
     assert!(h.mana() < mana_before, "E 施放应消耗法力");
 
@@ -106,7 +116,7 @@ fn fiora_e_expires_after_duration() {
     h.cast_skill(2, bevy::math::Vec2::ZERO).advance(0.1);
     assert!(has_e_buff(&h), "E 施法后应立刻挂上 BuffFioraE");
     assert!(
-        h.app.world().get::<BuffAttack>(h.champion).is_some(),
+        h.buff::<BuffAttack>(h.champion).is_some(),
         "E 施法后应有攻速加成"
     );
 
@@ -114,7 +124,7 @@ fn fiora_e_expires_after_duration() {
 
     assert!(!has_e_buff(&h), "E 应在 3s 后过期，移除 BuffFioraE");
     assert!(
-        h.app.world().get::<BuffAttack>(h.champion).is_none(),
+        h.buff::<BuffAttack>(h.champion).is_none(),
         "E 过期后应移除攻速加成"
     );
 
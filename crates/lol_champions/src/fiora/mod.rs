@@ -18,6 +18,7 @@ mod tests;
 mod w_tests;
 
 use bevy::prelude::*;
+use lol_core::base::buff::BuffAppExt;
 use lol_core::entities::champion::Champion;
 
 #[derive(Default)]
@@ -49,6 +50,8 @@ impl Plugin for PluginFiora {
         app.add_observer(r::on_r_damage_create);
         app.add_observer(w::on_fiora_w_parried_cc);
         app.add_observer(w::on_fiora_w_block_damage);
+        app.register_buff_timer::<e::BuffFioraE>(|b| &b.timer, |b| &mut b.timer)
+            .register_buff_timer::<r::BuffFioraR>(|b| &b.remove_timer, |b| &mut b.remove_timer);
     }
 }
 
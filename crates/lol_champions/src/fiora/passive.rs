@@ -7,10 +7,10 @@ use bevy::render::mesh::{Indices, PrimitiveTopology};
 use lol_base::render_cmd::{
     CommandSkinParticleDespawn, CommandSkinParticleSpawn, CommandSkinSoundPlay,
 };
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::{BuffCommandsExt, BuffOf};
 use lol_core::base::direction::{Direction, is_in_direction};
 use lol_core::buffs::common_buffs::{BuffMoveSpeed, BuffSelfHeal};
-use lol_core::damage::{CommandDamageCreate, DamageType, EventDamageCreate};
+use lol_core::damage::{CommandDamageCreate, DamageKind, DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
 use lol_core::life::Health;
 use lol_core::skill::PassiveSkillOf;
@@ -413,6 +413,7 @@ pub fn on_passive_damage_create(
         damage_type: DamageType::True,
         amount: hp.max * 0.05,
         tag: None,
+        kind: DamageKind::Other,
     });
 
     // 击破要害：治疗菲奥娜 + 8% 移速（1.5s），均走通用 buff 原语；
@@ -433,12 +434,10 @@ pub fn on_passive_damage_create(
     commands
         .entity(trigger.source)
         .with_related::<BuffOf>(BuffSelfHeal::new(FIORA_PASSIVE_HEAL));
-    commands
-        .entity(trigger.source)
-        .with_related::<BuffOf>(BuffMoveSpeed::new(
-            FIORA_PASSIVE_MS_PERCENT,
-            FIORA_PASSIVE_MS_DURATION,
-        ));
+    commands.entity(trigger.source).apply_buff(
+        BuffMoveSpeed::descriptor(FIORA_PASSIVE_MS_DURATION).with_source(trigger.source),
+        BuffMoveSpeed::new(FIORA_PASSIVE_MS_PERCENT),
+    );
 }
 
 // ── Vital 扇形视觉指示器 ──
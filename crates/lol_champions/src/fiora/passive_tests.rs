@@ -11,7 +11,7 @@
 use bevy::math::Vec3;
 use bevy::prelude::{Entity, Quat, Transform};
 use lol_core::base::direction::Direction;
-use lol_core::damage::{CommandDamageCreate, DamageType};
+use lol_core::damage::{CommandDamageCreate, DamageKind, DamageType};
 use lol_core::life::Health;
 use lol_core::movement::Movement;
 
@@ -181,6 +181,7 @@ fn fiora_passive_break_grants_move_speed() {
         damage_type: DamageType::Physical,
         amount: 10.0,
         tag: None,
+        kind: DamageKind::Other,
     });
     h.advance(0.2); // 让通用 update_move_speed_buff 应用 bonus
 
@@ -222,6 +223,7 @@ fn fiora_passive_break_heals_fiora() {
         damage_type: DamageType::Physical,
         amount: 100.0,
         tag: None,
+        kind: DamageKind::Other,
     });
     h.advance(0.1);
     let hp_damaged = h.health(h.champion);
@@ -234,6 +236,7 @@ fn fiora_passive_break_heals_fiora() {
         damage_type: DamageType::Physical,
         amount: 10.0,
         tag: None,
+        kind: DamageKind::Other,
     });
     h.advance(0.1);
     let hp_after = h.health(h.champion);
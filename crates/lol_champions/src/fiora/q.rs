@@ -12,12 +12,13 @@ use lol_core::action::dash::{ActionDash, DashMoveType};
 use lol_core::attack::CommandAttackReset;
 use lol_core::base::bounding::Bounding;
 use lol_core::base::direction::is_in_direction;
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType};
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType};
 use lol_core::entities::champion::Champion;
 use lol_core::life::Death;
 use lol_core::movement::{EventMovementEnd, MovementSource};
 use lol_core::skill::{
-    CoolDown, EventSkillCast, Skill, SkillSlot, get_skill_data_value, get_skill_value,
+    CoolDown, EventSkillCast, Skill, SkillCalcQuery, SkillSlot, get_skill_data_value,
+    get_skill_value_with,
 };
 use lol_core::team::Team;
 
@@ -119,6 +120,7 @@ pub fn on_fiora_q_dash_end(
         Without<Death>,
     >,
     mut q_cooldown: Query<&mut CoolDown>,
+    calc: SkillCalcQuery,
 ) {
     if trigger.source != MovementSource::Dash {
         return;
@@ -171,9 +173,15 @@ pub fn on_fiora_q_dash_end(
         let multiplier = if vital_hit { FIORA_Q_VITAL_MULT } else { 1.0 };
 
         if let Some(spell_object) = res_assets_spell_object.get(&pending.skill) {
-            let amount = get_skill_value(spell_object, FIORA_Q_DAMAGE_KEY, pending.level, |stat| {
-                if stat == 2 { damage.0 } else { 0.0 }
-            })
+            let amount = get_skill_value_with(
+                spell_object,
+                FIORA_Q_DAMAGE_KEY,
+                pending.level,
+                &calc.caster(entity),
+                |stat| {
+                    if stat == 2 { damage.0 } else { 0.0 }
+                },
+            )
             .unwrap_or(0.0)
                 * multiplier;
 
@@ -183,6 +191,7 @@ pub fn on_fiora_q_dash_end(
                 damage_type: DamageType::Physical,
                 amount,
                 tag: None,
+                kind: DamageKind::Ability,
             });
 
             // 戳刺命中特效：键在菲奥娜的 resolver 里，挂到受击目标身上
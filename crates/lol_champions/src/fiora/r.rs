@@ -12,11 +12,12 @@ use lol_base::render_cmd::{
     CommandSkinParticleDespawn, CommandSkinParticleSpawn, CommandSkinSoundPlay,
 };
 use lol_base::spell::Spell;
-use lol_core::base::buff::{Buff, BuffOf};
+use lol_core::base::buff::{Buff, BuffCommandsExt, BuffOf};
 use lol_core::base::direction::{Direction, is_in_direction};
 use lol_core::buffs::common_buffs::BuffMoveSpeed;
-use lol_core::damage::{CommandDamageCreate, DamageType, EventDamageCreate};
-use lol_core::entities::champion::Champion;
+use lol_core::damage::{CommandDamageCreate, DamageKind, DamageType, EventDamageCreate};
+use lol_core::entities::champion::TargetableAsChampion;
+use lol_core::heal::{CommandHeal, HealType};
 use lol_core::life::{Death, Health};
 use lol_core::skill::{EventSkillCast, Skill, SkillSlot, get_skill_data_value};
 use lol_core::team::Team;
@@ -189,21 +190,26 @@ pub fn fixed_update(
 pub fn update_fiora_r_heal(
     mut commands: Commands,
     mut q_heal: Query<(Entity, &BuffOf, &mut BuffFioraRHeal)>,
-    mut q_allies: Query<(Entity, &Transform, &Team, &mut Health), Without<Death>>,
+    q_allies: Query<(Entity, &Transform, &Team), (With<Health>, Without<Death>)>,
     time: Res<Time<Fixed>>,
 ) {
     for (buff_entity, buff_of, mut heal) in q_heal.iter_mut() {
         heal.timer.tick(time.delta());
         heal.tick.tick(time.delta());
         if heal.tick.just_finished() {
-            for (ally, transform, team, mut hp) in q_allies.iter_mut() {
+            for (ally, transform, team) in q_allies.iter() {
                 if team != &heal.team {
                     continue;
                 }
                 if transform.translation.distance(heal.center) > heal.heal_radius {
                     continue;
                 }
-                hp.value = (hp.value + heal.heal_per_second).min(hp.max);
+                commands.trigger(CommandHeal {
+                    entity: ally,
+                    source: buff_of.0,
+                    heal_type: HealType::Heal,
+                    amount: heal.heal_per_second,
+                });
                 // 每跳在受疗盟友身上播治疗粒子（键在菲奥娜 resolver 里）
                 commands.trigger(CommandSkinParticleSpawn {
                     entity: ally,
@@ -226,7 +232,7 @@ pub fn on_fiora_r(
     q_skill: Query<&Skill>,
     q_transform: Query<&Transform>,
     q_team: Query<&Team>,
-    q_targets: Query<(Entity, &Transform, &Team), (With<Champion>, Without<Death>)>,
+    q_targets: Query<(Entity, &Transform, &Team), (With<TargetableAsChampion>, Without<Death>)>,
     q_vital: Query<&Vital>,
     res_spells: Res<Assets<Spell>>,
 ) {
@@ -321,9 +327,10 @@ pub fn on_fiora_r(
         key: "FioraRMark_OnBuffActivate".to_string(),
     });
 
-    commands
-        .entity(entity)
-        .with_related::<BuffOf>(BuffMoveSpeed::new(ms_percent, duration));
+    commands.entity(entity).apply_buff(
+        BuffMoveSpeed::descriptor(duration).with_source(entity),
+        BuffMoveSpeed::new(ms_percent),
+    );
 }
 
 /// 监听伤害事件：从匹配方向击破一个 R 要害并造成最大生命值真实伤害；
@@ -413,6 +420,7 @@ pub fn on_r_damage_create(
             damage_type: DamageType::True,
             amount: true_damage,
             tag: None,
+            kind: DamageKind::Other,
         });
 
     let all_broken = buff_fiora_r.vitals.is_empty();
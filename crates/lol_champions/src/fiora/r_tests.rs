@@ -10,7 +10,7 @@ use bevy::math::{Vec2, Vec3};
 use bevy::prelude::{Entity, Transform};
 use lol_core::base::buff::{BuffOf, Buffs};
 use lol_core::base::direction::Direction;
-use lol_core::damage::{CommandDamageCreate, DamageType};
+use lol_core::damage::{CommandDamageCreate, DamageKind, DamageType};
 
 use super::tests::build_headless;
 use crate::fiora::passive::Vital;
@@ -65,6 +65,7 @@ fn fiora_r_vital_break_deals_true_damage() {
         damage_type: DamageType::Physical,
         amount: 10.0,
         tag: None,
+        kind: DamageKind::Other,
     });
     h.advance(0.1);
 
@@ -135,6 +136,7 @@ fn fiora_r_target_death_triggers_heal_aura() {
         damage_type: DamageType::Physical,
         amount: hp_before + 1000.0,
         tag: None,
+        kind: DamageKind::Other,
     });
     h.advance(0.1);
 
@@ -200,6 +202,7 @@ fn damage_from(h: &mut ChampionTestHarness, enemy: Entity, fiora_pos: Vec3) {
         damage_type: DamageType::Physical,
         amount: 10.0,
         tag: None,
+        kind: DamageKind::Other,
     });
     h.advance(0.1);
 }
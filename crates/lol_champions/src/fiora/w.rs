@@ -18,8 +18,8 @@ use lol_core::base::buff::{Buff, BuffOf};
 use lol_core::buffs::cc_debuffs::{ControlTag, DebuffSlow, DebuffStun, ImmuneToCC};
 use lol_core::buffs::common_buffs::BuffCastBlock;
 use lol_core::buffs::damage_reduction::BuffDamageReduction;
-use lol_core::damage::{CommandDamageCreate, DamageType, EventDamageCreate};
-use lol_core::entities::champion::Champion;
+use lol_core::damage::{CommandDamageCreate, DamageKind, DamageType, EventDamageCreate};
+use lol_core::entities::champion::TargetableAsChampion;
 use lol_core::life::Death;
 use lol_core::skill::{EventSkillCast, Skill, SkillSlot, get_skill_data_value};
 use lol_core::team::Team;
@@ -149,7 +149,7 @@ pub fn update_fiora_w(
     q_cast_block: Query<&BuffCastBlock>,
     q_transform: Query<&Transform>,
     q_team: Query<&Team>,
-    q_targets: Query<(Entity, &Transform, &Team), (With<Champion>, Without<Death>)>,
+    q_targets: Query<(Entity, &Transform, &Team), (With<TargetableAsChampion>, Without<Death>)>,
     time: Res<Time<Fixed>>,
 ) {
     for (buff_entity, buff_of, mut buff) in q_buff.iter_mut() {
@@ -196,7 +196,7 @@ fn counter_thrust(
     buff: &BuffFioraW,
     q_transform: &Query<&Transform>,
     q_team: &Query<&Team>,
-    q_targets: &Query<(Entity, &Transform, &Team), (With<Champion>, Without<Death>)>,
+    q_targets: &Query<(Entity, &Transform, &Team), (With<TargetableAsChampion>, Without<Death>)>,
 ) {
     let Ok(caster_tf) = q_transform.get(caster) else {
         return;
@@ -279,6 +279,7 @@ fn counter_thrust(
         damage_type: DamageType::Magic,
         amount: buff.stab_damage,
         tag: None,
+        kind: DamageKind::Ability,
     });
 
     if buff.parried_hard_cc {
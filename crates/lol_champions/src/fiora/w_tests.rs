@@ -8,7 +8,7 @@
 use bevy::math::{Vec2, Vec3};
 use lol_core::base::buff::{BuffOf, Buffs};
 use lol_core::buffs::cc_debuffs::{DebuffSlow, DebuffStun};
-use lol_core::damage::{CommandDamageCreate, DamageType};
+use lol_core::damage::{CommandDamageCreate, DamageKind, DamageType};
 
 use crate::fiora::tests::build_headless;
 use crate::fiora::w::BuffFioraW;
@@ -57,6 +57,7 @@ fn fiora_w_true_damage_penetrates_parry() {
         damage_type: DamageType::True,
         amount: 100.0,
         tag: None,
+        kind: DamageKind::Other,
     });
     h.advance(0.1);
 
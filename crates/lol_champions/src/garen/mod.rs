@@ -9,6 +9,7 @@ mod tests;
 
 use bevy::prelude::*;
 use lol_core::entities::champion::Champion;
+use lol_core::skill::{SkillSlot, SkillTargetFilter, SkillTargeting, attach_skill_targeting};
 
 use crate::garen::e::on_garen_e;
 use crate::garen::passive::on_garen_attack_end_silence;
@@ -26,6 +27,10 @@ impl Plugin for PluginGaren {
         app.add_observer(on_garen_e);
         app.add_observer(on_garen_r);
         app.add_observer(on_garen_attack_end_silence);
+        app.add_observer(attach_skill_targeting::<Garen>(
+            SkillSlot::R,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -1,6 +1,6 @@
 //! Garen R - 德玛西亚正义 (Demacian Justice)
 //!
-//! 指向性斩杀技能，对最近敌方英雄造成物理伤害。
+//! 指向性斩杀技能，对点选的敌方英雄（未点选时取最近者）造成物理伤害。
 
 use bevy::prelude::*;
 use lol_base::animation_names::ANIM_SPELL4;
@@ -44,9 +44,7 @@ pub fn on_garen_r(
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Nearest {
-                max_distance: 400.0,
-            },
+            shape: DamageShape::unit_or_nearest(trigger.target, 400.0),
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::Champion,
                 amount: "total_damage".to_string(),
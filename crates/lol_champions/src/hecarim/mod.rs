@@ -7,7 +7,7 @@ use lol_core::action::damage::{
     ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
 };
 use lol_core::action::dash::{ActionDash, DashMoveType};
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::{BuffCommandsExt, BuffOf};
 use lol_core::buffs::cc_debuffs::DebuffFear;
 use lol_core::buffs::common_buffs::{BuffMoveSpeed, BuffSelfHeal};
 use lol_core::damage::{DamageType, EventDamageCreate};
@@ -168,9 +168,10 @@ fn on_hecarim_e(
     });
     // E is movement speed boost + knockback on contact
     // Movement speed buff with knockback on collision
-    commands
-        .entity(entity)
-        .with_related::<BuffOf>(BuffMoveSpeed::new(0.75, 4.0));
+    commands.entity(entity).apply_buff(
+        BuffMoveSpeed::descriptor(4.0).with_source(entity),
+        BuffMoveSpeed::new(0.75),
+    );
 }
 
 fn on_hecarim_r(
@@ -230,7 +231,7 @@ fn on_hecarim_damage_hit(
     // R 命中施加恐惧
     commands
         .entity(target)
-        .with_related::<BuffOf>(DebuffFear::new(1.5));
+        .with_related::<BuffOf>(DebuffFear::new(1.5).with_source(source));
 }
 
 /// Hecarim E 冲刺结束时推开最近目标
@@ -5,10 +5,11 @@ use bevy::time::{Timer, TimerMode};
 use lol_base::animation_names::ANIM_SPELL3;
 use lol_base::render_cmd::{CommandAnimationPlay, CommandSkinParticleSpawn};
 use lol_base::spell::Spell;
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType};
-use lol_core::entities::champion::Champion;
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType};
+use lol_core::entities::champion::TargetableAsChampion;
 use lol_core::skill::{
-    CoolDown, EventSkillCast, Skill, SkillRecastWindow, SkillSlot, get_skill_value,
+    CoolDown, EventSkillCast, Skill, SkillCalcQuery, SkillRecastWindow, SkillSlot,
+    get_skill_value_with,
 };
 use lol_core::team::Team;
 
@@ -22,9 +23,10 @@ pub fn on_irelia_e(
     mut commands: Commands,
     q_irelia: Query<&Team, With<crate::irelia::Irelia>>,
     q_skill: Query<(&Skill, &CoolDown, Option<&SkillRecastWindow>)>,
-    q_enemies: Query<(Entity, &Transform, &Team), With<Champion>>,
+    q_enemies: Query<(Entity, &Transform, &Team), With<TargetableAsChampion>>,
     q_damage: Query<&Damage>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     let Ok(team) = q_irelia.get(entity) else {
@@ -68,9 +70,15 @@ pub fn on_irelia_e(
         return;
     }
 
-    let amount = get_skill_value(spell, "total_damage", skill.level, |stat| {
-        if stat == 2 { ad } else { 0.0 }
-    })
+    let amount = get_skill_value_with(
+        spell,
+        "total_damage",
+        skill.level,
+        &calc.caster(entity),
+        |stat| {
+            if stat == 2 { ad } else { 0.0 }
+        },
+    )
     .unwrap_or(0.0);
 
     for (target, tf, t) in q_enemies.iter() {
@@ -86,6 +94,7 @@ pub fn on_irelia_e(
             damage_type: DamageType::Magic,
             amount,
             tag: Some(IRELIA_E2_DAMAGE_TAG),
+            kind: DamageKind::Ability,
         });
     }
 
@@ -4,7 +4,8 @@
 //! 满层时普攻命中附带额外魔法伤害（20% AD）。
 //!
 //! 层数由 `EventSkillCast` 驱动（与锐雯被动一致），刷新持续时间。
-//! 攻速由通用 `BuffAttack` 承载，满层额外伤害由 `EventAttackEnd` 观察者直接结算。
+//! 攻速由通用 `BuffAttack` 承载（与层数同挂在被动 buff 实体上），
+//! 满层额外伤害由 `EventAttackEnd` 观察者直接结算。
 
 use bevy::prelude::*;
 use bevy::time::{Timer, TimerMode};
@@ -12,7 +13,7 @@ use lol_base::render_cmd::{CommandSkinParticleDespawn, CommandSkinParticleSpawn}
 use lol_core::attack::{BuffAttack, EventAttackEnd};
 use lol_core::base::buff::{Buff, BuffOf, Buffs};
 use lol_core::buffs::cc_debuffs::{DebuffSlow, DebuffStun};
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType, EventDamageCreate};
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType, EventDamageCreate};
 use lol_core::skill::EventSkillCast;
 
 use crate::irelia::Irelia;
@@ -57,38 +58,36 @@ pub fn on_irelia_skill_cast_stack_passive(
 ) {
     let caster = trigger.event().entity;
 
-    let mut new_charges: Option<u8> = None;
-
     // 先尝试在已有 buff 中找被动并叠加（首次施法时角色可能尚无 Buffs 组件）
-    if let Ok(buffs) = q_irelia.get(caster) {
-        for buff_entity in buffs.iter() {
-            if let Ok(mut fervor) = q_fervor.get_mut(buff_entity) {
-                fervor.charges = (fervor.charges + 1).min(IRELIA_FERVOR_MAX_STACKS);
-                fervor.timer = fresh_timer();
-                new_charges = Some(fervor.charges);
-                break;
-            }
-        }
-    }
+    let existing = q_irelia.get(caster).ok().and_then(|buffs| {
+        buffs.iter().find_map(|buff_entity| {
+            let mut fervor = q_fervor.get_mut(buff_entity).ok()?;
+            fervor.charges = (fervor.charges + 1).min(IRELIA_FERVOR_MAX_STACKS);
+            fervor.timer = fresh_timer();
+            Some((buff_entity, fervor.charges))
+        })
+    });
 
-    // 无被动 buff：新建 1 层
-    let charges = match new_charges {
-        Some(c) => c,
+    // 攻速每层 8%，与层数同挂在被动 buff 实体上
+    match existing {
+        Some((buff_entity, charges)) => {
+            commands.entity(buff_entity).insert(BuffAttack {
+                bonus_attack_speed: charges as f32 * IRELIA_FERVOR_AS_PER_STACK,
+            });
+        }
+        // 无被动 buff：新建 1 层
         None => {
-            commands
-                .entity(caster)
-                .with_related::<BuffOf>(BuffIreliaFervor {
+            commands.entity(caster).with_related::<BuffOf>((
+                BuffIreliaFervor {
                     charges: 1,
                     timer: fresh_timer(),
-                });
-            1
+                },
+                BuffAttack {
+                    bonus_attack_speed: IRELIA_FERVOR_AS_PER_STACK,
+                },
+            ));
         }
-    };
-
-    // 同步攻速：每层 8%
-    commands.entity(caster).insert(BuffAttack {
-        bonus_attack_speed: charges as f32 * IRELIA_FERVOR_AS_PER_STACK,
-    });
+    }
 }
 
 /// 满层（4 层）时，普攻命中附带 20% AD 额外魔法伤害。
@@ -124,6 +123,7 @@ pub fn on_irelia_passive_attack_end(
         damage_type: DamageType::Magic,
         amount: bonus,
         tag: None,
+        kind: DamageKind::Other,
     });
 }
 
@@ -201,20 +201,13 @@ pub fn on_irelia_damage_hit(
 pub fn update_irelia_fervor(
     mut commands: Commands,
     time: Res<Time<Fixed>>,
-    mut q_fervor: Query<(Entity, &mut BuffIreliaFervor, &BuffOf)>,
+    mut q_fervor: Query<(Entity, &mut BuffIreliaFervor)>,
 ) {
-    let mut expired = Vec::new();
-    for (entity, mut fervor, _) in q_fervor.iter_mut() {
+    for (entity, mut fervor) in q_fervor.iter_mut() {
         fervor.timer.tick(time.delta());
         if fervor.timer.is_finished() {
-            expired.push(entity);
-        }
-    }
-    for entity in expired {
-        // 到期：回收攻速并销毁层数 buff
-        if let Ok((_, _, bo)) = q_fervor.get(entity) {
-            commands.entity(bo.0).remove::<BuffAttack>();
+            // 到期：销毁层数 buff，攻速随之回收
+            commands.entity(entity).despawn();
         }
-        commands.entity(entity).despawn();
     }
 }
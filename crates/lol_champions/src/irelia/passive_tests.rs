@@ -18,7 +18,7 @@ fn irelia_passive_stacks_on_cast() {
 
     assert_eq!(fervor_charges(&h), Some(1), "一次施法应叠加 1 层热诚");
 
-    let as_buff = h.app.world().get::<BuffAttack>(h.champion);
+    let as_buff = h.buff::<BuffAttack>(h.champion);
     assert!(as_buff.is_some(), "应有 BuffAttack 攻速加成");
     assert!(
         (as_buff.unwrap().bonus_attack_speed - 0.08).abs() < 1e-4,
@@ -80,7 +80,7 @@ fn irelia_passive_expires_after_duration() {
     h.advance(6.5); // > 6s 持续时间
     assert_eq!(fervor_charges(&h), None, "6s 后热诚应过期清除");
     assert!(
-        h.app.world().get::<BuffAttack>(h.champion).is_none(),
+        h.buff::<BuffAttack>(h.champion).is_none(),
         "热诚过期后攻速加成应移除"
     );
     h.finish();
@@ -7,9 +7,11 @@ use lol_base::spell::Spell;
 use lol_core::action::dash::{ActionDash, DashMoveType};
 use lol_core::attack::CommandAttackReset;
 use lol_core::base::buff::Buffs;
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType};
-use lol_core::entities::champion::Champion;
-use lol_core::skill::{CoolDown, EventSkillCast, Skill, SkillSlot, get_skill_value};
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType};
+use lol_core::entities::champion::TargetableAsChampion;
+use lol_core::skill::{
+    CoolDown, EventSkillCast, Skill, SkillCalcQuery, SkillSlot, get_skill_value_with,
+};
 use lol_core::team::Team;
 
 use crate::irelia::IRELIA_Q_DAMAGE_TAG;
@@ -24,11 +26,12 @@ pub fn on_irelia_q(
     mut commands: Commands,
     q_irelia: Query<&Team, With<crate::irelia::Irelia>>,
     q_skill: Query<(&Skill, &CoolDown)>,
-    q_enemies: Query<(Entity, &Transform, &Team), With<Champion>>,
+    q_enemies: Query<(Entity, &Transform, &Team), With<TargetableAsChampion>>,
     q_buffs: Query<&Buffs>,
     q_unsteady: Query<&DebuffIreliaUnsteady>,
     q_damage: Query<&Damage>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     let Ok(team) = q_irelia.get(entity) else {
@@ -73,9 +76,15 @@ pub fn on_irelia_q(
         });
 
     if let Some((target, _, _)) = nearest {
-        let amount = get_skill_value(spell, "champion_damage", skill.level, |stat| {
-            if stat == 2 { ad } else { 0.0 }
-        })
+        let amount = get_skill_value_with(
+            spell,
+            "champion_damage",
+            skill.level,
+            &calc.caster(entity),
+            |stat| {
+                if stat == 2 { ad } else { 0.0 }
+            },
+        )
         .unwrap_or(0.0);
 
         commands.entity(target).trigger(|e| CommandDamageCreate {
@@ -84,6 +93,7 @@ pub fn on_irelia_q(
             damage_type: DamageType::Physical,
             amount,
             tag: Some(IRELIA_Q_DAMAGE_TAG),
+            kind: DamageKind::Ability,
         });
 
         let is_unsteady = q_buffs
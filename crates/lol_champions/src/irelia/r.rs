@@ -4,9 +4,9 @@ use bevy::prelude::*;
 use lol_base::animation_names::ANIM_SPELL4;
 use lol_base::render_cmd::{CommandAnimationPlay, CommandSkinParticleSpawn};
 use lol_base::spell::Spell;
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType};
-use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot, get_skill_value};
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType};
+use lol_core::entities::champion::TargetableAsChampion;
+use lol_core::skill::{EventSkillCast, Skill, SkillCalcQuery, SkillSlot, get_skill_value_with};
 use lol_core::team::Team;
 
 use crate::irelia::IRELIA_R_DAMAGE_TAG;
@@ -18,9 +18,10 @@ pub fn on_irelia_r(
     mut commands: Commands,
     q_irelia: Query<&Team, With<crate::irelia::Irelia>>,
     q_skill: Query<&Skill>,
-    q_enemies: Query<(Entity, &Transform, &Team), With<Champion>>,
+    q_enemies: Query<(Entity, &Transform, &Team), With<TargetableAsChampion>>,
     q_damage: Query<&Damage>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     let Ok(team) = q_irelia.get(entity) else {
@@ -52,9 +53,15 @@ pub fn on_irelia_r(
         resolver_entity: None,
     });
 
-    let amount = get_skill_value(spell, "missile_damage", skill.level, |stat| {
-        if stat == 2 { ad } else { 0.0 }
-    })
+    let amount = get_skill_value_with(
+        spell,
+        "missile_damage",
+        skill.level,
+        &calc.caster(entity),
+        |stat| {
+            if stat == 2 { ad } else { 0.0 }
+        },
+    )
     .unwrap_or(0.0);
 
     for (target, tf, t) in q_enemies.iter() {
@@ -70,6 +77,7 @@ pub fn on_irelia_r(
             damage_type: DamageType::Magic,
             amount,
             tag: Some(IRELIA_R_DAMAGE_TAG),
+            kind: DamageKind::Ability,
         });
     }
 }
@@ -2,7 +2,7 @@
 
 use bevy::prelude::*;
 use lol_core::base::buff::Buffs;
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType};
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType};
 
 use crate::irelia::Irelia;
 use crate::irelia::passive::BuffIreliaFervor;
@@ -61,5 +61,6 @@ pub fn apply_magic_damage(h: &mut ChampionTestHarness, source: Entity, amount: f
         damage_type: DamageType::Magic,
         amount,
         tag: None,
+        kind: DamageKind::Other,
     });
 }
@@ -9,10 +9,11 @@ use lol_base::render_cmd::{
 use lol_base::spell::Spell;
 use lol_core::base::buff::{Buff, BuffOf, Buffs};
 use lol_core::buffs::damage_reduction::BuffDamageReduction;
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType};
-use lol_core::entities::champion::Champion;
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType};
+use lol_core::entities::champion::TargetableAsChampion;
 use lol_core::skill::{
-    CoolDown, EventSkillCast, Skill, SkillRecastWindow, SkillSlot, get_skill_value,
+    CoolDown, EventSkillCast, Skill, SkillCalcQuery, SkillRecastWindow, SkillSlot,
+    get_skill_value_with,
 };
 use lol_core::team::Team;
 
@@ -63,12 +64,13 @@ pub fn on_irelia_w(
     mut commands: Commands,
     q_irelia: Query<&Team, With<Irelia>>,
     q_skill: Query<(&Skill, &CoolDown, Option<&SkillRecastWindow>)>,
-    q_enemies: Query<(Entity, &Transform, &Team), With<Champion>>,
+    q_enemies: Query<(Entity, &Transform, &Team), With<TargetableAsChampion>>,
     q_buffs: Query<&Buffs>,
     q_w: Query<&BuffIreliaW>,
     q_dr: Query<&BuffDamageReduction>,
     q_damage: Query<&Damage>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     let Ok(team) = q_irelia.get(entity) else {
@@ -139,8 +141,11 @@ pub fn on_irelia_w(
     });
 
     let stat_getter = |stat: u8| if stat == 2 { ad } else { 0.0 };
-    let min = get_skill_value(spell, "min_damage_calc", skill.level, stat_getter).unwrap_or(0.0);
-    let max = get_skill_value(spell, "max_damage_calc", skill.level, stat_getter).unwrap_or(0.0);
+    let caster = calc.caster(entity);
+    let min = get_skill_value_with(spell, "min_damage_calc", skill.level, &caster, stat_getter)
+        .unwrap_or(0.0);
+    let max = get_skill_value_with(spell, "max_damage_calc", skill.level, &caster, stat_getter)
+        .unwrap_or(0.0);
     let amount = min + frac * (max - min);
 
     for (target, tf, t) in q_enemies.iter() {
@@ -162,6 +167,7 @@ pub fn on_irelia_w(
             damage_type: DamageType::Physical,
             amount,
             tag: None,
+            kind: DamageKind::Ability,
         });
     }
 
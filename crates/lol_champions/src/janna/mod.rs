@@ -10,7 +10,9 @@ use lol_core::base::buff::BuffOf;
 use lol_core::buffs::cc_debuffs::DebuffSlow;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillSlot, SkillTargetFilter, SkillTargeting, attach_skill_targeting,
+};
 
 use crate::janna::buffs::BuffJannaPassive;
 
@@ -24,6 +26,10 @@ impl Plugin for PluginJanna {
         app.add_observer(on_janna_e);
         app.add_observer(on_janna_r);
         app.add_observer(on_janna_damage_hit);
+        app.add_observer(attach_skill_targeting::<Janna>(
+            SkillSlot::W,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -107,9 +113,7 @@ fn on_janna_w(
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Nearest {
-                max_distance: 550.0,
-            },
+            shape: DamageShape::unit_or_nearest(trigger.target, 550.0),
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::All,
                 amount: "total_damage".to_string(),
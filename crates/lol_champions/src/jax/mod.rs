@@ -8,7 +8,7 @@ use lol_core::action::damage::{
 };
 use lol_core::action::dash::{ActionDash, DashMoveType};
 use lol_core::attack::CommandAttackReset;
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::{BuffCommandsExt, BuffOf};
 use lol_core::buffs::common_buffs::BuffResist;
 use lol_core::buffs::on_hit::{BuffOnHitBonusDamage, BuffOnHitCounter};
 use lol_core::damage::DamageType;
@@ -118,7 +118,10 @@ fn on_jax_w(
     commands.trigger(CommandAttackReset { entity });
     commands
         .entity(entity)
-        .with_related::<BuffOf>(BuffOnHitCounter::new(1, 1.0))
+        .apply_buff(
+            BuffOnHitCounter::descriptor(1.0).with_source(entity),
+            BuffOnHitCounter::new(1),
+        )
         .with_related::<BuffOf>(BuffOnHitBonusDamage {
             flat: 50.0,
             ratio: 0.0,
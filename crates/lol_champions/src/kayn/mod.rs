@@ -7,7 +7,7 @@ use lol_core::action::damage::{
     ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
 };
 use lol_core::action::dash::{ActionDash, DashMoveType};
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::{BuffCommandsExt, BuffOf};
 use lol_core::buffs::cc_debuffs::DebuffSlow;
 use lol_core::buffs::common_buffs::BuffMoveSpeed;
 use lol_core::damage::{DamageType, EventDamageCreate};
@@ -154,9 +154,10 @@ fn on_kayn_e(
     });
     // E is a ghost-like dash that allows passing through terrain
     // Movement speed buff
-    commands
-        .entity(entity)
-        .with_related::<BuffOf>(BuffMoveSpeed::new(0.4, 1.5));
+    commands.entity(entity).apply_buff(
+        BuffMoveSpeed::descriptor(1.5).with_source(entity),
+        BuffMoveSpeed::new(0.4),
+    );
 }
 
 fn on_kayn_r(
@@ -6,12 +6,21 @@ use lol_base::render_cmd::CommandAnimationPlay;
 use lol_core::action::damage::{
     ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
 };
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::{BuffOf, Buffs};
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
+use lol_core::entities::pet::{CommandCloneSpawn, PetAi, PetClone};
+use lol_core::life::Health;
 use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
 
-use crate::leblanc::buffs::{BuffLeBlancE, BuffLeBlancQ};
+use crate::leblanc::buffs::{BuffLeBlancE, BuffLeBlancMirrorImage, BuffLeBlancQ};
+
+/// 被动触发的生命值比例
+const LEBLANC_PASSIVE_HEALTH_RATIO: f32 = 0.4;
+/// 被动分身持续时间（秒）
+const LEBLANC_PASSIVE_CLONE_DURATION: f32 = 8.0;
+/// 被动冷却（秒），期间乐芙兰身上挂着镜像 buff
+const LEBLANC_PASSIVE_COOLDOWN: f32 = 60.0;
 
 #[derive(Default)]
 pub struct PluginLeBlanc;
@@ -23,6 +32,8 @@ impl Plugin for PluginLeBlanc {
         app.add_observer(on_leblanc_e);
         app.add_observer(on_leblanc_r);
         app.add_observer(on_leblanc_damage_hit);
+        app.add_observer(on_leblanc_mirror_image);
+        app.add_systems(FixedUpdate, update_leblanc_mirror_image);
     }
 }
 
@@ -238,3 +249,53 @@ fn on_leblanc_damage_hit(
         .entity(target)
         .with_related::<BuffOf>(BuffLeBlancE::new(80.0, 1.5, 3.0));
 }
+
+/// 被动：生命值首次跌破 40% 时放出一个不造成伤害的分身。
+fn on_leblanc_mirror_image(
+    trigger: On<EventDamageCreate>,
+    mut commands: Commands,
+    q_leblanc: Query<(&Health, &Transform, Option<&Buffs>), With<LeBlanc>>,
+    q_mirror_image: Query<(), With<BuffLeBlancMirrorImage>>,
+) {
+    let entity = trigger.event_target();
+    let Ok((health, transform, buffs)) = q_leblanc.get(entity) else {
+        return;
+    };
+    if health.value <= 0.0 {
+        return;
+    }
+    let threshold = health.max * LEBLANC_PASSIVE_HEALTH_RATIO;
+    let health_before = health.value + trigger.damage_result.final_damage;
+    if health.value >= threshold || health_before < threshold {
+        return;
+    }
+    if buffs.is_some_and(|buffs| buffs.iter().any(|b| q_mirror_image.contains(*b))) {
+        return;
+    }
+
+    commands
+        .entity(entity)
+        .with_related::<BuffOf>(BuffLeBlancMirrorImage::new(LEBLANC_PASSIVE_COOLDOWN));
+    commands.trigger(CommandCloneSpawn {
+        entity,
+        position: transform.translation.xz(),
+        duration: LEBLANC_PASSIVE_CLONE_DURATION,
+        pet_clone: PetClone::new(0.0, 1.0),
+        ai: PetAi::controllable(),
+    });
+}
+
+/// 镜像 buff 计时，过期后被动可再次触发。
+fn update_leblanc_mirror_image(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    mut q: Query<(Entity, &mut BuffLeBlancMirrorImage)>,
+) {
+    let delta = time.delta();
+    for (entity, mut buff) in q.iter_mut() {
+        buff.timer.tick(delta);
+        if buff.timer.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -12,7 +12,11 @@ use lol_core::base::buff::BuffOf;
 use lol_core::buffs::cc_debuffs::DebuffSlow;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{CoolDown, EventSkillCast, Skill, SkillRecastWindow, SkillSlot};
+use lol_core::heal::Vamp;
+use lol_core::skill::{
+    CoolDown, EventSkillCast, Skill, SkillRecastWindow, SkillSlot, SkillTargetFilter,
+    SkillTargeting, attach_skill_targeting,
+};
 
 use crate::leesin::buffs::BuffLeeSinIronWill;
 
@@ -28,6 +32,11 @@ impl Plugin for PluginLeeSin {
         app.add_observer(on_leesin_e);
         app.add_observer(on_leesin_r);
         app.add_observer(on_leesin_damage_hit);
+        app.add_systems(FixedUpdate, update_leesin_iron_will);
+        app.add_observer(attach_skill_targeting::<LeeSin>(
+            SkillSlot::R,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -36,6 +45,19 @@ impl Plugin for PluginLeeSin {
 #[reflect(Component)]
 pub struct LeeSin;
 
+/// 铁意计时，到期销毁（吸血随之失效）
+fn update_leesin_iron_will(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    mut q: Query<(Entity, &mut BuffLeeSinIronWill)>,
+) {
+    for (entity, mut buff) in q.iter_mut() {
+        if buff.tick(time.delta_secs()) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
 /// 标记李青当前释放的技能，用于伤害命中 observer 判断是哪个技能命中
 /// stage: 1=E1, 2=E2, 3=R
 #[derive(Component, Debug, Clone)]
@@ -164,9 +186,14 @@ fn on_leesin_w(
             .insert(SkillRecastWindow::new(2, 2, LEESIN_RECAST_WINDOW));
     } else {
         // Second cast: Iron Will - lifesteal and attack speed buff
+        let iron_will = BuffLeeSinIronWill::new(0.1, 0.1, 4.0);
+        let vamp = Vamp {
+            life_steal: iron_will.lifesteal,
+            omnivamp: iron_will.spell_vamp,
+        };
         commands
             .entity(entity)
-            .with_related::<BuffOf>(BuffLeeSinIronWill::new(0.1, 0.1, 4.0));
+            .with_related::<BuffOf>((iron_will, vamp));
         commands.entity(skill_entity).remove::<SkillRecastWindow>();
         commands.entity(skill_entity).insert((CoolDown {
             duration: cooldown.duration,
@@ -294,9 +321,7 @@ fn on_leesin_r(
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Nearest {
-                max_distance: 150.0,
-            },
+            shape: DamageShape::unit_or_nearest(trigger.target, 150.0),
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::Champion,
                 amount: "total_damage".to_string(),
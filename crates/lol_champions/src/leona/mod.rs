@@ -7,14 +7,29 @@ use lol_core::action::damage::{
     ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
 };
 use lol_core::attack::CommandAttackReset;
-use lol_core::base::buff::BuffOf;
-use lol_core::buffs::cc_debuffs::DebuffSlow;
+use lol_core::base::buff::{BuffCommandsExt, BuffOf};
+use lol_core::buffs::cc_debuffs::{DebuffRoot, DebuffSlow, DebuffStun};
+use lol_core::buffs::on_hit::{BuffOnHitCounter, BuffOnHitStun};
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
 use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
 
 use crate::leona::buffs::{BuffLeonaSunlight, BuffLeonaW};
 
+/// E 命中禁锢。
+pub const LEONA_E_TAG: u32 = 20;
+/// R 中心区域眩晕。
+pub const LEONA_R_CENTER_TAG: u32 = 21;
+/// R 外圈减速。
+pub const LEONA_R_OUTER_TAG: u32 = 22;
+
+const LEONA_Q_STUN_DURATION: f32 = 1.0;
+const LEONA_E_ROOT_DURATION: f32 = 0.5;
+const LEONA_R_CENTER_RADIUS: f32 = 250.0;
+const LEONA_R_STUN_DURATION: f32 = 1.75;
+const LEONA_R_SLOW: f32 = 0.8;
+const LEONA_R_SLOW_DURATION: f32 = 1.75;
+
 #[derive(Default)]
 pub struct PluginLeona;
 
@@ -59,6 +74,15 @@ fn on_leona_q(
     });
 
     // Q is an empowered auto attack that stuns
+    commands
+        .entity(entity)
+        .apply_buff(
+            BuffOnHitCounter::descriptor(6.0).with_source(entity),
+            BuffOnHitCounter::new(1),
+        )
+        .with_related::<BuffOf>(BuffOnHitStun {
+            duration: LEONA_Q_STUN_DURATION,
+        });
     commands.trigger(CommandAttackReset { entity });
 }
 
@@ -152,6 +176,7 @@ fn on_leona_e(
                 damage_type: DamageType::Magic,
                 ..Default::default()
             }],
+            tag: Some(LEONA_E_TAG),
             ..Default::default()
         }],
     });
@@ -183,20 +208,36 @@ fn on_leona_r(
         duration: None,
     });
 
-    // R is a global nuke that slows and stuns center
+    // R stuns the center and slows the outer ring
+    let damage = || {
+        vec![TargetDamage {
+            filter: TargetFilter::All,
+            amount: "total_damage".to_string(),
+            damage_type: DamageType::Magic,
+            ..Default::default()
+        }]
+    };
     commands.trigger(ActionDamage {
         entity,
         skill: skill_spell,
-        effects: vec![ActionDamageEffect {
-            shape: DamageShape::Circle { radius: 1200.0 },
-            damage_list: vec![TargetDamage {
-                filter: TargetFilter::All,
-                amount: "total_damage".to_string(),
-                damage_type: DamageType::Magic,
+        effects: vec![
+            ActionDamageEffect {
+                shape: DamageShape::Circle {
+                    radius: LEONA_R_CENTER_RADIUS,
+                },
+                damage_list: damage(),
+                tag: Some(LEONA_R_CENTER_TAG),
                 ..Default::default()
-            }],
-            ..Default::default()
-        }],
+            },
+            ActionDamageEffect {
+                shape: DamageShape::Circle { radius: 1200.0 },
+                damage_list: damage(),
+                exclude: vec![DamageShape::Circle {
+                    radius: LEONA_R_CENTER_RADIUS,
+                }],
+                tag: Some(LEONA_R_OUTER_TAG),
+            },
+        ],
     });
 }
 
@@ -217,8 +258,22 @@ fn on_leona_damage_hit(
         .entity(target)
         .with_related::<BuffOf>(BuffLeonaSunlight::new(50.0, 2.5));
 
-    // R applies slow
-    commands
-        .entity(target)
-        .with_related::<BuffOf>(DebuffSlow::new(0.8, 1.75));
+    match trigger.tag {
+        Some(LEONA_E_TAG) => {
+            commands
+                .entity(target)
+                .with_related::<BuffOf>(DebuffRoot::new(LEONA_E_ROOT_DURATION));
+        }
+        Some(LEONA_R_CENTER_TAG) => {
+            commands
+                .entity(target)
+                .with_related::<BuffOf>(DebuffStun::new(LEONA_R_STUN_DURATION));
+        }
+        Some(LEONA_R_OUTER_TAG) => {
+            commands
+                .entity(target)
+                .with_related::<BuffOf>(DebuffSlow::new(LEONA_R_SLOW, LEONA_R_SLOW_DURATION));
+        }
+        _ => {}
+    }
 }
@@ -121,23 +121,164 @@ pub mod twisted_fate;
 pub mod twitch;
 pub mod urgot;
 pub mod volibear;
+pub mod warwick;
 
+#[cfg(test)]
+mod conformance;
 #[cfg(test)]
 mod test_utils;
 
-#[derive(Default)]
-pub struct PluginChampions;
+/// 英雄名册：`模块::标记组件 => 插件, "资源目录";`。
+///
+/// `registered` 段由 `PluginChampions` 注册，`optional` 段需自行添加插件；
+/// 一致性测试也从这里展开，新增英雄只改这一处。
+macro_rules! champion_roster {
+    ($callback:ident) => {
+        $callback! {
+            registered: [
+                aatrox::Aatrox => PluginAatrox, "aatrox";
+                camille::Camille => PluginCamille, "camille";
+                darius::Darius => PluginDarius, "darius";
+                fiora::Fiora => PluginFiora, "fiora";
+                irelia::Irelia => PluginIrelia, "irelia";
+                mordekaiser::Mordekaiser => PluginMordekaiser, "mordekaiser";
+                riven::Riven => PluginRiven, "riven";
+                sett::Sett => PluginSett, "sett";
+                volibear::Volibear => PluginVolibear, "volibear";
+            ]
+            optional: [
+                ahri::Ahri => PluginAhri, "ahri";
+                akali::Akali => PluginAkali, "akali";
+                akshan::Akshan => PluginAkshan, "akshan";
+                alistar::Alistar => PluginAlistar, "alistar";
+                amumu::Amumu => PluginAmumu, "amumu";
+                anivia::Anivia => PluginAnivia, "anivia";
+                annie::Annie => PluginAnnie, "annie";
+                aphelios::Aphelios => PluginAphelios, "aphelios";
+                ashe::Ashe => PluginAshe, "ashe";
+                aurora::Aurora => PluginAurora, "aurora";
+                bard::Bard => PluginBard, "bard";
+                belveth::Belveth => PluginBelveth, "belveth";
+                blitzcrank::Blitzcrank => PluginBlitzcrank, "blitzcrank";
+                brand::Brand => PluginBrand, "brand";
+                braum::Braum => PluginBraum, "braum";
+                briar::Briar => PluginBriar, "briar";
+                caitlyn::Caitlyn => PluginCaitlyn, "caitlyn";
+                cassiopeia::Cassiopeia => PluginCassiopeia, "cassiopeia";
+                diana::Diana => PluginDiana, "diana";
+                draven::Draven => PluginDraven, "draven";
+                ekko::Ekko => PluginEkko, "ekko";
+                evelynn::Evelynn => PluginEvelynn, "evelynn";
+                ezreal::Ezreal => PluginEzreal, "ezreal";
+                fizz::Fizz => PluginFizz, "fizz";
+                galio::Galio => PluginGalio, "galio";
+                gangplank::Gangplank => PluginGangplank, "gangplank";
+                garen::Garen => PluginGaren, "garen";
+                gnar::Gnar => PluginGnar, "gnar";
+                graves::Graves => PluginGraves, "graves";
+                hecarim::Hecarim => PluginHecarim, "hecarim";
+                heimerdinger::Heimerdinger => PluginHeimerdinger, "heimerdinger";
+                hwei::Hwei => PluginHwei, "hwei";
+                illaoi::Illaoi => PluginIllaoi, "illaoi";
+                ivern::Ivern => PluginIvern, "ivern";
+                janna::Janna => PluginJanna, "janna";
+                jarvan::JarvanIV => PluginJarvan, "jarvaniv";
+                jax::Jax => PluginJax, "jax";
+                jayce::Jayce => PluginJayce, "jayce";
+                jinx::Jinx => PluginJinx, "jinx";
+                kaisa::Kaisa => PluginKaisa, "kaisa";
+                kalista::Kalista => PluginKalista, "kalista";
+                karma::Karma => PluginKarma, "karma";
+                katarina::Katarina => PluginKatarina, "katarina";
+                kayle::Kayle => PluginKayle, "kayle";
+                kayn::Kayn => PluginKayn, "kayn";
+                kennen::Kennen => PluginKennen, "kennen";
+                kindred::Kindred => PluginKindred, "kindred";
+                kled::Kled => PluginKled, "kled";
+                leblanc::LeBlanc => PluginLeBlanc, "leblanc";
+                leesin::LeeSin => PluginLeeSin, "leesin";
+                leona::Leona => PluginLeona, "leona";
+                lissandra::Lissandra => PluginLissandra, "lissandra";
+                lucian::Lucian => PluginLucian, "lucian";
+                lulu::Lulu => PluginLulu, "lulu";
+                lux::Lux => PluginLux, "lux";
+                malzahar::Malzahar => PluginMalzahar, "malzahar";
+                maokai::Maokai => PluginMaokai, "maokai";
+                masteryi::MasterYi => PluginMasterYi, "masteryi";
+                missfortune::MissFortune => PluginMissFortune, "missfortune";
+                morgana::Morgana => PluginMorgana, "morgana";
+                nami::Nami => PluginNami, "nami";
+                nasus::Nasus => PluginNasus, "nasus";
+                nautilus::Nautilus => PluginNautilus, "nautilus";
+                neeko::Neeko => PluginNeeko, "neeko";
+                nidalee::Nidalee => PluginNidalee, "nidalee";
+                nocturne::Nocturne => PluginNocturne, "nocturne";
+                olaf::Olaf => PluginOlaf, "olaf";
+                orianna::Orianna => PluginOrianna, "orianna";
+                ornn::Ornn => PluginOrnn, "ornn";
+                pantheon::Pantheon => PluginPantheon, "pantheon";
+                pyke::Pyke => PluginPyke, "pyke";
+                qiyana::Qiyana => PluginQiyana, "qiyana";
+                quinn::Quinn => PluginQuinn, "quinn";
+                rakan::Rakan => PluginRakan, "rakan";
+                rammus::Rammus => PluginRammus, "rammus";
+                rell::Rell => PluginRell, "rell";
+                renata::Renata => PluginRenata, "renata";
+                renekton::Renekton => PluginRenekton, "renekton";
+                rengar::Rengar => PluginRengar, "rengar";
+                rumble::Rumble => PluginRumble, "rumble";
+                ryze::Ryze => PluginRyze, "ryze";
+                samira::Samira => PluginSamira, "samira";
+                sejuani::Sejuani => PluginSejuani, "sejuani";
+                senna::Senna => PluginSenna, "senna";
+                seraphine::Seraphine => PluginSeraphine, "seraphine";
+                shaco::Shaco => PluginShaco, "shaco";
+                shen::Shen => PluginShen, "shen";
+                shyvana::Shyvana => PluginShyvana, "shyvana";
+                singed::Singed => PluginSinged, "singed";
+                sion::Sion => PluginSion, "sion";
+                sivir::Sivir => PluginSivir, "sivir";
+                skarner::Skarner => PluginSkarner, "skarner";
+                smolder::Smolder => PluginSmolder, "smolder";
+                sona::Sona => PluginSona, "sona";
+                soraka::Soraka => PluginSoraka, "soraka";
+                swain::Swain => PluginSwain, "swain";
+                sylas::Sylas => PluginSylas, "sylas";
+                syndra::Syndra => PluginSyndra, "syndra";
+                tahm_kench::TahmKench => PluginTahmKench, "tahmkench";
+                taliyah::Taliyah => PluginTaliyah, "taliyah";
+                talon::Talon => PluginTalon, "talon";
+                taric::Taric => PluginTaric, "taric";
+                teemo::Teemo => PluginTeemo, "teemo";
+                thresh::Thresh => PluginThresh, "thresh";
+                tristana::Tristana => PluginTristana, "tristana";
+                trundle::Trundle => PluginTrundle, "trundle";
+                tryndamere::Tryndamere => PluginTryndamere, "tryndamere";
+                twisted_fate::TwistedFate => PluginTwistedFate, "twistedfate";
+                twitch::Twitch => PluginTwitch, "twitch";
+                urgot::Urgot => PluginUrgot, "urgot";
+                warwick::Warwick => PluginWarwick, "warwick";
+            ]
+        }
+    };
+}
+#[cfg(test)]
+pub(crate) use champion_roster;
 
-impl Plugin for PluginChampions {
-    fn build(&self, app: &mut bevy::app::App) {
-        app.add_plugins(aatrox::PluginAatrox);
-        app.add_plugins(camille::PluginCamille);
-        app.add_plugins(darius::PluginDarius);
-        app.add_plugins(fiora::PluginFiora);
-        app.add_plugins(irelia::PluginIrelia);
-        app.add_plugins(mordekaiser::PluginMordekaiser);
-        app.add_plugins(riven::PluginRiven);
-        app.add_plugins(sett::PluginSett);
-        app.add_plugins(volibear::PluginVolibear);
-    }
+macro_rules! plugin_champions {
+    (
+        registered: [$($module:ident :: $marker:ident => $plugin:ident, $dir:literal;)*]
+        optional: [$($rest:tt)*]
+    ) => {
+        #[derive(Default)]
+        pub struct PluginChampions;
+
+        impl Plugin for PluginChampions {
+            fn build(&self, app: &mut bevy::app::App) {
+                $(app.add_plugins($module::$plugin);)*
+            }
+        }
+    };
 }
+
+champion_roster!(plugin_champions);
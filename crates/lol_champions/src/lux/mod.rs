@@ -1,16 +1,11 @@
 pub mod buffs;
 
 use bevy::prelude::*;
-use lol_base::animation_names::{ANIM_SPELL1, ANIM_SPELL2, ANIM_SPELL3, ANIM_SPELL4};
-use lol_base::render_cmd::CommandAnimationPlay;
-use lol_core::action::damage::{
-    ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
-};
 use lol_core::base::buff::BuffOf;
 use lol_core::buffs::cc_debuffs::DebuffSlow;
-use lol_core::damage::{DamageType, EventDamageCreate};
+use lol_core::damage::EventDamageCreate;
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::skill_kit::SkillKit;
 
 use crate::lux::buffs::{BuffLuxIllumination, BuffLuxQ};
 
@@ -19,10 +14,7 @@ pub struct PluginLux;
 
 impl Plugin for PluginLux {
     fn build(&self, app: &mut App) {
-        app.add_observer(on_lux_q);
-        app.add_observer(on_lux_w);
-        app.add_observer(on_lux_e);
-        app.add_observer(on_lux_r);
+        app.add_observer(on_lux_add);
         app.add_observer(on_lux_damage_hit);
     }
 }
@@ -32,167 +24,11 @@ impl Plugin for PluginLux {
 #[reflect(Component)]
 pub struct Lux;
 
-fn on_lux_q(
-    trigger: On<EventSkillCast>,
-    mut commands: Commands,
-    q_lux: Query<(), With<Lux>>,
-    q_skill: Query<&Skill>,
-) {
-    let entity = trigger.event_target();
-    if q_lux.get(entity).is_err() {
-        return;
-    }
-
-    let Ok(skill) = q_skill.get(trigger.skill_entity) else {
-        return;
-    };
-    if !matches!(skill.slot, SkillSlot::Q) {
-        return;
-    }
-
-    let skill_spell = skill.spell.clone();
-    commands.trigger(CommandAnimationPlay {
-        entity,
-        hash: ANIM_SPELL1.to_string(),
-        repeat: false,
-        duration: None,
-    });
-
-    // Q roots enemies
-    commands.trigger(ActionDamage {
-        entity,
-        skill: skill_spell,
-        effects: vec![ActionDamageEffect {
-            shape: DamageShape::Sector {
-                radius: 1300.0,
-                angle: 10.0,
-            },
-            damage_list: vec![TargetDamage {
-                filter: TargetFilter::All,
-                amount: "total_damage".to_string(),
-                damage_type: DamageType::Magic,
-                ..Default::default()
-            }],
-            ..Default::default()
-        }],
-    });
-}
-
-fn on_lux_w(
-    trigger: On<EventSkillCast>,
-    mut commands: Commands,
-    q_lux: Query<(), With<Lux>>,
-    q_skill: Query<&Skill>,
-) {
-    let entity = trigger.event_target();
-    if q_lux.get(entity).is_err() {
-        return;
-    }
-
-    let Ok(skill) = q_skill.get(trigger.skill_entity) else {
-        return;
-    };
-    if !matches!(skill.slot, SkillSlot::W) {
-        return;
-    }
-
-    commands.trigger(CommandAnimationPlay {
-        entity,
-        hash: ANIM_SPELL2.to_string(),
-        repeat: false,
-        duration: None,
-    });
-
-    // W is a shield;
-}
-
-fn on_lux_e(
-    trigger: On<EventSkillCast>,
-    mut commands: Commands,
-    q_lux: Query<(), With<Lux>>,
-    q_skill: Query<&Skill>,
-) {
-    let entity = trigger.event_target();
-    if q_lux.get(entity).is_err() {
-        return;
-    }
-
-    let Ok(skill) = q_skill.get(trigger.skill_entity) else {
-        return;
-    };
-    if !matches!(skill.slot, SkillSlot::E) {
-        return;
-    }
-
-    let skill_spell = skill.spell.clone();
-    commands.trigger(CommandAnimationPlay {
-        entity,
-        hash: ANIM_SPELL3.to_string(),
-        repeat: false,
-        duration: None,
-    });
-
-    // E slows and deals damage
-    commands.trigger(ActionDamage {
-        entity,
-        skill: skill_spell,
-        effects: vec![ActionDamageEffect {
-            shape: DamageShape::Circle { radius: 300.0 },
-            damage_list: vec![TargetDamage {
-                filter: TargetFilter::All,
-                amount: "total_damage".to_string(),
-                damage_type: DamageType::Magic,
-                ..Default::default()
-            }],
-            ..Default::default()
-        }],
-    });
-}
-
-fn on_lux_r(
-    trigger: On<EventSkillCast>,
-    mut commands: Commands,
-    q_lux: Query<(), With<Lux>>,
-    q_skill: Query<&Skill>,
-) {
-    let entity = trigger.event_target();
-    if q_lux.get(entity).is_err() {
-        return;
-    }
-
-    let Ok(skill) = q_skill.get(trigger.skill_entity) else {
-        return;
-    };
-    if !matches!(skill.slot, SkillSlot::R) {
-        return;
-    }
-
-    let skill_spell = skill.spell.clone();
-    commands.trigger(CommandAnimationPlay {
-        entity,
-        hash: ANIM_SPELL4.to_string(),
-        repeat: false,
-        duration: None,
-    });
-
-    // R is a global beam
-    commands.trigger(ActionDamage {
-        entity,
-        skill: skill_spell,
-        effects: vec![ActionDamageEffect {
-            shape: DamageShape::Sector {
-                radius: 3400.0,
-                angle: 20.0,
-            },
-            damage_list: vec![TargetDamage {
-                filter: TargetFilter::All,
-                amount: "total_damage".to_string(),
-                damage_type: DamageType::Magic,
-                ..Default::default()
-            }],
-            ..Default::default()
-        }],
-    });
+/// 拉克丝的 Q/W/E/R 由 `kits/lux.ron` 数据驱动
+fn on_lux_add(trigger: On<Add, Lux>, mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .entity(trigger.event_target())
+        .insert(SkillKit::load(&asset_server, "Lux"));
 }
 
 fn on_lux_damage_hit(
@@ -7,10 +7,13 @@ use lol_core::action::damage::{
     ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
 };
 use lol_core::base::buff::BuffOf;
-use lol_core::buffs::cc_debuffs::DebuffSilence;
+use lol_core::buffs::cc_debuffs::{DebuffSilence, DebuffSuppression};
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillSlot, SkillTarget, SkillTargetFilter, SkillTargeting,
+    attach_skill_targeting,
+};
 
 use crate::malzahar::buffs::BuffMalzaharE;
 
@@ -24,6 +27,10 @@ impl Plugin for PluginMalzahar {
         app.add_observer(on_malzahar_e);
         app.add_observer(on_malzahar_r);
         app.add_observer(on_malzahar_damage_hit);
+        app.add_observer(attach_skill_targeting::<Malzahar>(
+            SkillSlot::R,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -186,12 +193,21 @@ fn on_malzahar_r(
         repeat: false,
         duration: None,
     });
-    // R suppresses target
+    // R 压制点选目标；未指定单位时退化为周身范围伤害
+    let shape = match trigger.target {
+        SkillTarget::Unit(target) => {
+            commands
+                .entity(target)
+                .with_related::<BuffOf>(DebuffSuppression::new(2.5));
+            DamageShape::Unit { entity: target }
+        }
+        _ => DamageShape::Circle { radius: 700.0 },
+    };
     commands.trigger(ActionDamage {
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Circle { radius: 700.0 },
+            shape,
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::All,
                 amount: "total_damage".to_string(),
@@ -6,7 +6,7 @@ use lol_base::render_cmd::CommandAnimationPlay;
 use lol_core::action::damage::{
     ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
 };
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::{BuffCommandsExt, BuffOf};
 use lol_core::buffs::common_buffs::BuffMoveSpeed;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
@@ -159,9 +159,10 @@ fn on_masteryi_r(
     commands
         .entity(entity)
         .with_related::<BuffOf>(BuffMasterYiR::new(0.8, 0.45, 10.0));
-    commands
-        .entity(entity)
-        .with_related::<BuffOf>(BuffMoveSpeed::new(0.45, 10.0));
+    commands.entity(entity).apply_buff(
+        BuffMoveSpeed::descriptor(10.0).with_source(entity),
+        BuffMoveSpeed::new(0.45),
+    );
 }
 
 fn on_masteryi_damage_hit(
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use lol_core::base::buff::Buff;
 
 // ===== W - 不坏之身 =====
 
@@ -52,8 +53,9 @@ pub struct MordekaiserRealm {
     pub stolen: bool,
 }
 
-/// R 击杀窃取的属性增益组件（挂在自身）。
+/// R 击杀窃取的属性记录（挂在 buff 实体上，同实体的 `StatModifiers` 承载实际加成）。
 #[derive(Component, Debug, Clone, Default)]
+#[require(Buff = Buff { name: "MordekaiserStatSteal" })]
 pub struct MordekaiserStatSteal {
     /// 窃取的 AD
     pub ad: f32,
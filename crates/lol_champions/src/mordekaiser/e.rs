@@ -16,7 +16,9 @@ use lol_core::action::displace::{
     ActionDisplace, DisplaceEffect, DisplaceMotion, DisplaceTargetSelection,
 };
 use lol_core::damage::{AbilityPower, DamageType};
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot, get_skill_data_value, get_skill_value};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillCalcQuery, SkillSlot, get_skill_data_value, get_skill_value_with,
+};
 
 use crate::mordekaiser::Mordekaiser;
 
@@ -37,6 +39,7 @@ pub fn on_mordekaiser_e(
     res_spells: Res<Assets<Spell>>,
     q_transform: Query<&Transform>,
     q_ap: Query<&AbilityPower>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     if q_morde.get(entity).is_err() {
@@ -78,9 +81,15 @@ pub fn on_mordekaiser_e(
     let pull_distance = get_skill_data_value(spell_obj, "KnockTowardsDistance", skill.level)
         .unwrap_or(MORDE_E_PULL_DISTANCE);
     let ap = q_ap.get(entity).map(|a| a.0).unwrap_or(0.0);
-    let damage = get_skill_value(spell_obj, "total_damage", skill.level, |stat| {
-        if stat == 0 { ap } else { 0.0 }
-    })
+    let damage = get_skill_value_with(
+        spell_obj,
+        "total_damage",
+        skill.level,
+        &calc.caster(entity),
+        |stat| {
+            if stat == 0 { ap } else { 0.0 }
+        },
+    )
     .unwrap_or(0.0);
 
     // 使用统一位移体系：锥形拽回 + 魔法伤害（tag:None 让被动观察者叠层）
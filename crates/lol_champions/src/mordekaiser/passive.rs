@@ -1,9 +1,12 @@
 use bevy::prelude::*;
 use bevy::time::{Timer, TimerMode};
-use lol_core::damage::{AbilityPower, CommandDamageCreate, DamageType, EventDamageCreate};
-use lol_core::entities::champion::Champion;
+use lol_core::base::buff::{Buff, BuffOf, Buffs};
+use lol_core::base::stat_modifier::{StatKind, StatModifier, StatModifiers};
+use lol_core::damage::{
+    AbilityPower, CommandDamageCreate, DamageKind, DamageType, EventDamageCreate,
+};
+use lol_core::entities::champion::TargetableAsChampion;
 use lol_core::life::Health;
-use lol_core::movement::Movement;
 use lol_core::team::Team;
 
 use crate::mordekaiser::Mordekaiser;
@@ -41,7 +44,7 @@ pub const MORDE_PASSIVE_AUTO_TAG: u32 = 2;
 ///
 /// `combat_timer` 每次命中重置，到期（脱战 4 秒）则清空层数并失效；
 /// `dot_timer` 仅在 `active` 时推进，每周期对半径内敌人结算 DoT；
-/// `ms_bonus` 记录已叠加到 [`lol_core::movement::Movement`] 上的移速数值，失效时据此还原。
+/// 激活期间的移速由 [`BuffMordekaiserDarkness`] 承载，失效时销毁。
 #[derive(Component, Debug, Clone)]
 pub struct MordekaiserDarkness {
     /// 当前层数，0..=3
@@ -52,10 +55,13 @@ pub struct MordekaiserDarkness {
     pub combat_timer: Timer,
     /// DoT 周期计时
     pub dot_timer: Timer,
-    /// 已施加的移速加成（用于失效时还原）
-    pub ms_bonus: f32,
 }
 
+/// 被动激活期间的移速 buff，加成由同实体的 `StatModifiers` 承载。
+#[derive(Component, Debug, Clone, Default)]
+#[require(Buff = Buff { name: "MordekaiserDarkness" })]
+pub struct BuffMordekaiserDarkness;
+
 impl MordekaiserDarkness {
     pub const MAX: u8 = MORDE_PASSIVE_MAX_STACKS;
 
@@ -66,7 +72,6 @@ impl MordekaiserDarkness {
             active: false,
             combat_timer: Timer::from_seconds(MORDE_PASSIVE_COMBAT_DURATION, TimerMode::Once),
             dot_timer: Timer::from_seconds(MORDE_PASSIVE_DOT_TICK, TimerMode::Repeating),
-            ms_bonus: 0.0,
         }
     }
 }
@@ -78,7 +83,6 @@ impl Default for MordekaiserDarkness {
             active: false,
             combat_timer: Timer::from_seconds(MORDE_PASSIVE_COMBAT_DURATION, TimerMode::Once),
             dot_timer: Timer::from_seconds(MORDE_PASSIVE_DOT_TICK, TimerMode::Repeating),
-            ms_bonus: 0.0,
         }
     }
 }
@@ -90,7 +94,6 @@ pub fn on_mordekaiser_damage_hit(
     q_morde: Query<(), With<Mordekaiser>>,
     mut q_darkness: Query<&mut MordekaiserDarkness>,
     q_ap: Query<&AbilityPower>,
-    mut q_movement: Query<&mut Movement>,
 ) {
     let morde = trigger.source;
     if q_morde.get(morde).is_err() {
@@ -109,12 +112,14 @@ pub fn on_mordekaiser_damage_hit(
             if d.stacks >= MORDE_PASSIVE_MAX_STACKS && !d.active {
                 d.active = true;
                 d.dot_timer.reset();
-                // 激活：+3% 移速（基于当前移速）
-                if let Ok(mut mv) = q_movement.get_mut(morde) {
-                    let bonus = mv.speed * MORDE_PASSIVE_MS_BONUS;
-                    d.ms_bonus = bonus;
-                    mv.speed += bonus;
-                }
+                // 激活：+3% 移速
+                commands.entity(morde).with_related::<BuffOf>((
+                    BuffMordekaiserDarkness,
+                    StatModifiers(vec![StatModifier::percent(
+                        StatKind::MoveSpeed,
+                        MORDE_PASSIVE_MS_BONUS,
+                    )]),
+                ));
             }
             d.active
         }
@@ -137,6 +142,7 @@ pub fn on_mordekaiser_damage_hit(
                 damage_type: DamageType::Magic,
                 amount: bonus,
                 tag: Some(MORDE_PASSIVE_AUTO_TAG),
+                kind: DamageKind::Other,
             });
         }
     }
@@ -176,21 +182,21 @@ pub fn update_mordekaiser_passive(
     time: Res<Time>,
     mut q_morde: Query<(Entity, &mut MordekaiserDarkness, &Transform, &Team), With<Mordekaiser>>,
     q_ap: Query<&AbilityPower>,
-    mut q_movement: Query<&mut Movement>,
-    q_enemies: Query<(Entity, &Transform, &Team), With<Champion>>,
+    q_buffs: Query<&Buffs>,
+    q_darkness_buff: Query<(), With<BuffMordekaiserDarkness>>,
+    q_enemies: Query<(Entity, &Transform, &Team), With<TargetableAsChampion>>,
 ) {
     for (morde, mut darkness, transform, team) in q_morde.iter_mut() {
         darkness.combat_timer.tick(time.delta());
         if darkness.combat_timer.just_finished() {
-            // 脱战：失效并还原移速
-            if darkness.active {
-                if let Ok(mut mv) = q_movement.get_mut(morde) {
-                    mv.speed -= darkness.ms_bonus;
+            // 脱战：失效并撤除移速 buff
+            if let Ok(buffs) = q_buffs.get(morde) {
+                for buff in buffs.iter().filter(|b| q_darkness_buff.contains(*b)) {
+                    commands.entity(buff).despawn();
                 }
             }
             darkness.stacks = 0;
             darkness.active = false;
-            darkness.ms_bonus = 0.0;
             continue;
         }
 
@@ -214,6 +220,7 @@ pub fn update_mordekaiser_passive(
                             damage_type: DamageType::Magic,
                             amount,
                             tag: Some(MORDE_PASSIVE_DOT_TAG),
+                            kind: DamageKind::Other,
                         });
                     }
                 }
@@ -5,8 +5,8 @@
 //!
 //! 组合表达：
 //! - 领域标记 = [`MordekaiserRealm`] 组件挂在自身，由 `update_mordekaiser_realm` 计时到期移除。
-//! - 属性窃取 = 目标生命归零时读取其属性，按 10% 加到自身对应组件，并记入
-//!   [`MordekaiserStatSteal`] 便于查询。
+//! - 属性窃取 = 目标生命归零时读取其属性，按 10% 累加到 [`MordekaiserStatSteal`] buff，
+//!   同实体的 `StatModifiers` 据此为自身提供固定加成。
 //!
 //! 鬼魂生成（ron `GhostAPRatio` 0.6）与领域空间隔离待后续实现。
 
@@ -14,6 +14,8 @@ use bevy::prelude::*;
 use lol_base::animation_names::ANIM_SPELL4;
 use lol_base::render_cmd::CommandAnimationPlay;
 use lol_base::spell::Spell;
+use lol_core::base::buff::{BuffOf, Buffs};
+use lol_core::base::stat_modifier::{StatKind, StatModifier, StatModifiers};
 use lol_core::damage::{AbilityPower, Armor, Damage};
 use lol_core::entities::champion::Champion;
 use lol_core::life::Health;
@@ -128,11 +130,8 @@ pub fn update_mordekaiser_realm(
     time: Res<Time>,
     mut q_morde: Query<(Entity, &mut MordekaiserRealm), With<Mordekaiser>>,
     q_target: Query<TargetStats, Without<Mordekaiser>>,
-    mut q_morde_dmg: Query<&mut Damage, With<Mordekaiser>>,
-    mut q_morde_hp: Query<&mut Health, With<Mordekaiser>>,
-    mut q_morde_armor: Query<&mut Armor, With<Mordekaiser>>,
-    mut q_morde_ap: Query<&mut AbilityPower, With<Mordekaiser>>,
-    mut q_steal: Query<&mut MordekaiserStatSteal>,
+    q_buffs: Query<&Buffs>,
+    q_steal: Query<&MordekaiserStatSteal>,
 ) {
     for (morde, mut realm) in q_morde.iter_mut() {
         // 击杀窃取
@@ -145,39 +144,34 @@ pub fn update_mordekaiser_realm(
                     let steal_armor =
                         t_armor.map(|a| a.0).unwrap_or(0.0) * MORDE_R_STAT_STEAL_RATIO;
 
-                    if let Ok(mut m_dmg) = q_morde_dmg.get_mut(morde) {
-                        m_dmg.0 += steal_ad;
-                    }
-                    if let Ok(mut m_hp) = q_morde_hp.get_mut(morde) {
-                        m_hp.max += steal_hp;
-                        m_hp.value += steal_hp;
-                    }
-                    if let Ok(mut m_armor) = q_morde_armor.get_mut(morde) {
-                        m_armor.0 += steal_armor;
-                    }
-                    match q_morde_ap.get_mut(morde) {
-                        Ok(mut ap) => ap.0 += steal_ap,
-                        Err(_) => {
-                            if steal_ap > 0.0 {
-                                commands.entity(morde).insert(AbilityPower(steal_ap));
-                            }
-                        }
-                    }
-
-                    match q_steal.get_mut(morde) {
-                        Ok(mut s) => {
-                            s.ad += steal_ad;
-                            s.ap += steal_ap;
-                            s.health += steal_hp;
-                            s.armor += steal_armor;
+                    // 多次窃取累加到同一 buff，重写修正器即按总量重算
+                    let existing = q_buffs.get(morde).ok().and_then(|buffs| {
+                        buffs
+                            .iter()
+                            .find_map(|b| q_steal.get(b).ok().map(|s| (b, s.clone())))
+                    });
+                    let (buff_entity, prev) = existing.unzip();
+                    let prev = prev.unwrap_or_default();
+                    let steal = MordekaiserStatSteal {
+                        ad: prev.ad + steal_ad,
+                        ap: prev.ap + steal_ap,
+                        health: prev.health + steal_hp,
+                        armor: prev.armor + steal_armor,
+                    };
+                    let modifiers = StatModifiers(vec![
+                        StatModifier::flat(StatKind::AttackDamage, steal.ad),
+                        StatModifier::flat(StatKind::AbilityPower, steal.ap),
+                        StatModifier::flat(StatKind::MaxHealth, steal.health),
+                        StatModifier::flat(StatKind::Armor, steal.armor),
+                    ]);
+                    match buff_entity {
+                        Some(buff_entity) => {
+                            commands.entity(buff_entity).insert((steal, modifiers));
                         }
-                        Err(_) => {
-                            commands.entity(morde).insert(MordekaiserStatSteal {
-                                ad: steal_ad,
-                                ap: steal_ap,
-                                health: steal_hp,
-                                armor: steal_armor,
-                            });
+                        None => {
+                            commands
+                                .entity(morde)
+                                .with_related::<BuffOf>((steal, modifiers));
                         }
                     }
                     realm.stolen = true;
@@ -4,7 +4,7 @@
 
 use bevy::ecs::entity::Entity;
 use bevy::math::Vec2;
-use lol_core::damage::{AbilityPower, Armor, CommandDamageCreate, Damage, DamageType};
+use lol_core::damage::{AbilityPower, Armor, CommandDamageCreate, Damage, DamageKind, DamageType};
 use lol_core::life::Health;
 use lol_core::movement::Movement;
 
@@ -81,6 +81,7 @@ pub fn morde_hit(h: &mut ChampionTestHarness, enemy: Entity, amount: f32) {
         damage_type: DamageType::Physical,
         amount,
         tag: None,
+        kind: DamageKind::Other,
     });
 }
 
@@ -92,6 +93,7 @@ pub fn morde_take_damage(h: &mut ChampionTestHarness, source: Entity, amount: f3
         damage_type: DamageType::Physical,
         amount,
         tag: None,
+        kind: DamageKind::Other,
     });
 }
 
@@ -131,10 +133,7 @@ pub fn realm_target(h: &ChampionTestHarness) -> Option<Entity> {
 }
 
 pub fn stat_steal(h: &ChampionTestHarness) -> Option<MordekaiserStatSteal> {
-    h.app
-        .world()
-        .get::<MordekaiserStatSteal>(h.champion)
-        .cloned()
+    h.buff::<MordekaiserStatSteal>(h.champion).cloned()
 }
 
 /// 框架冒烟测试：莫德凯撒能被正常构造并加载配置。
@@ -17,6 +17,7 @@ use lol_base::render_cmd::CommandAnimationPlay;
 use lol_base::spell::Spell;
 use lol_core::base::buff::{BuffOf, Buffs};
 use lol_core::buffs::shield_white::BuffShieldWhite;
+use lol_core::heal::{CommandHeal, HealType};
 use lol_core::life::Health;
 use lol_core::skill::{EventSkillCast, Skill, SkillRecastWindow, SkillSlot, get_skill_data_value};
 
@@ -87,10 +88,11 @@ pub fn on_mordekaiser_w(
             get_skill_data_value(spell_obj, "HealingPercent", skill.level).unwrap_or(0.325);
         let heal = heal_percent * remaining;
         if heal > 0.0 {
-            commands.entity(entity).queue(move |mut e: EntityWorldMut| {
-                if let Some(mut health) = e.get_mut::<Health>() {
-                    health.value = (health.value + heal).min(health.max);
-                }
+            commands.trigger(CommandHeal {
+                entity,
+                source: entity,
+                heal_type: HealType::Heal,
+                amount: heal,
             });
         }
         commands.entity(shield_entity).despawn();
@@ -127,6 +129,7 @@ pub fn on_mordekaiser_w(
     let shield_entity = commands
         .spawn((
             BuffShieldWhite::new(shield_amount),
+            BuffShieldWhite::descriptor().with_source(entity),
             BuffMordekaiserWShield {
                 elapsed: 0.0,
                 max_health: max_hp,
@@ -6,12 +6,23 @@ use lol_base::render_cmd::CommandAnimationPlay;
 use lol_core::action::damage::{
     ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
 };
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::{BuffCommandsExt, BuffOf};
+use lol_core::buffs::cc_debuffs::{DebuffRoot, DebuffStun};
+use lol_core::buffs::shield_magic::BuffShieldMagic;
+use lol_core::buffs::spell_shield::BuffSpellShield;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
 use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
 
-use crate::morgana::buffs::{BuffMorganaE, BuffMorganaQ};
+/// Q 命中禁锢。
+pub const MORGANA_Q_TAG: u32 = 20;
+/// R 锁链眩晕。
+pub const MORGANA_R_TAG: u32 = 21;
+
+const MORGANA_Q_ROOT_DURATION: f32 = 2.0;
+const MORGANA_R_STUN_DURATION: f32 = 1.5;
+const MORGANA_E_SHIELD: f32 = 150.0;
+const MORGANA_E_DURATION: f32 = 5.0;
 
 #[derive(Default)]
 pub struct PluginMorgana;
@@ -71,6 +82,7 @@ fn on_morgana_q(
                 damage_type: DamageType::Magic,
                 ..Default::default()
             }],
+            tag: Some(MORGANA_Q_TAG),
             ..Default::default()
         }],
     });
@@ -142,10 +154,16 @@ fn on_morgana_e(
         repeat: false,
         duration: None,
     });
-    // E is a shield that blocks CC
+    // E is a magic shield that also blocks CC; both expire together
     commands
         .entity(entity)
-        .with_related::<BuffOf>(BuffMorganaE::new(150.0, true, 5.0));
+        .apply_buff(
+            BuffShieldMagic::descriptor()
+                .with_source(entity)
+                .with_duration(MORGANA_E_DURATION),
+            BuffShieldMagic::new(MORGANA_E_SHIELD),
+        )
+        .with_related::<BuffOf>(BuffSpellShield::cc_only(MORGANA_E_DURATION));
 }
 
 fn on_morgana_r(
@@ -185,6 +203,7 @@ fn on_morgana_r(
                 damage_type: DamageType::Magic,
                 ..Default::default()
             }],
+            tag: Some(MORGANA_R_TAG),
             ..Default::default()
         }],
     });
@@ -202,8 +221,17 @@ fn on_morgana_damage_hit(
 
     let target = trigger.event_target();
 
-    // Q roots
-    commands
-        .entity(target)
-        .with_related::<BuffOf>(BuffMorganaQ::new(2.0, 2.0));
+    match trigger.tag {
+        Some(MORGANA_Q_TAG) => {
+            commands
+                .entity(target)
+                .with_related::<BuffOf>(DebuffRoot::new(MORGANA_Q_ROOT_DURATION));
+        }
+        Some(MORGANA_R_TAG) => {
+            commands
+                .entity(target)
+                .with_related::<BuffOf>(DebuffStun::new(MORGANA_R_STUN_DURATION));
+        }
+        _ => {}
+    }
 }
@@ -36,20 +36,3 @@ impl BuffNautilusW {
         }
     }
 }
-
-/// 诺提勒斯E - 潮汐（减速）
-#[derive(Component, Debug, Clone)]
-#[require(Buff = Buff { name: "NautilusE" })]
-pub struct BuffNautilusE {
-    pub slow_percent: f32,
-    pub timer: Timer,
-}
-
-impl BuffNautilusE {
-    pub fn new(slow_percent: f32, duration: f32) -> Self {
-        Self {
-            slow_percent,
-            timer: Timer::from_seconds(duration, TimerMode::Once),
-        }
-    }
-}
@@ -7,11 +7,23 @@ use lol_core::action::damage::{
     ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
 };
 use lol_core::base::buff::BuffOf;
+use lol_core::buffs::cc_debuffs::{DebuffKnockup, DebuffSlow};
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillSlot, SkillTargetFilter, SkillTargeting, attach_skill_targeting,
+};
+
+use crate::nautilus::buffs::BuffNautilusW;
+
+/// E 潮汐减速。
+pub const NAUTILUS_E_TAG: u32 = 20;
+/// R 深渊冲击击飞。
+pub const NAUTILUS_R_TAG: u32 = 21;
 
-use crate::nautilus::buffs::{BuffNautilusE, BuffNautilusW};
+const NAUTILUS_E_SLOW: f32 = 0.4;
+const NAUTILUS_E_SLOW_DURATION: f32 = 1.5;
+const NAUTILUS_R_KNOCKUP_DURATION: f32 = 1.0;
 
 #[derive(Default)]
 pub struct PluginNautilus;
@@ -23,6 +35,10 @@ impl Plugin for PluginNautilus {
         app.add_observer(on_nautilus_e);
         app.add_observer(on_nautilus_r);
         app.add_observer(on_nautilus_damage_hit);
+        app.add_observer(attach_skill_targeting::<Nautilus>(
+            SkillSlot::R,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -143,6 +159,7 @@ fn on_nautilus_e(
                 damage_type: DamageType::Magic,
                 ..Default::default()
             }],
+            tag: Some(NAUTILUS_E_TAG),
             ..Default::default()
         }],
     });
@@ -178,15 +195,14 @@ fn on_nautilus_r(
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Nearest {
-                max_distance: 825.0,
-            },
+            shape: DamageShape::unit_or_nearest(trigger.target, 825.0),
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::All,
                 amount: "total_damage".to_string(),
                 damage_type: DamageType::Magic,
                 ..Default::default()
             }],
+            tag: Some(NAUTILUS_R_TAG),
             ..Default::default()
         }],
     });
@@ -204,8 +220,17 @@ fn on_nautilus_damage_hit(
 
     let target = trigger.event_target();
 
-    // E slows
-    commands
-        .entity(target)
-        .with_related::<BuffOf>(BuffNautilusE::new(0.4, 1.5));
+    match trigger.tag {
+        Some(NAUTILUS_E_TAG) => {
+            commands
+                .entity(target)
+                .with_related::<BuffOf>(DebuffSlow::new(NAUTILUS_E_SLOW, NAUTILUS_E_SLOW_DURATION));
+        }
+        Some(NAUTILUS_R_TAG) => {
+            commands
+                .entity(target)
+                .with_related::<BuffOf>(DebuffKnockup::new(NAUTILUS_R_KNOCKUP_DURATION));
+        }
+        _ => {}
+    }
 }
@@ -9,10 +9,14 @@ use lol_core::action::damage::{
 use lol_core::base::buff::BuffOf;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
+use lol_core::entities::pet::{CommandCloneSpawn, PetAi, PetClone, PetOrder};
 use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
 
 use crate::neeko::buffs::BuffNeekoE;
 
+/// W 分身持续时间（秒）
+const NEEKO_W_CLONE_DURATION: f32 = 3.0;
+
 #[derive(Default)]
 pub struct PluginNeeko;
 
@@ -76,13 +80,13 @@ fn on_neeko_q(
 fn on_neeko_w(
     trigger: On<EventSkillCast>,
     mut commands: Commands,
-    q_neeko: Query<(), With<Neeko>>,
+    q_neeko: Query<&Transform, With<Neeko>>,
     q_skill: Query<&Skill>,
 ) {
     let entity = trigger.event_target();
-    if q_neeko.get(entity).is_err() {
+    let Ok(transform) = q_neeko.get(entity) else {
         return;
-    }
+    };
 
     let Ok(skill) = q_skill.get(trigger.skill_entity) else {
         return;
@@ -97,7 +101,14 @@ fn on_neeko_w(
         repeat: false,
         duration: None,
     });
-    // W is a shapesplitter dash;
+    // W 放出一个不造成伤害的分身，朝施法方向跑去
+    commands.trigger(CommandCloneSpawn {
+        entity,
+        position: transform.translation.xz(),
+        duration: NEEKO_W_CLONE_DURATION,
+        pet_clone: PetClone::new(0.0, 1.0),
+        ai: PetAi::default().with_order(PetOrder::MoveTo(trigger.point)),
+    });
 }
 
 fn on_neeko_e(
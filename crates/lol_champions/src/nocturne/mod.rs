@@ -206,5 +206,5 @@ fn on_nocturne_damage_hit(
     // E fears
     commands
         .entity(target)
-        .with_related::<BuffOf>(DebuffFear::new(2.0));
+        .with_related::<BuffOf>(DebuffFear::new(2.0).with_source(source));
 }
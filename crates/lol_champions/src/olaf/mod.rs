@@ -10,10 +10,12 @@ use lol_core::action::damage::{
     ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
 };
 use lol_core::base::buff::BuffOf;
-use lol_core::buffs::cc_debuffs::{CommandCleanse, ImmuneToCC};
+use lol_core::buffs::cc_debuffs::{CleanseKind, CommandCleanse, ImmuneToCC};
 use lol_core::damage::DamageType;
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillSlot, SkillTargetFilter, SkillTargeting, attach_skill_targeting,
+};
 
 use crate::olaf::buffs::{BuffOlafR, BuffOlafW};
 
@@ -38,6 +40,10 @@ impl Plugin for PluginOlaf {
         // BuffOlafR 消亡时由 On<Remove> 观察者移除--事件驱动，非轮询。
         app.add_observer(on_remove_olaf_r);
         app.add_systems(FixedUpdate, update_olaf_r_timer);
+        app.add_observer(attach_skill_targeting::<Olaf>(
+            SkillSlot::E,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -146,9 +152,7 @@ fn on_olaf_e(
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Nearest {
-                max_distance: 200.0,
-            },
+            shape: DamageShape::unit_or_nearest(trigger.target, 200.0),
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::All,
                 amount: "total_damage".to_string(),
@@ -192,9 +196,10 @@ fn on_olaf_r(
     // 免控标记直接挂角色（施法者已知）；BuffOlafR 消亡时由 On<Remove> 移除
     commands.entity(entity).insert(ImmuneToCC);
     // 激活即解控：净化自身所有控制
-    commands
-        .entity(entity)
-        .trigger(|e| CommandCleanse { entity: e });
+    commands.entity(entity).trigger(|e| CommandCleanse {
+        entity: e,
+        kind: CleanseKind::Cleanse,
+    });
 
     debug!(
         "{:?} 释放了 {} 技能，免疫控制效果持续 {} 秒",
@@ -9,7 +9,9 @@ use lol_core::action::damage::{
 use lol_core::base::buff::BuffOf;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillSlot, SkillTargetFilter, SkillTargeting, attach_skill_targeting,
+};
 
 use crate::quinn::buffs::{BuffQuinnE, BuffQuinnW};
 
@@ -23,6 +25,10 @@ impl Plugin for PluginQuinn {
         app.add_observer(on_quinn_e);
         app.add_observer(on_quinn_r);
         app.add_observer(on_quinn_damage_hit);
+        app.add_observer(attach_skill_targeting::<Quinn>(
+            SkillSlot::E,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -136,9 +142,7 @@ fn on_quinn_e(
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Nearest {
-                max_distance: 600.0,
-            },
+            shape: DamageShape::unit_or_nearest(trigger.target, 600.0),
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::All,
                 amount: "total_damage".to_string(),
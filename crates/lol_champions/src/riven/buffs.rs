@@ -8,7 +8,7 @@ use lol_core::buffs::shield_white::BuffShieldWhite;
 #[require(Buff = Buff { name: "RivenR" })]
 pub struct BuffRivenR {
     pub timer: Timer,
-    /// 存储开启时增加的 AD 比例（从 RON PercentBonusAD 读取），用于到期恢复
+    /// AD 百分比加成（从 RON PercentBonusAD 读取），由同实体上的属性修正结算
     pub bonus_ad_ratio: f32,
     /// 存储开启时增加的攻击距离（从 RON TooltipAttackRange 读取），用于到期恢复
     pub bonus_range: f32,
@@ -6,7 +6,7 @@ use lol_core::action::dash::{ActionDash, DashMoveType};
 use lol_core::base::buff::BuffOf;
 use lol_core::buffs::shield_white::BuffShieldWhite;
 use lol_core::damage::Damage;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot, get_skill_value};
+use lol_core::skill::{EventSkillCast, Skill, SkillCalcQuery, SkillSlot, get_skill_value_with};
 
 use crate::riven::Riven;
 
@@ -17,6 +17,7 @@ pub fn on_riven_e(
     q_skill: Query<&Skill>,
     q_damage: Query<&Damage>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     if q_riven.get(entity).is_err() {
@@ -36,9 +37,15 @@ pub fn on_riven_e(
 
     let damage_value = q_damage.get(entity).map(|d| d.0).unwrap_or(64.0);
 
-    let shield_value = get_skill_value(spell_obj, "total_shield", skill.level, |stat| {
-        if stat == 2 { damage_value } else { 0.0 }
-    })
+    let shield_value = get_skill_value_with(
+        spell_obj,
+        "total_shield",
+        skill.level,
+        &calc.caster(entity),
+        |stat| {
+            if stat == 2 { damage_value } else { 0.0 }
+        },
+    )
     .unwrap_or(100.0);
 
     commands.trigger(CommandAnimationPlay {
@@ -63,7 +70,12 @@ pub fn on_riven_e(
     });
 
     // 创建护盾 buff 实体并建立关系
-    let buff_entity = commands.spawn(BuffShieldWhite::new(shield_value)).id();
+    let buff_entity = commands
+        .spawn((
+            BuffShieldWhite::new(shield_value),
+            BuffShieldWhite::descriptor().with_source(entity),
+        ))
+        .id();
     commands
         .entity(entity)
         .add_related::<BuffOf>(&[buff_entity]);
@@ -4,7 +4,7 @@ use lol_base::render_cmd::{CommandSkinParticleDespawn, CommandSkinParticleSpawn}
 use lol_core::attack::EventAttackEnd;
 use lol_core::base::buff::{Buff, BuffOf, Buffs};
 use lol_core::base::level::Level;
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType};
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType};
 use lol_core::skill::EventSkillCast;
 
 use crate::riven::Riven;
@@ -139,6 +139,7 @@ pub fn on_damage_create_trigger_bonus(
             damage_type: DamageType::Physical,
             amount: bonus_damage,
             tag: None,
+            kind: DamageKind::Other,
         });
 
         passive.charges -= 1;
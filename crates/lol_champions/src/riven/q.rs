@@ -9,7 +9,9 @@ use lol_core::attack::CommandAttackReset;
 use lol_core::damage::{Damage, DamageType};
 use lol_core::missile::CommandAttachedFieldCreate;
 use lol_core::movement::{EventMovementEnd, MovementSource};
-use lol_core::skill::{EventSkillCast, Skill, SkillRecastWindow, SkillSlot, get_skill_value};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillCalcQuery, SkillRecastWindow, SkillSlot, get_skill_value_with,
+};
 
 use crate::riven::Riven;
 use crate::riven::buffs::RivenQ3Pending;
@@ -29,6 +31,7 @@ pub fn on_riven_q(
     q_skill: Query<(&Skill, Option<&SkillRecastWindow>)>,
     q_damage: Query<&Damage>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     if q_riven.get(entity).is_err() {
@@ -47,9 +50,15 @@ pub fn on_riven_q(
     };
 
     let damage_value = q_damage.get(entity).map(|d| d.0).unwrap_or(64.0);
-    let q_damage = get_skill_value(spell_obj, "first_slash_damage", skill.level, |stat| {
-        if stat == 2 { damage_value } else { 0.0 }
-    })
+    let q_damage = get_skill_value_with(
+        spell_obj,
+        "first_slash_damage",
+        skill.level,
+        &calc.caster(entity),
+        |stat| {
+            if stat == 2 { damage_value } else { 0.0 }
+        },
+    )
     .unwrap_or(0.0);
 
     let stage = recast.map(|window| window.stage).unwrap_or(1);
@@ -5,11 +5,12 @@ use lol_base::render_cmd::{
 use lol_base::spell::Spell;
 use lol_core::attack::Attack;
 use lol_core::base::buff::{BuffOf, Buffs};
+use lol_core::base::stat_modifier::{StatKind, StatModifier, StatModifiers};
 use lol_core::damage::Damage;
 use lol_core::missile::{CommandMissileCreate, MissileMissingHpScaling};
 use lol_core::skill::{
-    CoolDown, EventSkillCast, Skill, SkillRecastWindow, SkillSlot, get_skill_data_value,
-    get_skill_value,
+    CoolDown, EventSkillCast, Skill, SkillCalcCaster, SkillCalcQuery, SkillRecastWindow, SkillSlot,
+    get_skill_data_value, get_skill_value_with,
 };
 use lol_core::utils::direction_to_angle;
 
@@ -21,8 +22,9 @@ pub fn on_riven_r(
     mut commands: Commands,
     q_riven: Query<(), With<Riven>>,
     mut q_skill: Query<(&Skill, &mut CoolDown, Option<&SkillRecastWindow>)>,
-    mut q_damage: Query<&mut Damage>,
-    mut q_attack: Query<&mut Attack>,
+    q_damage: Query<&Damage>,
+    // 施法者快照只读 Attack，与攻击距离加成的写入互斥
+    mut q_attack_calc: ParamSet<(Query<&mut Attack>, SkillCalcQuery)>,
     mut q_transform: Query<&mut Transform>,
     res_spells: Res<Assets<Spell>>,
     res_asset_server: Res<AssetServer>,
@@ -63,6 +65,7 @@ pub fn on_riven_r(
                 trigger.point,
                 spell_obj,
                 skill.level,
+                &q_attack_calc.p1().caster(entity),
                 damage_value,
             );
 
@@ -85,20 +88,22 @@ pub fn on_riven_r(
                 get_skill_data_value(spell_obj, "TooltipAttackRange", skill.level).unwrap_or(75.0);
             let duration = get_skill_data_value(spell_obj, "Duration", skill.level).unwrap_or(15.0);
 
-            let bonus_ad = damage_value * bonus_ad_ratio;
-
-            if let Ok(mut dmg) = q_damage.get_mut(entity) {
-                dmg.0 += bonus_ad;
-            }
-            if let Ok(mut atk) = q_attack.get_mut(entity) {
+            if let Ok(mut atk) = q_attack_calc.p0().get_mut(entity) {
                 atk.range += bonus_range;
             }
 
-            commands.entity(entity).with_related::<BuffOf>(BuffRivenR {
-                timer: Timer::from_seconds(duration, TimerMode::Once),
-                bonus_ad_ratio,
-                bonus_range,
-            });
+            // AD 加成经属性修正层结算，buff 销毁即撤回
+            commands.entity(entity).with_related::<BuffOf>((
+                BuffRivenR {
+                    timer: Timer::from_seconds(duration, TimerMode::Once),
+                    bonus_ad_ratio,
+                    bonus_range,
+                },
+                StatModifiers(vec![StatModifier::percent(
+                    StatKind::AttackDamage,
+                    bonus_ad_ratio,
+                )]),
+            ));
 
             // 覆盖冷却为真实 R 冷却（cooldownTime 数组以 level 为索引以避免 nil 占位）
             let r_cd = spell_obj
@@ -138,6 +143,7 @@ pub fn on_riven_r(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn cast_riven_wind_slash(
     commands: &mut Commands,
     entity: Entity,
@@ -146,6 +152,7 @@ fn cast_riven_wind_slash(
     target_point: Vec2,
     spell_obj: &Spell,
     skill_level: usize,
+    caster: &SkillCalcCaster,
     total_ad: f32,
 ) {
     let Ok(mut transform) = q_transform.get_mut(entity) else {
@@ -161,12 +168,12 @@ fn cast_riven_wind_slash(
         *transform.forward()
     };
 
-    let min_damage = get_skill_value(spell_obj, "min_damage", skill_level, |stat| {
+    let min_damage = get_skill_value_with(spell_obj, "min_damage", skill_level, caster, |stat| {
         if stat == 2 { total_ad } else { 0.0 }
     })
     .unwrap_or(50.0);
 
-    let max_damage = get_skill_value(spell_obj, "max_damage", skill_level, |stat| {
+    let max_damage = get_skill_value_with(spell_obj, "max_damage", skill_level, caster, |stat| {
         if stat == 2 { total_ad } else { 0.0 }
     })
     .unwrap_or(150.0);
@@ -215,20 +222,20 @@ fn cast_riven_wind_slash(
     }
 }
 
-/// 更新 R buff 计时器，到期后移除并恢复属性
+/// 更新 R buff 计时器，到期后移除并恢复攻击距离（AD 加成随 buff 撤回）
 pub fn update_riven_buffs(
     mut commands: Commands,
-    mut q_champion: Query<(Entity, &Buffs, &mut Damage, &mut Attack), With<Riven>>,
+    mut q_champion: Query<(Entity, &Buffs, &mut Attack), With<Riven>>,
     mut q_buff_r: Query<(Entity, &mut BuffRivenR)>,
     time: Res<Time<Fixed>>,
 ) {
     // 收集到期的 buff 及其存储的加成值，避免二次借用
-    let mut expired: Vec<(Entity, f32, f32)> = Vec::new();
+    let mut expired: Vec<(Entity, f32)> = Vec::new();
 
     for (buff_entity, mut buff) in q_buff_r.iter_mut() {
         buff.timer.tick(time.delta());
         if buff.timer.is_finished() {
-            expired.push((buff_entity, buff.bonus_ad_ratio, buff.bonus_range));
+            expired.push((buff_entity, buff.bonus_range));
         }
     }
 
@@ -236,16 +243,13 @@ pub fn update_riven_buffs(
         return;
     }
 
-    let expired_entities: Vec<Entity> = expired.iter().map(|(e, _, _)| *e).collect();
+    let expired_entities: Vec<Entity> = expired.iter().map(|(e, _)| *e).collect();
 
     // 遍历所有 Riven 实体，检查它们的 buffs 是否有过期的 R 被动
-    for (champion, buffs, mut damage, mut attack) in q_champion.iter_mut() {
+    for (champion, buffs, mut attack) in q_champion.iter_mut() {
         for buff_entity in buffs.iter() {
-            if let Some((_, bonus_ad_ratio, bonus_range)) =
-                expired.iter().find(|(e, _, _)| *e == buff_entity)
-            {
-                // R 到期，用 BuffRivenR 中存储的数值恢复属性
-                damage.0 /= 1.0 + bonus_ad_ratio;
+            if let Some((_, bonus_range)) = expired.iter().find(|(e, _)| *e == buff_entity) {
+                // R 到期，用 BuffRivenR 中存储的数值恢复攻击距离
                 attack.range -= bonus_range;
                 // R 到期未放风斩：撤下大剑光效
                 commands.trigger(CommandSkinParticleDespawn {
@@ -8,7 +8,7 @@ use lol_core::buffs::common_buffs::BuffCastBlock;
 use lol_core::damage::Damage;
 use lol_core::life::Health;
 use lol_core::missile::CommandAttachedFieldCreate;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot, get_skill_value};
+use lol_core::skill::{EventSkillCast, Skill, SkillCalcQuery, SkillSlot, get_skill_value_with};
 use lol_core::team::Team;
 
 use crate::riven::Riven;
@@ -29,6 +29,7 @@ pub fn on_riven_w(
     q_team: Query<&Team>,
     q_targets: Query<(Entity, &Team, &Transform, &Health)>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     if q_riven.get(entity).is_err() {
@@ -48,9 +49,15 @@ pub fn on_riven_w(
 
     let damage_value = q_damage.get(entity).map(|d| d.0).unwrap_or(64.0);
 
-    let w_damage = get_skill_value(spell_obj, "total_damage", skill.level, |stat| {
-        if stat == 2 { damage_value } else { 0.0 }
-    })
+    let w_damage = get_skill_value_with(
+        spell_obj,
+        "total_damage",
+        skill.level,
+        &calc.caster(entity),
+        |stat| {
+            if stat == 2 { damage_value } else { 0.0 }
+        },
+    )
     .unwrap_or(150.0);
 
     // 动画 + 伤害场 + 自阻塞
@@ -9,7 +9,9 @@ use lol_core::action::damage::{
 use lol_core::base::buff::BuffOf;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillSlot, SkillTarget, SkillTargeting, attach_skill_targeting,
+};
 
 use crate::rumble::buffs::BuffRumbleW;
 
@@ -23,6 +25,10 @@ impl Plugin for PluginRumble {
         app.add_observer(on_rumble_e);
         app.add_observer(on_rumble_r);
         app.add_observer(on_rumble_damage_hit);
+        app.add_observer(attach_skill_targeting::<Rumble>(
+            SkillSlot::R,
+            SkillTargeting::Vector { max_length: 1000.0 },
+        ));
     }
 }
 
@@ -176,12 +182,20 @@ fn on_rumble_r(
         repeat: false,
         duration: None,
     });
-    // R is electro fire - large AoE damage
+    // R 为向量施法：沿 start→end 铺一条火线
+    let shape = match trigger.target {
+        SkillTarget::Vector { start, end } => DamageShape::Line {
+            start,
+            end,
+            width: 200.0,
+        },
+        _ => DamageShape::Circle { radius: 900.0 },
+    };
     commands.trigger(ActionDamage {
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Circle { radius: 900.0 },
+            shape,
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::All,
                 amount: "total_damage".to_string(),
@@ -9,10 +9,15 @@ use lol_core::action::damage::{
 use lol_core::base::buff::BuffOf;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
+use lol_core::missile_block::{BlockerShape, MissileBlocker};
 use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::team::Team;
 
 use crate::samira::buffs::BuffSamiraE;
 
+const SAMIRA_W_BLOCK_RADIUS: f32 = 325.0;
+const SAMIRA_W_DURATION: f32 = 0.75;
+
 #[derive(Default)]
 pub struct PluginSamira;
 
@@ -79,13 +84,13 @@ fn on_samira_q(
 fn on_samira_w(
     trigger: On<EventSkillCast>,
     mut commands: Commands,
-    q_samira: Query<(), With<Samira>>,
+    q_samira: Query<&Team, With<Samira>>,
     q_skill: Query<&Skill>,
 ) {
     let entity = trigger.event_target();
-    if q_samira.get(entity).is_err() {
+    let Ok(team) = q_samira.get(entity) else {
         return;
-    }
+    };
 
     let Ok(skill) = q_skill.get(trigger.skill_entity) else {
         return;
@@ -101,7 +106,19 @@ fn on_samira_w(
         repeat: false,
         duration: None,
     });
-    // W is blade storm - AoE damage
+    // W is blade storm - destroys enemy projectiles and deals AoE damage
+    commands.spawn((
+        MissileBlocker::new(
+            entity,
+            *team,
+            BlockerShape::Circle {
+                radius: SAMIRA_W_BLOCK_RADIUS,
+            },
+            SAMIRA_W_DURATION,
+        )
+        .following(entity, Vec3::ZERO),
+        Transform::default(),
+    ));
     commands.trigger(ActionDamage {
         entity,
         skill: skill_spell,
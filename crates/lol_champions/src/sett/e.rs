@@ -13,7 +13,7 @@ use lol_core::action::displace::{
     ActionDisplace, ConeHitPolicy, DisplaceEffect, DisplaceMotion, DisplaceTargetSelection,
 };
 use lol_core::damage::{Damage, DamageType};
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot, get_skill_value};
+use lol_core::skill::{EventSkillCast, Skill, SkillCalcQuery, SkillSlot, get_skill_value_with};
 
 use crate::sett::Sett;
 use crate::sett::buffs::SETT_E_TAG;
@@ -39,6 +39,7 @@ pub fn on_sett_e(
     q_transform: Query<&Transform>,
     q_damage: Query<&Damage>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     if q_sett.get(entity).is_err() {
@@ -76,9 +77,15 @@ pub fn on_sett_e(
     };
 
     let ad = q_damage.get(entity).map(|d| d.0).unwrap_or(0.0);
-    let damage = get_skill_value(spell_obj, "damage_calc", skill.level, |stat| {
-        if stat == 2 { ad } else { 0.0 }
-    })
+    let damage = get_skill_value_with(
+        spell_obj,
+        "damage_calc",
+        skill.level,
+        &calc.caster(entity),
+        |stat| {
+            if stat == 2 { ad } else { 0.0 }
+        },
+    )
     .unwrap_or(0.0);
 
     // 使用统一位移体系：双锥形检测 + 拉回 + 伤害 + 双侧眩晕/单侧减速
@@ -7,7 +7,7 @@ use bevy::prelude::*;
 use lol_core::attack::EventAttackEnd;
 use lol_core::base::buff::BuffOf;
 use lol_core::buffs::cc_debuffs::DebuffSlow;
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType, EventDamageCreate};
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType, EventDamageCreate};
 use lol_core::life::Health;
 
 use crate::sett::Sett;
@@ -72,6 +72,7 @@ pub fn on_sett_attack_end(
                 damage_type: DamageType::Physical,
                 amount: bonus,
                 tag: None,
+                kind: DamageKind::Other,
             });
         }
     }
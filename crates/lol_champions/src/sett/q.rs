@@ -6,7 +6,7 @@ use bevy::prelude::*;
 use lol_base::animation_names::ANIM_SPELL1;
 use lol_base::render_cmd::CommandAnimationPlay;
 use lol_core::attack::CommandAttackReset;
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::{BuffCommandsExt, BuffOf};
 use lol_core::buffs::common_buffs::BuffMoveSpeed;
 use lol_core::buffs::on_hit::{BuffOnHitCounter, BuffOnHitTargetMaxHp};
 use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
@@ -52,7 +52,13 @@ pub fn on_sett_q(
 
     commands
         .entity(entity)
-        .with_related::<BuffOf>(BuffOnHitCounter::new(SETT_Q_ATTACKS, SETT_Q_DURATION))
+        .apply_buff(
+            BuffOnHitCounter::descriptor(SETT_Q_DURATION).with_source(entity),
+            BuffOnHitCounter::new(SETT_Q_ATTACKS),
+        )
         .with_related::<BuffOf>(BuffOnHitTargetMaxHp { ratio })
-        .with_related::<BuffOf>(BuffMoveSpeed::new(SETT_Q_MS_BONUS, SETT_Q_DURATION));
+        .apply_buff(
+            BuffMoveSpeed::descriptor(SETT_Q_DURATION).with_source(entity),
+            BuffMoveSpeed::new(SETT_Q_MS_BONUS),
+        );
 }
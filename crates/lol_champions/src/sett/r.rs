@@ -20,9 +20,9 @@ use lol_core::action::displace::{
 use lol_core::base::buff::BuffOf;
 use lol_core::buffs::cc_debuffs::DebuffKnockup;
 use lol_core::damage::{Damage, DamageType};
-use lol_core::entities::champion::Champion;
+use lol_core::entities::champion::TargetableAsChampion;
 use lol_core::movement::{EventMovementEnd, MovementSource};
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot, get_skill_value};
+use lol_core::skill::{EventSkillCast, Skill, SkillCalcQuery, SkillSlot, get_skill_value_with};
 use lol_core::team::Team;
 
 use crate::sett::Sett;
@@ -54,7 +54,8 @@ pub fn on_sett_r(
     res_spells: Res<Assets<Spell>>,
     q_transform: Query<&Transform>,
     q_team: Query<&Team>,
-    q_enemies: Query<(Entity, &Transform, &Team), With<Champion>>,
+    q_enemies: Query<(Entity, &Transform, &Team), With<TargetableAsChampion>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     if q_sett.get(entity).is_err() {
@@ -119,9 +120,15 @@ pub fn on_sett_r(
     });
 
     let ad = q_damage.get(entity).map(|d| d.0).unwrap_or(0.0);
-    let damage = get_skill_value(spell_obj, "damage_calc", skill.level, |stat| {
-        if stat == 2 { ad } else { 0.0 }
-    })
+    let damage = get_skill_value_with(
+        spell_obj,
+        "damage_calc",
+        skill.level,
+        &calc.caster(entity),
+        |stat| {
+            if stat == 2 { ad } else { 0.0 }
+        },
+    )
     .unwrap_or(0.0);
 
     commands.entity(entity).insert(SettRLandingPending {
@@ -58,7 +58,11 @@ pub fn on_sett_w(
     // 灰心 -> 白盾子 buff
     if grit_stored > 0.0 {
         let shield_entity = commands
-            .spawn((BuffShieldWhite::new(grit_stored), SettWShield::new()))
+            .spawn((
+                BuffShieldWhite::new(grit_stored),
+                BuffShieldWhite::descriptor().with_source(entity),
+                SettWShield::new(),
+            ))
             .id();
         commands
             .entity(entity)
@@ -9,10 +9,20 @@ use lol_core::action::damage::{
 use lol_core::base::buff::BuffOf;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::entities::pet::{CommandCloneSpawn, PetAi, PetClone};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillSlot, SkillTargetFilter, SkillTargeting, attach_skill_targeting,
+};
 
 use crate::shaco::buffs::BuffShacoW;
 
+/// R 幻像持续时间（秒）
+const SHACO_R_DURATION: f32 = 18.0;
+/// R 幻像造成伤害倍率
+const SHACO_R_DAMAGE_DEALT: f32 = 0.75;
+/// R 幻像承受伤害倍率
+const SHACO_R_DAMAGE_TAKEN: f32 = 1.5;
+
 #[derive(Default)]
 pub struct PluginShaco;
 
@@ -23,6 +33,10 @@ impl Plugin for PluginShaco {
         app.add_observer(on_shaco_e);
         app.add_observer(on_shaco_r);
         app.add_observer(on_shaco_damage_hit);
+        app.add_observer(attach_skill_targeting::<Shaco>(
+            SkillSlot::E,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -130,9 +144,7 @@ fn on_shaco_e(
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Nearest {
-                max_distance: 625.0,
-            },
+            shape: DamageShape::unit_or_nearest(trigger.target, 625.0),
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::All,
                 amount: "total_damage".to_string(),
@@ -147,13 +159,13 @@ fn on_shaco_e(
 fn on_shaco_r(
     trigger: On<EventSkillCast>,
     mut commands: Commands,
-    q_shaco: Query<(), With<Shaco>>,
+    q_shaco: Query<&Transform, With<Shaco>>,
     q_skill: Query<&Skill>,
 ) {
     let entity = trigger.event_target();
-    if q_shaco.get(entity).is_err() {
+    let Ok(transform) = q_shaco.get(entity) else {
         return;
-    }
+    };
 
     let Ok(skill) = q_skill.get(trigger.skill_entity) else {
         return;
@@ -168,7 +180,14 @@ fn on_shaco_r(
         repeat: false,
         duration: None,
     });
-    // R is halluate - explosion;
+    // R 在身边召出可指挥的幻像
+    commands.trigger(CommandCloneSpawn {
+        entity,
+        position: transform.translation.xz(),
+        duration: SHACO_R_DURATION,
+        pet_clone: PetClone::new(SHACO_R_DAMAGE_DEALT, SHACO_R_DAMAGE_TAKEN),
+        ai: PetAi::controllable(),
+    });
 }
 
 fn on_shaco_damage_hit(
@@ -9,7 +9,9 @@ use lol_core::action::damage::{
 use lol_core::base::buff::BuffOf;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillSlot, SkillTargetFilter, SkillTargeting, attach_skill_targeting,
+};
 
 use crate::singed::buffs::BuffSingedE;
 
@@ -23,6 +25,10 @@ impl Plugin for PluginSinged {
         app.add_observer(on_singed_e);
         app.add_observer(on_singed_r);
         app.add_observer(on_singed_damage_hit);
+        app.add_observer(attach_skill_targeting::<Singed>(
+            SkillSlot::E,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -130,9 +136,7 @@ fn on_singed_e(
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Nearest {
-                max_distance: 400.0,
-            },
+            shape: DamageShape::unit_or_nearest(trigger.target, 400.0),
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::All,
                 amount: "total_damage".to_string(),
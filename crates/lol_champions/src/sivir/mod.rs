@@ -7,12 +7,15 @@ use lol_core::action::damage::{
     ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
 };
 use lol_core::base::buff::BuffOf;
+use lol_core::buffs::spell_shield::BuffSpellShield;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
 use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
 
 use crate::sivir::buffs::BuffSivirW;
 
+const SIVIR_E_DURATION: f32 = 1.5;
+
 #[derive(Default)]
 pub struct PluginSivir;
 
@@ -130,7 +133,10 @@ fn on_sivir_e(
         repeat: false,
         duration: None,
     });
-    // E is spell shield - magic shield;
+    // E is spell shield - blocks the next enemy ability
+    commands
+        .entity(entity)
+        .with_related::<BuffOf>(BuffSpellShield::new(SIVIR_E_DURATION));
 }
 
 fn on_sivir_r(
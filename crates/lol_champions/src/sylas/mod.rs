@@ -10,7 +10,10 @@ use lol_core::buffs::cc_debuffs::DebuffSlow;
 use lol_core::buffs::common_buffs::BuffSelfHeal;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{CoolDown, EventSkillCast, Skill, SkillRecastWindow, SkillSlot};
+use lol_core::skill::{
+    CoolDown, EventSkillCast, Skill, SkillRecastWindow, SkillSlot, SkillTargetFilter,
+    SkillTargeting, attach_skill_targeting,
+};
 
 const SYLAS_E_RECAST_WINDOW: f32 = 4.0;
 
@@ -24,6 +27,10 @@ impl Plugin for PluginSylas {
         app.add_observer(on_sylas_e);
         app.add_observer(on_sylas_r);
         app.add_observer(on_sylas_damage_hit);
+        app.add_observer(attach_skill_targeting::<Sylas>(
+            SkillSlot::R,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -217,9 +224,7 @@ fn on_sylas_r(
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Nearest {
-                max_distance: 400.0,
-            },
+            shape: DamageShape::unit_or_nearest(trigger.target, 400.0),
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::Champion,
                 amount: "total_damage".to_string(),
@@ -9,7 +9,9 @@ use lol_core::action::damage::{
 use lol_core::base::buff::BuffOf;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillSlot, SkillTargetFilter, SkillTargeting, attach_skill_targeting,
+};
 
 use crate::syndra::buffs::BuffSyndraE;
 
@@ -23,6 +25,10 @@ impl Plugin for PluginSyndra {
         app.add_observer(on_syndra_e);
         app.add_observer(on_syndra_r);
         app.add_observer(on_syndra_damage_hit);
+        app.add_observer(attach_skill_targeting::<Syndra>(
+            SkillSlot::R,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -186,9 +192,7 @@ fn on_syndra_r(
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Nearest {
-                max_distance: 675.0,
-            },
+            shape: DamageShape::unit_or_nearest(trigger.target, 675.0),
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::All,
                 amount: "total_damage".to_string(),
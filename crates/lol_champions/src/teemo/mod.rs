@@ -9,7 +9,9 @@ use lol_core::action::damage::{
 use lol_core::base::buff::BuffOf;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillSlot, SkillTargetFilter, SkillTargeting, attach_skill_targeting,
+};
 
 use crate::teemo::buffs::BuffTeemoQ;
 
@@ -23,6 +25,10 @@ impl Plugin for PluginTeemo {
         app.add_observer(on_teemo_e);
         app.add_observer(on_teemo_r);
         app.add_observer(on_teemo_damage_hit);
+        app.add_observer(attach_skill_targeting::<Teemo>(
+            SkillSlot::Q,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -60,9 +66,7 @@ fn on_teemo_q(
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Nearest {
-                max_distance: 680.0,
-            },
+            shape: DamageShape::unit_or_nearest(trigger.target, 680.0),
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::All,
                 amount: "total_damage".to_string(),
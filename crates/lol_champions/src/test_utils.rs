@@ -3,6 +3,7 @@
 use std::fs;
 use std::path::PathBuf;
 
+use bevy::ecs::system::RunSystemOnce;
 use bevy::prelude::*;
 use bevy::time::TimeUpdateStrategy;
 use bevy::winit::WinitPlugin;
@@ -12,14 +13,15 @@ use lol_core::action::{Action, CommandAction};
 use lol_core::base::ability_resource::AbilityResource;
 use lol_core::base::buff::Buffs;
 use lol_core::buffs::shield_white::BuffShieldWhite;
-use lol_core::damage::{CommandDamageCreate, DamageType};
+use lol_core::damage::{CommandDamageCreate, DamageKind, DamageType};
 use lol_core::entities::champion::Champion;
 use lol_core::life::Health;
 use lol_core::log::create_log_plugin;
 use lol_core::navigation::grid::ResourceGrid;
 use lol_core::navigation::navigation::NavigationDebug;
 use lol_core::skill::{
-    CoolDown, Skill, SkillRecastWindow, Skills, get_skill_value, is_skill_ready,
+    CoolDown, Skill, SkillCalcQuery, SkillRecastWindow, Skills, get_skill_value_with,
+    is_skill_ready,
 };
 use lol_core::team::Team;
 use lol_render::PluginRender;
@@ -79,20 +81,23 @@ impl ChampionTestHarness {
         test_name: &str,
         mode: HarnessMode,
         config: &ChampionHarnessConfig,
+    ) -> Self {
+        Self::build_with(test_name, mode, config, C::default())
+    }
+
+    /// 同 [`Self::build`]，英雄标记直接传入（未实现 `Default` 的标记也可用）。
+    pub fn build_with(
+        test_name: &str,
+        mode: HarnessMode,
+        config: &ChampionHarnessConfig,
+        marker: impl Bundle,
     ) -> Self {
         let mut app = App::new();
         app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
 
         let mode = mode.resolve();
 
-        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
-            .unwrap_or_else(|_| env!("CARGO_MANIFEST_DIR").to_string());
-        let workspace_root = PathBuf::from(&manifest_dir)
-            .parent()
-            .map(|p| p.parent())
-            .flatten()
-            .map(|p| p.to_path_buf())
-            .unwrap_or_else(|| PathBuf::from(&manifest_dir));
+        let workspace_root = workspace_root();
 
         let asset_plugin = bevy::asset::AssetPlugin {
             file_path: workspace_root.join("assets").to_string_lossy().to_string(),
@@ -157,7 +162,7 @@ impl ChampionTestHarness {
         let champion = app
             .world_mut()
             .spawn((
-                C::default(),
+                marker,
                 Transform::default(),
                 Team::Order,
                 ConfigCharacterRecord {
@@ -353,6 +358,7 @@ impl ChampionTestHarness {
             damage_type: DamageType::Physical,
             amount,
             tag: None,
+            kind: DamageKind::Other,
         });
         self
     }
@@ -369,6 +375,14 @@ impl ChampionTestHarness {
         None
     }
 
+    /// 实体身上第一个带 `T` 的 buff
+    pub fn buff<T: Component>(&self, entity: Entity) -> Option<&T> {
+        let buffs = self.app.world().get::<Buffs>(entity)?;
+        buffs
+            .iter()
+            .find_map(|buff| self.app.world().get::<T>(buff))
+    }
+
     pub fn position(&self, entity: Entity) -> Vec3 {
         self.app
             .world()
@@ -439,16 +453,23 @@ impl ChampionTestHarness {
             .contains::<SkillRecastWindow>()
     }
 
-    /// Calculates a skill effect value by name, e.g., "total_damage".
+    /// Calculates a skill effect value by name, e.g., "total_damage",
+    /// against the champion's current level, health and buffs.
     pub fn get_skill_value(
-        &self,
+        &mut self,
         index: usize,
         name: &str,
         level: usize,
         stat_getter: impl Fn(u8) -> f32,
     ) -> Option<f32> {
+        let champion = self.champion;
+        let caster = self
+            .app
+            .world_mut()
+            .run_system_once(move |calc: SkillCalcQuery| calc.caster(champion))
+            .ok()?;
         let spell = self.spell(index)?;
-        get_skill_value(spell, name, level, stat_getter)
+        get_skill_value_with(spell, name, level, &caster, stat_getter)
     }
 
     pub fn spell(&self, index: usize) -> Option<&Spell> {
@@ -477,6 +498,17 @@ impl ChampionTestHarness {
 
 // ── Shared free functions ──
 
+pub fn workspace_root() -> PathBuf {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .unwrap_or_else(|_| env!("CARGO_MANIFEST_DIR").to_string());
+    PathBuf::from(&manifest_dir)
+        .parent()
+        .map(|p| p.parent())
+        .flatten()
+        .map(|p| p.to_path_buf())
+        .unwrap_or_else(|| PathBuf::from(&manifest_dir))
+}
+
 pub fn render_output_dir(workspace_root: &PathBuf, champion: &str) -> PathBuf {
     workspace_root
         .join("assets")
@@ -9,7 +9,9 @@ use lol_core::action::damage::{
 use lol_core::base::buff::BuffOf;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::entities::champion::Champion;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillSlot, SkillTargetFilter, SkillTargeting, attach_skill_targeting,
+};
 
 use crate::tristana::buffs::BuffTristanaW;
 
@@ -23,6 +25,14 @@ impl Plugin for PluginTristana {
         app.add_observer(on_tristana_e);
         app.add_observer(on_tristana_r);
         app.add_observer(on_tristana_damage_hit);
+        app.add_observer(attach_skill_targeting::<Tristana>(
+            SkillSlot::E,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
+        app.add_observer(attach_skill_targeting::<Tristana>(
+            SkillSlot::R,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
     }
 }
 
@@ -127,9 +137,7 @@ fn on_tristana_e(
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Nearest {
-                max_distance: 700.0,
-            },
+            shape: DamageShape::unit_or_nearest(trigger.target, 700.0),
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::All,
                 amount: "total_damage".to_string(),
@@ -170,9 +178,7 @@ fn on_tristana_r(
         entity,
         skill: skill_spell,
         effects: vec![ActionDamageEffect {
-            shape: DamageShape::Nearest {
-                max_distance: 700.0,
-            },
+            shape: DamageShape::unit_or_nearest(trigger.target, 700.0),
             damage_list: vec![TargetDamage {
                 filter: TargetFilter::All,
                 amount: "total_damage".to_string(),
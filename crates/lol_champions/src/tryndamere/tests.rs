@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use bevy::math::{Vec2, Vec3};
-use lol_core::damage::{CommandDamageCreate, DamageType};
+use lol_core::damage::{CommandDamageCreate, DamageKind, DamageType};
 use lol_core::movement::MovementSlow;
 
 use crate::test_utils::*;
@@ -37,6 +37,7 @@ fn tryndamere_damage_hit_slows_enemy() {
             damage_type: DamageType::Physical,
             amount: 10.0,
             tag: None,
+            kind: DamageKind::Other,
         });
     h.advance(0.2);
 
@@ -7,7 +7,7 @@ use lol_core::action::damage::{
     ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
 };
 use lol_core::action::dash::{ActionDash, DashMoveType};
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::{BuffCommandsExt, BuffOf};
 use lol_core::buffs::cc_debuffs::DebuffSlow;
 use lol_core::buffs::shield_white::BuffShieldWhite;
 use lol_core::damage::{DamageType, EventDamageCreate};
@@ -160,9 +160,10 @@ fn on_urgot_e(
         move_type: DashMoveType::Pointer { max: 300.0 }, // E doesn't deal damage directly but knockback
         speed: 700.0,
     });
-    commands
-        .entity(entity)
-        .with_related::<BuffOf>(BuffShieldWhite::new(100.0));
+    commands.entity(entity).apply_buff(
+        BuffShieldWhite::descriptor().with_source(entity),
+        BuffShieldWhite::new(100.0),
+    );
 }
 
 fn on_urgot_r(
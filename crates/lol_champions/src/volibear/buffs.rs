@@ -46,6 +46,11 @@ impl Default for VolibearPStacks {
     }
 }
 
+/// R 变身：额外最大生命由同实体上的属性修正结算，到期销毁即撤回
+#[derive(Component, Debug, Default, Clone)]
+#[require(Buff = Buff { name: "VolibearR" })]
+pub struct BuffVolibearR;
+
 /// R 突进中，落地后以落点为圆心触发 AoE 伤害 + 减速 + 增加最大生命。
 #[derive(Component, Debug, Default)]
 pub struct VolibearRLandingPending {
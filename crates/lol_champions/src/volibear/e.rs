@@ -8,7 +8,7 @@ use lol_base::render_cmd::CommandAnimationPlay;
 use lol_base::spell::Spell;
 use lol_core::action::damage::{ActionDamageEffect, DamageShape, TargetDamage, TargetFilter};
 use lol_core::action::delayed_damage::{ActionDelayedDamage, AoEIndicator, AoEOrigin};
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::BuffCommandsExt;
 use lol_core::buffs::shield_white::BuffShieldWhite;
 use lol_core::damage::DamageType;
 use lol_core::skill::{
@@ -74,7 +74,8 @@ pub fn on_volibear_e(
     });
 
     // 落地护盾
-    commands
-        .entity(entity)
-        .with_related::<BuffOf>(BuffShieldWhite::new(100.0));
+    commands.entity(entity).apply_buff(
+        BuffShieldWhite::descriptor().with_source(entity),
+        BuffShieldWhite::new(100.0),
+    );
 }
@@ -6,8 +6,10 @@ use bevy::prelude::*;
 use lol_core::attack::{BuffAttack, EventAttackEnd};
 use lol_core::base::buff::{BuffOf, Buffs};
 use lol_core::buffs::cc_debuffs::DebuffSlow;
-use lol_core::damage::{AbilityPower, CommandDamageCreate, Damage, DamageType, EventDamageCreate};
-use lol_core::entities::champion::Champion;
+use lol_core::damage::{
+    AbilityPower, CommandDamageCreate, Damage, DamageKind, DamageType, EventDamageCreate,
+};
+use lol_core::entities::champion::TargetableAsChampion;
 use lol_core::team::Team;
 
 use crate::volibear::Volibear;
@@ -63,7 +65,7 @@ pub fn on_volibear_attack_end(
     >,
     q_buff_attack: Query<&BuffAttack>,
     q_ap: Query<&AbilityPower>,
-    q_enemies: Query<(Entity, &Transform, &Team), With<Champion>>,
+    q_enemies: Query<(Entity, &Transform, &Team), With<TargetableAsChampion>>,
 ) {
     let attacker = trigger.event_target();
     let Ok((mut stacks, transform, team, damage, buffs)) = q_volibear.get_mut(attacker) else {
@@ -109,6 +111,7 @@ pub fn on_volibear_attack_end(
                         damage_type: DamageType::Magic,
                         amount: chain_dmg,
                         tag: None,
+                        kind: DamageKind::Other,
                     });
                 }
             }
@@ -7,11 +7,13 @@ use lol_base::animation_names::ANIM_SPELL1;
 use lol_base::render_cmd::CommandAnimationPlay;
 use lol_base::spell::Spell;
 use lol_core::attack::CommandAttackReset;
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::{BuffCommandsExt, BuffOf};
 use lol_core::buffs::common_buffs::BuffMoveSpeed;
 use lol_core::buffs::on_hit::{BuffOnHitBonusDamage, BuffOnHitCounter, BuffOnHitStun};
 use lol_core::damage::Damage;
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot, get_skill_data_value, get_skill_value};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillCalcQuery, SkillSlot, get_skill_data_value, get_skill_value_with,
+};
 
 use crate::volibear::Volibear;
 
@@ -22,6 +24,7 @@ pub fn on_volibear_q(
     q_skill: Query<&Skill>,
     q_damage: Query<&Damage>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     if q_volibear.get(entity).is_err() {
@@ -46,9 +49,15 @@ pub fn on_volibear_q(
     });
     commands.trigger(CommandAttackReset { entity });
 
-    let bonus = get_skill_value(spell_obj, "calculated_damage", skill.level, |stat| {
-        if stat == 2 { ad } else { 0.0 }
-    })
+    let bonus = get_skill_value_with(
+        spell_obj,
+        "calculated_damage",
+        skill.level,
+        &calc.caster(entity),
+        |stat| {
+            if stat == 2 { ad } else { 0.0 }
+        },
+    )
     .unwrap_or(0.0);
     let ms_bonus = get_skill_data_value(spell_obj, "MaxSpeed", skill.level).unwrap_or(0.17);
     let duration = get_skill_data_value(spell_obj, "Duration", skill.level).unwrap_or(4.0);
@@ -56,7 +65,10 @@ pub fn on_volibear_q(
 
     commands
         .entity(entity)
-        .with_related::<BuffOf>(BuffOnHitCounter::new(1, duration))
+        .apply_buff(
+            BuffOnHitCounter::descriptor(duration).with_source(entity),
+            BuffOnHitCounter::new(1),
+        )
         .with_related::<BuffOf>(BuffOnHitBonusDamage {
             flat: bonus,
             ratio: 0.0,
@@ -64,5 +76,8 @@ pub fn on_volibear_q(
         .with_related::<BuffOf>(BuffOnHitStun {
             duration: stun_duration,
         })
-        .with_related::<BuffOf>(BuffMoveSpeed::new(ms_bonus, duration));
+        .apply_buff(
+            BuffMoveSpeed::descriptor(duration).with_source(entity),
+            BuffMoveSpeed::new(ms_bonus),
+        );
 }
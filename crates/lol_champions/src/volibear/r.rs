@@ -7,17 +7,19 @@ use lol_base::animation_names::ANIM_SPELL4;
 use lol_base::render_cmd::CommandAnimationPlay;
 use lol_base::spell::Spell;
 use lol_core::action::dash::{ActionDash, DashMoveType};
-use lol_core::base::buff::BuffOf;
+use lol_core::base::buff::{BuffCommandsExt, BuffDescriptor, BuffOf, BuffStacking, BuffTag};
+use lol_core::base::stat_modifier::{StatKind, StatModifier, StatModifiers};
 use lol_core::buffs::cc_debuffs::DebuffSlow;
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType};
-use lol_core::entities::champion::Champion;
-use lol_core::life::Health;
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType};
+use lol_core::entities::champion::TargetableAsChampion;
 use lol_core::movement::{EventMovementEnd, MovementSource};
-use lol_core::skill::{EventSkillCast, Skill, SkillSlot, get_skill_data_value, get_skill_value};
+use lol_core::skill::{
+    EventSkillCast, Skill, SkillCalcQuery, SkillSlot, get_skill_data_value, get_skill_value_with,
+};
 use lol_core::team::Team;
 
 use crate::volibear::Volibear;
-use crate::volibear::buffs::VolibearRLandingPending;
+use crate::volibear::buffs::{BuffVolibearR, VolibearRLandingPending};
 
 /// R 伤害标签
 pub const VOLIBEAR_R_TAG: u32 = 2;
@@ -27,6 +29,8 @@ pub const VOLIBEAR_R_LANDING_RADIUS: f32 = 300.0;
 pub const VOLIBEAR_R_MAX_RANGE: f32 = 550.0;
 /// R 突进速度
 pub const VOLIBEAR_R_DASH_SPEED: f32 = 750.0;
+/// R 变身（额外生命）持续时间
+pub const VOLIBEAR_R_DURATION: f32 = 12.0;
 
 pub fn on_volibear_r(
     trigger: On<EventSkillCast>,
@@ -35,6 +39,7 @@ pub fn on_volibear_r(
     q_skill: Query<&Skill>,
     q_damage: Query<&Damage>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     if q_volibear.get(entity).is_err() {
@@ -68,10 +73,11 @@ pub fn on_volibear_r(
     });
 
     let ad = q_damage.get(entity).map(|d| d.0).unwrap_or(0.0);
-    let damage = get_skill_value(
+    let damage = get_skill_value_with(
         spell_obj,
         "sweet_spot_damage_tooltip",
         skill.level,
+        &calc.caster(entity),
         |stat| {
             if stat == 2 { ad } else { 0.0 }
         },
@@ -99,7 +105,7 @@ pub fn on_volibear_r_dash_end(
     q_pending: Query<&VolibearRLandingPending>,
     q_transform: Query<&Transform>,
     q_team: Query<&Team>,
-    q_enemies: Query<(Entity, &Transform, &Team), With<Champion>>,
+    q_enemies: Query<(Entity, &Transform, &Team), With<TargetableAsChampion>>,
 ) {
     if trigger.event().source != MovementSource::Dash {
         return;
@@ -132,6 +138,7 @@ pub fn on_volibear_r_dash_end(
                 damage_type: DamageType::Physical,
                 amount: pending.damage,
                 tag: Some(VOLIBEAR_R_TAG),
+                kind: DamageKind::Ability,
             });
         }
         commands
@@ -139,15 +146,21 @@ pub fn on_volibear_r_dash_end(
             .with_related::<BuffOf>(DebuffSlow::new(pending.slow_percent, pending.slow_duration));
     }
 
-    // 增加最大生命（HealthAmount，当前生命同步增加）
-    let bonus_hp = pending.bonus_hp;
-    if bonus_hp > 0.0 {
-        commands.entity(entity).queue(move |mut e: EntityWorldMut| {
-            if let Some(mut health) = e.get_mut::<Health>() {
-                health.max += bonus_hp;
-                health.value = (health.value + bonus_hp).min(health.max);
-            }
-        });
+    // 变身期间增加最大生命（HealthAmount），当前生命按比例随上限提高
+    if pending.bonus_hp > 0.0 {
+        commands.entity(entity).apply_buff(
+            BuffDescriptor::new(BuffStacking::Renew)
+                .with_source(entity)
+                .with_duration(VOLIBEAR_R_DURATION)
+                .with_tags(&[BuffTag::Positive, BuffTag::Defense]),
+            (
+                BuffVolibearR,
+                StatModifiers(vec![StatModifier::flat(
+                    StatKind::MaxHealth,
+                    pending.bonus_hp,
+                )]),
+            ),
+        );
     }
 
     commands.entity(entity).remove::<VolibearRLandingPending>();
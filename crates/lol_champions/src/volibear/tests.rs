@@ -46,24 +46,17 @@ pub fn attack_end(h: &mut ChampionTestHarness, target: Entity) {
 }
 
 /// 读取英雄当前来自 buff 的额外攻击速度（被动层数 × 每层比例）。
-/// 优先查 BuffAttack 实体（通过 Buffs 关系），其次查 champion 自身。
 pub fn attack_speed_bonus(h: &ChampionTestHarness) -> f32 {
-    // 先从 buff 实体查找
-    if let Some(buffs) = h.app.world().get::<Buffs>(h.champion) {
-        let sum: f32 = buffs
-            .iter()
-            .filter_map(|e| h.app.world().get::<BuffAttack>(e))
-            .map(|b| b.bonus_attack_speed)
-            .sum();
-        if sum > 0.0 {
-            return sum;
-        }
-    }
-    // 再查 champion 自身（直接 insert 模式）
     h.app
         .world()
-        .get::<BuffAttack>(h.champion)
-        .map(|b| b.bonus_attack_speed)
+        .get::<Buffs>(h.champion)
+        .map(|buffs| {
+            buffs
+                .iter()
+                .filter_map(|e| h.app.world().get::<BuffAttack>(e))
+                .map(|b| b.bonus_attack_speed)
+                .sum()
+        })
         .unwrap_or(0.0)
 }
 
@@ -8,12 +8,13 @@ use lol_base::animation_names::ANIM_SPELL2;
 use lol_base::render_cmd::{CommandAnimationPlay, CommandSkinSoundPlay};
 use lol_base::spell::Spell;
 use lol_core::base::buff::{BuffOf, Buffs};
-use lol_core::damage::{CommandDamageCreate, Damage, DamageType};
-use lol_core::entities::champion::Champion;
+use lol_core::damage::{CommandDamageCreate, Damage, DamageKind, DamageType};
+use lol_core::entities::champion::TargetableAsChampion;
+use lol_core::heal::{CommandHeal, HealType};
 use lol_core::life::Health;
 use lol_core::skill::{
-    CoolDown, EventSkillCast, Skill, SkillRecastWindow, SkillSlot, get_skill_cast_radius,
-    get_skill_data_value, get_skill_value,
+    CoolDown, EventSkillCast, Skill, SkillCalcQuery, SkillRecastWindow, SkillSlot,
+    get_skill_cast_radius, get_skill_data_value, get_skill_value_with,
 };
 use lol_core::team::Team;
 
@@ -32,7 +33,7 @@ fn nearest_enemy(
     caster_pos: Vec2,
     range: f32,
     caster_team: &Team,
-    q_enemies: &Query<(Entity, &Transform), With<Champion>>,
+    q_enemies: &Query<(Entity, &Transform), With<TargetableAsChampion>>,
     q_team: &Query<&Team>,
 ) -> Option<Entity> {
     let mut best: Option<(Entity, f32)> = None;
@@ -75,12 +76,13 @@ pub fn on_volibear_w(
     q_skill: Query<(&Skill, &CoolDown, Option<&SkillRecastWindow>)>,
     q_transform: Query<&Transform>,
     q_team: Query<&Team>,
-    q_enemies: Query<(Entity, &Transform), With<Champion>>,
+    q_enemies: Query<(Entity, &Transform), With<TargetableAsChampion>>,
     q_buffs: Query<&Buffs>,
     q_mark: Query<&DebuffVolibearWMark>,
     q_health: Query<&Health>,
     q_damage: Query<&Damage>,
     res_spells: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     let entity = trigger.event_target();
     if q_volibear.get(entity).is_err() {
@@ -115,15 +117,21 @@ pub fn on_volibear_w(
     });
 
     let stage = recast.map(|w| w.stage).unwrap_or(1);
-    let total_damage = get_skill_value(spell_obj, "total_damage", skill.level, |stat| {
-        if stat == 2 {
-            ad
-        } else if stat == 12 {
-            max_hp
-        } else {
-            0.0
-        }
-    })
+    let total_damage = get_skill_value_with(
+        spell_obj,
+        "total_damage",
+        skill.level,
+        &calc.caster(entity),
+        |stat| {
+            if stat == 2 {
+                ad
+            } else if stat == 12 {
+                max_hp
+            } else {
+                0.0
+            }
+        },
+    )
     .unwrap_or(0.0);
     let cast_range = get_skill_cast_radius(spell_obj, skill.level).unwrap_or(325.0);
 
@@ -131,7 +139,7 @@ pub fn on_volibear_w(
         // W1：开启重施窗口 + 咬最近敌人 + 标记
         commands
             .entity(trigger.skill_entity)
-            .insert(SkillRecastWindow::new(2, 2, VOLIBEAR_W_RECAST_WINDOW));
+            .insert(SkillRecastWindow::second_cast(VOLIBEAR_W_RECAST_WINDOW));
         if let Some(enemy) = nearest_enemy(caster_pos, cast_range, caster_team, &q_enemies, &q_team)
         {
             if total_damage > 0.0 {
@@ -141,6 +149,7 @@ pub fn on_volibear_w(
                     damage_type: DamageType::Physical,
                     amount: total_damage,
                     tag: Some(VOLIBEAR_W_TAG),
+                    kind: DamageKind::Ability,
                 });
             }
             commands
@@ -178,6 +187,7 @@ pub fn on_volibear_w(
                     damage_type: DamageType::Physical,
                     amount: dmg,
                     tag: Some(VOLIBEAR_W_TAG),
+                    kind: DamageKind::Ability,
                 });
             }
             commands.trigger(CommandSkinSoundPlay {
@@ -192,10 +202,11 @@ pub fn on_volibear_w(
                 let missing = (max_hp - current_hp).max(0.0);
                 let heal = base_heal + heal_percent * missing;
                 if heal > 0.0 {
-                    commands.entity(entity).queue(move |mut e: EntityWorldMut| {
-                        if let Some(mut health) = e.get_mut::<Health>() {
-                            health.value = (health.value + heal).min(health.max);
-                        }
+                    commands.trigger(CommandHeal {
+                        entity,
+                        source: entity,
+                        heal_type: HealType::Heal,
+                        amount: heal,
                     });
                 }
             }
@@ -0,0 +1,365 @@
+//! 狼人：R 压制、E 恐惧，作为新控制类型（压制 / 恐惧）在英雄套件中的落地。
+
+use bevy::prelude::*;
+use lol_base::animation_names::{ANIM_SPELL1, ANIM_SPELL2, ANIM_SPELL3, ANIM_SPELL4};
+use lol_base::render_cmd::CommandAnimationPlay;
+use lol_core::action::damage::{
+    ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter,
+};
+use lol_core::base::buff::{Buff, BuffCommandsExt, BuffOf, Buffs};
+use lol_core::buffs::cc_debuffs::{DebuffFear, DebuffSuppression};
+use lol_core::buffs::common_buffs::BuffMoveSpeed;
+use lol_core::buffs::damage_reduction::BuffDamageReduction;
+use lol_core::damage::{DamageType, EventDamageCreate};
+use lol_core::entities::champion::{Champion, TargetableAsChampion};
+use lol_core::heal::{CommandHeal, HealType};
+use lol_core::skill::{
+    CoolDown, EventSkillCast, Skill, SkillRecastWindow, SkillSlot, SkillTarget, SkillTargetFilter,
+    SkillTargeting, Skills, attach_skill_targeting,
+};
+use lol_core::team::Team;
+
+// ── 伤害标签：区分 Q / R 的伤害，供 on_warwick_damage_hit 结算治疗 ──
+pub const WARWICK_Q_DAMAGE_TAG: u32 = 1;
+pub const WARWICK_R_DAMAGE_TAG: u32 = 2;
+
+/// Q 撕咬距离
+pub const WARWICK_Q_RANGE: f32 = 365.0;
+/// Q 治疗占伤害比例（按技能等级）
+pub const WARWICK_Q_HEAL_RATIO: [f32; 5] = [0.25, 0.375, 0.5, 0.625, 0.75];
+/// W 血猎移速加成（按技能等级）
+pub const WARWICK_W_MOVE_SPEED: [f32; 5] = [0.35, 0.425, 0.5, 0.575, 0.65];
+pub const WARWICK_W_DURATION: f32 = 3.0;
+/// E 伤害减免（按技能等级）
+pub const WARWICK_E_DR: [f32; 5] = [0.35, 0.4, 0.45, 0.5, 0.55];
+pub const WARWICK_E_DURATION: f32 = 2.5;
+/// E 结束时恐惧半径与时长
+pub const WARWICK_E_RADIUS: f32 = 375.0;
+pub const WARWICK_E_FEAR_DURATION: f32 = 1.0;
+/// R 压制时长
+pub const WARWICK_R_SUPPRESS_DURATION: f32 = 1.5;
+
+#[derive(Default)]
+pub struct PluginWarwick;
+
+impl Plugin for PluginWarwick {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_warwick_q);
+        app.add_observer(on_warwick_w);
+        app.add_observer(on_warwick_e);
+        app.add_observer(on_warwick_r);
+        app.add_observer(on_warwick_damage_hit);
+        app.add_systems(FixedUpdate, update_warwick_e);
+        app.add_observer(attach_skill_targeting::<Warwick>(
+            SkillSlot::Q,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
+        app.add_observer(attach_skill_targeting::<Warwick>(
+            SkillSlot::R,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ));
+    }
+}
+
+#[derive(Component, Reflect)]
+#[require(Champion, Name = Name::new("Warwick"))]
+#[reflect(Component)]
+pub struct Warwick;
+
+/// E 原始咆哮：减伤期间的计时，到期或重施时咆哮恐惧周围敌人
+#[derive(Component, Clone, Debug)]
+#[require(Buff = Buff { name: "WarwickE" })]
+pub struct BuffWarwickE {
+    pub timer: Timer,
+}
+
+impl BuffWarwickE {
+    pub fn new() -> Self {
+        Self {
+            timer: Timer::from_seconds(WARWICK_E_DURATION, TimerMode::Once),
+        }
+    }
+}
+
+fn level_value(table: &[f32; 5], level: usize) -> f32 {
+    table[level.clamp(1, 5) - 1]
+}
+
+fn on_warwick_q(
+    trigger: On<EventSkillCast>,
+    mut commands: Commands,
+    q_warwick: Query<(), With<Warwick>>,
+    q_skill: Query<&Skill>,
+) {
+    let entity = trigger.event_target();
+    if q_warwick.get(entity).is_err() {
+        return;
+    }
+
+    let Ok(skill) = q_skill.get(trigger.skill_entity) else {
+        return;
+    };
+    if !matches!(skill.slot, SkillSlot::Q) {
+        return;
+    }
+
+    commands.trigger(CommandAnimationPlay {
+        entity,
+        hash: ANIM_SPELL1.to_string(),
+        repeat: false,
+        duration: None,
+    });
+    // Q 撕咬点选目标；未指定单位时咬最近的敌人
+    let shape = match trigger.target {
+        SkillTarget::Unit(target) => DamageShape::Unit { entity: target },
+        _ => DamageShape::Nearest {
+            max_distance: WARWICK_Q_RANGE,
+        },
+    };
+    commands.trigger(ActionDamage {
+        entity,
+        skill: skill.spell.clone(),
+        effects: vec![ActionDamageEffect {
+            shape,
+            damage_list: vec![TargetDamage {
+                filter: TargetFilter::All,
+                amount: "total_damage".to_string(),
+                damage_type: DamageType::Magic,
+                ..Default::default()
+            }],
+            tag: Some(WARWICK_Q_DAMAGE_TAG),
+            ..Default::default()
+        }],
+    });
+}
+
+fn on_warwick_w(
+    trigger: On<EventSkillCast>,
+    mut commands: Commands,
+    q_warwick: Query<(), With<Warwick>>,
+    q_skill: Query<&Skill>,
+) {
+    let entity = trigger.event_target();
+    if q_warwick.get(entity).is_err() {
+        return;
+    }
+
+    let Ok(skill) = q_skill.get(trigger.skill_entity) else {
+        return;
+    };
+    if !matches!(skill.slot, SkillSlot::W) {
+        return;
+    }
+
+    commands.trigger(CommandAnimationPlay {
+        entity,
+        hash: ANIM_SPELL2.to_string(),
+        repeat: false,
+        duration: None,
+    });
+    // W 血猎：只保留主动的移速加成
+    commands.entity(entity).apply_buff(
+        BuffMoveSpeed::descriptor(WARWICK_W_DURATION).with_source(entity),
+        BuffMoveSpeed::new(level_value(&WARWICK_W_MOVE_SPEED, skill.level)),
+    );
+}
+
+fn on_warwick_e(
+    trigger: On<EventSkillCast>,
+    mut commands: Commands,
+    q_warwick: Query<(), With<Warwick>>,
+    q_skill: Query<(&Skill, &CoolDown, Option<&SkillRecastWindow>)>,
+    q_buffs: Query<&Buffs>,
+    q_e: Query<&BuffWarwickE>,
+    q_dr: Query<&BuffDamageReduction>,
+    q_enemies: Query<(Entity, &Transform, &Team), With<TargetableAsChampion>>,
+    q_self: Query<(&Transform, &Team)>,
+) {
+    let entity = trigger.event_target();
+    if q_warwick.get(entity).is_err() {
+        return;
+    }
+
+    let Ok((skill, cooldown, recast)) = q_skill.get(trigger.skill_entity) else {
+        return;
+    };
+    if !matches!(skill.slot, SkillSlot::E) {
+        return;
+    }
+
+    commands.trigger(CommandAnimationPlay {
+        entity,
+        hash: ANIM_SPELL3.to_string(),
+        repeat: false,
+        duration: None,
+    });
+
+    // 一段：获得减伤并开启重施窗口
+    if recast.map(|w| w.stage).unwrap_or(1) == 1 {
+        commands
+            .entity(entity)
+            .with_related::<BuffOf>(BuffDamageReduction::new(
+                level_value(&WARWICK_E_DR, skill.level),
+                None,
+            ));
+        commands
+            .entity(entity)
+            .with_related::<BuffOf>(BuffWarwickE::new());
+        commands
+            .entity(trigger.skill_entity)
+            .insert(SkillRecastWindow::new(2, 2, WARWICK_E_DURATION));
+        return;
+    }
+
+    // 二段：提前结束减伤并咆哮
+    let Some(buffs) = q_buffs.get(entity).ok() else {
+        return;
+    };
+    for buff in buffs.iter() {
+        if q_e.get(buff).is_ok() || q_dr.get(buff).is_ok() {
+            commands.entity(buff).despawn();
+        }
+    }
+    howl(&mut commands, entity, &q_self, &q_enemies);
+    commands
+        .entity(trigger.skill_entity)
+        .remove::<SkillRecastWindow>();
+    commands.entity(trigger.skill_entity).insert(CoolDown {
+        duration: cooldown.duration,
+        timer: Some(Timer::from_seconds(cooldown.duration, TimerMode::Once)),
+    });
+}
+
+/// E 咆哮：恐惧半径内的敌方英雄
+fn howl(
+    commands: &mut Commands,
+    entity: Entity,
+    q_self: &Query<(&Transform, &Team)>,
+    q_enemies: &Query<(Entity, &Transform, &Team), With<TargetableAsChampion>>,
+) {
+    let Ok((transform, team)) = q_self.get(entity) else {
+        return;
+    };
+    let center = transform.translation.xz();
+    for (target, tf, t) in q_enemies.iter() {
+        if t == team || tf.translation.xz().distance(center) > WARWICK_E_RADIUS {
+            continue;
+        }
+        commands
+            .entity(target)
+            .with_related::<BuffOf>(DebuffFear::new(WARWICK_E_FEAR_DURATION).with_source(entity));
+    }
+}
+
+/// E 到期：撤除减伤并自动咆哮
+pub fn update_warwick_e(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    mut q_e: Query<(Entity, &mut BuffWarwickE, &BuffOf)>,
+    q_buffs: Query<&Buffs>,
+    q_dr: Query<&BuffDamageReduction>,
+    q_enemies: Query<(Entity, &Transform, &Team), With<TargetableAsChampion>>,
+    q_self: Query<(&Transform, &Team)>,
+) {
+    for (buff_entity, mut e, buff_of) in q_e.iter_mut() {
+        e.timer.tick(time.delta());
+        if !e.timer.is_finished() {
+            continue;
+        }
+        let parent = buff_of.0;
+        commands.entity(buff_entity).despawn();
+        if let Ok(buffs) = q_buffs.get(parent) {
+            for b in buffs.iter() {
+                if q_dr.get(b).is_ok() {
+                    commands.entity(b).despawn();
+                }
+            }
+        }
+        howl(&mut commands, parent, &q_self, &q_enemies);
+    }
+}
+
+fn on_warwick_r(
+    trigger: On<EventSkillCast>,
+    mut commands: Commands,
+    q_warwick: Query<(), With<Warwick>>,
+    q_skill: Query<&Skill>,
+) {
+    let entity = trigger.event_target();
+    if q_warwick.get(entity).is_err() {
+        return;
+    }
+
+    let Ok(skill) = q_skill.get(trigger.skill_entity) else {
+        return;
+    };
+    if !matches!(skill.slot, SkillSlot::R) {
+        return;
+    }
+
+    // R 压制点选目标；没有单位目标时不生效
+    let SkillTarget::Unit(target) = trigger.target else {
+        return;
+    };
+    commands.trigger(CommandAnimationPlay {
+        entity,
+        hash: ANIM_SPELL4.to_string(),
+        repeat: false,
+        duration: None,
+    });
+    commands
+        .entity(target)
+        .with_related::<BuffOf>(DebuffSuppression::new(WARWICK_R_SUPPRESS_DURATION));
+    commands.trigger(ActionDamage {
+        entity,
+        skill: skill.spell.clone(),
+        effects: vec![ActionDamageEffect {
+            shape: DamageShape::Unit { entity: target },
+            damage_list: vec![TargetDamage {
+                filter: TargetFilter::All,
+                amount: "total_damage".to_string(),
+                damage_type: DamageType::Magic,
+                ..Default::default()
+            }],
+            tag: Some(WARWICK_R_DAMAGE_TAG),
+            ..Default::default()
+        }],
+    });
+}
+
+/// Q 按等级比例、R 按全额将造成的伤害转为治疗
+fn on_warwick_damage_hit(
+    trigger: On<EventDamageCreate>,
+    mut commands: Commands,
+    q_warwick: Query<&Skills, With<Warwick>>,
+    q_skill: Query<&Skill>,
+) {
+    let source = trigger.source;
+    let Ok(skills) = q_warwick.get(source) else {
+        return;
+    };
+
+    let ratio = match trigger.tag {
+        Some(WARWICK_Q_DAMAGE_TAG) => {
+            let level = skills
+                .iter()
+                .filter_map(|e| q_skill.get(e).ok())
+                .find(|s| matches!(s.slot, SkillSlot::Q))
+                .map(|s| s.level)
+                .unwrap_or(1);
+            level_value(&WARWICK_Q_HEAL_RATIO, level)
+        }
+        Some(WARWICK_R_DAMAGE_TAG) => 1.0,
+        _ => return,
+    };
+    let amount = trigger.damage_result.final_damage * ratio;
+    if amount <= 0.0 {
+        return;
+    }
+    commands.trigger(CommandHeal {
+        entity: source,
+        source,
+        heal_type: HealType::Heal,
+        amount,
+    });
+}
@@ -1,7 +1,9 @@
 use std::process::exit;
 
 use clap::{Parser, Subcommand};
-use lol_client::{Action, GameClient, WsResponse, start_ws_client};
+use lol_client::action::SkillTarget;
+use lol_client::rpc::ScriptLogsParams;
+use lol_client::{Action, GameClient, WsResponse, start_ws_client_with_token};
 
 #[derive(Parser)]
 #[command(name = "lol-cli")]
@@ -11,6 +13,10 @@ struct Cli {
     #[arg(long, default_value = "9001")]
     port: u16,
 
+    /// WS 访问令牌（连接启用了鉴权的托管对局时需要）
+    #[arg(long)]
+    token: Option<String>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -76,6 +82,16 @@ enum Commands {
         source: String,
     },
 
+    /// 输出 Script Agent 的 TypeScript 类型声明（可重定向保存为 .d.ts）
+    ScriptTypes,
+
+    /// 取出指定实体 Script Agent 自上次读取以来的脚本日志
+    ScriptLogs {
+        /// 目标英雄实体 ID
+        #[arg(short, long)]
+        entity_id: u64,
+    },
+
     /// RL 环境 reset：初始化并返回初始观测
     RlReset {
         /// 目标英雄实体 ID
@@ -105,6 +121,8 @@ enum ActionSubcommand {
     Attack { entity: u64 },
     /// 停止所有动作
     Stop,
+    /// 回城
+    Recall,
     /// 释放指定索引的技能到指定坐标
     Skill {
         /// 技能索引 (0-3)
@@ -112,6 +130,36 @@ enum ActionSubcommand {
         x: f32,
         y: f32,
     },
+    /// 对指定目标实体释放点选技能
+    SkillUnit {
+        /// 技能索引 (0-3)
+        index: usize,
+        entity: u64,
+    },
+    /// 对自身释放技能
+    SkillSelf {
+        /// 技能索引 (0-3)
+        index: usize,
+    },
+    /// 从 (x, y) 朝 (end_x, end_y) 释放向量技能
+    SkillVector {
+        /// 技能索引 (0-3)
+        index: usize,
+        x: f32,
+        y: f32,
+        end_x: f32,
+        end_y: f32,
+    },
+    /// 施放召唤师技能：给 --target 为点选，给 x y 为对地，都不给为对自身
+    Summoner {
+        /// 召唤师技能槽位 (0: D, 1: F)
+        slot: usize,
+        x: Option<f32>,
+        y: Option<f32>,
+        /// 目标实体 ID
+        #[arg(long)]
+        target: Option<u64>,
+    },
     /// 升级指定索引的技能
     #[command(alias = "upgrade")]
     SkillLevelUp {
@@ -125,7 +173,7 @@ async fn main() {
     let cli = Cli::parse();
     let url = format!("ws://127.0.0.1:{}", cli.port);
 
-    let session = match start_ws_client(cli.port, None).await {
+    let session = match start_ws_client_with_token(cli.port, cli.token.as_deref(), None).await {
         Ok(s) => s,
         Err(e) => {
             eprintln!(
@@ -159,10 +207,35 @@ async fn run(client: &GameClient, command: Commands) -> Result<(), String> {
                 ActionSubcommand::Move { x, y } => Action::Move([x, y]),
                 ActionSubcommand::Attack { entity } => Action::Attack(entity),
                 ActionSubcommand::Stop => Action::Stop,
+                ActionSubcommand::Recall => Action::Recall,
                 ActionSubcommand::Skill { index, x, y } => Action::Skill {
                     index,
                     point: [x, y],
                 },
+                ActionSubcommand::SkillUnit { index, entity } => Action::SkillUnit {
+                    index,
+                    target: entity,
+                },
+                ActionSubcommand::SkillSelf { index } => Action::SkillSelf { index },
+                ActionSubcommand::SkillVector {
+                    index,
+                    x,
+                    y,
+                    end_x,
+                    end_y,
+                } => Action::SkillVector {
+                    index,
+                    start: [x, y],
+                    end: [end_x, end_y],
+                },
+                ActionSubcommand::Summoner { slot, x, y, target } => Action::SummonerSpell {
+                    slot,
+                    target: match (target, x, y) {
+                        (Some(target), _, _) => SkillTarget::Unit(target),
+                        (None, Some(x), Some(y)) => SkillTarget::Point([x, y]),
+                        _ => SkillTarget::SelfCast,
+                    },
+                },
                 ActionSubcommand::SkillLevelUp { index } => Action::SkillLevelUp(index),
             };
             print_data(client.action(entity_id, action).await?)
@@ -192,6 +265,20 @@ async fn run(client: &GameClient, command: Commands) -> Result<(), String> {
         Commands::SetScript { entity_id, source } => {
             print_data(client.set_script(entity_id, &source).await?)
         }
+        Commands::ScriptTypes => {
+            println!("{}", client.rpc().script_types().await?);
+            Ok(())
+        }
+        Commands::ScriptLogs { entity_id } => {
+            let result = client
+                .rpc()
+                .script_logs(ScriptLogsParams { entity_id })
+                .await?;
+            for log in result.logs {
+                println!("{log}");
+            }
+            Ok(())
+        }
         Commands::RlReset {
             entity_id,
             config_json,
@@ -8,7 +8,12 @@ use serde::{Deserialize, Serialize};
 /// - `Attack(entity_id)`       → `{"Attack":entity_id}`
 /// - `Stop`                    → `"Stop"`
 /// - `Skill{index,point}`      → `{"Skill":{"index":..,"point":[x,y]}}`
+/// - `SkillUnit{index,target}` → `{"SkillUnit":{"index":..,"target":entity_id}}`
+/// - `SkillSelf{index}`        → `{"SkillSelf":{"index":..}}`
+/// - `SkillVector{..}`         → `{"SkillVector":{"index":..,"start":[x,y],"end":[x,y]}}`
 /// - `SkillLevelUp(index)`     → `{"SkillLevelUp":index}`
+/// - `SummonerSpell{slot,target}` → `{"SummonerSpell":{"slot":..,"target":{"Point":[x,y]}}}`
+/// - `Recall`                  → `"Recall"`
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
 pub enum Action {
     /// 移动到坐标 [x, y]
@@ -19,6 +24,38 @@ pub enum Action {
     Stop,
     /// 释放指定索引的技能到坐标 [x, y]
     Skill { index: usize, point: [f32; 2] },
+    /// 对指定实体 ID 释放点选技能
+    SkillUnit { index: usize, target: u64 },
+    /// 对自身释放技能
+    SkillSelf { index: usize },
+    /// 从 start 朝 end 释放向量技能
+    SkillVector {
+        index: usize,
+        start: [f32; 2],
+        end: [f32; 2],
+    },
     /// 升级指定索引的技能
     SkillLevelUp(usize),
+    /// 施放召唤师技能（槽位 0: D, 1: F）
+    SummonerSpell { slot: usize, target: SkillTarget },
+    /// 回城：引导结束后传送回己方泉水，受到伤害或下达其它指令会打断
+    Recall,
+}
+
+/// 施法目标，与服务端 `lol_core::skill::SkillTarget` 同形。
+///
+/// - `Point([x, y])` → `{"Point":[x,y]}`
+/// - `Unit(entity_id)` → `{"Unit":entity_id}`
+/// - `SelfCast` → `"SelfCast"`
+/// - `Vector{start,end}` → `{"Vector":{"start":[x,y],"end":[x,y]}}`
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, JsonSchema)]
+pub enum SkillTarget {
+    /// 地面坐标 [x, y]
+    Point([f32; 2]),
+    /// 指定实体 ID
+    Unit(u64),
+    /// 对自身
+    SelfCast,
+    /// 从 start 朝 end 的向量
+    Vector { start: [f32; 2], end: [f32; 2] },
 }
@@ -0,0 +1,194 @@
+//! 游戏 WS 连接鉴权：令牌、角色作用域与限流配置。
+//!
+//! 托管对局由 `lol_web_server` / 进程管理器为每个参与方签发令牌，经环境变量
+//! [`WS_AUTH_ENV`] 交给 Bevy 进程；客户端在握手 URL 上携带 `?token=`。
+//! 未配置鉴权时（桌面端本地调试、CLI 直连）服务端保持开放，所有连接视为 [`WsRole::Debug`]。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Bevy 进程读取鉴权配置的环境变量（JSON 形态的 [`WsAuthConfig`]）。
+pub const WS_AUTH_ENV: &str = "MOON_WS_AUTH";
+
+/// 连接角色。`Spectator` 的只读命令对所有角色开放，`Agent` 与 `Relay` 各自在其上
+/// 增加一组能力，`Debug` 包含全部。
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum WsRole {
+    /// 只读：订阅世界、查询状态与时间；按英雄观测仅限令牌绑定的英雄（见 [`ControlScope`]）。
+    Spectator,
+    /// 可操控英雄：动作、脚本、RL 环境；仅限令牌绑定的英雄（见 [`ControlScope`]）。
+    Agent,
+    /// 观战转播：订阅不受视野限制的全量世界快照，仅签发给平台侧的观战网关。
+    Relay,
+    /// 调试 / 作弊面：上帝模式、倍速、暂停等。
+    Debug,
+}
+
+impl WsRole {
+    /// 本角色能否调用要求 `required` 角色的命令。
+    pub fn allows(self, required: WsRole) -> bool {
+        self == required || required == WsRole::Spectator || self == WsRole::Debug
+    }
+}
+
+/// 连接可操控的英雄范围，由令牌签发时的绑定决定。
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum ControlScope {
+    /// 不限：开放模式与 `Debug` 令牌。
+    #[default]
+    Any,
+    /// 仅限 `AgentId` 在列表中的英雄。
+    Agents(Arc<[String]>),
+}
+
+impl ControlScope {
+    /// 能否操控 `AgentId` 为 `agent_id` 的英雄（无 `AgentId` 传 None）。
+    pub fn allows(&self, agent_id: Option<&str>) -> bool {
+        match self {
+            ControlScope::Any => true,
+            ControlScope::Agents(ids) => agent_id.is_some_and(|id| ids.iter().any(|a| a == id)),
+        }
+    }
+}
+
+/// 单连接令牌桶限流：持续速率 `per_second`，突发上限 `burst`。
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct WsRateLimit {
+    pub per_second: f32,
+    pub burst: u32,
+}
+
+impl Default for WsRateLimit {
+    /// 足够覆盖 RL 逐帧 `rl_step` 与 Agent 决策环的常规频率。
+    fn default() -> Self {
+        Self {
+            per_second: 120.0,
+            burst: 240,
+        }
+    }
+}
+
+/// Bevy 进程的 WS 鉴权配置。
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct WsAuthConfig {
+    /// 令牌 → 角色。
+    pub tokens: HashMap<String, WsRole>,
+    /// `Agent` 令牌 → 可操控英雄的 `AgentId`，签发时绑定。
+    #[serde(default)]
+    pub bindings: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub rate_limit: WsRateLimit,
+}
+
+impl WsAuthConfig {
+    /// 签发一个指定角色的新令牌并登记。`Agent` 令牌请用 [`Self::issue_agent`] 绑定英雄。
+    pub fn issue(&mut self, role: WsRole) -> String {
+        let token = generate_token();
+        self.tokens.insert(token.clone(), role);
+        token
+    }
+
+    /// 签发一个 `Agent` 令牌，只能操控 `AgentId` 在 `agent_ids` 中的英雄。
+    pub fn issue_agent(&mut self, agent_ids: impl IntoIterator<Item = String>) -> String {
+        let token = self.issue(WsRole::Agent);
+        self.bindings
+            .insert(token.clone(), agent_ids.into_iter().collect());
+        token
+    }
+
+    pub fn role_of(&self, token: &str) -> Option<WsRole> {
+        self.tokens.get(token).copied()
+    }
+
+    /// 令牌可操控的英雄范围：`Debug` 不限，其余角色仅限签发时绑定的英雄（未绑定即为空）。
+    pub fn scope_of(&self, token: &str) -> ControlScope {
+        if self.role_of(token) == Some(WsRole::Debug) {
+            return ControlScope::Any;
+        }
+        let ids = self.bindings.get(token).cloned().unwrap_or_default();
+        ControlScope::Agents(ids.into())
+    }
+
+    /// 从 [`WS_AUTH_ENV`] 读取；未设置返回 `Ok(None)`（开放模式），格式错误返回 `Err`。
+    pub fn from_env() -> Result<Option<Self>, String> {
+        match std::env::var(WS_AUTH_ENV) {
+            Ok(raw) => serde_json::from_str(&raw)
+                .map(Some)
+                .map_err(|e| format!("{WS_AUTH_ENV} 格式错误: {e}")),
+            Err(_) => Ok(None),
+        }
+    }
+
+    pub fn to_env_value(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// 128 位随机令牌（十六进制）。
+fn generate_token() -> String {
+    format!("{:032x}", rand::random::<u128>())
+}
+
+/// 从握手请求的 URI query 中取 `token` 参数。
+pub fn token_from_query(query: &str) -> Option<&str> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == "token")
+        .map(|(_, value)| value)
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issued_tokens_resolve_to_roles() {
+        let mut auth = WsAuthConfig::default();
+        let agent = auth.issue(WsRole::Agent);
+        let spectator = auth.issue(WsRole::Spectator);
+        assert_ne!(agent, spectator);
+        assert_eq!(agent.len(), 32);
+
+        let restored: WsAuthConfig = serde_json::from_str(&auth.to_env_value()).unwrap();
+        assert_eq!(restored.role_of(&agent), Some(WsRole::Agent));
+        assert_eq!(restored.role_of(&spectator), Some(WsRole::Spectator));
+        assert_eq!(restored.role_of("forged"), None);
+
+        assert!(WsRole::Debug.allows(WsRole::Agent));
+        assert!(!WsRole::Agent.allows(WsRole::Debug));
+        assert!(!WsRole::Spectator.allows(WsRole::Agent));
+        assert!(!WsRole::Agent.allows(WsRole::Relay));
+        assert!(!WsRole::Relay.allows(WsRole::Agent));
+        assert!(WsRole::Relay.allows(WsRole::Spectator));
+    }
+
+    #[test]
+    fn agent_tokens_are_bound_to_agent_ids() {
+        let mut auth = WsAuthConfig::default();
+        let agent = auth.issue_agent(["blue_1".to_string()]);
+        let debug = auth.issue(WsRole::Debug);
+
+        let restored: WsAuthConfig = serde_json::from_str(&auth.to_env_value()).unwrap();
+        let scope = restored.scope_of(&agent);
+        assert!(scope.allows(Some("blue_1")));
+        assert!(!scope.allows(Some("red_1")));
+        assert!(!scope.allows(None));
+        assert_eq!(restored.scope_of(&debug), ControlScope::Any);
+        assert!(!restored.scope_of("forged").allows(Some("blue_1")));
+    }
+
+    #[test]
+    fn token_is_read_from_query() {
+        assert_eq!(token_from_query("a=1&token=abc"), Some("abc"));
+        assert_eq!(token_from_query("token="), None);
+        assert_eq!(token_from_query(""), None);
+    }
+}
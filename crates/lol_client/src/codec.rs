@@ -0,0 +1,123 @@
+//! WS 帧编解码与观测增量。
+//!
+//! 同一连接上 JSON 文本帧与 MessagePack 二进制帧并存：发送方按协商的 [`WsEncoding`]
+//! 编码，接收方按帧类型解码，因此切换编码的瞬间在途的旧格式帧也能正确处理。
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+use crate::protocol::WsEncoding;
+
+/// 按编码把消息序列化为 WS 帧。MessagePack 使用具名字段（map），
+/// 与 JSON 形态一一对应，`serde` 的 rename / skip 规则两边一致。
+pub fn encode<T: Serialize>(encoding: WsEncoding, value: &T) -> Result<Message, String> {
+    match encoding {
+        WsEncoding::Json => serde_json::to_string(value)
+            .map(Message::Text)
+            .map_err(|e| e.to_string()),
+        WsEncoding::Msgpack => rmp_serde::to_vec_named(value)
+            .map(Message::Binary)
+            .map_err(|e| e.to_string()),
+    }
+}
+
+/// 按帧类型反序列化：文本帧走 JSON，二进制帧走 MessagePack。
+/// 控制帧（Ping/Pong/Close）返回 `None`。
+pub fn decode<T: DeserializeOwned>(msg: &Message) -> Option<Result<T, String>> {
+    match msg {
+        Message::Text(text) => Some(serde_json::from_str(text).map_err(|e| e.to_string())),
+        Message::Binary(bytes) => Some(rmp_serde::from_slice(bytes).map_err(|e| e.to_string())),
+        _ => None,
+    }
+}
+
+/// 计算 `old` -> `new` 的 JSON Merge Patch（RFC 7386），无变化返回 `None`。
+///
+/// 对象逐键递归；数组与标量整体替换；`new` 中消失的键以 `null` 标记删除。
+/// 因此值为 `null` 的字段在补丁里表现为"删除"，对 `Option` 字段两者等价。
+pub fn diff(old: &Value, new: &Value) -> Option<Value> {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            let mut patch = Map::new();
+            for (key, new_val) in new {
+                match old.get(key) {
+                    Some(old_val) => {
+                        if let Some(sub) = diff(old_val, new_val) {
+                            patch.insert(key.clone(), sub);
+                        }
+                    }
+                    None => {
+                        patch.insert(key.clone(), new_val.clone());
+                    }
+                }
+            }
+            for key in old.keys() {
+                if !new.contains_key(key) {
+                    patch.insert(key.clone(), Value::Null);
+                }
+            }
+            (!patch.is_empty()).then_some(Value::Object(patch))
+        }
+        _ if old == new => None,
+        _ => Some(new.clone()),
+    }
+}
+
+/// 把 JSON Merge Patch 应用到 `target` 上（RFC 7386）。
+pub fn apply_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    let Value::Object(target) = target else {
+        return;
+    };
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(key);
+        } else {
+            apply_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::protocol::WsResponse;
+
+    #[test]
+    fn msgpack_round_trips_response() {
+        let resp = WsResponse::ok_with_data(7, json!({ "time": 1.5, "list": [1, 2] }));
+        let frame = encode(WsEncoding::Msgpack, &resp).unwrap();
+        assert!(matches!(frame, Message::Binary(_)));
+        let back: WsResponse = decode(&frame).unwrap().unwrap();
+        assert_eq!(back.id, 7);
+        assert_eq!(back.msg_type, "result");
+        assert_eq!(back.data, resp.data);
+        assert!(back.error.is_none());
+    }
+
+    #[test]
+    fn merge_patch_reconstructs_new_value() {
+        let old = json!({ "time": 1.0, "myself": { "health": 100.0, "run_target": [1, 2] } });
+        let new = json!({ "time": 1.1, "myself": { "health": 100.0 }, "minions": [] });
+        let patch = diff(&old, &new).unwrap();
+        assert_eq!(
+            patch,
+            json!({ "time": 1.1, "myself": { "run_target": null }, "minions": [] })
+        );
+
+        let mut rebuilt = old.clone();
+        apply_patch(&mut rebuilt, &patch);
+        assert_eq!(rebuilt, new);
+        assert!(diff(&new, &new).is_none());
+    }
+}
@@ -2,6 +2,7 @@ use serde_json::{Value, json};
 
 use crate::action::Action;
 use crate::protocol::*;
+use crate::rpc::{RpcClient, RpcCommand};
 use crate::session::WsSession;
 
 /// 类型化游戏客户端：方法一一映射服务端 cmd 字符串，参数用纯 Rust 类型拼 JSON。
@@ -31,6 +32,23 @@ impl GameClient {
         self.session.send_cmd(cmd.to_string(), params).await
     }
 
+    /// 类型化调用：参数与返回值按 [`RpcCommand`] 契约序列化 / 反序列化，
+    /// 服务端错误与返回值形态不符都以 `Err` 返回。
+    pub async fn call<C: RpcCommand>(&self, params: &C::Params) -> Result<C::Result, String> {
+        let params = serde_json::to_value(params).map_err(|e| e.to_string())?;
+        let resp = self.cmd(C::NAME, params).await?;
+        if !resp.ok {
+            return Err(resp.error.unwrap_or_else(|| format!("{} 失败", C::NAME)));
+        }
+        serde_json::from_value(resp.data.unwrap_or(Value::Null))
+            .map_err(|e| format!("{} 响应格式不符: {}", C::NAME, e))
+    }
+
+    /// 由命令表生成的类型化客户端，覆盖服务端注册的全部命令。
+    pub fn rpc(&self) -> RpcClient<'_> {
+        RpcClient::new(self)
+    }
+
     pub async fn observe(&self, entity_id: u64, json: bool) -> Result<WsResponse, String> {
         self.cmd(CMD_OBSERVE, json!({ "entity_id": entity_id, "json": json }))
             .await
@@ -57,13 +75,7 @@ impl GameClient {
 
     /// 查询当前游戏内时间（秒）。
     pub async fn get_time(&self) -> Result<f64, String> {
-        let resp = self.cmd(CMD_GET_TIME, Value::Null).await?;
-        if !resp.ok {
-            return Err(resp.error.unwrap_or_else(|| "get_time 失败".to_string()));
-        }
-        resp.data
-            .and_then(|d| d.get("time").and_then(|t| t.as_f64()))
-            .ok_or_else(|| "get_time 响应缺少 time 字段".to_string())
+        self.rpc().get_time().await.map(|r| r.time)
     }
 
     pub async fn toggle_pause(&self) -> Result<WsResponse, String> {
@@ -119,6 +131,36 @@ impl GameClient {
         self.cmd(CMD_RL_STEP, params).await
     }
 
+    // ── 编码协商 / 观测订阅 ──
+
+    /// 切换本连接的帧编码（远程 RL / Agent 建议使用 MessagePack）。
+    pub async fn set_encoding(&self, encoding: WsEncoding) -> Result<(), String> {
+        self.session.set_encoding(encoding).await
+    }
+
+    /// 订阅观测推送：每 `every_ticks` 个 FixedUpdate tick 推送一次 `observe_delta` 事件，
+    /// 每 `keyframe_every` 次推送附带一次完整观测（其余为增量）。
+    pub async fn subscribe_observe(
+        &self,
+        entity_id: u64,
+        every_ticks: u32,
+        keyframe_every: Option<u32>,
+    ) -> Result<WsResponse, String> {
+        let mut params = json!({ "entity_id": entity_id, "every_ticks": every_ticks });
+        if let Some(k) = keyframe_every {
+            params["keyframe_every"] = json!(k);
+        }
+        self.cmd(CMD_SUBSCRIBE_OBSERVE, params).await
+    }
+
+    pub async fn unsubscribe_observe(&self, subscription_id: u64) -> Result<WsResponse, String> {
+        self.cmd(
+            CMD_UNSUBSCRIBE_OBSERVE,
+            json!({ "subscription_id": subscription_id }),
+        )
+        .await
+    }
+
     // ── 幂等暂停 / 恢复（先 get_state 再决定是否 toggle_pause）──
 
     /// 幂等暂停：已暂停则不操作。返回是否实际触发了切换。
@@ -6,6 +6,8 @@
 
 use std::path::PathBuf;
 
+use crate::auth::{WS_AUTH_ENV, WsAuthConfig};
+
 /// Bevy 进程的游戏参数（CLI 标志）。`None` 的字段不产出对应标志，以兼容桌面端
 /// （传 mode/champion/scene）与云端 headless（仅 ws-port + headless）两种调用面。
 #[derive(Debug, Clone, Default)]
@@ -144,11 +146,13 @@ pub struct BevySpawnRequest {
     pub rust_log: Option<String>,
     /// 每局日志 SQLite 路径；`None` 时 Bevy 进程沿用默认 `~/.moon-lol/logs/debug.db`。
     pub log_db: Option<PathBuf>,
+    /// WS 鉴权配置（经 [`WS_AUTH_ENV`] 注入）；`None` 表示开放模式（本地调试）。
+    pub ws_auth: Option<WsAuthConfig>,
 }
 
 /// 据请求构建配置好的（未 spawn 的）`std::process::Command`。
 ///
-/// 配置：stdout/stderr=null、可选 cwd、可选 RUST_LOG、program、前缀、`bevy_args`。
+/// 配置：stdout/stderr=null、可选 cwd、可选 RUST_LOG、可选 WS 鉴权、program、前缀、`bevy_args`。
 pub fn build_command(req: &BevySpawnRequest) -> std::process::Command {
     let mut cmd = std::process::Command::new(&req.program);
     cmd.args(&req.prefix_args)
@@ -161,6 +165,9 @@ pub fn build_command(req: &BevySpawnRequest) -> std::process::Command {
     if let Some(rust_log) = &req.rust_log {
         cmd.env("RUST_LOG", rust_log);
     }
+    if let Some(ws_auth) = &req.ws_auth {
+        cmd.env(WS_AUTH_ENV, ws_auth.to_env_value());
+    }
     if let Some(log_db) = &req.log_db {
         cmd.arg("--log-db").arg(log_db);
     }
@@ -3,14 +3,19 @@
 //! CLI、MCP、Tauri 后端三处都依赖本 crate，不再各自持有一份协议或会话代码。
 
 pub mod action;
+pub mod auth;
+pub mod codec;
 pub mod game_client;
 pub mod launch;
 pub mod mcp;
 pub mod protocol;
+pub mod rpc;
 pub mod session;
+pub mod spectate;
 
 pub use action::Action;
 pub use game_client::GameClient;
 pub use mcp::{GameToolServer, serve_inprocess};
 pub use protocol::*;
-pub use session::{WsSession, start_ws_client};
+pub use rpc::{RpcClient, RpcCommand, RpcCommandInfo};
+pub use session::{WsSession, start_ws_client, start_ws_client_with_token};
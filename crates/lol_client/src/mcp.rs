@@ -6,7 +6,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tokio::io::duplex;
 
-use crate::action::Action;
+use crate::action::{Action, SkillTarget};
 use crate::game_client::GameClient;
 use crate::protocol::WsResponse;
 
@@ -44,17 +44,97 @@ pub struct StopArgs {
     pub entity_id: u64,
 }
 
-/// `cast_skill` 工具入参。
+/// `recall` 工具入参。
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct RecallArgs {
+    /// 操作的英雄实体 ID
+    pub entity_id: u64,
+}
+
+/// `cast_skill` 工具入参。目标按优先级取：`target_id` > `self_cast` > 坐标（给出 `end_x/end_y` 时为向量施法）。
 #[derive(Serialize, Deserialize, JsonSchema)]
 pub struct CastSkillArgs {
     /// 操作的英雄实体 ID
     pub entity_id: u64,
     /// 技能索引 (0: Q, 1: W, 2: E, 3: R)
     pub index: usize,
-    /// 目标位置 X 坐标
-    pub x: f32,
-    /// 目标位置 Y 坐标
-    pub y: f32,
+    /// 目标位置 X 坐标（向量施法时为起点）
+    #[serde(default)]
+    pub x: Option<f32>,
+    /// 目标位置 Y 坐标（向量施法时为起点）
+    #[serde(default)]
+    pub y: Option<f32>,
+    /// 点选技能的目标实体 ID
+    #[serde(default)]
+    pub target_id: Option<u64>,
+    /// 对自身释放
+    #[serde(default)]
+    pub self_cast: bool,
+    /// 向量施法终点 X 坐标
+    #[serde(default)]
+    pub end_x: Option<f32>,
+    /// 向量施法终点 Y 坐标
+    #[serde(default)]
+    pub end_y: Option<f32>,
+}
+
+impl CastSkillArgs {
+    /// 按入参组合出施法动作；缺少坐标时返回错误说明。
+    pub fn to_action(&self) -> Result<Action, String> {
+        let index = self.index;
+        if let Some(target) = self.target_id {
+            return Ok(Action::SkillUnit { index, target });
+        }
+        if self.self_cast {
+            return Ok(Action::SkillSelf { index });
+        }
+        let (Some(x), Some(y)) = (self.x, self.y) else {
+            return Err("需要 target_id、self_cast 或坐标 x/y 之一".to_string());
+        };
+        Ok(match (self.end_x, self.end_y) {
+            (Some(end_x), Some(end_y)) => Action::SkillVector {
+                index,
+                start: [x, y],
+                end: [end_x, end_y],
+            },
+            _ => Action::Skill {
+                index,
+                point: [x, y],
+            },
+        })
+    }
+}
+
+/// `cast_summoner_spell` 工具入参。目标按优先级取：`target_id` > 坐标 > 自身。
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct CastSummonerSpellArgs {
+    /// 操作的英雄实体 ID
+    pub entity_id: u64,
+    /// 召唤师技能槽位 (0: D, 1: F)
+    pub slot: usize,
+    /// 目标位置 X 坐标（闪现）
+    #[serde(default)]
+    pub x: Option<f32>,
+    /// 目标位置 Y 坐标（闪现）
+    #[serde(default)]
+    pub y: Option<f32>,
+    /// 目标实体 ID（点燃 / 虚弱 / 惩戒 / 传送）
+    #[serde(default)]
+    pub target_id: Option<u64>,
+}
+
+impl CastSummonerSpellArgs {
+    pub fn to_action(&self) -> Action {
+        let target = match (self.target_id, self.x, self.y) {
+            (Some(target), _, _) => SkillTarget::Unit(target),
+            (None, Some(x), Some(y)) => SkillTarget::Point([x, y]),
+            _ => SkillTarget::SelfCast,
+        };
+        Action::SummonerSpell {
+            slot: self.slot,
+            target,
+        }
+    }
 }
 
 /// `level_up_skill` 工具入参。
@@ -141,15 +221,28 @@ impl GameToolServer {
         }
     }
 
-    /// 令指定英雄实体对目标坐标 [x, y] 释放指定索引的技能
+    /// 令指定英雄实体回城
+    #[tool(
+        name = "recall",
+        description = "令指定英雄实体开始回城，引导结束后回到己方泉水；受到伤害、被控制或下达其它指令会打断"
+    )]
+    async fn recall(&self, Parameters(args): Parameters<RecallArgs>) -> String {
+        let action = Action::Recall;
+        match self.client.action(args.entity_id, action).await {
+            Ok(resp) => format_response(resp),
+            Err(e) => format!("错误: {}", e),
+        }
+    }
+
+    /// 令指定英雄实体释放指定索引的技能：对坐标、点选目标、自身或两点向量
     #[tool(
         name = "cast_skill",
-        description = "令指定英雄实体对目标坐标 [x, y] 释放指定索引的技能 (0: Q, 1: W, 2: E, 3: R)"
+        description = "令指定英雄实体释放指定索引的技能 (0: Q, 1: W, 2: E, 3: R)。点选技能传 target_id，自身技能传 self_cast，对地技能传 x/y，向量技能再传 end_x/end_y"
     )]
     async fn cast_skill(&self, Parameters(args): Parameters<CastSkillArgs>) -> String {
-        let action = Action::Skill {
-            index: args.index,
-            point: [args.x, args.y],
+        let action = match args.to_action() {
+            Ok(action) => action,
+            Err(e) => return format!("错误: {}", e),
         };
         match self.client.action(args.entity_id, action).await {
             Ok(resp) => format_response(resp),
@@ -157,6 +250,22 @@ impl GameToolServer {
         }
     }
 
+    /// 令指定英雄实体施放召唤师技能
+    #[tool(
+        name = "cast_summoner_spell",
+        description = "令指定英雄实体施放召唤师技能 (slot 0: D, 1: F)。闪现传 x/y，点燃 / 虚弱 / 惩戒 / 传送传 target_id，治疗 / 屏障 / 幽灵 / 净化无需目标"
+    )]
+    async fn cast_summoner_spell(
+        &self,
+        Parameters(args): Parameters<CastSummonerSpellArgs>,
+    ) -> String {
+        let action = args.to_action();
+        match self.client.action(args.entity_id, action).await {
+            Ok(resp) => format_response(resp),
+            Err(e) => format!("错误: {}", e),
+        }
+    }
+
     /// 升级指定英雄实体的指定索引技能
     #[tool(
         name = "level_up_skill",
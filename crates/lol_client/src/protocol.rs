@@ -1,5 +1,26 @@
 use serde::{Deserialize, Serialize};
 
+use crate::rpc::{RpcCommand, cmd};
+
+// ── 帧编码 ──
+
+/// 连接级帧编码。默认 JSON 文本帧（浏览器可直接读写）；远程 RL / Agent 可经
+/// [`CMD_SET_ENCODING`] 协商为 MessagePack 二进制帧，降低 `observe` / `rl_step` 的编解码开销。
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum WsEncoding {
+    #[default]
+    Json,
+    #[serde(alias = "messagepack")]
+    Msgpack,
+}
+
+/// `set_encoding` 指令参数。该指令由 WS 传输层直接处理，不进入 Bevy RPC 分发。
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetEncodingParams {
+    pub encoding: WsEncoding,
+}
+
 // ── 请求 (客户端 → 游戏) ──
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -19,6 +40,16 @@ pub struct WsEvent {
     pub data: serde_json::Value,
 }
 
+/// 传输层拒绝请求的原因；业务处理失败的响应不带 code。
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WsErrorCode {
+    /// 连接角色无权调用该命令。
+    Forbidden,
+    /// 超出连接的请求速率上限。
+    RateLimited,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct WsResponse {
     pub id: u64,
@@ -29,6 +60,8 @@ pub struct WsResponse {
     pub data: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub code: Option<WsErrorCode>,
 }
 
 // ── 事件构造器 ──
@@ -74,7 +107,8 @@ impl WsEvent {
         }
     }
 
-    /// 对局结构化事件（champion_kill / turret_destroyed / cs_threshold / time_progress）。
+    /// 对局结构化事件（champion_kill / turret_destroyed / turret_plate_destroyed / cs_threshold / time_progress，
+    /// 以及 dragon_killed / dragon_soul / elder_dragon_killed / rift_herald_killed / baron_killed）。
     /// 由 lol_core 的 match_events 插件产出，经 WS 转发给 web server 的 match supervisor。
     pub fn match_event(payload: serde_json::Value) -> Self {
         Self {
@@ -83,6 +117,39 @@ impl WsEvent {
             data: payload,
         }
     }
+
+    /// 观测订阅推送。`keyframe` 为 true 时 `observe` 是完整观测，
+    /// 否则是相对上一帧的 JSON Merge Patch（见 [`crate::codec::apply_patch`]）。
+    /// `seq` 为该订阅的推送序号，从 0 递增，可用于检测丢帧。
+    pub fn observe_delta(
+        subscription_id: u64,
+        seq: u64,
+        keyframe: bool,
+        observe: serde_json::Value,
+    ) -> Self {
+        Self {
+            msg_type: "event".into(),
+            event: "observe_delta".into(),
+            data: serde_json::json!({
+                "subscription_id": subscription_id,
+                "seq": seq,
+                "keyframe": keyframe,
+                "observe": observe,
+            }),
+        }
+    }
+
+    /// 世界快照推送：`snapshot` 为完整的 [`crate::spectate::WorldSnapshot`]。
+    pub fn world_snapshot(subscription_id: u64, snapshot: serde_json::Value) -> Self {
+        Self {
+            msg_type: "event".into(),
+            event: "world_snapshot".into(),
+            data: serde_json::json!({
+                "subscription_id": subscription_id,
+                "snapshot": snapshot,
+            }),
+        }
+    }
 }
 
 impl WsResponse {
@@ -93,6 +160,7 @@ impl WsResponse {
             ok: true,
             data: None,
             error: None,
+            code: None,
         }
     }
 
@@ -103,6 +171,7 @@ impl WsResponse {
             ok: true,
             data: Some(data),
             error: None,
+            code: None,
         }
     }
 
@@ -113,22 +182,38 @@ impl WsResponse {
             ok: false,
             data: None,
             error: Some(error),
+            code: None,
+        }
+    }
+
+    /// 传输层拒绝（鉴权 / 限流），不进入 RPC 分发。
+    pub fn rejected(id: u64, code: WsErrorCode, error: String) -> Self {
+        Self {
+            code: Some(code),
+            ..Self::err(id, error)
         }
     }
 }
 
 // ── WebSocket 命令字常量定义 ──
-pub const CMD_OBSERVE: &str = "observe";
-pub const CMD_ACTION: &str = "action";
-pub const CMD_GET_AGENTS: &str = "get_agents";
-pub const CMD_GET_STATE: &str = "get_state";
-pub const CMD_GET_TIME: &str = "get_time";
-pub const CMD_TOGGLE_PAUSE: &str = "toggle_pause";
-pub const CMD_SET_SPEED: &str = "set_speed";
-pub const CMD_SWITCH_CHAMPION: &str = "switch_champion";
-pub const CMD_GOD_MODE: &str = "god_mode";
-pub const CMD_TOGGLE_COOLDOWN: &str = "toggle_cooldown";
-pub const CMD_RESET_POSITION: &str = "reset_position";
-pub const CMD_SET_SCRIPT: &str = "set_script";
-pub const CMD_RL_RESET: &str = "rl_reset";
-pub const CMD_RL_STEP: &str = "rl_step";
+// 除传输层的 `set_encoding` 外均取自 [`crate::rpc`] 命令表，避免与服务端注册漂移。
+pub const CMD_OBSERVE: &str = <cmd::Observe as RpcCommand>::NAME;
+pub const CMD_ACTION: &str = <cmd::Action as RpcCommand>::NAME;
+pub const CMD_GET_AGENTS: &str = <cmd::GetAgents as RpcCommand>::NAME;
+pub const CMD_GET_STATE: &str = <cmd::GetState as RpcCommand>::NAME;
+pub const CMD_GET_TIME: &str = <cmd::GetTime as RpcCommand>::NAME;
+pub const CMD_TOGGLE_PAUSE: &str = <cmd::TogglePause as RpcCommand>::NAME;
+pub const CMD_SET_SPEED: &str = <cmd::SetSpeed as RpcCommand>::NAME;
+pub const CMD_SWITCH_CHAMPION: &str = <cmd::SwitchChampion as RpcCommand>::NAME;
+pub const CMD_GOD_MODE: &str = <cmd::GodMode as RpcCommand>::NAME;
+pub const CMD_TOGGLE_COOLDOWN: &str = <cmd::ToggleCooldown as RpcCommand>::NAME;
+pub const CMD_RESET_POSITION: &str = <cmd::ResetPosition as RpcCommand>::NAME;
+pub const CMD_SET_SCRIPT: &str = <cmd::SetScript as RpcCommand>::NAME;
+pub const CMD_RL_RESET: &str = <cmd::RlReset as RpcCommand>::NAME;
+pub const CMD_RL_STEP: &str = <cmd::RlStep as RpcCommand>::NAME;
+pub const CMD_SET_ENCODING: &str = "set_encoding";
+pub const CMD_SUBSCRIBE_OBSERVE: &str = <cmd::SubscribeObserve as RpcCommand>::NAME;
+pub const CMD_UNSUBSCRIBE_OBSERVE: &str = <cmd::UnsubscribeObserve as RpcCommand>::NAME;
+pub const CMD_SUBSCRIBE_WORLD: &str = <cmd::SubscribeWorld as RpcCommand>::NAME;
+pub const CMD_UNSUBSCRIBE_WORLD: &str = <cmd::UnsubscribeWorld as RpcCommand>::NAME;
+pub const CMD_LIST_COMMANDS: &str = <cmd::ListCommands as RpcCommand>::NAME;
@@ -0,0 +1,451 @@
+//! RPC 命令契约：命令名、参数与返回值的唯一来源。
+//!
+//! 服务端各插件以 [`RpcCommand`] 标记类型注册命令（命令名与 JSON Schema 都取自这里），
+//! 客户端的 [`RpcClient`] 由同一张命令表生成，两端不会再各写一份字符串而漂移。
+//! 运行期可经内建的 `list_commands` 查询服务端实际注册的命令及其 Schema。
+
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::action::Action;
+use crate::auth::WsRole;
+use crate::game_client::GameClient;
+
+/// 一条 RPC 命令的类型契约。
+pub trait RpcCommand: 'static {
+    /// WS 命令字。
+    const NAME: &'static str;
+    /// 命令说明（取自命令表上的文档注释）。
+    const DESCRIPTION: &'static str;
+    /// 调用所需的最低连接角色。
+    const ROLE: WsRole;
+    /// 请求参数的线上形态；无参数命令为 `()`（JSON `null`）。
+    type Params: Serialize + DeserializeOwned + JsonSchema + Send + Sync;
+    /// 成功响应 `data` 的线上形态。
+    type Result: Serialize + DeserializeOwned + JsonSchema + Send + Sync;
+}
+
+/// `list_commands` 返回的单条命令描述。
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct RpcCommandInfo {
+    pub name: String,
+    pub description: String,
+    pub role: WsRole,
+    pub params_schema: Value,
+    pub result_schema: Value,
+}
+
+impl RpcCommandInfo {
+    pub fn of<C: RpcCommand>() -> Self {
+        Self {
+            name: C::NAME.to_string(),
+            description: C::DESCRIPTION.trim().to_string(),
+            role: C::ROLE,
+            params_schema: schemars::schema_for!(C::Params).to_value(),
+            result_schema: schemars::schema_for!(C::Result).to_value(),
+        }
+    }
+}
+
+// ── 参数 ──
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, JsonSchema)]
+pub struct ObserveParams {
+    /// 观测的英雄实体 ID，缺省取第一个玩家英雄。
+    pub entity_id: Option<u64>,
+    /// true 返回结构化 JSON，false 返回中文自然语言描述。
+    #[serde(default)]
+    pub json: bool,
+}
+
+/// 动作参数。除 `{"entity_id":..,"action":..}` 外，也接受省略 `entity_id` 的扁平动作
+/// （如 `{"Move":[x,y]}`），此时作用于第一个玩家英雄。
+#[derive(Serialize, Debug, Clone, JsonSchema)]
+pub struct ActionParams {
+    pub entity_id: Option<u64>,
+    pub action: Action,
+}
+
+impl<'de> Deserialize<'de> for ActionParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct ActionWithEntity {
+            entity_id: Option<u64>,
+            action: Action,
+        }
+
+        let value = Value::deserialize(deserializer)?;
+        if let Ok(wrapper) = serde_json::from_value::<ActionWithEntity>(value.clone()) {
+            Ok(ActionParams {
+                entity_id: wrapper.entity_id,
+                action: wrapper.action,
+            })
+        } else if let Ok(action) = serde_json::from_value::<Action>(value) {
+            Ok(ActionParams {
+                entity_id: None,
+                action,
+            })
+        } else {
+            Err(serde::de::Error::custom("Invalid action params"))
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct SetScriptParams {
+    pub entity_id: u64,
+    /// Script Agent 的 JS 源码。
+    pub source: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct ScriptLogsParams {
+    pub entity_id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, JsonSchema)]
+pub struct RlResetParams {
+    pub entity_id: Option<u64>,
+    /// 奖励塑形配置（`RewardShaper` 的 JSON 形态）。
+    pub config_json: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, JsonSchema)]
+pub struct RlStepParams {
+    pub entity_id: Option<u64>,
+    pub frames: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, JsonSchema)]
+pub struct SubscribeObserveParams {
+    pub entity_id: Option<u64>,
+    /// 推送间隔（FixedUpdate tick 数），缺省每 tick 推送。
+    pub every_ticks: Option<u32>,
+    /// 每多少次推送附带一次完整观测，0 表示仅首帧。
+    pub keyframe_every: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct UnsubscribeObserveParams {
+    pub subscription_id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, JsonSchema)]
+pub struct SubscribeWorldParams {
+    /// 推送间隔（FixedUpdate tick 数），缺省见服务端 `DEFAULT_WORLD_EVERY_TICKS`。
+    pub every_ticks: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct UnsubscribeWorldParams {
+    pub subscription_id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct SwitchChampionParams {
+    pub name: String,
+}
+
+/// 开关类调试命令（god_mode / toggle_cooldown）的参数。
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct EnabledParams {
+    pub enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct SetSpeedParams {
+    pub speed: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct PlayParticleParams {
+    /// 一段 ConfigVfxSystemDefinition 的 RON 字符串。
+    pub def: String,
+}
+
+// ── 返回值 ──
+
+/// 仅表示执行成功的返回值：`{"status":"success"}`。
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct StatusResult {
+    pub status: String,
+}
+
+/// 空对象返回值：`{}`。
+#[derive(Serialize, Deserialize, Debug, Clone, Default, JsonSchema)]
+pub struct EmptyResult {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct AgentInfo {
+    pub entity_id: u64,
+    pub agent_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct ScriptLogsResult {
+    /// 按产生顺序排列，每条为 `{"level","message","time"}`。
+    pub logs: Vec<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct RlResetResult {
+    pub observation: Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct SubscribeObserveResult {
+    pub subscription_id: u64,
+    pub entity_id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct SubscribeWorldResult {
+    pub subscription_id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct NameResult {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct EnabledResult {
+    pub enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct PausedResult {
+    pub paused: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct SpeedResult {
+    pub speed: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct TimeResult {
+    pub time: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct DebugStateResult {
+    pub champion: String,
+    pub god_mode: bool,
+    pub cooldown_disabled: bool,
+    pub paused: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct PlayParticleResult {
+    pub hash: u32,
+    pub particle_name: String,
+}
+
+// ── 命令表 ──
+
+/// 类型化 RPC 客户端：由命令表生成，每条命令一个同名方法，经 [`GameClient::rpc`] 获取。
+pub struct RpcClient<'a> {
+    client: &'a GameClient,
+}
+
+impl<'a> RpcClient<'a> {
+    pub fn new(client: &'a GameClient) -> Self {
+        Self { client }
+    }
+}
+
+/// 生成单条命令的客户端方法；无参数命令不要求调用方传 `()`。
+macro_rules! rpc_method {
+    ($(#[doc = $doc:literal])* $method:ident, $marker:ident, ()) => {
+        impl RpcClient<'_> {
+            $(#[doc = $doc])*
+            pub async fn $method(&self) -> Result<<cmd::$marker as RpcCommand>::Result, String> {
+                self.client.call::<cmd::$marker>(&()).await
+            }
+        }
+    };
+    ($(#[doc = $doc:literal])* $method:ident, $marker:ident, $params:ty) => {
+        impl RpcClient<'_> {
+            $(#[doc = $doc])*
+            pub async fn $method(
+                &self,
+                params: $params,
+            ) -> Result<<cmd::$marker as RpcCommand>::Result, String> {
+                self.client.call::<cmd::$marker>(&params).await
+            }
+        }
+    };
+}
+
+macro_rules! rpc_commands {
+    ($(
+        $(#[doc = $doc:literal])*
+        $method:ident => $marker:ident($name:literal, $params:tt, $role:ident) -> $result:ty;
+    )*) => {
+        /// 各命令的标记类型，服务端以 `register_rpc::<cmd::X>()` 注册并监听 `CommandWsRequest<cmd::X>`。
+        pub mod cmd {
+            use super::*;
+
+            $(
+                $(#[doc = $doc])*
+                pub struct $marker;
+
+                impl RpcCommand for $marker {
+                    const NAME: &'static str = $name;
+                    const DESCRIPTION: &'static str = concat!($($doc, "\n"),*);
+                    const ROLE: WsRole = WsRole::$role;
+                    type Params = $params;
+                    type Result = $result;
+                }
+            )*
+        }
+
+        /// 契约中的全部命令字（不含传输层直接处理的 `set_encoding`）。
+        pub const COMMANDS: &[&str] = &[$($name),*];
+
+        /// 契约中全部命令的描述（含 Schema）。
+        pub fn command_infos() -> Vec<RpcCommandInfo> {
+            vec![$(RpcCommandInfo::of::<cmd::$marker>()),*]
+        }
+
+        /// 命令所需的最低角色；不在契约中的命令返回 None。
+        pub fn required_role(name: &str) -> Option<WsRole> {
+            match name {
+                $($name => Some(WsRole::$role),)*
+                _ => None,
+            }
+        }
+
+        $(rpc_method!($(#[doc = $doc])* $method, $marker, $params);)*
+    };
+}
+
+rpc_commands! {
+    /// 列出服务端已注册的命令及其参数 / 返回值 JSON Schema。
+    list_commands => ListCommands("list_commands", (), Spectator) -> Vec<RpcCommandInfo>;
+
+    // ── Agent 面（lol_agent）──
+    /// 获取英雄的局势观测（中文描述或结构化 JSON）。
+    observe => Observe("observe", ObserveParams, Spectator) -> Value;
+    /// 向英雄下发一个动作。
+    action => Action("action", ActionParams, Agent) -> StatusResult;
+    /// 列出所有 Agent 控制的英雄。
+    get_agents => GetAgents("get_agents", (), Spectator) -> Vec<AgentInfo>;
+    /// 为英雄挂载或热重载 Script Agent。
+    set_script => SetScript("set_script", SetScriptParams, Agent) -> StatusResult;
+    /// 获取 Script Agent 观测模型与宿主 API 的 TypeScript 声明（`.d.ts` 内容）。
+    script_types => ScriptTypes("script_types", (), Spectator) -> String;
+    /// 取出英雄 Script Agent 自上次读取以来的 `console.*` 日志（每个英雄最多缓存 256 条）。
+    script_logs => ScriptLogs("script_logs", ScriptLogsParams, Agent) -> ScriptLogsResult;
+    /// 初始化 RL 环境并返回首帧观测。
+    rl_reset => RlReset("rl_reset", RlResetParams, Agent) -> RlResetResult;
+    /// 推进 RL 环境一步，返回奖励、终止标志与观测。
+    rl_step => RlStep("rl_step", RlStepParams, Agent) -> Value;
+    /// 订阅本连接的观测增量推送（`observe_delta` 事件）。
+    subscribe_observe => SubscribeObserve("subscribe_observe", SubscribeObserveParams, Spectator)
+        -> SubscribeObserveResult;
+    /// 取消本连接的观测订阅。
+    unsubscribe_observe => UnsubscribeObserve("unsubscribe_observe", UnsubscribeObserveParams, Spectator)
+        -> StatusResult;
+    /// 订阅全量世界快照推送（`world_snapshot` 事件），仅供观战网关转播。
+    subscribe_world => SubscribeWorld("subscribe_world", SubscribeWorldParams, Relay)
+        -> SubscribeWorldResult;
+    /// 取消本连接的世界快照订阅。
+    unsubscribe_world => UnsubscribeWorld("unsubscribe_world", UnsubscribeWorldParams, Relay)
+        -> StatusResult;
+
+    // ── 调试面（lol_debug，仅 debug 构建注册）──
+    /// 切换当前英雄。
+    switch_champion => SwitchChampion("switch_champion", SwitchChampionParams, Debug) -> NameResult;
+    /// 开关上帝模式。
+    god_mode => GodMode("god_mode", EnabledParams, Debug) -> EnabledResult;
+    /// 开关技能冷却。
+    toggle_cooldown => ToggleCooldown("toggle_cooldown", EnabledParams, Debug) -> EnabledResult;
+    /// 把英雄传送回出生点。
+    reset_position => ResetPosition("reset_position", (), Debug) -> EmptyResult;
+    /// 切换暂停状态。
+    toggle_pause => TogglePause("toggle_pause", (), Debug) -> PausedResult;
+    /// 设置游戏倍速。
+    set_speed => SetSpeed("set_speed", SetSpeedParams, Debug) -> SpeedResult;
+    /// 查询调试状态。
+    get_state => GetState("get_state", (), Spectator) -> DebugStateResult;
+    /// 查询游戏内时间（秒）。
+    get_time => GetTime("get_time", (), Spectator) -> TimeResult;
+
+    // ── 粒子预览（lol_particle）──
+    /// 播放一段粒子定义（替换正在播放的粒子）。
+    play_particle => PlayParticle("play_particle", PlayParticleParams, Debug) -> PlayParticleResult;
+    /// 停止正在播放的粒子。
+    stop_particle => StopParticle("stop_particle", (), Debug) -> EmptyResult;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn command_table_is_consistent() {
+        let infos = command_infos();
+        assert_eq!(infos.len(), COMMANDS.len());
+        assert_eq!(
+            COMMANDS.iter().collect::<HashSet<_>>().len(),
+            COMMANDS.len(),
+            "命令字重复"
+        );
+        for info in &infos {
+            assert!(!info.description.is_empty(), "{} 缺少说明", info.name);
+        }
+
+        let observe = infos.iter().find(|i| i.name == "observe").unwrap();
+        assert!(observe.params_schema["properties"]["entity_id"].is_object());
+        let get_time = infos.iter().find(|i| i.name == "get_time").unwrap();
+        assert_eq!(get_time.params_schema["type"], json!("null"));
+        assert!(get_time.result_schema["properties"]["time"].is_object());
+    }
+
+    #[test]
+    fn params_serialize_to_wire_shape() {
+        let params = ActionParams {
+            entity_id: Some(3),
+            action: crate::Action::Move([1.0, 2.0]),
+        };
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            json!({ "entity_id": 3, "action": { "Move": [1.0, 2.0] } })
+        );
+        assert_eq!(serde_json::to_value(()).unwrap(), Value::Null);
+    }
+
+    #[test]
+    fn action_params_accept_flat_and_wrapped() {
+        let params: ActionParams = serde_json::from_value(json!({ "Move": [10.0, 20.0] })).unwrap();
+        assert_eq!(params.entity_id, None);
+        let crate::Action::Move(pos) = params.action else {
+            panic!("Expected Action::Move");
+        };
+        assert_eq!(pos, [10.0, 20.0]);
+
+        let params: ActionParams = serde_json::from_value(json!({
+            "entity_id": 123,
+            "action": { "Move": [10.0, 20.0] }
+        }))
+        .unwrap();
+        assert_eq!(params.entity_id, Some(123));
+        let crate::Action::Move(pos) = params.action else {
+            panic!("Expected Action::Move");
+        };
+        assert_eq!(pos, [10.0, 20.0]);
+
+        assert!(serde_json::from_value::<ActionParams>(json!({ "Jump": 1 })).is_err());
+    }
+}
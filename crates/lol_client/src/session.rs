@@ -5,16 +5,19 @@ use std::sync::{Arc, Mutex};
 use futures_util::{SinkExt, StreamExt};
 use tokio::sync::{mpsc, oneshot};
 use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Error as WsError;
 use tokio_tungstenite::tungstenite::protocol::Message;
 
-use crate::protocol::{WsRequest, WsResponse};
+use crate::codec;
+use crate::protocol::{CMD_SET_ENCODING, WsEncoding, WsRequest, WsResponse};
 
-/// 与游戏 WebSocket 服务端的会话。Clone 后共享同一连接与挂起请求表。
+/// 与游戏 WebSocket 服务端的会话。Clone 后共享同一连接、挂起请求表与帧编码。
 #[derive(Clone)]
 pub struct WsSession {
     tx: mpsc::Sender<Message>,
     pending: Arc<Mutex<HashMap<u64, oneshot::Sender<WsResponse>>>>,
     next_id: Arc<AtomicU64>,
+    encoding: Arc<Mutex<WsEncoding>>,
 }
 
 impl WsSession {
@@ -33,14 +36,14 @@ impl WsSession {
 
         let req = WsRequest { id, cmd, params };
 
-        let req_str = serde_json::to_string(&req).map_err(|e| e.to_string())?;
+        let frame = codec::encode(self.encoding(), &req)?;
         let (rx_tx, rx) = oneshot::channel();
         {
             let mut pending_lock = self.pending.lock().unwrap();
             pending_lock.insert(id, rx_tx);
         }
 
-        if let Err(e) = self.tx.send(Message::Text(req_str.into())).await {
+        if let Err(e) = self.tx.send(frame).await {
             let mut pending_lock = self.pending.lock().unwrap();
             pending_lock.remove(&id);
             return Err(format!("发送 WS 写入任务失败: {}", e));
@@ -56,9 +59,32 @@ impl WsSession {
             }
         }
     }
+
+    /// 当前连接使用的帧编码。
+    pub fn encoding(&self) -> WsEncoding {
+        *self.encoding.lock().unwrap()
+    }
+
+    /// 与服务端协商帧编码。服务端以旧编码回执后切换，本端收到成功回执后再切换；
+    /// 两端都按帧类型解码，切换瞬间的在途帧不受影响。
+    pub async fn set_encoding(&self, encoding: WsEncoding) -> Result<(), String> {
+        let resp = self
+            .send_cmd(
+                CMD_SET_ENCODING.to_string(),
+                serde_json::json!({ "encoding": encoding }),
+            )
+            .await?;
+        if !resp.ok {
+            return Err(resp
+                .error
+                .unwrap_or_else(|| "set_encoding 失败".to_string()));
+        }
+        *self.encoding.lock().unwrap() = encoding;
+        Ok(())
+    }
 }
 
-/// 连接游戏 WS 服务端。
+/// 连接游戏 WS 服务端（开放模式，不携带令牌）。
 ///
 /// `event_tx` 为可选事件通道：非响应（`result`）的消息会以原始 JSON 推入该通道，
 /// 连接断开时会推送一条 `game_close` 事件。CLI / MCP 等不关心事件的消费方可传 `None`。
@@ -66,14 +92,40 @@ pub async fn start_ws_client(
     port: u16,
     event_tx: Option<mpsc::Sender<serde_json::Value>>,
 ) -> Result<WsSession, String> {
-    let url = format!("ws://127.0.0.1:{}", port);
+    start_ws_client_with_token(port, None, event_tx).await
+}
+
+/// 携带令牌连接启用了鉴权的游戏 WS 服务端（见 [`crate::auth`]）。
+///
+/// 令牌被拒（HTTP 401 / 403）时立即返回错误，不进入 30s 重试。
+pub async fn start_ws_client_with_token(
+    port: u16,
+    token: Option<&str>,
+    event_tx: Option<mpsc::Sender<serde_json::Value>>,
+) -> Result<WsSession, String> {
+    let url = match token {
+        Some(token) => format!("ws://127.0.0.1:{}/?token={}", port, token),
+        None => format!("ws://127.0.0.1:{}", port),
+    };
     let mut ws_stream = None;
     let start_time = std::time::Instant::now();
 
     while start_time.elapsed() < std::time::Duration::from_secs(30) {
-        if let Ok((stream, _)) = connect_async(&url).await {
-            ws_stream = Some(stream);
-            break;
+        match connect_async(&url).await {
+            Ok((stream, _)) => {
+                ws_stream = Some(stream);
+                break;
+            }
+            Err(WsError::Http(resp)) if resp.status().is_client_error() => {
+                let reason = resp
+                    .body()
+                    .as_deref()
+                    .map(String::from_utf8_lossy)
+                    .unwrap_or_default()
+                    .into_owned();
+                return Err(format!("WS 鉴权失败 ({}): {}", resp.status(), reason));
+            }
+            Err(_) => {}
         }
         tokio::time::sleep(std::time::Duration::from_millis(1500)).await;
     }
@@ -106,24 +158,23 @@ pub async fn start_ws_client(
             let Ok(msg) = msg_res else {
                 break;
             };
-            let Message::Text(text) = msg else {
+            // 文本帧（JSON）与二进制帧（MessagePack）统一解码为 Value 再分流。
+            let Some(Ok(val)) = codec::decode::<serde_json::Value>(&msg) else {
                 continue;
             };
 
-            if let Ok(resp) = serde_json::from_str::<WsResponse>(&text) {
-                if resp.msg_type == "result" {
+            if val.get("type").and_then(|t| t.as_str()) == Some("result") {
+                if let Ok(resp) = serde_json::from_value::<WsResponse>(val) {
                     let mut pending_lock = pending_clone.lock().unwrap();
                     if let Some(tx) = pending_lock.remove(&resp.id) {
                         let _ = tx.send(resp);
                     }
-                    continue;
                 }
+                continue;
             }
 
             if let Some(event_tx) = event_tx.as_ref() {
-                if let Ok(val) = serde_json::from_str::<serde_json::Value>(&text) {
-                    let _ = event_tx.try_send(val);
-                }
+                let _ = event_tx.try_send(val);
             }
         }
 
@@ -142,5 +193,6 @@ pub async fn start_ws_client(
         tx,
         pending,
         next_id,
+        encoding: Arc::new(Mutex::new(WsEncoding::Json)),
     })
 }
@@ -0,0 +1,48 @@
+//! 观战世界快照：Bevy 进程经 `world_snapshot` 事件推送的全量实体状态。
+//!
+//! 实体以 `entity` 位表示为键组织成 map，web server 的观战网关据此按实体逐字段计算
+//! JSON Merge Patch（见 [`crate::codec::diff`]），观众只收到变化的实体与字段。
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// 快照中实体的类别。
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SnapshotEntityKind {
+    Champion,
+    Minion,
+    Turret,
+    Inhibitor,
+    Nexus,
+    Other,
+}
+
+/// 单个实体在某一 tick 的可见状态。
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EntitySnapshot {
+    pub kind: SnapshotEntityKind,
+    /// "Order" / "Chaos" / "Neutral"。
+    pub team: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// 地面坐标 (x, z)。
+    pub position: [f32; 2],
+    pub health: f32,
+    pub max_health: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<u32>,
+    #[serde(default)]
+    pub dead: bool,
+}
+
+/// 整个对局世界在某一 tick 的快照。
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct WorldSnapshot {
+    /// Bevy 进程内的 FixedUpdate tick 序号。
+    pub tick: u64,
+    /// 对局时间（秒）。
+    pub time: f32,
+    pub entities: BTreeMap<u64, EntitySnapshot>,
+}
@@ -8,16 +8,19 @@ use serde::{Deserialize, Serialize};
 
 use crate::action::damage::on_action_damage;
 use crate::action::dash::{
-    on_action_dash, on_dash_end, on_dash_start_attach_damage, on_movement_block_add_cancel_dash,
-    update_dash_damage, update_tracking_dash,
+    on_action_dash, on_dash_block_add_cancel_dash, on_dash_end, on_dash_start_attach_damage,
+    on_movement_block_add_cancel_dash, update_dash_damage, update_tracking_dash,
 };
 use crate::action::delayed_damage::{on_action_delayed_damage, update_delayed_damage};
 use crate::action::displace::{on_action_displace, update_grabbed_entities};
 use crate::action::knockback::on_command_knockback;
 use crate::attack_auto::{CommandAttackAutoStart, CommandAttackAutoStop};
+use crate::buffs::cc_debuffs::ForcedAction;
 use crate::movement::{CommandMovement, MovementAction};
+use crate::recall::CommandRecall;
 use crate::run::{CommandRunStart, RunTarget};
-use crate::skill::{CommandSkillBeforeStart, CommandSkillLevelUp, CommandSkillStart};
+use crate::skill::{CommandSkillBeforeStart, CommandSkillLevelUp, CommandSkillStart, SkillTarget};
+use crate::summoner::CommandSummonerSpell;
 
 #[derive(Default)]
 pub struct PluginAction;
@@ -27,6 +30,7 @@ impl Plugin for PluginAction {
         app.add_observer(on_action_dash);
         app.add_observer(on_dash_end);
         app.add_observer(on_movement_block_add_cancel_dash);
+        app.add_observer(on_dash_block_add_cancel_dash);
         app.add_observer(on_dash_start_attach_damage);
         app.add_observer(on_action_damage);
         app.add_observer(on_action_delayed_damage);
@@ -53,13 +57,67 @@ pub enum Action {
     Attack(Entity),
     Move(Vec2),
     Stop,
-    Skill { index: usize, point: Vec2 },
+    Skill {
+        index: usize,
+        point: Vec2,
+    },
+    /// 点选单位施法
+    SkillUnit {
+        index: usize,
+        target: Entity,
+    },
+    /// 对自身施法
+    SkillSelf {
+        index: usize,
+    },
+    /// 两点向量施法
+    SkillVector {
+        index: usize,
+        start: Vec2,
+        end: Vec2,
+    },
     SkillLevelUp(usize),
+    /// 施放召唤师技能（槽位 0: D, 1: F）
+    SummonerSpell {
+        slot: usize,
+        target: SkillTarget,
+    },
+    /// 回城：引导结束后传送回己方泉水，受到伤害或下达其它指令会打断
+    Recall,
 }
 
-fn on_command_action(trigger: On<CommandAction>, mut commands: Commands) {
+impl Action {
+    /// 施法类动作的技能索引与目标。
+    pub fn skill_target(&self) -> Option<(usize, SkillTarget)> {
+        match *self {
+            Action::Skill { index, point } => Some((index, SkillTarget::Point(point))),
+            Action::SkillUnit { index, target } => Some((index, SkillTarget::Unit(target))),
+            Action::SkillSelf { index } => Some((index, SkillTarget::SelfCast)),
+            Action::SkillVector { index, start, end } => {
+                Some((index, SkillTarget::Vector { start, end }))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn on_command_action(
+    trigger: On<CommandAction>,
+    mut commands: Commands,
+    q_forced: Query<(), With<ForcedAction>>,
+) {
     let entity = trigger.event_target();
 
+    // 被嘲讽 / 魅惑 / 恐惧 / 硬控接管行动时忽略玩家指令（加点不受影响，召唤师技能自行判定净化）
+    if q_forced.get(entity).is_ok()
+        && !matches!(
+            trigger.action,
+            Action::SkillLevelUp(_) | Action::SummonerSpell { .. }
+        )
+    {
+        return;
+    }
+
     match trigger.action {
         Action::Attack(target) => {
             commands.trigger(CommandAttackAutoStart { entity, target });
@@ -76,21 +134,35 @@ fn on_command_action(trigger: On<CommandAction>, mut commands: Commands) {
             //     ..default()
             // });
         }
-        Action::Skill { index, point } => {
+        Action::Skill { .. }
+        | Action::SkillUnit { .. }
+        | Action::SkillSelf { .. }
+        | Action::SkillVector { .. } => {
+            let Some((index, target)) = trigger.action.skill_target() else {
+                return;
+            };
+            let start = CommandSkillStart::new(entity, index, target);
             commands.trigger(CommandSkillBeforeStart {
                 entity,
                 index,
-                point,
-            });
-            commands.trigger(CommandSkillStart {
-                entity,
-                index,
-                point,
+                point: start.point,
+                target,
             });
+            commands.trigger(start);
         }
         Action::SkillLevelUp(index) => {
             commands.trigger(CommandSkillLevelUp { entity, index });
         }
+        Action::SummonerSpell { slot, target } => {
+            commands.trigger(CommandSummonerSpell {
+                entity,
+                slot,
+                target,
+            });
+        }
+        Action::Recall => {
+            commands.trigger(CommandRecall { entity });
+        }
         Action::Stop => {
             commands.trigger(CommandAttackAutoStop { entity });
             commands.trigger(CommandMovement {
@@ -1,11 +1,19 @@
 use bevy::prelude::*;
 use lol_base::spell::Spell;
+use serde::{Deserialize, Serialize};
 
 use crate::action::delayed_damage::DelayedDamageInstance;
-use crate::damage::{AbilityPower, CommandDamageCreate, Damage, DamageType};
-use crate::entities::champion::Champion;
+use crate::buffs::spell_shield::SpellShields;
+use crate::damage::{
+    AbilityPower, CommandDamageCreate, Damage, DamageKind, DamageType, Untargetable,
+};
+use crate::entities::champion::TargetableAsChampion;
 use crate::entities::minion::Minion;
-use crate::skill::{Skill, Skills, get_skill_data_value, get_skill_value};
+use crate::skill::{
+    Skill, SkillCalcCaster, SkillCalcQuery, SkillTarget, Skills, get_skill_data_value,
+    get_skill_value_with,
+};
+use crate::spatial::{SpatialCandidates, SpatialFilter, SpatialShape};
 use crate::team::Team;
 
 #[derive(Debug, Clone)]
@@ -30,6 +38,16 @@ pub enum DamageShape {
         length: f32,
         start_distance: f32,
     },
+    /// 点选的单个目标（不看距离，存在且为敌方即命中）
+    Unit {
+        entity: Entity,
+    },
+    /// 世界坐标线段（向量施法）：`start` 到 `end`，左右各 width/2，与施法者位置无关
+    Line {
+        start: Vec2,
+        end: Vec2,
+        width: f32,
+    },
 }
 
 impl Default for DamageShape {
@@ -38,7 +56,17 @@ impl Default for DamageShape {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+impl DamageShape {
+    /// 点选技能的命中形状：指定了单位时只打该单位，否则退化为最近的敌人
+    pub fn unit_or_nearest(target: SkillTarget, max_distance: f32) -> Self {
+        match target {
+            SkillTarget::Unit(entity) => Self::Unit { entity },
+            _ => Self::Nearest { max_distance },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum TargetFilter {
     #[default]
     All,
@@ -133,10 +161,54 @@ pub fn is_in_shape(target_pos: Vec3, origin: Vec3, forward: Vec2, shape: &Damage
             let lateral = -forward.y * diff.x + forward.x * diff.y;
             lateral.abs() <= *width / 2.0
         }
+        // 单体形状只能按实体命中，不参与位置判定（作排除区时不排除任何目标）
+        DamageShape::Unit { .. } => false,
+        DamageShape::Line { start, end, width } => {
+            let p = target_pos.xz();
+            let seg = *end - *start;
+            let t = if seg.length_squared() > 0.0 {
+                ((p - *start).dot(seg) / seg.length_squared()).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            p.distance(*start + seg * t) <= *width / 2.0
+        }
+    }
+}
+
+/// 形状的空间索引粗筛范围（外扩由 [`SpatialCandidates`] 负责），单体形状返回 None。
+pub fn broad_phase_shape(origin: Vec3, forward: Vec2, shape: &DamageShape) -> Option<SpatialShape> {
+    let center = origin.xz();
+    let circle = |radius: f32| SpatialShape::Circle { center, radius };
+    match shape {
+        DamageShape::Circle { radius } | DamageShape::Sector { radius, .. } => {
+            Some(circle(*radius))
+        }
+        DamageShape::Annular { outer_radius, .. } => Some(circle(*outer_radius)),
+        DamageShape::Nearest { max_distance } => Some(circle(*max_distance)),
+        DamageShape::Rectangle {
+            width,
+            length,
+            start_distance,
+        } => Some(SpatialShape::Rectangle {
+            origin: center,
+            forward,
+            width: *width,
+            length: *length,
+            start_distance: *start_distance,
+        }),
+        DamageShape::Line { start, end, width } => Some(SpatialShape::Segment {
+            start: *start,
+            end: *end,
+            width: *width,
+        }),
+        DamageShape::Unit { .. } => None,
     }
 }
 
 /// 在形状内收集敌方目标（同队跳过）。Nearest 仅返回最近单体。
+///
+/// 先按 `broad_phase_shape` 粗筛候选，再用实时 Transform 精确判定。
 pub fn collect_targets_in_shape(
     origin: Vec3,
     forward: Vec2,
@@ -146,19 +218,39 @@ pub fn collect_targets_in_shape(
         (
             Entity,
             &Team,
-            Option<&Champion>,
+            Option<&TargetableAsChampion>,
             Option<&Minion>,
             &Transform,
         ),
-        Without<DelayedDamageInstance>,
+        (Without<DelayedDamageInstance>, Without<Untargetable>),
     >,
+    spatial: &SpatialCandidates,
 ) -> Vec<Entity> {
     let mut targets = Vec::new();
+    if let DamageShape::Unit { entity } = shape {
+        if let Ok((target, target_team, _, _, _)) = q_target.get(*entity)
+            && target_team != team
+        {
+            targets.push(target);
+        }
+        return targets;
+    }
+
+    let mut candidates = Vec::new();
+    if let Some(broad) = broad_phase_shape(origin, forward, shape) {
+        spatial.query_into(
+            &broad,
+            SpatialFilter::not_team(*team),
+            q_target.iter().map(|(e, ..)| e),
+            &mut candidates,
+        );
+    }
+
     match shape {
         DamageShape::Nearest { max_distance } => {
             let mut min_dist = *max_distance;
             let mut nearest = None;
-            for (target, target_team, _, _, target_transform) in q_target.iter() {
+            for (target, target_team, _, _, target_transform) in q_target.iter_many(&candidates) {
                 if target_team == team {
                     continue;
                 }
@@ -173,7 +265,7 @@ pub fn collect_targets_in_shape(
             }
         }
         _ => {
-            for (target, target_team, _, _, target_transform) in q_target.iter() {
+            for (target, target_team, _, _, target_transform) in q_target.iter_many(&candidates) {
                 if target_team == team {
                     continue;
                 }
@@ -191,6 +283,7 @@ pub fn collect_targets_in_shape(
 /// - `origin`/`forward` 为伤害快照位置与朝向（延迟伤害为施法瞬间快照）
 /// - 对每个 effect：先收集形状内目标，再剔除 exclude 区内目标，
 ///   最后对每条 damage 按 filter 与 modifier（Isolation 仅当唯一目标）结算
+/// - 每个目标首次结算时判定法术护盾，被挡下的目标不受本次任何伤害
 /// - 返回每个 effect 命中的目标列表（用于 AoE 命中报告）
 pub fn apply_damage_effects(
     commands: &mut Commands,
@@ -201,23 +294,30 @@ pub fn apply_damage_effects(
     effects: &[ActionDamageEffect],
     skill_object: &Spell,
     skill_level: usize,
+    calc_caster: &SkillCalcCaster,
     q_target: &Query<
         (
             Entity,
             &Team,
-            Option<&Champion>,
+            Option<&TargetableAsChampion>,
             Option<&Minion>,
             &Transform,
         ),
-        Without<DelayedDamageInstance>,
+        (Without<DelayedDamageInstance>, Without<Untargetable>),
     >,
     q_damage: &Query<&Damage>,
     q_ap: &Query<&AbilityPower>,
+    shields: &SpellShields,
+    spatial: &SpatialCandidates,
 ) -> Vec<Vec<Entity>> {
     let mut hit_per_effect = Vec::with_capacity(effects.len());
+    // 法术护盾：每个目标每次结算只判定一次，被挡下的目标跳过全部伤害
+    let mut shield_checked: Vec<Entity> = Vec::new();
+    let mut shielded: Vec<Entity> = Vec::new();
 
     for effect in effects {
-        let mut targets = collect_targets_in_shape(origin, forward, &effect.shape, team, q_target);
+        let mut targets =
+            collect_targets_in_shape(origin, forward, &effect.shape, team, q_target, spatial);
 
         // 排除区：在任意 exclude 子形状内的目标跳过本 effect
         if !effect.exclude.is_empty() {
@@ -252,8 +352,22 @@ pub fn apply_damage_effects(
                     continue;
                 }
 
-                let mut damage_amount =
-                    get_skill_value(&skill_object, &damage.amount, skill_level, |stat| {
+                if !shield_checked.contains(target_entity) {
+                    shield_checked.push(*target_entity);
+                    if shields.block_ability(commands, *target_entity, caster) {
+                        shielded.push(*target_entity);
+                    }
+                }
+                if shielded.contains(target_entity) {
+                    continue;
+                }
+
+                let Some(mut damage_amount) = get_skill_value_with(
+                    &skill_object,
+                    &damage.amount,
+                    skill_level,
+                    calc_caster,
+                    |stat| {
                         // stat==2 -> AD（物理攻击力），stat==0/None -> AP（法术强度）
                         if stat == 2 {
                             if let Ok(damage) = q_damage.get(caster) {
@@ -266,8 +380,12 @@ pub fn apply_damage_effects(
                             }
                         }
                         0.0
-                    })
-                    .unwrap();
+                    },
+                ) else {
+                    // 数据缺失时跳过这一段伤害，不让整个技能结算崩溃
+                    warn!("技能伤害计算失败: {}", damage.amount);
+                    continue;
+                };
 
                 // 修饰器：孤立增伤
                 if let DamageModifier::Isolation { scalar_data_value } = &damage.modifier {
@@ -287,6 +405,7 @@ pub fn apply_damage_effects(
                         damage_type: damage.damage_type,
                         amount: damage_amount,
                         tag: effect.tag,
+                        kind: DamageKind::Ability,
                     });
 
                 if !actually_hit.contains(target_entity) {
@@ -310,17 +429,20 @@ pub fn on_action_damage(
         (
             Entity,
             &Team,
-            Option<&Champion>,
+            Option<&TargetableAsChampion>,
             Option<&Minion>,
             &Transform,
         ),
-        Without<DelayedDamageInstance>,
+        (Without<DelayedDamageInstance>, Without<Untargetable>),
     >,
     q_team: Query<&Team>,
     q_skills: Query<&Skills>,
     q_skill: Query<&Skill>,
     q_damage: Query<&Damage>,
     q_ap: Query<&AbilityPower>,
+    calc: SkillCalcQuery,
+    shields: SpellShields,
+    spatial: SpatialCandidates,
 ) {
     let entity = event.event_target();
 
@@ -358,9 +480,12 @@ pub fn on_action_damage(
         &event.effects,
         skill_object,
         skill.level,
+        &calc.caster(entity),
         &q_target,
         &q_damage,
         &q_ap,
+        &shields,
+        &spatial,
     );
 }
 
@@ -555,4 +680,46 @@ mod tests {
             &shape,
         ));
     }
+
+    #[test]
+    fn broad_phase_covers_every_hit_point() {
+        let forward = Vec2::new(0.6, 0.8);
+        let shapes = [
+            DamageShape::Sector {
+                radius: 400.0,
+                angle: 60.0,
+            },
+            DamageShape::Rectangle {
+                width: 200.0,
+                length: 600.0,
+                start_distance: 50.0,
+            },
+            DamageShape::Line {
+                start: Vec2::new(-300.0, 100.0),
+                end: Vec2::new(500.0, -200.0),
+                width: 120.0,
+            },
+        ];
+        for shape in &shapes {
+            let broad = broad_phase_shape(ORIGIN, forward, shape).unwrap();
+            for x in (-800..=800).step_by(25) {
+                for z in (-800..=800).step_by(25) {
+                    let p = Vec3::new(x as f32, 0.0, z as f32);
+                    if is_in_shape(p, ORIGIN, forward, shape) {
+                        assert!(broad.contains(p.xz()), "{shape:?} 漏掉 {p}");
+                    }
+                }
+            }
+        }
+        assert!(
+            broad_phase_shape(
+                ORIGIN,
+                forward,
+                &DamageShape::Unit {
+                    entity: Entity::PLACEHOLDER
+                }
+            )
+            .is_none()
+        );
+    }
 }
@@ -1,18 +1,21 @@
 use std::collections::HashSet;
 
 use bevy::prelude::*;
+use lol_base::grid::ConfigNavigationGrid;
 use lol_base::spell::Spell;
 
 use crate::action::damage::{TargetDamage, TargetFilter};
-use crate::damage::{CommandDamageCreate, Damage};
-use crate::entities::champion::Champion;
+use crate::damage::{CommandDamageCreate, Damage, DamageKind};
+use crate::entities::champion::TargetableAsChampion;
 use crate::entities::minion::Minion;
 use crate::life::Death;
 use crate::movement::{
-    CommandMovement, EventMovementEnd, MovementAction, MovementBlock, MovementSource,
+    CommandMovement, DashBlock, EventMovementEnd, MovementAction, MovementBlock, MovementSource,
     MovementState, MovementWay,
 };
-use crate::skill::{Skill, Skills, get_skill_value};
+use crate::navigation::grid::ResourceGrid;
+use crate::navigation::terrain::clip_segment_by_terrain;
+use crate::skill::{Skill, SkillCalcQuery, Skills, get_skill_value_with};
 use crate::team::Team;
 
 #[derive(Debug, Clone, EntityEvent)]
@@ -86,9 +89,20 @@ pub fn on_action_dash(
     trigger: On<ActionDash>,
     mut commands: Commands,
     q_transform: Query<&Transform>,
+    q_dash_block: Query<(), With<DashBlock>>,
+    res_grid: Option<Res<ResourceGrid>>,
+    assets_grid: Option<Res<Assets<ConfigNavigationGrid>>>,
 ) {
     let entity = trigger.event_target();
 
+    if q_dash_block.get(entity).is_ok() {
+        debug!(
+            "on_action_dash: entity {:?} is dash-blocked, skipping",
+            entity
+        );
+        return;
+    }
+
     let Ok(transform) = q_transform.get(entity) else {
         debug!(
             "on_action_dash: entity {:?} has no Transform, skipping",
@@ -183,6 +197,18 @@ pub fn on_action_dash(
         "on_action_dash: triggering CommandMovement for entity {:?}, destination ({:.2}, {:.2}, {:.2}), move_type: {}",
         entity, destination.x, current_pos.y, destination.y, move_type_desc
     );
+    // 技能墙等地形覆盖层挡住位移：停在墙前（追踪位移由 update_tracking_dash 每帧截断）
+    let destination = match (res_grid.as_ref(), assets_grid.as_ref()) {
+        (Some(res_grid), Some(assets_grid))
+            if !matches!(trigger.move_type, DashMoveType::Entity { .. }) =>
+        {
+            match assets_grid.get(&res_grid.0) {
+                Some(grid) => clip_segment_by_terrain(grid, current_pos.xz(), destination),
+                None => destination,
+            }
+        }
+        _ => destination,
+    };
     let destination_y = current_pos.y;
     let destination = Vec3::new(destination.x, destination_y, destination.y);
     commands.trigger(CommandMovement {
@@ -205,14 +231,36 @@ pub fn on_action_dash(
 
 /// 硬控（`MovementBlock` 添加时）取消活跃位移：清路径、发结束事件、清理追踪/沿途伤害组件。
 ///
-/// `MovementBlock` 由 `sync_cc_markers` 在 Stun/Fear/BuffCastBlock 添加时自动桥接到角色。
+/// `MovementBlock` 由 CC 标记重算在眩晕 / 禁锢 / BuffCastBlock 等添加时自动桥接到角色。
 /// 本观察者确保：眩晕等硬控到达时，位移立即取消（而非暂停）。
 pub fn on_movement_block_add_cancel_dash(
     trigger: On<Add, MovementBlock>,
     mut commands: Commands,
     q_movement: Query<&MovementState>,
 ) {
-    let entity = trigger.entity;
+    cancel_dash(&mut commands, trigger.entity, &q_movement, "MovementBlock");
+}
+
+/// 位移阻塞（击飞 / 魅惑 / 禁足等）到达时同样取消进行中的位移。
+/// 同时带 `MovementBlock` 的控制已由上一个观察者处理，这里跳过避免重复结束事件。
+pub fn on_dash_block_add_cancel_dash(
+    trigger: On<Add, DashBlock>,
+    mut commands: Commands,
+    q_movement: Query<&MovementState>,
+    q_movement_block: Query<(), With<MovementBlock>>,
+) {
+    if q_movement_block.get(trigger.entity).is_ok() {
+        return;
+    }
+    cancel_dash(&mut commands, trigger.entity, &q_movement, "DashBlock");
+}
+
+fn cancel_dash(
+    commands: &mut Commands,
+    entity: Entity,
+    q_movement: &Query<&MovementState>,
+    reason: &str,
+) {
     let Ok(ms) = q_movement.get(entity) else {
         return;
     };
@@ -221,8 +269,8 @@ pub fn on_movement_block_add_cancel_dash(
     }
 
     debug!(
-        "硬控取消位移: entity {:?} 的 dash 被 MovementBlock 取消",
-        entity
+        "硬控取消位移: entity {:?} 的 dash 被 {} 取消",
+        entity, reason
     );
 
     // 清路径，发结束事件
@@ -278,7 +326,7 @@ pub fn update_dash_damage(
             Entity,
             &Transform,
             &Team,
-            Option<&Champion>,
+            Option<&TargetableAsChampion>,
             Option<&Minion>,
         ),
         Without<Death>,
@@ -287,6 +335,7 @@ pub fn update_dash_damage(
     q_skill: Query<&Skill>,
     q_damage: Query<&Damage>,
     res_assets_spell_object: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
 ) {
     for (entity, dasher_transform, mut dash_damage, team) in q_dasher.iter_mut() {
         let Some(skill_object) = res_assets_spell_object.get(&dash_damage.skill) else {
@@ -295,11 +344,26 @@ pub fn update_dash_damage(
         let Ok(skills) = q_skills.get(entity) else {
             continue;
         };
-        let skill = skills
-            .iter()
-            .map(|v| q_skill.get(v))
-            .find_map(|v| v.ok())
-            .unwrap();
+        let Some(skill) = skills.iter().find_map(|v| q_skill.get(v).ok()) else {
+            continue;
+        };
+        let Some(damage_amount) = get_skill_value_with(
+            &skill_object,
+            &dash_damage.damage.damage.amount,
+            skill.level,
+            &calc.caster(entity),
+            |stat| {
+                if stat == 2 {
+                    if let Ok(damage) = q_damage.get(entity) {
+                        return damage.0;
+                    }
+                }
+                0.0
+            },
+        ) else {
+            warn!("位移伤害计算失败: {}", dash_damage.damage.damage.amount);
+            continue;
+        };
 
         let start_pos = dash_damage.start_pos;
         let target_pos = dash_damage.target_pos;
@@ -336,21 +400,6 @@ pub fn update_dash_damage(
                 continue;
             }
 
-            let damage_amount = get_skill_value(
-                &skill_object,
-                &dash_damage.damage.damage.amount,
-                skill.level,
-                |stat| {
-                    if stat == 2 {
-                        if let Ok(damage) = q_damage.get(entity) {
-                            return damage.0;
-                        }
-                    }
-                    0.0
-                },
-            )
-            .unwrap();
-
             if dasher_transform
                 .translation
                 .distance(target_transform.translation)
@@ -362,6 +411,7 @@ pub fn update_dash_damage(
                     damage_type: dash_damage.damage.damage.damage_type,
                     amount: damage_amount,
                     tag: None,
+                    kind: DamageKind::Ability,
                 });
                 dash_damage.hit_entities.insert(target);
             }
@@ -375,7 +425,14 @@ pub fn update_tracking_dash(
     mut commands: Commands,
     mut q_dasher: Query<(Entity, &Transform, &mut MovementState, &TrackingDash)>,
     q_target: Query<&Transform>,
+    res_grid: Option<Res<ResourceGrid>>,
+    assets_grid: Option<Res<Assets<ConfigNavigationGrid>>>,
 ) {
+    let grid = res_grid
+        .as_ref()
+        .zip(assets_grid.as_ref())
+        .and_then(|(res_grid, assets_grid)| assets_grid.get(&res_grid.0));
+
     for (entity, dasher_transform, mut ms, tracking) in q_dasher.iter_mut() {
         // 目标消失：完成位移（若尚未完成）
         let target_pos = match q_target.get(tracking.target) {
@@ -393,9 +450,20 @@ pub fn update_tracking_dash(
             }
         };
 
-        // 每帧重新瞄准 target 当前位置
+        // 每帧重新瞄准 target 当前位置，中间隔着地形墙时只冲到墙前
         if !ms.path.is_empty() {
-            ms.path[0] = target_pos;
+            let aim = match grid {
+                Some(grid) => {
+                    let clipped = clip_segment_by_terrain(
+                        grid,
+                        dasher_transform.translation.xz(),
+                        target_pos.xz(),
+                    );
+                    Vec3::new(clipped.x, target_pos.y, clipped.y)
+                }
+                None => target_pos,
+            };
+            ms.path[0] = aim;
         }
 
         // 接触即停
@@ -417,15 +485,12 @@ pub fn update_tracking_dash(
 #[cfg(test)]
 mod tests {
     use bevy::time::TimeUpdateStrategy;
-    use lol_base::grid::ConfigNavigationGrid;
-    use lol_base::spell::Spell;
 
     use super::*;
     use crate::action::PluginAction;
     use crate::movement::{Movement, PluginMovement};
-    use crate::navigation::grid::ResourceGrid;
     use crate::navigation::navigation::PluginNavigaton;
-    use crate::team::Team;
+    use crate::navigation::terrain::{rasterize_polygon, terrain_wall_segment};
 
     fn app_with_grid() -> App {
         let mut app = App::new();
@@ -561,4 +626,46 @@ mod tests {
             "追踪系统应跟随移动后的目标到 -500 附近，实际 x = {x}"
         );
     }
+
+    #[test]
+    fn dash_entity_stops_before_terrain_wall() {
+        let mut app = app_with_grid();
+        let mut grid = ConfigNavigationGrid {
+            min_position: Vec2::new(-1000.0, -1000.0),
+            cell_size: 50.0,
+            x_len: 40,
+            y_len: 40,
+            cells: vec![vec![default(); 40]; 40],
+            ..default()
+        };
+        let wall = terrain_wall_segment(Vec2::new(200.0, 0.0), Vec2::X, 600.0, 20.0);
+        grid.terrain_cells.extend(rasterize_polygon(&grid, &wall));
+        let handle = app.world().resource::<ResourceGrid>().0.clone();
+        {
+            let mut assets = app
+                .world_mut()
+                .resource_mut::<Assets<ConfigNavigationGrid>>();
+            *assets.get_mut(&handle).unwrap() = grid;
+        }
+
+        let dasher = spawn_dasher(&mut app, Vec3::ZERO);
+        let target = app
+            .world_mut()
+            .spawn((Team::Chaos, Transform::from_xyz(400.0, 0.0, 0.0)))
+            .id();
+        trigger_dash(
+            &mut app,
+            dasher,
+            DashMoveType::Entity {
+                target,
+                stop_radius: 30.0,
+            },
+        );
+        for _ in 0..20 {
+            app.update();
+        }
+
+        let x = pos_of(&app, dasher).x;
+        assert!(x > 100.0 && x < 200.0, "追踪位移应停在墙前，实际 x = {x}");
+    }
 }
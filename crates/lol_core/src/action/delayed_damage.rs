@@ -13,9 +13,12 @@ use bevy::prelude::*;
 use lol_base::spell::Spell;
 
 use crate::action::damage::{ActionDamageEffect, DamageShape, apply_damage_effects};
-use crate::damage::{AbilityPower, Damage};
-use crate::entities::champion::Champion;
+use crate::buffs::spell_shield::SpellShields;
+use crate::damage::{AbilityPower, Damage, Untargetable};
+use crate::entities::champion::TargetableAsChampion;
 use crate::entities::minion::Minion;
+use crate::skill::SkillCalcQuery;
+use crate::spatial::SpatialCandidates;
 use crate::team::Team;
 
 /// AoE 命中报告：延迟伤害结算后发出，英雄可观察此事件执行后续效果。
@@ -235,14 +238,17 @@ pub fn update_delayed_damage(
         (
             Entity,
             &Team,
-            Option<&Champion>,
+            Option<&TargetableAsChampion>,
             Option<&Minion>,
             &Transform,
         ),
-        Without<DelayedDamageInstance>,
+        (Without<DelayedDamageInstance>, Without<Untargetable>),
     >,
     q_damage: Query<&Damage>,
     q_ap: Query<&AbilityPower>,
+    calc: SkillCalcQuery,
+    shields: SpellShields,
+    spatial: SpatialCandidates,
     time: Res<Time<Fixed>>,
 ) {
     for (entity, mut inst, mut transform, mut visual) in q_inst.iter_mut() {
@@ -285,9 +291,12 @@ pub fn update_delayed_damage(
                             &inst.effects,
                             skill_object,
                             inst.skill_level,
+                            &calc.caster(inst.caster),
                             &q_target,
                             &q_damage,
                             &q_ap,
+                            &shields,
+                            &spatial,
                         );
 
                         // 发出 AoE 命中报告
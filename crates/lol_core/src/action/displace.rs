@@ -20,7 +20,7 @@ use bevy::prelude::*;
 
 use crate::base::buff::BuffOf;
 use crate::buffs::cc_debuffs::{DebuffKnockup, DebuffSlow, DebuffStun};
-use crate::damage::{CommandDamageCreate, DamageType};
+use crate::damage::{CommandDamageCreate, DamageKind, DamageType};
 use crate::life::Death;
 use crate::movement::{CommandMovement, MovementAction, MovementSource, MovementWay};
 use crate::team::Team;
@@ -315,6 +315,7 @@ fn apply_effect(commands: &mut Commands, target: Entity, source: Entity, effect:
                 damage_type: *damage_type,
                 amount: *amount,
                 tag: *tag,
+                kind: DamageKind::Ability,
             });
         }
     }
@@ -2,13 +2,15 @@ use std::collections::HashMap;
 
 use bevy::prelude::*;
 
-use crate::damage::{DamageType, EventDamageCreate};
+use crate::damage::{DamageType, EventDamageCreate, Invulnerable, Untargetable};
 use crate::entities::champion::Champion;
+use crate::entities::jungle::JungleMonster;
 use crate::entities::minion::Minion;
 use crate::entities::pet::Pet;
 use crate::entities::turret::Turret;
 use crate::life::{Death, EventDead, Health};
 use crate::log::{CommandLog, EnumLogCategory};
+use crate::spatial::{SpatialCandidates, SpatialFilter, SpatialShape};
 use crate::team::Team;
 
 #[derive(Default)]
@@ -61,17 +63,34 @@ pub fn aggro_scan(
             Option<&Minion>,
             Option<&Pet>,
         ),
-        (Without<Death>, With<Health>),
+        (
+            Without<Death>,
+            With<Health>,
+            Without<Untargetable>,
+            Without<Invulnerable>,
+        ),
     >,
+    spatial: SpatialCandidates,
+    mut candidates: Local<Vec<Entity>>,
 ) {
     for (entity, team, transform, aggro, aggro_state, is_turret) in q_aggro.iter() {
         let mut best_score = -1.0;
         let mut closest_distance = f32::MAX;
         let mut target_entity = Entity::PLACEHOLDER;
 
-        // 遍历所有可攻击单位筛选目标
+        // 先粗筛范围内的候选
+        spatial.query_into(
+            &SpatialShape::Circle {
+                center: transform.translation.xz(),
+                radius: aggro.range,
+            },
+            SpatialFilter::enemies_of(*team),
+            q_attackable.iter().map(|(e, ..)| e),
+            &mut candidates,
+        );
+
         for (attackable_entity, attackable_team, attackable_transform, champion, minion, pet) in
-            q_attackable.iter()
+            q_attackable.iter_many(candidates.iter())
         {
             // 忽略友方单位
             if attackable_team == team || *attackable_team == Team::Neutral {
@@ -87,6 +106,18 @@ pub fn aggro_scan(
                 continue;
             }
 
+            // 获取累计仇恨值
+            let accumulated_aggro = aggro_state
+                .aggros
+                .get(&attackable_entity)
+                .copied()
+                .unwrap_or(0.0);
+
+            // 中立野怪是被动单位：只反击对自己或同营地伙伴造成过伤害的目标
+            if *team == Team::Neutral && accumulated_aggro <= 0.0 {
+                continue;
+            }
+
             // 获取基础优先级（如果是防御塔）
             let base_priority = if is_turret.is_some() {
                 if pet.is_some() {
@@ -107,13 +138,6 @@ pub fn aggro_scan(
                 0.0
             };
 
-            // 获取累计仇恨值
-            let accumulated_aggro = aggro_state
-                .aggros
-                .get(&attackable_entity)
-                .copied()
-                .unwrap_or(0.0);
-
             // 总分 = 基础优先级 + 累计仇恨
             let score = base_priority + accumulated_aggro;
 
@@ -154,20 +178,24 @@ pub fn on_team_get_damage(
     q_team: Query<&Team>,
     q_champion: Query<&Champion>,
     q_minion: Query<&Minion>,
+    q_jungle: Query<&JungleMonster>,
 ) {
     let source = trigger.source;
     let target = trigger.event_target();
+    let camp_of = |entity: Entity| q_jungle.get(entity).ok().map(|monster| monster.camp);
+    let target_camp = camp_of(target);
 
-    // 默认只响应物理伤害（普通攻击），LoL 防御塔仇恨对技能伤害也生效，但此处先保持一致
-    if trigger.damage_type != DamageType::Physical {
+    let Ok(target_team) = q_team.get(target) else {
         return;
-    }
+    };
 
-    let Ok(source_transform) = q_transform.get(source) else {
+    // 默认只响应物理伤害（普通攻击），LoL 防御塔仇恨对技能伤害也生效，但此处先保持一致；
+    // 中立野怪对任何伤害都会反击
+    if trigger.damage_type != DamageType::Physical && *target_team != Team::Neutral {
         return;
-    };
+    }
 
-    let Ok(target_team) = q_team.get(target) else {
+    let Ok(source_transform) = q_transform.get(source) else {
         return;
     };
 
@@ -180,6 +208,14 @@ pub fn on_team_get_damage(
             continue;
         }
 
+        // 中立单位只有同营地的野怪受袭才会反击，不牵连附近其他营地
+        if *team == Team::Neutral
+            && entity != target
+            && (target_camp.is_none() || camp_of(entity) != target_camp)
+        {
+            continue;
+        }
+
         // 英雄攻击小兵时，小兵不增加仇恨值
         if is_source_champion && is_target_minion && q_minion.contains(entity) {
             continue;
@@ -221,8 +257,9 @@ mod tests {
     use bevy::time::TimeUpdateStrategy;
 
     use super::*;
-    use crate::damage::{DamageResult, DamageType, EventDamageCreate};
+    use crate::damage::{DamageKind, DamageResult, DamageType, EventDamageCreate};
     use crate::life::EventDead;
+    use crate::spatial::PluginSpatial;
 
     // 用于测试中捕获系统选中的目标
     #[derive(Resource, Default)]
@@ -232,7 +269,7 @@ mod tests {
     fn setup_app() -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
-        app.add_plugins(PluginAggro);
+        app.add_plugins((PluginAggro, PluginSpatial));
         // 手动控制时间更新，使得 app.update() 能运行一次 FixedUpdate
         app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_micros(
             15625,
@@ -380,6 +417,7 @@ mod tests {
             damage_type: DamageType::Physical,
             damage_result: mock_damage_result(),
             tag: None,
+            kind: DamageKind::Other,
         });
 
         app.update();
@@ -391,6 +429,95 @@ mod tests {
         assert_eq!(aggro_val, 10.0, "队友受击应增加攻击者的仇恨值");
     }
 
+    #[test]
+    fn test_neutral_monster_only_retaliates() {
+        let mut app = setup_app();
+        let world = app.world_mut();
+
+        let monster = world
+            .spawn((Team::Neutral, Transform::default(), Aggro { range: 100.0 }))
+            .id();
+        let champion = world
+            .spawn((
+                Team::Order,
+                Transform::from_xyz(10.0, 0.0, 0.0),
+                Health::new(100.0),
+            ))
+            .id();
+
+        app.update();
+
+        // 断言：野怪不会主动攻击路过的单位
+        let target = app.world().resource::<LastTarget>().0;
+        assert_eq!(target, None, "中立野怪不应主动索敌");
+
+        // 魔法伤害同样会激怒野怪
+        app.world_mut().trigger(EventDamageCreate {
+            entity: monster,
+            source: champion,
+            damage_type: DamageType::Magic,
+            damage_result: mock_damage_result(),
+            tag: None,
+            kind: DamageKind::Other,
+        });
+
+        app.update();
+
+        let target = app.world().resource::<LastTarget>().0;
+        assert_eq!(target, Some(champion), "野怪受击后应反击攻击者");
+    }
+
+    #[test]
+    fn test_neutral_aggro_stays_within_camp() {
+        use crate::entities::jungle::JungleMonster;
+
+        let mut app = setup_app();
+        let world = app.world_mut();
+
+        let camp_a = world.spawn_empty().id();
+        let camp_b = world.spawn_empty().id();
+        let mut monster = |camp: Entity, x: f32| {
+            world
+                .spawn((
+                    Team::Neutral,
+                    Transform::from_xyz(x, 0.0, 0.0),
+                    Aggro { range: 800.0 },
+                    JungleMonster {
+                        camp,
+                        origin: Vec2::new(x, 0.0),
+                        camp_buff: None,
+                    },
+                ))
+                .id()
+        };
+        let hit = monster(camp_a, 0.0);
+        let sibling = monster(camp_a, 100.0);
+        let neighbour = monster(camp_b, 300.0);
+        let champion = world
+            .spawn((Team::Order, Transform::from_xyz(50.0, 0.0, 0.0)))
+            .id();
+
+        world.trigger(EventDamageCreate {
+            entity: hit,
+            source: champion,
+            damage_type: DamageType::Physical,
+            damage_result: mock_damage_result(),
+            tag: None,
+            kind: DamageKind::Other,
+        });
+        app.update();
+
+        let aggro = |entity: Entity| {
+            app.world()
+                .get::<AggroState>(entity)
+                .and_then(|state| state.aggros.get(&champion).copied())
+                .unwrap_or(0.0)
+        };
+        assert!(aggro(hit) > 0.0);
+        assert!(aggro(sibling) > 0.0, "同营地野怪应一起反击");
+        assert_eq!(aggro(neighbour), 0.0, "相邻营地不应被牵连");
+    }
+
     #[test]
     fn test_remove_aggro_on_dead() {
         let mut app = setup_app();
@@ -475,6 +602,7 @@ mod tests {
             damage_type: DamageType::Physical,
             damage_result: mock_damage_result(),
             tag: None,
+            kind: DamageKind::Other,
         });
 
         app.update();
@@ -4,8 +4,10 @@ use bevy::prelude::*;
 use lol_base::spell::Spell;
 use serde::{Deserialize, Serialize};
 
-use crate::base::buff::Buffs;
-use crate::damage::{CommandDamageCreate, Damage, DamageType};
+use crate::base::buff::Buff;
+use crate::base::stat_modifier::{StatKind, StatModifier, StatModifiers};
+use crate::buffs::cc_debuffs::Blinded;
+use crate::damage::{CommandDamageCreate, Damage, DamageKind, DamageType, Untargetable};
 use crate::life::{Death, EventDead};
 use crate::log::{CommandLog, EnumLogCategory};
 use crate::missile::CommandMissileCreate;
@@ -20,6 +22,9 @@ impl Plugin for PluginAttack {
         app.add_observer(on_command_attack_reset);
         app.add_observer(on_command_attack_stop);
         app.add_observer(on_event_dead);
+        app.add_observer(on_attack_block_add);
+        app.add_observer(on_insert_buff_attack);
+        app.add_observer(on_replace_buff_attack);
 
         app.add_systems(FixedUpdate, fixed_update);
     }
@@ -32,10 +37,8 @@ pub struct Attack {
     pub range: f32,
     /// 基础攻击速度 (1级时的每秒攻击次数)
     pub base_attack_speed: f32,
-    /// 持久的额外攻击速度加成 (来自装备/符文/直接修改)
+    /// 额外攻击速度加成（装备/符文/直接修改，以及属性修正层写入的成长与 buff 加成）
     pub bonus_attack_speed: f32,
-    /// 来自临时 buff 的攻击速度加成
-    pub buff_bonus_attack_speed: f32,
     /// 攻击速度上限 (默认 2.5)
     pub attack_speed_cap: f32,
     /// 前摇时间配置
@@ -59,6 +62,10 @@ pub enum WindupConfig {
     },
 }
 
+/// 攻击阻塞组件：眩晕 / 缴械等控制期间不能普攻，插入时打断前摇
+#[derive(Component, Default)]
+pub struct AttackBlock;
+
 /// 攻击状态机
 #[derive(Component, Clone, Serialize, Deserialize)]
 pub struct AttackState {
@@ -67,7 +74,9 @@ pub struct AttackState {
     pub target: Option<Entity>,
 }
 
+/// 攻速加成 buff：挂在 buff 实体上，经属性修正层加到持有者的额外攻速，buff 销毁即撤回
 #[derive(Component, Clone)]
+#[require(Buff = Buff { name: "AttackSpeed" })]
 pub struct BuffAttack {
     pub bonus_attack_speed: f32,
 }
@@ -110,6 +119,13 @@ pub struct EventAttackEnd {
     pub target: Entity,
 }
 
+/// 致盲时普攻落地：不造成伤害、不触发命中特效（取代 `EventAttackEnd`）
+#[derive(EntityEvent, Debug)]
+pub struct EventAttackMissed {
+    pub entity: Entity,
+    pub target: Entity,
+}
+
 #[derive(EntityEvent, Debug)]
 pub struct EventAttackReady {
     pub entity: Entity,
@@ -121,7 +137,6 @@ impl Attack {
             range,
             base_attack_speed: 1.0 / total_duration_secs,
             bonus_attack_speed: 0.0,
-            buff_bonus_attack_speed: 0.0,
             attack_speed_cap: 2.5,
             windup_config: WindupConfig::Modern {
                 attack_cast_time: windup_duration_secs,
@@ -137,7 +152,6 @@ impl Attack {
             range,
             base_attack_speed,
             bonus_attack_speed: 0.0,
-            buff_bonus_attack_speed: 0.0,
             attack_speed_cap: 2.5,
             windup_config: WindupConfig::Legacy {
                 attack_offset: windup_offset,
@@ -159,8 +173,7 @@ impl Attack {
 
     /// 计算当前总攻击速度
     pub fn current_attack_speed(&self) -> f32 {
-        (self.base_attack_speed * (1.0 + self.bonus_attack_speed + self.buff_bonus_attack_speed))
-            .min(self.attack_speed_cap)
+        (self.base_attack_speed * (1.0 + self.bonus_attack_speed)).min(self.attack_speed_cap)
     }
 
     /// 计算攻击间隔时间 (1 / attack_speed)
@@ -217,12 +230,51 @@ impl AttackState {
     }
 }
 
-fn update_attack_state(attack_state: &mut Attack, buffs: Vec<&BuffAttack>) {
-    attack_state.buff_bonus_attack_speed = buffs
-        .iter()
-        .map(|v| v.bonus_attack_speed)
-        .reduce(|a, b| a + b)
-        .unwrap_or(0.0);
+fn buff_attack_modifier(buff: &BuffAttack) -> StatModifier {
+    StatModifier::flat(StatKind::AttackSpeed, buff.bonus_attack_speed)
+}
+
+/// `BuffAttack` 写入或改值时把攻速修正并入 buff 实体已有的修正
+fn on_insert_buff_attack(
+    trigger: On<Insert, BuffAttack>,
+    mut commands: Commands,
+    q_buff: Query<&BuffAttack>,
+) {
+    let entity = trigger.entity;
+    let Ok(buff) = q_buff.get(entity) else {
+        return;
+    };
+    let modifier = buff_attack_modifier(buff);
+    commands.queue(move |world: &mut World| {
+        let Ok(mut entity) = world.get_entity_mut(entity) else {
+            return;
+        };
+        let mut modifiers = entity.get::<StatModifiers>().cloned().unwrap_or_default();
+        modifiers.0.push(modifier);
+        entity.insert(modifiers);
+    });
+}
+
+/// `BuffAttack` 被改值或移除前撤回它写入的那一条攻速修正，保留其它修正
+fn on_replace_buff_attack(
+    trigger: On<Replace, BuffAttack>,
+    mut commands: Commands,
+    q_buff: Query<&BuffAttack>,
+) {
+    let entity = trigger.entity;
+    let Ok(buff) = q_buff.get(entity) else {
+        return;
+    };
+    let modifier = buff_attack_modifier(buff);
+    commands.queue(move |world: &mut World| {
+        let Some(mut modifiers) = world.get::<StatModifiers>(entity).cloned() else {
+            return;
+        };
+        if let Some(index) = modifiers.0.iter().position(|m| *m == modifier) {
+            modifiers.0.remove(index);
+            world.entity_mut(entity).insert(modifiers);
+        }
+    });
 }
 
 // 观察者函数
@@ -230,38 +282,32 @@ fn on_command_attack_start(
     trigger: On<CommandAttackStart>,
     mut commands: Commands,
     mut q_attack_state: Query<&mut AttackState>,
-    mut q_attack: Query<&mut Attack>,
+    q_attack: Query<&Attack>,
     q_transform: Query<&Transform>,
-    q_buff_attack: Query<&BuffAttack>,
-    q_buffs: Query<&Buffs>,
     q_death: Query<&Death>,
+    q_attack_block: Query<(), With<AttackBlock>>,
+    q_untargetable: Query<(), With<Untargetable>>,
     time: Res<Time<Fixed>>,
 ) {
     let entity = trigger.event_target();
     let target = trigger.target;
 
-    // 忽略死亡目标（尸体不攻击）
-    if q_death.get(target).is_ok() {
+    // 忽略死亡与不可选中目标
+    if q_death.get(target).is_ok() || q_untargetable.get(target).is_ok() {
+        return;
+    }
+
+    if q_attack_block.get(entity).is_ok() {
         return;
     }
 
     let now = time.elapsed_secs();
 
-    let Ok(mut attack) = q_attack.get_mut(entity) else {
+    let Ok(attack) = q_attack.get(entity) else {
         return;
     };
 
     let Ok(mut attack_state) = q_attack_state.get_mut(entity) else {
-        if let Ok(buffs) = q_buffs.get(entity) {
-            let buffs = buffs
-                .iter()
-                .filter_map(|v| q_buff_attack.get(v).ok())
-                .collect::<Vec<_>>();
-            update_attack_state(&mut attack, buffs);
-        } else {
-            update_attack_state(&mut attack, vec![]);
-        }
-
         if let (Ok(target_transform), Ok(transform)) =
             (q_transform.get(target), q_transform.get(entity))
         {
@@ -395,15 +441,44 @@ fn on_event_dead(
     }
 }
 
+/// 被控制阻塞普攻时打断前摇；冷却中的攻击保留，冷却结束后的续攻会被 `AttackBlock` 拦下。
+fn on_attack_block_add(
+    trigger: On<Add, AttackBlock>,
+    mut commands: Commands,
+    q_attack_state: Query<&AttackState>,
+) {
+    let entity = trigger.entity;
+    let Ok(attack_state) = q_attack_state.get(entity) else {
+        return;
+    };
+    if let AttackStatus::Windup { .. } = attack_state.status {
+        commands.trigger(CommandLog {
+            entity,
+            info: "移除攻击状态：被控制打断".to_string(),
+            category: EnumLogCategory::Attack,
+        });
+        commands.entity(entity).try_remove::<AttackState>();
+    }
+}
+
 fn fixed_update(
-    mut query: Query<(Entity, &mut AttackState, &Attack, Option<&Damage>), Without<Death>>,
+    mut query: Query<
+        (
+            Entity,
+            &mut AttackState,
+            &Attack,
+            Option<&Damage>,
+            Has<Blinded>,
+        ),
+        Without<Death>,
+    >,
     mut commands: Commands,
     res_assets_spell_object: Option<Res<Assets<Spell>>>,
     time: Res<Time<Fixed>>,
 ) {
     let now = time.elapsed_secs();
 
-    for (entity, mut attack_state, attack, damage) in query.iter_mut() {
+    for (entity, mut attack_state, attack, damage, blinded) in query.iter_mut() {
         match &attack_state.status.clone() {
             AttackStatus::Windup { target, end_time } => {
                 // 检查前摇是否完成
@@ -412,6 +487,14 @@ fn fixed_update(
                         end_time: now + attack.cooldown_time(),
                     };
 
+                    if blinded {
+                        commands.try_trigger(EventAttackMissed {
+                            entity,
+                            target: *target,
+                        });
+                        continue;
+                    }
+
                     let spell_handle = &attack.spell;
                     if let Some(spell) = res_assets_spell_object
                         .as_ref()
@@ -438,6 +521,7 @@ fn fixed_update(
                                 damage_type: DamageType::Physical,
                                 amount: damage.0,
                                 tag: None,
+                                kind: DamageKind::BasicAttack,
                             });
                         }
                     } else if let Some(damage) = damage {
@@ -447,6 +531,7 @@ fn fixed_update(
                             damage_type: DamageType::Physical,
                             amount: damage.0,
                             tag: None,
+                            kind: DamageKind::BasicAttack,
                         });
                     }
                     commands.try_trigger(EventAttackEnd {
@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 
 use crate::attack::{Attack, AttackState, AttackStatus, CommandAttackStart, CommandAttackStop};
+use crate::avoidance::update_surround_slots;
 use crate::base::bounding::Bounding;
+use crate::damage::Untargetable;
 use crate::life::{Death, EventDead};
 use crate::log::{CommandLog, EnumLogCategory};
 use crate::run::{CommandRunStart, CommandRunStop, RunTarget};
@@ -18,7 +20,10 @@ impl Plugin for PluginAttackAuto {
         app.add_observer(on_command_attack_auto_stop);
         app.add_observer(on_event_dead);
 
-        app.add_systems(FixedPreUpdate, update_attack_auto);
+        app.add_systems(
+            FixedPreUpdate,
+            (update_attack_auto, update_surround_slots).chain(),
+        );
     }
 }
 
@@ -118,7 +123,7 @@ fn update_attack_auto(
         ),
         (Without<Death>, Changed<Transform>),
     >,
-    q_target: Query<(&Transform, &Bounding), Without<Death>>,
+    q_target: Query<(&Transform, &Bounding), (Without<Death>, Without<Untargetable>)>,
 ) {
     for (entity, attack_auto, attack, attack_state, transform, bounding) in q_attacker.iter() {
         if let Some(AttackState {
@@ -132,7 +137,7 @@ fn update_attack_auto(
         let target = attack_auto.target;
 
         let Ok((target_transform, target_bounding)) = q_target.get(target) else {
-            // 目标不存在或已死亡，停止追逐
+            // 目标不存在、已死亡或不可选中，停止追逐
             commands.entity(entity).remove::<AttackAuto>();
             commands.trigger(CommandRunStop { entity });
             commands.trigger(CommandAttackStop { entity });
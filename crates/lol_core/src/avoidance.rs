@@ -0,0 +1,718 @@
+//! 局部避让（ORCA）：移动中的单位按周围单位的 `Bounding` 求解无碰撞速度。
+//!
+//! - 每个沿路径移动的单位以「朝当前路点、全速」为期望速度，对每个邻居构造一条 ORCA 半平面，
+//!   用线性规划在速度圆内求最接近期望速度的可行解（移植自 RVO2）。
+//! - 静止单位、建筑按速度 0 处理并由移动方承担全部避让；双方都在移动时各承担一半。
+//! - 幽灵状态（`BuffGhosted`）与 `IgnoreUnitCollision` 单位既不避让他人也不被避让。
+//! - 邻居按 `Entity` 排序后求解，只读上一帧速度，结果与遍历顺序无关，回放可复现。
+//!
+//! 解出的速度写入 `MovementState.avoidance_velocity`，由 `update_path_movement` 本帧消费。
+//!
+//! 包围站位：同一攻击目标的追击者在目标周围分配互不重叠的槽位（`SurroundSlot`），
+//! 追逐时寻路到槽位而非目标中心，近战小兵因此会绕到目标两侧而不是排成一列。
+
+use bevy::prelude::*;
+
+use crate::attack::Attack;
+use crate::attack_auto::AttackAuto;
+use crate::base::bounding::Bounding;
+use crate::base::buff::Buffs;
+use crate::buffs::common_buffs::BuffGhosted;
+use crate::life::Death;
+use crate::movement::{Movement, MovementBlock, MovementSource, MovementState};
+use crate::spatial::{SpatialCandidates, SpatialFilter, SpatialShape};
+
+/// 预判时间窗（秒）：只避让该时间内会发生的碰撞
+pub const AVOIDANCE_TIME_HORIZON: f32 = 1.0;
+/// 邻居搜索半径（不含双方半径）
+pub const AVOIDANCE_NEIGHBOR_DIST: f32 = 400.0;
+/// 最多参与求解的邻居数
+pub const AVOIDANCE_MAX_NEIGHBORS: usize = 10;
+
+const RVO_EPSILON: f32 = 1e-5;
+
+/// 包围槽位向攻击范围内收缩的距离，保证到达槽位时已进入攻击范围
+pub const SURROUND_SLOT_INSET: f32 = 10.0;
+
+/// 忽略单位碰撞：不参与避让（如部分召唤物、技能期间穿越单位的效果）
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct IgnoreUnitCollision;
+
+/// 包围槽位：追击 `target` 时寻路到 `position`
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SurroundSlot {
+    pub target: Entity,
+    pub position: Vec2,
+}
+
+/// 参与槽位分配的追击者快照
+#[derive(Debug, Clone, Copy)]
+pub struct SurroundRequest {
+    pub position: Vec2,
+    pub radius: f32,
+    /// 槽位到目标中心的距离
+    pub ring_radius: f32,
+}
+
+/// 为同一目标的追击者分配包围槽位，返回与 `requests` 一一对应的站位。
+///
+/// 按传入顺序贪心：每个追击者从自身方位角出发左右交替试探，取第一个不与已分配槽位重叠的角度；
+/// 一圈都放不下时退回自身方位角。调用方应按（距离, Entity）排序，保证结果确定。
+pub fn assign_surround_slots(target_position: Vec2, requests: &[SurroundRequest]) -> Vec<Vec2> {
+    let mut slots: Vec<(Vec2, f32)> = Vec::with_capacity(requests.len());
+    for request in requests {
+        let ring_radius = request.ring_radius.max(RVO_EPSILON);
+        let bearing = (request.position - target_position)
+            .try_normalize()
+            .unwrap_or(Vec2::X);
+        let base_angle = bearing.to_angle();
+        let step = (request.radius / ring_radius).clamp(0.05, std::f32::consts::PI);
+        let slot_at = |angle: f32| target_position + Vec2::from_angle(angle) * ring_radius;
+        let is_free = |point: Vec2| {
+            slots.iter().all(|(other, other_radius)| {
+                point.distance(*other) >= request.radius + other_radius
+            })
+        };
+
+        let mut chosen = slot_at(base_angle);
+        if !is_free(chosen) {
+            let max_k = (std::f32::consts::PI / step).ceil() as i32;
+            for k in 1..=max_k {
+                let offset = k as f32 * step;
+                if let Some(point) = [slot_at(base_angle + offset), slot_at(base_angle - offset)]
+                    .into_iter()
+                    .find(|point| is_free(*point))
+                {
+                    chosen = point;
+                    break;
+                }
+            }
+        }
+        slots.push((chosen, request.radius));
+    }
+    slots.into_iter().map(|(point, _)| point).collect()
+}
+
+/// FixedPreUpdate（自动攻击判定之后）：按攻击目标分组，为每个自动攻击单位分配包围槽位。
+pub fn update_surround_slots(
+    mut commands: Commands,
+    q_attacker: Query<(Entity, &AttackAuto, &Attack, &Transform, &Bounding), Without<Death>>,
+    q_target: Query<(&Transform, &Bounding), Without<Death>>,
+    q_slotted: Query<Entity, (With<SurroundSlot>, Without<AttackAuto>)>,
+    mut chasers: Local<Vec<(Entity, f32, Entity, SurroundRequest)>>,
+) {
+    for entity in q_slotted.iter() {
+        commands.entity(entity).remove::<SurroundSlot>();
+    }
+
+    chasers.clear();
+    for (entity, attack_auto, attack, transform, bounding) in q_attacker.iter() {
+        let Ok((target_transform, target_bounding)) = q_target.get(attack_auto.target) else {
+            continue;
+        };
+        let position = transform.translation.xz();
+        let ring_radius = (attack.range + bounding.radius + target_bounding.radius
+            - SURROUND_SLOT_INSET)
+            .max(bounding.radius + target_bounding.radius);
+        chasers.push((
+            attack_auto.target,
+            position.distance(target_transform.translation.xz()),
+            entity,
+            SurroundRequest {
+                position,
+                radius: bounding.radius,
+                ring_radius,
+            },
+        ));
+    }
+    // 按目标分组，组内近者优先，Entity 兜底保证确定性
+    chasers.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)).then(a.2.cmp(&b.2)));
+
+    for group in chasers.chunk_by(|a, b| a.0 == b.0) {
+        let target = group[0].0;
+        let Ok((target_transform, _)) = q_target.get(target) else {
+            continue;
+        };
+        let requests = group
+            .iter()
+            .map(|(.., request)| *request)
+            .collect::<Vec<_>>();
+        let slots = assign_surround_slots(target_transform.translation.xz(), &requests);
+        for ((_, _, entity, _), position) in group.iter().zip(slots) {
+            commands
+                .entity(*entity)
+                .insert(SurroundSlot { target, position });
+        }
+    }
+}
+
+/// ORCA 半平面：`point` 所在、沿 `direction` 的有向直线，左侧为可行域
+#[derive(Debug, Clone, Copy)]
+pub struct OrcaLine {
+    pub point: Vec2,
+    pub direction: Vec2,
+}
+
+/// 参与求解的邻居快照
+#[derive(Debug, Clone, Copy)]
+pub struct AvoidanceNeighbor {
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub radius: f32,
+    /// 本单位承担的避让比例：对方也在避让时 0.5，否则 1.0
+    pub responsibility: f32,
+}
+
+/// 对一个邻居构造 ORCA 半平面
+pub fn orca_line(
+    position: Vec2,
+    velocity: Vec2,
+    radius: f32,
+    neighbor: &AvoidanceNeighbor,
+    time_horizon: f32,
+    dt: f32,
+) -> OrcaLine {
+    let relative_position = neighbor.position - position;
+    let relative_velocity = velocity - neighbor.velocity;
+    let dist_sq = relative_position.length_squared();
+    let combined_radius = radius + neighbor.radius;
+    let combined_radius_sq = combined_radius * combined_radius;
+    let inv_time_horizon = 1.0 / time_horizon;
+
+    let (direction, u) = if dist_sq > combined_radius_sq {
+        // 尚未碰撞：速度障碍为截断圆锥
+        let w = relative_velocity - inv_time_horizon * relative_position;
+        let w_length_sq = w.length_squared();
+        let dot = w.dot(relative_position);
+
+        if dot < 0.0 && dot * dot > combined_radius_sq * w_length_sq {
+            // 投影到截断圆
+            let w_length = w_length_sq.sqrt();
+            let unit_w = w / w_length;
+            (
+                Vec2::new(unit_w.y, -unit_w.x),
+                (combined_radius * inv_time_horizon - w_length) * unit_w,
+            )
+        } else {
+            // 投影到圆锥两腿
+            let leg = (dist_sq - combined_radius_sq).sqrt();
+            let direction = if relative_position.perp_dot(w) > 0.0 {
+                Vec2::new(
+                    relative_position.x * leg - relative_position.y * combined_radius,
+                    relative_position.x * combined_radius + relative_position.y * leg,
+                ) / dist_sq
+            } else {
+                -Vec2::new(
+                    relative_position.x * leg + relative_position.y * combined_radius,
+                    -relative_position.x * combined_radius + relative_position.y * leg,
+                ) / dist_sq
+            };
+            (
+                direction,
+                relative_velocity.dot(direction) * direction - relative_velocity,
+            )
+        }
+    } else {
+        // 已经重叠：本帧内推开
+        let inv_dt = 1.0 / dt;
+        let w = relative_velocity - inv_dt * relative_position;
+        // 完全重合且相对静止时取固定方向，保证确定性
+        let unit_w = w.try_normalize().unwrap_or(Vec2::X);
+        let w_length = w.length();
+        (
+            Vec2::new(unit_w.y, -unit_w.x),
+            (combined_radius * inv_dt - w_length) * unit_w,
+        )
+    };
+
+    OrcaLine {
+        point: velocity + neighbor.responsibility * u,
+        direction,
+    }
+}
+
+fn linear_program1(
+    lines: &[OrcaLine],
+    line_no: usize,
+    radius: f32,
+    opt_velocity: Vec2,
+    direction_opt: bool,
+    result: &mut Vec2,
+) -> bool {
+    let line = lines[line_no];
+    let dot = line.point.dot(line.direction);
+    let discriminant = dot * dot + radius * radius - line.point.length_squared();
+    if discriminant < 0.0 {
+        // 速度圆与该直线不相交
+        return false;
+    }
+
+    let sqrt_discriminant = discriminant.sqrt();
+    let mut t_left = -dot - sqrt_discriminant;
+    let mut t_right = -dot + sqrt_discriminant;
+
+    for other in &lines[..line_no] {
+        let denominator = line.direction.perp_dot(other.direction);
+        let numerator = other.direction.perp_dot(line.point - other.point);
+
+        if denominator.abs() <= RVO_EPSILON {
+            // 平行
+            if numerator < 0.0 {
+                return false;
+            }
+            continue;
+        }
+
+        let t = numerator / denominator;
+        if denominator >= 0.0 {
+            t_right = t_right.min(t);
+        } else {
+            t_left = t_left.max(t);
+        }
+        if t_left > t_right {
+            return false;
+        }
+    }
+
+    let t = if direction_opt {
+        if opt_velocity.dot(line.direction) > 0.0 {
+            t_right
+        } else {
+            t_left
+        }
+    } else {
+        line.direction
+            .dot(opt_velocity - line.point)
+            .clamp(t_left, t_right)
+    };
+    *result = line.point + t * line.direction;
+    true
+}
+
+fn linear_program2(
+    lines: &[OrcaLine],
+    radius: f32,
+    opt_velocity: Vec2,
+    direction_opt: bool,
+    result: &mut Vec2,
+) -> usize {
+    *result = if direction_opt {
+        opt_velocity * radius
+    } else if opt_velocity.length_squared() > radius * radius {
+        opt_velocity.normalize() * radius
+    } else {
+        opt_velocity
+    };
+
+    for i in 0..lines.len() {
+        if lines[i].direction.perp_dot(lines[i].point - *result) > 0.0 {
+            let previous = *result;
+            if !linear_program1(lines, i, radius, opt_velocity, direction_opt, result) {
+                *result = previous;
+                return i;
+            }
+        }
+    }
+    lines.len()
+}
+
+fn linear_program3(lines: &[OrcaLine], begin_line: usize, radius: f32, result: &mut Vec2) {
+    let mut distance = 0.0;
+
+    for i in begin_line..lines.len() {
+        if lines[i].direction.perp_dot(lines[i].point - *result) <= distance {
+            continue;
+        }
+
+        let mut projected = Vec::with_capacity(i);
+        for j in 0..i {
+            let determinant = lines[i].direction.perp_dot(lines[j].direction);
+            let point = if determinant.abs() <= RVO_EPSILON {
+                if lines[i].direction.dot(lines[j].direction) > 0.0 {
+                    // 同向平行
+                    continue;
+                }
+                0.5 * (lines[i].point + lines[j].point)
+            } else {
+                lines[i].point
+                    + (lines[j].direction.perp_dot(lines[i].point - lines[j].point) / determinant)
+                        * lines[i].direction
+            };
+            projected.push(OrcaLine {
+                point,
+                direction: (lines[j].direction - lines[i].direction).normalize_or_zero(),
+            });
+        }
+
+        let previous = *result;
+        let opt = Vec2::new(-lines[i].direction.y, lines[i].direction.x);
+        if linear_program2(&projected, radius, opt, true, result) < projected.len() {
+            // 数值误差导致失败时保留上一个解
+            *result = previous;
+        }
+        distance = lines[i].direction.perp_dot(lines[i].point - *result);
+    }
+}
+
+/// 求解 ORCA：在 `max_speed` 速度圆内找满足全部半平面、最接近 `preferred` 的速度。
+/// 无可行解时退而求违反程度最小的速度。
+pub fn solve_orca(lines: &[OrcaLine], max_speed: f32, preferred: Vec2) -> Vec2 {
+    let mut result = Vec2::ZERO;
+    let line_fail = linear_program2(lines, max_speed, preferred, false, &mut result);
+    if line_fail < lines.len() {
+        linear_program3(lines, line_fail, max_speed, &mut result);
+    }
+    result
+}
+
+fn is_ghosted(buffs: Option<&Buffs>, q_ghosted: &Query<(), With<BuffGhosted>>) -> bool {
+    buffs.is_some_and(|buffs| buffs.iter().any(|buff| q_ghosted.contains(buff)))
+}
+
+/// 只有沿路径自主行走的单位做避让；位移、击退、飞弹按原轨迹运动。
+fn is_steerable(state: &MovementState) -> bool {
+    !state.completed
+        && !state.path.is_empty()
+        && !matches!(
+            state.source,
+            MovementSource::Dash | MovementSource::Knockback | MovementSource::Missile
+        )
+}
+
+/// FixedPostUpdate（移动裁决之后、路径推进之前）：为每个行走中的单位求解避让速度。
+pub fn compute_avoidance_velocities(
+    mut q_unit: Query<
+        (
+            Entity,
+            &Transform,
+            &Bounding,
+            Option<&Movement>,
+            Option<&mut MovementState>,
+            Option<&Buffs>,
+            Has<MovementBlock>,
+        ),
+        (Without<Death>, Without<IgnoreUnitCollision>),
+    >,
+    q_ghosted: Query<(), With<BuffGhosted>>,
+    spatial: SpatialCandidates,
+    time: Res<Time<Fixed>>,
+    mut candidates: Local<Vec<Entity>>,
+    mut results: Local<Vec<(Entity, Option<Vec2>)>>,
+) {
+    let dt = time.delta_secs();
+    if dt <= 0.0 {
+        return;
+    }
+
+    results.clear();
+    for (entity, transform, bounding, movement, state, buffs, blocked) in q_unit.iter() {
+        let (Some(movement), Some(state)) = (movement, state) else {
+            continue;
+        };
+        if blocked || !is_steerable(state) || is_ghosted(buffs, &q_ghosted) {
+            results.push((entity, None));
+            continue;
+        }
+        let Some(waypoint) = state.path.get(state.current_target_index) else {
+            results.push((entity, None));
+            continue;
+        };
+
+        let position = transform.translation.xz();
+        let speed = state.speed.unwrap_or(movement.speed);
+        let to_waypoint = waypoint.xz() - position;
+        let preferred = to_waypoint.normalize_or_zero() * speed.min(to_waypoint.length() / dt);
+
+        // 收集邻居并按 Entity 排序，保证求解结果确定
+        let search_radius = AVOIDANCE_NEIGHBOR_DIST + bounding.radius;
+        spatial.query_into(
+            &SpatialShape::Circle {
+                center: position,
+                radius: search_radius,
+            },
+            SpatialFilter::default(),
+            q_unit.iter().map(|(other, ..)| other),
+            &mut candidates,
+        );
+        candidates.sort();
+
+        let mut neighbors = q_unit
+            .iter_many(candidates.iter())
+            .filter(|(other, ..)| *other != entity)
+            .filter(|(_, _, _, _, _, other_buffs, _)| !is_ghosted(*other_buffs, &q_ghosted))
+            .filter_map(
+                |(other, other_transform, other_bounding, _, other_state, ..)| {
+                    let other_position = other_transform.translation.xz();
+                    let distance = other_position.distance(position) - other_bounding.radius;
+                    if distance > search_radius {
+                        return None;
+                    }
+                    let moving = other_state.is_some_and(is_steerable);
+                    Some((
+                        distance,
+                        other,
+                        AvoidanceNeighbor {
+                            position: other_position,
+                            velocity: other_state.map_or(Vec2::ZERO, |s| s.velocity),
+                            radius: other_bounding.radius,
+                            responsibility: if moving { 0.5 } else { 1.0 },
+                        },
+                    ))
+                },
+            )
+            .collect::<Vec<_>>();
+        if neighbors.is_empty() {
+            results.push((entity, None));
+            continue;
+        }
+        neighbors.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        neighbors.truncate(AVOIDANCE_MAX_NEIGHBORS);
+
+        let lines = neighbors
+            .iter()
+            .map(|(_, _, neighbor)| {
+                orca_line(
+                    position,
+                    state.velocity,
+                    bounding.radius,
+                    neighbor,
+                    AVOIDANCE_TIME_HORIZON,
+                    dt,
+                )
+            })
+            .collect::<Vec<_>>();
+        let velocity = solve_orca(&lines, speed, preferred);
+        let steered = (velocity - preferred).length_squared() > RVO_EPSILON;
+        results.push((entity, steered.then_some(velocity)));
+    }
+
+    for (entity, velocity) in results.iter() {
+        if let Ok((_, _, _, _, Some(mut state), ..)) = q_unit.get_mut(*entity) {
+            state.avoidance_velocity = *velocity;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::time::TimeUpdateStrategy;
+    use lol_base::grid::{ConfigNavigationGrid, ConfigNavigationGridCell, GridFlagsVisionPathing};
+
+    use super::*;
+    use crate::base::buff::BuffOf;
+    use crate::movement::{CommandMovement, MovementAction, MovementWay, PluginMovement};
+    use crate::navigation::grid::ResourceGrid;
+    use crate::navigation::navigation::NavigationStats;
+
+    #[test]
+    fn no_neighbors_keeps_preferred_velocity() {
+        let preferred = Vec2::new(300.0, 0.0);
+        assert_eq!(solve_orca(&[], 340.0, preferred), preferred);
+        // 超速期望被限制到速度圆
+        let clamped = solve_orca(&[], 100.0, preferred);
+        assert!((clamped.length() - 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn head_on_agents_sidestep() {
+        let neighbor = AvoidanceNeighbor {
+            position: Vec2::new(200.0, 0.0),
+            velocity: Vec2::new(-300.0, 0.0),
+            radius: 50.0,
+            responsibility: 0.5,
+        };
+        let line = orca_line(
+            Vec2::ZERO,
+            Vec2::new(300.0, 0.0),
+            50.0,
+            &neighbor,
+            1.0,
+            1.0 / 30.0,
+        );
+        let velocity = solve_orca(&[line], 300.0, Vec2::new(300.0, 0.0));
+        assert!(velocity.y.abs() > 1.0, "迎面相遇应产生侧向速度：{velocity}");
+    }
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(PluginMovement);
+        app.insert_resource(Time::<Fixed>::from_hz(30.0));
+        let cell = ConfigNavigationGridCell {
+            heuristic: 1.0,
+            vision_pathing_flags: GridFlagsVisionPathing::Walkable,
+            ..default()
+        };
+        let mut assets_grid = Assets::<ConfigNavigationGrid>::default();
+        let handle = assets_grid.add(ConfigNavigationGrid {
+            min_position: Vec2::ZERO,
+            cell_size: 50.0,
+            x_len: 60,
+            y_len: 60,
+            cells: vec![vec![cell; 60]; 60],
+            ..default()
+        });
+        app.insert_resource(assets_grid);
+        app.insert_resource(ResourceGrid(handle));
+        app.insert_resource(NavigationStats::default());
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+        app
+    }
+
+    fn spawn_walker(app: &mut App, from: Vec2, to: Vec2) -> Entity {
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(from.x, 0.0, from.y),
+                Movement { speed: 300.0 },
+                Bounding {
+                    radius: 50.0,
+                    height: 100.0,
+                },
+            ))
+            .id();
+        app.world_mut().trigger(CommandMovement {
+            entity,
+            priority: 0,
+            action: MovementAction::Start {
+                way: MovementWay::Path(vec![Vec3::new(to.x, 0.0, to.y)]),
+                speed: None,
+                source: MovementSource::Run,
+            },
+        });
+        entity
+    }
+
+    fn run(app: &mut App, a: Entity, b: Entity, ticks: usize) -> f32 {
+        let mut min_distance = f32::MAX;
+        for _ in 0..ticks {
+            app.update();
+            let pa = app.world().get::<Transform>(a).unwrap().translation;
+            let pb = app.world().get::<Transform>(b).unwrap().translation;
+            min_distance = min_distance.min(pa.distance(pb));
+        }
+        min_distance
+    }
+
+    #[test]
+    fn walkers_pass_without_overlapping_and_arrive() {
+        let mut app = setup_app();
+        let a = spawn_walker(&mut app, Vec2::new(0.0, 1000.0), Vec2::new(1000.0, 1000.0));
+        let b = spawn_walker(&mut app, Vec2::new(1000.0, 1005.0), Vec2::new(0.0, 1005.0));
+
+        let min_distance = run(&mut app, a, b, 150);
+        assert!(
+            min_distance > 90.0,
+            "双方不应明显重叠，最近距离 {min_distance}"
+        );
+
+        let pa = app.world().get::<Transform>(a).unwrap().translation;
+        let pb = app.world().get::<Transform>(b).unwrap().translation;
+        assert!(
+            pa.distance(Vec3::new(1000.0, 0.0, 1000.0)) < 5.0,
+            "a 应到达终点：{pa}"
+        );
+        assert!(
+            pb.distance(Vec3::new(0.0, 0.0, 1005.0)) < 5.0,
+            "b 应到达终点：{pb}"
+        );
+    }
+
+    #[test]
+    fn surround_slots_spread_attackers_around_target() {
+        // 四个追击者从同一方向逼近，槽位应互不重叠且都在攻击范围内
+        let target = Vec2::new(1000.0, 1000.0);
+        let requests = (0..4)
+            .map(|i| SurroundRequest {
+                position: Vec2::new(600.0 - i as f32 * 60.0, 1000.0),
+                radius: 48.0,
+                ring_radius: 150.0,
+            })
+            .collect::<Vec<_>>();
+        let slots = assign_surround_slots(target, &requests);
+
+        assert!((slots[0] - Vec2::new(850.0, 1000.0)).length() < 1e-3);
+        for (i, a) in slots.iter().enumerate() {
+            assert!((a.distance(target) - 150.0).abs() < 1e-3);
+            for b in &slots[i + 1..] {
+                assert!(a.distance(*b) >= 96.0 - 1e-3, "槽位重叠：{a} {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn auto_attackers_receive_distinct_slots() {
+        let mut app = App::new();
+        app.add_systems(Update, update_surround_slots);
+        let target = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(1000.0, 0.0, 1000.0),
+                Bounding {
+                    radius: 50.0,
+                    height: 100.0,
+                },
+            ))
+            .id();
+        let attackers = (0..3)
+            .map(|i| {
+                app.world_mut()
+                    .spawn((
+                        Transform::from_xyz(500.0, 0.0, 1000.0 + i as f32),
+                        Bounding {
+                            radius: 48.0,
+                            height: 100.0,
+                        },
+                        Attack::new(110.0, 0.3, 1.0),
+                        AttackAuto { target },
+                    ))
+                    .id()
+            })
+            .collect::<Vec<_>>();
+        app.update();
+
+        let slots = attackers
+            .iter()
+            .map(|e| *app.world().get::<SurroundSlot>(*e).unwrap())
+            .collect::<Vec<_>>();
+        for (i, a) in slots.iter().enumerate() {
+            assert_eq!(a.target, target);
+            for b in &slots[i + 1..] {
+                assert!(a.position.distance(b.position) >= 96.0 - 1e-3);
+            }
+        }
+
+        // 停止自动攻击后槽位被回收
+        app.world_mut()
+            .entity_mut(attackers[0])
+            .remove::<AttackAuto>();
+        app.update();
+        assert!(app.world().get::<SurroundSlot>(attackers[0]).is_none());
+    }
+
+    #[test]
+    fn ghosted_walker_passes_straight_through() {
+        let mut app = setup_app();
+        let a = spawn_walker(&mut app, Vec2::new(0.0, 1000.0), Vec2::new(1000.0, 1000.0));
+        let b = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(500.0, 0.0, 1000.0),
+                Bounding {
+                    radius: 50.0,
+                    height: 100.0,
+                },
+            ))
+            .id();
+        app.world_mut()
+            .entity_mut(a)
+            .with_related::<BuffOf>(BuffGhosted::new(10.0));
+
+        let min_distance = run(&mut app, a, b, 60);
+        assert!(
+            min_distance < 10.0,
+            "幽灵状态应直接穿过，最近距离 {min_distance}"
+        );
+    }
+}
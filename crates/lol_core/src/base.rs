@@ -1,11 +1,13 @@
 pub mod ability_resource;
 pub mod bounding;
+pub mod bounty;
 pub mod buff;
 pub mod direction;
 pub mod gold;
 pub mod level;
 pub mod pipeline;
 pub mod position;
+pub mod stat_modifier;
 pub mod state;
 pub mod stats;
 use bevy::app::{App, Plugin};
@@ -15,7 +17,10 @@ pub struct PluginBase;
 
 impl Plugin for PluginBase {
     fn build(&self, app: &mut App) {
+        app.add_plugins(buff::PluginBuff);
+        app.add_plugins(bounty::PluginBounty);
         app.add_plugins(gold::PluginGold);
         app.add_plugins(stats::PluginChampionStats);
+        app.add_plugins(stat_modifier::PluginStatModifier);
     }
 }
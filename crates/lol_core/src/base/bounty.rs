@@ -0,0 +1,395 @@
+use bevy::prelude::*;
+
+use crate::base::gold::{EventGoldGain, Gold};
+use crate::base::stats::ChampionStats;
+use crate::damage::EventDamageCreate;
+use crate::entities::champion::Champion;
+use crate::entities::pet::{PetOf, resolve_owner};
+use crate::life::EventDead;
+use crate::team::Team;
+
+/// 助攻时间窗（秒）：死亡前这段时间内造成过伤害的敌方英雄计助攻
+pub const ASSIST_WINDOW_SECS: f32 = 10.0;
+/// 英雄基础击杀赏金
+pub const CHAMPION_BASE_BOUNTY: f32 = 300.0;
+/// 连杀中每多一次击杀追加的终结赏金
+pub const SHUTDOWN_BOUNTY_PER_KILL: f32 = 100.0;
+/// 终结赏金追加上限
+pub const SHUTDOWN_BOUNTY_CAP: f32 = 700.0;
+/// 连死时每多死一次赏金的衰减系数
+pub const DEATH_STREAK_BOUNTY_FACTOR: f32 = 0.8;
+/// 英雄赏金下限
+pub const CHAMPION_MIN_BOUNTY: f32 = 100.0;
+/// 助攻者平分的赏金比例
+pub const ASSIST_BOUNTY_RATIO: f32 = 0.5;
+
+/// 对立阵营；中立阵营返回自身
+pub fn opposing_team(team: Team) -> Team {
+    match team {
+        Team::Order => Team::Chaos,
+        Team::Chaos => Team::Order,
+        Team::Neutral => Team::Neutral,
+    }
+}
+
+#[derive(Default)]
+pub struct PluginBounty;
+
+impl Plugin for PluginBounty {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_damage_record_contribution);
+        app.add_observer(on_champion_dead);
+    }
+}
+
+/// 单个敌方英雄对本英雄的伤害贡献
+#[derive(Debug, Clone, Copy)]
+pub struct DamageContribution {
+    pub source: Entity,
+    pub amount: f32,
+    /// 最近一次造成伤害的对局时间（秒）
+    pub time: f32,
+}
+
+/// 伤害贡献账本：记录时间窗内敌方英雄造成的伤害，死亡时据此归属助攻。
+#[derive(Component, Debug, Clone, Default)]
+pub struct DamageLedger {
+    pub entries: Vec<DamageContribution>,
+}
+
+impl DamageLedger {
+    pub fn record(&mut self, source: Entity, amount: f32, now: f32) {
+        self.entries
+            .retain(|entry| now - entry.time <= ASSIST_WINDOW_SECS);
+        match self.entries.iter_mut().find(|entry| entry.source == source) {
+            Some(entry) => {
+                entry.amount += amount;
+                entry.time = now;
+            }
+            None => self.entries.push(DamageContribution {
+                source,
+                amount,
+                time: now,
+            }),
+        }
+    }
+
+    /// 时间窗内的贡献者，按最近造成伤害的先后倒序
+    pub fn contributors(&self, now: f32) -> Vec<Entity> {
+        let mut entries = self
+            .entries
+            .iter()
+            .filter(|entry| now - entry.time <= ASSIST_WINDOW_SECS)
+            .collect::<Vec<_>>();
+        entries.sort_by(|a, b| b.time.total_cmp(&a.time));
+        entries.into_iter().map(|entry| entry.source).collect()
+    }
+}
+
+/// 英雄被击杀的结算结果，`match_events` 据此产出对局事件。
+#[derive(Event, Debug, Clone)]
+pub struct EventChampionKill {
+    pub victim: Entity,
+    /// 拿到人头的敌方英雄；被防御塔 / 小兵处决时为 None
+    pub killer: Option<Entity>,
+    /// 击杀方阵营；被处决时取击杀单位或死者对方的阵营
+    pub killer_team: Team,
+    pub assisters: Vec<Entity>,
+    pub bounty: f32,
+    /// 是否终结了对方连杀
+    pub shutdown: bool,
+}
+
+/// 按死者的连杀 / 连死计算赏金，返回 `(赏金, 是否终结)`。
+pub fn champion_bounty(victim: &ChampionStats) -> (f32, bool) {
+    if victim.kill_streak >= 2 {
+        let bonus =
+            ((victim.kill_streak - 1) as f32 * SHUTDOWN_BOUNTY_PER_KILL).min(SHUTDOWN_BOUNTY_CAP);
+        return (CHAMPION_BASE_BOUNTY + bonus, true);
+    }
+    let bounty = CHAMPION_BASE_BOUNTY * DEATH_STREAK_BOUNTY_FACTOR.powi(victim.death_streak as i32);
+    (bounty.max(CHAMPION_MIN_BOUNTY), false)
+}
+
+fn on_damage_record_contribution(
+    trigger: On<EventDamageCreate>,
+    mut q_ledger: Query<(&mut DamageLedger, &Team)>,
+    q_champion: Query<&Team, With<Champion>>,
+    q_pet_of: Query<&PetOf>,
+    time: Res<Time<Fixed>>,
+) {
+    let result = &trigger.damage_result;
+    let amount = result.final_damage + result.white_shield_absorbed + result.magic_shield_absorbed;
+    if amount <= 0.0 {
+        return;
+    }
+    let Ok((mut ledger, team)) = q_ledger.get_mut(trigger.event_target()) else {
+        return;
+    };
+    // 召唤物造成的伤害计入主人的助攻贡献
+    let source = resolve_owner(&q_pet_of, trigger.source);
+    let Ok(source_team) = q_champion.get(source) else {
+        return;
+    };
+    if source_team == team {
+        return;
+    }
+    ledger.record(source, amount, time.elapsed_secs());
+}
+
+/// 英雄死亡：结算人头赏金、助攻与连杀 / 连死。
+fn on_champion_dead(
+    trigger: On<EventDead>,
+    mut commands: Commands,
+    mut q_victim: Query<(&Team, &mut DamageLedger), With<Champion>>,
+    q_champion: Query<&Team, With<Champion>>,
+    q_team: Query<&Team>,
+    mut q_stats: Query<&mut ChampionStats>,
+    mut q_gold: Query<&mut Gold>,
+    q_transform: Query<&GlobalTransform>,
+    time: Res<Time<Fixed>>,
+) {
+    let victim = trigger.event_target();
+    let Ok((victim_team, mut ledger)) = q_victim.get_mut(victim) else {
+        return;
+    };
+    let victim_team = *victim_team;
+    let now = time.elapsed_secs();
+
+    let is_enemy_champion = |entity: Entity| {
+        q_champion
+            .get(entity)
+            .is_ok_and(|team| *team != victim_team && *team != Team::Neutral)
+    };
+    let killer = trigger.killer.filter(|killer| is_enemy_champion(*killer));
+    let killer_team = trigger
+        .killer
+        .and_then(|killer| q_team.get(killer).ok().copied())
+        .filter(|team| *team != victim_team && *team != Team::Neutral)
+        .unwrap_or_else(|| opposing_team(victim_team));
+    let assisters = ledger
+        .contributors(now)
+        .into_iter()
+        .filter(|entity| Some(*entity) != killer && is_enemy_champion(*entity))
+        .collect::<Vec<_>>();
+    ledger.entries.clear();
+
+    let (bounty, shutdown) = q_stats
+        .get(victim)
+        .map(champion_bounty)
+        .unwrap_or((CHAMPION_BASE_BOUNTY, false));
+
+    let world_position = q_transform
+        .get(victim)
+        .map(|t| t.translation())
+        .unwrap_or_default();
+    let mut grant = |commands: &mut Commands, entity: Entity, amount: f32| {
+        if let Ok(mut gold) = q_gold.get_mut(entity) {
+            gold.current += amount;
+            gold.total += amount;
+            commands.trigger(EventGoldGain {
+                entity,
+                amount,
+                world_position,
+            });
+        }
+        if let Ok(mut stats) = q_stats.get_mut(entity) {
+            stats.bounty_gold += amount;
+            stats.death_streak = 0;
+        }
+    };
+
+    if let Some(killer) = killer {
+        grant(&mut commands, killer, bounty);
+    }
+    if !assisters.is_empty() {
+        // 没有英雄拿到人头时助攻者平分全额赏金
+        let ratio = if killer.is_some() {
+            ASSIST_BOUNTY_RATIO
+        } else {
+            1.0
+        };
+        let share = bounty * ratio / assisters.len() as f32;
+        for assister in assisters.iter().copied() {
+            grant(&mut commands, assister, share);
+        }
+    }
+
+    if let Some(mut stats) = killer.and_then(|killer| q_stats.get_mut(killer).ok()) {
+        stats.kill_streak += 1;
+    }
+    for assister in assisters.iter().copied() {
+        if let Ok(mut stats) = q_stats.get_mut(assister) {
+            stats.assists += 1;
+        }
+    }
+    if let Ok(mut stats) = q_stats.get_mut(victim) {
+        stats.kill_streak = 0;
+        stats.death_streak += 1;
+    }
+
+    debug!(
+        "{:?} 被击杀：击杀者 {:?}，助攻 {:?}，赏金 {:.0}{}",
+        victim,
+        killer,
+        assisters,
+        bounty,
+        if shutdown { "（终结）" } else { "" }
+    );
+    commands.trigger(EventChampionKill {
+        victim,
+        killer,
+        killer_team,
+        assisters,
+        bounty,
+        shutdown,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::damage::{DamageKind, DamageResult, DamageType};
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(PluginBounty);
+        app
+    }
+
+    fn spawn_champion(app: &mut App, team: Team) -> Entity {
+        app.world_mut().spawn((Champion, team)).id()
+    }
+
+    fn hit(app: &mut App, target: Entity, source: Entity, amount: f32) {
+        app.world_mut().trigger(EventDamageCreate {
+            entity: target,
+            source,
+            damage_type: DamageType::Physical,
+            damage_result: DamageResult {
+                final_damage: amount,
+                white_shield_absorbed: 0.0,
+                magic_shield_absorbed: 0.0,
+                reduced_damage: 0.0,
+                armor_reduced_damage: 0.0,
+                original_damage: amount,
+            },
+            tag: None,
+            kind: DamageKind::Other,
+        });
+    }
+
+    #[test]
+    fn contributors_get_assists_and_split_bounty() {
+        let mut app = setup_app();
+        let victim = spawn_champion(&mut app, Team::Chaos);
+        let killer = spawn_champion(&mut app, Team::Order);
+        let helper = spawn_champion(&mut app, Team::Order);
+        let ally = spawn_champion(&mut app, Team::Chaos);
+
+        hit(&mut app, victim, helper, 200.0);
+        hit(&mut app, victim, ally, 50.0);
+        hit(&mut app, victim, killer, 300.0);
+        app.world_mut().trigger(EventDead {
+            entity: victim,
+            killer: Some(killer),
+        });
+        app.update();
+
+        let stats = |entity| app.world().get::<ChampionStats>(entity).unwrap().clone();
+        assert_eq!(stats(helper).assists, 1);
+        assert_eq!(stats(ally).assists, 0, "同队伤害不计助攻");
+        assert_eq!(stats(killer).assists, 0);
+        assert_eq!(stats(killer).kill_streak, 1);
+        assert_eq!(stats(victim).death_streak, 1);
+        assert_eq!(stats(killer).bounty_gold, CHAMPION_BASE_BOUNTY);
+        assert_eq!(
+            stats(helper).bounty_gold,
+            CHAMPION_BASE_BOUNTY * ASSIST_BOUNTY_RATIO
+        );
+        assert!(
+            app.world()
+                .get::<DamageLedger>(victim)
+                .unwrap()
+                .entries
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn pet_damage_counts_for_owner_assist() {
+        let mut app = setup_app();
+        let victim = spawn_champion(&mut app, Team::Chaos);
+        let killer = spawn_champion(&mut app, Team::Order);
+        let annie = spawn_champion(&mut app, Team::Order);
+        let tibbers = app.world_mut().spawn((PetOf(annie), Team::Order)).id();
+
+        hit(&mut app, victim, tibbers, 200.0);
+        app.world_mut().trigger(EventDead {
+            entity: victim,
+            killer: Some(killer),
+        });
+        app.update();
+
+        let stats = app.world().get::<ChampionStats>(annie).unwrap();
+        assert_eq!(stats.assists, 1);
+        assert_eq!(
+            stats.bounty_gold,
+            CHAMPION_BASE_BOUNTY * ASSIST_BOUNTY_RATIO
+        );
+    }
+
+    #[test]
+    fn executed_champion_splits_full_bounty_among_assisters() {
+        let mut app = setup_app();
+        let victim = spawn_champion(&mut app, Team::Chaos);
+        let first = spawn_champion(&mut app, Team::Order);
+        let second = spawn_champion(&mut app, Team::Order);
+        let turret = app.world_mut().spawn(Team::Order).id();
+
+        hit(&mut app, victim, first, 100.0);
+        hit(&mut app, victim, second, 100.0);
+        app.world_mut().trigger(EventDead {
+            entity: victim,
+            killer: Some(turret),
+        });
+        app.update();
+
+        for assister in [first, second] {
+            let stats = app.world().get::<ChampionStats>(assister).unwrap();
+            assert_eq!(stats.assists, 1);
+            assert_eq!(stats.bounty_gold, CHAMPION_BASE_BOUNTY / 2.0);
+        }
+    }
+
+    #[test]
+    fn bounty_follows_kill_and_death_streaks() {
+        let base = ChampionStats::default();
+        assert_eq!(champion_bounty(&base), (CHAMPION_BASE_BOUNTY, false));
+
+        let on_fire = ChampionStats {
+            kill_streak: 4,
+            ..default()
+        };
+        assert_eq!(
+            champion_bounty(&on_fire),
+            (CHAMPION_BASE_BOUNTY + 3.0 * SHUTDOWN_BOUNTY_PER_KILL, true)
+        );
+
+        let legendary = ChampionStats {
+            kill_streak: 20,
+            ..default()
+        };
+        assert_eq!(
+            champion_bounty(&legendary).0,
+            CHAMPION_BASE_BOUNTY + SHUTDOWN_BOUNTY_CAP
+        );
+
+        let feeding = ChampionStats {
+            death_streak: 10,
+            ..default()
+        };
+        assert_eq!(champion_bounty(&feeding).0, CHAMPION_MIN_BOUNTY);
+    }
+}
@@ -1,3 +1,12 @@
+//! Buff 基础设施：持有关系、通用描述与活跃 buff 查询。
+//!
+//! 每个 buff 是挂在持有者 [`Buffs`] 下的独立实体，具体效果由各自组件实现。
+//! 需要统一叠加 / 持续时间语义的 buff 经 [`BuffCommandsExt::apply_buff`] 施加并携带 [`BuffDescriptor`]；
+//! 自带计时器的 buff 类型经 [`BuffAppExt::register_buff_timer`] 登记，[`active_buffs`] 即可读出剩余时间。
+
+use std::any::TypeId;
+
+use bevy::ecs::component::Mutable;
 use bevy::prelude::*;
 
 #[derive(Component, Debug)]
@@ -20,3 +29,449 @@ impl std::ops::Deref for Buffs {
         &self.0
     }
 }
+
+/// 同类 buff 再次施加时的处理方式（同类 = 同一 bundle 类型）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BuffStacking {
+    /// 层数 +1（不超过上限）并重置持续时间
+    #[default]
+    Refresh,
+    /// 只重置持续时间，层数不变
+    Renew,
+    /// 每次施加生成独立实例，各自计时；超出上限时移除剩余时间最短的实例
+    Independent,
+    /// 新实例强度（`potency`）更高时替换旧实例，相等时重置持续时间，更弱则忽略
+    ReplaceIfStronger,
+}
+
+/// buff 分类标签，供净化、UI 与观测筛选
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuffTag {
+    Positive,
+    Negative,
+    CrowdControl,
+    Movement,
+    Offense,
+    Defense,
+    Aura,
+}
+
+/// 通用 buff 描述：来源、叠加策略、持续时间、标签与可驱散性。
+///
+/// 持续时间由 `tick_buff_durations` 计时，到期销毁 buff 实体；`None` 表示由 buff 自身逻辑决定何时消失。
+#[derive(Component, Debug, Clone)]
+pub struct BuffDescriptor {
+    pub source: Option<Entity>,
+    pub max_stacks: u32,
+    pub stacking: BuffStacking,
+    pub duration: Option<Timer>,
+    pub tags: Vec<BuffTag>,
+    /// 可被净化移除
+    pub dispellable: bool,
+    pub stacks: u32,
+    /// `ReplaceIfStronger` 比较用的强度
+    pub potency: f32,
+    kind: Option<TypeId>,
+}
+
+impl Default for BuffDescriptor {
+    fn default() -> Self {
+        Self {
+            source: None,
+            max_stacks: 1,
+            stacking: BuffStacking::default(),
+            duration: None,
+            tags: Vec::new(),
+            dispellable: false,
+            stacks: 1,
+            potency: 0.0,
+            kind: None,
+        }
+    }
+}
+
+impl BuffDescriptor {
+    pub fn new(stacking: BuffStacking) -> Self {
+        Self {
+            stacking,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_source(mut self, source: Entity) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn with_max_stacks(mut self, max_stacks: u32) -> Self {
+        self.max_stacks = max_stacks.max(1);
+        self
+    }
+
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = Some(Timer::from_seconds(duration, TimerMode::Once));
+        self
+    }
+
+    pub fn with_tags(mut self, tags: &[BuffTag]) -> Self {
+        self.tags = tags.to_vec();
+        self
+    }
+
+    pub fn with_potency(mut self, potency: f32) -> Self {
+        self.potency = potency;
+        self
+    }
+
+    pub fn dispellable(mut self) -> Self {
+        self.dispellable = true;
+        self
+    }
+
+    pub fn has_tag(&self, tag: BuffTag) -> bool {
+        self.tags.contains(&tag)
+    }
+}
+
+type BuffTimerRead = Box<dyn Fn(&EntityRef) -> Option<(f32, f32)> + Send + Sync>;
+type BuffTimerReset = Box<dyn Fn(&mut EntityWorldMut) + Send + Sync>;
+
+/// 自带计时器的 buff 类型登记表：读取剩余 / 总时长，叠加刷新时重置。
+#[derive(Resource, Default)]
+pub struct BuffRegistry {
+    timers: Vec<(BuffTimerRead, BuffTimerReset)>,
+}
+
+impl BuffRegistry {
+    pub fn register_timer<T: Component<Mutability = Mutable>>(
+        &mut self,
+        get: fn(&T) -> &Timer,
+        get_mut: fn(&mut T) -> &mut Timer,
+    ) {
+        self.timers.push((
+            Box::new(move |entity: &EntityRef| {
+                entity
+                    .get::<T>()
+                    .map(get)
+                    .map(|timer| (timer.remaining_secs(), timer.duration().as_secs_f32()))
+            }),
+            Box::new(move |entity: &mut EntityWorldMut| {
+                if let Some(mut buff) = entity.get_mut::<T>() {
+                    get_mut(&mut buff).reset();
+                }
+            }),
+        ));
+    }
+
+    /// 第一个匹配的登记计时器：(剩余秒数, 总秒数)
+    pub fn timer_of(&self, entity: &EntityRef) -> Option<(f32, f32)> {
+        self.timers.iter().find_map(|(read, _)| read(entity))
+    }
+
+    fn reset(&self, entity: &mut EntityWorldMut) {
+        for (_, reset) in &self.timers {
+            reset(entity);
+        }
+    }
+}
+
+/// Bevy App 扩展：让各插件就地登记 buff 计时器。
+pub trait BuffAppExt {
+    /// 登记 `T` 的计时器（首次调用会初始化 `BuffRegistry` 资源）。
+    fn register_buff_timer<T: Component<Mutability = Mutable>>(
+        &mut self,
+        get: fn(&T) -> &Timer,
+        get_mut: fn(&mut T) -> &mut Timer,
+    ) -> &mut Self;
+}
+
+impl BuffAppExt for App {
+    fn register_buff_timer<T: Component<Mutability = Mutable>>(
+        &mut self,
+        get: fn(&T) -> &Timer,
+        get_mut: fn(&mut T) -> &mut Timer,
+    ) -> &mut Self {
+        self.init_resource::<BuffRegistry>();
+        self.world_mut()
+            .resource_mut::<BuffRegistry>()
+            .register_timer(get, get_mut);
+        self
+    }
+}
+
+/// 按描述施加 buff 的命令扩展。
+pub trait BuffCommandsExt {
+    /// 对本实体施加 `bundle` 作为 buff，按 `descriptor.stacking` 与已有同类 buff 合并。
+    ///
+    /// 刷新 / 续期只改层数与计时器，不替换已有实例的组件数值。
+    fn apply_buff<B: Bundle>(&mut self, descriptor: BuffDescriptor, bundle: B) -> &mut Self;
+}
+
+impl BuffCommandsExt for EntityCommands<'_> {
+    fn apply_buff<B: Bundle>(&mut self, descriptor: BuffDescriptor, bundle: B) -> &mut Self {
+        let holder = self.id();
+        self.commands().queue(move |world: &mut World| {
+            apply_buff(world, holder, descriptor, bundle);
+        });
+        self
+    }
+}
+
+/// [`BuffCommandsExt::apply_buff`] 的直接版本，返回新生成或被合并的 buff 实体。
+pub fn apply_buff<B: Bundle>(
+    world: &mut World,
+    holder: Entity,
+    mut descriptor: BuffDescriptor,
+    bundle: B,
+) -> Option<Entity> {
+    world.get_entity(holder).ok()?;
+    let kind = Some(TypeId::of::<B>());
+    descriptor.kind = kind;
+    descriptor.stacks = descriptor.stacks.clamp(1, descriptor.max_stacks);
+
+    let mut existing: Vec<(Entity, u32, f32, f32)> = world
+        .get::<Buffs>(holder)
+        .map(|buffs| {
+            buffs
+                .0
+                .iter()
+                .filter_map(|&e| {
+                    let d = world.get::<BuffDescriptor>(e).filter(|d| d.kind == kind)?;
+                    let remaining = d.duration.as_ref().map_or(f32::MAX, Timer::remaining_secs);
+                    Some((e, d.stacks, remaining, d.potency))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    match (descriptor.stacking, existing.first().copied()) {
+        (BuffStacking::Refresh, Some((e, stacks, ..))) => {
+            renew_buff(world, e, (stacks + 1).min(descriptor.max_stacks));
+            return Some(e);
+        }
+        (BuffStacking::Renew, Some((e, stacks, ..))) => {
+            renew_buff(world, e, stacks);
+            return Some(e);
+        }
+        (BuffStacking::ReplaceIfStronger, Some((e, stacks, _, potency))) => {
+            if descriptor.potency < potency {
+                return None;
+            }
+            if descriptor.potency == potency {
+                renew_buff(world, e, stacks);
+                return Some(e);
+            }
+            world.despawn(e);
+        }
+        (BuffStacking::Independent, _) => {
+            existing.sort_by(|a, b| a.2.total_cmp(&b.2));
+            let overflow = (existing.len() + 1).saturating_sub(descriptor.max_stacks as usize);
+            for &(e, ..) in existing.iter().take(overflow) {
+                world.despawn(e);
+            }
+        }
+        _ => {}
+    }
+
+    Some(world.spawn((bundle, descriptor, BuffOf(holder))).id())
+}
+
+/// 重置 buff 的描述计时器与登记计时器，并设置层数
+fn renew_buff(world: &mut World, buff_entity: Entity, stacks: u32) {
+    let Ok(mut entity) = world.get_entity_mut(buff_entity) else {
+        return;
+    };
+    if let Some(mut descriptor) = entity.get_mut::<BuffDescriptor>() {
+        descriptor.stacks = stacks;
+        if let Some(timer) = descriptor.duration.as_mut() {
+            timer.reset();
+        }
+    }
+    world.try_resource_scope(|world, registry: Mut<BuffRegistry>| {
+        if let Ok(mut entity) = world.get_entity_mut(buff_entity) {
+            registry.reset(&mut entity);
+        }
+    });
+}
+
+/// 活跃 buff 的统一视图
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActiveBuff {
+    pub entity: Entity,
+    pub name: &'static str,
+    pub source: Option<Entity>,
+    pub stacks: u32,
+    /// 剩余秒数；无计时器时为 `None`
+    pub remaining: Option<f32>,
+    pub duration: Option<f32>,
+    pub tags: Vec<BuffTag>,
+    pub dispellable: bool,
+}
+
+/// 列出实体身上所有 buff。
+///
+/// 剩余时间优先取 [`BuffDescriptor`] 的持续时间，否则取 [`BuffRegistry`] 登记的计时器；
+/// 无描述的 buff 视为 1 层、无来源、不可驱散。
+pub fn active_buffs(world: &World, entity: Entity) -> Vec<ActiveBuff> {
+    let Some(buffs) = world.get::<Buffs>(entity) else {
+        return Vec::new();
+    };
+    let registry = world.get_resource::<BuffRegistry>();
+
+    buffs
+        .0
+        .iter()
+        .filter_map(|&buff_entity| {
+            let entity_ref = world.get_entity(buff_entity).ok()?;
+            let buff = entity_ref.get::<Buff>()?;
+            let descriptor = entity_ref.get::<BuffDescriptor>();
+            let timer = descriptor
+                .and_then(|d| d.duration.as_ref())
+                .map(|t| (t.remaining_secs(), t.duration().as_secs_f32()))
+                .or_else(|| registry.and_then(|r| r.timer_of(&entity_ref)));
+            Some(ActiveBuff {
+                entity: buff_entity,
+                name: buff.name,
+                source: descriptor.and_then(|d| d.source),
+                stacks: descriptor.map_or(1, |d| d.stacks),
+                remaining: timer.map(|(remaining, _)| remaining),
+                duration: timer.map(|(_, duration)| duration),
+                tags: descriptor.map(|d| d.tags.clone()).unwrap_or_default(),
+                dispellable: descriptor.is_some_and(|d| d.dispellable),
+            })
+        })
+        .collect()
+}
+
+/// 描述持续时间计时，到期销毁 buff 实体。
+fn tick_buff_durations(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    mut q_descriptor: Query<(Entity, &mut BuffDescriptor)>,
+) {
+    for (entity, mut descriptor) in q_descriptor.iter_mut() {
+        let Some(timer) = descriptor.duration.as_mut() else {
+            continue;
+        };
+        timer.tick(time.delta());
+        if timer.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct PluginBuff;
+
+impl Plugin for PluginBuff {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BuffRegistry>();
+        app.add_systems(FixedUpdate, tick_buff_durations);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Debug)]
+    #[require(Buff = Buff { name: "Test" })]
+    struct BuffTest {
+        timer: Timer,
+    }
+
+    impl BuffTest {
+        fn new(duration: f32) -> Self {
+            Self {
+                timer: Timer::from_seconds(duration, TimerMode::Once),
+            }
+        }
+    }
+
+    fn world_with_registry() -> World {
+        let mut world = World::new();
+        let mut registry = BuffRegistry::default();
+        registry.register_timer::<BuffTest>(|b| &b.timer, |b| &mut b.timer);
+        world.insert_resource(registry);
+        world
+    }
+
+    fn advance(world: &mut World, buff_entity: Entity, secs: f32) {
+        let delta = std::time::Duration::from_secs_f32(secs);
+        let mut entity = world.entity_mut(buff_entity);
+        entity.get_mut::<BuffTest>().unwrap().timer.tick(delta);
+        if let Some(timer) = entity
+            .get_mut::<BuffDescriptor>()
+            .unwrap()
+            .duration
+            .as_mut()
+        {
+            timer.tick(delta);
+        }
+    }
+
+    #[test]
+    fn refresh_stacks_up_to_max_and_resets_timers() {
+        let mut world = world_with_registry();
+        let holder = world.spawn_empty().id();
+        let descriptor = BuffDescriptor::new(BuffStacking::Refresh)
+            .with_max_stacks(3)
+            .with_duration(4.0);
+
+        let first = apply_buff(&mut world, holder, descriptor.clone(), BuffTest::new(4.0)).unwrap();
+        advance(&mut world, first, 3.0);
+        for _ in 0..3 {
+            let again =
+                apply_buff(&mut world, holder, descriptor.clone(), BuffTest::new(4.0)).unwrap();
+            assert_eq!(again, first);
+        }
+
+        let buffs = active_buffs(&world, holder);
+        assert_eq!(buffs.len(), 1);
+        assert_eq!(buffs[0].name, "Test");
+        assert_eq!(buffs[0].stacks, 3);
+        assert_eq!(buffs[0].remaining, Some(4.0));
+        assert_eq!(
+            world.get::<BuffTest>(first).unwrap().timer.remaining_secs(),
+            4.0
+        );
+    }
+
+    #[test]
+    fn independent_evicts_shortest_and_replace_needs_stronger() {
+        let mut world = world_with_registry();
+        let holder = world.spawn_empty().id();
+        let independent = BuffDescriptor::new(BuffStacking::Independent)
+            .with_max_stacks(2)
+            .with_duration(5.0);
+
+        let a = apply_buff(&mut world, holder, independent.clone(), BuffTest::new(5.0)).unwrap();
+        advance(&mut world, a, 2.0);
+        let b = apply_buff(&mut world, holder, independent.clone(), BuffTest::new(5.0)).unwrap();
+        let c = apply_buff(&mut world, holder, independent, BuffTest::new(5.0)).unwrap();
+        assert!(world.get_entity(a).is_err());
+        assert_eq!(world.get::<Buffs>(holder).unwrap().len(), 2);
+        assert!(world.get_entity(b).is_ok() && world.get_entity(c).is_ok());
+
+        #[derive(Component)]
+        #[require(Buff = Buff { name: "Strong" })]
+        struct BuffStrong;
+
+        let target = world.spawn_empty().id();
+        let weak = BuffDescriptor::new(BuffStacking::ReplaceIfStronger)
+            .with_potency(0.2)
+            .with_source(holder)
+            .dispellable();
+        let first = apply_buff(&mut world, target, weak.clone(), BuffStrong).unwrap();
+        assert!(apply_buff(&mut world, target, weak.with_potency(0.1), BuffStrong).is_none());
+        let stronger = BuffDescriptor::new(BuffStacking::ReplaceIfStronger).with_potency(0.5);
+        let second = apply_buff(&mut world, target, stronger, BuffStrong).unwrap();
+        assert_ne!(first, second);
+
+        let buffs = active_buffs(&world, target);
+        assert_eq!(buffs.len(), 1);
+        assert_eq!(buffs[0].entity, second);
+        assert_eq!(buffs[0].remaining, None);
+        assert!(!buffs[0].dispellable);
+    }
+}
@@ -91,6 +91,11 @@ pub fn on_event_dead(
         return;
     };
 
+    // 英雄击杀赏金与助攻分成由 bounty 结算
+    if q_champion.contains(dead_entity) {
+        return;
+    }
+
     // 只有当击杀者是带有金币组件的实体时，才发放金币奖励
     let Ok(mut killer_gold) = q_gold.get_mut(killer_entity) else {
         return;
@@ -109,9 +114,6 @@ pub fn on_event_dead(
             Minion::Siege => 60.0,
             Minion::Super => 90.0,
         };
-    } else if q_champion.get(dead_entity).is_ok() {
-        // Fallback 到英雄的击杀金币
-        gold_gain = 300.0;
     }
 
     if gold_gain > 0.0 {
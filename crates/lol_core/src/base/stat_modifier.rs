@@ -0,0 +1,517 @@
+//! 属性修正层：在角色属性组件之上聚合等级成长与各来源的修正值。
+//!
+//! 修正挂在 buff 实体（或持有者自身）的 [`StatModifiers`] 上，buff 销毁即自动撤回；
+//! 增删修正或升级时重算：`(原值 + 等级成长 + 固定值) × (1 + 百分比之和) × 各乘算系数`，
+//! 移速叠加角色身上的 [`MovementSlow`] 减速后再经 [`soft_cap_move_speed`] 软上限。攻速写入 `Attack::bonus_attack_speed`，上限仍由 `attack_speed_cap` 把关。
+//!
+//! 修正层只记账自己写入的增量（[`StatLayer`]），直接改属性组件的旧逻辑计入原值，不会被重算覆盖。
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::attack::Attack;
+use crate::base::ability_resource::{AbilityResource, AbilityResourceType};
+use crate::base::buff::{BuffOf, Buffs};
+use crate::base::level::{EventLevelUp, Level};
+use crate::buffs::cc_debuffs::Tenacity;
+use crate::damage::{AbilityPower, Armor, Damage, MagicResist};
+use crate::heal::HealShieldPower;
+use crate::life::Health;
+use crate::movement::{Movement, MovementSlow};
+
+/// 受修正层管理的属性
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StatKind {
+    MaxHealth,
+    AttackDamage,
+    AbilityPower,
+    Armor,
+    MagicResist,
+    /// 额外攻速比例（0.1 = +10%）
+    AttackSpeed,
+    MoveSpeed,
+    /// 治疗与护盾强度（0.1 = +10%）
+    HealShieldPower,
+    /// 法力上限（`AbilityResource::max`）
+    MaxMana,
+    /// 基础每秒生命回复（`Health::base_static_regen`）
+    HealthRegen,
+    /// 韧性（0.1 = 10%），上限 100%
+    Tenacity,
+}
+
+impl StatKind {
+    pub const ALL: [StatKind; 11] = [
+        StatKind::MaxHealth,
+        StatKind::AttackDamage,
+        StatKind::AbilityPower,
+        StatKind::Armor,
+        StatKind::MagicResist,
+        StatKind::AttackSpeed,
+        StatKind::MoveSpeed,
+        StatKind::HealShieldPower,
+        StatKind::MaxMana,
+        StatKind::HealthRegen,
+        StatKind::Tenacity,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// 修正的结算方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ModifierOp {
+    /// 加到原值上
+    Flat,
+    /// 百分比加算：同属性的百分比先求和再乘
+    PercentAdd,
+    /// 独立乘算
+    Multiply,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StatModifier {
+    pub stat: StatKind,
+    pub op: ModifierOp,
+    pub value: f32,
+}
+
+impl StatModifier {
+    pub fn flat(stat: StatKind, value: f32) -> Self {
+        Self {
+            stat,
+            op: ModifierOp::Flat,
+            value,
+        }
+    }
+
+    pub fn percent(stat: StatKind, value: f32) -> Self {
+        Self {
+            stat,
+            op: ModifierOp::PercentAdd,
+            value,
+        }
+    }
+
+    pub fn multiply(stat: StatKind, value: f32) -> Self {
+        Self {
+            stat,
+            op: ModifierOp::Multiply,
+            value,
+        }
+    }
+}
+
+/// 一组属性修正；挂在 buff 实体上作用于 buff 持有者，挂在其它实体上作用于自身。
+#[derive(Component, Debug, Clone, Default)]
+pub struct StatModifiers(pub Vec<StatModifier>);
+
+/// 每级属性成长（由 CharacterRecord 导出），1 级为 0。
+#[derive(Component, Reflect, Serialize, Deserialize, Clone, Debug, Default)]
+#[reflect(Component, Default)]
+#[serde(default)]
+pub struct StatGrowth {
+    pub health: f32,
+    pub damage: f32,
+    pub ability_power: f32,
+    pub armor: f32,
+    pub magic_resist: f32,
+    /// 额外攻速比例（0.02 = 每级 +2%）
+    pub attack_speed: f32,
+}
+
+impl StatGrowth {
+    fn per_level(&self, stat: StatKind) -> f32 {
+        match stat {
+            StatKind::MaxHealth => self.health,
+            StatKind::AttackDamage => self.damage,
+            StatKind::AbilityPower => self.ability_power,
+            StatKind::Armor => self.armor,
+            StatKind::MagicResist => self.magic_resist,
+            StatKind::AttackSpeed => self.attack_speed,
+            // 法力成长取自 `AbilityResource::per_level`，见 [`recompute_stats`]
+            StatKind::MaxMana
+            | StatKind::MoveSpeed
+            | StatKind::HealShieldPower
+            | StatKind::HealthRegen
+            | StatKind::Tenacity => 0.0,
+        }
+    }
+}
+
+/// 修正层上次写入各属性的增量，重算时先撤回
+#[derive(Component, Debug, Default)]
+pub struct StatLayer {
+    applied: [f32; StatKind::ALL.len()],
+}
+
+impl StatLayer {
+    pub fn applied(&self, stat: StatKind) -> f32 {
+        self.applied[stat.index()]
+    }
+}
+
+/// 官方成长曲线：第 `level` 级累计成长 = g × (n−1) × (0.7025 + 0.0175 × (n−1))
+pub fn growth_at_level(per_level: f32, level: u32) -> f32 {
+    let n = level.saturating_sub(1) as f32;
+    per_level * n * (0.7025 + 0.0175 * n)
+}
+
+/// 移速软上限：415 以上按 80% 计，490 以上按 50% 计，220 以下按 50% 向 220 靠拢
+pub fn soft_cap_move_speed(speed: f32) -> f32 {
+    if speed > 490.0 {
+        speed * 0.5 + 230.0
+    } else if speed > 415.0 {
+        speed * 0.8 + 83.0
+    } else if speed < 220.0 {
+        speed * 0.5 + 110.0
+    } else {
+        speed
+    }
+}
+
+/// 按修正聚合出最终值
+pub fn apply_modifiers(stat: StatKind, raw: f32, modifiers: &[StatModifier]) -> f32 {
+    let mut flat = 0.0;
+    let mut percent = 0.0;
+    let mut multiply = 1.0;
+    for modifier in modifiers.iter().filter(|m| m.stat == stat) {
+        match modifier.op {
+            ModifierOp::Flat => flat += modifier.value,
+            ModifierOp::PercentAdd => percent += modifier.value,
+            ModifierOp::Multiply => multiply *= modifier.value,
+        }
+    }
+    match stat {
+        // 攻速加成均为基础攻速的比例，固定值与百分比同样加到额外攻速上
+        StatKind::AttackSpeed => (1.0 + raw + flat + percent) * multiply - 1.0,
+        StatKind::MoveSpeed => soft_cap_move_speed((raw + flat) * (1.0 + percent) * multiply),
+        StatKind::Tenacity => ((raw + flat) * (1.0 + percent) * multiply).min(1.0),
+        _ => (raw + flat) * (1.0 + percent) * multiply,
+    }
+}
+
+fn read_stat(entity: &EntityWorldMut, stat: StatKind) -> Option<f32> {
+    match stat {
+        StatKind::MaxHealth => entity.get::<Health>().map(|h| h.max),
+        StatKind::AttackDamage => entity.get::<Damage>().map(|d| d.0),
+        // 缺少法强 / 双抗 / 治疗强度 / 韧性组件时按 0 处理，写入时补上
+        StatKind::AbilityPower => Some(entity.get::<AbilityPower>().map_or(0.0, |ap| ap.0)),
+        StatKind::Armor => Some(entity.get::<Armor>().map_or(0.0, |a| a.0)),
+        StatKind::MagicResist => Some(entity.get::<MagicResist>().map_or(0.0, |mr| mr.0)),
+        StatKind::AttackSpeed => entity.get::<Attack>().map(|a| a.bonus_attack_speed),
+        StatKind::MoveSpeed => entity.get::<Movement>().map(|m| m.speed),
+        StatKind::HealShieldPower => Some(entity.get::<HealShieldPower>().map_or(0.0, |p| p.0)),
+        StatKind::MaxMana => entity.get::<AbilityResource>().map(|r| r.max),
+        StatKind::HealthRegen => entity.get::<Health>().map(|h| h.base_static_regen),
+        StatKind::Tenacity => Some(entity.get::<Tenacity>().map_or(0.0, |t| t.0)),
+    }
+}
+
+fn write_stat(entity: &mut EntityWorldMut, stat: StatKind, value: f32) {
+    match stat {
+        StatKind::MaxHealth => {
+            if let Some(mut health) = entity.get_mut::<Health>() {
+                // 当前生命按上限等比缩放
+                health.value = scale_current(health.value, health.max, value);
+                health.max = value;
+            }
+        }
+        StatKind::AttackDamage => {
+            if let Some(mut damage) = entity.get_mut::<Damage>() {
+                damage.0 = value;
+            }
+        }
+        StatKind::AbilityPower => {
+            entity.insert(AbilityPower(value));
+        }
+        StatKind::Armor => {
+            entity.insert(Armor(value));
+        }
+        StatKind::MagicResist => {
+            entity.insert(MagicResist(value));
+        }
+        StatKind::AttackSpeed => {
+            if let Some(mut attack) = entity.get_mut::<Attack>() {
+                attack.bonus_attack_speed = value;
+            }
+        }
+        StatKind::MoveSpeed => {
+            if let Some(mut movement) = entity.get_mut::<Movement>() {
+                movement.speed = value;
+            }
+        }
+        StatKind::HealShieldPower => {
+            entity.insert(HealShieldPower(value));
+        }
+        StatKind::MaxMana => {
+            if let Some(mut resource) = entity.get_mut::<AbilityResource>() {
+                resource.value = scale_current(resource.value, resource.max, value);
+                resource.max = value;
+            }
+        }
+        StatKind::HealthRegen => {
+            if let Some(mut health) = entity.get_mut::<Health>() {
+                health.base_static_regen = value;
+            }
+        }
+        StatKind::Tenacity => {
+            entity.insert(Tenacity(value));
+        }
+    }
+}
+
+/// 上限变化时当前值保持原比例；原上限为 0 时视为满值
+fn scale_current(current: f32, old_max: f32, new_max: f32) -> f32 {
+    if old_max > 0.0 {
+        current / old_max * new_max
+    } else {
+        new_max
+    }
+}
+
+/// 重算持有者的全部受管属性：撤回上次增量，按当前等级与修正重新写入。
+pub fn recompute_stats(world: &mut World, holder: Entity) {
+    let Ok(entity) = world.get_entity(holder) else {
+        return;
+    };
+    let mut modifiers: Vec<StatModifier> = entity
+        .get::<StatModifiers>()
+        .map(|m| m.0.clone())
+        .unwrap_or_default();
+    if let Some(buffs) = entity.get::<Buffs>() {
+        for buff_entity in buffs.iter() {
+            if let Some(buff_modifiers) = world.get::<StatModifiers>(buff_entity) {
+                modifiers.extend(buff_modifiers.0.iter().copied());
+            }
+        }
+    }
+    // 减速作为移速的乘算修正参与聚合，低速软上限对减速后的速度生效
+    if let Some(slow) = entity.get::<MovementSlow>() {
+        modifiers.push(StatModifier::multiply(
+            StatKind::MoveSpeed,
+            1.0 - slow.percent,
+        ));
+    }
+    let level = entity.get::<Level>().map_or(1, |l| l.value);
+    let growth = entity.get::<StatGrowth>().cloned().unwrap_or_default();
+    let mana_per_level = entity
+        .get::<AbilityResource>()
+        .filter(|r| matches!(r.ar_type, AbilityResourceType::Mana))
+        .map_or(0.0, |r| r.per_level);
+    let mut applied = entity
+        .get::<StatLayer>()
+        .map(|l| l.applied)
+        .unwrap_or_default();
+
+    let mut entity = world.entity_mut(holder);
+    for stat in StatKind::ALL {
+        let index = stat.index();
+        let per_level = match stat {
+            StatKind::MaxMana => mana_per_level,
+            _ => growth.per_level(stat),
+        };
+        let level_bonus = growth_at_level(per_level, level);
+        let touched = modifiers.iter().any(|m| m.stat == stat);
+        if !touched && level_bonus == 0.0 && applied[index] == 0.0 {
+            continue;
+        }
+        let Some(current) = read_stat(&entity, stat) else {
+            continue;
+        };
+        let raw = current - applied[index];
+        let value = apply_modifiers(stat, raw + level_bonus, &modifiers);
+        write_stat(&mut entity, stat, value);
+        applied[index] = value - raw;
+    }
+    entity.insert(StatLayer { applied });
+}
+
+fn queue_recompute(commands: &mut Commands, holder: Entity) {
+    // 排队到本轮命令之后：buff 销毁时重算看到的是已移除后的 Buffs
+    commands.queue(move |world: &mut World| recompute_stats(world, holder));
+}
+
+fn on_insert_stat_modifiers(
+    trigger: On<Insert, StatModifiers>,
+    mut commands: Commands,
+    q_buff_of: Query<&BuffOf>,
+) {
+    let entity = trigger.entity;
+    let holder = q_buff_of.get(entity).map_or(entity, |b| b.0);
+    queue_recompute(&mut commands, holder);
+}
+
+fn on_remove_stat_modifiers(
+    trigger: On<Remove, StatModifiers>,
+    mut commands: Commands,
+    q_buff_of: Query<&BuffOf>,
+) {
+    let entity = trigger.entity;
+    let holder = q_buff_of.get(entity).map_or(entity, |b| b.0);
+    queue_recompute(&mut commands, holder);
+}
+
+fn on_insert_movement_slow(trigger: On<Insert, MovementSlow>, mut commands: Commands) {
+    queue_recompute(&mut commands, trigger.entity);
+}
+
+fn on_remove_movement_slow(trigger: On<Remove, MovementSlow>, mut commands: Commands) {
+    queue_recompute(&mut commands, trigger.entity);
+}
+
+fn on_level_up_recompute(trigger: On<EventLevelUp>, mut commands: Commands) {
+    queue_recompute(&mut commands, trigger.event_target());
+}
+
+#[derive(Default)]
+pub struct PluginStatModifier;
+
+impl Plugin for PluginStatModifier {
+    fn build(&self, app: &mut App) {
+        app.register_type::<StatGrowth>();
+        app.add_observer(on_insert_stat_modifiers);
+        app.add_observer(on_remove_stat_modifiers);
+        app.add_observer(on_insert_movement_slow);
+        app.add_observer(on_remove_movement_slow);
+        app.add_observer(on_level_up_recompute);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aggregates_flat_percent_multiply_and_caps() {
+        let modifiers = [
+            StatModifier::flat(StatKind::AttackDamage, 10.0),
+            StatModifier::percent(StatKind::AttackDamage, 0.1),
+            StatModifier::percent(StatKind::AttackDamage, 0.1),
+            StatModifier::multiply(StatKind::AttackDamage, 1.5),
+            StatModifier::percent(StatKind::MoveSpeed, 0.5),
+        ];
+        let damage = apply_modifiers(StatKind::AttackDamage, 90.0, &modifiers);
+        assert!((damage - 180.0).abs() < 1e-3);
+        // 300 × 1.5 = 450 -> 415 + 35 × 0.8
+        let speed = apply_modifiers(StatKind::MoveSpeed, 300.0, &modifiers);
+        assert!((speed - 443.0).abs() < 1e-3);
+        assert_eq!(soft_cap_move_speed(600.0), 530.0);
+        assert_eq!(soft_cap_move_speed(100.0), 160.0);
+    }
+
+    #[test]
+    fn recompute_tracks_level_buffs_and_external_changes() {
+        let mut world = World::new();
+        let holder = world
+            .spawn((
+                Damage(60.0),
+                Level {
+                    value: 5,
+                    ..default()
+                },
+                StatGrowth {
+                    damage: 3.0,
+                    ..default()
+                },
+            ))
+            .id();
+        let buff = world
+            .spawn((
+                StatModifiers(vec![
+                    StatModifier::flat(StatKind::AttackDamage, 10.0),
+                    StatModifier::percent(StatKind::AttackDamage, 0.1),
+                ]),
+                BuffOf(holder),
+            ))
+            .id();
+        recompute_stats(&mut world, holder);
+        let level_bonus = growth_at_level(3.0, 5);
+        let expected = (60.0 + level_bonus + 10.0) * 1.1;
+        assert!((world.get::<Damage>(holder).unwrap().0 - expected).abs() < 1e-3);
+
+        // 旧逻辑直接改属性：计入原值，重算不覆盖
+        world.get_mut::<Damage>(holder).unwrap().0 += 5.0;
+        world.despawn(buff);
+        recompute_stats(&mut world, holder);
+        let damage = world.get::<Damage>(holder).unwrap().0;
+        assert!((damage - (65.0 + level_bonus)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn modifiers_grant_heal_shield_power() {
+        let mut world = World::new();
+        let holder = world.spawn_empty().id();
+        let buff = world
+            .spawn((
+                StatModifiers(vec![StatModifier::flat(StatKind::HealShieldPower, 0.15)]),
+                BuffOf(holder),
+            ))
+            .id();
+        recompute_stats(&mut world, holder);
+        assert_eq!(world.get::<HealShieldPower>(holder).unwrap().0, 0.15);
+
+        world.despawn(buff);
+        recompute_stats(&mut world, holder);
+        assert_eq!(world.get::<HealShieldPower>(holder).unwrap().0, 0.0);
+    }
+
+    #[test]
+    fn max_health_and_mana_keep_current_ratio() {
+        let mut world = World::new();
+        let holder = world
+            .spawn((
+                Health {
+                    value: 300.0,
+                    max: 600.0,
+                    ..default()
+                },
+                AbilityResource {
+                    value: 200.0,
+                    max: 400.0,
+                    per_level: 50.0,
+                    ..default()
+                },
+                Level {
+                    value: 3,
+                    ..default()
+                },
+            ))
+            .id();
+        let buff = world
+            .spawn((
+                StatModifiers(vec![StatModifier::flat(StatKind::MaxHealth, 200.0)]),
+                BuffOf(holder),
+            ))
+            .id();
+        recompute_stats(&mut world, holder);
+        let health = world.get::<Health>(holder).unwrap();
+        assert_eq!((health.value, health.max), (400.0, 800.0));
+        let mana_max = 400.0 + growth_at_level(50.0, 3);
+        let resource = world.get::<AbilityResource>(holder).unwrap();
+        assert!((resource.max - mana_max).abs() < 1e-3);
+        assert!((resource.value - mana_max / 2.0).abs() < 1e-3);
+
+        world.despawn(buff);
+        recompute_stats(&mut world, holder);
+        let health = world.get::<Health>(holder).unwrap();
+        assert_eq!((health.value, health.max), (300.0, 600.0));
+    }
+
+    #[test]
+    fn slow_is_applied_before_the_low_speed_soft_cap() {
+        let mut world = World::new();
+        let holder = world
+            .spawn((Movement { speed: 300.0 }, MovementSlow { percent: 0.5 }))
+            .id();
+        recompute_stats(&mut world, holder);
+        // 300 × 0.5 = 150 -> 220 以下按 50% 靠拢：150 × 0.5 + 110
+        assert_eq!(world.get::<Movement>(holder).unwrap().speed, 185.0);
+
+        world.entity_mut(holder).remove::<MovementSlow>();
+        recompute_stats(&mut world, holder);
+        assert_eq!(world.get::<Movement>(holder).unwrap().speed, 300.0);
+    }
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::entities::champion::Champion;
 use crate::entities::minion::Minion;
+use crate::heal::EventHealApplied;
 use crate::life::EventDead;
 
 /// 英雄统计数据组件，跟踪补刀与 KDA 统计
@@ -17,6 +18,14 @@ pub struct ChampionStats {
     pub assists: u32,
     /// 击杀小兵数 (Creep Score / 补刀数)
     pub minion_kills: u32,
+    /// 连杀数：上次死亡后的英雄击杀数，决定终结赏金
+    pub kill_streak: u32,
+    /// 连死数：上次击杀 / 助攻后的死亡数，降低自身赏金
+    pub death_streak: u32,
+    /// 累计从击杀赏金与助攻分成获得的金币
+    pub bounty_gold: f32,
+    /// 累计实际治疗量（不含溢出），按治疗来源统计
+    pub healing_done: f32,
 }
 
 /// 英雄统计插件
@@ -27,6 +36,7 @@ impl Plugin for PluginChampionStats {
     fn build(&self, app: &mut App) {
         app.register_type::<ChampionStats>();
         app.add_observer(on_event_dead);
+        app.add_observer(on_event_heal_applied);
     }
 }
 
@@ -77,3 +87,17 @@ pub fn on_event_dead(
         );
     }
 }
+
+/// 监听治疗结算，累计治疗来源的实际治疗量
+pub fn on_event_heal_applied(
+    trigger: On<EventHealApplied>,
+    mut q_stats: Query<&mut ChampionStats>,
+) {
+    let amount = trigger.heal_result.final_heal;
+    if amount <= 0.0 {
+        return;
+    }
+    if let Ok(mut stats) = q_stats.get_mut(trigger.source) {
+        stats.healing_done += amount;
+    }
+}
@@ -4,4 +4,5 @@ pub mod damage_reduction;
 pub mod on_hit;
 pub mod shield_magic;
 pub mod shield_white;
+pub mod spell_shield;
 pub mod turret_disabled;
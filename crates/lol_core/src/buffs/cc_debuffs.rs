@@ -1,38 +1,271 @@
+use bevy::ecs::component::Mutable;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
-use crate::base::buff::{Buff, BuffOf};
+use crate::attack::AttackBlock;
+use crate::attack_auto::{CommandAttackAutoStart, CommandAttackAutoStop};
+use crate::base::buff::{Buff, BuffAppExt, BuffDescriptor, BuffOf, BuffStacking, BuffTag, Buffs};
 use crate::buffs::common_buffs::BuffCastBlock;
-use crate::movement::{CastBlock, MovementBlock, MovementSlow};
+use crate::buffs::spell_shield::SpellShields;
+use crate::damage::EventDamageCreate;
+use crate::movement::{
+    CastBlock, CommandMovement, DashBlock, MovementAction, MovementBlock, MovementSlow,
+    MovementSource, MovementWay,
+};
 
-/// 控制标签：所有可被净化的 CC debuff 都 `#[require(ControlTag)]`。
-/// 净化（`CommandCleanse`）= 批量销毁角色身上带此标签的 buff 实体，
+/// 控制标签：所有控制 debuff 都 `#[require(ControlTag)]`。
+/// 净化（`CommandCleanse`）= 批量销毁角色身上带此标签、且按 [`CcKind::cleansed_by`] 可解的 buff 实体，
 /// 标记会随 buff 死亡（`On<Remove, ControlTag>`）自动清除。
 #[derive(Component, Default)]
 pub struct ControlTag;
 
+/// 经 [`crate::base::buff::BuffCommandsExt::apply_buff`] 施加控制时的描述：
+/// 各实例独立计时，记录来源供法术护盾区分敌我。
+pub fn cc_descriptor(source: Entity) -> BuffDescriptor {
+    BuffDescriptor::new(BuffStacking::Independent)
+        .with_max_stacks(u32::MAX)
+        .with_source(source)
+        .with_tags(&[BuffTag::Negative, BuffTag::CrowdControl])
+}
+
 /// 免疫控制标记：挂在角色上，供 CC 施加观察者极速查询。
 /// 由免控 buff（如 Olaf R）的 `On<Add/Remove>` 驱动加/删。
 #[derive(Component, Default)]
 pub struct ImmuneToCC;
 
-/// 眩晕
-#[derive(Component, Debug, Clone)]
-#[require(Buff = Buff { name: "Stun" }, ControlTag)]
-pub struct DebuffStun {
-    pub timer: Timer,
+/// 韧性（0.0-1.0）：按比例缩短受韧性影响的控制时长（见 [`CcKind::reduced_by_tenacity`]）。
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct Tenacity(pub f32);
+
+/// 减速抗性（0.0-1.0）：按比例削弱生效的减速比例。
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct SlowResist(pub f32);
+
+/// 控制类别。每个控制 debuff 经 `#[require(CrowdControl)]` 声明自己的类别，
+/// 封锁规则、韧性与净化语义都按类别查表，标记重算不再认识具体 buff 类型。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum CcKind {
+    Stun,
+    Slow,
+    Silence,
+    Fear,
+    Knockup,
+    Root,
+    Taunt,
+    Charm,
+    Suppression,
+    Sleep,
+    Blind,
+    Grounded,
+    Polymorph,
+    Nearsight,
+    Disarm,
 }
 
-impl DebuffStun {
-    pub fn new(duration: f32) -> Self {
-        Self {
-            timer: Timer::from_seconds(duration, TimerMode::Once),
+/// 一类控制封锁的行为。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CcBlocks {
+    /// 自主移动（`MovementBlock`）。
+    pub movement: bool,
+    /// 普攻（`AttackBlock`）。
+    pub attack: bool,
+    /// 施法（`CastBlock`）。
+    pub cast: bool,
+    /// 位移技能（`DashBlock`），到达时打断进行中的位移。
+    pub dash: bool,
+}
+
+/// 净化来源。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CleanseKind {
+    /// 召唤师技能「净化」与同类技能：不解压制与击飞。
+    #[default]
+    Cleanse,
+    /// 水银饰带 / 水银弯刀：解除压制，仍不解击飞。
+    Qss,
+}
+
+impl CcKind {
+    pub const fn blocks(self) -> CcBlocks {
+        let (movement, attack, cast, dash) = match self {
+            CcKind::Stun | CcKind::Suppression | CcKind::Sleep => (true, true, true, true),
+            // 恐惧 / 魅惑的移动由 `drive_forced_movement` 接管；无来源的恐惧原地停滞（见 sync）
+            CcKind::Fear | CcKind::Charm => (false, true, true, true),
+            // 击飞不加 MovementBlock：保留击退位移通路（见 action/knockback.rs），玩家指令由 ForcedAction 屏蔽
+            CcKind::Knockup => (false, true, true, true),
+            CcKind::Root => (true, false, false, true),
+            // 嘲讽：被迫普攻施加者，追击由自动攻击驱动
+            CcKind::Taunt => (false, false, true, true),
+            CcKind::Polymorph => (false, true, true, true),
+            CcKind::Silence => (false, false, true, false),
+            CcKind::Disarm => (false, true, false, false),
+            CcKind::Grounded => (false, false, false, true),
+            CcKind::Slow | CcKind::Blind | CcKind::Nearsight => (false, false, false, false),
+        };
+        CcBlocks {
+            movement,
+            attack,
+            cast,
+            dash,
+        }
+    }
+
+    /// 击飞与压制不受韧性影响。
+    pub const fn reduced_by_tenacity(self) -> bool {
+        !matches!(self, CcKind::Knockup | CcKind::Suppression)
+    }
+
+    pub const fn cleansed_by(self, kind: CleanseKind) -> bool {
+        match kind {
+            CleanseKind::Cleanse => !matches!(self, CcKind::Knockup | CcKind::Suppression),
+            CleanseKind::Qss => !matches!(self, CcKind::Knockup),
         }
     }
+
+    /// 接管角色行动（屏蔽玩家指令）的控制的优先级；多个同时生效时取最高者驱动 [`ForcedAction`]。
+    /// 硬控高于强制移动：眩晕期间被魅惑不会走动，眩晕结束后魅惑接管。
+    pub const fn order_priority(self) -> Option<u8> {
+        match self {
+            CcKind::Suppression => Some(6),
+            CcKind::Knockup => Some(5),
+            CcKind::Stun | CcKind::Sleep => Some(4),
+            CcKind::Charm => Some(3),
+            CcKind::Taunt => Some(2),
+            CcKind::Fear => Some(1),
+            _ => None,
+        }
+    }
+}
+
+/// 控制 debuff 的类别声明，由各 debuff 类型 `#[require]` 带上。
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CrowdControl(pub CcKind);
+
+impl Default for CrowdControl {
+    fn default() -> Self {
+        Self(CcKind::Stun)
+    }
+}
+
+/// 角色当前被哪类控制接管行动（由 CC 标记重算写入）。存在期间玩家的移动 / 攻击 / 施法指令被忽略。
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ForcedAction {
+    pub kind: CcKind,
+    pub source: Option<Entity>,
 }
 
-/// 减速
+/// 致盲标记：普攻落地时判定为未命中（不造成伤害、不触发命中特效）。
+#[derive(Component, Default)]
+pub struct Blinded;
+
+/// 视野缩减标记：智能体观测（`lol_agent` 的 `observe`）把敌方单位的可见半径收缩到 `radius`。
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Nearsighted {
+    pub radius: f32,
+}
+
+/// 带计时器的控制 buff：到期由 `tick_timed_debuffs` 销毁，施加时按韧性缩短。
+pub trait TimedDebuff: Component<Mutability = Mutable> {
+    fn timer(&self) -> &Timer;
+    fn timer_mut(&mut self) -> &mut Timer;
+}
+
+/// 定义只有计时器的控制 debuff。
+macro_rules! timed_cc_debuff {
+    ($(#[$meta:meta])* $name:ident, $buff:literal, $kind:ident) => {
+        $(#[$meta])*
+        #[derive(Component, Debug, Clone)]
+        #[require(Buff = Buff { name: $buff }, ControlTag, CrowdControl = CrowdControl(CcKind::$kind))]
+        pub struct $name {
+            pub timer: Timer,
+        }
+
+        impl $name {
+            pub fn new(duration: f32) -> Self {
+                Self {
+                    timer: Timer::from_seconds(duration, TimerMode::Once),
+                }
+            }
+        }
+
+        impl TimedDebuff for $name {
+            fn timer(&self) -> &Timer {
+                &self.timer
+            }
+
+            fn timer_mut(&mut self) -> &mut Timer {
+                &mut self.timer
+            }
+        }
+    };
+}
+
+timed_cc_debuff!(
+    /// 眩晕
+    DebuffStun,
+    "Stun",
+    Stun
+);
+timed_cc_debuff!(
+    /// 沉默
+    DebuffSilence,
+    "Silence",
+    Silence
+);
+timed_cc_debuff!(
+    /// 击飞（不受韧性减免；不加 MovementBlock，保留击退位移，见 action/knockback.rs）
+    DebuffKnockup,
+    "Knockup",
+    Knockup
+);
+timed_cc_debuff!(
+    /// 禁锢：不能移动与位移，仍可普攻和施法
+    DebuffRoot,
+    "Root",
+    Root
+);
+timed_cc_debuff!(
+    /// 压制：完全失控，不受韧性影响，只有水银类净化可解
+    DebuffSuppression,
+    "Suppression",
+    Suppression
+);
+timed_cc_debuff!(
+    /// 睡眠：完全失控，受到伤害即醒
+    DebuffSleep,
+    "Sleep",
+    Sleep
+);
+timed_cc_debuff!(
+    /// 致盲：普攻未命中
+    DebuffBlind,
+    "Blind",
+    Blind
+);
+timed_cc_debuff!(
+    /// 禁足：不能使用位移技能，可正常走动
+    DebuffGrounded,
+    "Grounded",
+    Grounded
+);
+timed_cc_debuff!(
+    /// 变形：不能普攻、施法与位移，可走动
+    DebuffPolymorph,
+    "Polymorph",
+    Polymorph
+);
+timed_cc_debuff!(
+    /// 缴械：不能普攻
+    DebuffDisarm,
+    "Disarm",
+    Disarm
+);
+
+/// 减速（多个减速只取最强者，不叠加）
 #[derive(Component, Debug, Clone)]
-#[require(Buff = Buff { name: "Slow" }, ControlTag)]
+#[require(Buff = Buff { name: "Slow" }, ControlTag, CrowdControl = CrowdControl(CcKind::Slow))]
 pub struct DebuffSlow {
     pub percent: f32, // 0.0-1.0
     pub timer: Timer,
@@ -47,121 +280,244 @@ impl DebuffSlow {
     }
 }
 
-/// 沉默
+/// 恐惧：被迫远离 `source`；无来源时原地停滞
 #[derive(Component, Debug, Clone)]
-#[require(Buff = Buff { name: "Silence" }, ControlTag)]
-pub struct DebuffSilence {
+#[require(Buff = Buff { name: "Fear" }, ControlTag, CrowdControl = CrowdControl(CcKind::Fear))]
+pub struct DebuffFear {
+    pub source: Option<Entity>,
     pub timer: Timer,
 }
 
-impl DebuffSilence {
+impl DebuffFear {
     pub fn new(duration: f32) -> Self {
         Self {
+            source: None,
             timer: Timer::from_seconds(duration, TimerMode::Once),
         }
     }
+
+    pub fn with_source(mut self, source: Entity) -> Self {
+        self.source = Some(source);
+        self
+    }
 }
 
-/// 恐惧
+/// 嘲讽：被迫普攻 `source`
 #[derive(Component, Debug, Clone)]
-#[require(Buff = Buff { name: "Fear" }, ControlTag)]
-pub struct DebuffFear {
+#[require(Buff = Buff { name: "Taunt" }, ControlTag, CrowdControl = CrowdControl(CcKind::Taunt))]
+pub struct DebuffTaunt {
+    pub source: Entity,
     pub timer: Timer,
 }
 
-impl DebuffFear {
-    pub fn new(duration: f32) -> Self {
+impl DebuffTaunt {
+    pub fn new(source: Entity, duration: f32) -> Self {
         Self {
+            source,
             timer: Timer::from_seconds(duration, TimerMode::Once),
         }
     }
 }
 
-/// 击飞（不受韧性减免；不加 MovementBlock，保留击退位移，见 action/knockback.rs）
+/// 魅惑：被迫走向 `source`
 #[derive(Component, Debug, Clone)]
-#[require(Buff = Buff { name: "Knockup" }, ControlTag)]
-pub struct DebuffKnockup {
+#[require(Buff = Buff { name: "Charm" }, ControlTag, CrowdControl = CrowdControl(CcKind::Charm))]
+pub struct DebuffCharm {
+    pub source: Entity,
     pub timer: Timer,
 }
 
-impl DebuffKnockup {
-    pub fn new(duration: f32) -> Self {
+impl DebuffCharm {
+    pub fn new(source: Entity, duration: f32) -> Self {
+        Self {
+            source,
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+        }
+    }
+}
+
+/// 致盲视野：可见半径缩至 `radius`（多个取最小）
+#[derive(Component, Debug, Clone)]
+#[require(Buff = Buff { name: "Nearsight" }, ControlTag, CrowdControl = CrowdControl(CcKind::Nearsight))]
+pub struct DebuffNearsight {
+    pub radius: f32,
+    pub timer: Timer,
+}
+
+impl DebuffNearsight {
+    pub fn new(radius: f32, duration: f32) -> Self {
         Self {
+            radius,
             timer: Timer::from_seconds(duration, TimerMode::Once),
         }
     }
 }
 
-/// 净化命令：销毁目标身上所有带 `ControlTag` 的 buff 实体。
-/// 不认识任何具体控制，只按标签批量杀人——角色标记随 buff 死亡自动清除。
+impl TimedDebuff for DebuffSlow {
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+impl TimedDebuff for DebuffFear {
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+impl TimedDebuff for DebuffTaunt {
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+impl TimedDebuff for DebuffCharm {
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+impl TimedDebuff for DebuffNearsight {
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+impl TimedDebuff for BuffCastBlock {
+    fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    fn timer_mut(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+/// 净化命令：销毁目标身上所有可被 `kind` 解除的控制 buff 实体。
+/// 只按类别查表批量杀人——角色标记随 buff 死亡自动清除。
 #[derive(EntityEvent, Debug, Clone)]
 pub struct CommandCleanse {
     pub entity: Entity,
+    pub kind: CleanseKind,
 }
 
-/// 重算角色身上的 CC 标记（MovementBlock / CastBlock / MovementSlow）。
-///
-/// 遍历所有 `BuffOf` 指向该角色的 buff 实体（排除 `excluding`），按类别取并集。
-/// 幂等：每次 CC buff 增删都调用一次，标记始终反映当前活跃 CC 的并集。
-/// 这是"Buff 自己管自己"的落点——buff 的增删事件驱动标记重算。
-fn sync_cc_markers(
-    commands: &mut Commands,
-    char: Entity,
-    excluding: Option<Entity>,
-    q_buffof: &Query<(Entity, &BuffOf)>,
-    q_stun: &Query<&DebuffStun>,
-    q_silence: &Query<&DebuffSilence>,
-    q_fear: &Query<&DebuffFear>,
-    q_slow: &Query<&DebuffSlow>,
-    q_knockup: &Query<&DebuffKnockup>,
-    q_cast_block: &Query<&BuffCastBlock>,
-) {
-    let mut move_block = false;
-    let mut cast_block = false;
-    let mut slow_percent = 0.0f32;
+/// 强制移动（魅惑 / 恐惧）的移动指令优先级：高于寻路与普攻追击，低于位移技能。
+pub const FORCED_MOVEMENT_PRIORITY: i32 = 50;
 
-    for (buff_entity, buffof) in q_buffof.iter() {
-        if buffof.0 != char {
-            continue;
-        }
-        if Some(buff_entity) == excluding {
-            continue;
-        }
-        if q_stun.get(buff_entity).is_ok() {
-            move_block = true;
-            cast_block = true;
-        }
-        if q_silence.get(buff_entity).is_ok() {
-            cast_block = true;
-        }
-        if q_fear.get(buff_entity).is_ok() {
-            move_block = true;
-            cast_block = true;
-        }
-        // 击飞不加 MovementBlock：保留击退位移通路（见 action/knockback.rs 注释）
-        if q_knockup.get(buff_entity).is_ok() {
-            cast_block = true;
-        }
-        if q_cast_block.get(buff_entity).is_ok() {
-            move_block = true;
-            cast_block = true;
-        }
-        if let Ok(slow) = q_slow.get(buff_entity) {
-            slow_percent = slow_percent.max(slow.percent);
-        }
-    }
+/// 恐惧每次重算的逃离步长。
+const FEAR_FLEE_STEP: f32 = 300.0;
 
-    if let Ok(mut entity) = commands.get_entity(char) {
-        if move_block {
-            entity.insert(MovementBlock);
-        } else {
-            entity.remove::<MovementBlock>();
-        }
-        if cast_block {
-            entity.insert(CastBlock);
-        } else {
-            entity.remove::<CastBlock>();
+type CcBuffData = (
+    &'static CrowdControl,
+    Option<&'static DebuffSlow>,
+    Option<&'static DebuffNearsight>,
+    (
+        Option<&'static DebuffTaunt>,
+        Option<&'static DebuffCharm>,
+        Option<&'static DebuffFear>,
+    ),
+);
+
+/// 重算角色 CC 标记所需的查询集合。
+#[derive(SystemParam)]
+pub struct CcSync<'w, 's> {
+    commands: Commands<'w, 's>,
+    q_buffof: Query<'w, 's, &'static BuffOf>,
+    q_buffs: Query<'w, 's, &'static Buffs>,
+    q_cc: Query<'w, 's, CcBuffData>,
+    q_cast_block: Query<'w, 's, (), With<BuffCastBlock>>,
+    q_char: Query<'w, 's, (Option<&'static SlowResist>, Option<&'static ForcedAction>)>,
+}
+
+impl CcSync<'_, '_> {
+    /// 重算角色身上的 CC 标记（MovementBlock / DashBlock / AttackBlock / CastBlock / MovementSlow /
+    /// Blinded / Nearsighted / ForcedAction）。
+    ///
+    /// 遍历该角色 [`Buffs`] 中的 buff 实体（排除 `excluding`），按类别查表取并集。
+    /// 幂等：每次 CC buff 增删都调用一次，标记始终反映当前活跃 CC 的并集。
+    /// 这是"Buff 自己管自己"的落点——buff 的增删事件驱动标记重算。
+    fn sync(&mut self, char: Entity, excluding: Option<Entity>) {
+        let mut blocks = CcBlocks::default();
+        let mut slow_percent = 0.0f32;
+        let mut blind = false;
+        let mut nearsight: Option<f32> = None;
+        let mut forced: Option<(u8, ForcedAction)> = None;
+
+        let buffs = self
+            .q_buffs
+            .get(char)
+            .map(|b| b.as_slice())
+            .unwrap_or_default();
+        for &buff_entity in buffs {
+            if Some(buff_entity) == excluding {
+                continue;
+            }
+            if self.q_cast_block.get(buff_entity).is_ok() {
+                blocks.movement = true;
+                blocks.cast = true;
+            }
+            let Ok((cc, slow, near, (taunt, charm, fear))) = self.q_cc.get(buff_entity) else {
+                continue;
+            };
+            let kind = cc.0;
+            let source = taunt
+                .map(|t| t.source)
+                .or(charm.map(|c| c.source))
+                .or(fear.and_then(|f| f.source));
+
+            let b = kind.blocks();
+            blocks.movement |= b.movement || (kind == CcKind::Fear && source.is_none());
+            blocks.attack |= b.attack;
+            blocks.cast |= b.cast;
+            blocks.dash |= b.dash;
+            blind |= kind == CcKind::Blind;
+            if let Some(slow) = slow {
+                slow_percent = slow_percent.max(slow.percent);
+            }
+            if let Some(near) = near {
+                nearsight = Some(nearsight.map_or(near.radius, |r| r.min(near.radius)));
+            }
+            if let Some(priority) = kind.order_priority()
+                && forced.is_none_or(|(current, _)| priority > current)
+            {
+                forced = Some((priority, ForcedAction { kind, source }));
+            }
         }
+
+        let (slow_resist, current_forced) = self.q_char.get(char).unwrap_or((None, None));
+        let slow_percent = slow_percent * (1.0 - slow_resist.map_or(0.0, |r| r.0.clamp(0.0, 1.0)));
+        let forced = forced.map(|(_, f)| f);
+        let forced_changed = current_forced.copied() != forced;
+
+        let Ok(mut entity) = self.commands.get_entity(char) else {
+            return;
+        };
+        // MovementBlock 先于 DashBlock 插入：两者的打断位移观察者只需处理一次
+        toggle_marker::<MovementBlock>(&mut entity, blocks.movement);
+        toggle_marker::<DashBlock>(&mut entity, blocks.dash);
+        toggle_marker::<AttackBlock>(&mut entity, blocks.attack);
+        toggle_marker::<CastBlock>(&mut entity, blocks.cast);
+        toggle_marker::<Blinded>(&mut entity, blind);
         if slow_percent > 0.0 {
             entity.insert(MovementSlow {
                 percent: slow_percent,
@@ -169,215 +525,282 @@ fn sync_cc_markers(
         } else {
             entity.remove::<MovementSlow>();
         }
+        match nearsight {
+            Some(radius) => {
+                entity.insert(Nearsighted { radius });
+            }
+            None => {
+                entity.remove::<Nearsighted>();
+            }
+        }
+        if forced_changed {
+            match forced {
+                Some(forced) => {
+                    entity.insert(forced);
+                }
+                None => {
+                    entity.remove::<ForcedAction>();
+                }
+            }
+        }
+    }
+
+    fn char_of(&self, buff_entity: Entity) -> Option<Entity> {
+        self.q_buffof.get(buff_entity).ok().map(|b| b.0)
     }
 }
 
-/// `On<Add, ControlTag>`：任一可净化 CC buff 生成时触发。
-/// 若角色免控，立即销毁该 buff（CC 不沾身）；否则重算标记。
+fn toggle_marker<T: Component + Default>(entity: &mut EntityCommands, on: bool) {
+    if on {
+        entity.insert(T::default());
+    } else {
+        entity.remove::<T>();
+    }
+}
+
+/// `On<Add, ControlTag>`：任一控制 buff 生成时触发。
+/// 若角色免控或法术护盾挡下，立即销毁该 buff（CC 不沾身）；否则重算标记。
 fn on_add_control(
     trigger: On<Add, ControlTag>,
-    mut commands: Commands,
-    q_buffof: Query<(Entity, &BuffOf)>,
+    mut sync: CcSync,
     q_immune: Query<(), With<ImmuneToCC>>,
-    q_stun: Query<&DebuffStun>,
-    q_silence: Query<&DebuffSilence>,
-    q_fear: Query<&DebuffFear>,
-    q_slow: Query<&DebuffSlow>,
-    q_knockup: Query<&DebuffKnockup>,
-    q_cast_block: Query<&BuffCastBlock>,
+    q_descriptor: Query<&BuffDescriptor>,
+    shields: SpellShields,
 ) {
     let buff_entity = trigger.entity;
-    let Ok((_, buffof)) = q_buffof.get(buff_entity) else {
+    let Some(char) = sync.char_of(buff_entity) else {
         return;
     };
-    let char = buffof.0;
 
-    // 免控：CC 不沾身，立即销毁（On<Remove> 会重算，不会留下标记）
-    if q_immune.get(char).is_ok() {
-        commands.entity(buff_entity).despawn();
+    // 来源取自 buff 描述，或嘲讽 / 魅惑 / 恐惧自带的来源
+    let source = q_descriptor
+        .get(buff_entity)
+        .ok()
+        .and_then(|descriptor| descriptor.source)
+        .or_else(|| {
+            let (_, _, _, (taunt, charm, fear)) = sync.q_cc.get(buff_entity).ok()?;
+            taunt
+                .map(|t| t.source)
+                .or(charm.map(|c| c.source))
+                .or(fear.and_then(|f| f.source))
+        });
+
+    // 免控或被法术护盾挡下：CC 不沾身，立即销毁（On<Remove> 会重算，不会留下标记）
+    if q_immune.get(char).is_ok() || shields.block_cc(&mut sync.commands, char, source) {
+        sync.commands.entity(buff_entity).despawn();
         return;
     }
 
-    sync_cc_markers(
-        &mut commands,
-        char,
-        None,
-        &q_buffof,
-        &q_stun,
-        &q_silence,
-        &q_fear,
-        &q_slow,
-        &q_knockup,
-        &q_cast_block,
-    );
+    sync.sync(char, None);
 }
 
-/// `On<Remove, ControlTag>`：任一可净化 CC buff 消亡（过期/净化/免疫销毁）时触发。
+/// `On<Remove, ControlTag>`：任一控制 buff 消亡（过期/净化/免疫销毁）时触发。
 /// 排除自身后重算标记——多 buff 叠加时移除其一不会误清标记。
-fn on_remove_control(
-    trigger: On<Remove, ControlTag>,
-    mut commands: Commands,
-    q_buffof: Query<(Entity, &BuffOf)>,
-    q_stun: Query<&DebuffStun>,
-    q_silence: Query<&DebuffSilence>,
-    q_fear: Query<&DebuffFear>,
-    q_slow: Query<&DebuffSlow>,
-    q_knockup: Query<&DebuffKnockup>,
-    q_cast_block: Query<&BuffCastBlock>,
-) {
+fn on_remove_control(trigger: On<Remove, ControlTag>, mut sync: CcSync) {
     let buff_entity = trigger.entity;
-    let Ok((_, buffof)) = q_buffof.get(buff_entity) else {
-        // buff 实体可能在 despawn 过程中已不可查；标记由其它存活 buff 的增删或计时收敛
+    // buff 实体可能在 despawn 过程中已不可查；标记由其它存活 buff 的增删或计时收敛
+    let Some(char) = sync.char_of(buff_entity) else {
         return;
     };
-    let char = buffof.0;
-    sync_cc_markers(
-        &mut commands,
-        char,
-        Some(buff_entity),
-        &q_buffof,
-        &q_stun,
-        &q_silence,
-        &q_fear,
-        &q_slow,
-        &q_knockup,
-        &q_cast_block,
-    );
+    sync.sync(char, Some(buff_entity));
 }
 
 /// `BuffCastBlock`（自施法锁，非 ControlTag、不可净化）的标记桥接。
 /// 自施法锁不被免控拦截（自身技能 windup 不受 Olaf R 影响）。
-fn on_add_cast_block(
-    trigger: On<Add, BuffCastBlock>,
-    mut commands: Commands,
-    q_buffof: Query<(Entity, &BuffOf)>,
-    q_stun: Query<&DebuffStun>,
-    q_silence: Query<&DebuffSilence>,
-    q_fear: Query<&DebuffFear>,
-    q_slow: Query<&DebuffSlow>,
-    q_knockup: Query<&DebuffKnockup>,
-    q_cast_block: Query<&BuffCastBlock>,
-) {
+fn on_add_cast_block(trigger: On<Add, BuffCastBlock>, mut sync: CcSync) {
+    let Some(char) = sync.char_of(trigger.entity) else {
+        return;
+    };
+    sync.sync(char, None);
+}
+
+fn on_remove_cast_block(trigger: On<Remove, BuffCastBlock>, mut sync: CcSync) {
     let buff_entity = trigger.entity;
-    let Ok((_, buffof)) = q_buffof.get(buff_entity) else {
+    let Some(char) = sync.char_of(buff_entity) else {
         return;
     };
-    let char = buffof.0;
-    sync_cc_markers(
-        &mut commands,
-        char,
-        None,
-        &q_buffof,
-        &q_stun,
-        &q_silence,
-        &q_fear,
-        &q_slow,
-        &q_knockup,
-        &q_cast_block,
-    );
+    sync.sync(char, Some(buff_entity));
 }
 
-fn on_remove_cast_block(
-    trigger: On<Remove, BuffCastBlock>,
-    mut commands: Commands,
-    q_buffof: Query<(Entity, &BuffOf)>,
-    q_stun: Query<&DebuffStun>,
-    q_silence: Query<&DebuffSilence>,
-    q_fear: Query<&DebuffFear>,
-    q_slow: Query<&DebuffSlow>,
-    q_knockup: Query<&DebuffKnockup>,
-    q_cast_block: Query<&BuffCastBlock>,
+/// 施加时按角色韧性缩短受韧性影响的控制时长。
+fn apply_tenacity<T: TimedDebuff>(
+    trigger: On<Add, T>,
+    q_cc: Query<(&BuffOf, &CrowdControl)>,
+    q_tenacity: Query<&Tenacity>,
+    mut q_debuff: Query<&mut T>,
 ) {
     let buff_entity = trigger.entity;
-    let Ok((_, buffof)) = q_buffof.get(buff_entity) else {
+    let Ok((buffof, cc)) = q_cc.get(buff_entity) else {
         return;
     };
-    let char = buffof.0;
-    sync_cc_markers(
-        &mut commands,
-        char,
-        Some(buff_entity),
-        &q_buffof,
-        &q_stun,
-        &q_silence,
-        &q_fear,
-        &q_slow,
-        &q_knockup,
-        &q_cast_block,
-    );
+    if !cc.0.reduced_by_tenacity() {
+        return;
+    }
+    let Ok(tenacity) = q_tenacity.get(buffof.0) else {
+        return;
+    };
+    let Ok(mut debuff) = q_debuff.get_mut(buff_entity) else {
+        return;
+    };
+    let timer = debuff.timer_mut();
+    let duration = timer.duration().mul_f32(1.0 - tenacity.0.clamp(0.0, 1.0));
+    timer.set_duration(duration);
 }
 
-/// 净化即杀人：销毁目标身上所有带 `ControlTag` 的 buff 实体。
+/// 净化即杀人：销毁目标身上所有可被本次净化解除的控制 buff 与描述为可驱散的 buff 实体。
 fn on_command_cleanse(
     trigger: On<CommandCleanse>,
     mut commands: Commands,
-    q_buffof: Query<(Entity, &BuffOf)>,
-    q_control: Query<(), With<ControlTag>>,
+    q_buffs: Query<&Buffs>,
+    q_control: Query<&CrowdControl, With<ControlTag>>,
+    q_descriptor: Query<&BuffDescriptor>,
 ) {
-    let char = trigger.event_target();
-    for (buff_entity, buffof) in q_buffof.iter() {
-        if buffof.0 != char {
-            continue;
-        }
-        if q_control.get(buff_entity).is_ok() {
+    let Ok(buffs) = q_buffs.get(trigger.event_target()) else {
+        return;
+    };
+    for buff_entity in buffs.iter() {
+        let cleansed = q_control
+            .get(buff_entity)
+            .is_ok_and(|cc| cc.0.cleansed_by(trigger.kind));
+        let dispelled = q_descriptor
+            .get(buff_entity)
+            .is_ok_and(|descriptor| descriptor.dispellable);
+        if cleansed || dispelled {
             commands.entity(buff_entity).despawn();
         }
     }
-    debug!("净化: 销毁 {:?} 身上所有控制 buff", char);
+    debug!(
+        "净化({:?}): 销毁 {:?} 身上可解除的控制 buff",
+        trigger.kind, char
+    );
 }
 
-/// 全局 CC 计时：tick 所有 CC debuff 与自施法锁的 timer，过期则销毁实体
-/// （销毁触发 `On<Remove, ControlTag>` -> 标记自动重算）。
-fn update_cc_buff_timers(
+/// 睡眠受到伤害即醒。
+fn on_damage_wake_sleep(
+    trigger: On<EventDamageCreate>,
     mut commands: Commands,
-    time: Res<Time<Fixed>>,
-    mut q_stun: Query<(Entity, &mut DebuffStun)>,
-    mut q_slow: Query<(Entity, &mut DebuffSlow)>,
-    mut q_silence: Query<(Entity, &mut DebuffSilence)>,
-    mut q_fear: Query<(Entity, &mut DebuffFear)>,
-    mut q_knockup: Query<(Entity, &mut DebuffKnockup)>,
-    mut q_cast_block: Query<(Entity, &mut BuffCastBlock)>,
+    q_buffs: Query<&Buffs>,
+    q_sleep: Query<(), With<DebuffSleep>>,
 ) {
-    let delta = time.delta();
-
-    for (entity, mut buff) in q_stun.iter_mut() {
-        buff.timer.tick(delta);
-        if buff.timer.is_finished() {
-            commands.entity(entity).despawn();
+    let Ok(buffs) = q_buffs.get(trigger.event_target()) else {
+        return;
+    };
+    for buff_entity in buffs.iter() {
+        if q_sleep.get(buff_entity).is_ok() {
+            commands.entity(buff_entity).despawn();
         }
     }
-    for (entity, mut buff) in q_slow.iter_mut() {
-        buff.timer.tick(delta);
-        if buff.timer.is_finished() {
-            commands.entity(entity).despawn();
+}
+
+/// 行动被接管时切换普攻：嘲讽强制攻击施加者，魅惑 / 恐惧停止当前攻击。
+fn on_insert_forced_action(
+    trigger: On<Insert, ForcedAction>,
+    mut commands: Commands,
+    q_forced: Query<&ForcedAction>,
+) {
+    let entity = trigger.entity;
+    let Ok(forced) = q_forced.get(entity) else {
+        return;
+    };
+    match (forced.kind, forced.source) {
+        (CcKind::Taunt, Some(target)) => {
+            commands.trigger(CommandAttackAutoStart { entity, target });
         }
-    }
-    for (entity, mut buff) in q_silence.iter_mut() {
-        buff.timer.tick(delta);
-        if buff.timer.is_finished() {
-            commands.entity(entity).despawn();
+        _ => {
+            commands.trigger(CommandAttackAutoStop { entity });
         }
     }
-    for (entity, mut buff) in q_fear.iter_mut() {
-        buff.timer.tick(delta);
-        if buff.timer.is_finished() {
-            commands.entity(entity).despawn();
+}
+
+/// 接管结束：停下强制移动 / 强制攻击，把控制权交还玩家。
+fn on_remove_forced_action(
+    trigger: On<Remove, ForcedAction>,
+    mut commands: Commands,
+    q_forced: Query<&ForcedAction>,
+) {
+    let entity = trigger.entity;
+    let Ok(forced) = q_forced.get(entity) else {
+        return;
+    };
+    match forced.kind {
+        CcKind::Taunt => {
+            commands.trigger(CommandAttackAutoStop { entity });
         }
-    }
-    for (entity, mut buff) in q_knockup.iter_mut() {
-        buff.timer.tick(delta);
-        if buff.timer.is_finished() {
-            commands.entity(entity).despawn();
+        CcKind::Charm | CcKind::Fear if forced.source.is_some() => {
+            commands.trigger(CommandMovement {
+                entity,
+                priority: FORCED_MOVEMENT_PRIORITY,
+                action: MovementAction::Stop,
+            });
         }
+        _ => {}
+    }
+}
+
+/// 每 tick 驱动强制移动：魅惑走向施加者，恐惧背离施加者。
+fn drive_forced_movement(
+    mut commands: Commands,
+    q_forced: Query<(Entity, &ForcedAction, &Transform), Without<MovementBlock>>,
+    q_transform: Query<&Transform>,
+) {
+    for (entity, forced, transform) in q_forced.iter() {
+        let Some(source) = forced.source else {
+            continue;
+        };
+        let Ok(source_transform) = q_transform.get(source) else {
+            continue;
+        };
+        let pos = transform.translation;
+        let destination = match forced.kind {
+            CcKind::Charm => source_transform.translation,
+            CcKind::Fear => {
+                let away = (pos - source_transform.translation)
+                    .xz()
+                    .normalize_or_zero();
+                let away = if away == Vec2::ZERO {
+                    transform.forward().xz()
+                } else {
+                    away
+                };
+                pos + Vec3::new(away.x, 0.0, away.y) * FEAR_FLEE_STEP
+            }
+            _ => continue,
+        };
+        commands.trigger(CommandMovement {
+            entity,
+            priority: FORCED_MOVEMENT_PRIORITY,
+            action: MovementAction::Start {
+                way: MovementWay::Path(vec![destination]),
+                speed: None,
+                source: MovementSource::Run,
+            },
+        });
     }
-    for (entity, mut buff) in q_cast_block.iter_mut() {
-        buff.timer.tick(delta);
-        if buff.timer.is_finished() {
+}
+
+/// 计时：tick 控制 debuff 与自施法锁的 timer，过期则销毁实体
+/// （销毁触发 `On<Remove, ControlTag>` -> 标记自动重算）。
+fn tick_timed_debuffs<T: TimedDebuff>(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    mut q_debuff: Query<(Entity, &mut T)>,
+) {
+    let delta = time.delta();
+    for (entity, mut debuff) in q_debuff.iter_mut() {
+        let timer = debuff.timer_mut();
+        timer.tick(delta);
+        if timer.is_finished() {
             commands.entity(entity).despawn();
         }
     }
 }
 
+fn register_timed_debuff<T: TimedDebuff>(app: &mut App) {
+    app.add_observer(apply_tenacity::<T>);
+    app.add_systems(FixedUpdate, tick_timed_debuffs::<T>);
+    app.register_buff_timer::<T>(T::timer, T::timer_mut);
+}
+
 #[derive(Default)]
 pub struct PluginCc;
 
@@ -388,7 +811,27 @@ impl Plugin for PluginCc {
         app.add_observer(on_add_cast_block);
         app.add_observer(on_remove_cast_block);
         app.add_observer(on_command_cleanse);
-        app.add_systems(FixedUpdate, update_cc_buff_timers);
+        app.add_observer(on_damage_wake_sleep);
+        app.add_observer(on_insert_forced_action);
+        app.add_observer(on_remove_forced_action);
+        app.add_systems(FixedUpdate, drive_forced_movement);
+
+        register_timed_debuff::<DebuffStun>(app);
+        register_timed_debuff::<DebuffSlow>(app);
+        register_timed_debuff::<DebuffSilence>(app);
+        register_timed_debuff::<DebuffFear>(app);
+        register_timed_debuff::<DebuffKnockup>(app);
+        register_timed_debuff::<DebuffRoot>(app);
+        register_timed_debuff::<DebuffTaunt>(app);
+        register_timed_debuff::<DebuffCharm>(app);
+        register_timed_debuff::<DebuffSuppression>(app);
+        register_timed_debuff::<DebuffSleep>(app);
+        register_timed_debuff::<DebuffBlind>(app);
+        register_timed_debuff::<DebuffGrounded>(app);
+        register_timed_debuff::<DebuffPolymorph>(app);
+        register_timed_debuff::<DebuffNearsight>(app);
+        register_timed_debuff::<DebuffDisarm>(app);
+        register_timed_debuff::<BuffCastBlock>(app);
     }
 }
 
@@ -399,6 +842,7 @@ mod tests {
 
     use super::*;
     use crate::base::buff::{BuffOf, Buffs};
+    use crate::buffs::spell_shield::BuffSpellShield;
     use crate::movement::{CastBlock, MovementBlock, MovementSlow};
     use crate::team::Team;
 
@@ -531,7 +975,10 @@ mod tests {
         // 净化即杀人：销毁所有 ControlTag buff
         app.world_mut()
             .entity_mut(char)
-            .trigger(|e| CommandCleanse { entity: e });
+            .trigger(|e| CommandCleanse {
+                entity: e,
+                kind: CleanseKind::Cleanse,
+            });
         app.update();
         for _ in 0..2 {
             app.update();
@@ -567,6 +1014,22 @@ mod tests {
         assert!(app.world().get::<CastBlock>(char).is_none());
     }
 
+    #[test]
+    fn spell_shield_blocks_cc_once() {
+        let mut app = app_with_cc();
+        let char = spawn_char(&mut app);
+        app.world_mut()
+            .entity_mut(char)
+            .with_related::<BuffOf>(BuffSpellShield::new(1.5));
+        apply_cc(&mut app, char, DebuffStun::new(1.0));
+        assert!(
+            app.world().get::<MovementBlock>(char).is_none(),
+            "法术护盾应挡下控制"
+        );
+        let mut q = app.world_mut().query::<&BuffSpellShield>();
+        assert_eq!(q.iter(app.world()).count(), 0, "法术护盾应被消耗");
+    }
+
     #[test]
     fn cast_block_buff_adds_markers_and_not_cleansed() {
         let mut app = app_with_cc();
@@ -577,7 +1040,10 @@ mod tests {
         // 净化不应移除自施法锁（非 ControlTag）
         app.world_mut()
             .entity_mut(char)
-            .trigger(|e| CommandCleanse { entity: e });
+            .trigger(|e| CommandCleanse {
+                entity: e,
+                kind: CleanseKind::Cleanse,
+            });
         for _ in 0..2 {
             app.update();
         }
@@ -587,6 +1053,178 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cleanse_removes_dispellable_buffs_and_registry_reports_timers() {
+        use crate::base::buff::{BuffStacking, active_buffs, apply_buff};
+        use crate::buffs::common_buffs::DebuffGrievousWounds;
+
+        let mut app = app_with_cc();
+        let char = spawn_char(&mut app);
+        apply_cc(&mut app, char, DebuffStun::new(1.0));
+        let world = app.world_mut();
+        let dispellable = BuffDescriptor::new(BuffStacking::Renew).dispellable();
+        let wounds = apply_buff(
+            world,
+            char,
+            dispellable,
+            DebuffGrievousWounds::new(0.4, 3.0),
+        )
+        .unwrap();
+        let kept = apply_buff(
+            world,
+            char,
+            BuffDescriptor::new(BuffStacking::Renew).with_duration(3.0),
+            BuffCastBlock::new(3.0),
+        )
+        .unwrap();
+
+        let active = active_buffs(app.world(), char);
+        let stun = active.iter().find(|b| b.name == "Stun").unwrap();
+        assert_eq!(stun.duration, Some(1.0));
+        assert!(stun.remaining.unwrap() <= 1.0);
+
+        app.world_mut()
+            .entity_mut(char)
+            .trigger(|e| CommandCleanse {
+                entity: e,
+                kind: CleanseKind::Cleanse,
+            });
+        app.update();
+        assert!(app.world().get_entity(wounds).is_err());
+        assert!(app.world().get_entity(kept).is_ok());
+    }
+
+    #[test]
+    fn root_blocks_movement_and_dash_but_not_cast() {
+        let mut app = app_with_cc();
+        let char = spawn_char(&mut app);
+        apply_cc(&mut app, char, DebuffRoot::new(1.0));
+        assert!(app.world().get::<MovementBlock>(char).is_some());
+        assert!(app.world().get::<DashBlock>(char).is_some());
+        assert!(app.world().get::<CastBlock>(char).is_none());
+        assert!(app.world().get::<AttackBlock>(char).is_none());
+        assert!(app.world().get::<ForcedAction>(char).is_none());
+    }
+
+    #[test]
+    fn tenacity_shortens_stun_but_not_knockup() {
+        let mut app = app_with_cc();
+        let char = spawn_char(&mut app);
+        app.world_mut().entity_mut(char).insert(Tenacity(0.5));
+        apply_cc(&mut app, char, DebuffStun::new(1.0));
+        apply_cc(&mut app, char, DebuffKnockup::new(1.0));
+
+        let buffs: Vec<Entity> = app.world().get::<Buffs>(char).unwrap().iter().collect();
+        let stun = buffs
+            .iter()
+            .find_map(|b| app.world().get::<DebuffStun>(*b))
+            .unwrap();
+        assert!((stun.timer.duration().as_secs_f32() - 0.5).abs() < 1e-4);
+        let knockup = buffs
+            .iter()
+            .find_map(|b| app.world().get::<DebuffKnockup>(*b))
+            .unwrap();
+        assert!((knockup.timer.duration().as_secs_f32() - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn cleanse_kind_decides_suppression() {
+        let mut app = app_with_cc();
+        let char = spawn_char(&mut app);
+        apply_cc(&mut app, char, DebuffSuppression::new(2.0));
+        apply_cc(&mut app, char, DebuffKnockup::new(2.0));
+
+        app.world_mut()
+            .entity_mut(char)
+            .trigger(|e| CommandCleanse {
+                entity: e,
+                kind: CleanseKind::Cleanse,
+            });
+        app.update();
+        assert!(
+            app.world().get::<MovementBlock>(char).is_some(),
+            "净化不解压制"
+        );
+
+        app.world_mut()
+            .entity_mut(char)
+            .trigger(|e| CommandCleanse {
+                entity: e,
+                kind: CleanseKind::Qss,
+            });
+        app.update();
+        assert!(
+            app.world().get::<MovementBlock>(char).is_none(),
+            "水银解压制"
+        );
+        let forced = app.world().get::<ForcedAction>(char).expect("击飞仍在");
+        assert_eq!(forced.kind, CcKind::Knockup);
+    }
+
+    #[test]
+    fn hard_cc_overrides_charm_until_expired() {
+        let mut app = app_with_cc();
+        let char = spawn_char(&mut app);
+        let source = spawn_char(&mut app);
+        apply_cc(&mut app, char, DebuffCharm::new(source, 1.0));
+        assert_eq!(
+            app.world().get::<ForcedAction>(char).unwrap().kind,
+            CcKind::Charm
+        );
+        assert!(app.world().get::<MovementBlock>(char).is_none());
+
+        apply_cc(&mut app, char, DebuffStun::new(0.1));
+        assert_eq!(
+            app.world().get::<ForcedAction>(char).unwrap().kind,
+            CcKind::Stun
+        );
+        for _ in 0..10 {
+            app.update();
+        }
+        let forced = app.world().get::<ForcedAction>(char).unwrap();
+        assert_eq!(forced.kind, CcKind::Charm);
+        assert_eq!(forced.source, Some(source));
+    }
+
+    #[test]
+    fn slow_resist_scales_strongest_slow() {
+        let mut app = app_with_cc();
+        let char = spawn_char(&mut app);
+        app.world_mut().entity_mut(char).insert(SlowResist(0.5));
+        apply_cc(&mut app, char, DebuffSlow::new(0.3, 1.0));
+        apply_cc(&mut app, char, DebuffSlow::new(0.6, 1.0));
+        let slow = app.world().get::<MovementSlow>(char).unwrap();
+        assert!((slow.percent - 0.3).abs() < 1e-4);
+    }
+
+    #[test]
+    fn sleep_wakes_on_damage() {
+        let mut app = app_with_cc();
+        let char = spawn_char(&mut app);
+        apply_cc(&mut app, char, DebuffSleep::new(2.0));
+        assert!(app.world().get::<MovementBlock>(char).is_some());
+
+        app.world_mut()
+            .entity_mut(char)
+            .trigger(|e| EventDamageCreate {
+                entity: e,
+                source: e,
+                damage_type: crate::damage::DamageType::Magic,
+                damage_result: crate::damage::DamageResult {
+                    final_damage: 10.0,
+                    white_shield_absorbed: 0.0,
+                    magic_shield_absorbed: 0.0,
+                    reduced_damage: 0.0,
+                    armor_reduced_damage: 0.0,
+                    original_damage: 10.0,
+                },
+                tag: None,
+                kind: crate::damage::DamageKind::Other,
+            });
+        app.update();
+        assert!(app.world().get::<MovementBlock>(char).is_none());
+    }
+
     /// 减速应真正降低移动速度：同样路径下，被 DebuffSlow 影响的实体移动距离更短。
     /// 这覆盖所有用 `with_related(DebuffSlow)` 的技能（Fiora E / Camille / Darius E / on-hit）。
     #[test]
@@ -595,6 +1233,7 @@ mod tests {
         use lol_base::spell::Spell;
 
         use crate::action::PluginAction;
+        use crate::base::stat_modifier::PluginStatModifier;
         use crate::movement::{
             CommandMovement, Movement, MovementAction, MovementSource, MovementWay, PluginMovement,
         };
@@ -608,6 +1247,7 @@ mod tests {
         app.add_plugins(PluginMovement);
         app.add_plugins(PluginNavigaton);
         app.add_plugins(PluginCc);
+        app.add_plugins(PluginStatModifier);
         app.init_asset::<Spell>();
         app.insert_resource(Time::<Fixed>::from_hz(30.0));
         app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
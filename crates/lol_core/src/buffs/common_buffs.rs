@@ -1,8 +1,8 @@
 use bevy::prelude::*;
 
-use crate::base::buff::{Buff, BuffOf};
-use crate::life::Health;
-use crate::movement::Movement;
+use crate::base::buff::{Buff, BuffAppExt, BuffDescriptor, BuffOf, BuffStacking, BuffTag, Buffs};
+use crate::base::stat_modifier::{StatKind, StatModifier, StatModifiers};
+use crate::heal::{CommandHeal, HealType};
 
 /// 施法期间阻塞 buff（通用）
 /// 阻止移动和技能施放。
@@ -26,34 +26,33 @@ impl BuffCastBlock {
 
 /// 移动速度加成 buff（通用）
 ///
-/// 首次 tick 把 `bonus_percent * 持有者当前移速` 加到 `Movement.speed`，到期精确回退并销毁。
-/// `applied`/`applied_bonus` 保证幂等应用与精确回退，多 buff 叠加各自独立记账。
+/// 施加时在 buff 实体上挂 `bonus_percent` 的移速百分比修正（见 `stat_modifier`），
+/// 按 [`Self::descriptor`] 计时，到期销毁即撤回。
+/// 多个加成百分比相加后统一结算软上限。
 /// 被动击破要害、R 大招期间、Aatrox/Sett/Kayn/Hecarim/Volibear/MasterYi 的移速增益共用此 buff。
 #[derive(Component, Debug, Clone)]
 #[require(Buff = Buff { name: "MoveSpeed" })]
 pub struct BuffMoveSpeed {
     pub bonus_percent: f32,
-    pub timer: Timer,
-    pub applied: bool,
-    pub applied_bonus: f32,
 }
 
 impl BuffMoveSpeed {
-    pub fn new(bonus_percent: f32, duration: f32) -> Self {
-        Self {
-            bonus_percent,
-            timer: Timer::from_seconds(duration, TimerMode::Once),
-            applied: false,
-            applied_bonus: 0.0,
-        }
+    pub fn new(bonus_percent: f32) -> Self {
+        Self { bonus_percent }
+    }
+
+    /// 不同来源的加成各自计时、互不覆盖
+    pub fn descriptor(duration: f32) -> BuffDescriptor {
+        BuffDescriptor::new(BuffStacking::Independent)
+            .with_max_stacks(u32::MAX)
+            .with_duration(duration)
+            .with_tags(&[BuffTag::Positive, BuffTag::Movement])
     }
 }
 
 /// 双抗加成 buff（通用）
 ///
-/// 注意：当前 `magic_resist` 字段尚无对应 `MagicResist` 原语与伤害结算通路，
-/// 故本 buff 暂无生效系统（见体检报告 F3）。仅 `armor` 在未来接入 `Armor` 后可生效。
-/// 保留字段以匹配调用方签名（Jax R），待魔抗原语就绪后补齐系统。
+/// `armor` / `magic_resist` 经属性修正层加到持有者双抗上，到期销毁即撤回。
 #[derive(Component, Debug, Clone)]
 #[require(Buff = Buff { name: "Resist" })]
 pub struct BuffResist {
@@ -72,6 +71,23 @@ impl BuffResist {
     }
 }
 
+/// 幽灵状态 buff（通用）
+///
+/// 持有者移动时无视单位碰撞：既不避让其他单位，也不被其他单位避让（见 `avoidance`）。
+#[derive(Component, Debug, Clone)]
+#[require(Buff = Buff { name: "Ghosted" })]
+pub struct BuffGhosted {
+    pub timer: Timer,
+}
+
+impl BuffGhosted {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+        }
+    }
+}
+
 /// 自我治疗 buff（通用）
 ///
 /// 一次性：下次 tick 把 `amount` 加到持有者生命值（夹取到 `max`）后立即销毁。
@@ -88,55 +104,152 @@ impl BuffSelfHeal {
     }
 }
 
-/// 计时并回退 `BuffMoveSpeed`：首次 tick 应用加成，到期回退并销毁。
-pub fn update_move_speed_buff(
+/// 重伤 debuff（通用）
+///
+/// 持有者受到的治疗按 `percent` 降低；多个重伤不叠加，取最大值（见 [`grievous_wounds_factor`]）。
+#[derive(Component, Debug, Clone)]
+#[require(Buff = Buff { name: "GrievousWounds" })]
+pub struct DebuffGrievousWounds {
+    pub percent: f32,
+    pub timer: Timer,
+}
+
+impl DebuffGrievousWounds {
+    pub fn new(percent: f32, duration: f32) -> Self {
+        Self {
+            percent: percent.clamp(0.0, 1.0),
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+        }
+    }
+}
+
+/// 持有者当前的治疗系数：`1 - 最强重伤百分比`，无重伤时为 1。
+pub fn grievous_wounds_factor(
+    buffs: Option<&Buffs>,
+    q_grievous: &Query<&DebuffGrievousWounds>,
+) -> f32 {
+    let strongest = buffs
+        .map(|buffs| {
+            buffs
+                .iter()
+                .filter_map(|e| q_grievous.get(e).ok())
+                .map(|g| g.percent)
+                .fold(0.0, f32::max)
+        })
+        .unwrap_or(0.0);
+    1.0 - strongest
+}
+
+/// `BuffMoveSpeed` 施加时挂上移速百分比修正。
+fn on_add_move_speed_buff(
+    trigger: On<Add, BuffMoveSpeed>,
+    mut commands: Commands,
+    q_buff: Query<&BuffMoveSpeed>,
+) {
+    let Ok(buff) = q_buff.get(trigger.entity) else {
+        return;
+    };
+    commands
+        .entity(trigger.entity)
+        .insert(StatModifiers(vec![StatModifier::percent(
+            StatKind::MoveSpeed,
+            buff.bonus_percent,
+        )]));
+}
+
+/// `BuffResist` 施加时挂上双抗修正。
+fn on_add_resist_buff(
+    trigger: On<Add, BuffResist>,
     mut commands: Commands,
-    mut q_buff: Query<(Entity, &BuffOf, &mut BuffMoveSpeed)>,
-    mut q_movement: Query<&mut Movement>,
+    q_buff: Query<&BuffResist>,
+) {
+    let Ok(buff) = q_buff.get(trigger.entity) else {
+        return;
+    };
+    commands.entity(trigger.entity).insert(StatModifiers(vec![
+        StatModifier::flat(StatKind::Armor, buff.armor),
+        StatModifier::flat(StatKind::MagicResist, buff.magic_resist),
+    ]));
+}
+
+/// `BuffResist` 计时，到期销毁（修正随之撤回）。
+pub fn update_resist_buff(
+    mut commands: Commands,
+    mut q_buff: Query<(Entity, &mut BuffResist)>,
     time: Res<Time<Fixed>>,
 ) {
-    for (buff_entity, buff_of, mut buff) in q_buff.iter_mut() {
-        let holder = buff_of.0;
-        if !buff.applied {
-            if let Ok(mut mov) = q_movement.get_mut(holder) {
-                buff.applied_bonus = mov.speed * buff.bonus_percent;
-                mov.speed += buff.applied_bonus;
-                buff.applied = true;
-            }
+    for (buff_entity, mut buff) in q_buff.iter_mut() {
+        buff.timer.tick(time.delta());
+        if buff.timer.is_finished() {
+            commands.entity(buff_entity).despawn();
         }
+    }
+}
+
+/// 幽灵状态计时，到期销毁。
+pub fn update_ghosted_buff(
+    mut commands: Commands,
+    mut q_buff: Query<(Entity, &mut BuffGhosted)>,
+    time: Res<Time<Fixed>>,
+) {
+    for (buff_entity, mut buff) in q_buff.iter_mut() {
         buff.timer.tick(time.delta());
-        if buff.timer.is_finished() && buff.applied {
-            if let Ok(mut mov) = q_movement.get_mut(holder) {
-                mov.speed -= buff.applied_bonus;
-            }
+        if buff.timer.is_finished() {
             commands.entity(buff_entity).despawn();
         }
     }
 }
 
-/// 结算 `BuffSelfHeal`：一次性治疗持有者后销毁 buff。
+/// 结算 `BuffSelfHeal`：经治疗管线一次性治疗持有者（受重伤削减）后销毁 buff。
 pub fn update_self_heal_buff(
     mut commands: Commands,
     q_buff: Query<(Entity, &BuffOf, &BuffSelfHeal)>,
-    mut q_health: Query<&mut Health>,
 ) {
     for (buff_entity, buff_of, buff) in q_buff.iter() {
         let holder = buff_of.0;
-        if let Ok(mut health) = q_health.get_mut(holder) {
-            if health.value > 0.0 {
-                health.value = (health.value + buff.amount).min(health.max);
-            }
-        }
+        commands.trigger(CommandHeal {
+            entity: holder,
+            source: holder,
+            heal_type: HealType::Heal,
+            amount: buff.amount,
+        });
         commands.entity(buff_entity).despawn();
     }
 }
 
+/// 重伤计时，到期销毁。
+pub fn update_grievous_wounds(
+    mut commands: Commands,
+    mut q_buff: Query<(Entity, &mut DebuffGrievousWounds)>,
+    time: Res<Time<Fixed>>,
+) {
+    for (buff_entity, mut buff) in q_buff.iter_mut() {
+        buff.timer.tick(time.delta());
+        if buff.timer.is_finished() {
+            commands.entity(buff_entity).despawn();
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct PluginCommonBuffs;
 
 impl Plugin for PluginCommonBuffs {
     fn build(&self, app: &mut App) {
-        app.add_systems(FixedUpdate, (update_move_speed_buff, update_self_heal_buff));
+        app.add_observer(on_add_move_speed_buff);
+        app.add_observer(on_add_resist_buff);
+        app.add_systems(
+            FixedUpdate,
+            (
+                update_resist_buff,
+                update_ghosted_buff,
+                update_self_heal_buff,
+                update_grievous_wounds,
+            ),
+        );
+        app.register_buff_timer::<BuffResist>(|b| &b.timer, |b| &mut b.timer)
+            .register_buff_timer::<BuffGhosted>(|b| &b.timer, |b| &mut b.timer)
+            .register_buff_timer::<DebuffGrievousWounds>(|b| &b.timer, |b| &mut b.timer);
     }
 }
 
@@ -146,13 +259,24 @@ mod tests {
     use bevy::time::TimeUpdateStrategy;
 
     use super::*;
+    use crate::base::buff::PluginBuff;
+    use crate::base::stat_modifier::PluginStatModifier;
+    use crate::damage::{Armor, MagicResist};
+    use crate::heal::PluginHeal;
+    use crate::life::Health;
+    use crate::movement::Movement;
     use crate::team::Team;
 
-    /// 构造仅含 `PluginCommonBuffs` 的最小 app：30fps 固定步进，每 `update()` 推进 1/30 秒。
+    /// 构造含 `PluginCommonBuffs`、属性修正层与治疗管线的最小 app：30fps 固定步进，每 `update()` 推进 1/30 秒。
     fn app_with_common_buffs() -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
-        app.add_plugins(PluginCommonBuffs);
+        app.add_plugins((
+            PluginBuff,
+            PluginCommonBuffs,
+            PluginStatModifier,
+            PluginHeal,
+        ));
         app.insert_resource(Time::<Fixed>::from_hz(30.0));
         app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
         app
@@ -165,6 +289,16 @@ mod tests {
             .with_related::<BuffOf>(buff);
     }
 
+    /// 按描述施加一个移速加成。
+    fn apply_move_speed(app: &mut App, char: Entity, bonus_percent: f32, duration: f32) {
+        crate::base::buff::apply_buff(
+            app.world_mut(),
+            char,
+            BuffMoveSpeed::descriptor(duration),
+            BuffMoveSpeed::new(bonus_percent),
+        );
+    }
+
     /// 推进若干帧。`FixedTimesteps(1)` 首帧仅初始化时钟（0 个固定步），
     /// 故需 ≥2 帧才能保证至少一个固定步执行。
     fn step(app: &mut App, frames: usize) {
@@ -181,15 +315,15 @@ mod tests {
             .spawn((
                 Team::Order,
                 Transform::from_xyz(0.0, 0.0, 0.0),
-                Movement { speed: 1000.0 },
+                Movement { speed: 300.0 },
             ))
             .id();
-        apply_buff(&mut app, char, BuffMoveSpeed::new(0.3, 1.0));
+        apply_move_speed(&mut app, char, 0.3, 1.0);
         step(&mut app, 3);
         let speed = app.world().get::<Movement>(char).unwrap().speed;
         assert!(
-            (speed - 1300.0).abs() < 1e-2,
-            "30% 加成应使移速 1000 -> 1300，实际 {speed}"
+            (speed - 390.0).abs() < 1e-2,
+            "30% 加成应使移速 300 -> 390，实际 {speed}"
         );
     }
 
@@ -201,16 +335,16 @@ mod tests {
             .spawn((
                 Team::Order,
                 Transform::from_xyz(0.0, 0.0, 0.0),
-                Movement { speed: 1000.0 },
+                Movement { speed: 300.0 },
             ))
             .id();
-        apply_buff(&mut app, char, BuffMoveSpeed::new(0.3, 0.1));
+        apply_move_speed(&mut app, char, 0.3, 0.1);
         // 0.1s 加成：3+ 固定步后过期
         step(&mut app, 10);
         let speed = app.world().get::<Movement>(char).unwrap().speed;
         assert!(
-            (speed - 1000.0).abs() < 1e-2,
-            "过期后移速应回退到 1000，实际 {speed}"
+            (speed - 300.0).abs() < 1e-2,
+            "过期后移速应回退到 300，实际 {speed}"
         );
         let buffs = app.world().get::<crate::base::buff::Buffs>(char);
         let still_has = buffs
@@ -223,27 +357,43 @@ mod tests {
     }
 
     #[test]
-    fn move_speed_buffs_stack_independently() {
+    fn move_speed_buffs_add_up_and_soft_cap() {
         let mut app = app_with_common_buffs();
         let char = app
             .world_mut()
             .spawn((
                 Team::Order,
                 Transform::from_xyz(0.0, 0.0, 0.0),
-                Movement { speed: 1000.0 },
+                Movement { speed: 300.0 },
             ))
             .id();
-        apply_buff(&mut app, char, BuffMoveSpeed::new(0.3, 1.0));
-        apply_buff(&mut app, char, BuffMoveSpeed::new(0.2, 1.0));
+        apply_move_speed(&mut app, char, 0.3, 1.0);
+        apply_move_speed(&mut app, char, 0.2, 1.0);
         step(&mut app, 3);
-        // 1000 -> 1300 (+30%) -> 1560 (+20% of 1300)
+        // 百分比相加：300 × 1.5 = 450，超过 415 的部分按 80% 计 -> 443
         let speed = app.world().get::<Movement>(char).unwrap().speed;
         assert!(
-            (speed - 1560.0).abs() < 1e-1,
-            "两 buff 独立叠加应得 1560，实际 {speed}"
+            (speed - 443.0).abs() < 1e-1,
+            "两 buff 加算后经软上限应得 443，实际 {speed}"
         );
     }
 
+    #[test]
+    fn resist_buff_adds_resists_until_expire() {
+        let mut app = app_with_common_buffs();
+        let char = app
+            .world_mut()
+            .spawn((Team::Order, Armor(40.0), MagicResist(30.0)))
+            .id();
+        apply_buff(&mut app, char, BuffResist::new(30.0, 20.0, 0.1));
+        step(&mut app, 1);
+        assert!((app.world().get::<Armor>(char).unwrap().0 - 70.0).abs() < 1e-3);
+        assert!((app.world().get::<MagicResist>(char).unwrap().0 - 50.0).abs() < 1e-3);
+        step(&mut app, 10);
+        assert!((app.world().get::<Armor>(char).unwrap().0 - 40.0).abs() < 1e-3);
+        assert!((app.world().get::<MagicResist>(char).unwrap().0 - 30.0).abs() < 1e-3);
+    }
+
     #[test]
     fn self_heal_buff_heals_and_despawns() {
         let mut app = app_with_common_buffs();
@@ -302,6 +452,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn grievous_wounds_reduces_self_heal() {
+        let mut app = app_with_common_buffs();
+        let char = app
+            .world_mut()
+            .spawn((
+                Team::Order,
+                Transform::from_xyz(0.0, 0.0, 0.0),
+                Health {
+                    value: 50.0,
+                    max: 100.0,
+                    ..default()
+                },
+            ))
+            .id();
+        apply_buff(&mut app, char, DebuffGrievousWounds::new(0.4, 5.0));
+        apply_buff(&mut app, char, DebuffGrievousWounds::new(0.25, 5.0));
+        apply_buff(&mut app, char, BuffSelfHeal::new(30.0));
+        step(&mut app, 3);
+        let health = app.world().get::<Health>(char).unwrap();
+        assert!(
+            (health.value - 68.0).abs() < 1e-2,
+            "重伤取最大值 40%：治疗 30 -> 18，实际 {}",
+            health.value
+        );
+    }
+
     #[test]
     fn self_heal_buff_skips_dead() {
         let mut app = app_with_common_buffs();
@@ -33,3 +33,19 @@ impl BuffDamageReduction {
         self.damage_type.map_or(true, |dt| dt == damage_type)
     }
 }
+
+/// 输出伤害降低 debuff（如虚弱）：持有者作为伤害来源时，造成的所有伤害按百分比降低
+#[derive(Component, Debug, Clone)]
+#[require(Buff = Buff { name: "DamageDealtReduction" })]
+pub struct BuffDamageDealtReduction {
+    /// 降低百分比 (0.0 - 1.0)
+    pub percentage: f32,
+}
+
+impl BuffDamageDealtReduction {
+    pub fn new(percentage: f32) -> Self {
+        Self {
+            percentage: percentage.clamp(0.0, 1.0),
+        }
+    }
+}
@@ -1,25 +1,30 @@
 use bevy::prelude::*;
 
-use crate::attack::{BuffAttack, EventAttackEnd};
-use crate::base::buff::{Buff, BuffOf, Buffs};
-use crate::buffs::cc_debuffs::{DebuffSlow, DebuffStun};
-use crate::damage::{CommandDamageCreate, Damage, DamageType};
+use crate::attack::EventAttackEnd;
+use crate::base::buff::{
+    Buff, BuffCommandsExt, BuffDescriptor, BuffOf, BuffStacking, BuffTag, Buffs,
+};
+use crate::buffs::cc_debuffs::{DebuffSlow, DebuffStun, cc_descriptor};
+use crate::damage::{CommandDamageCreate, Damage, DamageKind, DamageType};
 use crate::life::Health;
 
-/// 强化普攻计数器 — 控制"下次攻击强化"的次数和过期时间
+/// 强化普攻计数器 — 控制"下次攻击强化"的次数，过期时间由 [`Self::descriptor`] 计时
 #[derive(Component, Debug, Clone)]
 #[require(Buff = Buff { name: "OnHitCounter" })]
 pub struct BuffOnHitCounter {
     pub remaining: u8,
-    pub timer: Timer,
 }
 
 impl BuffOnHitCounter {
-    pub fn new(hits: u8, duration: f32) -> Self {
-        Self {
-            remaining: hits,
-            timer: Timer::from_seconds(duration, TimerMode::Once),
-        }
+    pub fn new(hits: u8) -> Self {
+        Self { remaining: hits }
+    }
+
+    /// 同时只保留一个计数器：再次施加会顶掉旧计数器及其 on-hit 组件
+    pub fn descriptor(duration: f32) -> BuffDescriptor {
+        BuffDescriptor::new(BuffStacking::Independent)
+            .with_duration(duration)
+            .with_tags(&[BuffTag::Positive, BuffTag::Offense])
     }
 }
 
@@ -98,6 +103,7 @@ pub fn on_event_attack_end_consume_on_hit(
                 damage_type: DamageType::Physical,
                 amount: extra,
                 tag: None,
+                kind: DamageKind::Other,
             });
         }
     }
@@ -113,68 +119,60 @@ pub fn on_event_attack_end_consume_on_hit(
                 damage_type: DamageType::Physical,
                 amount: extra,
                 tag: None,
+                kind: DamageKind::Other,
             });
         }
     }
 
     // 减速
     if let Some(slow) = buffs.iter().find_map(|b| q_slow.get(b).ok()) {
-        commands
-            .entity(target)
-            .with_related::<BuffOf>(DebuffSlow::new(slow.percent, slow.duration));
+        commands.entity(target).apply_buff(
+            cc_descriptor(attacker),
+            DebuffSlow::new(slow.percent, slow.duration),
+        );
     }
 
     // 眩晕
     if let Some(stun) = buffs.iter().find_map(|b| q_stun.get(b).ok()) {
         commands
             .entity(target)
-            .with_related::<BuffOf>(DebuffStun::new(stun.duration));
+            .apply_buff(cc_descriptor(attacker), DebuffStun::new(stun.duration));
     }
 
     // 消耗次数
     counter.remaining = counter.remaining.saturating_sub(1);
     debug!("on-hit: 消耗强化普攻次数，剩余 {}", counter.remaining);
+    if counter.remaining == 0 {
+        commands.entity(counter_entity).despawn();
+    }
 }
 
-/// FixedUpdate 中计时，次数耗尽或超时后清理所有 on-hit 组件
-pub fn update_on_hit_buff_timers(
+/// 计数器次数耗尽或到期移除时，一并清理同一持有者身上的 on-hit 组件
+fn on_remove_on_hit_counter(
+    trigger: On<Remove, BuffOnHitCounter>,
     mut commands: Commands,
+    q_buff_of: Query<&BuffOf>,
     q_buffs: Query<&Buffs>,
-    mut q_counters: Query<(Entity, &BuffOf, &mut BuffOnHitCounter)>,
-    q_bonus_damage: Query<Entity, With<BuffOnHitBonusDamage>>,
-    q_target_max_hp: Query<Entity, With<BuffOnHitTargetMaxHp>>,
-    q_slow: Query<Entity, With<BuffOnHitSlow>>,
-    q_stun: Query<Entity, With<BuffOnHitStun>>,
-    time: Res<Time<Fixed>>,
+    q_effects: Query<
+        (),
+        Or<(
+            With<BuffOnHitBonusDamage>,
+            With<BuffOnHitTargetMaxHp>,
+            With<BuffOnHitSlow>,
+            With<BuffOnHitStun>,
+        )>,
+    >,
 ) {
-    for (counter_entity, buff_of, mut counter) in q_counters.iter_mut() {
-        counter.timer.tick(time.delta());
-        if counter.remaining > 0 && !counter.timer.is_finished() {
-            continue;
-        }
-
-        // 计数器过期或次数耗尽，移除所有 on-hit 组件
-        let parent = buff_of.0;
-        let Ok(buffs) = q_buffs.get(parent) else {
-            commands.entity(counter_entity).despawn();
-            continue;
-        };
-
-        for buff_entity in buffs.iter() {
-            if q_bonus_damage.get(buff_entity).is_ok()
-                || q_target_max_hp.get(buff_entity).is_ok()
-                || q_slow.get(buff_entity).is_ok()
-                || q_stun.get(buff_entity).is_ok()
-                || buff_entity == counter_entity
-            {
-                commands.entity(buff_entity).despawn();
-            }
-        }
-
-        // 移除攻速加成（如果有）
-        commands.entity(parent).remove::<BuffAttack>();
-        debug!("on-hit: 强化普攻已过期，清理所有 on-hit 组件");
+    let Ok(buff_of) = q_buff_of.get(trigger.entity) else {
+        return;
+    };
+    let Ok(buffs) = q_buffs.get(buff_of.0) else {
+        return;
+    };
+    for buff_entity in buffs.iter().filter(|&b| q_effects.contains(b)) {
+        commands.entity(buff_entity).try_despawn();
     }
+    debug!("on-hit: 强化普攻已结束，清理所有 on-hit 组件");
 }
 
 #[derive(Default)]
@@ -183,6 +181,6 @@ pub struct PluginOnHit;
 impl Plugin for PluginOnHit {
     fn build(&self, app: &mut App) {
         app.add_observer(on_event_attack_end_consume_on_hit);
-        app.add_systems(FixedUpdate, update_on_hit_buff_timers);
+        app.add_observer(on_remove_on_hit_counter);
     }
 }
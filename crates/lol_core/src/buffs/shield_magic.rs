@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::base::buff::Buff;
+use crate::base::buff::{Buff, BuffDescriptor, BuffStacking, BuffTag};
 
 #[derive(Default)]
 pub struct PluginShieldMagic;
@@ -29,6 +29,13 @@ impl BuffShieldMagic {
         }
     }
 
+    /// 多个护盾各自独立承伤；施放者经 `with_source` 指定，决定治疗与护盾强度加成
+    pub fn descriptor() -> BuffDescriptor {
+        BuffDescriptor::new(BuffStacking::Independent)
+            .with_max_stacks(u32::MAX)
+            .with_tags(&[BuffTag::Positive, BuffTag::Defense])
+    }
+
     /// 吸收魔法伤害，返回剩余伤害
     pub fn absorb_magic_damage(&mut self, damage: f32) -> f32 {
         let absorbed = damage.min(self.current);
@@ -0,0 +1,302 @@
+//! 法术护盾：抵挡下一个敌方技能（伤害与控制）后消失，如希维尔 E。
+//!
+//! 护盾被消耗时在持有者身上挂一个短暂的 [`SpellShieldGrace`]，同一来源随后到达的
+//! 伤害与控制（如先伤害、后经伤害事件施加的定身）一并挡下。
+//! `cc_only` 护盾只拦截控制且不被消耗，如莫甘娜 E。
+//!
+//! 只拦截敌方来源；伤害在统一入口 `on_command_damage_create` 判定（只拦截
+//! [`crate::damage::DamageKind::Ability`]），技能飞弹命中与控制施加另在各自入口判定。
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::base::buff::{Buff, BuffAppExt, Buffs};
+use crate::team::Team;
+
+#[derive(Default)]
+pub struct PluginSpellShield;
+
+impl Plugin for PluginSpellShield {
+    fn build(&self, app: &mut App) {
+        app.register_buff_timer::<BuffSpellShield>(|b| &b.timer, |b| &mut b.timer);
+        app.add_systems(
+            FixedUpdate,
+            (update_spell_shield, update_spell_shield_grace),
+        );
+    }
+}
+
+/// 护盾消耗后的宽限时长（秒）
+const SPELL_SHIELD_GRACE_DURATION: f32 = 0.25;
+
+/// 法术护盾 buff
+#[derive(Component, Debug, Clone)]
+#[require(Buff = Buff { name: "SpellShield" })]
+pub struct BuffSpellShield {
+    pub timer: Timer,
+    /// 只拦截控制且不被消耗
+    pub cc_only: bool,
+}
+
+impl BuffSpellShield {
+    /// 抵挡下一个敌方技能
+    pub fn new(duration: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+            cc_only: false,
+        }
+    }
+
+    /// 持续期间免疫控制，不被消耗
+    pub fn cc_only(duration: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+            cc_only: true,
+        }
+    }
+}
+
+/// 护盾刚被消耗后的宽限窗口，挂在持有者身上
+#[derive(Component, Debug, Clone)]
+pub struct SpellShieldGrace {
+    /// 被挡下的技能来源；None 表示由来源未知的控制触发
+    pub source: Option<Entity>,
+    pub timer: Timer,
+}
+
+/// 法术护盾抵挡事件，触发于护盾持有者
+#[derive(EntityEvent, Debug, Clone)]
+pub struct EventSpellShieldBlocked {
+    pub entity: Entity,
+    /// 被挡下的技能来源；控制无法追溯来源时为 None
+    pub source: Option<Entity>,
+}
+
+/// 技能命中入口查询法术护盾用的系统参数。
+///
+/// 只读查询；消耗护盾经由调用方传入的 `Commands` 延迟执行，
+/// 同一系统内对同一目标的多次命中需由调用方自行去重。
+#[derive(SystemParam)]
+pub struct SpellShields<'w, 's> {
+    q_buffs: Query<'w, 's, &'static Buffs>,
+    q_shield: Query<'w, 's, &'static BuffSpellShield>,
+    q_grace: Query<'w, 's, &'static SpellShieldGrace>,
+    q_team: Query<'w, 's, &'static Team>,
+}
+
+impl SpellShields<'_, '_> {
+    /// 技能命中 `target` 前调用：敌方技能被护盾或宽限窗口挡下时返回 true，并消耗护盾。
+    pub fn block_ability(&self, commands: &mut Commands, target: Entity, source: Entity) -> bool {
+        if !self.hostile(target, Some(source)) {
+            return false;
+        }
+        if let Ok(grace) = self.q_grace.get(target)
+            && grace.source.is_none_or(|s| s == source)
+        {
+            return true;
+        }
+        let Some(shield) = self.find(target, false) else {
+            return false;
+        };
+        consume(commands, target, shield, Some(source));
+        true
+    }
+
+    /// 控制施加到 `target` 时调用：敌方控制被挡下时返回 true。
+    /// 来源未知按敌方处理；可消耗的护盾会被消耗，`cc_only` 护盾保留。
+    pub fn block_cc(
+        &self,
+        commands: &mut Commands,
+        target: Entity,
+        source: Option<Entity>,
+    ) -> bool {
+        if !self.hostile(target, source) {
+            return false;
+        }
+        if self.q_grace.get(target).is_ok() {
+            return true;
+        }
+        let Some(shield) = self.find(target, true) else {
+            return false;
+        };
+        if self.q_shield.get(shield).is_ok_and(|s| s.cc_only) {
+            return true;
+        }
+        consume(commands, target, shield, source);
+        true
+    }
+
+    /// 是否持有可用的法术护盾（含只拦截控制的护盾）
+    pub fn has_shield(&self, target: Entity) -> bool {
+        self.find(target, true).is_some()
+    }
+
+    /// 来源是否为 `target` 的敌方：自身与同队不算，缺少阵营时按敌方处理
+    fn hostile(&self, target: Entity, source: Option<Entity>) -> bool {
+        let Some(source) = source else {
+            return true;
+        };
+        if source == target {
+            return false;
+        }
+        match (self.q_team.get(target), self.q_team.get(source)) {
+            (Ok(target_team), Ok(source_team)) => target_team != source_team,
+            _ => true,
+        }
+    }
+
+    fn find(&self, target: Entity, include_cc_only: bool) -> Option<Entity> {
+        let buffs = self.q_buffs.get(target).ok()?;
+        buffs.iter().find(|e| {
+            self.q_shield
+                .get(*e)
+                .is_ok_and(|s| include_cc_only || !s.cc_only)
+        })
+    }
+}
+
+fn consume(commands: &mut Commands, holder: Entity, shield: Entity, source: Option<Entity>) {
+    debug!("{:?} 的法术护盾抵挡了 {:?} 的技能", holder, source);
+    commands.entity(shield).despawn();
+    commands.entity(holder).insert(SpellShieldGrace {
+        source,
+        timer: Timer::from_seconds(SPELL_SHIELD_GRACE_DURATION, TimerMode::Once),
+    });
+    commands.trigger(EventSpellShieldBlocked {
+        entity: holder,
+        source,
+    });
+}
+
+fn update_spell_shield(
+    mut commands: Commands,
+    mut q_shield: Query<(Entity, &mut BuffSpellShield)>,
+    time: Res<Time<Fixed>>,
+) {
+    for (entity, mut shield) in q_shield.iter_mut() {
+        shield.timer.tick(time.delta());
+        if shield.timer.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn update_spell_shield_grace(
+    mut commands: Commands,
+    mut q_grace: Query<(Entity, &mut SpellShieldGrace)>,
+    time: Res<Time<Fixed>>,
+) {
+    for (entity, mut grace) in q_grace.iter_mut() {
+        grace.timer.tick(time.delta());
+        if grace.timer.is_finished() {
+            commands.entity(entity).remove::<SpellShieldGrace>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::SystemState;
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::base::buff::BuffOf;
+
+    #[derive(Component)]
+    struct Holder;
+
+    /// 每帧交替探测：偶数帧技能命中，奇数帧控制施加
+    #[derive(Resource, Default)]
+    struct Blocked(Vec<bool>);
+
+    fn probe(
+        mut commands: Commands,
+        shields: SpellShields,
+        q_holder: Query<Entity, With<Holder>>,
+        mut blocked: ResMut<Blocked>,
+        mut source: Local<Option<Entity>>,
+    ) {
+        let source = *source.get_or_insert_with(|| commands.spawn_empty().id());
+        for target in q_holder.iter() {
+            let hit = if blocked.0.len() % 2 == 0 {
+                shields.block_ability(&mut commands, target, source)
+            } else {
+                shields.block_cc(&mut commands, target, Some(source))
+            };
+            blocked.0.push(hit);
+        }
+    }
+
+    fn app_with_holder(shield: BuffSpellShield) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(PluginSpellShield);
+        app.insert_resource(Time::<Fixed>::from_hz(30.0));
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+        app.init_resource::<Blocked>();
+        app.add_systems(Update, probe);
+
+        let holder = app.world_mut().spawn(Holder).id();
+        app.world_mut()
+            .entity_mut(holder)
+            .with_related::<BuffOf>(shield);
+        (app, holder)
+    }
+
+    #[test]
+    fn spell_shield_consumed_then_grace_expires() {
+        let (mut app, holder) = app_with_holder(BuffSpellShield::new(1.5));
+
+        for _ in 0..3 {
+            app.update();
+        }
+        // 首个技能消耗护盾，随后的控制与同源技能落在宽限窗口内
+        assert_eq!(app.world().resource::<Blocked>().0, vec![true, true, true]);
+        assert!(app.world().get::<SpellShieldGrace>(holder).is_some());
+        let mut q = app.world_mut().query::<&BuffSpellShield>();
+        assert_eq!(q.iter(app.world()).count(), 0, "护盾应被消耗");
+
+        for _ in 0..12 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Blocked>().0.last(), Some(&false));
+        assert!(app.world().get::<SpellShieldGrace>(holder).is_none());
+    }
+
+    #[test]
+    fn cc_only_shield_blocks_cc_without_consuming() {
+        let (mut app, holder) = app_with_holder(BuffSpellShield::cc_only(5.0));
+
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(
+            app.world().resource::<Blocked>().0,
+            vec![false, true, false, true]
+        );
+        assert!(app.world().get::<SpellShieldGrace>(holder).is_none());
+        let mut q = app.world_mut().query::<&BuffSpellShield>();
+        assert_eq!(q.iter(app.world()).count(), 1, "只拦控制的护盾不应被消耗");
+    }
+
+    #[test]
+    fn allied_sources_pass_through_without_consuming() {
+        let mut world = World::new();
+        let holder = world.spawn(Team::Order).id();
+        let ally = world.spawn(Team::Order).id();
+        let enemy = world.spawn(Team::Chaos).id();
+        world
+            .entity_mut(holder)
+            .with_related::<BuffOf>(BuffSpellShield::new(1.5));
+
+        let mut state = SystemState::<(Commands, SpellShields)>::new(&mut world);
+        let (mut commands, shields) = state.get_mut(&mut world);
+        assert!(!shields.block_cc(&mut commands, holder, Some(ally)));
+        assert!(!shields.block_cc(&mut commands, holder, Some(holder)));
+        assert!(!shields.block_ability(&mut commands, holder, ally));
+        assert!(shields.has_shield(holder), "友方来源不应消耗护盾");
+        assert!(shields.block_cc(&mut commands, holder, Some(enemy)));
+        state.apply(&mut world);
+        assert!(world.get::<SpellShieldGrace>(holder).is_some());
+    }
+}
@@ -36,6 +36,7 @@ fn on_event_dead(
     event: On<EventDead>,
     query: Query<(Entity, &GlobalTransform, &ExperienceDrop, &Team)>,
     mut level_query: Query<(Entity, &GlobalTransform, &Team, &mut Level, Option<&Death>)>,
+    q_team: Query<&Team>,
     mut commands: Commands,
 ) {
     let entity = event.event_target();
@@ -48,6 +49,11 @@ fn on_event_dead(
         return;
     }
 
+    // 中立野怪的经验只分给击杀方队伍
+    let killer_team = event
+        .killer
+        .and_then(|killer| q_team.get(killer).ok().copied());
+
     let position = transform.translation();
     for (target_entity, target_transform, target_team, mut level, death) in level_query.iter_mut() {
         if death.is_some() {
@@ -58,6 +64,10 @@ fn on_event_dead(
             continue;
         }
 
+        if *team == Team::Neutral && Some(*target_team) != killer_team {
+            continue;
+        }
+
         if target_transform.translation().distance(position) > exp_drop.experience_radius {
             continue;
         }
@@ -2,9 +2,11 @@ use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::base::buff::Buffs;
-use crate::buffs::damage_reduction::BuffDamageReduction;
+use crate::buffs::damage_reduction::{BuffDamageDealtReduction, BuffDamageReduction};
 use crate::buffs::shield_magic::BuffShieldMagic;
 use crate::buffs::shield_white::BuffShieldWhite;
+use crate::buffs::spell_shield::SpellShields;
+use crate::entities::pet::PetClone;
 use crate::life::Health;
 
 /// 伤害系统插件
@@ -25,6 +27,11 @@ pub struct Damage(pub f32);
 #[reflect(Component)]
 pub struct Armor(pub f32);
 
+/// 魔法抗性：按与护甲相同的公式减免魔法伤害，缺失时按 0 处理。
+#[derive(Component, Clone, Serialize, Deserialize, Reflect, Default)]
+#[reflect(Component)]
+pub struct MagicResist(pub f32);
+
 /// 法术强度（Ability Power）。
 ///
 /// 法系英雄魔法伤害的加成来源。绝大多数英雄基础 AP 为 0（AP 来自装备/符文），
@@ -34,8 +41,32 @@ pub struct Armor(pub f32);
 #[reflect(Component)]
 pub struct AbilityPower(pub f32);
 
-/// 伤害类型枚举
+/// 无敌：仍可被选中，但不受任何伤害（如凯尔 R）。
+/// 由对应 buff 的增删维护；仇恨扫描不会选择无敌单位。
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct Invulnerable;
+
+/// 不可选中：不能被普攻、指向技能、范围技能与投射物命中（如金身、亚索 R 飞行中）。
+/// 伤害管线本身不拦截——已在途的伤害由各命中入口按此标记过滤。
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct Untargetable;
+
+/// 伤害来源类别：由伤害发起方标明，法术护盾只拦截技能伤害。
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DamageKind {
+    /// 技能直接命中（技能结算、技能飞弹、位移与击飞附带伤害）
+    Ability,
+    /// 普攻命中（含远程普攻飞弹）
+    BasicAttack,
+    /// 其余伤害：攻击特效、持续伤害、被动、防御塔、泉水与野怪等
+    #[default]
+    Other,
+}
+
+/// 伤害类型枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum DamageType {
     /// 物理伤害
     #[default]
@@ -58,6 +89,8 @@ pub struct CommandDamageCreate {
     pub amount: f32,
     /// 伤害标签，用于区分同一技能的不同伤害来源（如 Darius Q 内外圈）
     pub tag: Option<u32>,
+    /// 伤害来源类别
+    pub kind: DamageKind,
 }
 
 #[derive(EntityEvent, Debug)]
@@ -68,6 +101,8 @@ pub struct EventDamageCreate {
     pub damage_result: DamageResult,
     /// 伤害标签，透传自 CommandDamageCreate
     pub tag: Option<u32>,
+    /// 伤害来源类别，透传自 CommandDamageCreate
+    pub kind: DamageKind,
 }
 
 /// 伤害计算结果
@@ -81,7 +116,7 @@ pub struct DamageResult {
     pub magic_shield_absorbed: f32,
     /// 被减免的伤害
     pub reduced_damage: f32,
-    /// 被护甲减免的伤害
+    /// 被护甲 / 魔抗减免的伤害
     pub armor_reduced_damage: f32,
     /// 原始伤害
     pub original_damage: f32,
@@ -91,10 +126,20 @@ pub struct DamageResult {
 pub fn on_command_damage_create(
     trigger: On<CommandDamageCreate>,
     mut commands: Commands,
-    mut query: Query<(&mut Health, Option<&Armor>, Option<&Buffs>)>,
+    mut query: Query<(
+        &mut Health,
+        Option<&Armor>,
+        Option<&MagicResist>,
+        Option<&Buffs>,
+    )>,
     mut q_shield_white: Query<&mut BuffShieldWhite>,
     mut q_shield_magic: Query<&mut BuffShieldMagic>,
     q_damage_reduction: Query<&BuffDamageReduction>,
+    q_source_buffs: Query<&Buffs>,
+    q_damage_dealt_reduction: Query<&BuffDamageDealtReduction>,
+    q_invulnerable: Query<(), With<Invulnerable>>,
+    q_clone: Query<&PetClone>,
+    shields: SpellShields,
 ) {
     debug!(
         "{:?} 对 {:?} 造成 {:.1} 点 {:?} 伤害",
@@ -104,7 +149,7 @@ pub fn on_command_damage_create(
         trigger.damage_type,
     );
 
-    let Ok((mut health, armor, buffs)) = query.get_mut(trigger.event_target()) else {
+    let Ok((mut health, armor, magic_resist, buffs)) = query.get_mut(trigger.event_target()) else {
         debug!("未找到伤害目标实体 {:?}", trigger.event_target());
         return;
     };
@@ -114,8 +159,24 @@ pub fn on_command_damage_create(
         return;
     }
 
+    // 无敌：整笔伤害作废，不产生伤害事件
+    if q_invulnerable.get(trigger.event_target()).is_ok() {
+        return;
+    }
+
+    // 法术护盾：敌方技能伤害被挡下时整笔作废
+    if trigger.kind == DamageKind::Ability
+        && shields.block_ability(&mut commands, trigger.event_target(), trigger.source)
+    {
+        return;
+    }
+
     let health_before = health.value;
-    let armor_value = armor.map(|a| a.0);
+    let resist_value = match trigger.damage_type {
+        DamageType::Physical => armor.map(|a| a.0),
+        DamageType::Magic => magic_resist.map(|mr| mr.0),
+        DamageType::True => None,
+    };
 
     let mut remaining_damage = trigger.amount;
     let mut white_shield_absorbed = 0.0;
@@ -123,18 +184,39 @@ pub fn on_command_damage_create(
     let mut reduced_damage = 0.0;
     let mut armor_reduced_damage = 0.0;
 
+    // 来源身上的输出降低（如虚弱）作用于所有伤害类型，按乘法叠加
+    let dealt_factor = q_source_buffs
+        .get(trigger.source)
+        .map(|source_buffs| {
+            source_buffs
+                .iter()
+                .filter_map(|e| q_damage_dealt_reduction.get(e).ok())
+                .fold(1.0, |factor, r| factor * (1.0 - r.percentage))
+        })
+        .unwrap_or(1.0);
+    if dealt_factor < 1.0 {
+        reduced_damage = remaining_damage * (1.0 - dealt_factor);
+        remaining_damage -= reduced_damage;
+    }
+
+    // 分身按自身输出倍率造成伤害、按承伤倍率受到伤害，倍率可高于 1
+    if let Ok(clone) = q_clone.get(trigger.source) {
+        remaining_damage *= clone.damage_dealt;
+    }
+    if let Ok(clone) = q_clone.get(trigger.event_target()) {
+        remaining_damage *= clone.damage_taken;
+    }
+
     // 真实伤害无视所有防御机制
     if trigger.damage_type == DamageType::True {
         health.value -= remaining_damage;
     } else {
-        // 对物理伤害应用护甲减伤
-        if trigger.damage_type == DamageType::Physical {
-            if let Some(armor_val) = armor_value {
-                if armor_val > 0.0 {
-                    let damage_after_armor = remaining_damage * 100.0 / (100.0 + armor_val);
-                    armor_reduced_damage = remaining_damage - damage_after_armor;
-                    remaining_damage = damage_after_armor;
-                }
+        // 物理伤害按护甲、魔法伤害按魔抗减伤
+        if let Some(resist) = resist_value {
+            if resist > 0.0 {
+                let damage_after_resist = remaining_damage * 100.0 / (100.0 + resist);
+                armor_reduced_damage = remaining_damage - damage_after_resist;
+                remaining_damage = damage_after_resist;
             }
         }
 
@@ -152,7 +234,7 @@ pub fn on_command_damage_create(
             }
             if total_reduction > 0.0 {
                 let reduction_amount = remaining_damage * total_reduction;
-                reduced_damage = reduction_amount;
+                reduced_damage += reduction_amount;
                 remaining_damage -= reduction_amount;
             }
         }
@@ -220,6 +302,7 @@ pub fn on_command_damage_create(
         damage_type: trigger.damage_type,
         damage_result: result,
         tag: trigger.tag,
+        kind: trigger.kind,
     });
 
     if health.value <= 0.0 {
@@ -267,6 +350,7 @@ mod tests {
                 damage_type: DamageType::True,
                 amount: 10.0,
                 tag: Some(42),
+                kind: DamageKind::Other,
             });
         // 观察者链（CommandDamageCreate -> EventDamageCreate）跨两次命令刷新，多跑几帧确保落地
         for _ in 0..3 {
@@ -301,6 +385,7 @@ mod tests {
                 damage_type: DamageType::True,
                 amount: 10.0,
                 tag: None,
+                kind: DamageKind::Other,
             });
         for _ in 0..3 {
             app.update();
@@ -313,4 +398,165 @@ mod tests {
             captured.0
         );
     }
+
+    /// 无敌单位不受伤害，也不产生伤害事件。
+    #[test]
+    fn invulnerable_target_ignores_damage() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(PluginDamage);
+        app.init_resource::<CapturedDamageTags>();
+        app.add_observer(on_capture_damage_event);
+
+        let target = app
+            .world_mut()
+            .spawn((Health::new(1000.0), Invulnerable))
+            .id();
+        let source = app.world_mut().spawn_empty().id();
+
+        app.world_mut()
+            .entity_mut(target)
+            .trigger(|e| CommandDamageCreate {
+                entity: e,
+                source,
+                damage_type: DamageType::True,
+                amount: 100.0,
+                tag: None,
+                kind: DamageKind::Other,
+            });
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert_eq!(app.world().get::<Health>(target).unwrap().value, 1000.0);
+        assert!(app.world().resource::<CapturedDamageTags>().0.is_empty());
+    }
+
+    /// 法术护盾在伤害入口只挡下敌方技能伤害，普攻与持续伤害等照常结算。
+    #[test]
+    fn spell_shield_blocks_only_ability_damage() {
+        use crate::base::buff::BuffOf;
+        use crate::buffs::spell_shield::BuffSpellShield;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(PluginDamage);
+
+        let target = app.world_mut().spawn(Health::new(1000.0)).id();
+        app.world_mut()
+            .entity_mut(target)
+            .with_related::<BuffOf>(BuffSpellShield::new(5.0));
+        let source = app.world_mut().spawn_empty().id();
+
+        let mut hit = |kind| {
+            app.world_mut()
+                .entity_mut(target)
+                .trigger(|e| CommandDamageCreate {
+                    entity: e,
+                    source,
+                    damage_type: DamageType::True,
+                    amount: 10.0,
+                    tag: None,
+                    kind,
+                });
+            app.update();
+            app.world().get::<Health>(target).unwrap().value
+        };
+
+        assert_eq!(hit(DamageKind::BasicAttack), 990.0);
+        assert_eq!(hit(DamageKind::Other), 980.0, "持续伤害等不应触发法术护盾");
+        assert_eq!(hit(DamageKind::Ability), 980.0, "技能伤害应被法术护盾挡下");
+        assert_eq!(hit(DamageKind::BasicAttack), 970.0);
+        let mut q = app.world_mut().query::<&BuffSpellShield>();
+        assert_eq!(q.iter(app.world()).count(), 0, "法术护盾应被消耗");
+    }
+
+    #[test]
+    fn magic_resist_reduces_only_magic_damage() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(PluginDamage);
+
+        let target = app
+            .world_mut()
+            .spawn((Health::new(1000.0), MagicResist(100.0)))
+            .id();
+        let source = app.world_mut().spawn_empty().id();
+
+        for damage_type in [DamageType::Magic, DamageType::Physical] {
+            app.world_mut()
+                .entity_mut(target)
+                .trigger(|e| CommandDamageCreate {
+                    entity: e,
+                    source,
+                    damage_type,
+                    amount: 100.0,
+                    tag: None,
+                    kind: DamageKind::Other,
+                });
+        }
+        for _ in 0..3 {
+            app.update();
+        }
+
+        // 魔法伤害减半，物理伤害不受魔抗影响
+        assert_eq!(app.world().get::<Health>(target).unwrap().value, 850.0);
+    }
+
+    #[test]
+    fn clone_scales_dealt_and_taken_damage() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(PluginDamage);
+
+        let target = app.world_mut().spawn(Health::new(1000.0)).id();
+        let clone = app
+            .world_mut()
+            .spawn((Health::new(1000.0), PetClone::new(0.5, 1.5)))
+            .id();
+        let strong_clone = app
+            .world_mut()
+            .spawn((Health::new(1000.0), PetClone::new(2.0, 1.0)))
+            .id();
+
+        // 分身造成一半伤害
+        app.world_mut()
+            .entity_mut(target)
+            .trigger(|e| CommandDamageCreate {
+                entity: e,
+                source: clone,
+                damage_type: DamageType::True,
+                amount: 100.0,
+                tag: None,
+                kind: DamageKind::Other,
+            });
+        // 分身承受 1.5 倍伤害
+        app.world_mut()
+            .entity_mut(clone)
+            .trigger(|e| CommandDamageCreate {
+                entity: e,
+                source: target,
+                damage_type: DamageType::True,
+                amount: 100.0,
+                tag: None,
+                kind: DamageKind::Other,
+            });
+        // 输出倍率高于 1 的分身造成更高伤害
+        app.world_mut()
+            .entity_mut(target)
+            .trigger(|e| CommandDamageCreate {
+                entity: e,
+                source: strong_clone,
+                damage_type: DamageType::True,
+                amount: 100.0,
+                tag: None,
+                kind: DamageKind::Other,
+            });
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert_eq!(app.world().get::<Health>(target).unwrap().value, 750.0);
+        assert_eq!(app.world().get::<Health>(clone).unwrap().value, 850.0);
+    }
 }
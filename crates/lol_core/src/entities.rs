@@ -1,6 +1,8 @@
 pub mod barrack;
 pub mod champion;
+pub mod epic;
 pub mod inhibitor;
+pub mod jungle;
 pub mod minion;
 pub mod nexus;
 pub mod pet;
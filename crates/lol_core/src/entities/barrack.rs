@@ -101,9 +101,29 @@ fn init_barrack_state_system(
     }
 }
 
+/// 水晶状态：记录被摧毁、等待复活的水晶
 #[derive(Resource, Default)]
 pub struct InhibitorState {
-    pub inhibitors_down: usize,
+    pub destroyed: Vec<InhibitorRespawn>,
+}
+
+/// 被摧毁水晶的复活倒计时
+#[derive(Debug, Clone)]
+pub struct InhibitorRespawn {
+    pub entity: Entity,
+    /// 水晶所属阵营
+    pub team: Team,
+    pub timer: Timer,
+}
+
+impl InhibitorState {
+    /// 某阵营当前被摧毁的水晶数
+    pub fn inhibitors_down(&self, team: Team) -> usize {
+        self.destroyed
+            .iter()
+            .filter(|respawn| respawn.team == team)
+            .count()
+    }
 }
 
 /// 核心系统：处理兵营的计时、升级和生成逻辑
@@ -122,11 +142,18 @@ fn barracks_spawning_system(
     time: Res<Time>,
     asset_server: Res<AssetServer>,
 ) {
-    for (transform, config_handler, mut barrack_state, _team, lane) in query.iter_mut() {
+    for (transform, config_handler, mut barrack_state, team, lane) in query.iter_mut() {
         let Some(barracks_config) = res_barracks_config.get(&config_handler.config_handle) else {
             continue;
         };
 
+        // 超级兵数量取决于对方被摧毁的水晶数
+        let enemy_inhibitors_down = match team {
+            Team::Order => inhibitor_state.inhibitors_down(Team::Chaos),
+            Team::Chaos => inhibitor_state.inhibitors_down(Team::Order),
+            Team::Neutral => 0,
+        };
+
         // --- 1. 更新所有计时器 ---
         barrack_state.wave_timer.tick(time.delta());
 
@@ -173,7 +200,7 @@ fn barracks_spawning_system(
                     &minion_config.wave_behavior,
                     game_time.elapsed_secs(),
                     barrack_state.wave_count,
-                    &inhibitor_state,
+                    enemy_inhibitors_down,
                 );
 
                 if spawn_count > 0 {
@@ -280,7 +307,7 @@ fn calculate_spawn_count(
     behavior: &EnumWaveBehavior,
     game_time_secs: f32,
     wave_count: u32,
-    inhibitor_state: &InhibitorState,
+    inhibitors_down: usize,
 ) -> i32 {
     match behavior {
         EnumWaveBehavior::ConstantWaveBehavior(ConstantWaveBehavior { spawn_count }) => {
@@ -289,12 +316,12 @@ fn calculate_spawn_count(
         EnumWaveBehavior::InhibitorWaveBehavior(InhibitorWaveBehavior {
             spawn_count_per_inhibitor_down,
         }) => {
-            if inhibitor_state.inhibitors_down == 0 {
+            if inhibitors_down == 0 {
                 return 0;
             }
 
             spawn_count_per_inhibitor_down
-                .get(inhibitor_state.inhibitors_down - 1)
+                .get(inhibitors_down - 1)
                 .copied()
                 .unwrap_or(0)
         }
@@ -314,7 +341,7 @@ fn calculate_spawn_count(
 
             if let Some(active_behavior) = active_behavior {
                 // 递归调用
-                calculate_spawn_count(active_behavior, game_time_secs, wave_count, inhibitor_state)
+                calculate_spawn_count(active_behavior, game_time_secs, wave_count, inhibitors_down)
             } else {
                 0
             }
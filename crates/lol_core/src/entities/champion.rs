@@ -1,11 +1,13 @@
 use bevy::prelude::*;
 
+use crate::base::bounty::DamageLedger;
 use crate::base::gold::Gold;
 use crate::base::level::Level;
 use crate::base::state::State;
 use crate::base::stats::ChampionStats;
 use crate::character::Character;
 use crate::skill::SkillPoints;
+use crate::summoner::SummonerSpells;
 
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
@@ -15,10 +17,19 @@ use crate::skill::SkillPoints;
     Level = Level { value: 1, experience: 0, experience_to_next_level: 280 },
     SkillPoints,
     Gold,
-    ChampionStats
+    ChampionStats,
+    DamageLedger,
+    SummonerSpells,
+    TargetableAsChampion
 )]
 pub struct Champion;
 
+/// 目标选择时按英雄处理的单位：英雄自身，以及镜像英雄的分身。
+/// 只影响技能目标筛选与空间索引分类，不带来经验、金币、复活等英雄结算。
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct TargetableAsChampion;
+
 #[derive(Component, Reflect, Default, Debug, Clone, PartialEq, Eq, Hash)]
 #[reflect(Component)]
 pub struct AgentId(pub String);
@@ -0,0 +1,1024 @@
+use bevy::prelude::*;
+use lol_base::grid::{ConfigNavigationGrid, GridFlagsPOI};
+use serde::{Deserialize, Serialize};
+
+use crate::aggro::Aggro;
+use crate::attack::{Attack, EventAttackEnd};
+use crate::attack_auto::{AttackAuto, CommandAttackAutoStart};
+use crate::base::bounding::Bounding;
+use crate::base::buff::{Buff, BuffOf, Buffs};
+use crate::base::gold::GoldDrop;
+use crate::base::level::ExperienceDrop;
+use crate::base::stat_modifier::{StatKind, StatModifier, StatModifiers};
+use crate::buffs::cc_debuffs::DebuffKnockup;
+use crate::buffs::damage_reduction::BuffDamageReduction;
+use crate::damage::{
+    Armor, CommandDamageCreate, Damage, DamageKind, DamageType, EventDamageCreate,
+};
+use crate::entities::champion::Champion;
+use crate::entities::jungle::{
+    JUNGLE_AGGRO_RANGE, JUNGLE_GOLD_RADIUS, JungleMonster, JungleMonsterStats,
+};
+use crate::entities::minion::Minion;
+use crate::entities::pet::Pet;
+use crate::entities::turret::Turret;
+use crate::life::{Death, EventDead, Health};
+use crate::lifetime::Lifetime;
+use crate::log::{CommandLog, EnumLogCategory};
+use crate::movement::Movement;
+use crate::navigation::grid::ResourceGrid;
+use crate::run::{CommandRunStart, RunTarget};
+use crate::team::Team;
+
+/// 第一条元素龙刷新时间（秒）
+pub const DRAGON_FIRST_SPAWN_SECS: f32 = 300.0;
+/// 元素龙重生间隔（秒）
+pub const DRAGON_RESPAWN_SECS: f32 = 300.0;
+/// 远古龙重生间隔（秒）
+pub const ELDER_RESPAWN_SECS: f32 = 360.0;
+/// 拿到龙魂所需的元素龙层数
+pub const DRAGON_SOUL_STACKS: usize = 4;
+/// 峡谷先锋刷新时间（秒），只刷新一次
+pub const HERALD_SPAWN_SECS: f32 = 480.0;
+/// 纳什男爵首次刷新时间（秒），届时未被击杀的先锋离场
+pub const BARON_SPAWN_SECS: f32 = 1200.0;
+/// 纳什男爵重生间隔（秒）
+pub const BARON_RESPAWN_SECS: f32 = 360.0;
+/// 男爵之手持续时间（秒）
+pub const BARON_BUFF_SECS: f32 = 180.0;
+/// 男爵之手提供的攻击力加成
+pub const BARON_BUFF_DAMAGE: f32 = 24.0;
+/// 男爵之手强化附近友方小兵的半径
+pub const BARON_EMPOWER_RADIUS: f32 = 1200.0;
+/// 被男爵之手强化的小兵受到的伤害减免
+pub const BARON_MINION_DAMAGE_REDUCTION: f32 = 0.5;
+/// 远古龙之焰持续时间（秒）
+pub const ELDER_BUFF_SECS: f32 = 150.0;
+/// 远古龙之焰的斩杀线（目标最大生命百分比）
+pub const ELDER_EXECUTE_THRESHOLD: f32 = 0.2;
+/// 被召唤的峡谷先锋存在时间（秒）
+pub const HERALD_SUMMON_SECS: f32 = 40.0;
+
+#[derive(Default)]
+pub struct PluginEpicMonster;
+
+impl Plugin for PluginEpicMonster {
+    fn build(&self, app: &mut App) {
+        app.register_type::<EpicPit>();
+        app.init_resource::<EpicObjectives>();
+        app.add_observer(on_epic_monster_dead);
+        app.add_observer(on_epic_attack_end);
+        app.add_observer(on_elder_execute);
+        app.add_systems(
+            FixedUpdate,
+            (
+                spawn_epic_pits_from_grid,
+                init_epic_pit_state,
+                update_epic_pits,
+                update_herald_summon,
+                update_baron_buff,
+                update_elder_buff,
+                update_baron_minion_empowerment,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// 元素龙种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum DragonElement {
+    Infernal,
+    Ocean,
+    Mountain,
+    Cloud,
+    Hextech,
+    Chemtech,
+}
+
+impl DragonElement {
+    pub const ALL: [DragonElement; 6] = [
+        DragonElement::Infernal,
+        DragonElement::Ocean,
+        DragonElement::Mountain,
+        DragonElement::Cloud,
+        DragonElement::Hextech,
+        DragonElement::Chemtech,
+    ];
+}
+
+/// 史诗野怪种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum EpicMonsterKind {
+    Dragon(DragonElement),
+    ElderDragon,
+    RiftHerald,
+    BaronNashor,
+}
+
+impl EpicMonsterKind {
+    /// 史诗野怪基础数值（近似正式服数值）
+    pub fn stats(self) -> JungleMonsterStats {
+        let (name, health, damage, armor, gold, experience) = match self {
+            EpicMonsterKind::Dragon(_) => ("SRU_Dragon", 3500.0, 100.0, 21.0, 25.0, 300.0),
+            EpicMonsterKind::ElderDragon => ("SRU_Dragon_Elder", 6400.0, 150.0, 120.0, 25.0, 750.0),
+            EpicMonsterKind::RiftHerald => ("SRU_RiftHerald", 7000.0, 105.0, 60.0, 100.0, 500.0),
+            EpicMonsterKind::BaronNashor => ("SRU_Baron", 9000.0, 150.0, 120.0, 300.0, 800.0),
+        };
+        JungleMonsterStats {
+            name,
+            health,
+            damage,
+            armor,
+            gold,
+            experience,
+            offset: Vec2::ZERO,
+            grants_camp_buff: false,
+        }
+    }
+}
+
+/// 史诗野怪巢穴种类：小龙坑刷元素龙 / 远古龙，大龙坑先刷峡谷先锋后刷纳什男爵。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum EpicPitKind {
+    Dragon,
+    Baron,
+}
+
+impl EpicPitKind {
+    pub fn poi(self) -> GridFlagsPOI {
+        match self {
+            EpicPitKind::Dragon => GridFlagsPOI::DragonPit,
+            EpicPitKind::Baron => GridFlagsPOI::BaronPit,
+        }
+    }
+}
+
+/// 史诗野怪巢穴：可由地图场景直接放置，缺省时从导航网格 POI 推导生成。
+#[derive(Component, Reflect, Debug, Clone, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct EpicPit {
+    pub kind: EpicPitKind,
+}
+
+/// 巢穴动态状态
+#[derive(Component, Debug, Default)]
+pub struct EpicPitState {
+    /// 下一只主野怪（龙 / 男爵）的刷新倒计时，巢穴有主野怪时为 None
+    pub timer: Option<Timer>,
+    /// 峡谷先锋刷新倒计时（仅大龙坑，只刷新一次）
+    pub herald_timer: Option<Timer>,
+    pub monster: Option<(Entity, EpicMonsterKind)>,
+}
+
+/// 史诗野怪标记，`attacks` 为普攻计数，用于每第三次普攻的强化攻击。
+#[derive(Component, Debug, Clone)]
+pub struct EpicMonster {
+    pub kind: EpicMonsterKind,
+    pub attacks: u32,
+}
+
+/// 全局史诗目标进度：元素龙归属、龙魂与元素轮换。
+///
+/// 插件只在资源缺省时初始化；要指定元素轮换，在添加插件前插入 `EpicObjectives::with_seed(seed)`。
+#[derive(Resource, Debug, Default, Clone)]
+pub struct EpicObjectives {
+    /// 元素轮换种子：前两条龙依次为 `ALL[seed]`、`ALL[seed + 1]`，之后固定为峡谷元素
+    pub seed: usize,
+    pub dragons: Vec<(Team, DragonElement)>,
+    pub soul: Option<(Team, DragonElement)>,
+}
+
+impl EpicObjectives {
+    pub fn with_seed(seed: usize) -> Self {
+        Self {
+            seed: seed % DragonElement::ALL.len(),
+            ..default()
+        }
+    }
+
+    /// 峡谷元素：第三条龙起固定刷新的元素，也是龙魂的元素
+    pub fn rift_element(&self) -> DragonElement {
+        DragonElement::ALL[(self.seed + 2) % DragonElement::ALL.len()]
+    }
+
+    pub fn next_dragon(&self) -> EpicMonsterKind {
+        if self.soul.is_some() {
+            return EpicMonsterKind::ElderDragon;
+        }
+        let index = self.seed + self.dragons.len().min(2);
+        EpicMonsterKind::Dragon(DragonElement::ALL[index % DragonElement::ALL.len()])
+    }
+
+    pub fn dragon_count(&self, team: Team) -> usize {
+        self.dragons.iter().filter(|(t, _)| *t == team).count()
+    }
+}
+
+/// 史诗目标被拿下（由击杀方阵营取得），`match_events` 据此产出对局事件。
+#[derive(Event, Debug, Clone)]
+pub struct EventEpicObjectiveTaken {
+    pub team: Team,
+    pub monster: EpicMonsterKind,
+}
+
+/// 某方拿到龙魂。
+#[derive(Event, Debug, Clone)]
+pub struct EventDragonSoulClaimed {
+    pub team: Team,
+    pub element: DragonElement,
+}
+
+/// 元素龙层数：永久，按元素提供属性（同实体的属性修正结算）。
+#[derive(Component, Debug, Clone)]
+#[require(Buff = Buff { name: "DragonStack" })]
+pub struct BuffDragon {
+    pub element: DragonElement,
+}
+
+/// 龙魂：永久，再提供两倍于单层的同元素属性。
+#[derive(Component, Debug, Clone)]
+#[require(Buff = Buff { name: "DragonSoul" })]
+pub struct BuffDragonSoul {
+    pub element: DragonElement,
+}
+
+/// 远古龙之焰：对生命低于斩杀线的敌方英雄造成伤害时直接斩杀。
+#[derive(Component, Debug, Clone)]
+#[require(Buff = Buff { name: "ElderDragon" })]
+pub struct BuffElderDragon {
+    pub timer: Timer,
+}
+
+/// 男爵之手：攻击力加成（buff 上的属性修正）、强化附近友方小兵，回城强化由回城系统读取。
+#[derive(Component, Debug, Clone)]
+#[require(Buff = Buff { name: "HandOfBaron" })]
+pub struct BuffHandOfBaron {
+    pub timer: Timer,
+}
+
+/// 男爵强化标记，挂在小兵的减伤 buff 上，离开范围或男爵之手消失时移除。
+#[derive(Component, Default)]
+pub struct BaronEmpowerment;
+
+/// 被召唤的峡谷先锋：冲向最近的敌方防御塔。
+#[derive(Component, Debug, Clone, Default)]
+pub struct HeraldSummon {
+    pub target: Option<Entity>,
+}
+
+/// 元素龙层数的属性修正，`scale` 为层数倍率（龙魂为 2）；挂在层数 buff 上，由属性修正层结算。
+fn dragon_modifiers(element: DragonElement, scale: f32) -> StatModifiers {
+    let modifiers = match element {
+        DragonElement::Infernal => {
+            vec![StatModifier::percent(StatKind::AttackDamage, 0.04 * scale)]
+        }
+        DragonElement::Mountain => vec![StatModifier::percent(StatKind::Armor, 0.06 * scale)],
+        DragonElement::Cloud => vec![StatModifier::percent(StatKind::MoveSpeed, 0.03 * scale)],
+        DragonElement::Hextech => vec![StatModifier::flat(StatKind::AttackSpeed, 0.09 * scale)],
+        DragonElement::Ocean => vec![StatModifier::flat(StatKind::HealthRegen, 2.0 * scale)],
+        DragonElement::Chemtech => vec![StatModifier::flat(StatKind::Tenacity, 0.06 * scale)],
+    };
+    StatModifiers(modifiers)
+}
+
+fn spawn_epic_pits_from_grid(
+    mut commands: Commands,
+    mut done: Local<bool>,
+    res_grid: Option<Res<ResourceGrid>>,
+    res_assets_grid: Option<Res<Assets<ConfigNavigationGrid>>>,
+    q_pit: Query<(), With<EpicPit>>,
+) {
+    if *done {
+        return;
+    }
+    let (Some(res_grid), Some(res_assets_grid)) = (res_grid, res_assets_grid) else {
+        return;
+    };
+    let Some(grid) = res_assets_grid.get(&res_grid.0) else {
+        return;
+    };
+    *done = true;
+
+    // 地图场景已放置巢穴时以场景为准
+    if !q_pit.is_empty() {
+        return;
+    }
+
+    for kind in [EpicPitKind::Dragon, EpicPitKind::Baron] {
+        // 每种巢穴只取导航网格中最大的一块 POI 区域
+        let Some((center, _)) = grid.poi_regions(kind.poi()).into_iter().next() else {
+            continue;
+        };
+        commands.spawn((
+            Name::new(format!("EpicPit {:?}", kind)),
+            EpicPit { kind },
+            Team::Neutral,
+            Transform::from_translation(grid.get_world_position_by_position(&center)),
+        ));
+    }
+}
+
+fn init_epic_pit_state(
+    mut commands: Commands,
+    q_pit: Query<(Entity, &EpicPit), Without<EpicPitState>>,
+) {
+    for (entity, pit) in q_pit.iter() {
+        let state = match pit.kind {
+            EpicPitKind::Dragon => EpicPitState {
+                timer: Some(Timer::from_seconds(
+                    DRAGON_FIRST_SPAWN_SECS,
+                    TimerMode::Once,
+                )),
+                ..default()
+            },
+            EpicPitKind::Baron => EpicPitState {
+                timer: Some(Timer::from_seconds(BARON_SPAWN_SECS, TimerMode::Once)),
+                herald_timer: Some(Timer::from_seconds(HERALD_SPAWN_SECS, TimerMode::Once)),
+                monster: None,
+            },
+        };
+        commands.entity(entity).insert(state);
+    }
+}
+
+fn spawn_epic_monster(
+    commands: &mut Commands,
+    pit: Entity,
+    position: Vec3,
+    kind: EpicMonsterKind,
+) -> Entity {
+    let stats = kind.stats();
+    commands.trigger(CommandLog {
+        entity: pit,
+        info: format!("史诗野怪 {:?} 刷新", kind),
+        category: EnumLogCategory::Jungle,
+    });
+    commands
+        .spawn((
+            epic_monster_bundle(
+                kind,
+                JungleMonster {
+                    camp: pit,
+                    origin: position.xz(),
+                    camp_buff: None,
+                },
+                position.y,
+            ),
+            EpicMonster { kind, attacks: 0 },
+        ))
+        .id()
+}
+
+/// 史诗野怪的组件集合：仇恨、拉扯与奖励沿用普通野怪的约定，
+/// 体型、射程与移速按种类单独给出（男爵原地不动）。
+pub fn epic_monster_bundle(
+    kind: EpicMonsterKind,
+    monster: JungleMonster,
+    height: f32,
+) -> impl Bundle {
+    let stats = kind.stats();
+    let origin = monster.origin;
+    let (attack_range, bounding_radius, move_speed) = match kind {
+        EpicMonsterKind::Dragon(_) | EpicMonsterKind::ElderDragon => (500.0, 150.0, 330.0),
+        EpicMonsterKind::RiftHerald => (250.0, 140.0, 300.0),
+        EpicMonsterKind::BaronNashor => (500.0, 200.0, 0.0),
+    };
+    (
+        Name::new(stats.name),
+        monster,
+        Team::Neutral,
+        Transform::from_xyz(origin.x, height, origin.y),
+        Health::new(stats.health),
+        Damage(stats.damage),
+        Armor(stats.armor),
+        Attack::new(attack_range, 0.4, 2.0),
+        Bounding {
+            radius: bounding_radius,
+            height: 300.0,
+        },
+        Movement { speed: move_speed },
+        Aggro {
+            range: JUNGLE_AGGRO_RANGE,
+        },
+        GoldDrop {
+            gold_given_on_death: stats.gold,
+            gold_radius: JUNGLE_GOLD_RADIUS,
+        },
+        ExperienceDrop {
+            exp_given_on_death: stats.experience,
+            experience_radius: 1400.0,
+        },
+    )
+}
+
+fn update_epic_pits(
+    mut commands: Commands,
+    mut q_pit: Query<(Entity, &EpicPit, &Transform, &mut EpicPitState)>,
+    q_alive: Query<(), (With<EpicMonster>, Without<Death>)>,
+    res_objectives: Res<EpicObjectives>,
+    time: Res<Time<Fixed>>,
+) {
+    for (pit_entity, pit, transform, mut state) in q_pit.iter_mut() {
+        if let Some(timer) = state.timer.as_mut() {
+            timer.tick(time.delta());
+        }
+        if let Some(timer) = state.herald_timer.as_mut() {
+            timer.tick(time.delta());
+        }
+
+        // 巢穴里的野怪被击杀：主野怪进入重生倒计时，先锋不再刷新
+        if let Some((monster, kind)) = state.monster {
+            if !q_alive.contains(monster) {
+                state.monster = None;
+                state.timer = match kind {
+                    EpicMonsterKind::Dragon(_) | EpicMonsterKind::ElderDragon => {
+                        let secs = if res_objectives.soul.is_some() {
+                            ELDER_RESPAWN_SECS
+                        } else {
+                            DRAGON_RESPAWN_SECS
+                        };
+                        Some(Timer::from_seconds(secs, TimerMode::Once))
+                    }
+                    EpicMonsterKind::BaronNashor => {
+                        Some(Timer::from_seconds(BARON_RESPAWN_SECS, TimerMode::Once))
+                    }
+                    EpicMonsterKind::RiftHerald => state.timer.take(),
+                };
+            }
+        }
+
+        let position = transform.translation;
+        let main_ready = state.timer.as_ref().is_some_and(|t| t.is_finished());
+        match pit.kind {
+            EpicPitKind::Dragon => {
+                if main_ready && state.monster.is_none() {
+                    let kind = res_objectives.next_dragon();
+                    let monster = spawn_epic_monster(&mut commands, pit_entity, position, kind);
+                    state.monster = Some((monster, kind));
+                    state.timer = None;
+                }
+            }
+            EpicPitKind::Baron => {
+                if main_ready {
+                    // 男爵登场时未被击杀的先锋离场
+                    if let Some((herald, EpicMonsterKind::RiftHerald)) = state.monster {
+                        commands.entity(herald).despawn();
+                        state.monster = None;
+                    }
+                    if state.monster.is_none() {
+                        let kind = EpicMonsterKind::BaronNashor;
+                        let monster = spawn_epic_monster(&mut commands, pit_entity, position, kind);
+                        state.monster = Some((monster, kind));
+                        state.timer = None;
+                        state.herald_timer = None;
+                    }
+                } else if state.herald_timer.as_ref().is_some_and(|t| t.is_finished())
+                    && state.monster.is_none()
+                {
+                    let kind = EpicMonsterKind::RiftHerald;
+                    let monster = spawn_epic_monster(&mut commands, pit_entity, position, kind);
+                    state.monster = Some((monster, kind));
+                    state.herald_timer = None;
+                }
+            }
+        }
+    }
+}
+
+/// 史诗野怪死亡：按击杀方阵营结算团队奖励并广播目标事件。
+fn on_epic_monster_dead(
+    trigger: On<EventDead>,
+    mut commands: Commands,
+    q_epic: Query<(&EpicMonster, &Transform)>,
+    q_team: Query<&Team>,
+    q_champion: Query<(Entity, &Team, Option<&Death>), With<Champion>>,
+    mut res_objectives: ResMut<EpicObjectives>,
+) {
+    let Ok((epic, transform)) = q_epic.get(trigger.event_target()) else {
+        return;
+    };
+    let Some(team) = trigger
+        .killer
+        .and_then(|killer| q_team.get(killer).ok().copied())
+        .filter(|team| *team != Team::Neutral)
+    else {
+        return;
+    };
+
+    let team_champions = q_champion
+        .iter()
+        .filter(|(_, t, _)| **t == team)
+        .map(|(entity, _, death)| (entity, death.is_none()))
+        .collect::<Vec<_>>();
+
+    match epic.kind {
+        EpicMonsterKind::Dragon(element) => {
+            res_objectives.dragons.push((team, element));
+            for (champion, _) in team_champions.iter().copied() {
+                commands.entity(champion).with_related::<BuffOf>((
+                    BuffDragon { element },
+                    dragon_modifiers(element, 1.0),
+                ));
+            }
+            if res_objectives.soul.is_none()
+                && res_objectives.dragon_count(team) >= DRAGON_SOUL_STACKS
+            {
+                let element = res_objectives.rift_element();
+                res_objectives.soul = Some((team, element));
+                for (champion, _) in team_champions.iter().copied() {
+                    commands.entity(champion).with_related::<BuffOf>((
+                        BuffDragonSoul { element },
+                        dragon_modifiers(element, 2.0),
+                    ));
+                }
+                commands.trigger(EventDragonSoulClaimed { team, element });
+            }
+        }
+        EpicMonsterKind::ElderDragon => {
+            for (champion, alive) in team_champions.iter().copied() {
+                if alive {
+                    commands
+                        .entity(champion)
+                        .with_related::<BuffOf>(BuffElderDragon {
+                            timer: Timer::from_seconds(ELDER_BUFF_SECS, TimerMode::Once),
+                        });
+                }
+            }
+        }
+        EpicMonsterKind::BaronNashor => {
+            for (champion, alive) in team_champions.iter().copied() {
+                if alive {
+                    commands.entity(champion).with_related::<BuffOf>((
+                        BuffHandOfBaron {
+                            timer: Timer::from_seconds(BARON_BUFF_SECS, TimerMode::Once),
+                        },
+                        StatModifiers(vec![StatModifier::flat(
+                            StatKind::AttackDamage,
+                            BARON_BUFF_DAMAGE,
+                        )]),
+                    ));
+                }
+            }
+        }
+        EpicMonsterKind::RiftHerald => {
+            // 击杀方在巢穴召唤一只友方先锋去冲塔
+            let position = transform.translation;
+            commands.spawn((
+                Name::new("SRU_RiftHerald_Ally"),
+                HeraldSummon::default(),
+                Pet,
+                team,
+                Transform::from_translation(position),
+                Health::new(3000.0),
+                Damage(400.0),
+                Armor(60.0),
+                Attack::new(250.0, 0.4, 2.0),
+                Bounding {
+                    radius: 120.0,
+                    height: 200.0,
+                },
+                Movement { speed: 300.0 },
+                Lifetime::new_timer(HERALD_SUMMON_SECS),
+            ));
+        }
+    }
+
+    commands.trigger(EventEpicObjectiveTaken {
+        team,
+        monster: epic.kind,
+    });
+}
+
+/// 史诗野怪的攻击模式：
+/// - 元素龙 / 远古龙：龙息溅射，目标周围敌人受到 50% 魔法伤害；
+/// - 峡谷先锋：每第三次普攻冲撞，额外造成一倍物理伤害；
+/// - 纳什男爵：酸液溅射目标周围敌人，每第三次普攻击飞范围内所有敌人。
+fn on_epic_attack_end(
+    trigger: On<EventAttackEnd>,
+    mut commands: Commands,
+    mut q_epic: Query<(&mut EpicMonster, &Damage)>,
+    q_unit: Query<(Entity, &Transform, &Team), (With<Health>, Without<Death>)>,
+) {
+    let entity = trigger.event_target();
+    let Ok((mut epic, damage)) = q_epic.get_mut(entity) else {
+        return;
+    };
+    epic.attacks += 1;
+    let empowered = epic.attacks % 3 == 0;
+    let target = trigger.target;
+    let Ok((_, target_transform, _)) = q_unit.get(target) else {
+        return;
+    };
+    let center = target_transform.translation.xz();
+
+    let radius = match epic.kind {
+        EpicMonsterKind::Dragon(_) | EpicMonsterKind::ElderDragon => 250.0,
+        EpicMonsterKind::RiftHerald => 0.0,
+        EpicMonsterKind::BaronNashor => 300.0,
+    };
+    let splash = q_unit
+        .iter()
+        .filter(|(unit, transform, team)| {
+            *unit != target
+                && **team != Team::Neutral
+                && transform.translation.xz().distance(center) <= radius
+        })
+        .map(|(unit, ..)| unit)
+        .collect::<Vec<_>>();
+
+    match epic.kind {
+        EpicMonsterKind::Dragon(_) | EpicMonsterKind::ElderDragon => {
+            for unit in splash {
+                commands.trigger(CommandDamageCreate {
+                    entity: unit,
+                    source: entity,
+                    damage_type: DamageType::Magic,
+                    amount: damage.0 * 0.5,
+                    tag: None,
+                    kind: DamageKind::Other,
+                });
+            }
+        }
+        EpicMonsterKind::RiftHerald => {
+            if empowered {
+                commands.trigger(CommandDamageCreate {
+                    entity: target,
+                    source: entity,
+                    damage_type: DamageType::Physical,
+                    amount: damage.0,
+                    tag: None,
+                    kind: DamageKind::Other,
+                });
+            }
+        }
+        EpicMonsterKind::BaronNashor => {
+            for unit in splash.iter().copied() {
+                commands.trigger(CommandDamageCreate {
+                    entity: unit,
+                    source: entity,
+                    damage_type: DamageType::Magic,
+                    amount: damage.0,
+                    tag: None,
+                    kind: DamageKind::Other,
+                });
+            }
+            if empowered {
+                for unit in splash.into_iter().chain(std::iter::once(target)) {
+                    commands
+                        .entity(unit)
+                        .with_related::<BuffOf>(DebuffKnockup::new(0.75));
+                }
+            }
+        }
+    }
+}
+
+/// 远古龙之焰：持有者造成伤害后，若敌方英雄生命低于斩杀线则直接斩杀。
+fn on_elder_execute(
+    trigger: On<EventDamageCreate>,
+    mut commands: Commands,
+    q_buffs: Query<&Buffs>,
+    q_elder: Query<(), With<BuffElderDragon>>,
+    q_target: Query<&Health, (With<Champion>, Without<Death>)>,
+) {
+    let source = trigger.source;
+    let has_elder = q_buffs
+        .get(source)
+        .is_ok_and(|buffs| buffs.iter().any(|buff| q_elder.contains(buff)));
+    if !has_elder {
+        return;
+    }
+    let target = trigger.event_target();
+    let Ok(health) = q_target.get(target) else {
+        return;
+    };
+    if health.value <= 0.0 || health.value > health.max * ELDER_EXECUTE_THRESHOLD {
+        return;
+    }
+    commands.trigger(CommandDamageCreate {
+        entity: target,
+        source,
+        damage_type: DamageType::True,
+        amount: health.value,
+        tag: None,
+        kind: DamageKind::Other,
+    });
+}
+
+/// 被召唤的先锋锁定最近的敌方防御塔：远处先跑过去，进入范围后自动攻击。
+fn update_herald_summon(
+    mut commands: Commands,
+    mut q_herald: Query<
+        (
+            Entity,
+            &mut HeraldSummon,
+            &Team,
+            &Transform,
+            Option<&AttackAuto>,
+        ),
+        Without<Death>,
+    >,
+    q_turret: Query<(Entity, &Team, &Transform), (With<Turret>, Without<Death>)>,
+) {
+    for (entity, mut herald, team, transform, attack_auto) in q_herald.iter_mut() {
+        let position = transform.translation.xz();
+        let target = herald
+            .target
+            .filter(|target| q_turret.contains(*target))
+            .or_else(|| {
+                q_turret
+                    .iter()
+                    .filter(|(_, turret_team, _)| *turret_team != team)
+                    .min_by(|(.., a), (.., b)| {
+                        let da = a.translation.xz().distance(position);
+                        let db = b.translation.xz().distance(position);
+                        da.total_cmp(&db)
+                    })
+                    .map(|(turret, ..)| turret)
+            });
+        herald.target = target;
+        let Some(target) = target else {
+            continue;
+        };
+        if attack_auto.is_some_and(|attack_auto| attack_auto.target == target) {
+            continue;
+        }
+
+        let Ok((_, _, target_transform)) = q_turret.get(target) else {
+            continue;
+        };
+        if target_transform.translation.xz().distance(position) > 1000.0 {
+            commands.trigger(CommandRunStart {
+                entity,
+                target: RunTarget::Target(target),
+            });
+        } else {
+            commands.trigger(CommandAttackAutoStart { entity, target });
+        }
+    }
+}
+
+/// 男爵之手到期销毁，攻击力加成随 buff 撤回。
+fn update_baron_buff(
+    mut commands: Commands,
+    mut q_buff: Query<(Entity, &mut BuffHandOfBaron)>,
+    time: Res<Time<Fixed>>,
+) {
+    for (buff_entity, mut buff) in q_buff.iter_mut() {
+        buff.timer.tick(time.delta());
+        if buff.timer.is_finished() {
+            commands.entity(buff_entity).despawn();
+        }
+    }
+}
+
+fn update_elder_buff(
+    mut commands: Commands,
+    mut q_buff: Query<(Entity, &mut BuffElderDragon)>,
+    time: Res<Time<Fixed>>,
+) {
+    for (buff_entity, mut buff) in q_buff.iter_mut() {
+        buff.timer.tick(time.delta());
+        if buff.timer.is_finished() {
+            commands.entity(buff_entity).despawn();
+        }
+    }
+}
+
+/// 持有男爵之手的英雄附近的友方小兵获得减伤，离开范围后移除。
+fn update_baron_minion_empowerment(
+    mut commands: Commands,
+    q_baron_holder: Query<(&BuffOf, &BuffHandOfBaron)>,
+    q_champion: Query<(&Team, &Transform), (With<Champion>, Without<Death>)>,
+    q_minion: Query<(Entity, &Team, &Transform, Option<&Buffs>), (With<Minion>, Without<Death>)>,
+    q_empowerment: Query<(), With<BaronEmpowerment>>,
+) {
+    let holders = q_baron_holder
+        .iter()
+        .filter_map(|(buff_of, _)| q_champion.get(buff_of.0).ok())
+        .map(|(team, transform)| (*team, transform.translation.xz()))
+        .collect::<Vec<_>>();
+
+    for (minion, team, transform, buffs) in q_minion.iter() {
+        let position = transform.translation.xz();
+        let near = holders.iter().any(|(holder_team, holder_position)| {
+            holder_team == team && holder_position.distance(position) <= BARON_EMPOWER_RADIUS
+        });
+        let existing = buffs
+            .into_iter()
+            .flat_map(|buffs| buffs.iter())
+            .find(|buff| q_empowerment.contains(*buff));
+
+        match (near, existing) {
+            (true, None) => {
+                commands.entity(minion).with_related::<BuffOf>((
+                    BuffDamageReduction::new(BARON_MINION_DAMAGE_REDUCTION, None),
+                    BaronEmpowerment,
+                ));
+            }
+            (false, Some(buff)) => {
+                commands.entity(buff).despawn();
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::base::stat_modifier::PluginStatModifier;
+
+    fn app_with_epic() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins((PluginStatModifier, PluginEpicMonster));
+        app.insert_resource(Time::<Fixed>::from_hz(30.0));
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+        app
+    }
+
+    fn step(app: &mut App, frames: usize) {
+        for _ in 0..frames {
+            app.update();
+        }
+    }
+
+    /// 放置巢穴并把主野怪倒计时压到一帧内。
+    fn spawn_ready_pit(app: &mut App, kind: EpicPitKind) -> Entity {
+        let pit = app
+            .world_mut()
+            .spawn((EpicPit { kind }, Team::Neutral, Transform::default()))
+            .id();
+        step(app, 2);
+        app.world_mut().get_mut::<EpicPitState>(pit).unwrap().timer =
+            Some(Timer::from_seconds(0.01, TimerMode::Once));
+        step(app, 2);
+        pit
+    }
+
+    fn pit_monster(app: &App, pit: Entity) -> (Entity, EpicMonsterKind) {
+        app.world()
+            .get::<EpicPitState>(pit)
+            .unwrap()
+            .monster
+            .expect("巢穴应已刷新野怪")
+    }
+
+    fn kill(app: &mut App, monster: Entity, killer: Entity) {
+        app.world_mut().trigger(EventDead {
+            entity: monster,
+            killer: Some(killer),
+        });
+        app.world_mut().despawn(monster);
+        step(app, 2);
+    }
+
+    #[test]
+    fn dragon_rotation_reaches_soul_then_elder() {
+        let mut app = app_with_epic();
+        let pit = spawn_ready_pit(&mut app, EpicPitKind::Dragon);
+        let champion = app
+            .world_mut()
+            .spawn((Champion, Team::Order, Transform::default(), Damage(100.0)))
+            .id();
+
+        let (first, kind) = pit_monster(&app, pit);
+        assert_eq!(kind, EpicMonsterKind::Dragon(DragonElement::Infernal));
+        kill(&mut app, first, champion);
+        assert!((app.world().get::<Damage>(champion).unwrap().0 - 104.0).abs() < 1e-3);
+
+        for _ in 0..DRAGON_SOUL_STACKS - 1 {
+            app.world_mut().get_mut::<EpicPitState>(pit).unwrap().timer =
+                Some(Timer::from_seconds(0.01, TimerMode::Once));
+            step(&mut app, 2);
+            let (dragon, _) = pit_monster(&app, pit);
+            kill(&mut app, dragon, champion);
+        }
+
+        let objectives = app.world().resource::<EpicObjectives>();
+        assert_eq!(objectives.dragon_count(Team::Order), DRAGON_SOUL_STACKS);
+        assert_eq!(
+            objectives.soul,
+            Some((Team::Order, DragonElement::Mountain))
+        );
+        assert_eq!(objectives.next_dragon(), EpicMonsterKind::ElderDragon);
+
+        let state = app.world().get::<EpicPitState>(pit).unwrap();
+        let remaining = state.timer.as_ref().unwrap().remaining_secs();
+        assert!(
+            remaining > ELDER_RESPAWN_SECS - 1.0,
+            "龙魂后应按远古龙间隔重生"
+        );
+    }
+
+    #[test]
+    fn herald_leaves_when_baron_spawns() {
+        let mut app = app_with_epic();
+        let pit = app
+            .world_mut()
+            .spawn((
+                EpicPit {
+                    kind: EpicPitKind::Baron,
+                },
+                Team::Neutral,
+                Transform::default(),
+            ))
+            .id();
+        step(&mut app, 2);
+        app.world_mut()
+            .get_mut::<EpicPitState>(pit)
+            .unwrap()
+            .herald_timer = Some(Timer::from_seconds(0.01, TimerMode::Once));
+        step(&mut app, 2);
+        let (herald, kind) = pit_monster(&app, pit);
+        assert_eq!(kind, EpicMonsterKind::RiftHerald);
+
+        app.world_mut().get_mut::<EpicPitState>(pit).unwrap().timer =
+            Some(Timer::from_seconds(0.01, TimerMode::Once));
+        step(&mut app, 2);
+        assert!(
+            app.world().get_entity(herald).is_err(),
+            "男爵登场时先锋应离场"
+        );
+        assert_eq!(pit_monster(&app, pit).1, EpicMonsterKind::BaronNashor);
+    }
+
+    #[test]
+    fn baron_buff_empowers_nearby_minions_and_expires() {
+        let mut app = app_with_epic();
+        let pit = spawn_ready_pit(&mut app, EpicPitKind::Baron);
+        let (baron, kind) = pit_monster(&app, pit);
+        assert_eq!(kind, EpicMonsterKind::BaronNashor);
+
+        let champion = app
+            .world_mut()
+            .spawn((Champion, Team::Chaos, Transform::default(), Damage(100.0)))
+            .id();
+        let minion = app
+            .world_mut()
+            .spawn((
+                Minion::Melee,
+                Team::Chaos,
+                Transform::from_xyz(500.0, 0.0, 0.0),
+            ))
+            .id();
+
+        kill(&mut app, baron, champion);
+        step(&mut app, 2);
+        assert_eq!(
+            app.world().get::<Damage>(champion).unwrap().0,
+            100.0 + BARON_BUFF_DAMAGE
+        );
+        let empowered = |app: &App| {
+            app.world().get::<Buffs>(minion).is_some_and(|buffs| {
+                buffs
+                    .iter()
+                    .any(|buff| app.world().get::<BaronEmpowerment>(buff).is_some())
+            })
+        };
+        assert!(empowered(&app), "男爵之手附近的友方小兵应被强化");
+
+        // 男爵之手到期：攻击力回退，小兵强化移除
+        let buff = app
+            .world()
+            .get::<Buffs>(champion)
+            .unwrap()
+            .iter()
+            .find(|buff| app.world().get::<BuffHandOfBaron>(*buff).is_some())
+            .unwrap();
+        app.world_mut()
+            .get_mut::<BuffHandOfBaron>(buff)
+            .unwrap()
+            .timer
+            .set_elapsed(std::time::Duration::from_secs_f32(BARON_BUFF_SECS));
+        step(&mut app, 3);
+        assert_eq!(app.world().get::<Damage>(champion).unwrap().0, 100.0);
+        assert!(!empowered(&app));
+    }
+
+    #[test]
+    fn seed_rotates_elements_and_baron_holds_position() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(EpicObjectives::with_seed(3));
+        app.add_plugins((PluginStatModifier, PluginEpicMonster));
+        app.insert_resource(Time::<Fixed>::from_hz(30.0));
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+
+        let dragon_pit = spawn_ready_pit(&mut app, EpicPitKind::Dragon);
+        assert_eq!(
+            pit_monster(&app, dragon_pit).1,
+            EpicMonsterKind::Dragon(DragonElement::Cloud)
+        );
+
+        let baron_pit = spawn_ready_pit(&mut app, EpicPitKind::Baron);
+        let (baron, _) = pit_monster(&app, baron_pit);
+        let world = app.world();
+        assert_eq!(world.get::<Movement>(baron).unwrap().speed, 0.0);
+        assert!(world.get::<Attack>(baron).unwrap().range > 175.0);
+    }
+}
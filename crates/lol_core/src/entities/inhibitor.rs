@@ -1,11 +1,125 @@
 use bevy::prelude::*;
 
+use crate::entities::barrack::{InhibitorRespawn, InhibitorState};
+use crate::life::{Death, EventDead, EventSpawn, Health};
+use crate::team::Team;
+
+/// 水晶被摧毁后的复活时间（秒）
+pub const INHIBITOR_RESPAWN_SECS: f32 = 5.0 * 60.0;
+
 #[derive(Default)]
 pub struct PluginInhibitor;
 impl Plugin for PluginInhibitor {
-    fn build(&self, _app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InhibitorState>();
+        app.add_observer(on_inhibitor_dead);
+        app.add_systems(FixedUpdate, update_inhibitor_respawn);
+    }
 }
 
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
 pub struct Inhibitor;
+
+fn on_inhibitor_dead(
+    trigger: On<EventDead>,
+    q_inhibitor: Query<&Team, With<Inhibitor>>,
+    mut inhibitor_state: ResMut<InhibitorState>,
+) {
+    let entity = trigger.event_target();
+    let Ok(team) = q_inhibitor.get(entity) else {
+        return;
+    };
+    if inhibitor_state
+        .destroyed
+        .iter()
+        .any(|respawn| respawn.entity == entity)
+    {
+        return;
+    }
+    inhibitor_state.destroyed.push(InhibitorRespawn {
+        entity,
+        team: *team,
+        timer: Timer::from_seconds(INHIBITOR_RESPAWN_SECS, TimerMode::Once),
+    });
+    debug!(
+        "{:?} 水晶 {:?} 被摧毁，{:.0} 秒后复活",
+        team, entity, INHIBITOR_RESPAWN_SECS
+    );
+}
+
+fn update_inhibitor_respawn(
+    mut commands: Commands,
+    mut inhibitor_state: ResMut<InhibitorState>,
+    mut q_health: Query<&mut Health, With<Inhibitor>>,
+    time: Res<Time<Fixed>>,
+) {
+    inhibitor_state.destroyed.retain_mut(|respawn| {
+        respawn.timer.tick(time.delta());
+        if !respawn.timer.is_finished() {
+            return true;
+        }
+        let Ok(mut health) = q_health.get_mut(respawn.entity) else {
+            return false;
+        };
+        health.value = health.max;
+        commands.entity(respawn.entity).remove::<Death>();
+        commands.trigger(EventSpawn {
+            entity: respawn.entity,
+        });
+        debug!("{:?} 水晶 {:?} 已复活", respawn.team, respawn.entity);
+        false
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::damage::{CommandDamageCreate, DamageKind, DamageType, PluginDamage};
+    use crate::life::PluginLife;
+
+    #[test]
+    fn destroyed_inhibitor_respawns_after_timer() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins((PluginDamage, PluginLife, PluginInhibitor));
+        app.insert_resource(Time::<Fixed>::from_hz(30.0));
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+
+        let inhibitor = app
+            .world_mut()
+            .spawn((Inhibitor, Team::Chaos, Health::new(4000.0)))
+            .id();
+        app.update();
+
+        app.world_mut().trigger(CommandDamageCreate {
+            entity: inhibitor,
+            source: Entity::PLACEHOLDER,
+            damage_type: DamageType::True,
+            amount: 5000.0,
+            tag: None,
+            kind: DamageKind::Other,
+        });
+        app.update();
+
+        assert!(app.world().get::<Death>(inhibitor).is_some());
+        let state = app.world().resource::<InhibitorState>();
+        assert_eq!(state.inhibitors_down(Team::Chaos), 1);
+        assert_eq!(state.inhibitors_down(Team::Order), 0);
+
+        for _ in 0..(INHIBITOR_RESPAWN_SECS * 30.0) as usize + 2 {
+            app.update();
+        }
+
+        assert!(app.world().get::<Death>(inhibitor).is_none());
+        assert_eq!(app.world().get::<Health>(inhibitor).unwrap().value, 4000.0);
+        assert_eq!(
+            app.world()
+                .resource::<InhibitorState>()
+                .inhibitors_down(Team::Chaos),
+            0
+        );
+    }
+}
@@ -0,0 +1,737 @@
+use bevy::prelude::*;
+use lol_base::grid::{ConfigNavigationGrid, GridFlagsPOI};
+use serde::{Deserialize, Serialize};
+
+use crate::aggro::{Aggro, AggroState, EventAggroTargetFound};
+use crate::attack::{Attack, EventAttackEnd};
+use crate::attack_auto::{AttackAuto, CommandAttackAutoStart, CommandAttackAutoStop};
+use crate::base::ability_resource::AbilityResource;
+use crate::base::bounding::Bounding;
+use crate::base::buff::{
+    Buff, BuffCommandsExt, BuffDescriptor, BuffOf, BuffStacking, BuffTag, Buffs,
+};
+use crate::base::gold::GoldDrop;
+use crate::base::level::{ExperienceDrop, Level};
+use crate::buffs::cc_debuffs::{DebuffSlow, cc_descriptor};
+use crate::damage::{Armor, CommandDamageCreate, Damage, DamageKind, DamageType};
+use crate::entities::champion::Champion;
+use crate::heal::{CommandHeal, HealType};
+use crate::life::{Death, EventDead, Health};
+use crate::log::{CommandLog, EnumLogCategory};
+use crate::movement::Movement;
+use crate::navigation::grid::ResourceGrid;
+use crate::run::{CommandRunStart, RunTarget};
+use crate::skill::SkillTarget;
+use crate::summoner::{EventSummonerSpellCast, SummonerSpellKind};
+use crate::team::Team;
+
+/// 野怪离开营地超过此距离即脱战回家
+pub const JUNGLE_LEASH_RANGE: f32 = 900.0;
+/// 野怪回到营地中心此距离内视为复位完成
+pub const JUNGLE_RESET_ARRIVE_DISTANCE: f32 = 50.0;
+/// 野怪被攻击时同营地伙伴的呼救范围
+pub const JUNGLE_AGGRO_RANGE: f32 = 800.0;
+/// 野怪金币掉落分配半径
+pub const JUNGLE_GOLD_RADIUS: f32 = 1400.0;
+/// 红 / 蓝 buff 持续时间（秒）
+pub const JUNGLE_CAMP_BUFF_SECS: f32 = 120.0;
+/// 导航网格中 POI 区域少于该格子数时视为噪点，不生成营地
+pub const JUNGLE_CAMP_MIN_CELLS: usize = 4;
+/// 惩戒野怪时回复施法者的生命值
+pub const SMITE_MONSTER_HEAL: f32 = 90.0;
+
+#[derive(Default)]
+pub struct PluginJungle;
+
+impl Plugin for PluginJungle {
+    fn build(&self, app: &mut App) {
+        app.register_type::<JungleCamp>();
+        app.add_observer(on_event_aggro_target_found);
+        app.add_observer(on_jungle_monster_dead);
+        app.add_observer(on_smite_monster);
+        app.add_observer(on_attack_end_red_buff);
+        app.add_systems(
+            FixedUpdate,
+            (
+                spawn_jungle_camps_from_grid,
+                init_jungle_camp_state,
+                update_jungle_camps,
+                update_jungle_leash,
+                update_jungle_camp_buffs,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// 野怪营地种类，与导航网格的 `GridFlagsPOI::Camp*` 一一对应。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum JungleCampKind {
+    RedBuff,
+    BlueBuff,
+    Gromp,
+    Krugs,
+    Raptors,
+    MurkWolves,
+}
+
+/// 单只野怪的基础数值（近似正式服 1 级数值）。
+#[derive(Debug, Clone, Copy)]
+pub struct JungleMonsterStats {
+    pub name: &'static str,
+    pub health: f32,
+    pub damage: f32,
+    pub armor: f32,
+    pub gold: f32,
+    pub experience: f32,
+    /// 相对营地中心的出生偏移
+    pub offset: Vec2,
+    /// 击杀者是否获得营地 buff（红 / 蓝）
+    pub grants_camp_buff: bool,
+}
+
+/// 小怪相对营地中心的出生偏移
+const OFFSET_EAST: Vec2 = Vec2::new(150.0, 0.0);
+const OFFSET_WEST: Vec2 = Vec2::new(-150.0, 0.0);
+const OFFSET_NORTH: Vec2 = Vec2::new(0.0, 150.0);
+
+const fn monster(
+    name: &'static str,
+    health: f32,
+    damage: f32,
+    gold: f32,
+    experience: f32,
+    offset: Vec2,
+) -> JungleMonsterStats {
+    JungleMonsterStats {
+        name,
+        health,
+        damage,
+        armor: 20.0,
+        gold,
+        experience,
+        offset,
+        grants_camp_buff: false,
+    }
+}
+
+const RED_BUFF_MONSTERS: [JungleMonsterStats; 1] = [JungleMonsterStats {
+    armor: 30.0,
+    grants_camp_buff: true,
+    ..monster("SRU_Red", 2300.0, 82.0, 90.0, 95.0, Vec2::ZERO)
+}];
+const BLUE_BUFF_MONSTERS: [JungleMonsterStats; 1] = [JungleMonsterStats {
+    armor: 30.0,
+    grants_camp_buff: true,
+    ..monster("SRU_Blue", 2300.0, 82.0, 90.0, 95.0, Vec2::ZERO)
+}];
+const GROMP_MONSTERS: [JungleMonsterStats; 1] =
+    [monster("SRU_Gromp", 2050.0, 70.0, 80.0, 95.0, Vec2::ZERO)];
+const KRUGS_MONSTERS: [JungleMonsterStats; 2] = [
+    monster("SRU_Krug", 1250.0, 80.0, 80.0, 80.0, Vec2::ZERO),
+    monster("SRU_KrugMini", 550.0, 30.0, 15.0, 15.0, OFFSET_EAST),
+];
+const RAPTORS_MONSTERS: [JungleMonsterStats; 4] = [
+    monster("SRU_Razorbeak", 1200.0, 35.0, 35.0, 20.0, Vec2::ZERO),
+    monster("SRU_RazorbeakMini", 250.0, 13.0, 7.0, 10.0, OFFSET_EAST),
+    monster("SRU_RazorbeakMini", 250.0, 13.0, 7.0, 10.0, OFFSET_NORTH),
+    monster("SRU_RazorbeakMini", 250.0, 13.0, 7.0, 10.0, OFFSET_WEST),
+];
+const MURK_WOLVES_MONSTERS: [JungleMonsterStats; 3] = [
+    monster("SRU_Murkwolf", 1600.0, 50.0, 55.0, 55.0, Vec2::ZERO),
+    monster("SRU_MurkwolfMini", 400.0, 16.0, 15.0, 10.0, OFFSET_EAST),
+    monster("SRU_MurkwolfMini", 400.0, 16.0, 15.0, 10.0, OFFSET_WEST),
+];
+
+impl JungleCampKind {
+    pub const ALL: [JungleCampKind; 6] = [
+        JungleCampKind::RedBuff,
+        JungleCampKind::BlueBuff,
+        JungleCampKind::Gromp,
+        JungleCampKind::Krugs,
+        JungleCampKind::Raptors,
+        JungleCampKind::MurkWolves,
+    ];
+
+    /// 导航网格中标记该营地的 POI。
+    pub fn poi(self) -> GridFlagsPOI {
+        match self {
+            JungleCampKind::RedBuff => GridFlagsPOI::CampRedBuff,
+            JungleCampKind::BlueBuff => GridFlagsPOI::CampBlueBuff,
+            JungleCampKind::Gromp => GridFlagsPOI::CampGromp,
+            JungleCampKind::Krugs => GridFlagsPOI::CampKrugs,
+            JungleCampKind::Raptors => GridFlagsPOI::CampRaptors,
+            JungleCampKind::MurkWolves => GridFlagsPOI::CampMurkWolves,
+        }
+    }
+
+    /// 开局首次刷新时间（秒）
+    pub fn initial_spawn_secs(self) -> f32 {
+        90.0
+    }
+
+    /// 清空后重新刷新的间隔（秒）
+    pub fn respawn_secs(self) -> f32 {
+        match self {
+            JungleCampKind::RedBuff | JungleCampKind::BlueBuff => 300.0,
+            _ => 135.0,
+        }
+    }
+
+    pub fn monsters(self) -> &'static [JungleMonsterStats] {
+        match self {
+            JungleCampKind::RedBuff => &RED_BUFF_MONSTERS,
+            JungleCampKind::BlueBuff => &BLUE_BUFF_MONSTERS,
+            JungleCampKind::Gromp => &GROMP_MONSTERS,
+            JungleCampKind::Krugs => &KRUGS_MONSTERS,
+            JungleCampKind::Raptors => &RAPTORS_MONSTERS,
+            JungleCampKind::MurkWolves => &MURK_WOLVES_MONSTERS,
+        }
+    }
+}
+
+/// 野怪营地：可由地图场景直接放置，缺省时从导航网格 POI 推导生成。
+#[derive(Component, Reflect, Debug, Clone, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct JungleCamp {
+    pub kind: JungleCampKind,
+}
+
+/// 营地动态状态：`timer` 为下一次刷新的倒计时，野怪存活期间为 None。
+#[derive(Component, Debug, Default)]
+pub struct JungleCampState {
+    pub timer: Option<Timer>,
+    pub monsters: Vec<Entity>,
+}
+
+/// 野怪标记，记录所属营地（或史诗野怪的巢穴）与回家点。
+#[derive(Component, Debug, Clone)]
+#[require(JungleMonsterState)]
+pub struct JungleMonster {
+    pub camp: Entity,
+    pub origin: Vec2,
+    /// 击杀后交给击杀英雄的营地 buff（仅红 / 蓝怪）
+    pub camp_buff: Option<JungleCampKind>,
+}
+
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum JungleMonsterState {
+    #[default]
+    Idle,
+    Fighting,
+    /// 脱战回营地，途中不再响应仇恨
+    Resetting,
+}
+
+/// 红 buff：普攻附加真实伤害与减速，并持续回复生命。
+#[derive(Component, Debug, Clone, Default)]
+#[require(Buff = Buff { name: "JungleRed" })]
+pub struct BuffJungleRed;
+
+impl BuffJungleRed {
+    /// 再次获得时只重置持续时间，不可驱散
+    pub fn descriptor() -> BuffDescriptor {
+        BuffDescriptor::new(BuffStacking::Renew)
+            .with_duration(JUNGLE_CAMP_BUFF_SECS)
+            .with_tags(&[BuffTag::Positive, BuffTag::Offense])
+    }
+}
+
+/// 蓝 buff：持续回复法力。
+#[derive(Component, Debug, Clone, Default)]
+#[require(Buff = Buff { name: "JungleBlue" })]
+pub struct BuffJungleBlue;
+
+impl BuffJungleBlue {
+    /// 再次获得时只重置持续时间，不可驱散
+    pub fn descriptor() -> BuffDescriptor {
+        BuffDescriptor::new(BuffStacking::Renew)
+            .with_duration(JUNGLE_CAMP_BUFF_SECS)
+            .with_tags(&[BuffTag::Positive])
+    }
+}
+
+/// 红 buff 普攻附加的真实伤害，随等级成长
+fn red_buff_on_hit_damage(level: u32) -> f32 {
+    10.0 + 2.0 * level as f32
+}
+
+fn spawn_jungle_camps_from_grid(
+    mut commands: Commands,
+    mut done: Local<bool>,
+    res_grid: Option<Res<ResourceGrid>>,
+    res_assets_grid: Option<Res<Assets<ConfigNavigationGrid>>>,
+    q_camp: Query<(), With<JungleCamp>>,
+) {
+    if *done {
+        return;
+    }
+    let (Some(res_grid), Some(res_assets_grid)) = (res_grid, res_assets_grid) else {
+        return;
+    };
+    let Some(grid) = res_assets_grid.get(&res_grid.0) else {
+        return;
+    };
+    *done = true;
+
+    // 地图场景已放置营地时以场景为准
+    if !q_camp.is_empty() {
+        return;
+    }
+
+    for kind in JungleCampKind::ALL {
+        for (center, cells) in grid.poi_regions(kind.poi()) {
+            if cells < JUNGLE_CAMP_MIN_CELLS {
+                continue;
+            }
+            commands.spawn((
+                Name::new(format!("JungleCamp {:?}", kind)),
+                JungleCamp { kind },
+                Team::Neutral,
+                Transform::from_translation(grid.get_world_position_by_position(&center)),
+            ));
+        }
+    }
+}
+
+fn init_jungle_camp_state(
+    mut commands: Commands,
+    q_camp: Query<(Entity, &JungleCamp), Without<JungleCampState>>,
+) {
+    for (entity, camp) in q_camp.iter() {
+        commands.entity(entity).insert(JungleCampState {
+            timer: Some(Timer::from_seconds(
+                camp.kind.initial_spawn_secs(),
+                TimerMode::Once,
+            )),
+            monsters: Vec::new(),
+        });
+    }
+}
+
+fn update_jungle_camps(
+    mut commands: Commands,
+    mut q_camp: Query<(Entity, &JungleCamp, &Transform, &mut JungleCampState)>,
+    q_monster: Query<(), (With<JungleMonster>, Without<Death>)>,
+    time: Res<Time<Fixed>>,
+) {
+    for (camp_entity, camp, transform, mut state) in q_camp.iter_mut() {
+        if !state.monsters.is_empty() {
+            state
+                .monsters
+                .retain(|monster| q_monster.contains(*monster));
+            if state.monsters.is_empty() {
+                commands.trigger(CommandLog {
+                    entity: camp_entity,
+                    info: format!("{:?} 营地被清空", camp.kind),
+                    category: EnumLogCategory::Jungle,
+                });
+                state.timer = Some(Timer::from_seconds(
+                    camp.kind.respawn_secs(),
+                    TimerMode::Once,
+                ));
+            }
+            continue;
+        }
+
+        let Some(timer) = state.timer.as_mut() else {
+            continue;
+        };
+        timer.tick(time.delta());
+        if !timer.is_finished() {
+            continue;
+        }
+        state.timer = None;
+
+        let center = transform.translation;
+        for stats in camp.kind.monsters() {
+            let origin = center.xz() + stats.offset;
+            let monster = commands
+                .spawn(jungle_monster_bundle(
+                    stats,
+                    JungleMonster {
+                        camp: camp_entity,
+                        origin,
+                        camp_buff: stats.grants_camp_buff.then_some(camp.kind),
+                    },
+                    center.y,
+                ))
+                .id();
+            state.monsters.push(monster);
+        }
+        commands.trigger(CommandLog {
+            entity: camp_entity,
+            info: format!("{:?} 营地刷新 {} 只野怪", camp.kind, state.monsters.len()),
+            category: EnumLogCategory::Jungle,
+        });
+    }
+}
+
+/// 野怪实体的通用组件集合：被动仇恨、拉扯复位与击杀奖励均由此驱动。
+pub fn jungle_monster_bundle(
+    stats: &JungleMonsterStats,
+    monster: JungleMonster,
+    height: f32,
+) -> impl Bundle {
+    let origin = monster.origin;
+    (
+        Name::new(stats.name),
+        monster,
+        Team::Neutral,
+        Transform::from_xyz(origin.x, height, origin.y),
+        Health::new(stats.health),
+        Damage(stats.damage),
+        Armor(stats.armor),
+        Attack::new(175.0, 0.3, 1.25),
+        Bounding {
+            radius: 65.0,
+            height: 150.0,
+        },
+        Movement { speed: 350.0 },
+        Aggro {
+            range: JUNGLE_AGGRO_RANGE,
+        },
+        GoldDrop {
+            gold_given_on_death: stats.gold,
+            gold_radius: JUNGLE_GOLD_RADIUS,
+        },
+        ExperienceDrop {
+            exp_given_on_death: stats.experience,
+            experience_radius: 1400.0,
+        },
+    )
+}
+
+fn on_event_aggro_target_found(
+    trigger: On<EventAggroTargetFound>,
+    mut commands: Commands,
+    mut q_monster: Query<(&mut JungleMonsterState, Option<&AttackAuto>), With<JungleMonster>>,
+) {
+    let entity = trigger.event_target();
+    let Ok((mut state, attack_auto)) = q_monster.get_mut(entity) else {
+        return;
+    };
+    if *state == JungleMonsterState::Resetting {
+        return;
+    }
+
+    *state = JungleMonsterState::Fighting;
+    let target = trigger.target;
+    if attack_auto.is_none_or(|attack_auto| attack_auto.target != target) {
+        commands.trigger(CommandAttackAutoStart { entity, target });
+    }
+}
+
+/// 拉扯与复位：离营地过远或失去目标时清空仇恨并回到营地，回到营地后回满生命。
+fn update_jungle_leash(
+    mut commands: Commands,
+    mut q_monster: Query<
+        (
+            Entity,
+            &JungleMonster,
+            &mut JungleMonsterState,
+            &Transform,
+            &mut Health,
+            &mut AggroState,
+            Option<&AttackAuto>,
+        ),
+        Without<Death>,
+    >,
+    q_alive: Query<(), (With<Health>, Without<Death>)>,
+) {
+    for (entity, monster, mut state, transform, mut health, mut aggro_state, attack_auto) in
+        q_monster.iter_mut()
+    {
+        let distance = transform.translation.xz().distance(monster.origin);
+        match *state {
+            JungleMonsterState::Idle => {}
+            JungleMonsterState::Fighting => {
+                let has_target =
+                    attack_auto.is_some_and(|attack_auto| q_alive.contains(attack_auto.target));
+                if distance <= JUNGLE_LEASH_RANGE && has_target {
+                    continue;
+                }
+                *state = JungleMonsterState::Resetting;
+                aggro_state.aggros.clear();
+                commands.trigger(CommandLog {
+                    entity,
+                    info: "脱战，返回营地".to_string(),
+                    category: EnumLogCategory::Jungle,
+                });
+                commands.trigger(CommandAttackAutoStop { entity });
+                commands.trigger(CommandRunStart {
+                    entity,
+                    target: RunTarget::Position(monster.origin),
+                });
+            }
+            JungleMonsterState::Resetting => {
+                if distance > JUNGLE_RESET_ARRIVE_DISTANCE {
+                    continue;
+                }
+                *state = JungleMonsterState::Idle;
+                aggro_state.aggros.clear();
+                health.value = health.max;
+            }
+        }
+    }
+}
+
+/// 野怪死亡：红 / 蓝怪把对应 buff 交给击杀英雄（已有同类 buff 时刷新）。
+fn on_jungle_monster_dead(
+    trigger: On<EventDead>,
+    mut commands: Commands,
+    q_monster: Query<&JungleMonster>,
+    q_champion: Query<(), With<Champion>>,
+) {
+    let Some(camp_buff) = q_monster
+        .get(trigger.event_target())
+        .ok()
+        .and_then(|monster| monster.camp_buff)
+    else {
+        return;
+    };
+    let Some(killer) = trigger.killer else {
+        return;
+    };
+    if !q_champion.contains(killer) {
+        return;
+    }
+
+    match camp_buff {
+        JungleCampKind::RedBuff => {
+            commands
+                .entity(killer)
+                .apply_buff(BuffJungleRed::descriptor(), BuffJungleRed);
+        }
+        JungleCampKind::BlueBuff => {
+            commands
+                .entity(killer)
+                .apply_buff(BuffJungleBlue::descriptor(), BuffJungleBlue);
+        }
+        _ => {}
+    }
+}
+
+/// 惩戒命中野怪时回复施法者生命。
+fn on_smite_monster(
+    trigger: On<EventSummonerSpellCast>,
+    mut commands: Commands,
+    q_monster: Query<(), With<JungleMonster>>,
+) {
+    if trigger.kind != SummonerSpellKind::Smite {
+        return;
+    }
+    let SkillTarget::Unit(target) = trigger.target else {
+        return;
+    };
+    if !q_monster.contains(target) {
+        return;
+    }
+    commands.trigger(CommandHeal {
+        entity: trigger.entity,
+        source: trigger.entity,
+        heal_type: HealType::Heal,
+        amount: SMITE_MONSTER_HEAL,
+    });
+}
+
+fn on_attack_end_red_buff(
+    trigger: On<EventAttackEnd>,
+    mut commands: Commands,
+    q_buffs: Query<(&Buffs, Option<&Level>)>,
+    q_red: Query<(), With<BuffJungleRed>>,
+) {
+    let attacker = trigger.event_target();
+    let Ok((buffs, level)) = q_buffs.get(attacker) else {
+        return;
+    };
+    if !buffs.iter().any(|buff| q_red.contains(buff)) {
+        return;
+    }
+
+    let target = trigger.target;
+    commands.trigger(CommandDamageCreate {
+        entity: target,
+        source: attacker,
+        damage_type: DamageType::True,
+        amount: red_buff_on_hit_damage(level.map(|l| l.value).unwrap_or(1)),
+        tag: None,
+        kind: DamageKind::Other,
+    });
+    commands
+        .entity(target)
+        .apply_buff(cc_descriptor(attacker), DebuffSlow::new(0.1, 1.0));
+}
+
+/// 红 buff 每秒回复 1% 最大生命，蓝 buff 每秒回复 1% 最大法力；到期由描述计时器销毁。
+fn update_jungle_camp_buffs(
+    q_red: Query<&BuffOf, With<BuffJungleRed>>,
+    q_blue: Query<&BuffOf, With<BuffJungleBlue>>,
+    mut q_health: Query<&mut Health, Without<Death>>,
+    mut q_resource: Query<&mut AbilityResource, Without<Death>>,
+    time: Res<Time<Fixed>>,
+) {
+    let dt = time.delta_secs();
+    for buff_of in q_red.iter() {
+        if let Ok(mut health) = q_health.get_mut(buff_of.0) {
+            health.value = (health.value + health.max * 0.01 * dt).min(health.max);
+        }
+    }
+    for buff_of in q_blue.iter() {
+        if let Ok(mut resource) = q_resource.get_mut(buff_of.0) {
+            resource.value = (resource.value + resource.max * 0.01 * dt).min(resource.max);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    fn app_with_jungle() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(PluginJungle);
+        app.insert_resource(Time::<Fixed>::from_hz(30.0));
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+        app
+    }
+
+    fn step(app: &mut App, frames: usize) {
+        for _ in 0..frames {
+            app.update();
+        }
+    }
+
+    /// 放置营地并把刷新倒计时压到一帧内。
+    fn spawn_ready_camp(app: &mut App, kind: JungleCampKind) -> Entity {
+        let camp = app
+            .world_mut()
+            .spawn((JungleCamp { kind }, Team::Neutral, Transform::default()))
+            .id();
+        step(app, 2);
+        app.world_mut()
+            .get_mut::<JungleCampState>(camp)
+            .unwrap()
+            .timer = Some(Timer::from_seconds(0.01, TimerMode::Once));
+        step(app, 2);
+        camp
+    }
+
+    #[test]
+    fn camp_spawns_monsters_and_respawns_after_clear() {
+        let mut app = app_with_jungle();
+        let camp = spawn_ready_camp(&mut app, JungleCampKind::Raptors);
+
+        let monsters = app
+            .world()
+            .get::<JungleCampState>(camp)
+            .unwrap()
+            .monsters
+            .clone();
+        assert_eq!(monsters.len(), JungleCampKind::Raptors.monsters().len());
+        for monster in &monsters {
+            assert_eq!(app.world().get::<Team>(*monster), Some(&Team::Neutral));
+            assert!(app.world().get::<GoldDrop>(*monster).is_some());
+            assert!(app.world().get::<ExperienceDrop>(*monster).is_some());
+        }
+
+        for monster in monsters {
+            app.world_mut().despawn(monster);
+        }
+        step(&mut app, 2);
+
+        let state = app.world().get::<JungleCampState>(camp).unwrap();
+        assert!(state.monsters.is_empty());
+        let remaining = state.timer.as_ref().unwrap().remaining_secs();
+        assert!(
+            remaining > JungleCampKind::Raptors.respawn_secs() - 1.0,
+            "清空后应进入重生倒计时，实际剩余 {remaining}"
+        );
+    }
+
+    #[test]
+    fn monster_leashes_home_and_resets_health() {
+        let mut app = app_with_jungle();
+        let camp = spawn_ready_camp(&mut app, JungleCampKind::Gromp);
+        let gromp = app.world().get::<JungleCampState>(camp).unwrap().monsters[0];
+
+        // 被拉到营地外并受伤
+        {
+            let world = app.world_mut();
+            let mut entity = world.entity_mut(gromp);
+            *entity.get_mut::<JungleMonsterState>().unwrap() = JungleMonsterState::Fighting;
+            entity.get_mut::<Transform>().unwrap().translation.x = JUNGLE_LEASH_RANGE + 100.0;
+            entity.get_mut::<Health>().unwrap().value = 500.0;
+            entity
+                .get_mut::<AggroState>()
+                .unwrap()
+                .aggros
+                .insert(camp, 10.0);
+        }
+        step(&mut app, 2);
+        assert_eq!(
+            app.world().get::<JungleMonsterState>(gromp),
+            Some(&JungleMonsterState::Resetting)
+        );
+        assert!(
+            app.world()
+                .get::<AggroState>(gromp)
+                .unwrap()
+                .aggros
+                .is_empty()
+        );
+
+        // 回到营地后复位并回满生命
+        app.world_mut()
+            .get_mut::<Transform>(gromp)
+            .unwrap()
+            .translation
+            .x = 0.0;
+        step(&mut app, 2);
+        assert_eq!(
+            app.world().get::<JungleMonsterState>(gromp),
+            Some(&JungleMonsterState::Idle)
+        );
+        let health = app.world().get::<Health>(gromp).unwrap();
+        assert_eq!(health.value, health.max);
+    }
+
+    #[test]
+    fn red_buff_goes_to_killing_champion() {
+        let mut app = app_with_jungle();
+        let camp = spawn_ready_camp(&mut app, JungleCampKind::RedBuff);
+        let red = app.world().get::<JungleCampState>(camp).unwrap().monsters[0];
+        let champion = app
+            .world_mut()
+            .spawn((Champion, Team::Order, Transform::default()))
+            .id();
+
+        // 第二次击杀只刷新已有红 buff 的持续时间
+        for _ in 0..2 {
+            app.world_mut().trigger(EventDead {
+                entity: red,
+                killer: Some(champion),
+            });
+            app.world_mut().flush();
+        }
+
+        let buffs = app
+            .world()
+            .get::<Buffs>(champion)
+            .expect("击杀者应获得 buff");
+        let red_buffs = buffs
+            .iter()
+            .filter(|buff| app.world().get::<BuffJungleRed>(*buff).is_some())
+            .collect::<Vec<_>>();
+        assert_eq!(red_buffs.len(), 1, "击杀红怪应获得且只有一个红 buff");
+        let descriptor = app
+            .world()
+            .get::<BuffDescriptor>(red_buffs[0])
+            .expect("红 buff 应带描述");
+        assert_eq!(descriptor.stacking, BuffStacking::Renew);
+        assert!(descriptor.tags.contains(&BuffTag::Positive));
+    }
+}
@@ -1,13 +1,531 @@
+//! 召唤物与分身：主人关系、默认 AI、主人指挥与镜像主人的分身。
+//!
+//! 召唤物通过 [`PetOf`] 指向主人；其造成的伤害与击杀经 [`resolve_owner`] 归到主人名下，
+//! 由生命、赏金等结算统一处理。
+
 use bevy::prelude::*;
+use lol_base::character::{ConfigSkin, HealthBar, LoadedSkin, Skin};
+
+use crate::aggro::{Aggro, EventAggroTargetFound};
+use crate::attack::Attack;
+use crate::attack_auto::{AttackAuto, CommandAttackAutoStart};
+use crate::base::bounding::Bounding;
+use crate::damage::{AbilityPower, Armor, Damage, MagicResist, Untargetable};
+use crate::entities::champion::TargetableAsChampion;
+use crate::life::{Death, Health};
+use crate::lifetime::Lifetime;
+use crate::movement::Movement;
+use crate::run::{CommandRunStart, CommandRunStop, Run, RunTarget};
+use crate::team::Team;
+
+/// 召唤物默认索敌范围
+const PET_AGGRO_RANGE: f32 = 600.0;
+/// 召唤物离主人超过该距离时放弃目标
+const PET_LEASH_RANGE: f32 = 1200.0;
+/// 跟随时与主人保持的距离
+const PET_FOLLOW_DISTANCE: f32 = 250.0;
+
+#[derive(Default)]
+pub struct PluginPet;
+
+impl Plugin for PluginPet {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_command_pet_order);
+        app.add_observer(on_command_clone_spawn);
+        app.add_observer(on_pet_aggro_target_found);
+        app.add_systems(FixedUpdate, update_pet_ai);
+    }
+}
 
 /// 召唤物组件，用于标识安妮的提伯斯、婕拉的植物等特殊单位
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
 pub struct Pet;
 
-#[derive(Default)]
-pub struct PluginPet;
+/// 召唤物 -> 主人
+#[derive(Component, Debug)]
+#[relationship(relationship_target = Pets)]
+pub struct PetOf(pub Entity);
 
-impl Plugin for PluginPet {
-    fn build(&self, _app: &mut App) {}
+/// 主人名下的召唤物
+#[derive(Component, Debug)]
+#[relationship_target(relationship = PetOf)]
+pub struct Pets(Vec<Entity>);
+
+impl std::ops::Deref for Pets {
+    type Target = Vec<Entity>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// 召唤物当前执行的指令
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PetOrder {
+    /// 跟随主人，按仇恨自动索敌
+    #[default]
+    Follow,
+    /// 攻击指定单位，目标失效后回到跟随
+    Attack(Entity),
+    /// 移动到指定位置，到达后回到跟随
+    MoveTo(Vec2),
+}
+
+/// 召唤物默认 AI
+#[derive(Component, Debug, Clone)]
+#[require(Aggro = Aggro { range: PET_AGGRO_RANGE })]
+pub struct PetAi {
+    pub order: PetOrder,
+    /// 离主人超过该距离时放弃目标回到主人身边
+    pub leash_range: f32,
+    /// 跟随时与主人保持的距离
+    pub follow_distance: f32,
+    /// 是否接受主人指挥（如安妮 R 重新施放指挥提伯斯）
+    pub controllable: bool,
+}
+
+impl Default for PetAi {
+    fn default() -> Self {
+        Self {
+            order: PetOrder::Follow,
+            leash_range: PET_LEASH_RANGE,
+            follow_distance: PET_FOLLOW_DISTANCE,
+            controllable: false,
+        }
+    }
+}
+
+impl PetAi {
+    /// 可被主人指挥的召唤物
+    pub fn controllable() -> Self {
+        Self {
+            controllable: true,
+            ..default()
+        }
+    }
+
+    pub fn with_order(mut self, order: PetOrder) -> Self {
+        self.order = order;
+        self
+    }
+}
+
+/// 分身：镜像主人外观与属性的召唤物（萨科 R、乐芙兰被动、妮蔻 W 等）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PetClone {
+    /// 造成伤害倍率
+    pub damage_dealt: f32,
+    /// 承受伤害倍率
+    pub damage_taken: f32,
+}
+
+impl PetClone {
+    pub fn new(damage_dealt: f32, damage_taken: f32) -> Self {
+        Self {
+            damage_dealt,
+            damage_taken,
+        }
+    }
+}
+
+/// 主人指挥名下可控的召唤物，触发于主人
+#[derive(EntityEvent, Debug, Clone)]
+pub struct CommandPetOrder {
+    pub entity: Entity,
+    pub order: PetOrder,
+}
+
+/// 生成主人的分身，触发于主人
+#[derive(EntityEvent, Debug, Clone)]
+pub struct CommandCloneSpawn {
+    pub entity: Entity,
+    pub position: Vec2,
+    /// 存活时长（秒）
+    pub duration: f32,
+    pub pet_clone: PetClone,
+    pub ai: PetAi,
+}
+
+/// 伤害来源若是召唤物则返回其主人，否则返回来源本身
+pub fn resolve_owner(q_pet_of: &Query<&PetOf>, entity: Entity) -> Entity {
+    q_pet_of
+        .get(entity)
+        .map(|pet_of| pet_of.0)
+        .unwrap_or(entity)
+}
+
+fn on_command_pet_order(
+    trigger: On<CommandPetOrder>,
+    mut commands: Commands,
+    q_pets: Query<&Pets>,
+    mut q_ai: Query<&mut PetAi, Without<Death>>,
+) {
+    let owner = trigger.event_target();
+    let Ok(pets) = q_pets.get(owner) else {
+        return;
+    };
+
+    for &pet in pets.iter() {
+        let Ok(mut ai) = q_ai.get_mut(pet) else {
+            continue;
+        };
+        if !ai.controllable {
+            continue;
+        }
+        ai.order = trigger.order;
+        apply_order(&mut commands, pet, trigger.order);
+    }
+}
+
+fn apply_order(commands: &mut Commands, pet: Entity, order: PetOrder) {
+    match order {
+        PetOrder::Follow => {}
+        PetOrder::Attack(target) => {
+            commands.trigger(CommandAttackAutoStart {
+                entity: pet,
+                target,
+            });
+        }
+        PetOrder::MoveTo(position) => {
+            commands.entity(pet).remove::<AttackAuto>();
+            commands.trigger(CommandRunStart {
+                entity: pet,
+                target: RunTarget::Position(position),
+            });
+        }
+    }
+}
+
+/// 跟随状态下发现仇恨目标：未在攻击且仍在主人附近时开始自动攻击
+fn on_pet_aggro_target_found(
+    trigger: On<EventAggroTargetFound>,
+    mut commands: Commands,
+    q_pet: Query<(&PetAi, &PetOf, &Transform, Option<&AttackAuto>), Without<Death>>,
+    q_transform: Query<&Transform>,
+) {
+    let entity = trigger.event_target();
+    let Ok((ai, pet_of, transform, attack_auto)) = q_pet.get(entity) else {
+        return;
+    };
+    if ai.order != PetOrder::Follow || attack_auto.is_some() {
+        return;
+    }
+    let Ok(owner_transform) = q_transform.get(pet_of.0) else {
+        return;
+    };
+    let distance = transform
+        .translation
+        .xz()
+        .distance(owner_transform.translation.xz());
+    if distance > ai.leash_range {
+        return;
+    }
+
+    commands.trigger(CommandAttackAutoStart {
+        entity,
+        target: trigger.target,
+    });
+}
+
+fn update_pet_ai(
+    mut commands: Commands,
+    mut q_pet: Query<
+        (
+            Entity,
+            &mut PetAi,
+            &PetOf,
+            &Transform,
+            Option<&AttackAuto>,
+            Option<&Run>,
+        ),
+        Without<Death>,
+    >,
+    q_transform: Query<&Transform>,
+    q_target: Query<(), (Without<Death>, Without<Untargetable>)>,
+) {
+    for (entity, mut ai, pet_of, transform, attack_auto, run) in q_pet.iter_mut() {
+        let owner = pet_of.0;
+        let Ok(owner_transform) = q_transform.get(owner) else {
+            continue;
+        };
+        let distance = transform
+            .translation
+            .xz()
+            .distance(owner_transform.translation.xz());
+
+        match ai.order {
+            PetOrder::Attack(target) => {
+                if !q_target.contains(target) {
+                    ai.order = PetOrder::Follow;
+                    commands.entity(entity).remove::<AttackAuto>();
+                } else if attack_auto.is_none_or(|a| a.target != target) {
+                    commands.trigger(CommandAttackAutoStart { entity, target });
+                }
+            }
+            PetOrder::MoveTo(_) => {
+                // 寻路结束时 Run 被移除，视为到达
+                if run.is_none() {
+                    ai.order = PetOrder::Follow;
+                }
+            }
+            PetOrder::Follow => {
+                if attack_auto.is_some() {
+                    // 超出牵引范围：放弃目标回到主人身边
+                    if distance > ai.leash_range {
+                        commands.entity(entity).remove::<AttackAuto>();
+                        commands.trigger(CommandRunStart {
+                            entity,
+                            target: RunTarget::Target(owner),
+                        });
+                    }
+                    continue;
+                }
+
+                let following = run.is_some_and(
+                    |run| matches!(run.target, RunTarget::Target(target) if target == owner),
+                );
+                if distance > ai.follow_distance {
+                    if run.is_none() {
+                        commands.trigger(CommandRunStart {
+                            entity,
+                            target: RunTarget::Target(owner),
+                        });
+                    }
+                } else if following {
+                    commands.trigger(CommandRunStop { entity });
+                }
+            }
+        }
+    }
+}
+
+fn on_command_clone_spawn(trigger: On<CommandCloneSpawn>, mut commands: Commands) {
+    let event = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        spawn_clone(world, &event);
+    });
+}
+
+/// 生成分身：复制主人的队伍、属性与外观，挂上召唤物 AI 与生命周期
+fn spawn_clone(world: &mut World, event: &CommandCloneSpawn) -> Option<Entity> {
+    let owner = event.entity;
+    let owner_ref = world.get_entity(owner).ok()?;
+    let team = *owner_ref.get::<Team>()?;
+    let owner_transform = owner_ref.get::<Transform>().copied().unwrap_or_default();
+    let loaded_skin = owner_ref.get::<LoadedSkin>().map(|s| s.skin.clone());
+
+    let order = event.ai.order;
+    let clone = world
+        .spawn((
+            Pet,
+            PetOf(owner),
+            event.pet_clone,
+            event.ai.clone(),
+            team,
+            Transform::from_xyz(
+                event.position.x,
+                owner_transform.translation.y,
+                event.position.y,
+            )
+            .with_rotation(owner_transform.rotation),
+            Lifetime::new_timer(event.duration),
+        ))
+        .id();
+
+    mirror::<Name>(world, owner, clone);
+    mirror::<Health>(world, owner, clone);
+    mirror::<Damage>(world, owner, clone);
+    mirror::<Armor>(world, owner, clone);
+    mirror::<MagicResist>(world, owner, clone);
+    mirror::<AbilityPower>(world, owner, clone);
+    mirror::<Attack>(world, owner, clone);
+    mirror::<Movement>(world, owner, clone);
+    mirror::<Bounding>(world, owner, clone);
+    mirror::<Skin>(world, owner, clone);
+    mirror::<HealthBar>(world, owner, clone);
+    // 分身在目标选择上与英雄本体无异，但不复制 Champion 本身以免带上经验、金币与复活结算
+    mirror::<TargetableAsChampion>(world, owner, clone);
+    if let Some(skin) = loaded_skin {
+        world.entity_mut(clone).insert(ConfigSkin { skin });
+    }
+
+    if let PetOrder::MoveTo(position) = order {
+        world.trigger(CommandRunStart {
+            entity: clone,
+            target: RunTarget::Position(position),
+        });
+    }
+
+    debug!("{:?} 生成分身 {:?}", owner, clone);
+    Some(clone)
+}
+
+fn mirror<T: Component + Clone>(world: &mut World, from: Entity, to: Entity) {
+    if let Some(value) = world.get::<T>(from).cloned() {
+        world.entity_mut(to).insert(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::SystemState;
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct Captured {
+        attacks: Vec<(Entity, Entity)>,
+        runs: Vec<(Entity, Vec2)>,
+    }
+
+    fn app_with_pets() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(PluginPet);
+        app.insert_resource(Time::<Fixed>::from_hz(30.0));
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+        app.init_resource::<Captured>();
+        app.add_observer(
+            |trigger: On<CommandAttackAutoStart>, mut captured: ResMut<Captured>| {
+                captured
+                    .attacks
+                    .push((trigger.event_target(), trigger.target));
+            },
+        );
+        app.add_observer(
+            |trigger: On<CommandRunStart>, mut captured: ResMut<Captured>| {
+                if let RunTarget::Position(position) = trigger.target {
+                    captured.runs.push((trigger.event_target(), position));
+                }
+            },
+        );
+        app
+    }
+
+    fn spawn_owner(app: &mut App) -> Entity {
+        app.world_mut()
+            .spawn((
+                Name::new("Shaco"),
+                Team::Order,
+                Transform::default(),
+                Health {
+                    value: 420.0,
+                    max: 600.0,
+                    ..default()
+                },
+                Damage(70.0),
+                Armor(30.0),
+                MagicResist(32.0),
+                TargetableAsChampion,
+            ))
+            .id()
+    }
+
+    #[test]
+    fn clone_mirrors_owner_and_links_back() {
+        let mut app = app_with_pets();
+        let owner = spawn_owner(&mut app);
+
+        app.world_mut().trigger(CommandCloneSpawn {
+            entity: owner,
+            position: Vec2::new(300.0, 0.0),
+            duration: 18.0,
+            pet_clone: PetClone::new(0.75, 1.5),
+            ai: PetAi::controllable(),
+        });
+        app.update();
+
+        let pets: Vec<Entity> = app.world().get::<Pets>(owner).unwrap().to_vec();
+        assert_eq!(pets.len(), 1);
+        let clone = pets[0];
+        let world = app.world();
+        assert_eq!(world.get::<Team>(clone), Some(&Team::Order));
+        assert_eq!(world.get::<Name>(clone).unwrap().as_str(), "Shaco");
+        assert_eq!(world.get::<Health>(clone).unwrap().value, 420.0);
+        assert_eq!(world.get::<Damage>(clone).unwrap().0, 70.0);
+        assert_eq!(world.get::<MagicResist>(clone).unwrap().0, 32.0);
+        assert_eq!(world.get::<PetClone>(clone).unwrap().damage_taken, 1.5);
+        assert!(world.get::<TargetableAsChampion>(clone).is_some());
+        assert_eq!(
+            world.get::<Transform>(clone).unwrap().translation.xz(),
+            Vec2::new(300.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn owner_orders_only_controllable_pets() {
+        let mut app = app_with_pets();
+        let owner = spawn_owner(&mut app);
+        let enemy = app
+            .world_mut()
+            .spawn((Team::Chaos, Transform::default()))
+            .id();
+        let tibbers = app
+            .world_mut()
+            .spawn((
+                Pet,
+                PetOf(owner),
+                PetAi::controllable(),
+                Team::Order,
+                Transform::default(),
+            ))
+            .id();
+        let minion = app
+            .world_mut()
+            .spawn((
+                Pet,
+                PetOf(owner),
+                PetAi::default(),
+                Team::Order,
+                Transform::default(),
+            ))
+            .id();
+
+        app.world_mut().trigger(CommandPetOrder {
+            entity: owner,
+            order: PetOrder::Attack(enemy),
+        });
+        app.world_mut().flush();
+        assert_eq!(
+            app.world().resource::<Captured>().attacks,
+            vec![(tibbers, enemy)]
+        );
+        assert_eq!(
+            app.world().get::<PetAi>(minion).unwrap().order,
+            PetOrder::Follow
+        );
+
+        // 目标消失后回到跟随
+        app.world_mut().despawn(enemy);
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().get::<PetAi>(tibbers).unwrap().order,
+            PetOrder::Follow
+        );
+
+        app.world_mut().trigger(CommandPetOrder {
+            entity: owner,
+            order: PetOrder::MoveTo(Vec2::new(500.0, 200.0)),
+        });
+        app.world_mut().flush();
+        assert_eq!(
+            app.world().resource::<Captured>().runs,
+            vec![(tibbers, Vec2::new(500.0, 200.0))]
+        );
+    }
+
+    #[test]
+    fn resolve_owner_maps_pet_to_owner() {
+        let mut app = app_with_pets();
+        let owner = spawn_owner(&mut app);
+        let pet = app.world_mut().spawn((Pet, PetOf(owner))).id();
+
+        let mut state = SystemState::<Query<&PetOf>>::new(app.world_mut());
+        let q_pet_of = state.get(app.world());
+        assert_eq!(resolve_owner(&q_pet_of, pet), owner);
+        assert_eq!(resolve_owner(&q_pet_of, owner), owner);
+    }
 }
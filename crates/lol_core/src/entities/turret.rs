@@ -2,18 +2,53 @@ use bevy::prelude::*;
 
 use crate::aggro::{Aggro, EventAggroTargetFound};
 use crate::attack_auto::{CommandAttackAutoStart, CommandAttackAutoStop};
+use crate::base::buff::{Buff, BuffOf, Buffs};
+use crate::base::gold::{EventGoldGain, Gold};
+use crate::base::stat_modifier::{StatKind, StatModifier, StatModifiers};
+use crate::buffs::damage_reduction::BuffDamageReduction;
 use crate::damage::{Damage, EventDamageCreate};
 use crate::entities::champion::Champion;
+use crate::entities::inhibitor::Inhibitor;
+use crate::entities::minion::Minion;
+use crate::entities::nexus::Nexus;
+use crate::life::{Death, Health};
 use crate::log::{CommandLog, EnumLogCategory};
+use crate::spatial::{SpatialCandidates, SpatialFilter, SpatialKind, SpatialShape};
+use crate::team::Team;
+
+/// 外塔镀层数量
+pub const TURRET_PLATE_COUNT: u32 = 5;
+/// 每块镀层的金币，由附近的敌方英雄平分
+pub const TURRET_PLATE_GOLD: f32 = 125.0;
+/// 镀层金币的分享范围
+pub const TURRET_PLATE_SHARE_RANGE: f32 = 1200.0;
+/// 镀层脱落时间（秒）：14 分钟后镀层消失，不再产出金币
+pub const TURRET_PLATING_FALLOFF_SECS: f32 = 14.0 * 60.0;
+/// 前期加固的持续时间（秒）
+pub const TURRET_FORTIFICATION_SECS: f32 = 5.0 * 60.0;
+/// 前期加固提供的护甲与魔抗
+pub const TURRET_FORTIFICATION_RESISTS: f32 = 100.0;
+/// 偷塔保护：该范围内没有敌方小兵时生效
+pub const BACKDOOR_MINION_RANGE: f32 = 1000.0;
+/// 偷塔保护的伤害减免
+pub const BACKDOOR_REDUCTION: f32 = 0.66;
 
 #[derive(Default)]
 pub struct PluginTurret;
 
 impl Plugin for PluginTurret {
     fn build(&self, app: &mut App) {
+        app.register_type::<TurretTier>();
+        app.register_type::<TurretPlating>();
         app.add_observer(on_event_aggro_target_found);
         app.add_observer(on_command_attack_auto_stop);
         app.add_observer(on_event_damage_create);
+        app.add_observer(on_add_turret_tier);
+        app.add_observer(on_damage_break_plates);
+        app.add_systems(
+            FixedUpdate,
+            (update_turret_plating_falloff, update_structure_defenses),
+        );
     }
 }
 
@@ -34,6 +69,76 @@ pub struct TurretHeat {
     pub base_damage: f32,
 }
 
+/// 防御塔所在的层级，由地图提取时按角色名写入
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Component)]
+pub enum TurretTier {
+    /// 外塔：带镀层
+    #[default]
+    Outer,
+    Inner,
+    Inhibitor,
+    Nexus,
+}
+
+impl TurretTier {
+    /// 按角色名尾号识别层级（如 `SRUAP_Turret_Order1` 为外塔）
+    pub fn from_character_name(name: &str) -> Option<Self> {
+        match name.chars().last()? {
+            '1' => Some(Self::Outer),
+            '2' => Some(Self::Inner),
+            '3' => Some(Self::Inhibitor),
+            '4' => Some(Self::Nexus),
+            _ => None,
+        }
+    }
+}
+
+/// 外塔镀层：生命值按 `TURRET_PLATE_COUNT + 1` 等分，前若干段各对应一块镀层
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct TurretPlating {
+    pub plates_remaining: u32,
+}
+
+impl Default for TurretPlating {
+    fn default() -> Self {
+        Self {
+            plates_remaining: TURRET_PLATE_COUNT,
+        }
+    }
+}
+
+/// 镀层被击破，`team` 为失去镀层的一方
+#[derive(Event, Debug, Clone)]
+pub struct EventTurretPlateDestroyed {
+    pub turret: Entity,
+    pub team: Team,
+    pub plates_remaining: u32,
+}
+
+/// 前期加固：经属性修正层提高防御塔的护甲与魔抗
+#[derive(Component, Debug, Clone)]
+#[require(
+    Buff = Buff { name: "TurretFortification" },
+    StatModifiers = StatModifiers(vec![
+        StatModifier::flat(StatKind::Armor, TURRET_FORTIFICATION_RESISTS),
+        StatModifier::flat(StatKind::MagicResist, TURRET_FORTIFICATION_RESISTS),
+    ])
+)]
+pub struct BuffTurretFortification;
+
+/// 偷塔保护：附近没有敌方小兵时，内塔及以后的建筑（含水晶与主水晶）受到的伤害降低
+#[derive(Component, Debug, Clone)]
+#[require(
+    Buff = Buff { name: "BackdoorProtection" },
+    BuffDamageReduction = BuffDamageReduction {
+        percentage: BACKDOOR_REDUCTION,
+        damage_type: None,
+    }
+)]
+pub struct BuffBackdoorProtection;
+
 fn on_event_aggro_target_found(
     trigger: On<EventAggroTargetFound>,
     mut commands: Commands,
@@ -124,20 +229,215 @@ fn on_event_damage_create(
     });
 }
 
+fn on_add_turret_tier(
+    trigger: On<Add, TurretTier>,
+    mut commands: Commands,
+    q_tier: Query<&TurretTier>,
+    time: Res<Time<Fixed>>,
+) {
+    let Ok(tier) = q_tier.get(trigger.entity) else {
+        return;
+    };
+    if *tier == TurretTier::Outer && time.elapsed_secs() < TURRET_PLATING_FALLOFF_SECS {
+        commands
+            .entity(trigger.entity)
+            .insert(TurretPlating::default());
+    }
+}
+
+/// 生命值跌破镀层分段即击破镀层，金币由附近存活的敌方英雄平分。
+fn on_damage_break_plates(
+    trigger: On<EventDamageCreate>,
+    mut commands: Commands,
+    mut q_turret: Query<(&mut TurretPlating, &Health, &Team, &Transform)>,
+    q_champion: Query<(Entity, &Team, &Transform), (With<Champion>, Without<Death>)>,
+    mut q_gold: Query<&mut Gold>,
+) {
+    let turret = trigger.event_target();
+    let Ok((mut plating, health, team, transform)) = q_turret.get_mut(turret) else {
+        return;
+    };
+
+    let segment = health.max / (TURRET_PLATE_COUNT + 1) as f32;
+    while plating.plates_remaining > 0 && health.value <= segment * plating.plates_remaining as f32
+    {
+        plating.plates_remaining -= 1;
+
+        let position = transform.translation.xz();
+        let receivers = q_champion
+            .iter()
+            .filter(|(_, champion_team, champion_transform)| {
+                *champion_team != team
+                    && **champion_team != Team::Neutral
+                    && champion_transform.translation.xz().distance(position)
+                        <= TURRET_PLATE_SHARE_RANGE
+            })
+            .map(|(entity, _, _)| entity)
+            .collect::<Vec<_>>();
+        if !receivers.is_empty() {
+            let share = TURRET_PLATE_GOLD / receivers.len() as f32;
+            for receiver in receivers {
+                let Ok(mut gold) = q_gold.get_mut(receiver) else {
+                    continue;
+                };
+                gold.current += share;
+                gold.total += share;
+                commands.trigger(EventGoldGain {
+                    entity: receiver,
+                    amount: share,
+                    world_position: transform.translation,
+                });
+            }
+        }
+
+        commands.trigger(CommandLog {
+            entity: turret,
+            info: format!("镀层被击破，剩余 {} 块", plating.plates_remaining),
+            category: EnumLogCategory::Turret,
+        });
+        commands.trigger(EventTurretPlateDestroyed {
+            turret,
+            team: *team,
+            plates_remaining: plating.plates_remaining,
+        });
+    }
+}
+
+fn update_turret_plating_falloff(
+    mut commands: Commands,
+    q_plating: Query<Entity, With<TurretPlating>>,
+    time: Res<Time<Fixed>>,
+) {
+    if time.elapsed_secs() < TURRET_PLATING_FALLOFF_SECS {
+        return;
+    }
+    for entity in q_plating.iter() {
+        commands.entity(entity).remove::<TurretPlating>();
+        debug!("{:?} 镀层脱落", entity);
+    }
+}
+
+/// 同步建筑的前期加固与偷塔保护 buff。
+fn update_structure_defenses(
+    mut commands: Commands,
+    q_structure: Query<
+        (
+            Entity,
+            &Team,
+            &Transform,
+            Option<&TurretTier>,
+            Has<Turret>,
+            Option<&Buffs>,
+        ),
+        (
+            Or<(With<Turret>, With<Inhibitor>, With<Nexus>)>,
+            Without<Death>,
+        ),
+    >,
+    q_minion: Query<(Entity, &Team, &Transform), (With<Minion>, Without<Death>)>,
+    q_fortification: Query<(), With<BuffTurretFortification>>,
+    q_backdoor: Query<(), With<BuffBackdoorProtection>>,
+    spatial: SpatialCandidates,
+    mut candidates: Local<Vec<Entity>>,
+    time: Res<Time<Fixed>>,
+) {
+    let early_game = time.elapsed_secs() < TURRET_FORTIFICATION_SECS;
+
+    for (entity, team, transform, tier, is_turret, buffs) in q_structure.iter() {
+        let fortified = is_turret && early_game;
+        let backdoor_eligible = !is_turret || tier.is_some_and(|t| *t != TurretTier::Outer);
+        let position = transform.translation.xz();
+
+        // 先粗筛附近的敌方小兵
+        candidates.clear();
+        if backdoor_eligible {
+            spatial.query_into(
+                &SpatialShape::Circle {
+                    center: position,
+                    radius: BACKDOOR_MINION_RANGE,
+                },
+                SpatialFilter::enemies_of(*team).with_kind(SpatialKind::Minion),
+                q_minion.iter().map(|(e, ..)| e),
+                &mut candidates,
+            );
+        }
+        let protected = backdoor_eligible
+            && !q_minion
+                .iter_many(candidates.iter())
+                .any(|(_, minion_team, minion_transform)| {
+                    minion_team != team
+                        && minion_transform.translation.xz().distance(position)
+                            <= BACKDOOR_MINION_RANGE
+                });
+
+        let fortification =
+            buffs.and_then(|buffs| buffs.iter().find(|b| q_fortification.contains(*b)));
+        let backdoor = buffs.and_then(|buffs| buffs.iter().find(|b| q_backdoor.contains(*b)));
+        toggle_buff(
+            &mut commands,
+            entity,
+            fortification,
+            fortified,
+            BuffTurretFortification,
+        );
+        toggle_buff(
+            &mut commands,
+            entity,
+            backdoor,
+            protected,
+            BuffBackdoorProtection,
+        );
+    }
+}
+
+fn toggle_buff<B: Bundle>(
+    commands: &mut Commands,
+    entity: Entity,
+    existing: Option<Entity>,
+    active: bool,
+    bundle: B,
+) {
+    match (active, existing) {
+        (true, None) => {
+            commands.entity(entity).with_related::<BuffOf>(bundle);
+        }
+        (false, Some(buff)) => {
+            commands.entity(buff).despawn();
+        }
+        _ => {}
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use bevy::time::TimeUpdateStrategy;
+
     use super::*;
-    use crate::damage::{DamageResult, DamageType, EventDamageCreate};
+    use crate::base::stat_modifier::PluginStatModifier;
+    use crate::damage::{
+        Armor, DamageKind, DamageResult, DamageType, EventDamageCreate, MagicResist,
+    };
+    use crate::spatial::PluginSpatial;
     use crate::team::Team;
 
     fn setup_app() -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
-        app.add_plugins(PluginTurret);
+        app.add_plugins((PluginTurret, PluginStatModifier, PluginSpatial));
+        app.insert_resource(Time::<Fixed>::from_hz(30.0));
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
         app.update();
         app
     }
 
+    fn has_buff<B: Component>(app: &App, entity: Entity) -> bool {
+        app.world().get::<Buffs>(entity).is_some_and(|buffs| {
+            buffs
+                .iter()
+                .any(|buff| app.world().get::<B>(buff).is_some())
+        })
+    }
+
     fn mock_damage_result() -> DamageResult {
         DamageResult {
             final_damage: 10.0,
@@ -166,6 +466,7 @@ mod tests {
             damage_type: DamageType::Physical,
             damage_result: mock_damage_result(),
             tag: None,
+            kind: DamageKind::Other,
         });
 
         // 检查伤害是否增加到 140
@@ -179,6 +480,7 @@ mod tests {
             damage_type: DamageType::Physical,
             damage_result: mock_damage_result(),
             tag: None,
+            kind: DamageKind::Other,
         });
 
         let damage = app.world().get::<Damage>(turret).unwrap();
@@ -191,6 +493,7 @@ mod tests {
             damage_type: DamageType::Physical,
             damage_result: mock_damage_result(),
             tag: None,
+            kind: DamageKind::Other,
         });
 
         let damage = app.world().get::<Damage>(turret).unwrap();
@@ -203,6 +506,7 @@ mod tests {
             damage_type: DamageType::Physical,
             damage_result: mock_damage_result(),
             tag: None,
+            kind: DamageKind::Other,
         });
 
         let damage = app.world().get::<Damage>(turret).unwrap();
@@ -227,6 +531,7 @@ mod tests {
             damage_type: DamageType::Physical,
             damage_result: mock_damage_result(),
             tag: None,
+            kind: DamageKind::Other,
         });
 
         assert_eq!(app.world().get::<Damage>(turret).unwrap().0, 140.0);
@@ -241,4 +546,124 @@ mod tests {
         let heat = app.world().get::<TurretHeat>(turret).unwrap();
         assert_eq!(heat.heat_level, 0, "切换目标后加热等级应重置");
     }
+
+    #[test]
+    fn plates_break_share_gold_and_fall_off() {
+        let mut app = setup_app();
+        let turret = app
+            .world_mut()
+            .spawn((
+                Turret,
+                TurretTier::Outer,
+                Team::Order,
+                Health::new(6000.0),
+                Transform::default(),
+            ))
+            .id();
+        let near = app
+            .world_mut()
+            .spawn((Champion, Team::Chaos, Transform::from_xyz(500.0, 0.0, 0.0)))
+            .id();
+        let near_too = app
+            .world_mut()
+            .spawn((Champion, Team::Chaos, Transform::from_xyz(0.0, 0.0, 500.0)))
+            .id();
+        let far = app
+            .world_mut()
+            .spawn((Champion, Team::Chaos, Transform::from_xyz(5000.0, 0.0, 0.0)))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world()
+                .get::<TurretPlating>(turret)
+                .unwrap()
+                .plates_remaining,
+            TURRET_PLATE_COUNT
+        );
+
+        // 从 6000 打到 3900：跌破 5000 与 4000 两个分段
+        app.world_mut().get_mut::<Health>(turret).unwrap().value = 3900.0;
+        app.world_mut().trigger(EventDamageCreate {
+            entity: turret,
+            source: near,
+            damage_type: DamageType::Physical,
+            damage_result: mock_damage_result(),
+            tag: None,
+            kind: DamageKind::Other,
+        });
+        app.update();
+
+        assert_eq!(
+            app.world()
+                .get::<TurretPlating>(turret)
+                .unwrap()
+                .plates_remaining,
+            TURRET_PLATE_COUNT - 2
+        );
+        let gold = |entity| app.world().get::<Gold>(entity).unwrap().total;
+        assert_eq!(gold(near), TURRET_PLATE_GOLD);
+        assert_eq!(gold(near_too), TURRET_PLATE_GOLD);
+        assert_eq!(gold(far), 0.0);
+
+        app.world_mut().resource_mut::<Time<Fixed>>().advance_to(
+            std::time::Duration::from_secs_f32(TURRET_PLATING_FALLOFF_SECS),
+        );
+        app.update();
+        assert!(app.world().get::<TurretPlating>(turret).is_none());
+    }
+
+    #[test]
+    fn backdoor_protection_requires_enemy_minions_nearby() {
+        let mut app = setup_app();
+        let inner = app
+            .world_mut()
+            .spawn((Turret, TurretTier::Inner, Team::Order, Transform::default()))
+            .id();
+        let outer = app
+            .world_mut()
+            .spawn((
+                Turret,
+                TurretTier::Outer,
+                Team::Order,
+                Transform::from_xyz(5000.0, 0.0, 0.0),
+            ))
+            .id();
+        let nexus = app
+            .world_mut()
+            .spawn((Nexus, Team::Order, Transform::from_xyz(0.0, 0.0, 500.0)))
+            .id();
+        app.update();
+        app.update();
+
+        assert!(has_buff::<BuffBackdoorProtection>(&app, inner));
+        assert!(has_buff::<BuffBackdoorProtection>(&app, nexus));
+        assert!(
+            !has_buff::<BuffBackdoorProtection>(&app, outer),
+            "外塔没有偷塔保护"
+        );
+        assert!(has_buff::<BuffTurretFortification>(&app, inner));
+        assert!(has_buff::<BuffTurretFortification>(&app, outer));
+        assert!(!has_buff::<BuffTurretFortification>(&app, nexus));
+        assert_eq!(
+            app.world().get::<Armor>(outer).unwrap().0,
+            TURRET_FORTIFICATION_RESISTS
+        );
+        assert_eq!(
+            app.world().get::<MagicResist>(outer).unwrap().0,
+            TURRET_FORTIFICATION_RESISTS
+        );
+
+        app.world_mut().spawn((
+            Minion::Melee,
+            Team::Chaos,
+            Transform::from_xyz(300.0, 0.0, 0.0),
+        ));
+        app.update();
+        app.update();
+        assert!(
+            !has_buff::<BuffBackdoorProtection>(&app, inner),
+            "敌方小兵在场时失去偷塔保护"
+        );
+        assert!(!has_buff::<BuffBackdoorProtection>(&app, nexus));
+    }
 }
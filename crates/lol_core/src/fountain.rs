@@ -0,0 +1,277 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::base::ability_resource::AbilityResource;
+use crate::damage::{CommandDamageCreate, DamageKind, DamageType};
+use crate::entities::champion::Champion;
+use crate::life::{Death, Health};
+use crate::team::Team;
+
+/// 泉水平台半径：平台内己方英雄快速回复，同时即商店区
+pub const FOUNTAIN_PLATFORM_RADIUS: f32 = 1100.0;
+/// 泉水平台内每秒回复的最大生命 / 最大法力比例
+pub const FOUNTAIN_REGEN_RATIO: f32 = 0.1;
+/// 泉水激光射程
+pub const FOUNTAIN_LASER_RANGE: f32 = 1300.0;
+/// 泉水激光攻击间隔（秒）
+pub const FOUNTAIN_LASER_INTERVAL_SECS: f32 = 0.25;
+/// 泉水激光单次真实伤害
+pub const FOUNTAIN_LASER_DAMAGE: f32 = 400.0;
+
+#[derive(Default)]
+pub struct PluginFountain;
+
+impl Plugin for PluginFountain {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Fountain>();
+        app.add_systems(
+            FixedUpdate,
+            (
+                spawn_default_fountains,
+                update_fountain_regen,
+                update_fountain_laser,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// 泉水：可由地图场景直接放置（需带 `Team` 与 `Transform`），缺省时按阵营默认位置生成。
+#[derive(Component, Reflect, Debug, Clone, Default, Serialize, Deserialize)]
+#[reflect(Component)]
+#[require(FountainLaser)]
+pub struct Fountain;
+
+/// 泉水激光的攻击节奏
+#[derive(Component, Debug, Clone)]
+pub struct FountainLaser {
+    pub timer: Timer,
+}
+
+impl Default for FountainLaser {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(FOUNTAIN_LASER_INTERVAL_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+/// 阵营默认泉水位置（地面高度对齐真实地图），地图未放置泉水时在此生成。
+pub fn fountain_position(team: Team) -> Option<Vec3> {
+    match team {
+        Team::Order => Some(Vec3::new(1000.0, 146.97, 1000.0)),
+        Team::Chaos => Some(Vec3::new(14000.0, 159.54, 14000.0)),
+        Team::Neutral => None,
+    }
+}
+
+/// 场上泉水查询：复活 / 回城的落点与商店区判定共用。
+///
+/// 落点取场上己方泉水实体的位置（地图场景可自行放置），尚未生成时退回阵营默认位置；
+/// 调用方对 `Transform` 的可变查询需带 `Without<Fountain>`。
+#[derive(SystemParam)]
+pub struct Fountains<'w, 's> {
+    q_fountain: Query<'w, 's, (&'static Team, &'static Transform), With<Fountain>>,
+}
+
+impl Fountains<'_, '_> {
+    pub fn position(&self, team: Team) -> Option<Vec3> {
+        self.q_fountain
+            .iter()
+            .find(|(fountain_team, _)| **fountain_team == team)
+            .map(|(_, transform)| transform.translation)
+            .or_else(|| fountain_position(team))
+    }
+
+    /// 商店区判定，供购买逻辑使用：单位位于己方泉水平台内才可购买。
+    pub fn in_shop(&self, team: Team, position: Vec2) -> bool {
+        self.q_fountain.iter().any(|(fountain_team, transform)| {
+            *fountain_team == team
+                && transform.translation.xz().distance(position) <= FOUNTAIN_PLATFORM_RADIUS
+        })
+    }
+}
+
+fn spawn_default_fountains(
+    mut commands: Commands,
+    mut done: Local<bool>,
+    q_fountain: Query<(), With<Fountain>>,
+) {
+    if *done {
+        return;
+    }
+    *done = true;
+
+    // 地图场景已放置泉水时以场景为准
+    if !q_fountain.is_empty() {
+        return;
+    }
+
+    for team in [Team::Order, Team::Chaos] {
+        let Some(position) = fountain_position(team) else {
+            continue;
+        };
+        commands.spawn((
+            Name::new(format!("Fountain {:?}", team)),
+            Fountain,
+            team,
+            Transform::from_translation(position),
+        ));
+    }
+}
+
+/// 泉水平台内的己方存活英雄按比例快速回复生命与法力。
+fn update_fountain_regen(
+    q_fountain: Query<(&Team, &Transform), With<Fountain>>,
+    mut q_champion: Query<
+        (&Team, &Transform, &mut Health, Option<&mut AbilityResource>),
+        (With<Champion>, Without<Death>),
+    >,
+    time: Res<Time<Fixed>>,
+) {
+    let dt = time.delta_secs();
+    if dt <= 0.0 {
+        return;
+    }
+
+    for (team, transform, mut health, ability_resource) in q_champion.iter_mut() {
+        let position = transform.translation.xz();
+        let in_fountain = q_fountain
+            .iter()
+            .any(|(fountain_team, fountain_transform)| {
+                fountain_team == team
+                    && fountain_transform.translation.xz().distance(position)
+                        <= FOUNTAIN_PLATFORM_RADIUS
+            });
+        if !in_fountain {
+            continue;
+        }
+
+        health.value = (health.value + health.max * FOUNTAIN_REGEN_RATIO * dt).min(health.max);
+        if let Some(mut ability_resource) = ability_resource {
+            ability_resource.value = (ability_resource.value
+                + ability_resource.max * FOUNTAIN_REGEN_RATIO * dt)
+                .min(ability_resource.max);
+        }
+    }
+}
+
+/// 泉水激光：每个节拍对射程内所有敌方存活单位造成真实伤害。
+fn update_fountain_laser(
+    mut commands: Commands,
+    mut q_fountain: Query<(Entity, &Team, &Transform, &mut FountainLaser), With<Fountain>>,
+    q_unit: Query<(Entity, &Team, &Transform), (With<Health>, Without<Death>, Without<Fountain>)>,
+    time: Res<Time<Fixed>>,
+) {
+    for (fountain, fountain_team, fountain_transform, mut laser) in q_fountain.iter_mut() {
+        laser.timer.tick(time.delta());
+        if !laser.timer.just_finished() {
+            continue;
+        }
+
+        let center = fountain_transform.translation.xz();
+        for (unit, team, transform) in q_unit.iter() {
+            if team == fountain_team || *team == Team::Neutral {
+                continue;
+            }
+            if transform.translation.xz().distance(center) > FOUNTAIN_LASER_RANGE {
+                continue;
+            }
+            commands.trigger(CommandDamageCreate {
+                entity: unit,
+                source: fountain,
+                damage_type: DamageType::True,
+                amount: FOUNTAIN_LASER_DAMAGE,
+                tag: None,
+                kind: DamageKind::Other,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::damage::PluginDamage;
+    use crate::life::PluginLife;
+
+    fn app_with_fountain() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins((PluginDamage, PluginLife, PluginFountain));
+        app.insert_resource(Time::<Fixed>::from_hz(30.0));
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+        app
+    }
+
+    fn step(app: &mut App, frames: usize) {
+        for _ in 0..frames {
+            app.update();
+        }
+    }
+
+    fn spawn_champion(app: &mut App, team: Team, position: Vec3, health: f32) -> Entity {
+        let mut max_health = Health::new(1000.0);
+        max_health.value = health;
+        app.world_mut()
+            .spawn((
+                Champion,
+                team,
+                Transform::from_translation(position),
+                max_health,
+            ))
+            .id()
+    }
+
+    #[test]
+    fn fountain_heals_allies_and_lasers_enemies() {
+        let mut app = app_with_fountain();
+        let fountain = fountain_position(Team::Order).unwrap();
+        let ally = spawn_champion(&mut app, Team::Order, fountain, 100.0);
+        let enemy = spawn_champion(&mut app, Team::Chaos, fountain, 1000.0);
+        let outside = spawn_champion(
+            &mut app,
+            Team::Order,
+            fountain + Vec3::new(3000.0, 0.0, 0.0),
+            100.0,
+        );
+
+        step(&mut app, 30);
+
+        let ally_health = app.world().get::<Health>(ally).unwrap().value;
+        assert!(
+            ally_health > 150.0,
+            "平台内己方英雄应快速回复，实际 {ally_health}"
+        );
+        assert_eq!(app.world().get::<Health>(outside).unwrap().value, 100.0);
+        assert!(
+            app.world().get::<Death>(enemy).is_some(),
+            "泉水激光应击杀平台内的敌方英雄"
+        );
+    }
+
+    #[test]
+    fn shop_zone_only_covers_own_fountain() {
+        let mut app = app_with_fountain();
+        step(&mut app, 2);
+
+        let order = fountain_position(Team::Order).unwrap().xz();
+        let chaos = fountain_position(Team::Chaos).unwrap().xz();
+        let result = app
+            .world_mut()
+            .run_system_once(move |fountains: Fountains| {
+                (
+                    fountains.in_shop(Team::Order, order),
+                    fountains.in_shop(Team::Order, chaos),
+                    fountains.in_shop(Team::Chaos, chaos + Vec2::new(500.0, 0.0)),
+                    fountains.in_shop(Team::Order, Vec2::new(7000.0, 7000.0)),
+                )
+            })
+            .unwrap();
+        assert_eq!(result, (true, false, true, false));
+    }
+}
@@ -0,0 +1,460 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::base::buff::{BuffDescriptor, BuffOf, Buffs};
+use crate::buffs::common_buffs::{DebuffGrievousWounds, grievous_wounds_factor};
+use crate::buffs::shield_magic::BuffShieldMagic;
+use crate::buffs::shield_white::BuffShieldWhite;
+use crate::damage::{DamageKind, EventDamageCreate};
+use crate::life::Health;
+
+/// 治疗系统插件
+#[derive(Default)]
+pub struct PluginHeal;
+
+impl Plugin for PluginHeal {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HealShieldPower>();
+        app.add_observer(on_command_heal);
+        app.add_observer(on_insert_shield_white_apply_power);
+        app.add_observer(on_insert_shield_magic_apply_power);
+        app.add_observer(on_damage_life_steal);
+        app.add_observer(on_damage_omnivamp);
+    }
+}
+
+/// 治疗与护盾强度：按比例放大持有者施放的治疗与护盾。
+///
+/// 例如 0.2 表示治疗量 / 护盾值提升 20%，缺失时按 0 处理。
+/// 经属性修正层的 `StatKind::HealShieldPower` 授予。
+#[derive(Component, Reflect, Clone, Serialize, Deserialize, Default)]
+#[reflect(Component)]
+pub struct HealShieldPower(pub f32);
+
+/// 治疗类型枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HealType {
+    /// 技能、召唤师技能等主动治疗，受来源的治疗与护盾强度加成
+    #[default]
+    Heal,
+    /// 生命偷取 / 全能吸血，不受治疗与护盾强度加成
+    Vamp,
+}
+
+/// 吸血：挂在 buff 实体上作用于持有者，挂在其它实体上作用于自身，多个来源相加。
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Vamp {
+    /// 生命偷取：按普攻造成的实际伤害的比例回复
+    pub life_steal: f32,
+    /// 全能吸血：按造成的所有伤害的比例回复
+    pub omnivamp: f32,
+}
+
+/// 治疗命令：对目标施加瞬时治疗，依次结算治疗强度、重伤，并夹取到 health.max。
+///
+/// 用法：`commands.trigger(CommandHeal { entity, source, heal_type, amount });`
+#[derive(EntityEvent, Debug)]
+pub struct CommandHeal {
+    pub entity: Entity,
+    /// 治疗来源实体
+    pub source: Entity,
+    /// 治疗类型
+    pub heal_type: HealType,
+    /// 治疗数值
+    pub amount: f32,
+}
+
+/// 治疗结算完成事件，`heal_result` 记录各环节的增减。
+#[derive(EntityEvent, Debug)]
+pub struct EventHealApplied {
+    pub entity: Entity,
+    pub source: Entity,
+    pub heal_type: HealType,
+    pub heal_result: HealResult,
+}
+
+/// 治疗计算结果
+#[derive(Debug)]
+pub struct HealResult {
+    /// 实际回复的生命值
+    pub final_heal: f32,
+    /// 超出生命上限被浪费的治疗
+    pub overheal: f32,
+    /// 被重伤削减的治疗
+    pub grievous_reduced: f32,
+    /// 治疗与护盾强度带来的额外治疗
+    pub amplified: f32,
+    /// 原始治疗
+    pub original_heal: f32,
+}
+
+/// 治疗与护盾强度系数：`1 + 强度`，缺失时为 1。
+pub fn heal_shield_power_factor(power: Option<&HealShieldPower>) -> f32 {
+    1.0 + power.map(|p| p.0.max(0.0)).unwrap_or(0.0)
+}
+
+/// 治疗系统 - 处理治疗命令。死亡目标不生效。
+pub fn on_command_heal(
+    trigger: On<CommandHeal>,
+    mut commands: Commands,
+    mut q_health: Query<(&mut Health, Option<&Buffs>)>,
+    q_grievous: Query<&DebuffGrievousWounds>,
+    q_power: Query<&HealShieldPower>,
+) {
+    let entity = trigger.event_target();
+    let Ok((mut health, buffs)) = q_health.get_mut(entity) else {
+        return;
+    };
+    if health.value <= 0.0 || health.max <= 0.0 {
+        return;
+    }
+
+    let original_heal = trigger.amount.max(0.0);
+    let mut remaining_heal = original_heal;
+
+    // 主动治疗按来源的治疗与护盾强度放大
+    let mut amplified = 0.0;
+    if trigger.heal_type == HealType::Heal {
+        let factor = heal_shield_power_factor(q_power.get(trigger.source).ok());
+        amplified = remaining_heal * (factor - 1.0);
+        remaining_heal += amplified;
+    }
+
+    // 重伤削减目标受到的所有治疗
+    let grievous_reduced = remaining_heal * (1.0 - grievous_wounds_factor(buffs, &q_grievous));
+    remaining_heal -= grievous_reduced;
+
+    let before = health.value;
+    health.value = (health.value + remaining_heal).min(health.max);
+    let final_heal = health.value - before;
+
+    let result = HealResult {
+        final_heal,
+        overheal: remaining_heal - final_heal,
+        grievous_reduced,
+        amplified,
+        original_heal,
+    };
+
+    debug!(
+        "{:?} 治疗 {:?} 类型 {:?} 原始 {:.1} 强度加成 {:.1} 重伤削减 {:.1} 实际 {:.1} 溢出 {:.1}（{:.1} → {:.1}）",
+        trigger.source,
+        entity,
+        trigger.heal_type,
+        result.original_heal,
+        result.amplified,
+        result.grievous_reduced,
+        result.final_heal,
+        result.overheal,
+        before,
+        health.value,
+    );
+
+    commands.trigger(EventHealApplied {
+        entity,
+        source: trigger.source,
+        heal_type: trigger.heal_type,
+        heal_result: result,
+    });
+}
+
+/// 护盾施放者的治疗与护盾强度系数。
+///
+/// 施放者取 [`BuffDescriptor::source`]，没有描述时视为持有者给自己套盾。
+fn shield_power_factor(
+    buff: Entity,
+    q_buff_of: &Query<(&BuffOf, Option<&BuffDescriptor>)>,
+    q_power: &Query<&HealShieldPower>,
+) -> f32 {
+    let Ok((buff_of, descriptor)) = q_buff_of.get(buff) else {
+        return 1.0;
+    };
+    let source = descriptor.and_then(|d| d.source).unwrap_or(buff_of.0);
+    heal_shield_power_factor(q_power.get(source).ok())
+}
+
+/// 白色护盾写入（新套盾或重新写入护盾值刷新）时，按施放者的治疗与护盾强度放大护盾值。
+fn on_insert_shield_white_apply_power(
+    trigger: On<Insert, BuffShieldWhite>,
+    q_buff_of: Query<(&BuffOf, Option<&BuffDescriptor>)>,
+    q_power: Query<&HealShieldPower>,
+    mut q_shield: Query<&mut BuffShieldWhite>,
+) {
+    let buff = trigger.entity;
+    let factor = shield_power_factor(buff, &q_buff_of, &q_power);
+    if factor <= 1.0 {
+        return;
+    }
+    if let Ok(mut shield) = q_shield.get_mut(buff) {
+        shield.current *= factor;
+        shield.max *= factor;
+    }
+}
+
+/// 魔法护盾写入时，按施放者的治疗与护盾强度放大护盾值。
+fn on_insert_shield_magic_apply_power(
+    trigger: On<Insert, BuffShieldMagic>,
+    q_buff_of: Query<(&BuffOf, Option<&BuffDescriptor>)>,
+    q_power: Query<&HealShieldPower>,
+    mut q_shield: Query<&mut BuffShieldMagic>,
+) {
+    let buff = trigger.entity;
+    let factor = shield_power_factor(buff, &q_buff_of, &q_power);
+    if factor <= 1.0 {
+        return;
+    }
+    if let Ok(mut shield) = q_shield.get_mut(buff) {
+        shield.current *= factor;
+        shield.max *= factor;
+    }
+}
+
+/// 汇总实体自身与其 buff 上的吸血
+fn total_vamp(entity: Entity, q_buffs: &Query<&Buffs>, q_vamp: &Query<&Vamp>) -> Vamp {
+    let own = q_vamp.get(entity).ok().copied();
+    let from_buffs = q_buffs
+        .get(entity)
+        .into_iter()
+        .flat_map(|buffs| buffs.iter())
+        .filter_map(|buff| q_vamp.get(buff).ok().copied());
+    own.into_iter()
+        .chain(from_buffs)
+        .fold(Vamp::default(), |total, vamp| Vamp {
+            life_steal: total.life_steal + vamp.life_steal,
+            omnivamp: total.omnivamp + vamp.omnivamp,
+        })
+}
+
+/// 普攻造成伤害：按实际伤害与生命偷取回复攻击者。
+fn on_damage_life_steal(
+    trigger: On<EventDamageCreate>,
+    mut commands: Commands,
+    q_buffs: Query<&Buffs>,
+    q_vamp: Query<&Vamp>,
+) {
+    if trigger.kind != DamageKind::BasicAttack {
+        return;
+    }
+    let attacker = trigger.source;
+    let life_steal = total_vamp(attacker, &q_buffs, &q_vamp).life_steal;
+    let amount = trigger.damage_result.final_damage * life_steal;
+    if amount <= 0.0 {
+        return;
+    }
+    commands.trigger(CommandHeal {
+        entity: attacker,
+        source: attacker,
+        heal_type: HealType::Vamp,
+        amount,
+    });
+}
+
+/// 造成伤害：按实际伤害与全能吸血回复伤害来源。
+fn on_damage_omnivamp(
+    trigger: On<EventDamageCreate>,
+    mut commands: Commands,
+    q_buffs: Query<&Buffs>,
+    q_vamp: Query<&Vamp>,
+) {
+    let source = trigger.source;
+    if source == trigger.event_target() {
+        return;
+    }
+    let omnivamp = total_vamp(source, &q_buffs, &q_vamp).omnivamp;
+    let amount = trigger.damage_result.final_damage * omnivamp;
+    if amount <= 0.0 {
+        return;
+    }
+    commands.trigger(CommandHeal {
+        entity: source,
+        source,
+        heal_type: HealType::Vamp,
+        amount,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct CapturedHeals(Vec<(f32, f32, f32, f32)>);
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(PluginHeal);
+        app.init_resource::<CapturedHeals>();
+        app.add_observer(
+            |trigger: On<EventHealApplied>, mut captured: ResMut<CapturedHeals>| {
+                let r = &trigger.heal_result;
+                captured
+                    .0
+                    .push((r.final_heal, r.overheal, r.grievous_reduced, r.amplified));
+            },
+        );
+        app
+    }
+
+    fn spawn_target(app: &mut App, value: f32) -> Entity {
+        let mut health = Health::new(1000.0);
+        health.value = value;
+        app.world_mut().spawn(health).id()
+    }
+
+    #[test]
+    fn heal_power_amplifies_and_grievous_wounds_reduce() {
+        let mut app = setup_app();
+        let source = app.world_mut().spawn(HealShieldPower(0.2)).id();
+        let target = spawn_target(&mut app, 500.0);
+        app.world_mut()
+            .entity_mut(target)
+            .with_related::<BuffOf>(DebuffGrievousWounds::new(0.4, 5.0));
+
+        app.world_mut().trigger(CommandHeal {
+            entity: target,
+            source,
+            heal_type: HealType::Heal,
+            amount: 100.0,
+        });
+        app.update();
+
+        // 100 * 1.2 = 120，重伤 40% 削减 48，实际 72
+        let health = app.world().get::<Health>(target).unwrap().value;
+        assert!((health - 572.0).abs() < 1e-3, "实际 {health}");
+        let (final_heal, overheal, grievous, amplified) =
+            app.world().resource::<CapturedHeals>().0[0];
+        assert!((final_heal - 72.0).abs() < 1e-3);
+        assert_eq!(overheal, 0.0);
+        assert!((grievous - 48.0).abs() < 1e-3);
+        assert!((amplified - 20.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn vamp_ignores_heal_power_and_reports_overheal() {
+        let mut app = setup_app();
+        let source = app.world_mut().spawn(HealShieldPower(0.5)).id();
+        let target = spawn_target(&mut app, 950.0);
+
+        app.world_mut().trigger(CommandHeal {
+            entity: target,
+            source,
+            heal_type: HealType::Vamp,
+            amount: 100.0,
+        });
+        app.update();
+
+        assert_eq!(app.world().get::<Health>(target).unwrap().value, 1000.0);
+        let (final_heal, overheal, _, amplified) = app.world().resource::<CapturedHeals>().0[0];
+        assert_eq!(final_heal, 50.0);
+        assert_eq!(overheal, 50.0);
+        assert_eq!(amplified, 0.0);
+    }
+
+    #[test]
+    fn shield_power_comes_from_the_shield_source() {
+        let mut app = setup_app();
+        let holder = app.world_mut().spawn(HealShieldPower(0.25)).id();
+        let ally = app.world_mut().spawn(HealShieldPower(0.5)).id();
+        let own = app
+            .world_mut()
+            .spawn((BuffShieldWhite::new(200.0), BuffOf(holder)))
+            .id();
+        let granted = app
+            .world_mut()
+            .spawn((
+                BuffShieldWhite::new(200.0),
+                BuffDescriptor::default().with_source(ally),
+                BuffOf(holder),
+            ))
+            .id();
+        app.update();
+
+        let shield = |buff| app.world().get::<BuffShieldWhite>(buff).unwrap();
+        assert_eq!(shield(own).current, 250.0);
+        assert_eq!(shield(own).max, 250.0);
+        assert_eq!(shield(granted).current, 300.0, "队友套的盾按队友的强度放大");
+    }
+
+    #[test]
+    fn reinserted_shield_is_amplified_again() {
+        let mut app = setup_app();
+        let holder = app.world_mut().spawn(HealShieldPower(0.5)).id();
+        let buff = app
+            .world_mut()
+            .spawn((BuffShieldMagic::new(100.0), BuffOf(holder)))
+            .id();
+        app.update();
+        app.world_mut()
+            .entity_mut(buff)
+            .insert(BuffShieldMagic::new(80.0));
+        app.update();
+
+        let shield = app.world().get::<BuffShieldMagic>(buff).unwrap();
+        assert_eq!(shield.current, 120.0, "刷新写入的护盾值同样吃强度加成");
+        assert_eq!(shield.max, 120.0);
+    }
+
+    fn damage_event(
+        target: Entity,
+        source: Entity,
+        final_damage: f32,
+        kind: DamageKind,
+    ) -> EventDamageCreate {
+        EventDamageCreate {
+            entity: target,
+            source,
+            damage_type: crate::damage::DamageType::Physical,
+            damage_result: crate::damage::DamageResult {
+                final_damage,
+                white_shield_absorbed: 0.0,
+                magic_shield_absorbed: 0.0,
+                reduced_damage: 0.0,
+                armor_reduced_damage: 0.0,
+                original_damage: final_damage,
+            },
+            tag: None,
+            kind,
+        }
+    }
+
+    #[test]
+    fn vamp_heals_from_attacks_and_damage() {
+        let mut app = setup_app();
+        let attacker = app
+            .world_mut()
+            .spawn((Health::new(1000.0), HealShieldPower(1.0)))
+            .id();
+        app.world_mut().get_mut::<Health>(attacker).unwrap().value = 500.0;
+        app.world_mut().spawn((
+            Vamp {
+                life_steal: 0.1,
+                omnivamp: 0.0,
+            },
+            BuffOf(attacker),
+        ));
+        app.world_mut().spawn((
+            Vamp {
+                life_steal: 0.0,
+                omnivamp: 0.2,
+            },
+            BuffOf(attacker),
+        ));
+        let target = spawn_target(&mut app, 1000.0);
+
+        app.world_mut().trigger(damage_event(
+            target,
+            attacker,
+            80.0,
+            DamageKind::BasicAttack,
+        ));
+        app.world_mut()
+            .trigger(damage_event(target, attacker, 50.0, DamageKind::Ability));
+        app.update();
+
+        // 生命偷取只算普攻实际伤害 80 × 0.1 = 8，全能吸血 (80 + 50) × 0.2 = 26，
+        // 均不受治疗强度加成
+        let health = app.world().get::<Health>(attacker).unwrap().value;
+        assert!((health - 534.0).abs() < 1e-3, "实际 {health}");
+    }
+}
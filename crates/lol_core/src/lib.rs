@@ -2,6 +2,7 @@ pub mod action;
 pub mod aggro;
 pub mod attack;
 pub mod attack_auto;
+pub mod avoidance;
 pub mod base;
 pub mod buffs;
 pub mod character;
@@ -11,7 +12,9 @@ pub mod damage;
 pub mod effect;
 pub mod entities;
 pub mod error;
+pub mod fountain;
 pub mod game;
+pub mod heal;
 pub mod lane;
 pub mod life;
 pub mod lifetime;
@@ -20,13 +23,18 @@ pub mod log;
 pub mod map;
 pub mod match_events;
 pub mod missile;
+pub mod missile_block;
 pub mod movement;
 pub mod navigation;
+pub mod recall;
 pub mod rotate;
 pub mod run;
 pub mod skill;
+pub mod skill_kit;
 pub mod skill_script;
 pub mod skin;
+pub mod spatial;
+pub mod summoner;
 pub mod team;
 pub mod utils;
 
@@ -43,29 +51,39 @@ use buffs::damage_reduction::PluginDamageReduction;
 use buffs::on_hit::PluginOnHit;
 use buffs::shield_magic::PluginShieldMagic;
 use buffs::shield_white::PluginShieldWhite;
+use buffs::spell_shield::PluginSpellShield;
 use character::PluginCharacter;
 use cooldown::PluginCooldown;
 use damage::PluginDamage;
 pub use entities::barrack::PluginBarrack;
 use entities::champion::PluginChampion;
+use entities::epic::PluginEpicMonster;
 use entities::inhibitor::PluginInhibitor;
+use entities::jungle::PluginJungle;
 use entities::minion::PluginMinion;
 use entities::nexus::PluginNexus;
 use entities::pet::PluginPet;
 use entities::turret::PluginTurret;
+use fountain::PluginFountain;
 use game::PluginGame;
+use heal::PluginHeal;
 use life::PluginLife;
 use lifetime::PluginLifetime;
 use log::PluginLog;
 use map::PluginMap;
 use match_events::PluginMatchEvents;
 use missile::PluginMissile;
+use missile_block::PluginMissileBlock;
 use movement::PluginMovement;
 use navigation::navigation::PluginNavigaton;
+use recall::PluginRecall;
 use rotate::PluginRotate;
 use run::PluginRun;
 use skill::PluginSkill;
+use skill_kit::PluginSkillKit;
 use skill_script::PluginSkillScript;
+use spatial::PluginSpatial;
+use summoner::PluginSummonerSpell;
 
 plugin_group! {
     pub struct PluginCore {
@@ -82,7 +100,9 @@ plugin_group! {
         :PluginCooldown,
         :PluginDamage,
         :PluginDamageReduction,
+        :PluginFountain,
         :PluginGame,
+        :PluginHeal,
         :PluginLife,
         :PluginLifetime,
         :PluginLog,
@@ -90,19 +110,27 @@ plugin_group! {
         :PluginMatchEvents,
         :PluginMinion,
         :PluginMissile,
+        :PluginMissileBlock,
         :PluginMovement,
         :PluginNavigaton,
         :PluginOnHit,
+        :PluginRecall,
         :PluginRotate,
         :PluginRun,
         :PluginShieldMagic,
         :PluginShieldWhite,
+        :PluginSpellShield,
         :PluginSkill,
+        :PluginSkillKit,
         :PluginSkillScript,
+        :PluginSpatial,
         :PluginState,
+        :PluginSummonerSpell,
         :PluginTurret,
         :PluginPet,
         :PluginInhibitor,
+        :PluginJungle,
+        :PluginEpicMonster,
         :PluginNexus,
     }
 }
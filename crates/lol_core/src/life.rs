@@ -6,6 +6,9 @@ use crate::base::level::Level;
 use crate::base::state::State;
 use crate::damage::EventDamageCreate;
 use crate::entities::champion::Champion;
+use crate::entities::inhibitor::Inhibitor;
+use crate::entities::pet::{PetOf, resolve_owner};
+use crate::fountain::{Fountain, Fountains};
 use crate::movement::MovementState;
 use crate::run::Run;
 use crate::team::Team;
@@ -24,7 +27,6 @@ impl Plugin for PluginLife {
         app.add_systems(FixedUpdate, (spawn_event, update_respawn, regen));
         app.add_systems(Update, apply_god_mode);
         app.add_observer(on_event_damage_create);
-        app.add_observer(on_command_heal);
     }
 }
 
@@ -53,16 +55,6 @@ pub struct EventDead {
     pub killer: Option<Entity>,
 }
 
-/// 治疗命令：对目标实体施加瞬时治疗，不超过 health.max。
-///
-/// 用法：`commands.trigger(CommandHeal { entity, source, amount });`
-#[derive(EntityEvent, Debug)]
-pub struct CommandHeal {
-    pub entity: Entity,
-    pub source: Entity,
-    pub amount: f32,
-}
-
 #[derive(EntityEvent, Debug)]
 pub struct EventSpawn {
     entity: Entity,
@@ -79,6 +71,19 @@ impl Health {
     }
 }
 
+/// 1-18 级的基础死亡时长（秒）
+const DEATH_TIMER_BY_LEVEL: [f32; 18] = [
+    10.0, 10.0, 12.0, 12.0, 14.0, 16.0, 20.0, 25.0, 28.0, 32.5, 35.0, 37.5, 40.0, 42.5, 45.0, 47.5,
+    50.0, 52.5,
+];
+
+/// 英雄死亡时长：按等级查表，对局 15 分钟后每分钟再延长 2%，最多延长 50%。
+pub fn death_timer_secs(level: u32, game_time_secs: f32) -> f32 {
+    let base = DEATH_TIMER_BY_LEVEL[(level.clamp(1, 18) - 1) as usize];
+    let minutes = game_time_secs / 60.0;
+    base * (1.0 + ((minutes - 15.0) * 0.02).clamp(0.0, 0.5))
+}
+
 pub fn spawn_event(mut commands: Commands, q_alive: Query<Entity, Added<Health>>) {
     let spawn_count = q_alive.iter().count();
     if spawn_count > 0 {
@@ -135,30 +140,18 @@ pub fn regen(
     }
 }
 
-/// 处理瞬时治疗命令。治疗量夹取到 health.max，死亡目标不生效。
-pub fn on_command_heal(trigger: On<CommandHeal>, mut q_health: Query<&mut Health>) {
-    let Ok(mut health) = q_health.get_mut(trigger.entity) else {
-        return;
-    };
-    if health.value <= 0.0 || health.max <= 0.0 {
-        return;
-    }
-    let before = health.value;
-    health.value = (health.value + trigger.amount).min(health.max);
-    debug!(
-        "{:?} 治疗 {:?} {:.1} HP（{:.1} → {:.1}）",
-        trigger.source, trigger.entity, trigger.amount, before, health.value,
-    );
-}
-
 fn on_event_damage_create(
     trigger: On<EventDamageCreate>,
     mut commands: Commands,
-    q_health: Query<&Health>,
+    // 已死亡（保留实体等待复活）的单位不再重复结算死亡
+    q_health: Query<&Health, Without<Death>>,
     q_champion: Query<&Champion>,
+    q_inhibitor: Query<(), With<Inhibitor>>,
     q_level: Query<&Level>,
+    q_pet_of: Query<&PetOf>,
     mut q_movement_state: Query<&mut MovementState>,
     mut q_state: Query<&mut State>,
+    time: Res<Time<Fixed>>,
 ) {
     let entity = trigger.event_target();
 
@@ -168,9 +161,10 @@ fn on_event_damage_create(
 
     if health.value <= 0.0 {
         debug!("{:?} 死了", entity);
+        // 召唤物的击杀记在主人名下
         commands.trigger(EventDead {
             entity,
-            killer: Some(trigger.source),
+            killer: Some(resolve_owner(&q_pet_of, trigger.source)),
         });
 
         if q_champion.get(entity).is_ok() {
@@ -184,11 +178,14 @@ fn on_event_damage_create(
             }
 
             let level = q_level.get(entity).map(|l| l.value).unwrap_or(1);
-            let duration = 5.0 + level as f32 * 2.0;
+            let duration = death_timer_secs(level, time.elapsed_secs());
             commands
                 .entity(entity)
                 .insert(RespawnTimer(Timer::from_seconds(duration, TimerMode::Once)));
             debug!("{:?} 将在 {:.1} 秒后复活", entity, duration);
+        } else if q_inhibitor.contains(entity) {
+            // 水晶保留实体，由 PluginInhibitor 计时复活
+            commands.entity(entity).insert(Death);
         } else {
             commands.entity(entity).despawn();
         }
@@ -197,30 +194,29 @@ fn on_event_damage_create(
 
 pub fn update_respawn(
     mut commands: Commands,
-    mut q_respawn: Query<(
-        Entity,
-        &mut RespawnTimer,
-        &mut Health,
-        Option<&mut AbilityResource>,
-        &Team,
-        &mut Transform,
-        Option<&mut MovementState>,
-        Option<&mut State>,
-    )>,
+    mut q_respawn: Query<
+        (
+            Entity,
+            &mut RespawnTimer,
+            &mut Health,
+            Option<&mut AbilityResource>,
+            &Team,
+            &mut Transform,
+            Option<&mut MovementState>,
+            Option<&mut State>,
+        ),
+        Without<Fountain>,
+    >,
+    fountains: Fountains,
     time: Res<Time<Fixed>>,
 ) {
-    for (entity, mut timer, mut health, ar, team, mut transform, ms, state) in
-        q_respawn.iter_mut()
+    for (entity, mut timer, mut health, ar, team, mut transform, ms, state) in q_respawn.iter_mut()
     {
         timer.0.tick(time.delta());
 
         if timer.0.just_finished() {
-            // 复活位置（地面高度对齐真实地图）
-            let spawn_pos = match team {
-                Team::Order => Vec3::new(1000.0, 146.97, 1000.0),
-                Team::Chaos => Vec3::new(14000.0, 159.54, 14000.0),
-                Team::Neutral => transform.translation, // 应该不会发生
-            };
+            // 复活位置：己方泉水（中立单位不会复活，保持原地）
+            let spawn_pos = fountains.position(*team).unwrap_or(transform.translation);
 
             transform.translation = spawn_pos;
             health.value = health.max;
@@ -252,7 +248,7 @@ mod tests {
     use bevy::ecs::system::RunSystemOnce;
 
     use super::*;
-    use crate::damage::{DamageResult, DamageType, EventDamageCreate};
+    use crate::damage::{DamageKind, DamageResult, DamageType, EventDamageCreate};
 
     fn setup_app() -> App {
         let mut app = App::new();
@@ -305,6 +301,7 @@ mod tests {
                 original_damage: 100.0,
             },
             tag: None,
+            kind: DamageKind::Other,
         });
 
         // 触发伤害事件处理
@@ -314,8 +311,8 @@ mod tests {
         assert!(app.world().get::<Death>(hero).is_some());
         assert!(app.world().get::<RespawnTimer>(hero).is_some());
 
-        // 3. 模拟时间流逝 (level 1 -> 5+1*2 = 7s)
-        for _ in 0..8 {
+        // 3. 模拟时间流逝 (level 1 -> 10s)
+        for _ in 0..11 {
             let delta = Duration::from_secs(1);
             {
                 let mut time = app.world_mut().resource_mut::<Time<Fixed>>();
@@ -340,6 +337,53 @@ mod tests {
         assert_eq!(transform.translation, Vec3::new(1000.0, 146.97, 1000.0));
     }
 
+    #[test]
+    fn dead_inhibitor_does_not_die_again() {
+        #[derive(Resource, Default)]
+        struct DeadCount(u32);
+
+        let mut app = setup_app();
+        app.init_resource::<DeadCount>();
+        app.add_observer(|_: On<EventDead>, mut count: ResMut<DeadCount>| count.0 += 1);
+        let inhibitor = app
+            .world_mut()
+            .spawn((Inhibitor, Team::Order, Health::new(100.0)))
+            .id();
+        app.world_mut().get_mut::<Health>(inhibitor).unwrap().value = 0.0;
+
+        for _ in 0..2 {
+            app.world_mut().trigger(EventDamageCreate {
+                entity: inhibitor,
+                source: Entity::PLACEHOLDER,
+                damage_type: DamageType::True,
+                damage_result: DamageResult {
+                    final_damage: 10.0,
+                    white_shield_absorbed: 0.0,
+                    magic_shield_absorbed: 0.0,
+                    reduced_damage: 0.0,
+                    armor_reduced_damage: 0.0,
+                    original_damage: 10.0,
+                },
+                tag: None,
+                kind: DamageKind::Other,
+            });
+            app.update();
+        }
+
+        assert!(app.world().get::<Death>(inhibitor).is_some());
+        assert_eq!(app.world().resource::<DeadCount>().0, 1);
+    }
+
+    #[test]
+    fn death_timer_scales_with_level_and_game_time() {
+        assert_eq!(death_timer_secs(1, 0.0), 10.0);
+        assert_eq!(death_timer_secs(18, 600.0), 52.5);
+        // 15 分钟前不随时间增长，之后每分钟 +2%，封顶 +50%
+        assert_eq!(death_timer_secs(10, 900.0), 32.5);
+        assert!((death_timer_secs(10, 1500.0) - 32.5 * 1.2).abs() < 1e-3);
+        assert_eq!(death_timer_secs(18, 3600.0), 52.5 * 1.5);
+    }
+
     /// 推进固定时间并执行一次 `regen` 系统。
     fn advance_and_regen(app: &mut App, delta: Duration) {
         {
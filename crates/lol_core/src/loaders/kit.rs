@@ -0,0 +1,33 @@
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::reflect::TypePath;
+
+use crate::error::Error;
+use crate::skill_kit::ChampionKit;
+
+#[derive(Default, TypePath)]
+pub struct LoaderChampionKit;
+
+impl AssetLoader for LoaderChampionKit {
+    type Asset = ChampionKit;
+
+    type Settings = ();
+
+    type Error = Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn bevy::asset::io::Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).await?;
+        let content = String::from_utf8(buf).map_err(|e| Error::Parse(e.to_string()))?;
+        let kit: ChampionKit = ron::from_str(&content).map_err(|e| Error::Parse(e.to_string()))?;
+        Ok(kit)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
@@ -1,3 +1,4 @@
 pub mod barrack;
+pub mod kit;
 pub mod navgrid;
 pub mod spell;
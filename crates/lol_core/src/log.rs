@@ -320,6 +320,7 @@ pub enum EnumLogCategory {
     Attack,
     Movement,
     Minion,
+    Jungle,
 }
 
 #[derive(EntityEvent, Clone, Debug)]
@@ -357,6 +358,7 @@ fn on_command_log(event: On<CommandLog>, q_name: Query<Option<&Name>>) {
         EnumLogCategory::Attack => "attack",
         EnumLogCategory::Movement => "movement",
         EnumLogCategory::Minion => "minion",
+        EnumLogCategory::Jungle => "jungle",
     };
 
     debug!(
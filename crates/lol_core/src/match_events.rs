@@ -4,8 +4,9 @@
 //! 设计要点：
 //! - 胜负判定不在 Bevy 进程内（见 docs/product/match/arch.md）。本插件只**产出**
 //!   事件，不做终局判定。
-//! - 复用已有数据：`EventDead`（life.rs）覆盖英雄/防御塔死亡；`ChampionStats`
-//!   （base/stats.rs）已跟踪 kills / minion_kills。
+//! - 复用已有数据：`EventChampionKill`（base/bounty.rs）覆盖英雄击杀及助攻 / 赏金，
+//!   `EventDead`（life.rs）覆盖防御塔死亡；`ChampionStats`（base/stats.rs）已跟踪
+//!   kills / minion_kills。
 //! - 一血 / 一塔由 web server 判定"首个"（跨事件状态），本插件只上报"发生了
 //!   击杀/推塔"及其所属阵营，避免在引擎侧维护全局先后顺序。
 //! - 补刀阈值（默认 100）一旦达到即上报 cs_threshold；上限事件只发一次（用
@@ -17,9 +18,13 @@ use async_channel::Sender;
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::base::bounty::EventChampionKill;
 use crate::base::stats::ChampionStats;
 use crate::entities::champion::Champion;
-use crate::entities::turret::Turret;
+use crate::entities::epic::{
+    DragonElement, EpicMonsterKind, EventDragonSoulClaimed, EventEpicObjectiveTaken,
+};
+use crate::entities::turret::{EventTurretPlateDestroyed, Turret};
 use crate::life::{Death, EventDead};
 use crate::team::Team;
 
@@ -32,15 +37,37 @@ pub const CS_TARGET: u32 = 100;
 pub enum MatchEventOut {
     /// 英雄被击杀。`killer_team` 为击杀者阵营（拿人头方）。
     /// 一血判定由 web server 做（首个 champion_kill 即一血）。
-    ChampionKill { killer_team: Team },
+    /// `assists` 为助攻人数，`bounty` 为击杀赏金，`shutdown` 表示终结了对方连杀。
+    ChampionKill {
+        killer_team: Team,
+        assists: u32,
+        bounty: f32,
+        shutdown: bool,
+    },
     /// 防御塔被摧毁。`killer_team` 为推塔方。
     /// 一塔判定由 web server 做（首个 turret_destroyed 即一塔）。
     TurretDestroyed { killer_team: Team },
+    /// 防御塔镀层被击破。`killer_team` 为拿到镀层的一方。
+    /// 一镀层判定由 web server 做（首个 turret_plate_destroyed 即一镀层）。
+    TurretPlateDestroyed { killer_team: Team },
     /// 某方补刀达到阈值（CS_TARGET）。每方只发一次。
     CsThreshold { team: Team, cs: u32 },
     /// 对局时间推进（秒）。由 supervisor 累积用于 15 分钟超时判定。
     /// 周期性上报，频率较低（每秒一次）。
     TimeProgress { elapsed_secs: f64 },
+    /// 元素龙被击杀。`element` 为该龙的元素。
+    DragonKilled {
+        killer_team: Team,
+        element: DragonElement,
+    },
+    /// 某方集齐元素龙拿到龙魂。
+    DragonSoul { team: Team, element: DragonElement },
+    /// 远古龙被击杀。
+    ElderDragonKilled { killer_team: Team },
+    /// 峡谷先锋被击杀。
+    RiftHeraldKilled { killer_team: Team },
+    /// 纳什男爵被击杀。
+    BaronKilled { killer_team: Team },
 }
 
 /// 对局事件输出通道（Resource）。由 lol_server 在启动时注入 Sender。
@@ -63,6 +90,10 @@ pub struct PluginMatchEvents;
 impl Plugin for PluginMatchEvents {
     fn build(&self, app: &mut App) {
         app.add_observer(on_event_dead);
+        app.add_observer(on_champion_kill);
+        app.add_observer(on_turret_plate_destroyed);
+        app.add_observer(on_epic_objective_taken);
+        app.add_observer(on_dragon_soul_claimed);
         app.add_systems(FixedUpdate, check_cs_threshold);
         app.init_resource::<TimeProgressAccumulator>();
         app.add_systems(FixedUpdate, report_time_progress);
@@ -74,7 +105,22 @@ fn emit(ch: &MatchEventChannel, event: MatchEventOut) {
     let _ = ch.tx.try_send(event);
 }
 
-/// 监听死亡事件，产出英雄击杀 / 推塔事件。
+/// 监听英雄击杀结算，上报击杀者阵营、助攻人数与赏金。
+/// 被防御塔 / 小兵处决同样上报，阵营取结算给出的击杀方。
+fn on_champion_kill(trigger: On<EventChampionKill>, channel: Option<Res<MatchEventChannel>>) {
+    let Some(ch) = channel else { return };
+    emit(
+        &ch,
+        MatchEventOut::ChampionKill {
+            killer_team: trigger.killer_team,
+            assists: trigger.assisters.len() as u32,
+            bounty: trigger.bounty,
+            shutdown: trigger.shutdown,
+        },
+    );
+}
+
+/// 监听死亡事件，产出推塔事件（英雄击杀由 [`on_champion_kill`] 处理）。
 fn on_event_dead(
     trigger: On<EventDead>,
     q_champion: Query<&Team, With<Champion>>,
@@ -84,16 +130,6 @@ fn on_event_dead(
     let Some(ch) = channel else { return };
     let dead_entity = trigger.entity;
 
-    // 英雄被击杀：上报击杀者阵营。
-    if q_champion.get(dead_entity).is_ok() {
-        if let Some(killer_entity) = trigger.killer {
-            if let Ok(&killer_team) = q_champion.get(killer_entity) {
-                emit(&ch, MatchEventOut::ChampionKill { killer_team });
-            }
-        }
-        return;
-    }
-
     // 防御塔被摧毁：上报推塔方阵营。
     if q_turret.get(dead_entity).is_ok() {
         // 击杀者可能是英雄；若拿不到阵营（泉水/小兵击杀），用被毁塔的对方阵营。
@@ -114,6 +150,53 @@ fn on_event_dead(
     // 小兵死亡不计入对局事件（补刀里程碑由 check_cs_threshold 统一处理）。
 }
 
+/// 镀层被击破：上报拿到镀层的一方（被破塔的对方阵营）。
+fn on_turret_plate_destroyed(
+    trigger: On<EventTurretPlateDestroyed>,
+    channel: Option<Res<MatchEventChannel>>,
+) {
+    let Some(ch) = channel else { return };
+    let killer_team = match trigger.team {
+        Team::Order => Team::Chaos,
+        Team::Chaos => Team::Order,
+        Team::Neutral => return,
+    };
+    emit(&ch, MatchEventOut::TurretPlateDestroyed { killer_team });
+}
+
+/// 史诗目标（龙 / 先锋 / 男爵）被拿下，按野怪种类产出对应事件。
+fn on_epic_objective_taken(
+    trigger: On<EventEpicObjectiveTaken>,
+    channel: Option<Res<MatchEventChannel>>,
+) {
+    let Some(ch) = channel else { return };
+    let killer_team = trigger.team;
+    let event = match trigger.monster {
+        EpicMonsterKind::Dragon(element) => MatchEventOut::DragonKilled {
+            killer_team,
+            element,
+        },
+        EpicMonsterKind::ElderDragon => MatchEventOut::ElderDragonKilled { killer_team },
+        EpicMonsterKind::RiftHerald => MatchEventOut::RiftHeraldKilled { killer_team },
+        EpicMonsterKind::BaronNashor => MatchEventOut::BaronKilled { killer_team },
+    };
+    emit(&ch, event);
+}
+
+fn on_dragon_soul_claimed(
+    trigger: On<EventDragonSoulClaimed>,
+    channel: Option<Res<MatchEventChannel>>,
+) {
+    let Some(ch) = channel else { return };
+    emit(
+        &ch,
+        MatchEventOut::DragonSoul {
+            team: trigger.team,
+            element: trigger.element,
+        },
+    );
+}
+
 /// 检查每方补刀是否达到阈值，首次达到时上报（用 CsThresholdReached 去重）。
 fn check_cs_threshold(
     mut q: Query<(Entity, &ChampionStats, &Team, Option<&CsThresholdReached>), Without<Death>>,
@@ -159,6 +242,7 @@ mod tests {
     use async_channel::{Receiver, Sender, unbounded};
 
     use super::*;
+    use crate::base::bounty::PluginBounty;
 
     /// 收集通道中所有待读事件。
     fn drain(rx: &Receiver<MatchEventOut>) -> Vec<MatchEventOut> {
@@ -174,7 +258,7 @@ mod tests {
         let (tx, rx) = unbounded::<MatchEventOut>();
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
-        app.add_plugins(PluginMatchEvents);
+        app.add_plugins((PluginBounty, PluginMatchEvents));
         app.insert_resource(MatchEventChannel { tx });
         (app, rx)
     }
@@ -196,7 +280,31 @@ mod tests {
         assert!(events.iter().any(|e| matches!(
             e,
             MatchEventOut::ChampionKill {
-                killer_team: Team::Order
+                killer_team: Team::Order,
+                assists: 0,
+                shutdown: false,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn executed_champion_still_emits_kill() {
+        let (mut app, rx) = setup_app();
+
+        let victim = app.world_mut().spawn((Champion, Team::Chaos)).id();
+        app.world_mut().trigger(EventDead {
+            entity: victim,
+            killer: None,
+        });
+        app.update();
+
+        let events = drain(&rx);
+        assert!(events.iter().any(|e| matches!(
+            e,
+            MatchEventOut::ChampionKill {
+                killer_team: Team::Order,
+                ..
             }
         )));
     }
@@ -223,6 +331,63 @@ mod tests {
         )));
     }
 
+    #[test]
+    fn turret_plate_emits_event_for_attacking_team() {
+        let (mut app, rx) = setup_app();
+
+        app.world_mut().trigger(EventTurretPlateDestroyed {
+            turret: Entity::PLACEHOLDER,
+            team: Team::Chaos,
+            plates_remaining: 4,
+        });
+        app.update();
+
+        let events = drain(&rx);
+        assert!(events.iter().any(|e| matches!(
+            e,
+            MatchEventOut::TurretPlateDestroyed {
+                killer_team: Team::Order
+            }
+        )));
+    }
+
+    #[test]
+    fn epic_objectives_emit_events() {
+        let (mut app, rx) = setup_app();
+
+        app.world_mut().trigger(EventEpicObjectiveTaken {
+            team: Team::Order,
+            monster: EpicMonsterKind::Dragon(DragonElement::Ocean),
+        });
+        app.world_mut().trigger(EventDragonSoulClaimed {
+            team: Team::Order,
+            element: DragonElement::Ocean,
+        });
+        app.world_mut().trigger(EventEpicObjectiveTaken {
+            team: Team::Chaos,
+            monster: EpicMonsterKind::BaronNashor,
+        });
+        app.update();
+
+        let events = drain(&rx);
+        assert!(matches!(
+            events.as_slice(),
+            [
+                MatchEventOut::DragonKilled {
+                    killer_team: Team::Order,
+                    element: DragonElement::Ocean
+                },
+                MatchEventOut::DragonSoul {
+                    team: Team::Order,
+                    element: DragonElement::Ocean
+                },
+                MatchEventOut::BaronKilled {
+                    killer_team: Team::Chaos
+                },
+            ]
+        ));
+    }
+
     #[test]
     fn cs_threshold_emits_once() {
         use bevy::ecs::system::RunSystemOnce;
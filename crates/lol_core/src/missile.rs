@@ -9,12 +9,14 @@ use lol_base::spell::Spell;
 use serde::{Deserialize, Serialize};
 
 use crate::attack::EntityCommandsTrigger;
-use crate::damage::{CommandDamageCreate, Damage, DamageType};
+use crate::buffs::spell_shield::SpellShields;
+use crate::damage::{CommandDamageCreate, Damage, DamageKind, DamageType, Untargetable};
 use crate::life::{Death, Health};
 use crate::movement::{
     CommandMovement, EventMovementEnd, Movement, MovementAction, MovementSource, MovementWay,
 };
 use crate::navigation::grid::ResourceGrid;
+use crate::spatial::{SpatialCandidates, SpatialFilter, SpatialShape};
 use crate::team::Team;
 
 #[derive(Default)]
@@ -68,6 +70,10 @@ pub struct LinearMissile {
     pub missing_hp_scaling: Option<MissileMissingHpScaling>,
 }
 
+/// 追踪导弹抵达时不造成伤害，改发 [`EventMissileHitEntity`]（伊芙琳 W 等技能弹）。
+#[derive(Component, Debug)]
+pub struct TrackingMissileNoDirectDamage;
+
 /// 飞弹碰撞目标策略
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum MissileCollisionTarget {
@@ -117,7 +123,7 @@ pub struct CommandMissileCreate {
     pub sticky: bool,
     /// 穿透飞弹：碰撞敌人后不销毁，继续飞行（刀妹 R 飞弹）。
     pub pass_through: bool,
-    /// 碰撞目标策略
+    /// 碰撞目标策略；追踪导弹仅区分 `EnemyNoDirectDamage`（抵达时改发事件）与其它（普攻伤害）
     pub collision_target: MissileCollisionTarget,
     /// 按目标已损失生命值缩放伤害（锐雯 R 斩杀），None 时用固定 `damage`
     pub missing_hp_scaling: Option<MissileMissingHpScaling>,
@@ -209,7 +215,7 @@ fn fixed_update(
     }
 }
 
-fn linear_missile_collision(
+pub(crate) fn linear_missile_collision(
     mut commands: Commands,
     mut q_missile: Query<(
         Entity,
@@ -220,11 +226,18 @@ fn linear_missile_collision(
     )>,
     q_targets: Query<
         (Entity, &Team, &Transform, Option<&Health>),
-        (Without<LinearMissile>, Without<Death>),
+        (
+            Without<LinearMissile>,
+            Without<Death>,
+            Without<Untargetable>,
+        ),
     >,
     q_source_team: Query<&Team>,
+    shields: SpellShields,
     res_grid: Option<Res<ResourceGrid>>,
     assets_grid: Option<Res<Assets<ConfigNavigationGrid>>>,
+    spatial: SpatialCandidates,
+    mut candidates: Local<Vec<Entity>>,
     time: Res<Time<Fixed>>,
 ) {
     let dt = time.delta_secs();
@@ -275,6 +288,19 @@ fn linear_missile_collision(
             continue;
         }
 
+        // 非粘性飞弹越过静态地形，但被技能墙等地形覆盖层挡下
+        if let (Some(res_grid), Some(assets_grid)) = (res_grid.as_ref(), assets_grid.as_ref()) {
+            if let Some(grid) = assets_grid.get(&res_grid.0) {
+                let blocked = grid
+                    .get_cell_xy_by_position(&transform.translation.xz())
+                    .is_some_and(|xy| grid.terrain_cells.contains(&xy));
+                if blocked {
+                    commands.entity(missile_entity).despawn();
+                    continue;
+                }
+            }
+        }
+
         // WallOnly：不做实体碰撞（仅粘性碰墙）
         if linear.collision_target == MissileCollisionTarget::WallOnly {
             continue;
@@ -284,7 +310,18 @@ fn linear_missile_collision(
             continue;
         };
 
-        for (target, team, target_transform, health) in q_targets.iter() {
+        // 粗筛碰撞候选
+        spatial.query_into(
+            &SpatialShape::Circle {
+                center: transform.translation.xz(),
+                radius: linear.width,
+            },
+            SpatialFilter::not_team(*source_team),
+            q_targets.iter().map(|(e, ..)| e),
+            &mut candidates,
+        );
+
+        for (target, team, target_transform, health) in q_targets.iter_many(candidates.iter()) {
             if team == source_team {
                 continue;
             }
@@ -297,30 +334,34 @@ fn linear_missile_collision(
             if dist < linear.width {
                 linear.hit_enemies.push(target);
 
-                match linear.collision_target {
-                    MissileCollisionTarget::EnemyNoDirectDamage => {
-                        commands
-                            .entity(state.source)
-                            .trigger(|e| EventMissileHitEntity {
-                                source: e,
-                                target,
-                                spell: missile.key.clone(),
-                                hit_point: target_transform.translation,
+                // 法术护盾挡下：不造成伤害也不发命中事件，飞弹照常视为碰撞
+                if !shields.block_ability(&mut commands, target, state.source) {
+                    match linear.collision_target {
+                        MissileCollisionTarget::EnemyNoDirectDamage => {
+                            commands
+                                .entity(state.source)
+                                .trigger(|e| EventMissileHitEntity {
+                                    source: e,
+                                    target,
+                                    spell: missile.key.clone(),
+                                    hit_point: target_transform.translation,
+                                });
+                        }
+                        _ => {
+                            // 按目标已损失生命值缩放（锐雯 R 斩杀），否则用固定伤害
+                            let amount = match (linear.missing_hp_scaling, health) {
+                                (Some(scaling), Some(health)) => scaling.damage_for(health),
+                                _ => linear.damage,
+                            };
+                            commands.trigger(CommandDamageCreate {
+                                entity: target,
+                                source: state.source,
+                                damage_type: DamageType::Physical,
+                                amount,
+                                tag: None,
+                                kind: DamageKind::Ability,
                             });
-                    }
-                    _ => {
-                        // 按目标已损失生命值缩放（锐雯 R 斩杀），否则用固定伤害
-                        let amount = match (linear.missing_hp_scaling, health) {
-                            (Some(scaling), Some(health)) => scaling.damage_for(health),
-                            _ => linear.damage,
-                        };
-                        commands.trigger(CommandDamageCreate {
-                            entity: target,
-                            source: state.source,
-                            damage_type: DamageType::Physical,
-                            amount,
-                            tag: None,
-                        });
+                        }
                     }
                 }
 
@@ -511,6 +552,9 @@ fn on_command_missile_create(
     if let Some(team) = opt_team.clone() {
         cmd.insert(team);
     }
+    if trigger.collision_target == MissileCollisionTarget::EnemyNoDirectDamage {
+        cmd.insert(TrackingMissileNoDirectDamage);
+    }
 
     let missile_entity = cmd.id();
 
@@ -544,9 +588,16 @@ fn on_command_missile_create(
 fn on_event_movement_end(
     trigger: On<EventMovementEnd>,
     mut commands: Commands,
-    q_missile: Query<&MissileState>,
+    q_missile: Query<(
+        &Missile,
+        &MissileState,
+        &Transform,
+        Has<TrackingMissileNoDirectDamage>,
+    )>,
     q_linear: Query<&LinearMissile>,
     q_damage: Query<&Damage>,
+    q_untargetable: Query<(), With<Untargetable>>,
+    shields: SpellShields,
 ) {
     // 直线导弹到达终点：直接销毁（碰撞伤害已在 linear_missile_collision 中处理）
     if q_linear.get(trigger.entity).is_ok() {
@@ -555,7 +606,7 @@ fn on_event_movement_end(
     }
 
     // 追踪导弹
-    let Ok(state) = q_missile.get(trigger.entity) else {
+    let Ok((missile, state, transform, no_direct_damage)) = q_missile.get(trigger.entity) else {
         return;
     };
 
@@ -565,6 +616,25 @@ fn on_event_movement_end(
         return;
     };
 
+    // 飞行途中目标转为不可选中：导弹落空
+    if q_untargetable.get(target).is_ok() {
+        return;
+    }
+
+    if no_direct_damage {
+        // 指向技能飞弹被法术护盾挡下：不发命中事件
+        if shields.block_ability(&mut commands, target, state.source) {
+            return;
+        }
+        commands.trigger(EventMissileHitEntity {
+            source: state.source,
+            target,
+            spell: missile.key.clone(),
+            hit_point: transform.translation,
+        });
+        return;
+    }
+
     if let Ok(damage) = q_damage.get(state.source) {
         debug!("{} 对 {} 造成伤害 {}", state.source, target, damage.0);
         commands.try_trigger(CommandDamageCreate {
@@ -573,6 +643,7 @@ fn on_event_movement_end(
             damage_type: DamageType::Physical,
             amount: damage.0,
             tag: None,
+            kind: DamageKind::BasicAttack,
         });
     }
 }
@@ -611,7 +682,14 @@ fn update_attached_fields(
     mut commands: Commands,
     mut q_fields: Query<(Entity, &mut AttachedField, &ChildOf, &mut Transform)>,
     q_parent_transform: Query<&Transform, Without<AttachedField>>,
-    q_enemies: Query<(Entity, &Team, &Transform), (Without<AttachedField>, Without<Death>)>,
+    q_enemies: Query<
+        (Entity, &Team, &Transform),
+        (
+            Without<AttachedField>,
+            Without<Death>,
+            Without<Untargetable>,
+        ),
+    >,
     q_parent_team: Query<&Team, Without<AttachedField>>,
     time: Res<Time<Fixed>>,
 ) {
@@ -661,6 +739,7 @@ fn update_attached_fields(
                     damage_type: DamageType::Physical,
                     amount: field.damage_amount,
                     tag: None,
+                    kind: DamageKind::Ability,
                 });
             }
         }
@@ -692,9 +771,12 @@ mod tests {
     use lol_base::hash_key::HashKey;
 
     use super::*;
+    use crate::base::buff::BuffOf;
+    use crate::buffs::spell_shield::BuffSpellShield;
     use crate::movement::PluginMovement;
     use crate::navigation::grid::ResourceGrid;
     use crate::navigation::navigation::PluginNavigaton;
+    use crate::spatial::{PluginSpatial, SpatialIndex};
     use crate::team::Team;
 
     const MISSILE_SPELL_KEY: u32 = 0x7001;
@@ -828,4 +910,164 @@ mod tests {
         );
         assert_eq!(count::<WallAnchor>(&mut app), 0, "不应生成 WallAnchor");
     }
+
+    #[derive(Resource, Default)]
+    struct DamageTrace(Vec<Entity>);
+
+    fn on_damage(trigger: On<CommandDamageCreate>, mut trace: ResMut<DamageTrace>) {
+        trace.0.push(trigger.event_target());
+    }
+
+    #[test]
+    fn spell_shield_blocks_linear_missile_hit() {
+        let mut app = app_with_grid();
+        app.init_resource::<DamageTrace>();
+        app.add_observer(on_damage);
+        let caster = app
+            .world_mut()
+            .spawn((
+                Team::Order,
+                Transform::from_xyz(0.0, 0.0, 100.0),
+                GlobalTransform::from_xyz(0.0, 0.0, 100.0),
+            ))
+            .id();
+        let target = app
+            .world_mut()
+            .spawn((Team::Chaos, Transform::from_xyz(150.0, 0.0, 100.0)))
+            .id();
+        app.world_mut()
+            .entity_mut(target)
+            .with_related::<BuffOf>(BuffSpellShield::new(1.5));
+
+        app.world_mut()
+            .entity_mut(caster)
+            .trigger(|e| CommandMissileCreate {
+                entity: e,
+                target: None,
+                destination: Some(Vec3::new(1000.0, 0.0, 100.0)),
+                spell: spell_handle(),
+                damage: 50.0,
+                speed: Some(1200.0),
+                particle_key: None,
+                sticky: false,
+                pass_through: false,
+                collision_target: MissileCollisionTarget::Enemy,
+                missing_hp_scaling: None,
+            });
+        for _ in 0..5 {
+            app.update();
+        }
+
+        assert!(
+            app.world().resource::<DamageTrace>().0.is_empty(),
+            "法术护盾应挡下飞弹伤害"
+        );
+        assert_eq!(count::<LinearMissile>(&mut app), 0, "被挡下的飞弹照常销毁");
+        assert_eq!(count::<BuffSpellShield>(&mut app), 0, "法术护盾应被消耗");
+    }
+
+    #[test]
+    fn linear_missile_hits_through_spatial_index() {
+        let mut app = app_with_grid();
+        app.add_plugins(PluginSpatial);
+        app.init_resource::<DamageTrace>();
+        app.add_observer(on_damage);
+        let caster = app
+            .world_mut()
+            .spawn((
+                Team::Order,
+                Transform::from_xyz(0.0, 0.0, 100.0),
+                GlobalTransform::from_xyz(0.0, 0.0, 100.0),
+            ))
+            .id();
+        let enemy = app
+            .world_mut()
+            .spawn((Team::Chaos, Transform::from_xyz(150.0, 0.0, 100.0)))
+            .id();
+        let ally = app
+            .world_mut()
+            .spawn((Team::Order, Transform::from_xyz(100.0, 0.0, 100.0)))
+            .id();
+        app.update();
+        app.update();
+        assert!(!app.world().resource::<SpatialIndex>().is_empty());
+
+        app.world_mut()
+            .entity_mut(caster)
+            .trigger(|e| CommandMissileCreate {
+                entity: e,
+                target: None,
+                destination: Some(Vec3::new(1000.0, 0.0, 100.0)),
+                spell: spell_handle(),
+                damage: 50.0,
+                speed: Some(1200.0),
+                particle_key: None,
+                sticky: false,
+                pass_through: false,
+                collision_target: MissileCollisionTarget::Enemy,
+                missing_hp_scaling: None,
+            });
+        for _ in 0..5 {
+            app.update();
+        }
+
+        let hits = &app.world().resource::<DamageTrace>().0;
+        assert_eq!(hits, &vec![enemy], "经索引粗筛后只命中敌方单位");
+        assert!(!hits.contains(&ally));
+        assert_eq!(count::<LinearMissile>(&mut app), 0);
+    }
+
+    #[test]
+    fn non_sticky_missile_stops_at_terrain_wall() {
+        let mut app = app_with_grid();
+        app.init_resource::<DamageTrace>();
+        app.add_observer(on_damage);
+        let handle = app.world().resource::<ResourceGrid>().0.clone();
+        app.world_mut()
+            .resource_mut::<Assets<ConfigNavigationGrid>>()
+            .get_mut(&handle)
+            .unwrap()
+            .terrain_cells
+            .insert((1, 1));
+        let caster = app
+            .world_mut()
+            .spawn((
+                Team::Order,
+                Transform::from_xyz(50.0, 0.0, 150.0),
+                GlobalTransform::from_xyz(50.0, 0.0, 150.0),
+            ))
+            .id();
+        let behind_wall = app
+            .world_mut()
+            .spawn((Team::Chaos, Transform::from_xyz(400.0, 0.0, 150.0)))
+            .id();
+
+        app.world_mut()
+            .entity_mut(caster)
+            .trigger(|e| CommandMissileCreate {
+                entity: e,
+                target: None,
+                destination: Some(Vec3::new(1000.0, 0.0, 150.0)),
+                spell: spell_handle(),
+                damage: 50.0,
+                speed: Some(1200.0),
+                particle_key: None,
+                sticky: false,
+                pass_through: true,
+                collision_target: MissileCollisionTarget::Enemy,
+                missing_hp_scaling: None,
+            });
+        for _ in 0..15 {
+            app.update();
+        }
+
+        assert_eq!(count::<LinearMissile>(&mut app), 0, "飞弹应被地形墙挡下");
+        assert!(
+            !app.world()
+                .resource::<DamageTrace>()
+                .0
+                .contains(&behind_wall),
+            "墙后的单位不应被命中"
+        );
+    }
 }
@@ -0,0 +1,423 @@
+//! 投射物阻挡：风墙、盾牌等阻挡体拦截敌方飞弹（亚索 W、布隆 E、萨米拉 W）。
+//!
+//! 阻挡体是独立实体，按 [`BlockerShape`] 在 XZ 平面上与飞弹本帧的位移线段求交，
+//! 命中后按 [`BlockResponse`] 销毁或反弹飞弹，并在阻挡体所有者上触发 [`EventMissileBlocked`]。
+//! 判定排在 `linear_missile_collision` 之前，被拦下的直线飞弹不会再结算本帧碰撞。
+
+use bevy::prelude::*;
+use lol_base::spell::Spell;
+
+use crate::missile::{LinearMissile, Missile, MissileState, linear_missile_collision};
+use crate::movement::{CommandMovement, MovementAction, MovementSource, MovementWay};
+use crate::team::Team;
+
+#[derive(Default)]
+pub struct PluginMissileBlock;
+
+impl Plugin for PluginMissileBlock {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (update_missile_blockers, block_missiles)
+                .chain()
+                .before(linear_missile_collision),
+        );
+    }
+}
+
+/// 阻挡体形状（XZ 平面）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlockerShape {
+    /// 以阻挡体位置为圆心的圆
+    Circle { radius: f32 },
+    /// 以阻挡体位置为中心、沿其局部 X 轴展开的墙
+    Wall { half_length: f32, thickness: f32 },
+}
+
+impl BlockerShape {
+    /// 飞弹位移线段 `from -> to` 是否触及阻挡体
+    pub fn intersects_segment(&self, transform: &Transform, from: Vec2, to: Vec2) -> bool {
+        let center = transform.translation.xz();
+        match *self {
+            BlockerShape::Circle { radius } => point_segment_distance(center, from, to) <= radius,
+            BlockerShape::Wall {
+                half_length,
+                thickness,
+            } => {
+                let axis = (transform.rotation * Vec3::X).xz().normalize_or(Vec2::X);
+                let wall_start = center - axis * half_length;
+                let wall_end = center + axis * half_length;
+                segment_distance(from, to, wall_start, wall_end) <= thickness * 0.5
+            }
+        }
+    }
+}
+
+/// 命中阻挡体后的处理
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlockResponse {
+    /// 销毁飞弹
+    #[default]
+    Destroy,
+    /// 反弹：飞弹改归阻挡体所有者，飞回原施法者
+    Reflect,
+}
+
+/// 投射物阻挡体，只拦截敌方队伍的飞弹
+#[derive(Component, Debug, Clone)]
+pub struct MissileBlocker {
+    pub owner: Entity,
+    pub team: Team,
+    pub shape: BlockerShape,
+    pub response: BlockResponse,
+    /// 跟随的实体：每帧同步位置，保留阻挡体自身朝向
+    pub follow: Option<Entity>,
+    /// 跟随时相对被跟随者的偏移（世界坐标）
+    pub offset: Vec3,
+    pub timer: Timer,
+}
+
+impl MissileBlocker {
+    pub fn new(owner: Entity, team: Team, shape: BlockerShape, duration: f32) -> Self {
+        Self {
+            owner,
+            team,
+            shape,
+            response: BlockResponse::Destroy,
+            follow: None,
+            offset: Vec3::ZERO,
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+        }
+    }
+
+    pub fn with_response(mut self, response: BlockResponse) -> Self {
+        self.response = response;
+        self
+    }
+
+    /// 跟随 `entity`；被跟随者消失时阻挡体一并销毁
+    pub fn following(mut self, entity: Entity, offset: Vec3) -> Self {
+        self.follow = Some(entity);
+        self.offset = offset;
+        self
+    }
+}
+
+/// 飞弹被阻挡事件，触发于阻挡体所有者
+#[derive(EntityEvent, Debug, Clone)]
+pub struct EventMissileBlocked {
+    #[event_target]
+    pub owner: Entity,
+    pub blocker: Entity,
+    pub missile: Entity,
+    /// 飞弹原施法者
+    pub source: Entity,
+    pub spell: Handle<Spell>,
+    pub reflected: bool,
+}
+
+/// 阻挡体生命周期与跟随
+fn update_missile_blockers(
+    mut commands: Commands,
+    mut q_blocker: Query<(Entity, &mut MissileBlocker, &mut Transform)>,
+    q_follow: Query<&Transform, Without<MissileBlocker>>,
+    time: Res<Time<Fixed>>,
+) {
+    for (entity, mut blocker, mut transform) in q_blocker.iter_mut() {
+        blocker.timer.tick(time.delta());
+        if blocker.timer.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let Some(follow) = blocker.follow else {
+            continue;
+        };
+        match q_follow.get(follow) {
+            Ok(follow_transform) => {
+                transform.translation = follow_transform.translation + blocker.offset;
+            }
+            Err(_) => {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+/// 用飞弹本帧将要走过的线段检测敌方阻挡体
+fn block_missiles(
+    mut commands: Commands,
+    q_blocker: Query<(Entity, &MissileBlocker, &Transform)>,
+    mut q_missile: Query<
+        (
+            Entity,
+            &Missile,
+            &mut MissileState,
+            &Team,
+            &Transform,
+            Option<&mut LinearMissile>,
+        ),
+        Without<MissileBlocker>,
+    >,
+    q_global_transform: Query<&GlobalTransform>,
+    time: Res<Time<Fixed>>,
+) {
+    if q_blocker.is_empty() {
+        return;
+    }
+    let dt = time.delta_secs();
+
+    for (missile_entity, missile, mut state, team, transform, linear) in q_missile.iter_mut() {
+        // 直线飞弹朝目标点，追踪飞弹朝目标骨骼
+        let aim = match state.destination {
+            Some(destination) => destination,
+            None => {
+                let Some(target_transform) = state
+                    .target_bone
+                    .and_then(|bone| q_global_transform.get(bone).ok())
+                else {
+                    continue;
+                };
+                target_transform.translation()
+            }
+        };
+
+        let current = transform.translation;
+        let to_aim = (aim - current).xz();
+        let step = (missile.speed * dt).min(to_aim.length());
+        let from = current.xz();
+        let to = from + to_aim.normalize_or_zero() * step;
+
+        let Some((blocker_entity, blocker)) = q_blocker
+            .iter()
+            .filter(|(_, blocker, _)| blocker.team != *team)
+            .find(|(_, blocker, tf)| blocker.shape.intersects_segment(tf, from, to))
+            .map(|(e, blocker, _)| (e, blocker))
+        else {
+            continue;
+        };
+
+        let original_source = state.source;
+        let reflected = blocker.response == BlockResponse::Reflect;
+        if reflected {
+            match linear {
+                // 直线飞弹掉头飞向原施法者，保留剩余射程
+                Some(mut linear) => {
+                    let source_position = q_global_transform
+                        .get(original_source)
+                        .map(|t| t.translation())
+                        .ok();
+                    let destination = reflected_destination(current, aim, source_position);
+                    state.destination = Some(destination);
+                    linear.hit_enemies.clear();
+                    commands.trigger(CommandMovement {
+                        entity: missile_entity,
+                        priority: 0,
+                        action: MovementAction::Start {
+                            way: MovementWay::Path(vec![destination]),
+                            speed: Some(missile.speed),
+                            source: MovementSource::Missile,
+                        },
+                    });
+                }
+                // 追踪飞弹改追原施法者
+                None => {
+                    state.target = Some(original_source);
+                    state.target_bone = Some(original_source);
+                }
+            }
+            state.source = blocker.owner;
+            commands.entity(missile_entity).insert(blocker.team);
+        } else {
+            commands.entity(missile_entity).despawn();
+        }
+
+        debug!(
+            "{:?} 的阻挡体{}了 {:?} 的飞弹",
+            blocker.owner,
+            if reflected { "反弹" } else { "拦截" },
+            original_source
+        );
+        commands.trigger(EventMissileBlocked {
+            owner: blocker.owner,
+            blocker: blocker_entity,
+            missile: missile_entity,
+            source: original_source,
+            spell: missile.key.clone(),
+            reflected,
+        });
+    }
+}
+
+/// 反弹后的直线飞弹目标点：朝原施法者方向，射程为剩余射程加上已飞行距离
+fn reflected_destination(current: Vec3, aim: Vec3, source_position: Option<Vec3>) -> Vec3 {
+    let remaining = current.distance(aim);
+    let back = source_position
+        .map(|p| (p - current).with_y(0.0))
+        .unwrap_or(current - aim)
+        .normalize_or_zero();
+    let range = source_position.map_or(0.0, |p| p.distance(current)) + remaining;
+    current + back * range
+}
+
+/// 点 `p` 到线段 `ab` 的最近距离
+fn point_segment_distance(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+    let t = if len_sq > f32::EPSILON {
+        ((p - a).dot(ab) / len_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    p.distance(a + ab * t)
+}
+
+/// 两线段最近距离（相交为 0）
+fn segment_distance(a0: Vec2, a1: Vec2, b0: Vec2, b1: Vec2) -> f32 {
+    let a = a1 - a0;
+    let b = b1 - b0;
+    let d1 = a.perp_dot(b0 - a0);
+    let d2 = a.perp_dot(b1 - a0);
+    let d3 = b.perp_dot(a0 - b0);
+    let d4 = b.perp_dot(a1 - b0);
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return 0.0;
+    }
+    point_segment_distance(a0, b0, b1)
+        .min(point_segment_distance(a1, b0, b1))
+        .min(point_segment_distance(b0, a0, a1))
+        .min(point_segment_distance(b1, a0, a1))
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::time::TimeUpdateStrategy;
+    use lol_base::hash_key::HashKey;
+
+    use super::*;
+    use crate::missile::{CommandMissileCreate, MissileCollisionTarget, PluginMissile};
+    use crate::movement::PluginMovement;
+
+    #[derive(Resource, Default)]
+    struct BlockTrace(Vec<(Entity, bool)>);
+
+    fn on_blocked(trigger: On<EventMissileBlocked>, mut trace: ResMut<BlockTrace>) {
+        trace.0.push((trigger.source, trigger.reflected));
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AssetPlugin::default());
+        app.add_plugins((PluginMissile, PluginMissileBlock, PluginMovement));
+        app.init_asset::<Spell>();
+        app.init_resource::<BlockTrace>();
+        app.add_observer(on_blocked);
+        app.insert_resource(Time::<Fixed>::from_hz(30.0));
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+        app
+    }
+
+    /// Order 方从原点向 +X 发射直线飞弹，Chaos 方在 x=300 处竖一道南北向风墙
+    fn fire_into_wall(app: &mut App, response: BlockResponse) -> (Entity, Entity) {
+        let caster = app
+            .world_mut()
+            .spawn((
+                Team::Order,
+                Transform::default(),
+                GlobalTransform::default(),
+            ))
+            .id();
+        let owner = app.world_mut().spawn(Team::Chaos).id();
+        app.world_mut().spawn((
+            MissileBlocker::new(
+                owner,
+                Team::Chaos,
+                BlockerShape::Wall {
+                    half_length: 200.0,
+                    thickness: 40.0,
+                },
+                4.0,
+            )
+            .with_response(response),
+            Transform::from_xyz(300.0, 0.0, 0.0).looking_to(Vec3::X, Vec3::Y),
+        ));
+
+        app.world_mut()
+            .entity_mut(caster)
+            .trigger(|e| CommandMissileCreate {
+                entity: e,
+                target: None,
+                destination: Some(Vec3::new(1000.0, 0.0, 0.0)),
+                spell: Handle::from(HashKey::<Spell>::from(0x7002)),
+                damage: 50.0,
+                speed: Some(1200.0),
+                particle_key: None,
+                sticky: false,
+                pass_through: false,
+                collision_target: MissileCollisionTarget::Enemy,
+                missing_hp_scaling: None,
+            });
+        (caster, owner)
+    }
+
+    fn missile_x(app: &mut App) -> Option<f32> {
+        let mut q = app
+            .world_mut()
+            .query_filtered::<&Transform, With<LinearMissile>>();
+        q.iter(app.world()).next().map(|t| t.translation.x)
+    }
+
+    #[test]
+    fn wall_destroys_enemy_missile() {
+        let mut app = app();
+        let (caster, _) = fire_into_wall(&mut app, BlockResponse::Destroy);
+        for _ in 0..15 {
+            app.update();
+        }
+
+        assert_eq!(
+            app.world().resource::<BlockTrace>().0,
+            vec![(caster, false)]
+        );
+        assert_eq!(missile_x(&mut app), None, "被拦截的飞弹应已销毁");
+    }
+
+    #[test]
+    fn wall_reflects_missile_back_to_owner_team() {
+        let mut app = app();
+        let (caster, owner) = fire_into_wall(&mut app, BlockResponse::Reflect);
+        // 第 7 帧触墙反弹，再飞一帧，尚未回到施法者身边
+        for _ in 0..8 {
+            app.update();
+        }
+
+        assert_eq!(app.world().resource::<BlockTrace>().0, vec![(caster, true)]);
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(&MissileState, &Team, &Transform), With<LinearMissile>>();
+        let (state, team, transform) = q.single(app.world()).unwrap();
+        assert_eq!(state.source, owner);
+        assert_eq!(*team, Team::Chaos);
+        assert!(transform.translation.x < 300.0, "反弹后应飞回施法者一侧");
+        assert!(state.destination.unwrap().x < 0.0);
+    }
+
+    #[test]
+    fn segment_distance_handles_crossing_and_parallel() {
+        let d = segment_distance(
+            Vec2::new(-1.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, -1.0),
+            Vec2::new(0.0, 1.0),
+        );
+        assert_eq!(d, 0.0);
+        let d = segment_distance(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(0.0, 3.0),
+            Vec2::new(2.0, 3.0),
+        );
+        assert!((d - 3.0).abs() < 1e-5);
+    }
+}
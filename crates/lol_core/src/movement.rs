@@ -5,6 +5,7 @@ use bevy::prelude::*;
 use lol_base::grid::ConfigNavigationGrid;
 use serde::{Deserialize, Serialize};
 
+use crate::avoidance::compute_avoidance_velocities;
 use crate::base::bounding::Bounding;
 use crate::base::pipeline::{
     ArbitrationPipelinePlugin, FinalDecision, LastDecision, PipelineStages, RequestBuffer,
@@ -33,6 +34,7 @@ impl Plugin for PluginMovement {
                 reduce_movement_by_priority.in_set(MovementPipeline::Reduce),
                 (
                     apply_final_movement_decision.run_if(resource_exists::<ResourceGrid>),
+                    compute_avoidance_velocities,
                     update_path_movement,
                 )
                     .chain()
@@ -77,6 +79,8 @@ pub struct MovementState {
     pub completed: bool,
     pub pathfind: Option<(Vec3, f32)>,
     pub source: MovementSource,
+    /// 本帧局部避让解出的速度，由 `update_path_movement` 消费
+    pub avoidance_velocity: Option<Vec2>,
 }
 
 #[derive(Component, Default)]
@@ -86,8 +90,12 @@ pub struct MovementBlock;
 #[derive(Component, Default)]
 pub struct CastBlock;
 
+/// 位移阻塞组件：禁锢 / 禁足等控制期间不能发起位移技能，插入时打断进行中的位移
+#[derive(Component, Default)]
+pub struct DashBlock;
+
 /// 减速标记：由 CC 系统按最强活跃减速写入角色（percent 0.0-1.0）。
-/// 移动系统据此按比例降低本帧位移速度。轻量标记，逻辑在 DebuffSlow buff 实体上。
+/// 属性修正层据此按比例降低 `Movement::speed` 后再套移速软上限。轻量标记，逻辑在 DebuffSlow buff 实体上。
 #[derive(Component, Debug, Clone, Default)]
 pub struct MovementSlow {
     pub percent: f32,
@@ -209,29 +217,71 @@ fn calculate_and_set_exclude_cells(grid: &mut ConfigNavigationGrid, entity_pos:
 fn update_path_movement(
     mut commands: Commands,
     mut query: Query<
-        (Entity, &Movement, &mut MovementState, Option<&MovementSlow>),
+        (Entity, &Movement, &mut MovementState),
         (Without<MovementBlock>, Without<Death>),
     >,
     mut q_transform: Query<&mut Transform>,
+    res_grid: Option<Res<ResourceGrid>>,
+    assets_grid: Option<Res<Assets<ConfigNavigationGrid>>>,
     time: Res<Time<Fixed>>,
 ) {
     let dt = time.delta_secs();
+    let grid = res_grid
+        .as_ref()
+        .zip(assets_grid.as_ref())
+        .and_then(|(res_grid, assets_grid)| assets_grid.get(&res_grid.0));
 
-    for (entity, movement, mut movement_state, slow) in query.iter_mut() {
+    for (entity, movement, mut movement_state) in query.iter_mut() {
         if movement_state.completed || movement_state.path.is_empty() {
             continue;
         }
 
         let mut transform = q_transform.get_mut(entity).unwrap();
 
+        // 减速已由属性修正层折算进 movement.speed
         let speed = movement_state.speed.unwrap_or(movement.speed);
-        // 减速：按最强活跃减速比例降低本帧速度（系统只认 MovementSlow 标记）
-        let speed = speed * slow.map_or(1.0, |s| 1.0 - s.percent);
 
         let mut remaining_distance_this_frame = speed * dt;
 
         let mut last_direction = Vec2::ZERO;
 
+        // 局部避让：按避让速度走一步，走进墙体时退回按路径行走
+        let avoidance_velocity = movement_state.avoidance_velocity.take();
+        let steered = avoidance_velocity.is_some_and(|velocity| {
+            let Some(target) = movement_state.path.get(movement_state.current_target_index) else {
+                return false;
+            };
+            let target_xz = target.xz();
+            let new_pos_xz = transform.translation.xz() + velocity * dt;
+            // 只看静态墙体与地形覆盖层，单位占位交给避让本身
+            let blocked =
+                grid.is_some_and(|grid| match grid.get_cell_xy_by_position(&new_pos_xz) {
+                    Some(xy) => {
+                        !grid.get_cell_by_xy(xy).is_walkable() || grid.terrain_cells.contains(&xy)
+                    }
+                    None => true,
+                });
+            if blocked {
+                return false;
+            }
+            transform.translation.x = new_pos_xz.x;
+            transform.translation.z = new_pos_xz.y;
+            last_direction = velocity.normalize_or_zero();
+
+            if new_pos_xz.distance(target_xz) <= remaining_distance_this_frame {
+                let new_index = movement_state.current_target_index + 1;
+                if new_index >= movement_state.path.len() {
+                    movement_state.completed = true;
+                } else {
+                    movement_state.current_target_index = new_index;
+                }
+            }
+            true
+        });
+        if steered {
+            remaining_distance_this_frame = 0.0;
+        }
+
         while remaining_distance_this_frame > 0.0 {
             let target = match movement_state.path.get(movement_state.current_target_index) {
                 Some(p) => *p,
@@ -304,7 +354,10 @@ fn update_path_movement(
             movement_state.clear_path();
         } else {
             movement_state.direction = last_direction;
-            movement_state.velocity = last_direction * speed;
+            movement_state.velocity = match avoidance_velocity {
+                Some(velocity) if steered => velocity,
+                _ => last_direction * speed,
+            };
         }
 
         if last_direction.length_squared() > 0.0 {
@@ -419,50 +472,51 @@ fn apply_final_movement_decision(
                 let now = time.elapsed_secs();
 
                 // 检查是否需要重新规划路径
-                let need_replan = if let Some((last_target, last_replan_time)) = movement_state.pathfind {
-                    let target_teleported = (target - last_target).xz().length() > 150.0;
-                    let cooldown_elapsed = (now - last_replan_time) >= REPLAN_COOLDOWN_SECS;
+                let need_replan =
+                    if let Some((last_target, last_replan_time)) = movement_state.pathfind {
+                        let target_teleported = (target - last_target).xz().length() > 150.0;
+                        let cooldown_elapsed = (now - last_replan_time) >= REPLAN_COOLDOWN_SECS;
 
-                    if target_teleported {
-                        commands.trigger(CommandLog {
-                            entity,
-                            info: format!("目标位置发生突变: {}", target_teleported),
-                            category: EnumLogCategory::Movement,
-                        });
-                        true
-                    } else if cooldown_elapsed {
-                        let target_moved = (target - last_target).xz().length() > 20.0;
-                        let path_blocked = is_path_blocked(
-                            &grid,
-                            &movement_state.path,
-                            movement_state.current_target_index,
-                            Some(transform.translation.xz()),
-                        );
-                        if target_moved || path_blocked {
+                        if target_teleported {
                             commands.trigger(CommandLog {
                                 entity,
-                                info: format!(
-                                    "目标移动或路径受阻: moved={}, blocked={}",
-                                    target_moved, path_blocked
-                                ),
+                                info: format!("目标位置发生突变: {}", target_teleported),
                                 category: EnumLogCategory::Movement,
                             });
                             true
+                        } else if cooldown_elapsed {
+                            let target_moved = (target - last_target).xz().length() > 20.0;
+                            let path_blocked = is_path_blocked(
+                                &grid,
+                                &movement_state.path,
+                                movement_state.current_target_index,
+                                Some(transform.translation.xz()),
+                            );
+                            if target_moved || path_blocked {
+                                commands.trigger(CommandLog {
+                                    entity,
+                                    info: format!(
+                                        "目标移动或路径受阻: moved={}, blocked={}",
+                                        target_moved, path_blocked
+                                    ),
+                                    category: EnumLogCategory::Movement,
+                                });
+                                true
+                            } else {
+                                false
+                            }
                         } else {
                             false
                         }
                     } else {
-                        false
-                    }
-                } else {
-                    // 第一次规划
-                    commands.trigger(CommandLog {
-                        entity,
-                        info: "第一次规划".to_string(),
-                        category: EnumLogCategory::Movement,
-                    });
-                    true
-                };
+                        // 第一次规划
+                        commands.trigger(CommandLog {
+                            entity,
+                            info: "第一次规划".to_string(),
+                            category: EnumLogCategory::Movement,
+                        });
+                        true
+                    };
 
                 if !need_replan {
                     continue;
@@ -578,6 +632,7 @@ mod tests {
             height_samples: vec![vec![0.0; 100]; 100],
             occupied_cells: Default::default(),
             exclude_cells: Default::default(),
+            terrain_cells: Default::default(),
         }
     }
 
@@ -611,7 +666,9 @@ mod tests {
             .id();
 
         // 第一次执行，进行首次寻路
-        let _ = app.world_mut().run_system_once(apply_final_movement_decision);
+        let _ = app
+            .world_mut()
+            .run_system_once(apply_final_movement_decision);
         let stats_1 = app.world().resource::<NavigationStats>().get_nav_path_count;
         assert_eq!(stats_1, 1, "首次规划应执行 1 次寻路");
 
@@ -634,7 +691,9 @@ mod tests {
                 },
             }));
 
-        let _ = app.world_mut().run_system_once(apply_final_movement_decision);
+        let _ = app
+            .world_mut()
+            .run_system_once(apply_final_movement_decision);
         let stats_2 = app.world().resource::<NavigationStats>().get_nav_path_count;
         assert_eq!(stats_2, 1, "冷却时间内相同目标不应重复寻路");
 
@@ -651,7 +710,9 @@ mod tests {
                 },
             }));
 
-        let _ = app.world_mut().run_system_once(apply_final_movement_decision);
+        let _ = app
+            .world_mut()
+            .run_system_once(apply_final_movement_decision);
         let stats_3 = app.world().resource::<NavigationStats>().get_nav_path_count;
         assert_eq!(stats_3, 2, "目标改变应立即触发新寻路");
     }
@@ -373,6 +373,7 @@ mod tests {
             height_samples: vec![vec![0.0; size]; size],
             occupied_cells: Default::default(),
             exclude_cells: Default::default(),
+            terrain_cells: Default::default(),
         }
     }
 
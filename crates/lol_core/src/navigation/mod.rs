@@ -2,3 +2,4 @@ pub mod astar;
 pub mod grid;
 pub mod navigation;
 pub mod smoother;
+pub mod terrain;
@@ -9,6 +9,7 @@ use crate::character::Character;
 use crate::loaders::navgrid::NavGridLoader;
 use crate::navigation::astar::find_grid_path_with_result;
 use crate::navigation::grid::ResourceGrid;
+use crate::navigation::terrain::PluginTerrain;
 
 #[derive(Default)]
 pub struct PluginNavigaton;
@@ -17,6 +18,7 @@ impl Plugin for PluginNavigaton {
     fn build(&self, app: &mut App) {
         app.init_asset::<ConfigNavigationGrid>();
         app.init_asset_loader::<NavGridLoader>();
+        app.add_plugins(PluginTerrain);
 
         app.init_resource::<NavigationStats>();
         app.init_resource::<NavigationDebugState>();
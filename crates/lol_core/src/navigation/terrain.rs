@@ -0,0 +1,429 @@
+use bevy::prelude::*;
+use lol_base::grid::ConfigNavigationGrid;
+
+use crate::movement::{
+    CommandMovement, MovementAction, MovementPipeline, MovementState, MovementWay,
+};
+use crate::navigation::grid::ResourceGrid;
+use crate::navigation::navigation::is_path_blocked;
+
+/// 运行时地形覆盖层：技能墙等限时多边形写入导航网格的 `terrain_cells`，
+/// 寻路、视线、路径阻塞检测、位移与飞弹碰墙都据此视同墙体。
+///
+/// - 普通位移经 [`clip_segment_by_terrain`] 停在墙前，追踪位移每帧把终点截到墙前；
+/// - 闪现等瞬移经 [`blink_landing`] 可越过薄墙，但不会落进墙里；
+/// - 新墙生成时压在墙内的单位被挤到最近的可站立位置。
+#[derive(Default)]
+pub struct PluginTerrain;
+
+impl Plugin for PluginTerrain {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_command_terrain_wall_create);
+        app.add_systems(FixedUpdate, update_terrain_walls);
+        app.add_systems(
+            FixedPostUpdate,
+            sync_terrain_overlay
+                .run_if(resource_exists::<ResourceGrid>)
+                .before(MovementPipeline::Reduce),
+        );
+    }
+}
+
+/// 限时地形墙，polygon 为 XZ 平面上的世界坐标顶点
+#[derive(Component, Debug)]
+pub struct TerrainWall {
+    pub source: Entity,
+    pub polygon: Vec<Vec2>,
+    pub timer: Timer,
+}
+
+/// 创建地形墙：`entity` 为施放者，`polygon` 为世界坐标顶点，`duration` 秒后消失。
+#[derive(EntityEvent, Debug, Clone)]
+pub struct CommandTerrainWallCreate {
+    pub entity: Entity,
+    pub polygon: Vec<Vec2>,
+    pub duration: f32,
+}
+
+/// 地形墙已创建，供渲染端绘制墙体
+#[derive(EntityEvent, Debug, Clone)]
+pub struct EventTerrainWallCreated {
+    /// 墙体实体
+    pub entity: Entity,
+    pub source: Entity,
+    pub polygon: Vec<Vec2>,
+    pub duration: f32,
+}
+
+/// 地形墙到期消失
+#[derive(EntityEvent, Debug, Clone)]
+pub struct EventTerrainWallRemoved {
+    pub entity: Entity,
+}
+
+/// 以 center 为中点、垂直于 facing 展开的矩形墙（长 length、厚 thickness）
+pub fn terrain_wall_segment(center: Vec2, facing: Vec2, length: f32, thickness: f32) -> Vec<Vec2> {
+    let facing = facing.try_normalize().unwrap_or(Vec2::X);
+    let along = Vec2::new(-facing.y, facing.x) * (length / 2.0);
+    let across = facing * (thickness / 2.0);
+    vec![
+        center - along - across,
+        center + along - across,
+        center + along + across,
+        center - along + across,
+    ]
+}
+
+/// 射线法判断点是否在多边形内
+pub fn point_in_polygon(point: Vec2, polygon: &[Vec2]) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len().wrapping_sub(1);
+    for i in 0..polygon.len() {
+        let (a, b) = (polygon[i], polygon[j]);
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+fn distance_to_segment(point: Vec2, start: Vec2, end: Vec2) -> f32 {
+    let seg = end - start;
+    let t = if seg.length_squared() > 0.0 {
+        ((point - start).dot(seg) / seg.length_squared()).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    point.distance(start + seg * t)
+}
+
+/// 多边形覆盖的格子：格子中心在多边形内，或多边形边穿过该格子。
+/// 后者保证比格子还薄的墙也不会被穿过。
+pub fn rasterize_polygon(grid: &ConfigNavigationGrid, polygon: &[Vec2]) -> Vec<(usize, usize)> {
+    if polygon.len() < 3 || grid.x_len == 0 || grid.y_len == 0 {
+        return Vec::new();
+    }
+    let (min, max) = polygon[1..]
+        .iter()
+        .fold((polygon[0], polygon[0]), |(min, max), p| {
+            (min.min(*p), max.max(*p))
+        });
+    let (min_x, min_y) = grid.clamp_position_to_grid_xy(&min);
+    let (max_x, max_y) = grid.clamp_position_to_grid_xy(&max);
+    let half_cell = grid.cell_size / 2.0;
+
+    let mut cells = Vec::new();
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            let center = grid.get_cell_center_position_by_xy((x, y)).xz();
+            let touched = point_in_polygon(center, polygon)
+                || (0..polygon.len()).any(|i| {
+                    let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+                    distance_to_segment(center, a, b) <= half_cell
+                });
+            if touched {
+                cells.push((x, y));
+            }
+        }
+    }
+    cells
+}
+
+/// 把 start → end 的位移截断在第一道地形墙之前，返回可到达的终点。
+/// 只看覆盖层，静态墙体的翻越规则由各位移自行决定。
+pub fn clip_segment_by_terrain(grid: &ConfigNavigationGrid, start: Vec2, end: Vec2) -> Vec2 {
+    if grid.terrain_cells.is_empty() {
+        return end;
+    }
+    let distance = start.distance(end);
+    let step = (grid.cell_size / 2.0).max(1.0);
+    let steps = (distance / step).ceil() as usize;
+    let mut last_free = start;
+    for i in 1..=steps {
+        let point = start.lerp(end, (i as f32 * step / distance).min(1.0));
+        if grid
+            .get_cell_xy_by_position(&point)
+            .is_some_and(|xy| grid.terrain_cells.contains(&xy))
+        {
+            return last_free;
+        }
+        last_free = point;
+    }
+    end
+}
+
+/// 瞬移落点：终点落在静态墙体或地形覆盖层内时，沿 end → start 回退到第一个可站立的点；
+/// 中途的墙可以越过（闪现翻墙），全程都不可站立则留在 start。
+pub fn blink_landing(grid: &ConfigNavigationGrid, start: Vec2, end: Vec2) -> Vec2 {
+    let standable = |point: Vec2| {
+        grid.get_cell_xy_by_position(&point).is_some_and(|xy| {
+            grid.get_cell_by_xy(xy).is_walkable() && !grid.terrain_cells.contains(&xy)
+        })
+    };
+    if standable(end) {
+        return end;
+    }
+    let distance = start.distance(end);
+    let step = (grid.cell_size / 2.0).max(1.0);
+    let steps = (distance / step).ceil() as usize;
+    for i in 1..=steps {
+        let point = end.lerp(start, (i as f32 * step / distance).min(1.0));
+        if standable(point) {
+            return point;
+        }
+    }
+    start
+}
+
+/// 被覆盖层压住时的脱困位置：由近及远逐圈搜索第一个可站立格子的中心；
+/// 不在覆盖层内时返回 `None`。
+pub fn terrain_escape_point(grid: &ConfigNavigationGrid, point: Vec2) -> Option<Vec2> {
+    let (cx, cy) = grid.get_cell_xy_by_position(&point)?;
+    if !grid.terrain_cells.contains(&(cx, cy)) {
+        return None;
+    }
+    let max_ring = grid.x_len.max(grid.y_len);
+    for ring in 1..=max_ring {
+        let mut best: Option<Vec2> = None;
+        let (min_x, max_x) = (cx.saturating_sub(ring), (cx + ring).min(grid.x_len - 1));
+        let (min_y, max_y) = (cy.saturating_sub(ring), (cy + ring).min(grid.y_len - 1));
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let on_ring = x.abs_diff(cx) == ring || y.abs_diff(cy) == ring;
+                if !on_ring || !grid.is_walkable_by_xy((x, y)) {
+                    continue;
+                }
+                let center = grid.get_cell_center_position_by_xy((x, y)).xz();
+                if best.is_none_or(|b| center.distance(point) < b.distance(point)) {
+                    best = Some(center);
+                }
+            }
+        }
+        if best.is_some() {
+            return best;
+        }
+    }
+    None
+}
+
+fn on_command_terrain_wall_create(trigger: On<CommandTerrainWallCreate>, mut commands: Commands) {
+    let source = trigger.event_target();
+    if trigger.polygon.len() < 3 {
+        return;
+    }
+    let centroid = trigger.polygon.iter().copied().sum::<Vec2>() / trigger.polygon.len() as f32;
+    let wall = commands
+        .spawn((
+            TerrainWall {
+                source,
+                polygon: trigger.polygon.clone(),
+                timer: Timer::from_seconds(trigger.duration, TimerMode::Once),
+            },
+            Transform::from_xyz(centroid.x, 0.0, centroid.y),
+        ))
+        .id();
+    debug!(
+        "{:?} 创建地形墙 {:?}，持续 {:.1} 秒",
+        source, wall, trigger.duration
+    );
+    commands.trigger(EventTerrainWallCreated {
+        entity: wall,
+        source,
+        polygon: trigger.polygon.clone(),
+        duration: trigger.duration,
+    });
+}
+
+fn update_terrain_walls(
+    mut commands: Commands,
+    mut q_wall: Query<(Entity, &mut TerrainWall)>,
+    time: Res<Time<Fixed>>,
+) {
+    for (entity, mut wall) in q_wall.iter_mut() {
+        wall.timer.tick(time.delta());
+        if wall.timer.is_finished() {
+            commands.trigger(EventTerrainWallRemoved { entity });
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// 墙体增删时重建 `terrain_cells`：把压在墙内的单位挤出去，
+/// 并让路径被新墙挡住的寻路单位立即重新寻路。
+fn sync_terrain_overlay(
+    mut commands: Commands,
+    q_added: Query<(), Added<TerrainWall>>,
+    mut removed: RemovedComponents<TerrainWall>,
+    q_wall: Query<&TerrainWall>,
+    mut q_moving: Query<(Entity, &mut Transform, &mut MovementState)>,
+    res_grid: Res<ResourceGrid>,
+    mut assets_grid: ResMut<Assets<ConfigNavigationGrid>>,
+) {
+    let any_removed = removed.read().count() > 0;
+    if q_added.is_empty() && !any_removed {
+        return;
+    }
+    let Some(mut grid) = assets_grid.get_mut(&res_grid.0) else {
+        return;
+    };
+
+    let mut terrain_cells = std::mem::take(&mut grid.terrain_cells);
+    terrain_cells.clear();
+    for wall in q_wall.iter() {
+        terrain_cells.extend(rasterize_polygon(&grid, &wall.polygon));
+    }
+    grid.terrain_cells = terrain_cells;
+
+    for (entity, mut transform, mut movement_state) in q_moving.iter_mut() {
+        if let Some(escape) = terrain_escape_point(&grid, transform.translation.xz()) {
+            debug!("{:?} 被地形墙压住，挤到 {:?}", entity, escape);
+            transform.translation.x = escape.x;
+            transform.translation.z = escape.y;
+        }
+        let Some((target, _)) = movement_state.pathfind else {
+            continue;
+        };
+        if movement_state.path.is_empty()
+            || !is_path_blocked(
+                &grid,
+                &movement_state.path,
+                movement_state.current_target_index,
+                Some(transform.translation.xz()),
+            )
+        {
+            continue;
+        }
+        // 清掉寻路记录，下一次寻路指令跳过重规划冷却
+        movement_state.pathfind = None;
+        commands.trigger(CommandMovement {
+            entity,
+            priority: 0,
+            action: MovementAction::Start {
+                way: MovementWay::Pathfind(target),
+                speed: movement_state.speed,
+                source: movement_state.source.clone(),
+            },
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::time::TimeUpdateStrategy;
+    use lol_base::grid::ConfigNavigationGridCell;
+
+    use super::*;
+    use crate::navigation::navigation::{find_path, has_line_of_sight};
+
+    fn make_grid() -> ConfigNavigationGrid {
+        ConfigNavigationGrid {
+            min_position: Vec2::ZERO,
+            cell_size: 50.0,
+            x_len: 40,
+            y_len: 40,
+            cells: vec![vec![ConfigNavigationGridCell::default(); 40]; 40],
+            ..default()
+        }
+    }
+
+    #[test]
+    fn thin_wall_blocks_line_of_sight_and_dashes() {
+        let mut grid = make_grid();
+        // 沿 z 方向的薄墙，位于 x = 1000 处
+        let wall = terrain_wall_segment(Vec2::new(1000.0, 1000.0), Vec2::X, 1200.0, 10.0);
+        grid.terrain_cells.extend(rasterize_polygon(&grid, &wall));
+
+        let to_grid = |p: Vec2| (p - grid.min_position) / grid.cell_size;
+        let (a, b) = (Vec2::new(600.0, 1000.0), Vec2::new(1400.0, 1000.0));
+        assert!(!has_line_of_sight(&grid, to_grid(a), to_grid(b)));
+
+        let clipped = clip_segment_by_terrain(&grid, a, b);
+        assert!(clipped.x < 1000.0 - 25.0, "位移应停在墙前，实际 {clipped}");
+
+        // 绕过墙端的路径仍然存在
+        let path = find_path(&grid, &a, &b).expect("应能绕墙");
+        assert!(path.len() > 2);
+    }
+
+    #[test]
+    fn blink_crosses_thin_wall_but_not_into_thick_wall() {
+        let mut grid = make_grid();
+        let thin = terrain_wall_segment(Vec2::new(1000.0, 1000.0), Vec2::X, 1200.0, 10.0);
+        grid.terrain_cells.extend(rasterize_polygon(&grid, &thin));
+
+        let start = Vec2::new(800.0, 1000.0);
+        let across = Vec2::new(1200.0, 1000.0);
+        assert_eq!(blink_landing(&grid, start, across), across);
+
+        let thick = terrain_wall_segment(Vec2::new(1300.0, 500.0), Vec2::X, 400.0, 400.0);
+        grid.terrain_cells.extend(rasterize_polygon(&grid, &thick));
+        let landing = blink_landing(&grid, Vec2::new(900.0, 500.0), Vec2::new(1300.0, 500.0));
+        assert!(landing.x < 1100.0, "落点应退到厚墙之前，实际 {landing}");
+        assert!(landing.x > 900.0);
+    }
+
+    #[test]
+    fn wall_expires_and_clears_overlay() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AssetPlugin::default());
+        app.init_asset::<ConfigNavigationGrid>();
+        app.add_plugins(PluginTerrain);
+        app.insert_resource(Time::<Fixed>::from_hz(30.0));
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<ConfigNavigationGrid>>()
+            .add(make_grid());
+        app.insert_resource(ResourceGrid(handle.clone()));
+
+        let caster = app.world_mut().spawn_empty().id();
+        app.world_mut().trigger(CommandTerrainWallCreate {
+            entity: caster,
+            polygon: terrain_wall_segment(Vec2::splat(500.0), Vec2::X, 400.0, 50.0),
+            duration: 1.0,
+        });
+        app.update();
+
+        let blocked = |app: &App| {
+            let grid = app
+                .world()
+                .resource::<Assets<ConfigNavigationGrid>>()
+                .get(&handle)
+                .unwrap();
+            !grid.is_walkable_by_position(&Vec2::splat(500.0))
+        };
+        assert!(blocked(&app));
+
+        for _ in 0..35 {
+            app.update();
+        }
+        assert!(!blocked(&app), "墙体到期后覆盖层应清空");
+        assert!(
+            app.world_mut()
+                .query::<&TerrainWall>()
+                .iter(app.world())
+                .next()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn units_inside_new_wall_are_pushed_out() {
+        let mut grid = make_grid();
+        let wall = terrain_wall_segment(Vec2::new(1000.0, 1000.0), Vec2::X, 600.0, 150.0);
+        grid.terrain_cells.extend(rasterize_polygon(&grid, &wall));
+
+        let inside = Vec2::new(1010.0, 1000.0);
+        let escape = terrain_escape_point(&grid, inside).expect("墙内应有脱困点");
+        assert!(grid.is_walkable_by_position(&escape));
+        assert!(
+            escape.distance(inside) < 200.0,
+            "应挤到最近的墙外，实际 {escape}"
+        );
+        assert!(terrain_escape_point(&grid, Vec2::new(500.0, 500.0)).is_none());
+    }
+}
@@ -0,0 +1,367 @@
+use bevy::prelude::*;
+
+use crate::action::{Action, CommandAction};
+use crate::attack_auto::CommandAttackAutoStop;
+use crate::base::buff::{Buff, BuffOf, Buffs};
+use crate::buffs::cc_debuffs::ForcedAction;
+use crate::damage::EventDamageCreate;
+use crate::entities::champion::Champion;
+use crate::entities::epic::BuffHandOfBaron;
+use crate::fountain::{Fountain, Fountains};
+use crate::life::Death;
+use crate::movement::{CastBlock, CommandMovement, MovementAction, MovementState};
+use crate::run::Run;
+use crate::team::Team;
+
+/// 回城引导时长（秒）
+pub const RECALL_CHANNEL_SECS: f32 = 8.0;
+/// 持有男爵之手时的强化回城引导时长（秒）
+pub const RECALL_EMPOWERED_CHANNEL_SECS: f32 = 4.0;
+
+#[derive(Default)]
+pub struct PluginRecall;
+
+impl Plugin for PluginRecall {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_command_recall);
+        app.add_observer(on_action_cancel_recall);
+        app.add_observer(on_damage_cancel_recall);
+        app.add_observer(on_cast_block_cancel_recall);
+        app.add_observer(on_forced_action_cancel_recall);
+        app.add_systems(FixedUpdate, update_recall_channel);
+    }
+}
+
+/// 回城命令：开始引导，引导完成后传送回己方泉水。
+#[derive(EntityEvent, Debug)]
+pub struct CommandRecall {
+    pub entity: Entity,
+}
+
+/// 回城结束：`completed` 为 false 表示被打断。
+#[derive(EntityEvent, Debug)]
+pub struct EventRecallEnd {
+    pub entity: Entity,
+    pub completed: bool,
+}
+
+/// 回城引导：受到伤害、被硬控或下达其它指令都会打断。
+#[derive(Component, Debug, Clone)]
+#[require(Buff = Buff { name: "Recall" })]
+pub struct BuffRecallChannel {
+    pub timer: Timer,
+}
+
+/// 实体身上进行中的回城引导 buff。
+pub fn recall_channel(
+    buffs: Option<&Buffs>,
+    q_recall: &Query<&BuffRecallChannel>,
+) -> Option<Entity> {
+    buffs?.iter().find(|buff| q_recall.contains(*buff))
+}
+
+fn on_command_recall(
+    trigger: On<CommandRecall>,
+    mut commands: Commands,
+    q_caster: Query<Option<&Buffs>, (With<Champion>, Without<Death>)>,
+    q_blocked: Query<(), Or<(With<CastBlock>, With<ForcedAction>)>>,
+    q_recall: Query<&BuffRecallChannel>,
+    q_baron: Query<(), With<BuffHandOfBaron>>,
+) {
+    let entity = trigger.event_target();
+    let Ok(buffs) = q_caster.get(entity) else {
+        return;
+    };
+    if q_blocked.contains(entity) || recall_channel(buffs, &q_recall).is_some() {
+        return;
+    }
+
+    let empowered = buffs.is_some_and(|buffs| buffs.iter().any(|buff| q_baron.contains(buff)));
+    let duration = if empowered {
+        RECALL_EMPOWERED_CHANNEL_SECS
+    } else {
+        RECALL_CHANNEL_SECS
+    };
+
+    commands.trigger(CommandAttackAutoStop { entity });
+    commands.trigger(CommandMovement {
+        entity,
+        priority: 0,
+        action: MovementAction::Stop,
+    });
+    commands
+        .entity(entity)
+        .with_related::<BuffOf>(BuffRecallChannel {
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+        });
+    debug!("{:?} 开始回城，引导 {:.1} 秒", entity, duration);
+}
+
+fn cancel_recall(
+    commands: &mut Commands,
+    entity: Entity,
+    buffs: Option<&Buffs>,
+    q_recall: &Query<&BuffRecallChannel>,
+    reason: &str,
+) {
+    let Some(buff) = recall_channel(buffs, q_recall) else {
+        return;
+    };
+    commands.entity(buff).despawn();
+    commands.trigger(EventRecallEnd {
+        entity,
+        completed: false,
+    });
+    debug!("{:?} 回城被打断：{}", entity, reason);
+}
+
+/// 除回城与加点外的任意指令（移动、攻击、施法、停止）都会取消回城。
+fn on_action_cancel_recall(
+    trigger: On<CommandAction>,
+    mut commands: Commands,
+    q_buffs: Query<&Buffs>,
+    q_recall: Query<&BuffRecallChannel>,
+) {
+    if matches!(trigger.action, Action::Recall | Action::SkillLevelUp(_)) {
+        return;
+    }
+    let entity = trigger.event_target();
+    cancel_recall(
+        &mut commands,
+        entity,
+        q_buffs.get(entity).ok(),
+        &q_recall,
+        "指令",
+    );
+}
+
+fn on_damage_cancel_recall(
+    trigger: On<EventDamageCreate>,
+    mut commands: Commands,
+    q_buffs: Query<&Buffs>,
+    q_recall: Query<&BuffRecallChannel>,
+) {
+    if trigger.damage_result.final_damage <= 0.0 {
+        return;
+    }
+    let entity = trigger.event_target();
+    cancel_recall(
+        &mut commands,
+        entity,
+        q_buffs.get(entity).ok(),
+        &q_recall,
+        "受到伤害",
+    );
+}
+
+fn on_cast_block_cancel_recall(
+    trigger: On<Add, CastBlock>,
+    mut commands: Commands,
+    q_buffs: Query<&Buffs>,
+    q_recall: Query<&BuffRecallChannel>,
+) {
+    cancel_recall(
+        &mut commands,
+        trigger.entity,
+        q_buffs.get(trigger.entity).ok(),
+        &q_recall,
+        "CastBlock",
+    );
+}
+
+fn on_forced_action_cancel_recall(
+    trigger: On<Add, ForcedAction>,
+    mut commands: Commands,
+    q_buffs: Query<&Buffs>,
+    q_recall: Query<&BuffRecallChannel>,
+) {
+    cancel_recall(
+        &mut commands,
+        trigger.entity,
+        q_buffs.get(trigger.entity).ok(),
+        &q_recall,
+        "ForcedAction",
+    );
+}
+
+/// 推进回城引导：施法者死亡则中止，引导完成传送回己方泉水。
+fn update_recall_channel(
+    mut commands: Commands,
+    mut q_channel: Query<(Entity, &BuffOf, &mut BuffRecallChannel)>,
+    mut q_caster: Query<(&Team, &mut Transform, Option<&mut MovementState>), Without<Fountain>>,
+    q_death: Query<(), With<Death>>,
+    fountains: Fountains,
+    time: Res<Time<Fixed>>,
+) {
+    for (buff_entity, buff_of, mut channel) in q_channel.iter_mut() {
+        let caster = buff_of.0;
+        if q_death.contains(caster) {
+            commands.entity(buff_entity).despawn();
+            commands.trigger(EventRecallEnd {
+                entity: caster,
+                completed: false,
+            });
+            continue;
+        }
+
+        channel.timer.tick(time.delta());
+        if !channel.timer.is_finished() {
+            continue;
+        }
+        commands.entity(buff_entity).despawn();
+
+        let Ok((team, mut transform, movement_state)) = q_caster.get_mut(caster) else {
+            continue;
+        };
+        let Some(destination) = fountains.position(*team) else {
+            continue;
+        };
+        transform.translation = destination;
+        if let Some(mut movement_state) = movement_state {
+            movement_state.clear_path();
+        }
+        commands.entity(caster).remove::<Run>();
+        commands.trigger(EventRecallEnd {
+            entity: caster,
+            completed: true,
+        });
+        debug!("{:?} 回城完成", caster);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::damage::{DamageKind, DamageResult, DamageType};
+    use crate::fountain::fountain_position;
+    use crate::life::Health;
+
+    fn app_with_recall() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(PluginRecall);
+        app.insert_resource(Time::<Fixed>::from_hz(30.0));
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+        app
+    }
+
+    fn step(app: &mut App, frames: usize) {
+        for _ in 0..frames {
+            app.update();
+        }
+    }
+
+    fn spawn_champion(app: &mut App) -> Entity {
+        app.world_mut()
+            .spawn((
+                Champion,
+                Team::Order,
+                Transform::from_xyz(5000.0, 0.0, 5000.0),
+                Health::new(1000.0),
+            ))
+            .id()
+    }
+
+    fn recalling(app: &App, entity: Entity) -> bool {
+        app.world().get::<Buffs>(entity).is_some_and(|buffs| {
+            buffs
+                .iter()
+                .any(|buff| app.world().get::<BuffRecallChannel>(buff).is_some())
+        })
+    }
+
+    #[test]
+    fn recall_teleports_to_fountain_after_channel() {
+        let mut app = app_with_recall();
+        let champion = spawn_champion(&mut app);
+
+        app.world_mut().trigger(CommandRecall { entity: champion });
+        step(&mut app, 2);
+        assert!(recalling(&app, champion));
+
+        step(&mut app, (RECALL_CHANNEL_SECS * 30.0) as usize + 2);
+        assert!(!recalling(&app, champion));
+        assert_eq!(
+            app.world().get::<Transform>(champion).unwrap().translation,
+            fountain_position(Team::Order).unwrap()
+        );
+    }
+
+    #[test]
+    fn recall_lands_on_placed_fountain() {
+        let mut app = app_with_recall();
+        let placed = Vec3::new(2000.0, 100.0, 1500.0);
+        app.world_mut()
+            .spawn((Fountain, Team::Order, Transform::from_translation(placed)));
+        let champion = spawn_champion(&mut app);
+
+        app.world_mut().trigger(CommandRecall { entity: champion });
+        step(&mut app, (RECALL_CHANNEL_SECS * 30.0) as usize + 4);
+        assert_eq!(
+            app.world().get::<Transform>(champion).unwrap().translation,
+            placed
+        );
+    }
+
+    #[test]
+    fn damage_and_actions_interrupt_recall() {
+        let mut app = app_with_recall();
+        let champion = spawn_champion(&mut app);
+
+        app.world_mut().trigger(CommandRecall { entity: champion });
+        step(&mut app, 2);
+        app.world_mut().trigger(EventDamageCreate {
+            entity: champion,
+            source: Entity::PLACEHOLDER,
+            damage_type: DamageType::Physical,
+            damage_result: DamageResult {
+                final_damage: 10.0,
+                white_shield_absorbed: 0.0,
+                magic_shield_absorbed: 0.0,
+                reduced_damage: 0.0,
+                armor_reduced_damage: 0.0,
+                original_damage: 10.0,
+            },
+            tag: None,
+            kind: DamageKind::Other,
+        });
+        step(&mut app, 1);
+        assert!(!recalling(&app, champion), "受到伤害应打断回城");
+
+        app.world_mut().trigger(CommandRecall { entity: champion });
+        step(&mut app, 2);
+        app.world_mut().trigger(CommandAction {
+            entity: champion,
+            action: Action::Move(Vec2::ZERO),
+        });
+        step(&mut app, 1);
+        assert!(!recalling(&app, champion), "移动指令应取消回城");
+        assert_eq!(
+            app.world().get::<Transform>(champion).unwrap().translation,
+            Vec3::new(5000.0, 0.0, 5000.0)
+        );
+    }
+
+    #[test]
+    fn hand_of_baron_empowers_recall() {
+        let mut app = app_with_recall();
+        let champion = spawn_champion(&mut app);
+        app.world_mut()
+            .entity_mut(champion)
+            .with_related::<BuffOf>(BuffHandOfBaron {
+                timer: Timer::from_seconds(180.0, TimerMode::Once),
+            });
+
+        app.world_mut().trigger(CommandRecall { entity: champion });
+        step(
+            &mut app,
+            (RECALL_EMPOWERED_CHANNEL_SECS * 30.0) as usize + 3,
+        );
+        assert_eq!(
+            app.world().get::<Transform>(champion).unwrap().translation,
+            fountain_position(Team::Order).unwrap()
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+use crate::avoidance::SurroundSlot;
 use crate::life::Death;
 use crate::log::{CommandLog, EnumLogCategory};
 use crate::movement::{
@@ -75,10 +76,10 @@ fn on_command_run_stop(trigger: On<CommandRunStop>, mut commands: Commands) {
 
 fn fixed_update(
     mut commands: Commands,
-    q: Query<(Entity, &Run), Without<Death>>,
+    q: Query<(Entity, &Run, Option<&SurroundSlot>), Without<Death>>,
     q_transform: Query<&Transform>,
 ) {
-    for (entity, run) in q.iter() {
+    for (entity, run, slot) in q.iter() {
         match run.target {
             RunTarget::Position(position) => {
                 let Ok(transform) = q_transform.get(entity) else {
@@ -108,17 +109,24 @@ fn fixed_update(
                 let Ok(transform) = q_transform.get(target) else {
                     continue;
                 };
+                // 有包围槽位时绕到槽位，避免多个追击者挤在同一侧
+                let destination = match slot {
+                    Some(slot) if slot.target == target => {
+                        Vec3::new(slot.position.x, transform.translation.y, slot.position.y)
+                    }
+                    _ => transform.translation,
+                };
 
                 commands.trigger(CommandLog {
                     entity,
-                    info: format!("寻路到实体 {:?} Vec3({})", target, transform.translation),
+                    info: format!("寻路到实体 {:?} Vec3({})", target, destination),
                     category: EnumLogCategory::Run,
                 });
                 commands.trigger(CommandMovement {
                     entity,
                     priority: 0,
                     action: MovementAction::Start {
-                        way: MovementWay::Pathfind(transform.translation),
+                        way: MovementWay::Pathfind(destination),
                         speed: None,
                         source: MovementSource::Run,
                     },
@@ -1,4 +1,4 @@
-use bevy::prelude::Reflect;
+use bevy::prelude::{Component, Entity, Reflect, ReflectComponent, Vec2};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Default, Serialize, Deserialize)]
@@ -19,6 +19,54 @@ pub enum SkillCooldownMode {
     Manual,
 }
 
+/// 一次施法指定的目标。
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+pub enum SkillTarget {
+    /// 地面坐标（指向性 / 范围技能）
+    Point(Vec2),
+    /// 指定单位（点选技能，如安妮 Q、马尔扎哈 R）
+    Unit(Entity),
+    /// 对自身释放
+    SelfCast,
+    /// 两点向量施法（如维克托 E、兰博 R）：从 `start` 朝 `end` 展开
+    Vector { start: Vec2, end: Vec2 },
+}
+
+impl SkillTarget {
+    /// 不依赖世界状态即可确定的施法坐标（单位 / 自身目标需查询位置，返回 None）。
+    pub fn point(&self) -> Option<Vec2> {
+        match self {
+            SkillTarget::Point(point) => Some(*point),
+            SkillTarget::Vector { start, .. } => Some(*start),
+            SkillTarget::Unit(_) | SkillTarget::SelfCast => None,
+        }
+    }
+}
+
+/// 点选技能可选的目标阵营。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Default, Serialize, Deserialize)]
+pub enum SkillTargetFilter {
+    #[default]
+    Enemy,
+    Ally,
+    Any,
+}
+
+/// 技能接受的施法方式，挂在技能实体上（缺省视为 [`SkillTargeting::Point`]）。
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect, Default, Serialize, Deserialize)]
+#[reflect(Component)]
+pub enum SkillTargeting {
+    /// 对地施法：接受任意目标，单位 / 自身 / 向量目标折算为坐标，不校验施法距离
+    #[default]
+    Point,
+    /// 点选单位：只接受符合阵营过滤、存活且在施法距离内的单位
+    Unit(SkillTargetFilter),
+    /// 自身施法：忽略目标，施法点为施法者位置
+    SelfCast,
+    /// 向量施法：起点须在施法距离内，向量长度不超过 `max_length`
+    Vector { max_length: f32 },
+}
+
 pub fn skill_slot_from_index(index: usize) -> SkillSlot {
     match index {
         0 => SkillSlot::Q,
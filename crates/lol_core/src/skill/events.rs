@@ -1,6 +1,6 @@
 use bevy::prelude::{Entity, EntityEvent, Resource, Vec2};
 
-use super::enums::SkillSlot;
+use super::enums::{SkillSlot, SkillTarget};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SkillCastFailureReason {
@@ -14,6 +14,10 @@ pub enum SkillCastFailureReason {
     CoolingDown,
     Blocked,
     CasterDead,
+    /// 目标不符合技能的施法方式或阵营过滤（如点选技能缺少目标、目标已死亡）
+    InvalidTarget,
+    /// 目标或向量起点超出施法距离
+    OutOfRange,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -29,6 +33,7 @@ pub struct SkillCastRecord {
     pub index: usize,
     pub slot: Option<SkillSlot>,
     pub point: Vec2,
+    pub target: SkillTarget,
     pub result: SkillCastResult,
 }
 
@@ -42,11 +47,25 @@ impl SkillCastLog {
     }
 }
 
+/// 施法命令。`point` 为对地坐标，`target` 为完整目标；二者由 [`CommandSkillStart::new`] 保持一致，
+/// 校验后 [`EventSkillCast`] 的 `point` 会按目标重新折算（单位 → 单位位置，自身 → 施法者位置，向量 → 起点）。
 #[derive(EntityEvent)]
 pub struct CommandSkillStart {
     pub entity: Entity,
     pub index: usize,
     pub point: Vec2,
+    pub target: SkillTarget,
+}
+
+impl CommandSkillStart {
+    pub fn new(entity: Entity, index: usize, target: SkillTarget) -> Self {
+        Self {
+            entity,
+            index,
+            point: target.point().unwrap_or_default(),
+            target,
+        }
+    }
 }
 
 #[derive(EntityEvent)]
@@ -54,6 +73,7 @@ pub struct CommandSkillBeforeStart {
     pub entity: Entity,
     pub index: usize,
     pub point: Vec2,
+    pub target: SkillTarget,
 }
 
 #[derive(EntityEvent, Debug, Clone, Copy)]
@@ -62,6 +82,7 @@ pub struct EventSkillCast {
     pub skill_entity: Entity,
     pub index: usize,
     pub point: Vec2,
+    pub target: SkillTarget,
 }
 
 #[derive(EntityEvent)]
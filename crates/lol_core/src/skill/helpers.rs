@@ -1,11 +1,26 @@
 use lol_base::spell::Spell;
 use lol_base::spell_calc::{
-    CalculationPart, CalculationPartEffectValue, CalculationPartNamedDataValue,
+    CalculationPart, CalculationPartBuffCounterCoefficient,
+    CalculationPartBuffCounterNamedDataValue, CalculationPartByCharLevelBreakpoints,
+    CalculationPartByCharLevelFormula, CalculationPartByCharLevelInterpolation,
+    CalculationPartClamp, CalculationPartEffectValue, CalculationPartExponent,
+    CalculationPartNamedDataValue, CalculationPartNumber, CalculationPartProduct,
     CalculationPartStatCoefficient, CalculationPartStatNamedDataValue, CalculationPartStatSub,
-    CalculationType,
+    CalculationPartSum, CalculationRequirement, CalculationType,
 };
 
-use super::{CoolDown, SkillRecastWindow};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::{Entity, Query, Vec2};
+
+use super::{
+    CoolDown, SkillCastFailureReason, SkillRecastWindow, SkillTarget, SkillTargetFilter,
+    SkillTargeting,
+};
+use crate::attack::Attack;
+use crate::base::buff::{Buff, Buffs};
+use crate::base::level::Level;
+use crate::life::Health;
+use crate::team::Team;
 
 /// 技能是否处于"可施放/就绪"状态，供 UI 显示与施法前置判断共用同一语义。
 ///
@@ -21,29 +36,363 @@ pub fn is_skill_ready(cooldown: &CoolDown, recast: Option<&SkillRecastWindow>) -
     cooldown.timer.as_ref().map_or(true, |t| t.is_finished())
 }
 
+/// 公式求值时施法者的状态：英雄等级、生命比例、远程与否，以及身上的 buff 名（同名多个按层数计）。
+#[derive(Debug, Clone)]
+pub struct SkillCalcCaster {
+    pub char_level: u32,
+    pub health_percent: f32,
+    pub ranged: bool,
+    pub buffs: Vec<String>,
+}
+
+impl Default for SkillCalcCaster {
+    fn default() -> Self {
+        Self {
+            char_level: 1,
+            health_percent: 1.0,
+            ranged: false,
+            buffs: Vec::new(),
+        }
+    }
+}
+
+impl SkillCalcCaster {
+    /// 同名 buff 的层数；名称匹配忽略大小写与下划线（提取数据为 snake_case）
+    pub fn buff_count(&self, name: &str) -> u32 {
+        let normalize = |name: &str| name.to_lowercase().replace('_', "");
+        let target = normalize(name);
+        self.buffs
+            .iter()
+            .filter(|buff| normalize(buff) == target)
+            .count() as u32
+    }
+}
+
+/// 普攻距离达到该值视为远程
+const RANGED_ATTACK_RANGE: f32 = 300.0;
+
+/// 从 ECS 读取 [`SkillCalcCaster`] 所需的查询集合。
+#[derive(SystemParam)]
+pub struct SkillCalcQuery<'w, 's> {
+    q_caster: Query<
+        'w,
+        's,
+        (
+            Option<&'static Level>,
+            Option<&'static Health>,
+            Option<&'static Attack>,
+            Option<&'static Buffs>,
+        ),
+    >,
+    q_buff: Query<'w, 's, &'static Buff>,
+}
+
+impl SkillCalcQuery<'_, '_> {
+    /// 施法者当前状态快照；实体不存在时返回默认值
+    pub fn caster(&self, entity: Entity) -> SkillCalcCaster {
+        let Ok((level, health, attack, buffs)) = self.q_caster.get(entity) else {
+            return SkillCalcCaster::default();
+        };
+        SkillCalcCaster {
+            char_level: level.map_or(1, |l| l.value),
+            health_percent: health
+                .filter(|h| h.max > 0.0)
+                .map_or(1.0, |h| h.value / h.max),
+            ranged: attack.is_some_and(|a| a.range >= RANGED_ATTACK_RANGE),
+            buffs: buffs
+                .map(|buffs| {
+                    self.q_buff
+                        .iter_many(buffs.iter())
+                        .map(|buff| buff.name.to_string())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// 条件 / 修饰计算互相引用的最大深度，防止数据成环
+const MAX_CALCULATION_DEPTH: u32 = 8;
+
+/// 按技能等级求值计算公式；计算缺失、引用成环或含不支持的部件 / 条件时返回 None，
+/// 调用方应跳过这段效果并记录日志，而不是按 0 结算。
 pub fn get_skill_value(
     skill_object: &Spell,
     name: &str,
     level: usize,
     get_stat: impl Fn(u8) -> f32,
 ) -> Option<f32> {
-    let spell = skill_object.spell_data.as_ref()?;
-    let calculations = spell.calculations.as_ref()?;
-    let calculation = calculations.get(name)?;
+    get_skill_value_with(
+        skill_object,
+        name,
+        level,
+        &SkillCalcCaster::default(),
+        get_stat,
+    )
+}
 
-    let CalculationType::CalculationSpell(calc) = calculation;
-    let mut value = 0.0;
+/// 同 [`get_skill_value`]，但按施法者状态求值英雄等级、buff 层数与条件计算。
+pub fn get_skill_value_with(
+    skill_object: &Spell,
+    name: &str,
+    level: usize,
+    caster: &SkillCalcCaster,
+    get_stat: impl Fn(u8) -> f32,
+) -> Option<f32> {
+    let ctx = CalcContext {
+        skill_object,
+        caster,
+        get_stat: &get_stat,
+    };
+    ctx.calculation(name, level, 0)
+}
+
+struct CalcContext<'a> {
+    skill_object: &'a Spell,
+    caster: &'a SkillCalcCaster,
+    get_stat: &'a dyn Fn(u8) -> f32,
+}
+
+impl CalcContext<'_> {
+    fn calculation(&self, name: &str, level: usize, depth: u32) -> Option<f32> {
+        if depth > MAX_CALCULATION_DEPTH {
+            return None;
+        }
+        let spell = self.skill_object.spell_data.as_ref()?;
+        let calculations = spell.calculations.as_ref()?;
 
-    if let Some(parts) = &calc.formula_parts {
-        for part in parts {
-            value += calculate_part(part, skill_object, level, &get_stat);
+        match calculations.get(name)? {
+            CalculationType::CalculationSpell(calc) => {
+                let mut value = 0.0;
+                if let Some(parts) = &calc.formula_parts {
+                    for part in parts {
+                        value += self.part(part, level)?;
+                    }
+                }
+                if let Some(multiplier) = &calc.multiplier {
+                    value *= self.part(multiplier, level)?;
+                }
+                Some(value)
+            }
+            CalculationType::CalculationConditional(calc) => {
+                if self.requirement(&calc.requirement, level)? {
+                    self.calculation(&calc.conditional, level, depth + 1)
+                } else {
+                    match &calc.default {
+                        Some(default) => self.calculation(default, level, depth + 1),
+                        None => Some(0.0),
+                    }
+                }
+            }
+            CalculationType::CalculationModified(calc) => {
+                let inner_level = calc
+                    .override_spell_level
+                    .filter(|l| *l > 0)
+                    .map_or(level, |l| l as usize);
+                let value = self.calculation(&calc.calculation, inner_level, depth + 1)?;
+                Some(value * self.part(&calc.multiplier, level)?)
+            }
         }
     }
 
-    if let Some(multiplier) = &calc.multiplier {
-        value *= calculate_part(multiplier, skill_object, level, &get_stat);
+    /// 条件求值；含不支持的条件时返回 None，由调用方放弃整条计算
+    fn requirement(&self, requirement: &CalculationRequirement, level: usize) -> Option<bool> {
+        let met = match requirement {
+            CalculationRequirement::AboveHealthPercent {
+                percent,
+                data_value,
+                invert,
+            } => {
+                let threshold = percent
+                    .or_else(|| {
+                        data_value
+                            .as_ref()
+                            .and_then(|name| get_named_data_value(self.skill_object, name, level))
+                    })
+                    .unwrap_or(0.0);
+                // 数据里既有 0.5 也有 50 两种写法
+                let threshold = if threshold > 1.0 {
+                    threshold / 100.0
+                } else {
+                    threshold
+                };
+                (self.caster.health_percent > threshold) != *invert
+            }
+            CalculationRequirement::HasBuff { buff_name, invert } => {
+                let has = buff_name
+                    .as_ref()
+                    .is_some_and(|name| self.caster.buff_count(name) > 0);
+                has != *invert
+            }
+            CalculationRequirement::SpellLevelAtLeast { level: min } => level as u32 >= *min,
+            CalculationRequirement::IsRanged => self.caster.ranged,
+            CalculationRequirement::All { requirements } => {
+                for requirement in requirements {
+                    if !self.requirement(requirement, level)? {
+                        return Some(false);
+                    }
+                }
+                true
+            }
+            CalculationRequirement::AtLeast {
+                requirements,
+                count,
+            } => {
+                let mut met = 0;
+                for requirement in requirements {
+                    if self.requirement(requirement, level)? {
+                        met += 1;
+                    }
+                }
+                met >= *count
+            }
+            CalculationRequirement::Unsupported { .. } => return None,
+        };
+        Some(met)
+    }
+
+    /// 部件求值；含不支持的部件时返回 None，避免按 0 参与乘积得出错误数值
+    fn part(&self, part: &CalculationPart, level: usize) -> Option<f32> {
+        let skill_object = self.skill_object;
+        let get_stat = self.get_stat;
+        let char_level = self.caster.char_level.max(1);
+
+        let value = match part {
+            CalculationPart::CalculationPartEffectValue(CalculationPartEffectValue {
+                effect_index,
+            }) => get_effect_value(skill_object, *effect_index, level).unwrap_or(0.0),
+            CalculationPart::CalculationPartStatCoefficient(CalculationPartStatCoefficient {
+                stat,
+                coefficient,
+                ..
+            }) => {
+                let stat = stat.unwrap_or(0);
+                let coefficient = coefficient.unwrap_or(0.0);
+                get_stat(stat) * coefficient
+            }
+            CalculationPart::CalculationPartNamedDataValue(CalculationPartNamedDataValue {
+                data_value,
+            }) => get_named_data_value(skill_object, data_value, level).unwrap_or(0.0),
+            CalculationPart::CalculationPartStatSub(CalculationPartStatSub {
+                stat,
+                subpart,
+                ..
+            }) => {
+                let stat_val = stat.unwrap_or(0);
+                let sub_val = match subpart {
+                    Some(sub) => self.part(sub, level)?,
+                    None => 0.0,
+                };
+                get_stat(stat_val) * sub_val
+            }
+            CalculationPart::CalculationPartStatNamedDataValue(
+                CalculationPartStatNamedDataValue {
+                    stat, data_value, ..
+                },
+            ) => {
+                let stat = stat.unwrap_or(0);
+                let val = get_named_data_value(skill_object, data_value, level).unwrap_or(0.0);
+                get_stat(stat) * val
+            }
+            CalculationPart::CalculationPartNumber(CalculationPartNumber { number }) => {
+                number.unwrap_or(0.0)
+            }
+            CalculationPart::CalculationPartByCharLevelBreakpoints(part) => {
+                by_char_level_breakpoints(part, char_level)
+            }
+            CalculationPart::CalculationPartByCharLevelInterpolation(part) => {
+                by_char_level_interpolation(part, char_level)
+            }
+            CalculationPart::CalculationPartByCharLevelFormula(
+                CalculationPartByCharLevelFormula { values },
+            ) => values
+                .get(char_level as usize - 1)
+                .or(values.last())
+                .copied()
+                .unwrap_or(0.0),
+            CalculationPart::CalculationPartBuffCounterCoefficient(
+                CalculationPartBuffCounterCoefficient {
+                    buff_name,
+                    coefficient,
+                },
+            ) => self.caster.buff_count(buff_name) as f32 * coefficient,
+            CalculationPart::CalculationPartBuffCounterNamedDataValue(
+                CalculationPartBuffCounterNamedDataValue {
+                    buff_name,
+                    data_value,
+                },
+            ) => {
+                let val = get_named_data_value(skill_object, data_value, level).unwrap_or(0.0);
+                self.caster.buff_count(buff_name) as f32 * val
+            }
+            CalculationPart::CalculationPartClamp(CalculationPartClamp {
+                floor,
+                ceiling,
+                subparts,
+            }) => {
+                let mut value = self.sum(subparts, level)?;
+                if let Some(floor) = floor {
+                    value = value.max(*floor);
+                }
+                if let Some(ceiling) = ceiling {
+                    value = value.min(*ceiling);
+                }
+                value
+            }
+            CalculationPart::CalculationPartSum(CalculationPartSum { subparts }) => {
+                self.sum(subparts, level)?
+            }
+            CalculationPart::CalculationPartProduct(CalculationPartProduct { part1, part2 }) => {
+                self.part(part1, level)? * self.part(part2, level)?
+            }
+            CalculationPart::CalculationPartExponent(CalculationPartExponent { part1, part2 }) => {
+                self.part(part1, level)?.powf(self.part(part2, level)?)
+            }
+            CalculationPart::CalculationPartUnsupported(_) => return None,
+        };
+        Some(value)
+    }
+
+    fn sum(&self, parts: &[CalculationPart], level: usize) -> Option<f32> {
+        parts.iter().map(|p| self.part(p, level)).sum()
+    }
+}
+
+/// 英雄等级断点：逐级累加，断点等级起改用新的每级加成
+fn by_char_level_breakpoints(part: &CalculationPartByCharLevelBreakpoints, char_level: u32) -> f32 {
+    let mut value = part.level1_value.unwrap_or(0.0);
+    let mut per_level = part.initial_bonus_per_level.unwrap_or(0.0);
+    for level in 2..=char_level {
+        if let Some(breakpoint) = part.breakpoints.iter().find(|b| b.level == Some(level)) {
+            value += breakpoint.additional_bonus_at_this_level.unwrap_or(0.0);
+            if let Some(bonus) = breakpoint.bonus_per_level_at_and_after {
+                per_level = bonus;
+            }
+        }
+        value += per_level;
     }
-    Some(value)
+    value
+}
+
+/// 英雄等级插值：属性成长曲线 `(n-1)(0.7025+0.0175(n-1))` 在 18 级恰为 17，与线性插值同端点
+fn by_char_level_interpolation(
+    part: &CalculationPartByCharLevelInterpolation,
+    char_level: u32,
+) -> f32 {
+    let start = part.start_value.unwrap_or(0.0);
+    let end = part.end_value.unwrap_or(0.0);
+    let level = if part.scale_past_default_max_level.unwrap_or(false) {
+        char_level
+    } else {
+        char_level.min(18)
+    };
+    let n = (level - 1) as f32;
+    let progress = if part.scale_by_stat_progression.unwrap_or(false) {
+        n * (0.7025 + 0.0175 * n) / 17.0
+    } else {
+        n / 17.0
+    };
+    start + (end - start) * progress
 }
 
 /// 读取技能的原始 `dataValues`（按名称），不受 `calculations` 公式约束。
@@ -76,7 +425,107 @@ pub fn get_skill_cast_radius(skill_object: &Spell, level: usize) -> Option<f32>
     Some(*radii.get(lvl_idx).unwrap_or(&0.0))
 }
 
-fn get_named_data_value(skill_object: &Spell, target_name: &str, level: usize) -> Option<f32> {
+/// 读取技能资产的 `castRange`（按等级），即点选 / 向量施法的最大施法距离。
+pub fn get_skill_cast_range(skill_object: &Spell, level: usize) -> Option<f32> {
+    let spell_data = skill_object.spell_data.as_ref()?;
+    let ranges = spell_data.cast_range.as_ref()?;
+    let lvl_idx = if level > 0 { level - 1 } else { 0 };
+    ranges.get(lvl_idx).or(ranges.last()).copied()
+}
+
+/// 施法校验时目标单位的状态。
+#[derive(Debug, Clone, Copy)]
+pub struct SkillTargetInfo {
+    pub position: Vec2,
+    pub team: Option<Team>,
+    pub dead: bool,
+    /// 不可选取（[`crate::damage::Untargetable`]），只有自身能以其为目标
+    pub untargetable: bool,
+}
+
+/// 按技能的施法方式校验并规范化施法目标，返回规范化后的目标与施法坐标。
+///
+/// - `Point`：接受任意目标，折算为坐标，不校验距离（保持对地技能原有语义）
+/// - `Unit`：必须是存活、可选取、符合阵营过滤、在施法距离内的单位
+/// - `SelfCast`：忽略目标，施法坐标为施法者位置
+/// - `Vector`：起点须在施法距离内，终点截断到 `max_length`；对地坐标视为从该点沿施法方向展开
+///
+/// `cast_range` 为 None 或非正数时不校验距离。
+pub fn resolve_skill_target(
+    targeting: SkillTargeting,
+    target: SkillTarget,
+    caster: Entity,
+    caster_pos: Vec2,
+    caster_team: Option<Team>,
+    cast_range: Option<f32>,
+    lookup: impl Fn(Entity) -> Option<SkillTargetInfo>,
+) -> Result<(SkillTarget, Vec2), SkillCastFailureReason> {
+    let in_range = |pos: Vec2| {
+        cast_range
+            .filter(|range| *range > 0.0)
+            .is_none_or(|range| pos.distance(caster_pos) <= range)
+    };
+
+    match targeting {
+        SkillTargeting::SelfCast => Ok((SkillTarget::SelfCast, caster_pos)),
+        SkillTargeting::Point => {
+            let point = match target {
+                SkillTarget::Point(point) => point,
+                SkillTarget::Vector { start, .. } => start,
+                SkillTarget::SelfCast => caster_pos,
+                SkillTarget::Unit(entity) => {
+                    lookup(entity)
+                        .ok_or(SkillCastFailureReason::InvalidTarget)?
+                        .position
+                }
+            };
+            Ok((target, point))
+        }
+        SkillTargeting::Unit(filter) => {
+            let SkillTarget::Unit(entity) = target else {
+                return Err(SkillCastFailureReason::InvalidTarget);
+            };
+            let info = lookup(entity).ok_or(SkillCastFailureReason::InvalidTarget)?;
+            let allied = info.team.is_some() && info.team == caster_team;
+            let team_ok = match filter {
+                SkillTargetFilter::Enemy => entity != caster && !allied,
+                SkillTargetFilter::Ally => allied,
+                SkillTargetFilter::Any => true,
+            };
+            let untargetable = info.untargetable && entity != caster;
+            if info.dead || untargetable || !team_ok {
+                return Err(SkillCastFailureReason::InvalidTarget);
+            }
+            if !in_range(info.position) {
+                return Err(SkillCastFailureReason::OutOfRange);
+            }
+            Ok((target, info.position))
+        }
+        SkillTargeting::Vector { max_length } => {
+            let (start, end) = match target {
+                SkillTarget::Vector { start, end } => (start, end),
+                SkillTarget::Point(point) => {
+                    let dir = (point - caster_pos).normalize_or_zero();
+                    (point, point + dir * max_length)
+                }
+                SkillTarget::Unit(_) | SkillTarget::SelfCast => {
+                    return Err(SkillCastFailureReason::InvalidTarget);
+                }
+            };
+            if !in_range(start) {
+                return Err(SkillCastFailureReason::OutOfRange);
+            }
+            let end = start + (end - start).clamp_length_max(max_length);
+            Ok((SkillTarget::Vector { start, end }, start))
+        }
+    }
+}
+
+pub(super) fn get_named_data_value(
+    skill_object: &Spell,
+    target_name: &str,
+    level: usize,
+) -> Option<f32> {
     let spell_data = skill_object.spell_data.as_ref()?;
     let data_values = spell_data.data_values.as_ref()?;
     let norm_target = target_name.to_lowercase().replace('_', "");
@@ -93,7 +542,11 @@ fn get_named_data_value(skill_object: &Spell, target_name: &str, level: usize) -
     None
 }
 
-fn get_effect_value(skill_object: &Spell, effect_index: Option<i32>, level: usize) -> Option<f32> {
+pub(super) fn get_effect_value(
+    skill_object: &Spell,
+    effect_index: Option<i32>,
+    level: usize,
+) -> Option<f32> {
     let index = effect_index.unwrap_or(1) - 1;
     let spell_data = skill_object.spell_data.as_ref()?;
     let effect_amounts = spell_data.effect_amounts.as_ref()?;
@@ -102,48 +555,3 @@ fn get_effect_value(skill_object: &Spell, effect_index: Option<i32>, level: usiz
     let lvl_idx = if level > 0 { level - 1 } else { 0 };
     Some(*values.get(lvl_idx).unwrap_or(&0.0))
 }
-
-fn calculate_part(
-    part: &CalculationPart,
-    skill_object: &Spell,
-    level: usize,
-    get_stat: &impl Fn(u8) -> f32,
-) -> f32 {
-    match part {
-        CalculationPart::CalculationPartEffectValue(CalculationPartEffectValue {
-            effect_index,
-        }) => get_effect_value(skill_object, *effect_index, level).unwrap_or(0.0),
-        CalculationPart::CalculationPartStatCoefficient(CalculationPartStatCoefficient {
-            stat,
-            coefficient,
-            ..
-        }) => {
-            let stat = stat.unwrap_or(0);
-            let coefficient = coefficient.unwrap_or(0.0);
-            get_stat(stat) * coefficient
-        }
-        CalculationPart::CalculationPartNamedDataValue(CalculationPartNamedDataValue {
-            data_value,
-        }) => get_named_data_value(skill_object, data_value, level).unwrap_or(0.0),
-        CalculationPart::CalculationPartStatSub(CalculationPartStatSub {
-            stat, subpart, ..
-        }) => {
-            let stat_val = stat.unwrap_or(0);
-            let sub_val = if let Some(sub) = subpart {
-                calculate_part(sub, skill_object, level, get_stat)
-            } else {
-                0.0
-            };
-            get_stat(stat_val) * sub_val
-        }
-        CalculationPart::CalculationPartStatNamedDataValue(CalculationPartStatNamedDataValue {
-            stat,
-            data_value,
-            ..
-        }) => {
-            let stat = stat.unwrap_or(0);
-            let val = get_named_data_value(skill_object, data_value, level).unwrap_or(0.0);
-            get_stat(stat) * val
-        }
-    }
-}
@@ -23,7 +23,7 @@ use crate::movement::PluginMovement;
 use crate::skill::{
     CommandSkillLevelUp, CommandSkillStart, CoolDown, EventSkillCast, PluginSkill, Skill,
     SkillCastFailureReason, SkillCastLog, SkillCastResult, SkillCooldownMode, SkillOf, SkillPoints,
-    SkillRecastWindow, SkillSlot, Skills,
+    SkillRecastWindow, SkillSlot, SkillTarget, Skills,
 };
 use crate::team::Team;
 
@@ -219,11 +219,11 @@ impl SkillHarness {
     }
 
     fn cast_skill(&mut self, index: usize, point: Vec2) -> &mut Self {
-        self.app.world_mut().trigger(CommandSkillStart {
-            entity: self.caster,
+        self.app.world_mut().trigger(CommandSkillStart::new(
+            self.caster,
             index,
-            point,
-        });
+            SkillTarget::Point(point),
+        ));
         self.app.update();
         self
     }
@@ -375,6 +375,8 @@ fn observer_skill_can_drive_recast_state_and_manual_cooldown() {
 
     let stages = &harness.app.world().resource::<ObserverStages>().0;
     assert_eq!(stages.as_slice(), &[1, 2, 3]);
+    // 只有第一段扣蓝，重施窗口内的后续段免费
+    assert!((harness.mana() - 80.0).abs() < EPSILON);
 
     let skill_entity = harness.skill_entity(0);
     assert!(
@@ -606,11 +608,11 @@ fn test_god_mode_mechanics() {
         .get_mut::<AbilityResource>(caster)
         .unwrap()
         .value = 10.0;
-    app.world_mut().trigger(CommandSkillStart {
-        entity: caster,
-        index: 0,
-        point: Vec2::ZERO,
-    });
+    app.world_mut().trigger(CommandSkillStart::new(
+        caster,
+        0,
+        SkillTarget::Point(Vec2::ZERO),
+    ));
     app.update();
 
     // 验证技能释放成功且不扣蓝 (但在这一帧 apply_god_mode 运行后它也会重新回满)
@@ -6,6 +6,7 @@ mod integration_tests;
 mod observers;
 #[cfg(test)]
 mod tests;
+mod tooltip;
 
 use std::collections::BTreeMap;
 use std::ops::Deref;
@@ -14,11 +15,15 @@ use bevy::prelude::{Component, Handle, Reflect, ReflectComponent, *};
 pub use enums::*;
 pub use events::*;
 pub use helpers::{
-    delay_from_cast_frame, get_skill_cast_radius, get_skill_data_value, get_skill_value,
-    is_skill_ready,
+    SkillCalcCaster, SkillCalcQuery, SkillTargetInfo, delay_from_cast_frame, get_skill_cast_radius,
+    get_skill_cast_range, get_skill_data_value, get_skill_value, get_skill_value_with,
+    is_skill_ready, resolve_skill_target,
 };
 use lol_base::spell::Spell;
 pub use observers::*;
+pub use tooltip::{
+    SpellTooltip, TooltipSpan, TooltipStyle, render_spell_tooltip, spell_tooltip_template,
+};
 
 use crate::loaders::spell::LoaderSpell;
 
@@ -43,6 +48,7 @@ impl Plugin for PluginSkill {
         app.register_type::<NoCooldown>();
         app.init_resource::<GodMode>();
         app.register_type::<GodMode>();
+        app.register_type::<SkillTargeting>();
 
         app.add_observer(on_skill_cast);
         app.add_observer(on_skill_level_up);
@@ -103,6 +109,31 @@ impl Skill {
     }
 }
 
+/// 给带 `C` 标记的英雄的 `slot` 技能实体挂上施法方式。
+///
+/// 技能实体由场景加载生成，英雄插件无法在生成时指定施法方式，故在 `SkillOf` 挂上时补挂：
+/// `app.add_observer(attach_skill_targeting::<Annie>(SkillSlot::Q, SkillTargeting::Unit(..)))`。
+/// 场景按组件逐个写入，`Skill` 可能晚于 `SkillOf` 到达，故两者任一挂上都检查一次。
+pub fn attach_skill_targeting<C: Component>(
+    slot: SkillSlot,
+    targeting: SkillTargeting,
+) -> impl FnMut(
+    On<Add, (Skill, SkillOf)>,
+    Commands,
+    Query<(&Skill, &SkillOf), Without<SkillTargeting>>,
+    Query<(), With<C>>,
+) {
+    move |trigger, mut commands, q_skill, q_owner| {
+        let skill_entity = trigger.event_target();
+        let Ok((skill, skill_of)) = q_skill.get(skill_entity) else {
+            return;
+        };
+        if skill.slot == slot && q_owner.get(skill_of.0).is_ok() {
+            commands.entity(skill_entity).insert(targeting);
+        }
+    }
+}
+
 #[derive(Component, Reflect, Debug)]
 #[relationship(relationship_target = Skills)]
 #[reflect(Component)]
@@ -183,9 +214,12 @@ impl Default for SkillPoints {
     }
 }
 
+/// 重施窗口：窗口未过期时技能可绕过冷却、免资源消耗再次施放。
 #[derive(Component, Debug, Clone)]
 pub struct SkillRecastWindow {
+    /// 下一次施放是第几段（从 1 开始）
     pub stage: u8,
+    /// 技能总段数
     pub max_stage: u8,
     pub timer: Timer,
 }
@@ -198,6 +232,17 @@ impl SkillRecastWindow {
             timer: Timer::from_seconds(duration, TimerMode::Once),
         }
     }
+
+    /// 两段技能施放第一段后打开：下一次施放为第 2 段，也是最后一段
+    pub fn second_cast(duration: f32) -> Self {
+        Self::new(2, 2, duration)
+    }
+
+    /// 不分段的持续重施窗口（如指挥召唤物）：窗口内可反复施放，段数不推进，
+    /// 由技能自己在召唤物消失时移除
+    pub fn sustained(duration: f32) -> Self {
+        Self::new(1, 1, duration)
+    }
 }
 
 /// 英雄所有技能映射（导出时附加到角色实体上）
@@ -1,6 +1,7 @@
 use bevy::ecs::event::EntityEvent;
 use bevy::prelude::{
-    Assets, Commands, Entity, Fixed, On, Query, Res, ResMut, Time, Timer, TimerMode, With,
+    Assets, Commands, Entity, Fixed, Has, On, Query, Res, ResMut, Time, Timer, TimerMode,
+    Transform, Vec3Swizzles, With,
 };
 use lol_base::spell::Spell;
 
@@ -9,13 +10,16 @@ use super::events::{
     CommandSkillLevelUp, CommandSkillStart, EventSkillCast, SkillCastFailureReason, SkillCastLog,
     SkillCastRecord, SkillCastResult,
 };
-use super::{CoolDown, SkillPoints, SkillRecastWindow, Skills};
+use super::helpers::{SkillTargetInfo, get_skill_cast_range, resolve_skill_target};
+use super::{CoolDown, SkillPoints, SkillRecastWindow, SkillTargeting, Skills};
 use crate::base::ability_resource::AbilityResource;
 use crate::base::level::{EventLevelUp, Level};
+use crate::damage::Untargetable;
 use crate::life::Death;
 use crate::log::{CommandLog, EnumLogCategory};
 use crate::movement::CastBlock;
 use crate::skill::Skill;
+use crate::team::Team;
 
 pub fn on_skill_cast(
     trigger: On<CommandSkillStart>,
@@ -23,10 +27,16 @@ pub fn on_skill_cast(
     skills: Query<&Skills>,
     q_cast_block: Query<(), With<CastBlock>>,
     res_assets_spell_object: Res<Assets<Spell>>,
-    mut q_skill: Query<(&Skill, &mut CoolDown, Option<&SkillRecastWindow>)>,
+    mut q_skill: Query<(
+        &Skill,
+        &mut CoolDown,
+        Option<&SkillRecastWindow>,
+        Option<&SkillTargeting>,
+    )>,
     mut q_ability_resource: Query<&mut AbilityResource>,
     mut log: ResMut<SkillCastLog>,
     q_death: Query<(), With<Death>>,
+    q_target: Query<(&Transform, Option<&Team>, Has<Untargetable>)>,
     god_mode: Res<super::GodMode>,
 ) {
     let entity = trigger.event_target();
@@ -36,6 +46,7 @@ pub fn on_skill_cast(
         index: trigger.index,
         slot: None,
         point: trigger.point,
+        target: trigger.target,
         result: SkillCastResult::Started,
     };
 
@@ -66,7 +77,9 @@ pub fn on_skill_cast(
     };
     record.skill_entity = Some(skill_entity_id);
 
-    let Ok((skill, mut cooldown_state, recast_window)) = q_skill.get_mut(skill_entity_id) else {
+    let Ok((skill, mut cooldown_state, recast_window, targeting)) =
+        q_skill.get_mut(skill_entity_id)
+    else {
         record.result = SkillCastResult::Failed(SkillCastFailureReason::MissingSkillEntity);
         log.push(record);
         return;
@@ -100,7 +113,40 @@ pub fn on_skill_cast(
         return;
     }
 
-    let bypass_mana = god_mode.0;
+    // 按施法方式校验目标（阵营、存活、施法距离），并折算施法坐标
+    let (caster_pos, caster_team) = q_target
+        .get(entity)
+        .map(|(transform, team, _)| (transform.translation.xz(), team.copied()))
+        .unwrap_or_default();
+    let (target, point) = match resolve_skill_target(
+        targeting.copied().unwrap_or_default(),
+        trigger.target,
+        entity,
+        caster_pos,
+        caster_team,
+        get_skill_cast_range(spell_object, skill.level),
+        |target| {
+            let (transform, team, untargetable) = q_target.get(target).ok()?;
+            Some(SkillTargetInfo {
+                position: transform.translation.xz(),
+                team: team.copied(),
+                dead: q_death.get(target).is_ok(),
+                untargetable,
+            })
+        },
+    ) {
+        Ok(resolved) => resolved,
+        Err(reason) => {
+            record.result = SkillCastResult::Failed(reason);
+            log.push(record);
+            return;
+        }
+    };
+    record.target = target;
+    record.point = point;
+
+    // 重施窗口内的后续段（含提伯斯指挥等持续重施）不再消耗资源
+    let bypass_mana = god_mode.0 || can_cast_despite_cooldown;
 
     if !bypass_mana {
         let Ok(mut ability_resource) = q_ability_resource.get_mut(entity) else {
@@ -130,7 +176,8 @@ pub fn on_skill_cast(
         entity,
         skill_entity: skill_entity_id,
         index: trigger.index,
-        point: trigger.point,
+        point,
+        target,
     };
 
     commands.trigger(cast_event);
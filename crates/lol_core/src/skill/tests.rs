@@ -440,10 +440,265 @@ fn test_named_data_value_calculation() {
     assert_eq!(result, Some(expected_value));
 }
 
+#[test]
+fn test_conditional_and_modified_calculation() {
+    use std::collections::BTreeMap;
+
+    use lol_base::spell::ValuesData;
+    use lol_base::spell_calc::{
+        CalculationConditional, CalculationModified, CalculationPart,
+        CalculationPartNamedDataValue, CalculationPartNumber, CalculationRequirement,
+        CalculationSpell, CalculationType,
+    };
+
+    use crate::skill::{SkillCalcCaster, get_skill_value_with};
+
+    let named = |name: &str| {
+        CalculationType::CalculationSpell(CalculationSpell {
+            formula_parts: Some(vec![CalculationPart::CalculationPartNamedDataValue(
+                CalculationPartNamedDataValue {
+                    data_value: name.to_string(),
+                },
+            )]),
+            multiplier: None,
+            precision: None,
+        })
+    };
+
+    let mut calculations = BTreeMap::new();
+    calculations.insert("low_damage".to_string(), named("Low"));
+    calculations.insert("high_damage".to_string(), named("High"));
+    calculations.insert(
+        "damage".to_string(),
+        CalculationType::CalculationConditional(CalculationConditional {
+            requirement: CalculationRequirement::AboveHealthPercent {
+                percent: Some(0.5),
+                data_value: None,
+                invert: false,
+            },
+            conditional: "high_damage".to_string(),
+            default: Some("low_damage".to_string()),
+        }),
+    );
+    calculations.insert(
+        "max_rank_damage".to_string(),
+        CalculationType::CalculationModified(CalculationModified {
+            calculation: "high_damage".to_string(),
+            multiplier: CalculationPart::CalculationPartNumber(CalculationPartNumber {
+                number: Some(2.0),
+            }),
+            override_spell_level: Some(3),
+        }),
+    );
+
+    let data_values = vec![
+        ValuesData {
+            name: "Low".to_string(),
+            values: Some(vec![10.0, 20.0, 30.0]),
+        },
+        ValuesData {
+            name: "High".to_string(),
+            values: Some(vec![100.0, 200.0, 300.0]),
+        },
+    ];
+    let spell = create_mock_spell(calculations, None, Some(data_values));
+
+    let healthy = SkillCalcCaster::default();
+    let wounded = SkillCalcCaster {
+        health_percent: 0.3,
+        ..Default::default()
+    };
+    assert_eq!(
+        get_skill_value_with(&spell, "damage", 2, &healthy, |_| 0.0),
+        Some(200.0)
+    );
+    assert_eq!(
+        get_skill_value_with(&spell, "damage", 2, &wounded, |_| 0.0),
+        Some(20.0)
+    );
+    // 修饰计算按覆盖后的 3 级求值
+    assert_eq!(
+        get_skill_value(&spell, "max_rank_damage", 1, |_| 0.0),
+        Some(600.0)
+    );
+}
+
+#[test]
+fn test_char_level_and_buff_counter_parts() {
+    use std::collections::BTreeMap;
+
+    use lol_base::spell_calc::{
+        CalculationBreakpoint, CalculationPart, CalculationPartBuffCounterCoefficient,
+        CalculationPartByCharLevelBreakpoints, CalculationPartByCharLevelInterpolation,
+        CalculationPartClamp, CalculationPartNumber, CalculationPartProduct, CalculationSpell,
+        CalculationType,
+    };
+
+    use crate::skill::{SkillCalcCaster, get_skill_value_with};
+
+    let single = |part: CalculationPart| {
+        CalculationType::CalculationSpell(CalculationSpell {
+            formula_parts: Some(vec![part]),
+            multiplier: None,
+            precision: None,
+        })
+    };
+    let number = |n: f32| {
+        Box::new(CalculationPart::CalculationPartNumber(
+            CalculationPartNumber { number: Some(n) },
+        ))
+    };
+
+    let mut calculations = BTreeMap::new();
+    calculations.insert(
+        "breakpoints".to_string(),
+        single(CalculationPart::CalculationPartByCharLevelBreakpoints(
+            CalculationPartByCharLevelBreakpoints {
+                level1_value: Some(10.0),
+                initial_bonus_per_level: Some(1.0),
+                breakpoints: vec![CalculationBreakpoint {
+                    level: Some(6),
+                    additional_bonus_at_this_level: Some(5.0),
+                    bonus_per_level_at_and_after: Some(2.0),
+                }],
+            },
+        )),
+    );
+    calculations.insert(
+        "interpolation".to_string(),
+        single(CalculationPart::CalculationPartByCharLevelInterpolation(
+            CalculationPartByCharLevelInterpolation {
+                start_value: Some(0.0),
+                end_value: Some(170.0),
+                scale_by_stat_progression: None,
+                scale_past_default_max_level: None,
+            },
+        )),
+    );
+    calculations.insert(
+        "stacks".to_string(),
+        single(CalculationPart::CalculationPartClamp(
+            CalculationPartClamp {
+                floor: None,
+                ceiling: Some(25.0),
+                subparts: vec![
+                    CalculationPart::CalculationPartBuffCounterCoefficient(
+                        CalculationPartBuffCounterCoefficient {
+                            buff_name: "hemorrhage".to_string(),
+                            coefficient: 10.0,
+                        },
+                    ),
+                    CalculationPart::CalculationPartProduct(CalculationPartProduct {
+                        part1: number(0.5),
+                        part2: number(2.0),
+                    }),
+                ],
+            },
+        )),
+    );
+
+    let spell = create_mock_spell(calculations, None, None);
+    let caster = SkillCalcCaster {
+        char_level: 7,
+        buffs: vec!["Hemorrhage".to_string()],
+        ..Default::default()
+    };
+
+    // 2-5 级每级 +1，6 级 +5 且起每级 +2：10 + 4 + 5 + 2 + 2
+    assert_eq!(
+        get_skill_value_with(&spell, "breakpoints", 1, &caster, |_| 0.0),
+        Some(23.0)
+    );
+    let interpolated = get_skill_value_with(&spell, "interpolation", 1, &caster, |_| 0.0).unwrap();
+    assert!((interpolated - 60.0).abs() < 1e-3);
+    // 1 层 × 10 + 0.5 × 2 = 11，未触及上限
+    assert_eq!(
+        get_skill_value_with(&spell, "stacks", 1, &caster, |_| 0.0),
+        Some(11.0)
+    );
+    let stacked = SkillCalcCaster {
+        buffs: vec!["Hemorrhage".to_string(); 5],
+        ..caster
+    };
+    assert_eq!(
+        get_skill_value_with(&spell, "stacks", 1, &stacked, |_| 0.0),
+        Some(25.0)
+    );
+}
+
+#[test]
+fn test_unsupported_parts_fail_the_calculation() {
+    use std::collections::BTreeMap;
+
+    use lol_base::spell_calc::{
+        CalculationConditional, CalculationModified, CalculationPart, CalculationPartNumber,
+        CalculationPartProduct, CalculationPartUnsupported, CalculationRequirement,
+        CalculationSpell, CalculationType,
+    };
+
+    let unsupported = || {
+        CalculationPart::CalculationPartUnsupported(CalculationPartUnsupported {
+            kind: "Unknown".to_string(),
+        })
+    };
+    let number =
+        |n: f32| CalculationPart::CalculationPartNumber(CalculationPartNumber { number: Some(n) });
+
+    let mut calculations = BTreeMap::new();
+    calculations.insert(
+        "product".to_string(),
+        CalculationType::CalculationSpell(CalculationSpell {
+            formula_parts: Some(vec![
+                number(50.0),
+                CalculationPart::CalculationPartProduct(CalculationPartProduct {
+                    part1: Box::new(number(2.0)),
+                    part2: Box::new(unsupported()),
+                }),
+            ]),
+            multiplier: None,
+            precision: None,
+        }),
+    );
+    calculations.insert(
+        "conditional".to_string(),
+        CalculationType::CalculationConditional(CalculationConditional {
+            requirement: CalculationRequirement::Unsupported {
+                kind: "UnknownRequirement".to_string(),
+            },
+            conditional: "product".to_string(),
+            default: None,
+        }),
+    );
+    calculations.insert(
+        "modified".to_string(),
+        CalculationType::CalculationModified(CalculationModified {
+            calculation: "product".to_string(),
+            multiplier: number(2.0),
+            override_spell_level: None,
+        }),
+    );
+    let spell = create_mock_spell(calculations.clone(), None, None);
+
+    // 不支持的部件不按 0 计入乘积，整条计算失败
+    assert_eq!(get_skill_value(&spell, "product", 1, |_| 0.0), None);
+    assert_eq!(get_skill_value(&spell, "conditional", 1, |_| 0.0), None);
+    assert_eq!(get_skill_value(&spell, "modified", 1, |_| 0.0), None);
+
+    // 报告沿修饰 / 条件引用展开被引用计算
+    assert_eq!(
+        calculations["modified"].unsupported_parts(&calculations),
+        vec!["Unknown".to_string()]
+    );
+    assert_eq!(
+        calculations["conditional"].unsupported_parts(&calculations),
+        vec!["UnknownRequirement".to_string(), "Unknown".to_string()]
+    );
+}
+
 #[test]
 fn test_dead_caster_cannot_start_skill() {
     use crate::life::Death;
-    use crate::skill::{CommandSkillStart, SkillCastFailureReason};
+    use crate::skill::{CommandSkillStart, SkillCastFailureReason, SkillTarget};
 
     let mut harness = ActionSkillHarness::new();
     harness.register_spell(10.0, 0.0).add_skill(
@@ -459,11 +714,11 @@ fn test_dead_caster_cannot_start_skill() {
         .insert(Death);
 
     // 2. 尝试施放技能
-    harness.app.world_mut().trigger(CommandSkillStart {
-        entity: harness.caster,
-        index: 0,
-        point: Vec2::ZERO,
-    });
+    harness.app.world_mut().trigger(CommandSkillStart::new(
+        harness.caster,
+        0,
+        SkillTarget::Point(Vec2::ZERO),
+    ));
     harness.app.update();
 
     // 3. 验证失败原因
@@ -474,3 +729,259 @@ fn test_dead_caster_cannot_start_skill() {
         SkillCastResult::Failed(SkillCastFailureReason::CasterDead)
     ));
 }
+
+// ========== 施法目标（点选 / 自身 / 向量） ==========
+
+fn last_cast_result(harness: &ActionSkillHarness) -> SkillCastResult {
+    harness
+        .app
+        .world()
+        .resource::<SkillCastLog>()
+        .0
+        .last()
+        .unwrap()
+        .result
+}
+
+fn set_cast_range(harness: &mut ActionSkillHarness, range: f32) {
+    let mut spells = harness.app.world_mut().resource_mut::<Assets<Spell>>();
+    let spell = spells.get_mut(spell_handle(SPELL_KEY).id()).unwrap();
+    spell.spell_data.as_mut().unwrap().cast_range = Some(vec![range; 6]);
+}
+
+#[test]
+fn unit_targeted_skill_casts_at_target_position() {
+    use crate::skill::{SkillTargetFilter, SkillTargeting};
+
+    let mut harness = ActionSkillHarness::new();
+    harness.register_spell(10.0, 0.0).add_skill(
+        Skill::new(SkillSlot::Q, spell_handle(SPELL_KEY)).with_level(1),
+        (
+            ActionObserverSkill,
+            SkillTargeting::Unit(SkillTargetFilter::Enemy),
+        ),
+    );
+    set_cast_range(&mut harness, 500.0);
+
+    let enemy = harness.enemy;
+    harness.send_action(Action::SkillUnit {
+        index: 0,
+        target: enemy,
+    });
+
+    assert!(matches!(
+        last_cast_result(&harness),
+        SkillCastResult::Started
+    ));
+    let trace = &harness.app.world().resource::<ActionObserverTrace>().0;
+    assert_eq!(trace.as_slice(), &[Vec2::new(60.0, 0.0)]);
+}
+
+#[test]
+fn unit_targeted_skill_rejects_out_of_range_without_spending_mana() {
+    use crate::skill::{SkillCastFailureReason, SkillTargetFilter, SkillTargeting};
+
+    let mut harness = ActionSkillHarness::new();
+    harness.register_spell(10.0, 0.0).add_skill(
+        Skill::new(SkillSlot::Q, spell_handle(SPELL_KEY)).with_level(1),
+        SkillTargeting::Unit(SkillTargetFilter::Enemy),
+    );
+    set_cast_range(&mut harness, 30.0);
+
+    let enemy = harness.enemy;
+    harness.send_action(Action::SkillUnit {
+        index: 0,
+        target: enemy,
+    });
+
+    assert!(matches!(
+        last_cast_result(&harness),
+        SkillCastResult::Failed(SkillCastFailureReason::OutOfRange)
+    ));
+    assert!((harness.mana() - 100.0).abs() < EPSILON);
+}
+
+#[test]
+fn unit_targeted_skill_rejects_point_and_self_targets() {
+    use crate::skill::{SkillCastFailureReason, SkillTargetFilter, SkillTargeting};
+
+    let mut harness = ActionSkillHarness::new();
+    harness.register_spell(10.0, 0.0).add_skill(
+        Skill::new(SkillSlot::Q, spell_handle(SPELL_KEY)).with_level(1),
+        SkillTargeting::Unit(SkillTargetFilter::Enemy),
+    );
+
+    harness.send_action(Action::Skill {
+        index: 0,
+        point: Vec2::new(60.0, 0.0),
+    });
+    assert!(matches!(
+        last_cast_result(&harness),
+        SkillCastResult::Failed(SkillCastFailureReason::InvalidTarget)
+    ));
+
+    let caster = harness.caster;
+    harness.send_action(Action::SkillUnit {
+        index: 0,
+        target: caster,
+    });
+    assert!(matches!(
+        last_cast_result(&harness),
+        SkillCastResult::Failed(SkillCastFailureReason::InvalidTarget)
+    ));
+}
+
+#[test]
+fn self_cast_resolves_to_caster_position() {
+    let mut harness = ActionSkillHarness::new();
+    harness.register_spell(10.0, 0.0).add_skill(
+        Skill::new(SkillSlot::Q, spell_handle(SPELL_KEY)).with_level(1),
+        ActionObserverSkill,
+    );
+    let caster = harness.caster;
+    harness
+        .app
+        .world_mut()
+        .get_mut::<Transform>(caster)
+        .unwrap()
+        .translation = Vec3::new(15.0, 0.0, -20.0);
+
+    harness.send_action(Action::SkillSelf { index: 0 });
+
+    let trace = &harness.app.world().resource::<ActionObserverTrace>().0;
+    assert_eq!(trace.as_slice(), &[Vec2::new(15.0, -20.0)]);
+}
+
+#[test]
+fn vector_targeting_clamps_length_and_checks_start_range() {
+    use crate::skill::{SkillCastFailureReason, SkillTarget, SkillTargeting, resolve_skill_target};
+
+    let targeting = SkillTargeting::Vector { max_length: 100.0 };
+    let caster = Entity::PLACEHOLDER;
+    let resolve = |target| {
+        resolve_skill_target(
+            targeting,
+            target,
+            caster,
+            Vec2::ZERO,
+            Some(Team::Order),
+            Some(300.0),
+            |_| None,
+        )
+    };
+
+    let (target, point) = resolve(SkillTarget::Vector {
+        start: Vec2::new(200.0, 0.0),
+        end: Vec2::new(200.0, 500.0),
+    })
+    .unwrap();
+    assert_eq!(point, Vec2::new(200.0, 0.0));
+    assert_eq!(
+        target,
+        SkillTarget::Vector {
+            start: Vec2::new(200.0, 0.0),
+            end: Vec2::new(200.0, 100.0),
+        }
+    );
+
+    assert!(matches!(
+        resolve(SkillTarget::Vector {
+            start: Vec2::new(400.0, 0.0),
+            end: Vec2::new(500.0, 0.0),
+        }),
+        Err(SkillCastFailureReason::OutOfRange)
+    ));
+    assert!(matches!(
+        resolve(SkillTarget::SelfCast),
+        Err(SkillCastFailureReason::InvalidTarget)
+    ));
+}
+
+#[test]
+fn unit_targeting_rejects_untargetable_units() {
+    use crate::skill::{
+        SkillCastFailureReason, SkillTarget, SkillTargetFilter, SkillTargetInfo, SkillTargeting,
+        resolve_skill_target,
+    };
+
+    let mut world = World::new();
+    let caster = world.spawn_empty().id();
+    let enemy = world.spawn_empty().id();
+    let resolve = |target, filter, untargetable| {
+        resolve_skill_target(
+            SkillTargeting::Unit(filter),
+            SkillTarget::Unit(target),
+            caster,
+            Vec2::ZERO,
+            Some(Team::Order),
+            Some(300.0),
+            |entity| {
+                Some(SkillTargetInfo {
+                    position: Vec2::new(100.0, 0.0),
+                    team: Some(if entity == caster {
+                        Team::Order
+                    } else {
+                        Team::Chaos
+                    }),
+                    dead: false,
+                    untargetable,
+                })
+            },
+        )
+    };
+
+    assert!(resolve(enemy, SkillTargetFilter::Enemy, false).is_ok());
+    assert!(matches!(
+        resolve(enemy, SkillTargetFilter::Enemy, true),
+        Err(SkillCastFailureReason::InvalidTarget)
+    ));
+    // 自身不可选取时仍可以自己为目标
+    assert!(resolve(caster, SkillTargetFilter::Ally, true).is_ok());
+}
+
+#[test]
+fn skill_targeting_attaches_when_skill_is_linked() {
+    use crate::skill::{SkillTargetFilter, SkillTargeting, attach_skill_targeting};
+
+    #[derive(Component)]
+    struct Marked;
+
+    let mut app = App::new();
+    app.add_observer(attach_skill_targeting::<Marked>(
+        SkillSlot::Q,
+        SkillTargeting::Unit(SkillTargetFilter::Enemy),
+    ));
+
+    let marked = app.world_mut().spawn(Marked).id();
+    let other = app.world_mut().spawn_empty().id();
+    let q = app
+        .world_mut()
+        .spawn((Skill::new(SkillSlot::Q, Handle::default()), SkillOf(marked)))
+        .id();
+    let w = app
+        .world_mut()
+        .spawn((Skill::new(SkillSlot::W, Handle::default()), SkillOf(marked)))
+        .id();
+    let foreign = app
+        .world_mut()
+        .spawn((Skill::new(SkillSlot::Q, Handle::default()), SkillOf(other)))
+        .id();
+    // 先挂关系、后挂技能数据，同样要补挂
+    let late = app.world_mut().spawn(SkillOf(marked)).id();
+    app.world_mut()
+        .entity_mut(late)
+        .insert(Skill::new(SkillSlot::Q, Handle::default()));
+    app.update();
+
+    let targeting = |e| app.world().get::<SkillTargeting>(e).copied();
+    assert_eq!(
+        targeting(q),
+        Some(SkillTargeting::Unit(SkillTargetFilter::Enemy))
+    );
+    assert_eq!(
+        targeting(late),
+        Some(SkillTargeting::Unit(SkillTargetFilter::Enemy))
+    );
+    assert_eq!(targeting(w), None);
+    assert_eq!(targeting(foreign), None);
+}
@@ -0,0 +1,367 @@
+//! 技能提示文本：把本地化模板里的占位符按技能数据与施法者实时属性求值，输出带样式的富文本。
+//!
+//! 占位符支持 `@Effect1Amount@` 与 `{{ TotalDamage }}` 两种写法，可带 `*100` 倍率后缀：
+//! - `EffectNAmount`：`effect_amounts` 第 N 组
+//! - 计算名：`calculations`（含条件 / 修饰计算），按施法者属性、英雄等级与 buff 求值
+//! - 数据值：`data_values`
+//! - `Cost` / `ManaCost`、`Cooldown` / `CooldownTime`：消耗与冷却
+//!
+//! 样式标签（`<physicalDamage>`、`<scaleAP>` 等）转为 [`TooltipStyle`]，`<br>` 转为换行，
+//! 未知标签沿用外层样式。无法解析的占位符输出 `?` 并记入 [`SpellTooltip::unresolved`]。
+
+use lol_base::spell::Spell;
+use lol_base::spell_calc::CalculationType;
+
+use super::helpers::{
+    SkillCalcCaster, get_effect_value, get_named_data_value, get_skill_value_with,
+};
+
+/// 提示文本片段样式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TooltipStyle {
+    #[default]
+    Plain,
+    PhysicalDamage,
+    MagicDamage,
+    TrueDamage,
+    Healing,
+    Shield,
+    Status,
+    Speed,
+    ScaleAD,
+    ScaleAP,
+    ScaleHealth,
+    ScaleMana,
+    Keyword,
+    SpellName,
+}
+
+impl TooltipStyle {
+    fn from_tag(tag: &str) -> Option<Self> {
+        let style = match tag.to_lowercase().as_str() {
+            "physicaldamage" => Self::PhysicalDamage,
+            "magicdamage" => Self::MagicDamage,
+            "truedamage" => Self::TrueDamage,
+            "healing" => Self::Healing,
+            "shield" => Self::Shield,
+            "status" => Self::Status,
+            "speed" => Self::Speed,
+            "scalead" | "scalebonusad" => Self::ScaleAD,
+            "scaleap" => Self::ScaleAP,
+            "scalehealth" | "scalebonushealth" => Self::ScaleHealth,
+            "scalemana" => Self::ScaleMana,
+            "keyword" | "keywordmajor" | "keywordstealth" => Self::Keyword,
+            "spellname" => Self::SpellName,
+            _ => return None,
+        };
+        Some(style)
+    }
+}
+
+/// 富文本片段；`value` 为真表示由占位符求值得到的数字
+#[derive(Debug, Clone, PartialEq)]
+pub struct TooltipSpan {
+    pub text: String,
+    pub style: TooltipStyle,
+    pub value: bool,
+}
+
+/// 渲染后的技能提示
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpellTooltip {
+    pub spans: Vec<TooltipSpan>,
+    /// 未能解析的占位符名
+    pub unresolved: Vec<String>,
+}
+
+impl SpellTooltip {
+    /// 去掉样式后的纯文本
+    pub fn plain_text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    fn push(&mut self, text: &str, style: TooltipStyle, value: bool) {
+        if text.is_empty() {
+            return;
+        }
+        // 合并相邻同样式的普通文本
+        if !value
+            && let Some(last) = self.spans.last_mut()
+            && !last.value
+            && last.style == style
+        {
+            last.text.push_str(text);
+            return;
+        }
+        self.spans.push(TooltipSpan {
+            text: text.to_string(),
+            style,
+            value,
+        });
+    }
+}
+
+/// 提示模板的本地化键名，按优先级排列
+const TOOLTIP_TEMPLATE_KEYS: [&str; 2] = ["keyTooltip", "keySummary"];
+
+/// 取技能提取时附带的提示文本模板，优先完整提示，缺失时退回简介
+pub fn spell_tooltip_template(skill_object: &Spell) -> Option<&str> {
+    let templates = skill_object
+        .spell_data
+        .as_ref()?
+        .tooltip_templates
+        .as_ref()?;
+    TOOLTIP_TEMPLATE_KEYS
+        .iter()
+        .find_map(|key| templates.get(*key))
+        .map(String::as_str)
+}
+
+/// 按技能等级与施法者状态渲染提示模板。
+///
+/// `level` 为 1-based 技能等级；`get_stat` 与 [`get_skill_value`](super::get_skill_value) 相同。
+pub fn render_spell_tooltip(
+    template: &str,
+    skill_object: &Spell,
+    level: usize,
+    caster: &SkillCalcCaster,
+    get_stat: impl Fn(u8) -> f32,
+) -> SpellTooltip {
+    let mut tooltip = SpellTooltip::default();
+    let mut styles = vec![TooltipStyle::Plain];
+    let mut rest = template;
+
+    while !rest.is_empty() {
+        let next = rest.find(['<', '@', '{']).unwrap_or(rest.len());
+        let style = *styles.last().unwrap();
+        tooltip.push(&rest[..next], style, false);
+        rest = &rest[next..];
+        if rest.is_empty() {
+            break;
+        }
+
+        if rest.starts_with('<') {
+            let Some(end) = rest.find('>') else {
+                tooltip.push(rest, style, false);
+                break;
+            };
+            let tag = rest[1..end].trim().trim_end_matches('/').trim();
+            if tag.eq_ignore_ascii_case("br") {
+                tooltip.push("\n", style, false);
+            } else if let Some(closing) = tag.strip_prefix('/') {
+                if !closing.eq_ignore_ascii_case("br") && styles.len() > 1 {
+                    styles.pop();
+                }
+            } else {
+                let tag_name = tag.split_whitespace().next().unwrap_or_default();
+                styles.push(TooltipStyle::from_tag(tag_name).unwrap_or(style));
+            }
+            rest = &rest[end + 1..];
+            continue;
+        }
+
+        let placeholder = if let Some(inner) = rest.strip_prefix("{{") {
+            inner.find("}}").map(|end| (&inner[..end], end + 4))
+        } else if let Some(inner) = rest.strip_prefix('@') {
+            inner
+                .find('@')
+                .filter(|end| !inner[..*end].contains(char::is_whitespace))
+                .map(|end| (&inner[..end], end + 2))
+        } else {
+            None
+        };
+        let Some((expr, consumed)) = placeholder else {
+            tooltip.push(&rest[..1], style, false);
+            rest = &rest[1..];
+            continue;
+        };
+
+        let (name, multiplier) = parse_placeholder(expr);
+        match resolve_placeholder(name, skill_object, level, caster, &get_stat) {
+            Some((value, precision)) => {
+                let text = format_value(value * multiplier, precision);
+                tooltip.push(&text, style, true);
+            }
+            None => {
+                tooltip.unresolved.push(name.to_string());
+                tooltip.push("?", style, true);
+            }
+        }
+        rest = &rest[consumed..];
+    }
+
+    tooltip
+}
+
+/// 拆出占位符名与 `*k` 倍率
+fn parse_placeholder(expr: &str) -> (&str, f32) {
+    let expr = expr.trim();
+    match expr.split_once('*') {
+        Some((name, multiplier)) => (name.trim(), multiplier.trim().parse::<f32>().unwrap_or(1.0)),
+        None => (expr, 1.0),
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.to_lowercase().replace('_', "")
+}
+
+/// 解析占位符，返回数值与小数位数（None 为自动）
+fn resolve_placeholder(
+    name: &str,
+    skill_object: &Spell,
+    level: usize,
+    caster: &SkillCalcCaster,
+    get_stat: &impl Fn(u8) -> f32,
+) -> Option<(f32, Option<i32>)> {
+    let spell_data = skill_object.spell_data.as_ref()?;
+    let key = normalize(name);
+
+    if let Some(index) = key
+        .strip_prefix("effect")
+        .and_then(|s| s.strip_suffix("amount"))
+        .and_then(|s| s.parse::<i32>().ok())
+    {
+        return get_effect_value(skill_object, Some(index), level).map(|v| (v, None));
+    }
+
+    if let Some((calc_name, calc)) = spell_data
+        .calculations
+        .iter()
+        .flatten()
+        .find(|(calc_name, _)| normalize(calc_name) == key)
+    {
+        let precision = match calc {
+            CalculationType::CalculationSpell(spell) => spell.precision,
+            _ => None,
+        };
+        return get_skill_value_with(skill_object, calc_name, level, caster, get_stat)
+            .map(|v| (v, precision));
+    }
+
+    if let Some(value) = get_named_data_value(skill_object, name, level) {
+        return Some((value, None));
+    }
+
+    // 消耗与冷却数组按技能等级直接索引（与施法扣费一致）
+    let by_level = |values: Option<&Vec<f32>>| values?.get(level).copied();
+    match key.as_str() {
+        "cost" | "manacost" | "abilityresourcecost" => {
+            by_level(spell_data.mana.as_ref()).map(|v| (v, None))
+        }
+        "cooldown" | "cooldowntime" => {
+            by_level(spell_data.cooldown_time.as_ref()).map(|v| (v, None))
+        }
+        _ => None,
+    }
+}
+
+/// 数字格式化：指定精度时按精度，否则最多两位小数并去掉末尾 0
+fn format_value(value: f32, precision: Option<i32>) -> String {
+    let decimals = precision.map_or(2, |p| p.clamp(0, 6) as usize);
+    let text = format!("{:.*}", decimals, value);
+    if precision.is_some() || !text.contains('.') {
+        return text;
+    }
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use lol_base::spell::{DataSpell, ValuesData, ValuesEffect};
+    use lol_base::spell_calc::{
+        CalculationPart, CalculationPartEffectValue, CalculationPartStatCoefficient,
+        CalculationSpell,
+    };
+
+    use super::*;
+
+    fn make_spell() -> Spell {
+        let mut calculations = BTreeMap::new();
+        calculations.insert(
+            "total_damage".to_string(),
+            CalculationType::CalculationSpell(CalculationSpell {
+                formula_parts: Some(vec![
+                    CalculationPart::CalculationPartEffectValue(CalculationPartEffectValue {
+                        effect_index: Some(1),
+                    }),
+                    CalculationPart::CalculationPartStatCoefficient(
+                        CalculationPartStatCoefficient {
+                            stat: Some(2),
+                            coefficient: Some(0.5),
+                            stat_formula: None,
+                        },
+                    ),
+                ]),
+                multiplier: None,
+                precision: Some(0),
+            }),
+        );
+        Spell {
+            spell_data: Some(DataSpell {
+                calculations: Some(calculations),
+                effect_amounts: Some(vec![ValuesEffect {
+                    values: Some(vec![50.0, 80.0, 110.0]),
+                }]),
+                data_values: Some(vec![ValuesData {
+                    name: "SlowPercent".to_string(),
+                    values: Some(vec![0.25, 0.3, 0.35]),
+                }]),
+                mana: Some(vec![0.0, 40.0, 45.0, 50.0]),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn resolves_placeholders_with_live_stats() {
+        let spell = make_spell();
+        let template = "造成<physicalDamage>{{ TotalDamage }} 物理伤害</physicalDamage>并减速\
+                        <status>@SlowPercent*100@%</status><br>消耗 @Cost@ 法力";
+        let tooltip =
+            render_spell_tooltip(template, &spell, 2, &SkillCalcCaster::default(), |stat| {
+                if stat == 2 { 102.0 } else { 0.0 }
+            });
+
+        assert!(tooltip.unresolved.is_empty());
+        assert_eq!(
+            tooltip.plain_text(),
+            "造成131 物理伤害并减速30%\n消耗 45 法力"
+        );
+        let damage = tooltip.spans.iter().find(|s| s.text == "131").unwrap();
+        assert_eq!(damage.style, TooltipStyle::PhysicalDamage);
+        assert!(damage.value);
+        let slow = tooltip.spans.iter().find(|s| s.text == "30").unwrap();
+        assert_eq!(slow.style, TooltipStyle::Status);
+    }
+
+    #[test]
+    fn template_prefers_full_tooltip_over_summary() {
+        let mut spell = make_spell();
+        assert_eq!(spell_tooltip_template(&spell), None);
+
+        let mut templates = BTreeMap::from([("keySummary".to_string(), "简介".to_string())]);
+        spell.spell_data.as_mut().unwrap().tooltip_templates = Some(templates.clone());
+        assert_eq!(spell_tooltip_template(&spell), Some("简介"));
+
+        templates.insert("keyTooltip".to_string(), "完整提示".to_string());
+        spell.spell_data.as_mut().unwrap().tooltip_templates = Some(templates);
+        assert_eq!(spell_tooltip_template(&spell), Some("完整提示"));
+    }
+
+    #[test]
+    fn unknown_placeholders_and_tags_degrade_gracefully() {
+        let spell = make_spell();
+        let tooltip = render_spell_tooltip(
+            "<unknownTag>@Effect1Amount@ + @f1@</unknownTag> a@b.com",
+            &spell,
+            1,
+            &SkillCalcCaster::default(),
+            |_| 0.0,
+        );
+        assert_eq!(tooltip.plain_text(), "50 + ? a@b.com");
+        assert_eq!(tooltip.unresolved, vec!["f1".to_string()]);
+    }
+}
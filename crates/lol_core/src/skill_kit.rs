@@ -0,0 +1,979 @@
+//! 数据驱动的英雄技能套件。
+//!
+//! 套件（RON）按技能槽声明施法时触发哪些现有动作原语：伤害形状、飞弹、位移、
+//! 控制位移、buff、重施窗口与动画，简单英雄不必再手写 `on_xxx_q` 观察者。
+//! 给英雄挂上 [`SkillKit`] 即生效；施法时实时读取资源，文件修改热重载后下一次施法即用新数值。
+//!
+//! 数值字段用 [`KitValue`]：固定数、技能计算名或数据值名，按当前技能等级求值。
+//!
+//! 套件资源尚未加载完成时的施法记入 [`PendingKitCasts`]，加载后按顺序补放；加载失败则丢弃并报错。
+
+use bevy::prelude::*;
+use lol_base::render_cmd::{CommandAnimationPlay, CommandSkinParticleSpawn, CommandSkinSoundPlay};
+use lol_base::spell::Spell;
+use serde::{Deserialize, Serialize};
+
+use crate::action::damage::{
+    ActionDamage, ActionDamageEffect, DamageShape, TargetDamage, TargetFilter, is_in_shape,
+};
+use crate::action::dash::{ActionDash, DashMoveType};
+use crate::action::displace::{
+    ActionDisplace, DisplaceCenter, DisplaceEffect, DisplaceMotion, DisplaceTargetSelection,
+};
+use crate::attack::CommandAttackReset;
+use crate::base::buff::{BuffCommandsExt, BuffOf};
+use crate::buffs::cc_debuffs::{
+    DebuffKnockup, DebuffRoot, DebuffSilence, DebuffSlow, DebuffStun, cc_descriptor,
+};
+use crate::buffs::common_buffs::{BuffCastBlock, BuffMoveSpeed};
+use crate::buffs::shield_white::BuffShieldWhite;
+use crate::damage::{AbilityPower, Damage, DamageType};
+use crate::life::{Death, Health};
+use crate::loaders::kit::LoaderChampionKit;
+use crate::missile::{CommandAttachedFieldCreate, CommandMissileCreate};
+use crate::skill::{
+    EventSkillCast, Skill, SkillCalcCaster, SkillCalcQuery, SkillRecastWindow, SkillSlot,
+    get_skill_data_value, get_skill_value_with,
+};
+use crate::spatial::{SpatialCandidates, SpatialFilter, SpatialShape};
+use crate::team::Team;
+
+#[derive(Default)]
+pub struct PluginSkillKit;
+
+impl Plugin for PluginSkillKit {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<ChampionKit>();
+        app.init_asset_loader::<LoaderChampionKit>();
+
+        app.add_observer(on_kit_skill_cast);
+        app.add_observer(on_command_kit_cast);
+        app.add_systems(Update, (replay_pending_kit_casts, log_kit_reloads));
+    }
+}
+
+/// 英雄技能套件资源
+#[derive(Asset, TypePath, Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ChampionKit {
+    pub skills: Vec<KitSkill>,
+}
+
+impl ChampionKit {
+    pub fn skill(&self, slot: SkillSlot) -> Option<&KitSkill> {
+        self.skills.iter().find(|skill| skill.slot == slot)
+    }
+}
+
+/// 挂在英雄身上，施法时按套件执行
+#[derive(Component, Debug, Clone)]
+pub struct SkillKit(pub Handle<ChampionKit>);
+
+impl SkillKit {
+    /// 约定路径 `kits/<name>.ron`
+    pub fn load(asset_server: &AssetServer, name: &str) -> Self {
+        Self(asset_server.load(format!("kits/{}.ron", name.to_lowercase())))
+    }
+}
+
+/// 套件加载完成前的施法，加载后按顺序补放
+#[derive(Component, Debug, Clone, Default)]
+pub struct PendingKitCasts(pub Vec<CommandKitCast>);
+
+/// 按套件执行一次施法
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct CommandKitCast {
+    pub entity: Entity,
+    pub skill_entity: Entity,
+    pub point: Vec2,
+}
+
+/// 单个技能槽
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct KitSkill {
+    pub slot: SkillSlot,
+    /// 多段技能的重施窗口（秒）；每段施法后窗口内可施放下一段
+    #[serde(default)]
+    pub recast_window: Option<f32>,
+    /// 各段效果，单段技能只写一段
+    pub stages: Vec<KitStage>,
+}
+
+impl KitSkill {
+    /// 按重施窗口的段数（1-based）取本段，超出时取最后一段
+    pub fn stage(&self, stage: u8) -> Option<&KitStage> {
+        let index = (stage.max(1) as usize - 1).min(self.stages.len().checked_sub(1)?);
+        self.stages.get(index)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct KitStage {
+    #[serde(default)]
+    pub animation: Option<String>,
+    #[serde(default)]
+    pub effects: Vec<KitEffect>,
+}
+
+/// 数值来源
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum KitValue {
+    Fixed(f32),
+    /// 技能计算名（`calculations`）
+    Calc(String),
+    /// 技能数据值名（`data_values`）
+    Data(String),
+}
+
+/// 以施法者为原点、朝向为前方的形状
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum KitShape {
+    Circle {
+        radius: f32,
+    },
+    Sector {
+        radius: f32,
+        angle: f32,
+    },
+    Annular {
+        inner_radius: f32,
+        outer_radius: f32,
+    },
+    Rectangle {
+        width: f32,
+        length: f32,
+        #[serde(default)]
+        start_distance: f32,
+    },
+    Nearest {
+        max_distance: f32,
+    },
+}
+
+impl KitShape {
+    pub fn to_damage_shape(&self) -> DamageShape {
+        match *self {
+            KitShape::Circle { radius } => DamageShape::Circle { radius },
+            KitShape::Sector { radius, angle } => DamageShape::Sector { radius, angle },
+            KitShape::Annular {
+                inner_radius,
+                outer_radius,
+            } => DamageShape::Annular {
+                inner_radius,
+                outer_radius,
+            },
+            KitShape::Rectangle {
+                width,
+                length,
+                start_distance,
+            } => DamageShape::Rectangle {
+                width,
+                length,
+                start_distance,
+            },
+            KitShape::Nearest { max_distance } => DamageShape::Nearest { max_distance },
+        }
+    }
+
+    /// 形状离施法者最远的距离，用作空间索引粗筛半径
+    pub fn reach(&self) -> f32 {
+        match *self {
+            KitShape::Circle { radius } | KitShape::Sector { radius, .. } => radius,
+            KitShape::Annular { outer_radius, .. } => outer_radius,
+            KitShape::Rectangle {
+                width,
+                length,
+                start_distance,
+            } => Vec2::new(start_distance + length, width / 2.0).length(),
+            KitShape::Nearest { max_distance } => max_distance,
+        }
+    }
+}
+
+/// 可施加的 buff / debuff
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum KitBuff {
+    Slow {
+        percent: KitValue,
+        duration: KitValue,
+    },
+    Stun {
+        duration: KitValue,
+    },
+    Root {
+        duration: KitValue,
+    },
+    Silence {
+        duration: KitValue,
+    },
+    Knockup {
+        duration: KitValue,
+    },
+    Shield {
+        amount: KitValue,
+    },
+    MoveSpeed {
+        percent: KitValue,
+        duration: KitValue,
+    },
+    CastBlock {
+        duration: f32,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum KitDash {
+    Fixed(f32),
+    Pointer { max: f32 },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum KitDisplaceTargets {
+    Cone { range: f32, angle: f32 },
+    Circle { radius: f32 },
+    Nearest { range: f32 },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum KitDisplaceMotion {
+    PullToward { distance: f32, speed: f32 },
+    PushAway { distance: f32, speed: f32 },
+    None,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum KitDisplaceEffect {
+    Knockup {
+        duration: f32,
+    },
+    Stun {
+        duration: f32,
+    },
+    Slow {
+        percent: f32,
+        duration: f32,
+    },
+    Damage {
+        amount: KitValue,
+        damage_type: DamageType,
+    },
+}
+
+/// 技能效果原语
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum KitEffect {
+    /// 形状内瞬时伤害（`ActionDamage`），`amount` 为计算名
+    Damage {
+        shape: KitShape,
+        amount: String,
+        damage_type: DamageType,
+        #[serde(default)]
+        filter: TargetFilter,
+    },
+    /// 朝施法点发射直线飞弹
+    Missile {
+        damage: KitValue,
+        range: f32,
+        #[serde(default)]
+        speed: Option<f32>,
+        #[serde(default)]
+        particle: Option<String>,
+        #[serde(default)]
+        pass_through: bool,
+    },
+    Dash {
+        movement: KitDash,
+        speed: f32,
+    },
+    Displace {
+        targets: KitDisplaceTargets,
+        motion: KitDisplaceMotion,
+        #[serde(default)]
+        effects: Vec<KitDisplaceEffect>,
+    },
+    /// 附着在施法者身上的伤害场
+    Field {
+        radius: f32,
+        damage: KitValue,
+        duration: f32,
+    },
+    SelfBuff(KitBuff),
+    /// 对形状内敌人施加 debuff
+    AreaDebuff {
+        shape: KitShape,
+        buff: KitBuff,
+    },
+    AttackReset,
+    Particle(String),
+    Sound(String),
+}
+
+/// 施法时的求值上下文
+struct KitCast<'a, F: Fn(u8) -> f32> {
+    spell: Option<&'a Spell>,
+    level: usize,
+    caster: SkillCalcCaster,
+    get_stat: F,
+}
+
+impl<F: Fn(u8) -> f32> KitCast<'_, F> {
+    fn value(&self, value: &KitValue) -> f32 {
+        match value {
+            KitValue::Fixed(v) => *v,
+            KitValue::Calc(name) => self
+                .spell
+                .and_then(|spell| {
+                    get_skill_value_with(spell, name, self.level, &self.caster, &self.get_stat)
+                })
+                .unwrap_or_else(|| {
+                    // 计算缺失或含不支持部件时这一项按 0 结算，并留下日志便于排查
+                    warn!("技能套件数值计算失败: {}", name);
+                    0.0
+                }),
+            KitValue::Data(name) => self
+                .spell
+                .and_then(|spell| get_skill_data_value(spell, name, self.level))
+                .unwrap_or(0.0),
+        }
+    }
+}
+
+fn insert_kit_buff<F: Fn(u8) -> f32>(
+    commands: &mut Commands,
+    source: Entity,
+    target: Entity,
+    buff: &KitBuff,
+    cast: &KitCast<'_, F>,
+) {
+    let mut entity = commands.entity(target);
+    match buff {
+        KitBuff::Slow { percent, duration } => {
+            entity.apply_buff(
+                cc_descriptor(source),
+                DebuffSlow::new(cast.value(percent), cast.value(duration)),
+            );
+        }
+        KitBuff::Stun { duration } => {
+            entity.apply_buff(cc_descriptor(source), DebuffStun::new(cast.value(duration)));
+        }
+        KitBuff::Root { duration } => {
+            entity.apply_buff(cc_descriptor(source), DebuffRoot::new(cast.value(duration)));
+        }
+        KitBuff::Silence { duration } => {
+            entity.apply_buff(
+                cc_descriptor(source),
+                DebuffSilence::new(cast.value(duration)),
+            );
+        }
+        KitBuff::Knockup { duration } => {
+            entity.apply_buff(
+                cc_descriptor(source),
+                DebuffKnockup::new(cast.value(duration)),
+            );
+        }
+        KitBuff::Shield { amount } => {
+            entity.apply_buff(
+                BuffShieldWhite::descriptor().with_source(source),
+                BuffShieldWhite::new(cast.value(amount)),
+            );
+        }
+        KitBuff::MoveSpeed { percent, duration } => {
+            entity.apply_buff(
+                BuffMoveSpeed::descriptor(cast.value(duration)).with_source(source),
+                BuffMoveSpeed::new(cast.value(percent)),
+            );
+        }
+        KitBuff::CastBlock { duration } => {
+            entity.with_related::<BuffOf>(BuffCastBlock::new(*duration));
+        }
+    }
+}
+
+/// 挂有套件的英雄施法时转为 [`CommandKitCast`]；套件未加载完成则先排队
+pub fn on_kit_skill_cast(
+    trigger: On<EventSkillCast>,
+    mut commands: Commands,
+    mut q_kit: Query<(&SkillKit, Option<&mut PendingKitCasts>)>,
+    res_kits: Res<Assets<ChampionKit>>,
+) {
+    let entity = trigger.event_target();
+    let Ok((kit, pending)) = q_kit.get_mut(entity) else {
+        return;
+    };
+    let cast = CommandKitCast {
+        entity,
+        skill_entity: trigger.skill_entity,
+        point: trigger.point,
+    };
+    if res_kits.contains(&kit.0) {
+        commands.trigger(cast);
+        return;
+    }
+
+    warn!("技能套件尚未加载，施法排队等待: {:?}", entity);
+    match pending {
+        Some(mut pending) => pending.0.push(cast),
+        None => {
+            commands.entity(entity).insert(PendingKitCasts(vec![cast]));
+        }
+    }
+}
+
+/// 套件加载完成后补放排队的施法；加载失败时丢弃并报错
+fn replay_pending_kit_casts(
+    mut commands: Commands,
+    q_pending: Query<(Entity, &SkillKit, &PendingKitCasts)>,
+    res_kits: Res<Assets<ChampionKit>>,
+    asset_server: Res<AssetServer>,
+) {
+    for (entity, kit, pending) in q_pending.iter() {
+        if res_kits.contains(&kit.0) {
+            for cast in &pending.0 {
+                commands.trigger(*cast);
+            }
+        } else if asset_server.load_state(&kit.0).is_failed() {
+            error!(
+                "技能套件加载失败，丢弃 {} 次排队施法: {:?}",
+                pending.0.len(),
+                entity
+            );
+        } else {
+            continue;
+        }
+        commands.entity(entity).remove::<PendingKitCasts>();
+    }
+}
+
+/// 按套件执行施法：选段、播放动画、依次触发各效果原语，并维护重施窗口
+pub fn on_command_kit_cast(
+    trigger: On<CommandKitCast>,
+    mut commands: Commands,
+    q_kit: Query<&SkillKit>,
+    q_skill: Query<(&Skill, Option<&SkillRecastWindow>)>,
+    q_transform: Query<&Transform>,
+    q_team: Query<&Team>,
+    q_targets: Query<(Entity, &Team, &Transform), (With<Health>, Without<Death>)>,
+    q_damage: Query<&Damage>,
+    q_ap: Query<&AbilityPower>,
+    calc: SkillCalcQuery,
+    spatial: SpatialCandidates,
+    res_kits: Res<Assets<ChampionKit>>,
+    res_spells: Res<Assets<Spell>>,
+    mut candidates: Local<Vec<Entity>>,
+) {
+    let entity = trigger.event_target();
+    let Ok(kit) = q_kit.get(entity) else {
+        return;
+    };
+    let Some(kit) = res_kits.get(&kit.0) else {
+        return;
+    };
+    let Ok((skill, recast)) = q_skill.get(trigger.skill_entity) else {
+        return;
+    };
+    let Some(kit_skill) = kit.skill(skill.slot) else {
+        return;
+    };
+
+    let stage = recast.map(|window| window.stage).unwrap_or(1);
+    let Some(kit_stage) = kit_skill.stage(stage) else {
+        return;
+    };
+
+    let cast = KitCast {
+        spell: res_spells.get(&skill.spell),
+        level: skill.level,
+        caster: calc.caster(entity),
+        get_stat: |stat: u8| match stat {
+            2 => q_damage.get(entity).map(|d| d.0).unwrap_or(0.0),
+            0 => q_ap.get(entity).map(|ap| ap.0).unwrap_or(0.0),
+            _ => 0.0,
+        },
+    };
+
+    if let Some(animation) = &kit_stage.animation {
+        commands.trigger(CommandAnimationPlay {
+            entity,
+            hash: animation.clone(),
+            repeat: false,
+            duration: None,
+        });
+    }
+
+    let origin = q_transform
+        .get(entity)
+        .map(|t| t.translation)
+        .unwrap_or_default();
+    let forward = q_transform
+        .get(entity)
+        .map(|t| t.forward().xz())
+        .unwrap_or(Vec2::X);
+    let cast_dir = (trigger.point - origin.xz()).normalize_or(forward);
+
+    for effect in &kit_stage.effects {
+        match effect {
+            KitEffect::Damage {
+                shape,
+                amount,
+                damage_type,
+                filter,
+            } => {
+                commands.trigger(ActionDamage {
+                    entity,
+                    skill: skill.spell.clone(),
+                    effects: vec![ActionDamageEffect {
+                        shape: shape.to_damage_shape(),
+                        damage_list: vec![TargetDamage {
+                            filter: filter.clone(),
+                            amount: amount.clone(),
+                            damage_type: *damage_type,
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }],
+                });
+            }
+            KitEffect::Missile {
+                damage,
+                range,
+                speed,
+                particle,
+                pass_through,
+            } => {
+                commands.trigger(CommandMissileCreate {
+                    entity,
+                    target: None,
+                    destination: Some(origin + Vec3::new(cast_dir.x, 0.0, cast_dir.y) * *range),
+                    spell: skill.spell.clone(),
+                    damage: cast.value(damage),
+                    speed: *speed,
+                    particle_key: particle.clone(),
+                    sticky: false,
+                    pass_through: *pass_through,
+                    collision_target: default(),
+                    missing_hp_scaling: None,
+                });
+            }
+            KitEffect::Dash { movement, speed } => {
+                let move_type = match movement {
+                    KitDash::Fixed(distance) => DashMoveType::Fixed(*distance),
+                    KitDash::Pointer { max } => DashMoveType::Pointer { max: *max },
+                };
+                commands.trigger(ActionDash {
+                    entity,
+                    move_type,
+                    speed: *speed,
+                    point: trigger.point,
+                });
+            }
+            KitEffect::Displace {
+                targets,
+                motion,
+                effects,
+            } => {
+                let targets = match *targets {
+                    KitDisplaceTargets::Cone { range, angle } => DisplaceTargetSelection::Cone {
+                        range,
+                        angle,
+                        direction: cast_dir,
+                    },
+                    KitDisplaceTargets::Circle { radius } => DisplaceTargetSelection::Circle {
+                        radius,
+                        center: DisplaceCenter::Caster,
+                    },
+                    KitDisplaceTargets::Nearest { range } => {
+                        DisplaceTargetSelection::Nearest { range }
+                    }
+                };
+                let motion = match *motion {
+                    KitDisplaceMotion::PullToward { distance, speed } => {
+                        DisplaceMotion::PullToward { distance, speed }
+                    }
+                    KitDisplaceMotion::PushAway { distance, speed } => {
+                        DisplaceMotion::PushAway { distance, speed }
+                    }
+                    KitDisplaceMotion::None => DisplaceMotion::None,
+                };
+                let effects = effects
+                    .iter()
+                    .map(|effect| match effect {
+                        KitDisplaceEffect::Knockup { duration } => DisplaceEffect::Knockup {
+                            duration: *duration,
+                        },
+                        KitDisplaceEffect::Stun { duration } => DisplaceEffect::Stun {
+                            duration: *duration,
+                        },
+                        KitDisplaceEffect::Slow { percent, duration } => DisplaceEffect::Slow {
+                            percent: *percent,
+                            duration: *duration,
+                        },
+                        KitDisplaceEffect::Damage {
+                            amount,
+                            damage_type,
+                        } => DisplaceEffect::Damage {
+                            amount: cast.value(amount),
+                            damage_type: *damage_type,
+                            tag: None,
+                        },
+                    })
+                    .collect();
+                commands.trigger(ActionDisplace {
+                    entity,
+                    targets,
+                    motion,
+                    effects,
+                    cone_hit_policy: None,
+                });
+            }
+            KitEffect::Field {
+                radius,
+                damage,
+                duration,
+            } => {
+                commands.trigger(CommandAttachedFieldCreate {
+                    entity,
+                    radius: *radius,
+                    damage: cast.value(damage),
+                    duration: *duration,
+                    grow_from: None,
+                    grow_duration: None,
+                });
+            }
+            KitEffect::SelfBuff(buff) => {
+                insert_kit_buff(&mut commands, entity, entity, buff, &cast);
+            }
+            KitEffect::AreaDebuff { shape, buff } => {
+                let Ok(team) = q_team.get(entity) else {
+                    continue;
+                };
+                spatial.query_into(
+                    &SpatialShape::Circle {
+                        center: origin.xz(),
+                        radius: shape.reach(),
+                    },
+                    SpatialFilter::not_team(*team),
+                    q_targets.iter().map(|(target, ..)| target),
+                    &mut candidates,
+                );
+                let shape = shape.to_damage_shape();
+                for (target, target_team, target_transform) in
+                    q_targets.iter_many(candidates.iter())
+                {
+                    if target_team == team
+                        || !is_in_shape(target_transform.translation, origin, forward, &shape)
+                    {
+                        continue;
+                    }
+                    insert_kit_buff(&mut commands, entity, target, buff, &cast);
+                }
+            }
+            KitEffect::AttackReset => {
+                commands.trigger(CommandAttackReset { entity });
+            }
+            KitEffect::Particle(hash) => {
+                commands.trigger(CommandSkinParticleSpawn {
+                    entity,
+                    hash: hash.clone(),
+                    rotation: None,
+                    resolver_entity: None,
+                });
+            }
+            KitEffect::Sound(key) => {
+                commands.trigger(CommandSkinSoundPlay {
+                    entity,
+                    key: key.clone(),
+                });
+            }
+        }
+    }
+
+    // 重施窗口：未到最后一段则开启下一段窗口，最后一段结束后关闭
+    if let Some(window) = kit_skill.recast_window {
+        let max_stage = kit_skill.stages.len() as u8;
+        if stage < max_stage {
+            commands
+                .entity(trigger.skill_entity)
+                .insert(SkillRecastWindow::new(stage + 1, max_stage, window));
+        } else {
+            commands
+                .entity(trigger.skill_entity)
+                .remove::<SkillRecastWindow>();
+        }
+    }
+}
+
+fn log_kit_reloads(mut events: MessageReader<AssetEvent<ChampionKit>>) {
+    for event in events.read() {
+        if let AssetEvent::Modified { id } = event {
+            info!("技能套件 {:?} 已热重载", id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::action::damage::DamageShape;
+    use crate::base::buff::Buffs;
+    use crate::skill::SkillTarget;
+    use crate::spatial::{PluginSpatial, SpatialIndex};
+
+    const KIT: &str = r#"(
+        skills: [
+            (
+                slot: Q,
+                recast_window: Some(4.0),
+                stages: [
+                    (animation: Some("Spell1A"), effects: [Dash(movement: Fixed(250.0), speed: 1000.0)]),
+                    (animation: Some("Spell1B"), effects: [AttackReset]),
+                ],
+            ),
+            (
+                slot: E,
+                stages: [
+                    (
+                        effects: [
+                            Damage(shape: Circle(radius: 300.0), amount: "total_damage", damage_type: Magic),
+                            AreaDebuff(shape: Circle(radius: 300.0), buff: Slow(percent: Data("SlowPercent"), duration: Fixed(1.0))),
+                            SelfBuff(Shield(amount: Calc("total_shield"))),
+                        ],
+                    ),
+                ],
+            ),
+        ],
+    )"#;
+
+    #[test]
+    fn parses_kit_ron() {
+        let kit: ChampionKit = ron::from_str(KIT).unwrap();
+        assert_eq!(kit.skills.len(), 2);
+
+        let q = kit.skill(SkillSlot::Q).unwrap();
+        assert_eq!(q.recast_window, Some(4.0));
+        assert_eq!(q.stages[0].animation.as_deref(), Some("Spell1A"));
+
+        let e = kit.skill(SkillSlot::E).unwrap();
+        assert!(e.recast_window.is_none());
+        assert!(matches!(
+            e.stages[0].effects[0],
+            KitEffect::Damage {
+                damage_type: DamageType::Magic,
+                filter: TargetFilter::All,
+                ..
+            }
+        ));
+        assert!(kit.skill(SkillSlot::R).is_none());
+    }
+
+    #[test]
+    fn stage_selection_clamps_to_last_stage() {
+        let kit: ChampionKit = ron::from_str(KIT).unwrap();
+        let q = kit.skill(SkillSlot::Q).unwrap();
+        let animation = |stage| q.stage(stage).unwrap().animation.clone().unwrap();
+        assert_eq!(animation(0), "Spell1A");
+        assert_eq!(animation(1), "Spell1A");
+        assert_eq!(animation(2), "Spell1B");
+        assert_eq!(animation(3), "Spell1B");
+    }
+
+    #[test]
+    fn bundled_lux_kit_parses() {
+        let kit: ChampionKit = ron::from_str(include_str!("../../../assets/kits/lux.ron")).unwrap();
+        for slot in [SkillSlot::Q, SkillSlot::W, SkillSlot::E, SkillSlot::R] {
+            assert!(kit.skill(slot).is_some(), "{slot:?}");
+        }
+    }
+
+    #[derive(Resource, Default)]
+    struct Captured {
+        animations: Vec<String>,
+        damages: Vec<ActionDamage>,
+    }
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AssetPlugin::default());
+        app.init_asset::<Spell>();
+        app.add_plugins(PluginSkillKit);
+        app.insert_resource(Time::<Fixed>::from_hz(30.0));
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+        app.init_resource::<Captured>();
+        app.add_observer(
+            |trigger: On<CommandAnimationPlay>, mut captured: ResMut<Captured>| {
+                captured.animations.push(trigger.hash.clone());
+            },
+        );
+        app.add_observer(
+            |trigger: On<ActionDamage>, mut captured: ResMut<Captured>| {
+                captured.damages.push(trigger.event().clone());
+            },
+        );
+        app
+    }
+
+    fn add_kit(app: &mut App, ron: &str) -> Handle<ChampionKit> {
+        let kit: ChampionKit = ron::from_str(ron).unwrap();
+        app.world_mut()
+            .resource_mut::<Assets<ChampionKit>>()
+            .add(kit)
+    }
+
+    /// 生成带四个技能的施法者，返回（施法者, [Q, W, E, R] 技能实体）
+    fn spawn_caster(app: &mut App, kit: Handle<ChampionKit>) -> (Entity, [Entity; 4]) {
+        let caster = app
+            .world_mut()
+            .spawn((SkillKit(kit), Team::Order, Transform::default()))
+            .id();
+        let skills = [SkillSlot::Q, SkillSlot::W, SkillSlot::E, SkillSlot::R].map(|slot| {
+            app.world_mut()
+                .spawn(Skill {
+                    level: 1,
+                    ..Skill::new(slot, Handle::default())
+                })
+                .id()
+        });
+        (caster, skills)
+    }
+
+    fn cast(app: &mut App, caster: Entity, skill_entity: Entity, index: usize) {
+        let point = Vec2::new(500.0, 0.0);
+        app.world_mut().trigger(EventSkillCast {
+            entity: caster,
+            skill_entity,
+            index,
+            point,
+            target: SkillTarget::Point(point),
+        });
+        app.update();
+    }
+
+    #[test]
+    fn lux_kit_matches_handwritten_observers() {
+        let mut app = setup_app();
+        let kit = add_kit(&mut app, include_str!("../../../assets/kits/lux.ron"));
+        let (lux, skills) = spawn_caster(&mut app, kit);
+        for (index, skill) in skills.into_iter().enumerate() {
+            cast(&mut app, lux, skill, index);
+        }
+
+        let captured = app.world().resource::<Captured>();
+        assert_eq!(
+            captured.animations,
+            ["Spell1", "Spell2", "Spell3", "Spell4"]
+        );
+        // 与迁移前的 on_lux_q / on_lux_e / on_lux_r 一致，W 只播动画
+        assert_eq!(captured.damages.len(), 3);
+        let shapes = captured
+            .damages
+            .iter()
+            .map(|damage| {
+                assert_eq!(damage.entity, lux);
+                let effect = &damage.effects[0];
+                let target = &effect.damage_list[0];
+                assert_eq!(target.amount, "total_damage");
+                assert_eq!(target.damage_type, DamageType::Magic);
+                assert_eq!(target.filter, TargetFilter::All);
+                effect.shape.clone()
+            })
+            .collect::<Vec<_>>();
+        let sector = |shape: &DamageShape| match *shape {
+            DamageShape::Sector { radius, angle } => Some((radius, angle)),
+            _ => None,
+        };
+        assert_eq!(sector(&shapes[0]), Some((1300.0, 10.0)));
+        assert!(matches!(shapes[1], DamageShape::Circle { radius } if radius == 300.0));
+        assert_eq!(sector(&shapes[2]), Some((3400.0, 20.0)));
+    }
+
+    #[test]
+    fn kit_cast_opens_and_closes_recast_window() {
+        let mut app = setup_app();
+        let kit = add_kit(&mut app, KIT);
+        let (caster, [q, ..]) = spawn_caster(&mut app, kit);
+
+        cast(&mut app, caster, q, 0);
+        let window = app.world().get::<SkillRecastWindow>(q).unwrap();
+        assert_eq!(window.stage, 2);
+
+        cast(&mut app, caster, q, 0);
+        assert!(app.world().get::<SkillRecastWindow>(q).is_none());
+        assert_eq!(
+            app.world().resource::<Captured>().animations,
+            ["Spell1A", "Spell1B"]
+        );
+    }
+
+    #[test]
+    fn casts_before_kit_loads_are_replayed() {
+        let mut app = setup_app();
+        let (caster, [q, ..]) = spawn_caster(&mut app, Handle::default());
+
+        cast(&mut app, caster, q, 0);
+        assert!(app.world().resource::<Captured>().animations.is_empty());
+        assert_eq!(
+            app.world().get::<PendingKitCasts>(caster).unwrap().0.len(),
+            1
+        );
+
+        // 套件就绪后补放
+        let kit = add_kit(&mut app, KIT);
+        app.world_mut().entity_mut(caster).insert(SkillKit(kit));
+        app.update();
+        assert_eq!(app.world().resource::<Captured>().animations, ["Spell1A"]);
+        assert!(app.world().get::<PendingKitCasts>(caster).is_none());
+    }
+
+    #[test]
+    fn failed_kit_drops_queued_casts() {
+        let mut app = setup_app();
+        let missing = app
+            .world()
+            .resource::<AssetServer>()
+            .load::<ChampionKit>("kits/does_not_exist.ron");
+        let (caster, [q, ..]) = spawn_caster(&mut app, missing);
+
+        cast(&mut app, caster, q, 0);
+        for _ in 0..100 {
+            app.update();
+            if app.world().get::<PendingKitCasts>(caster).is_none() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert!(app.world().get::<PendingKitCasts>(caster).is_none());
+        assert!(app.world().resource::<Captured>().animations.is_empty());
+    }
+
+    #[test]
+    fn area_debuff_hits_enemies_through_spatial_index() {
+        let mut app = setup_app();
+        app.add_plugins(PluginSpatial);
+        let kit = add_kit(&mut app, KIT);
+        let (caster, [_, _, e, _]) = spawn_caster(&mut app, kit);
+        let mut spawn_unit = |team: Team, x: f32| {
+            app.world_mut()
+                .spawn((team, Transform::from_xyz(x, 0.0, 0.0), Health::new(100.0)))
+                .id()
+        };
+        let near = spawn_unit(Team::Chaos, 200.0);
+        let far = spawn_unit(Team::Chaos, 800.0);
+        let ally = spawn_unit(Team::Order, 100.0);
+        app.update();
+        app.update();
+        assert!(!app.world().resource::<SpatialIndex>().is_empty());
+
+        cast(&mut app, caster, e, 2);
+        let slowed = |app: &App, entity: Entity| {
+            app.world().get::<Buffs>(entity).is_some_and(|buffs| {
+                buffs
+                    .iter()
+                    .any(|buff| app.world().get::<DebuffSlow>(buff).is_some())
+            })
+        };
+        assert!(slowed(&app, near));
+        assert!(!slowed(&app, far));
+        assert!(!slowed(&app, ally));
+    }
+}
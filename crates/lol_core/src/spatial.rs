@@ -0,0 +1,618 @@
+use std::collections::HashMap;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::entities::champion::TargetableAsChampion;
+use crate::entities::minion::Minion;
+use crate::entities::pet::Pet;
+use crate::entities::turret::Turret;
+use crate::life::Death;
+use crate::team::Team;
+
+/// 网格单元边长（游戏单位）
+pub const SPATIAL_CELL_SIZE: f32 = 500.0;
+/// 查询外扩余量：索引在 FixedPreUpdate 重建，本 tick 内单位的位移由此兜底，
+/// 调用方再用实时 Transform 做精确判定
+pub const SPATIAL_QUERY_MARGIN: f32 = 100.0;
+
+/// 空间索引插件：每个固定 tick 重建一次按队伍分桶的均匀网格，
+/// 供仇恨扫描、直线飞弹碰撞与范围伤害共用。
+///
+/// 索引只反映 tick 开始时的快照，有一个 tick 的延迟：
+/// - 本 tick 内新生成的单位尚未入索引，查不到；
+/// - 本 tick 内位移超过 [`SPATIAL_QUERY_MARGIN`] 的单位（闪现、传送、长距离位移）仍按旧位置索引，
+///   在新位置查不到、在旧位置附近会作为候选返回（再经实时 Transform 剔除）。
+///
+/// 下一个 tick 重建后即恢复一致。需要对刚生成或刚瞬移的单位立即生效的判定，
+/// 不要依赖索引，直接遍历查询。
+#[derive(Default)]
+pub struct PluginSpatial;
+
+impl Plugin for PluginSpatial {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpatialIndex>();
+        app.add_systems(FixedPreUpdate, rebuild_spatial_index);
+    }
+}
+
+/// 单位类型，供查询按类型筛选
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpatialKind {
+    Champion,
+    Minion,
+    Pet,
+    Turret,
+    Other,
+}
+
+/// 索引中的一条记录，位置为重建时的 XZ 坐标
+#[derive(Debug, Clone, Copy)]
+pub struct SpatialEntry {
+    pub entity: Entity,
+    pub team: Team,
+    pub kind: SpatialKind,
+    pub position: Vec2,
+}
+
+/// 查询过滤条件
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SpatialFilter {
+    /// 跳过该队伍的单位
+    pub exclude_team: Option<Team>,
+    /// 跳过中立单位
+    pub exclude_neutral: bool,
+    /// 只返回该类型的单位
+    pub kind: Option<SpatialKind>,
+}
+
+impl SpatialFilter {
+    /// `team` 的所有非同队单位（含中立）
+    pub fn not_team(team: Team) -> Self {
+        Self {
+            exclude_team: Some(team),
+            ..default()
+        }
+    }
+
+    /// `team` 的敌方单位（不含中立）
+    pub fn enemies_of(team: Team) -> Self {
+        Self {
+            exclude_team: Some(team),
+            exclude_neutral: true,
+            kind: None,
+        }
+    }
+
+    pub fn with_kind(mut self, kind: SpatialKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    fn accepts_team(&self, team: Team) -> bool {
+        self.exclude_team != Some(team) && !(self.exclude_neutral && team == Team::Neutral)
+    }
+}
+
+/// XZ 平面上的查询形状
+#[derive(Debug, Clone, Copy)]
+pub enum SpatialShape {
+    Circle {
+        center: Vec2,
+        radius: f32,
+    },
+    /// 以 center 为顶点、forward 为朝向的扇形，angle 为完整张角（度）
+    Sector {
+        center: Vec2,
+        forward: Vec2,
+        radius: f32,
+        angle: f32,
+    },
+    /// 沿 forward 从 start_distance 延伸到 start_distance + length，左右各 width/2
+    Rectangle {
+        origin: Vec2,
+        forward: Vec2,
+        width: f32,
+        length: f32,
+        start_distance: f32,
+    },
+    /// 线段 start → end，左右各 width/2
+    Segment {
+        start: Vec2,
+        end: Vec2,
+        width: f32,
+    },
+}
+
+impl SpatialShape {
+    /// 判定点是否在形状内
+    pub fn contains(&self, point: Vec2) -> bool {
+        match *self {
+            SpatialShape::Circle { center, radius } => {
+                point.distance_squared(center) <= radius * radius
+            }
+            SpatialShape::Sector {
+                center,
+                forward,
+                radius,
+                angle,
+            } => {
+                let diff = point - center;
+                let distance = diff.length();
+                if distance > radius {
+                    return false;
+                }
+                if distance == 0.0 {
+                    return true;
+                }
+                forward.dot(diff / distance).acos() <= angle.to_radians() / 2.0
+            }
+            SpatialShape::Rectangle {
+                origin,
+                forward,
+                width,
+                length,
+                start_distance,
+            } => {
+                let diff = point - origin;
+                let along = forward.dot(diff);
+                if along < start_distance || along > start_distance + length {
+                    return false;
+                }
+                (-forward.y * diff.x + forward.x * diff.y).abs() <= width / 2.0
+            }
+            SpatialShape::Segment { start, end, width } => {
+                let seg = end - start;
+                let t = if seg.length_squared() > 0.0 {
+                    ((point - start).dot(seg) / seg.length_squared()).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                point.distance(start + seg * t) <= width / 2.0
+            }
+        }
+    }
+
+    /// 向外扩 `margin` 的粗筛形状：扇形放宽为整圆，矩形与线段四周各扩 `margin`
+    pub fn expanded(&self, margin: f32) -> SpatialShape {
+        match *self {
+            SpatialShape::Circle { center, radius }
+            | SpatialShape::Sector { center, radius, .. } => SpatialShape::Circle {
+                center,
+                radius: radius + margin,
+            },
+            SpatialShape::Rectangle {
+                origin,
+                forward,
+                width,
+                length,
+                start_distance,
+            } => SpatialShape::Rectangle {
+                origin,
+                forward,
+                width: width + margin * 2.0,
+                length: length + margin * 2.0,
+                start_distance: start_distance - margin,
+            },
+            SpatialShape::Segment { start, end, width } => SpatialShape::Segment {
+                start,
+                end,
+                width: width + margin * 2.0,
+            },
+        }
+    }
+
+    /// 外接轴对齐包围盒 `(min, max)`
+    pub fn bounds(&self) -> (Vec2, Vec2) {
+        match *self {
+            SpatialShape::Circle { center, radius }
+            | SpatialShape::Sector { center, radius, .. } => {
+                (center - Vec2::splat(radius), center + Vec2::splat(radius))
+            }
+            SpatialShape::Rectangle {
+                origin,
+                forward,
+                width,
+                length,
+                start_distance,
+            } => {
+                let lateral = Vec2::new(-forward.y, forward.x) * (width / 2.0);
+                let near = origin + forward * start_distance;
+                let far = origin + forward * (start_distance + length);
+                let corners = [near + lateral, near - lateral, far + lateral, far - lateral];
+                corners[1..]
+                    .iter()
+                    .fold((corners[0], corners[0]), |(min, max), c| {
+                        (min.min(*c), max.max(*c))
+                    })
+            }
+            SpatialShape::Segment { start, end, width } => {
+                let half = Vec2::splat(width / 2.0);
+                (start.min(end) - half, start.max(end) + half)
+            }
+        }
+    }
+}
+
+/// 单个队伍的均匀网格
+#[derive(Default)]
+struct TeamGrid {
+    cells: HashMap<IVec2, Vec<SpatialEntry>>,
+}
+
+/// 按队伍分桶的均匀网格空间索引
+#[derive(Resource)]
+pub struct SpatialIndex {
+    cell_size: f32,
+    /// 按 Order / Chaos / Neutral 顺序分桶
+    grids: [TeamGrid; 3],
+    len: usize,
+}
+
+impl Default for SpatialIndex {
+    fn default() -> Self {
+        Self::new(SPATIAL_CELL_SIZE)
+    }
+}
+
+fn team_slot(team: Team) -> usize {
+    match team {
+        Team::Order => 0,
+        Team::Chaos => 1,
+        Team::Neutral => 2,
+    }
+}
+
+const SLOT_TEAMS: [Team; 3] = [Team::Order, Team::Chaos, Team::Neutral];
+
+impl SpatialIndex {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            grids: default(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 清空所有记录：上一轮仍有单位的单元保留以便复用，已空置的单元释放
+    pub fn clear(&mut self) {
+        for grid in &mut self.grids {
+            grid.cells.retain(|_, cell| {
+                let used = !cell.is_empty();
+                cell.clear();
+                used
+            });
+        }
+        self.len = 0;
+    }
+
+    pub fn insert(&mut self, entry: SpatialEntry) {
+        let cell = self.cell_of(entry.position);
+        self.grids[team_slot(entry.team)]
+            .cells
+            .entry(cell)
+            .or_default()
+            .push(entry);
+        self.len += 1;
+    }
+
+    fn cell_of(&self, position: Vec2) -> IVec2 {
+        (position / self.cell_size).floor().as_ivec2()
+    }
+
+    /// 把形状内、满足过滤条件的实体追加到 `out`（不清空 `out`）
+    pub fn query_into(&self, shape: &SpatialShape, filter: SpatialFilter, out: &mut Vec<Entity>) {
+        let (min, max) = shape.bounds();
+        let (min_cell, max_cell) = (self.cell_of(min), self.cell_of(max));
+        for (slot, grid) in self.grids.iter().enumerate() {
+            if grid.cells.is_empty() || !filter.accepts_team(SLOT_TEAMS[slot]) {
+                continue;
+            }
+            for x in min_cell.x..=max_cell.x {
+                for y in min_cell.y..=max_cell.y {
+                    let Some(cell) = grid.cells.get(&IVec2::new(x, y)) else {
+                        continue;
+                    };
+                    out.extend(
+                        cell.iter()
+                            .filter(|entry| filter.kind.is_none_or(|kind| kind == entry.kind))
+                            .filter(|entry| shape.contains(entry.position))
+                            .map(|entry| entry.entity),
+                    );
+                }
+            }
+        }
+    }
+
+    pub fn query(&self, shape: &SpatialShape, filter: SpatialFilter) -> Vec<Entity> {
+        let mut out = Vec::new();
+        self.query_into(shape, filter, &mut out);
+        out
+    }
+
+    pub fn query_circle(&self, center: Vec2, radius: f32, filter: SpatialFilter) -> Vec<Entity> {
+        self.query(&SpatialShape::Circle { center, radius }, filter)
+    }
+
+    pub fn query_sector(
+        &self,
+        center: Vec2,
+        forward: Vec2,
+        radius: f32,
+        angle: f32,
+        filter: SpatialFilter,
+    ) -> Vec<Entity> {
+        self.query(
+            &SpatialShape::Sector {
+                center,
+                forward,
+                radius,
+                angle,
+            },
+            filter,
+        )
+    }
+
+    pub fn query_rectangle(
+        &self,
+        origin: Vec2,
+        forward: Vec2,
+        width: f32,
+        length: f32,
+        start_distance: f32,
+        filter: SpatialFilter,
+    ) -> Vec<Entity> {
+        self.query(
+            &SpatialShape::Rectangle {
+                origin,
+                forward,
+                width,
+                length,
+                start_distance,
+            },
+            filter,
+        )
+    }
+
+    pub fn query_segment(
+        &self,
+        start: Vec2,
+        end: Vec2,
+        width: f32,
+        filter: SpatialFilter,
+    ) -> Vec<Entity> {
+        self.query(&SpatialShape::Segment { start, end, width }, filter)
+    }
+}
+
+/// 粗筛候选的系统参数：注册了 [`SpatialIndex`] 时按形状外扩 [`SPATIAL_QUERY_MARGIN`] 查询，
+/// 未注册时退化为调用方给出的全部实体。候选仍需调用方用实时 Transform 精确判定。
+#[derive(SystemParam)]
+pub struct SpatialCandidates<'w> {
+    index: Option<Res<'w, SpatialIndex>>,
+}
+
+impl SpatialCandidates<'_> {
+    /// 清空 `out` 后写入 `shape` 附近满足过滤条件的候选；无索引时写入 `all`
+    pub fn query_into(
+        &self,
+        shape: &SpatialShape,
+        filter: SpatialFilter,
+        all: impl IntoIterator<Item = Entity>,
+        out: &mut Vec<Entity>,
+    ) {
+        out.clear();
+        match self.index.as_deref() {
+            Some(index) => index.query_into(&shape.expanded(SPATIAL_QUERY_MARGIN), filter, out),
+            None => out.extend(all),
+        }
+    }
+}
+
+/// FixedPreUpdate：用本 tick 开始时的位置重建索引，死亡单位不入索引。
+pub fn rebuild_spatial_index(
+    mut index: ResMut<SpatialIndex>,
+    q_units: Query<
+        (
+            Entity,
+            &Team,
+            &Transform,
+            Has<TargetableAsChampion>,
+            Has<Minion>,
+            Has<Pet>,
+            Has<Turret>,
+        ),
+        Without<Death>,
+    >,
+) {
+    index.clear();
+    for (entity, team, transform, champion, minion, pet, turret) in q_units.iter() {
+        let kind = if champion {
+            SpatialKind::Champion
+        } else if minion {
+            SpatialKind::Minion
+        } else if pet {
+            SpatialKind::Pet
+        } else if turret {
+            SpatialKind::Turret
+        } else {
+            SpatialKind::Other
+        };
+        index.insert(SpatialEntry {
+            entity,
+            team: *team,
+            kind,
+            position: transform.translation.xz(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::entities::champion::Champion;
+
+    /// 按插入顺序编号的测试索引，查询结果还原成编号（从 1 开始）
+    struct TestIndex {
+        world: World,
+        index: SpatialIndex,
+        entities: Vec<Entity>,
+    }
+
+    impl TestIndex {
+        fn new() -> Self {
+            Self {
+                world: World::new(),
+                index: SpatialIndex::default(),
+                entities: Vec::new(),
+            }
+        }
+
+        fn insert(&mut self, team: Team, kind: SpatialKind, x: f32, z: f32) {
+            let entity = self.world.spawn_empty().id();
+            self.entities.push(entity);
+            self.index.insert(SpatialEntry {
+                entity,
+                team,
+                kind,
+                position: Vec2::new(x, z),
+            });
+        }
+
+        fn ids(&self, found: Vec<Entity>) -> Vec<usize> {
+            let mut ids = found
+                .iter()
+                .map(|e| self.entities.iter().position(|v| v == e).unwrap() + 1)
+                .collect::<Vec<_>>();
+            ids.sort();
+            ids
+        }
+    }
+
+    #[test]
+    fn circle_query_spans_cells_and_filters_team_and_kind() {
+        let mut t = TestIndex::new();
+        t.insert(Team::Chaos, SpatialKind::Minion, 490.0, 0.0);
+        t.insert(Team::Chaos, SpatialKind::Champion, 520.0, 0.0);
+        t.insert(Team::Order, SpatialKind::Champion, 510.0, 0.0);
+        t.insert(Team::Neutral, SpatialKind::Other, 500.0, 10.0);
+        t.insert(Team::Chaos, SpatialKind::Minion, 2000.0, 0.0);
+
+        let center = Vec2::new(500.0, 0.0);
+        assert_eq!(
+            t.ids(
+                t.index
+                    .query_circle(center, 50.0, SpatialFilter::not_team(Team::Order))
+            ),
+            vec![1, 2, 4]
+        );
+        assert_eq!(
+            t.ids(
+                t.index
+                    .query_circle(center, 50.0, SpatialFilter::enemies_of(Team::Order))
+            ),
+            vec![1, 2]
+        );
+        assert_eq!(
+            t.ids(t.index.query_circle(
+                center,
+                50.0,
+                SpatialFilter::enemies_of(Team::Order).with_kind(SpatialKind::Champion)
+            )),
+            vec![2]
+        );
+
+        t.index.clear();
+        assert!(t.index.is_empty());
+        // 清空后保留上一轮有单位的单元，再清空一次即释放
+        let cells = |index: &SpatialIndex| index.grids.iter().map(|g| g.cells.len()).sum::<usize>();
+        assert_eq!(cells(&t.index), 5);
+        t.index.clear();
+        assert_eq!(cells(&t.index), 0);
+        assert!(
+            t.index
+                .query_circle(center, 5000.0, SpatialFilter::default())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn sector_rectangle_and_segment_queries() {
+        let mut t = TestIndex::new();
+        t.insert(Team::Chaos, SpatialKind::Minion, 300.0, 0.0);
+        t.insert(Team::Chaos, SpatialKind::Minion, 0.0, 300.0);
+        t.insert(Team::Chaos, SpatialKind::Minion, -300.0, 0.0);
+        t.insert(Team::Chaos, SpatialKind::Minion, 900.0, 40.0);
+
+        let filter = SpatialFilter::not_team(Team::Order);
+        assert_eq!(
+            t.ids(
+                t.index
+                    .query_sector(Vec2::ZERO, Vec2::X, 400.0, 90.0, filter)
+            ),
+            vec![1]
+        );
+        assert_eq!(
+            t.ids(
+                t.index
+                    .query_rectangle(Vec2::ZERO, Vec2::X, 100.0, 1000.0, 100.0, filter)
+            ),
+            vec![1, 4]
+        );
+        assert_eq!(
+            t.ids(t.index.query_segment(
+                Vec2::new(0.0, -400.0),
+                Vec2::new(400.0, -400.0),
+                100.0,
+                filter
+            )),
+            Vec::<usize>::new()
+        );
+        assert_eq!(
+            t.ids(t.index.query_segment(
+                Vec2::new(-300.0, 0.0),
+                Vec2::new(0.0, 300.0),
+                20.0,
+                filter
+            )),
+            vec![2, 3]
+        );
+    }
+
+    #[test]
+    fn rebuild_skips_dead_units() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(PluginSpatial);
+        let alive = app
+            .world_mut()
+            .spawn((Team::Chaos, Champion, Transform::from_xyz(100.0, 0.0, 0.0)))
+            .id();
+        app.world_mut()
+            .spawn((Team::Chaos, Death, Transform::from_xyz(120.0, 0.0, 0.0)));
+        app.world_mut()
+            .run_system_once(rebuild_spatial_index)
+            .unwrap();
+
+        let index = app.world().resource::<SpatialIndex>();
+        assert_eq!(index.len(), 1);
+        assert_eq!(
+            index.query_circle(
+                Vec2::new(100.0, 0.0),
+                50.0,
+                SpatialFilter::default().with_kind(SpatialKind::Champion)
+            ),
+            vec![alive]
+        );
+    }
+}
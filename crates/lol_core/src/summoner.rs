@@ -0,0 +1,1116 @@
+use bevy::prelude::*;
+use lol_base::grid::ConfigNavigationGrid;
+use lol_base::spell::Spell;
+use serde::{Deserialize, Serialize};
+
+use crate::base::buff::{
+    Buff, BuffCommandsExt, BuffDescriptor, BuffOf, BuffStacking, BuffTag, Buffs,
+};
+use crate::base::level::Level;
+use crate::buffs::cc_debuffs::{
+    CleanseKind, CommandCleanse, CrowdControl, DebuffSlow, DebuffSuppression, ForcedAction,
+    cc_descriptor,
+};
+use crate::buffs::common_buffs::{BuffCastBlock, BuffGhosted, BuffMoveSpeed, DebuffGrievousWounds};
+use crate::buffs::damage_reduction::BuffDamageDealtReduction;
+use crate::buffs::shield_white::BuffShieldWhite;
+use crate::damage::{CommandDamageCreate, DamageKind, DamageType, EventDamageCreate, Untargetable};
+use crate::entities::champion::Champion;
+use crate::heal::{CommandHeal, HealType};
+use crate::life::{Death, Health};
+use crate::movement::{CastBlock, CommandMovement, MovementAction, MovementSource};
+use crate::navigation::grid::ResourceGrid;
+use crate::navigation::terrain::blink_landing;
+use crate::skill::{
+    NoCooldown, SkillCastFailureReason, SkillTarget, SkillTargetFilter, SkillTargetInfo,
+    SkillTargeting, resolve_skill_target,
+};
+use crate::team::Team;
+
+/// 每个英雄的召唤师技能槽位数（D / F）
+pub const SUMMONER_SLOT_COUNT: usize = 2;
+
+/// 闪现最大位移距离
+pub const FLASH_RANGE: f32 = 400.0;
+/// 传送引导时长（秒）
+pub const TELEPORT_CHANNEL_SECS: f32 = 4.0;
+/// 惩戒伤害（真实伤害）
+pub const SMITE_DAMAGE: f32 = 600.0;
+
+#[derive(Default)]
+pub struct PluginSummonerSpell;
+
+impl Plugin for PluginSummonerSpell {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<NoCooldown>() {
+            app.init_resource::<NoCooldown>();
+        }
+        app.register_type::<SummonerSpells>();
+        app.register_type::<ConfigSummonerSpells>();
+        app.add_observer(on_insert_config_summoner_spells);
+        app.add_observer(on_insert_summoner_spells_load_data);
+        app.add_observer(on_command_summoner_spell);
+        app.add_observer(on_cleanse_summoner_debuffs);
+        app.add_observer(on_teleport_interrupt_damage);
+        app.add_observer(on_teleport_interrupt_movement);
+        app.add_observer(on_teleport_interrupt_cc);
+        app.add_systems(
+            FixedUpdate,
+            (
+                tick_summoner_cooldowns,
+                update_ignite,
+                update_teleport_channel,
+            ),
+        );
+    }
+}
+
+/// 召唤师技能种类。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum SummonerSpellKind {
+    Flash,
+    Ignite,
+    Heal,
+    Barrier,
+    Exhaust,
+    Ghost,
+    Teleport,
+    Smite,
+    Cleanse,
+}
+
+impl SummonerSpellKind {
+    pub const ALL: [SummonerSpellKind; 9] = [
+        SummonerSpellKind::Flash,
+        SummonerSpellKind::Ignite,
+        SummonerSpellKind::Heal,
+        SummonerSpellKind::Barrier,
+        SummonerSpellKind::Exhaust,
+        SummonerSpellKind::Ghost,
+        SummonerSpellKind::Teleport,
+        SummonerSpellKind::Smite,
+        SummonerSpellKind::Cleanse,
+    ];
+
+    /// 游戏数据中的技能名（资产路径与图标按此查找）。
+    pub fn spell_name(&self) -> &'static str {
+        match self {
+            SummonerSpellKind::Flash => "SummonerFlash",
+            SummonerSpellKind::Ignite => "SummonerDot",
+            SummonerSpellKind::Heal => "SummonerHeal",
+            SummonerSpellKind::Barrier => "SummonerBarrier",
+            SummonerSpellKind::Exhaust => "SummonerExhaust",
+            SummonerSpellKind::Ghost => "SummonerHaste",
+            SummonerSpellKind::Teleport => "SummonerTeleport",
+            SummonerSpellKind::Smite => "SummonerSmite",
+            SummonerSpellKind::Cleanse => "SummonerBoost",
+        }
+    }
+
+    /// 导出的技能资产路径（`spells/<技能名>.ron`）。
+    pub fn asset_path(&self) -> String {
+        format!("spells/{}.ron", self.spell_name())
+    }
+
+    /// 未挂技能资产时的默认冷却（秒）。
+    pub fn default_cooldown(&self) -> f32 {
+        match self {
+            SummonerSpellKind::Flash => 300.0,
+            SummonerSpellKind::Ignite => 180.0,
+            SummonerSpellKind::Heal => 240.0,
+            SummonerSpellKind::Barrier => 180.0,
+            SummonerSpellKind::Exhaust => 240.0,
+            SummonerSpellKind::Ghost => 240.0,
+            SummonerSpellKind::Teleport => 360.0,
+            SummonerSpellKind::Smite => 90.0,
+            SummonerSpellKind::Cleanse => 240.0,
+        }
+    }
+
+    /// 未挂技能资产时的默认施法距离，0 表示不校验距离。
+    pub fn default_range(&self) -> f32 {
+        match self {
+            SummonerSpellKind::Flash => FLASH_RANGE,
+            SummonerSpellKind::Ignite => 600.0,
+            SummonerSpellKind::Heal => 850.0,
+            SummonerSpellKind::Exhaust => 650.0,
+            SummonerSpellKind::Smite => 500.0,
+            _ => 0.0,
+        }
+    }
+
+    /// 施法方式：目标校验复用技能管线的 [`resolve_skill_target`]。
+    pub fn targeting(&self) -> SkillTargeting {
+        match self {
+            SummonerSpellKind::Flash => SkillTargeting::Point,
+            SummonerSpellKind::Ignite | SummonerSpellKind::Exhaust | SummonerSpellKind::Smite => {
+                SkillTargeting::Unit(SkillTargetFilter::Enemy)
+            }
+            SummonerSpellKind::Teleport => SkillTargeting::Unit(SkillTargetFilter::Ally),
+            SummonerSpellKind::Heal
+            | SummonerSpellKind::Barrier
+            | SummonerSpellKind::Ghost
+            | SummonerSpellKind::Cleanse => SkillTargeting::SelfCast,
+        }
+    }
+
+    /// 点选目标是否须为英雄：Some(true) 仅英雄，Some(false) 仅非英雄（野怪 / 小兵 / 建筑），None 不限。
+    fn requires_champion_target(&self) -> Option<bool> {
+        match self {
+            SummonerSpellKind::Ignite | SummonerSpellKind::Exhaust => Some(true),
+            SummonerSpellKind::Smite | SummonerSpellKind::Teleport => Some(false),
+            _ => None,
+        }
+    }
+
+    /// 硬控（眩晕 / 沉默 / 嘲讽等）期间是否仍可施放。只有净化可以，压制下也不行。
+    fn usable_while_blocked(&self) -> bool {
+        matches!(self, SummonerSpellKind::Cleanse)
+    }
+}
+
+/// 单个召唤师技能槽位。
+#[derive(Reflect, Debug, Clone)]
+pub struct SummonerSpell {
+    pub kind: SummonerSpellKind,
+    /// 技能资产；已加载时冷却与施法距离以资产为准，否则用 [`SummonerSpellKind`] 的默认值
+    pub spell: Option<Handle<Spell>>,
+    pub cooldown: Option<Timer>,
+}
+
+impl SummonerSpell {
+    pub fn new(kind: SummonerSpellKind) -> Self {
+        Self {
+            kind,
+            spell: None,
+            cooldown: None,
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.cooldown.as_ref().is_none_or(|t| t.is_finished())
+    }
+
+    /// 剩余冷却秒数，就绪时为 0。
+    pub fn remaining_secs(&self) -> f32 {
+        self.cooldown
+            .as_ref()
+            .filter(|t| !t.is_finished())
+            .map(|t| t.remaining_secs())
+            .unwrap_or(0.0)
+    }
+
+    pub fn start_cooldown(&mut self, duration: f32) {
+        self.cooldown = Some(Timer::from_seconds(duration, TimerMode::Once));
+    }
+
+    fn cooldown_secs(&self, spells: Option<&Assets<Spell>>) -> f32 {
+        self.spell_data(spells)
+            .and_then(|d| d.cooldown_time.as_ref()?.first().copied())
+            .unwrap_or(self.kind.default_cooldown())
+    }
+
+    fn range(&self, spells: Option<&Assets<Spell>>) -> f32 {
+        self.spell_data(spells)
+            .and_then(|d| d.cast_range.as_ref()?.first().copied())
+            .unwrap_or(self.kind.default_range())
+    }
+
+    fn spell_data<'a>(
+        &self,
+        spells: Option<&'a Assets<Spell>>,
+    ) -> Option<&'a lol_base::spell::DataSpell> {
+        spells?.get(self.spell.as_ref()?)?.spell_data.as_ref()
+    }
+}
+
+/// 英雄的两个召唤师技能槽位（默认闪现 + 点燃）。
+///
+/// 插入时按 [`SummonerSpellKind::asset_path`] 补挂技能资产，冷却与施法距离以技能数据为准。
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct SummonerSpells {
+    pub slots: [SummonerSpell; SUMMONER_SLOT_COUNT],
+}
+
+impl Default for SummonerSpells {
+    fn default() -> Self {
+        Self::new(SummonerSpellKind::Flash, SummonerSpellKind::Ignite)
+    }
+}
+
+impl SummonerSpells {
+    pub fn new(d: SummonerSpellKind, f: SummonerSpellKind) -> Self {
+        Self {
+            slots: [SummonerSpell::new(d), SummonerSpell::new(f)],
+        }
+    }
+
+    pub fn get(&self, slot: usize) -> Option<&SummonerSpell> {
+        self.slots.get(slot)
+    }
+
+    /// 查找某种召唤师技能所在槽位。
+    pub fn find(&self, kind: SummonerSpellKind) -> Option<usize> {
+        self.slots.iter().position(|s| s.kind == kind)
+    }
+
+    pub fn reset_cooldowns(&mut self) {
+        for slot in self.slots.iter_mut() {
+            slot.cooldown = None;
+        }
+    }
+}
+
+/// 对局 / 环境配置中为英雄选择的召唤师技能（D / F），插入后替换为对应的 [`SummonerSpells`]。
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct ConfigSummonerSpells {
+    pub d: SummonerSpellKind,
+    pub f: SummonerSpellKind,
+}
+
+/// 施放召唤师技能命令。
+#[derive(EntityEvent, Debug, Clone)]
+pub struct CommandSummonerSpell {
+    pub entity: Entity,
+    pub slot: usize,
+    pub target: SkillTarget,
+}
+
+/// 召唤师技能施放成功（已进入冷却）。
+#[derive(EntityEvent, Debug, Clone)]
+pub struct EventSummonerSpellCast {
+    pub entity: Entity,
+    pub slot: usize,
+    pub kind: SummonerSpellKind,
+    pub target: SkillTarget,
+    pub point: Vec2,
+}
+
+/// 召唤师技能施加的 debuff 标记：净化 / 水银可一并解除（点燃、虚弱及其重伤）。
+#[derive(Component, Default)]
+pub struct SummonerDebuff;
+
+/// 点燃：每秒结算一次真实伤害。
+#[derive(Component, Debug, Clone)]
+#[require(Buff = Buff { name: "SummonerIgnite" }, SummonerDebuff)]
+pub struct DebuffIgnite {
+    pub source: Entity,
+    pub damage_per_tick: f32,
+    pub remaining_ticks: u32,
+    pub tick: Timer,
+}
+
+impl DebuffIgnite {
+    pub fn new(source: Entity, total_damage: f32, duration_secs: u32) -> Self {
+        let ticks = duration_secs.max(1);
+        Self {
+            source,
+            damage_per_tick: total_damage / ticks as f32,
+            remaining_ticks: ticks,
+            tick: Timer::from_seconds(1.0, TimerMode::Repeating),
+        }
+    }
+}
+
+/// 传送引导：引导结束时若目标仍存活，施法者移动到目标位置。
+/// 引导期间受到伤害、自主移动或被控制（减速等不封锁行动的除外）都会打断。
+#[derive(Component, Debug, Clone)]
+#[require(Buff = Buff { name: "SummonerTeleport" })]
+pub struct BuffTeleportChannel {
+    pub target: Entity,
+    pub timer: Timer,
+    /// 引导附带的施法锁 buff，打断时一并撤除
+    pub cast_block: Entity,
+}
+
+/// 按 1-18 级在 `[min, max]` 间线性插值，召唤师技能数值均按此随等级成长。
+fn level_lerp(level: u32, min: f32, max: f32) -> f32 {
+    let t = (level.clamp(1, 18) - 1) as f32 / 17.0;
+    min + (max - min) * t
+}
+
+fn on_command_summoner_spell(
+    trigger: On<CommandSummonerSpell>,
+    mut commands: Commands,
+    mut q_caster: Query<(&mut SummonerSpells, Option<&Level>, Option<&Buffs>)>,
+    q_blocked: Query<(), Or<(With<CastBlock>, With<ForcedAction>)>>,
+    q_suppression: Query<(), With<DebuffSuppression>>,
+    q_death: Query<(), With<Death>>,
+    q_unit: Query<(&Transform, Option<&Team>, Has<Untargetable>)>,
+    q_champion: Query<(), With<Champion>>,
+    q_allies: Query<(Entity, &Transform, &Team, &Health), (With<Champion>, Without<Death>)>,
+    res_spells: Option<Res<Assets<Spell>>>,
+    no_cooldown: Option<Res<NoCooldown>>,
+    res_grid: Option<Res<ResourceGrid>>,
+    assets_grid: Option<Res<Assets<ConfigNavigationGrid>>>,
+) {
+    let entity = trigger.event_target();
+    let slot = trigger.slot;
+
+    let Ok((mut summoners, level, buffs)) = q_caster.get_mut(entity) else {
+        debug!("{:?} 没有召唤师技能", entity);
+        return;
+    };
+    let Some(spell) = summoners.slots.get(slot) else {
+        debug!("{:?} 召唤师技能槽位 {} 不存在", entity, slot);
+        return;
+    };
+    let kind = spell.kind;
+
+    let fail = |reason: SkillCastFailureReason| {
+        debug!("{:?} 施放召唤师技能 {:?} 失败: {:?}", entity, kind, reason);
+    };
+
+    if q_death.get(entity).is_ok() {
+        return fail(SkillCastFailureReason::CasterDead);
+    }
+    let suppressed = buffs.is_some_and(|b| b.iter().any(|e| q_suppression.get(e).is_ok()));
+    if suppressed || (q_blocked.get(entity).is_ok() && !kind.usable_while_blocked()) {
+        return fail(SkillCastFailureReason::Blocked);
+    }
+    if !spell.is_ready() {
+        return fail(SkillCastFailureReason::CoolingDown);
+    }
+
+    let spells = res_spells.as_deref();
+    let range = spell.range(spells);
+    let cooldown = spell.cooldown_secs(spells);
+
+    let (caster_pos, caster_team) = q_unit
+        .get(entity)
+        .map(|(transform, team, _)| (transform.translation.xz(), team.copied()))
+        .unwrap_or_default();
+    // 闪现按最大距离截断而非拒绝，其余技能按施法距离校验
+    let cast_range = match kind {
+        SummonerSpellKind::Flash => None,
+        _ => Some(range),
+    };
+    let (target, point) = match resolve_skill_target(
+        kind.targeting(),
+        trigger.target,
+        entity,
+        caster_pos,
+        caster_team,
+        cast_range,
+        |target| {
+            let (transform, team, untargetable) = q_unit.get(target).ok()?;
+            Some(SkillTargetInfo {
+                position: transform.translation.xz(),
+                team: team.copied(),
+                dead: q_death.get(target).is_ok(),
+                untargetable,
+            })
+        },
+    ) {
+        Ok(resolved) => resolved,
+        Err(reason) => return fail(reason),
+    };
+    if let (SkillTarget::Unit(unit), Some(champion_only)) =
+        (target, kind.requires_champion_target())
+    {
+        if q_champion.get(unit).is_ok() != champion_only {
+            return fail(SkillCastFailureReason::InvalidTarget);
+        }
+    }
+
+    let level = level.map(|l| l.value).unwrap_or(1);
+    match kind {
+        SummonerSpellKind::Flash => {
+            let offset = point - caster_pos;
+            let dir = if offset.length_squared() > 1e-4 {
+                offset.normalize()
+            } else {
+                Vec2::X
+            };
+            let destination = caster_pos + dir * offset.length().min(range);
+            // 可以越墙，但不能落在墙体或地形覆盖层里
+            let grid = res_grid
+                .as_ref()
+                .zip(assets_grid.as_ref())
+                .and_then(|(res_grid, assets_grid)| assets_grid.get(&res_grid.0));
+            let destination = match grid {
+                Some(grid) => blink_landing(grid, caster_pos, destination),
+                None => destination,
+            };
+            commands.entity(entity).queue(move |mut e: EntityWorldMut| {
+                if let Some(mut transform) = e.get_mut::<Transform>() {
+                    transform.translation.x = destination.x;
+                    transform.translation.z = destination.y;
+                }
+            });
+        }
+        SummonerSpellKind::Ignite => {
+            let SkillTarget::Unit(unit) = target else {
+                return;
+            };
+            // 多个来源的点燃各自结算；重伤取最强者，同强度时续期
+            commands.entity(unit).apply_buff(
+                BuffDescriptor::new(BuffStacking::Independent)
+                    .with_max_stacks(u32::MAX)
+                    .with_source(entity)
+                    .with_tags(&[BuffTag::Negative])
+                    .dispellable(),
+                DebuffIgnite::new(entity, level_lerp(level, 70.0, 410.0), 5),
+            );
+            commands.entity(unit).apply_buff(
+                BuffDescriptor::new(BuffStacking::ReplaceIfStronger)
+                    .with_potency(0.4)
+                    .with_source(entity)
+                    .with_tags(&[BuffTag::Negative])
+                    .dispellable(),
+                (DebuffGrievousWounds::new(0.4, 5.0), SummonerDebuff),
+            );
+        }
+        SummonerSpellKind::Heal => {
+            let amount = level_lerp(level, 80.0, 318.0);
+            commands.trigger(CommandHeal {
+                entity,
+                source: entity,
+                heal_type: HealType::Heal,
+                amount,
+            });
+            commands.entity(entity).apply_buff(
+                BuffMoveSpeed::descriptor(1.0).with_source(entity),
+                BuffMoveSpeed::new(0.3),
+            );
+            // 同时治疗施法距离内血量比例最低的一名友方英雄
+            let ally = q_allies
+                .iter()
+                .filter(|(ally, transform, team, _)| {
+                    *ally != entity
+                        && Some(**team) == caster_team
+                        && transform.translation.xz().distance(caster_pos) <= range
+                })
+                .min_by(|(.., a), (.., b)| {
+                    (a.value / a.max.max(1.0)).total_cmp(&(b.value / b.max.max(1.0)))
+                })
+                .map(|(ally, ..)| ally);
+            if let Some(ally) = ally {
+                commands.trigger(CommandHeal {
+                    entity: ally,
+                    source: entity,
+                    heal_type: HealType::Heal,
+                    amount,
+                });
+                commands.entity(ally).apply_buff(
+                    BuffMoveSpeed::descriptor(1.0).with_source(entity),
+                    BuffMoveSpeed::new(0.3),
+                );
+            }
+        }
+        SummonerSpellKind::Barrier => {
+            commands.entity(entity).apply_buff(
+                BuffShieldWhite::descriptor()
+                    .with_source(entity)
+                    .with_duration(2.5),
+                BuffShieldWhite::new(level_lerp(level, 105.0, 411.0)),
+            );
+        }
+        SummonerSpellKind::Exhaust => {
+            let SkillTarget::Unit(unit) = target else {
+                return;
+            };
+            commands
+                .entity(unit)
+                .apply_buff(cc_descriptor(entity), DebuffSlow::new(0.3, 3.0));
+            commands.entity(unit).apply_buff(
+                BuffDescriptor::new(BuffStacking::Renew)
+                    .with_source(entity)
+                    .with_duration(3.0)
+                    .with_tags(&[BuffTag::Negative, BuffTag::Offense])
+                    .dispellable(),
+                (BuffDamageDealtReduction::new(0.4), SummonerDebuff),
+            );
+        }
+        SummonerSpellKind::Ghost => {
+            commands
+                .entity(entity)
+                .apply_buff(
+                    BuffMoveSpeed::descriptor(10.0).with_source(entity),
+                    BuffMoveSpeed::new(level_lerp(level, 0.24, 0.48)),
+                )
+                .apply_buff(
+                    BuffDescriptor::new(BuffStacking::Renew)
+                        .with_source(entity)
+                        .with_tags(&[BuffTag::Positive, BuffTag::Movement]),
+                    BuffGhosted::new(10.0),
+                );
+        }
+        SummonerSpellKind::Teleport => {
+            let SkillTarget::Unit(unit) = target else {
+                return;
+            };
+            let cast_block = commands
+                .spawn((BuffCastBlock::new(TELEPORT_CHANNEL_SECS), BuffOf(entity)))
+                .id();
+            commands.spawn((
+                BuffTeleportChannel {
+                    target: unit,
+                    timer: Timer::from_seconds(TELEPORT_CHANNEL_SECS, TimerMode::Once),
+                    cast_block,
+                },
+                BuffOf(entity),
+            ));
+        }
+        SummonerSpellKind::Smite => {
+            let SkillTarget::Unit(unit) = target else {
+                return;
+            };
+            commands.trigger(CommandDamageCreate {
+                entity: unit,
+                source: entity,
+                damage_type: DamageType::True,
+                amount: SMITE_DAMAGE,
+                tag: None,
+                kind: DamageKind::Other,
+            });
+        }
+        SummonerSpellKind::Cleanse => {
+            commands.trigger(CommandCleanse {
+                entity,
+                kind: CleanseKind::Cleanse,
+            });
+        }
+    }
+
+    if !no_cooldown.is_some_and(|n| n.0) {
+        summoners.slots[slot].start_cooldown(cooldown);
+    }
+    debug!("{:?} 施放召唤师技能 {:?}", entity, kind);
+    commands.trigger(EventSummonerSpellCast {
+        entity,
+        slot,
+        kind,
+        target,
+        point,
+    });
+}
+
+fn on_insert_config_summoner_spells(
+    trigger: On<Insert, ConfigSummonerSpells>,
+    mut commands: Commands,
+    q_config: Query<&ConfigSummonerSpells>,
+) {
+    let entity = trigger.event_target();
+    let Ok(config) = q_config.get(entity) else {
+        return;
+    };
+    commands
+        .entity(entity)
+        .insert(SummonerSpells::new(config.d, config.f))
+        .remove::<ConfigSummonerSpells>();
+}
+
+/// 给未挂资产的槽位加载导出的技能数据；无资产服务器（无头测试）时沿用默认数值。
+fn on_insert_summoner_spells_load_data(
+    trigger: On<Insert, SummonerSpells>,
+    mut commands: Commands,
+    q_summoners: Query<&SummonerSpells>,
+    asset_server: Option<Res<AssetServer>>,
+) {
+    let entity = trigger.event_target();
+    let (Ok(summoners), Some(asset_server)) = (q_summoners.get(entity), asset_server) else {
+        return;
+    };
+    let handles: Vec<_> = summoners
+        .slots
+        .iter()
+        .map(|slot| {
+            slot.spell
+                .is_none()
+                .then(|| asset_server.load::<Spell>(slot.kind.asset_path()))
+        })
+        .collect();
+    if handles.iter().all(Option::is_none) {
+        return;
+    }
+    // 原地写入而非重新插入，避免再次触发本观察者
+    commands.entity(entity).queue(move |mut e: EntityWorldMut| {
+        let Some(mut summoners) = e.get_mut::<SummonerSpells>() else {
+            return;
+        };
+        for (slot, handle) in summoners.slots.iter_mut().zip(handles) {
+            if let Some(handle) = handle {
+                slot.spell = Some(handle);
+            }
+        }
+    });
+}
+
+/// 净化 / 水银同时解除召唤师技能 debuff。
+fn on_cleanse_summoner_debuffs(
+    trigger: On<CommandCleanse>,
+    mut commands: Commands,
+    q_buffs: Query<&Buffs>,
+    q_summoner_debuff: Query<(), With<SummonerDebuff>>,
+) {
+    let Ok(buffs) = q_buffs.get(trigger.event_target()) else {
+        return;
+    };
+    for buff_entity in buffs.iter() {
+        if q_summoner_debuff.get(buff_entity).is_ok() {
+            commands.entity(buff_entity).despawn();
+        }
+    }
+}
+
+fn tick_summoner_cooldowns(
+    time: Res<Time<Fixed>>,
+    no_cooldown: Res<NoCooldown>,
+    mut q_summoners: Query<&mut SummonerSpells>,
+) {
+    for mut summoners in q_summoners.iter_mut() {
+        for slot in summoners.slots.iter_mut() {
+            if no_cooldown.0 {
+                slot.cooldown = None;
+                continue;
+            }
+            if let Some(timer) = slot.cooldown.as_mut() {
+                timer.tick(time.delta());
+                if timer.is_finished() {
+                    slot.cooldown = None;
+                }
+            }
+        }
+    }
+}
+
+/// 点燃计时：每跳结算一次真实伤害，跳数用尽后销毁。
+fn update_ignite(
+    mut commands: Commands,
+    mut q_ignite: Query<(Entity, &BuffOf, &mut DebuffIgnite)>,
+    time: Res<Time<Fixed>>,
+) {
+    for (buff_entity, buff_of, mut ignite) in q_ignite.iter_mut() {
+        ignite.tick.tick(time.delta());
+        let ticks = ignite
+            .tick
+            .times_finished_this_tick()
+            .min(ignite.remaining_ticks);
+        for _ in 0..ticks {
+            commands.trigger(CommandDamageCreate {
+                entity: buff_of.0,
+                source: ignite.source,
+                damage_type: DamageType::True,
+                amount: ignite.damage_per_tick,
+                tag: None,
+                kind: DamageKind::Other,
+            });
+        }
+        ignite.remaining_ticks -= ticks;
+        if ignite.remaining_ticks == 0 {
+            commands.entity(buff_entity).despawn();
+        }
+    }
+}
+
+/// 传送引导结束：施法者存活且目标仍在时落到目标位置。
+fn update_teleport_channel(
+    mut commands: Commands,
+    mut q_channel: Query<(Entity, &BuffOf, &mut BuffTeleportChannel)>,
+    mut q_transform: Query<&mut Transform>,
+    q_death: Query<(), With<Death>>,
+    time: Res<Time<Fixed>>,
+) {
+    for (buff_entity, buff_of, mut channel) in q_channel.iter_mut() {
+        channel.timer.tick(time.delta());
+        if !channel.timer.is_finished() {
+            continue;
+        }
+        commands.entity(buff_entity).despawn();
+
+        let caster = buff_of.0;
+        if q_death.get(caster).is_ok() || q_death.get(channel.target).is_ok() {
+            continue;
+        }
+        let Ok(destination) = q_transform.get(channel.target).map(|t| t.translation) else {
+            continue;
+        };
+        if let Ok(mut transform) = q_transform.get_mut(caster) {
+            transform.translation.x = destination.x;
+            transform.translation.z = destination.z;
+        }
+    }
+}
+
+/// 打断 `caster` 正在进行的传送引导（连同引导的施法锁）。
+fn interrupt_teleport(
+    commands: &mut Commands,
+    caster: Entity,
+    q_buffs: &Query<&Buffs>,
+    q_channel: &Query<&BuffTeleportChannel>,
+) {
+    let Ok(buffs) = q_buffs.get(caster) else {
+        return;
+    };
+    for buff in buffs.iter() {
+        let Ok(channel) = q_channel.get(buff) else {
+            continue;
+        };
+        debug!("{:?} 传送引导被打断", caster);
+        commands.entity(channel.cast_block).try_despawn();
+        commands.entity(buff).despawn();
+    }
+}
+
+fn on_teleport_interrupt_damage(
+    trigger: On<EventDamageCreate>,
+    mut commands: Commands,
+    q_buffs: Query<&Buffs>,
+    q_channel: Query<&BuffTeleportChannel>,
+) {
+    if trigger.damage_result.final_damage <= 0.0 {
+        return;
+    }
+    interrupt_teleport(&mut commands, trigger.event_target(), &q_buffs, &q_channel);
+}
+
+/// 自主移动指令打断引导；指令本身照常进入移动仲裁，施法锁撤除后即开始行走。
+fn on_teleport_interrupt_movement(
+    trigger: On<CommandMovement>,
+    mut commands: Commands,
+    q_buffs: Query<&Buffs>,
+    q_channel: Query<&BuffTeleportChannel>,
+) {
+    let MovementAction::Start { source, .. } = &trigger.action else {
+        return;
+    };
+    if !matches!(
+        source,
+        MovementSource::Player
+            | MovementSource::AI
+            | MovementSource::Run
+            | MovementSource::Pathfind
+    ) {
+        return;
+    }
+    interrupt_teleport(&mut commands, trigger.event_target(), &q_buffs, &q_channel);
+}
+
+/// 封锁行动的控制打断引导（减速、致盲等不打断）。
+fn on_teleport_interrupt_cc(
+    trigger: On<Add, CrowdControl>,
+    mut commands: Commands,
+    q_cc: Query<(&CrowdControl, &BuffOf)>,
+    q_buffs: Query<&Buffs>,
+    q_channel: Query<&BuffTeleportChannel>,
+) {
+    let Ok((cc, buff_of)) = q_cc.get(trigger.event_target()) else {
+        return;
+    };
+    let blocks = cc.0.blocks();
+    if !(blocks.movement || blocks.cast || blocks.dash) {
+        return;
+    }
+    interrupt_teleport(&mut commands, buff_of.0, &q_buffs, &q_channel);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::base::buff::PluginBuff;
+    use crate::buffs::cc_debuffs::{DebuffStun, PluginCc};
+    use crate::buffs::common_buffs::PluginCommonBuffs;
+    use crate::damage::PluginDamage;
+    use crate::heal::PluginHeal;
+    use crate::life::PluginLife;
+    use crate::lifetime::PluginLifetime;
+    use crate::skill::PluginSkill;
+
+    fn app_with_summoners() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AssetPlugin::default());
+        app.init_asset::<Spell>();
+        app.add_plugins((
+            PluginBuff,
+            PluginSkill,
+            PluginDamage,
+            PluginHeal,
+            PluginLife,
+            PluginCc,
+            PluginCommonBuffs,
+            PluginLifetime,
+            PluginSummonerSpell,
+        ));
+        app.insert_resource(Time::<Fixed>::from_hz(30.0));
+        app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+        app
+    }
+
+    fn spawn_champion(app: &mut App, team: Team, x: f32, summoners: SummonerSpells) -> Entity {
+        app.world_mut()
+            .spawn((
+                Champion,
+                team,
+                Transform::from_xyz(x, 0.0, 0.0),
+                Health::new(1000.0),
+                summoners,
+            ))
+            .id()
+    }
+
+    fn cast(app: &mut App, entity: Entity, slot: usize, target: SkillTarget) {
+        app.world_mut().trigger(CommandSummonerSpell {
+            entity,
+            slot,
+            target,
+        });
+        app.update();
+    }
+
+    fn step(app: &mut App, frames: usize) {
+        for _ in 0..frames {
+            app.update();
+        }
+    }
+
+    #[test]
+    fn flash_blinks_up_to_max_range_and_goes_on_cooldown() {
+        let mut app = app_with_summoners();
+        let caster = spawn_champion(&mut app, Team::Order, 0.0, SummonerSpells::default());
+
+        cast(
+            &mut app,
+            caster,
+            0,
+            SkillTarget::Point(Vec2::new(1000.0, 0.0)),
+        );
+        let x = app.world().get::<Transform>(caster).unwrap().translation.x;
+        assert!(
+            (x - FLASH_RANGE).abs() < 1e-3,
+            "闪现应截断到最大距离，实际 {x}"
+        );
+        let summoners = app.world().get::<SummonerSpells>(caster).unwrap();
+        assert!(!summoners.slots[0].is_ready());
+        assert!(summoners.slots[0].remaining_secs() > 290.0);
+
+        // 冷却中再次施放无效
+        cast(&mut app, caster, 0, SkillTarget::Point(Vec2::new(0.0, 0.0)));
+        let x = app.world().get::<Transform>(caster).unwrap().translation.x;
+        assert!((x - FLASH_RANGE).abs() < 1e-3);
+    }
+
+    #[test]
+    fn ignite_deals_true_damage_over_time_and_applies_grievous_wounds() {
+        let mut app = app_with_summoners();
+        let caster = spawn_champion(&mut app, Team::Order, 0.0, SummonerSpells::default());
+        let enemy = spawn_champion(&mut app, Team::Chaos, 300.0, SummonerSpells::default());
+
+        cast(&mut app, caster, 1, SkillTarget::Unit(enemy));
+        let buffs = app.world().get::<Buffs>(enemy).expect("应有 Buffs");
+        assert!(
+            buffs
+                .iter()
+                .any(|b| app.world().get::<DebuffGrievousWounds>(b).is_some()),
+            "点燃应施加重伤"
+        );
+
+        // 5 秒（150 帧）后 1 级点燃共造成 70 真实伤害
+        step(&mut app, 160);
+        let health = app.world().get::<Health>(enemy).unwrap().value;
+        assert!((health - 930.0).abs() < 1e-2, "实际生命 {health}");
+    }
+
+    #[test]
+    fn config_selects_summoners_and_slots_load_spell_data() {
+        let mut app = app_with_summoners();
+        let caster = app
+            .world_mut()
+            .spawn((
+                Champion,
+                ConfigSummonerSpells {
+                    d: SummonerSpellKind::Flash,
+                    f: SummonerSpellKind::Exhaust,
+                },
+            ))
+            .id();
+        app.update();
+
+        let summoners = app.world().get::<SummonerSpells>(caster).unwrap();
+        assert_eq!(summoners.find(SummonerSpellKind::Exhaust), Some(1));
+        assert!(summoners.find(SummonerSpellKind::Ignite).is_none());
+        assert!(
+            summoners.slots.iter().all(|slot| slot.spell.is_some()),
+            "槽位应挂上技能资产"
+        );
+        assert!(app.world().get::<ConfigSummonerSpells>(caster).is_none());
+    }
+
+    #[test]
+    fn exhaust_is_a_dispellable_timed_debuff() {
+        let mut app = app_with_summoners();
+        let caster = spawn_champion(
+            &mut app,
+            Team::Order,
+            0.0,
+            SummonerSpells::new(SummonerSpellKind::Flash, SummonerSpellKind::Exhaust),
+        );
+        let enemy = spawn_champion(&mut app, Team::Chaos, 300.0, SummonerSpells::default());
+
+        cast(&mut app, caster, 1, SkillTarget::Unit(enemy));
+        app.update();
+        let weaken = app
+            .world()
+            .get::<Buffs>(enemy)
+            .unwrap()
+            .iter()
+            .find(|b| app.world().get::<BuffDamageDealtReduction>(*b).is_some())
+            .expect("虚弱应施加输出降低");
+        let descriptor = app.world().get::<BuffDescriptor>(weaken).unwrap();
+        assert_eq!(descriptor.source, Some(caster));
+        assert!(descriptor.dispellable && descriptor.has_tag(BuffTag::Negative));
+
+        step(&mut app, 100);
+        assert!(app.world().get_entity(weaken).is_err(), "3 秒后虚弱应到期");
+    }
+
+    #[test]
+    fn ignite_rejects_out_of_range_and_non_champion_targets() {
+        let mut app = app_with_summoners();
+        let caster = spawn_champion(&mut app, Team::Order, 0.0, SummonerSpells::default());
+        let far = spawn_champion(&mut app, Team::Chaos, 900.0, SummonerSpells::default());
+        let minion = app
+            .world_mut()
+            .spawn((
+                Team::Chaos,
+                Transform::from_xyz(100.0, 0.0, 0.0),
+                Health::new(500.0),
+            ))
+            .id();
+
+        cast(&mut app, caster, 1, SkillTarget::Unit(far));
+        cast(&mut app, caster, 1, SkillTarget::Unit(minion));
+        let summoners = app.world().get::<SummonerSpells>(caster).unwrap();
+        assert!(summoners.slots[1].is_ready(), "失败的施放不应进入冷却");
+    }
+
+    #[test]
+    fn cleanse_is_usable_while_stunned_and_removes_ignite() {
+        let mut app = app_with_summoners();
+        let caster = spawn_champion(
+            &mut app,
+            Team::Order,
+            0.0,
+            SummonerSpells::new(SummonerSpellKind::Flash, SummonerSpellKind::Cleanse),
+        );
+        let enemy = spawn_champion(&mut app, Team::Chaos, 300.0, SummonerSpells::default());
+        cast(&mut app, enemy, 1, SkillTarget::Unit(caster));
+        app.world_mut()
+            .entity_mut(caster)
+            .with_related::<BuffOf>(DebuffStun::new(2.0));
+        step(&mut app, 2);
+        assert!(app.world().get::<CastBlock>(caster).is_some());
+
+        // 眩晕中闪现无效，净化可用
+        cast(
+            &mut app,
+            caster,
+            0,
+            SkillTarget::Point(Vec2::new(300.0, 0.0)),
+        );
+        assert!(app.world().get::<SummonerSpells>(caster).unwrap().slots[0].is_ready());
+        cast(&mut app, caster, 1, SkillTarget::SelfCast);
+        step(&mut app, 2);
+
+        assert!(
+            app.world().get::<CastBlock>(caster).is_none(),
+            "净化应解除眩晕"
+        );
+        let buffs = app.world().get::<Buffs>(caster);
+        let has_ignite = buffs.is_some_and(|b| {
+            b.iter().any(|e| {
+                app.world().get::<DebuffIgnite>(e).is_some()
+                    || app.world().get::<DebuffGrievousWounds>(e).is_some()
+            })
+        });
+        assert!(!has_ignite, "净化应解除点燃与重伤");
+    }
+
+    #[test]
+    fn barrier_absorbs_damage_then_expires() {
+        let mut app = app_with_summoners();
+        let caster = spawn_champion(
+            &mut app,
+            Team::Order,
+            0.0,
+            SummonerSpells::new(SummonerSpellKind::Flash, SummonerSpellKind::Barrier),
+        );
+        cast(&mut app, caster, 1, SkillTarget::SelfCast);
+        app.world_mut().trigger(CommandDamageCreate {
+            entity: caster,
+            source: caster,
+            damage_type: DamageType::Physical,
+            amount: 100.0,
+            tag: None,
+            kind: DamageKind::Other,
+        });
+        step(&mut app, 2);
+        let health = app.world().get::<Health>(caster).unwrap().value;
+        assert!(
+            (health - 1000.0).abs() < 1e-2,
+            "护盾应吸收伤害，实际 {health}"
+        );
+
+        step(&mut app, 90);
+        let buffs = app.world().get::<Buffs>(caster);
+        let has_shield = buffs.is_some_and(|b| {
+            b.iter()
+                .any(|e| app.world().get::<BuffShieldWhite>(e).is_some())
+        });
+        assert!(!has_shield, "屏障 2.5 秒后应消失");
+    }
+
+    #[test]
+    fn teleport_channel_is_interrupted_by_damage_and_cc() {
+        let has_channel = |app: &App, entity: Entity| {
+            app.world().get::<Buffs>(entity).is_some_and(|b| {
+                b.iter()
+                    .any(|e| app.world().get::<BuffTeleportChannel>(e).is_some())
+            })
+        };
+        let teleport =
+            || SummonerSpells::new(SummonerSpellKind::Flash, SummonerSpellKind::Teleport);
+
+        // 受到伤害打断，施法锁一并撤除
+        let mut app = app_with_summoners();
+        let caster = spawn_champion(&mut app, Team::Order, 0.0, teleport());
+        let ally = spawn_champion(&mut app, Team::Order, 3000.0, SummonerSpells::default());
+        cast(&mut app, caster, 1, SkillTarget::Unit(ally));
+        assert!(has_channel(&app, caster));
+        app.world_mut().trigger(CommandDamageCreate {
+            entity: caster,
+            source: caster,
+            damage_type: DamageType::True,
+            amount: 50.0,
+            tag: None,
+            kind: DamageKind::Other,
+        });
+        step(&mut app, 2);
+        assert!(!has_channel(&app, caster), "受到伤害应打断传送");
+        assert!(app.world().get::<CastBlock>(caster).is_none());
+        step(&mut app, 150);
+        let x = app.world().get::<Transform>(caster).unwrap().translation.x;
+        assert!(x.abs() < 1e-3, "被打断的传送不应落地，实际 {x}");
+
+        // 眩晕打断，减速不打断
+        let mut app = app_with_summoners();
+        let caster = spawn_champion(&mut app, Team::Order, 0.0, teleport());
+        let ally = spawn_champion(&mut app, Team::Order, 3000.0, SummonerSpells::default());
+        cast(&mut app, caster, 1, SkillTarget::Unit(ally));
+        app.world_mut()
+            .entity_mut(caster)
+            .with_related::<BuffOf>(DebuffSlow::new(0.3, 1.0));
+        step(&mut app, 2);
+        assert!(has_channel(&app, caster), "减速不应打断传送");
+        app.world_mut()
+            .entity_mut(caster)
+            .with_related::<BuffOf>(DebuffStun::new(0.5));
+        step(&mut app, 2);
+        assert!(!has_channel(&app, caster), "眩晕应打断传送");
+    }
+}
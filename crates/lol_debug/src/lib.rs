@@ -5,16 +5,9 @@ use lol_champions::riven::Riven;
 use lol_core::entities::champion::Champion;
 use lol_core::team::Team;
 use lol_render::camera::CameraState;
-use lol_rpc::{CommandWsRequest as TypedCommandWsRequest, RpcAppExt};
+use lol_rpc::{CommandWsRequest as TypedCommandWsRequest, RpcAppExt, cmd};
 use lol_server::server::send_event;
 
-mod params;
-#[cfg(debug_assertions)]
-pub use params::{
-    GetStateParams, GetTimeParams, GodModeParams, ResetPositionParams, SetSpeedParams,
-    SwitchChampionParams, ToggleCooldownParams, TogglePauseParams,
-};
-
 pub struct PluginDebug;
 
 impl Plugin for PluginDebug {
@@ -38,14 +31,14 @@ impl Plugin for PluginDebug {
         #[cfg(debug_assertions)]
         {
             // 注册 debug 面 RPC 命令
-            app.register_rpc::<SwitchChampionParams>("switch_champion");
-            app.register_rpc::<GodModeParams>("god_mode");
-            app.register_rpc::<ToggleCooldownParams>("toggle_cooldown");
-            app.register_rpc::<ResetPositionParams>("reset_position");
-            app.register_rpc::<TogglePauseParams>("toggle_pause");
-            app.register_rpc::<SetSpeedParams>("set_speed");
-            app.register_rpc::<GetStateParams>("get_state");
-            app.register_rpc::<GetTimeParams>("get_time");
+            app.register_rpc::<cmd::SwitchChampion>();
+            app.register_rpc::<cmd::GodMode>();
+            app.register_rpc::<cmd::ToggleCooldown>();
+            app.register_rpc::<cmd::ResetPosition>();
+            app.register_rpc::<cmd::TogglePause>();
+            app.register_rpc::<cmd::SetSpeed>();
+            app.register_rpc::<cmd::GetState>();
+            app.register_rpc::<cmd::GetTime>();
 
             app.add_observer(on_switch_champion)
                 .add_observer(on_god_mode)
@@ -150,7 +143,7 @@ fn on_d_key_select_entity(
 // ── Typed Debug Observers ──
 
 fn on_switch_champion(
-    event: On<TypedCommandWsRequest<SwitchChampionParams>>,
+    event: On<TypedCommandWsRequest<cmd::SwitchChampion>>,
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     champions: Query<(Entity, &Champion, Option<&Name>)>,
@@ -195,7 +188,7 @@ fn on_switch_champion(
 }
 
 fn on_god_mode(
-    event: On<TypedCommandWsRequest<GodModeParams>>,
+    event: On<TypedCommandWsRequest<cmd::GodMode>>,
     mut debug_state: ResMut<GlobalDebugState>,
     god_mode: Option<ResMut<lol_core::skill::GodMode>>,
     no_cooldown: Option<ResMut<lol_core::skill::NoCooldown>>,
@@ -215,7 +208,7 @@ fn on_god_mode(
 }
 
 fn on_toggle_cooldown(
-    event: On<TypedCommandWsRequest<ToggleCooldownParams>>,
+    event: On<TypedCommandWsRequest<cmd::ToggleCooldown>>,
     mut debug_state: ResMut<GlobalDebugState>,
     no_cooldown: Option<ResMut<lol_core::skill::NoCooldown>>,
 ) {
@@ -228,7 +221,7 @@ fn on_toggle_cooldown(
 }
 
 fn on_reset_position(
-    event: On<TypedCommandWsRequest<ResetPositionParams>>,
+    event: On<TypedCommandWsRequest<cmd::ResetPosition>>,
     champions: Query<(Entity, &Champion, Option<&Name>)>,
     mut transforms: Query<&mut Transform>,
 ) {
@@ -248,7 +241,7 @@ fn on_reset_position(
 }
 
 fn on_toggle_pause(
-    event: On<TypedCommandWsRequest<TogglePauseParams>>,
+    event: On<TypedCommandWsRequest<cmd::TogglePause>>,
     mut debug_state: ResMut<GlobalDebugState>,
     mut time: ResMut<Time<Virtual>>,
 ) {
@@ -258,14 +251,14 @@ fn on_toggle_pause(
     lol_rpc::respond(&event, Ok(serde_json::json!({"paused": paused})));
 }
 
-fn on_set_speed(event: On<TypedCommandWsRequest<SetSpeedParams>>, mut time: ResMut<Time<Virtual>>) {
+fn on_set_speed(event: On<TypedCommandWsRequest<cmd::SetSpeed>>, mut time: ResMut<Time<Virtual>>) {
     let speed = event.params.speed;
     time.set_relative_speed(speed);
     lol_rpc::respond(&event, Ok(serde_json::json!({"speed": speed})));
 }
 
 fn on_get_state(
-    event: On<TypedCommandWsRequest<GetStateParams>>,
+    event: On<TypedCommandWsRequest<cmd::GetState>>,
     champions: Query<(Entity, &Champion, Option<&Name>)>,
     debug_state: Res<GlobalDebugState>,
 ) {
@@ -286,7 +279,7 @@ fn on_get_state(
     );
 }
 
-fn on_get_time(event: On<TypedCommandWsRequest<GetTimeParams>>, time: Res<Time>) {
+fn on_get_time(event: On<TypedCommandWsRequest<cmd::GetTime>>, time: Res<Time>) {
     lol_rpc::respond(
         &event,
         Ok(serde_json::json!({ "time": time.elapsed_secs() })),
@@ -11,11 +11,13 @@ use lol_champions::fiora::Fiora;
 use lol_champions::fiora::passive::Vital;
 use lol_champions::riven::Riven;
 use lol_core::base::direction::Direction;
+use lol_core::base::stats::ChampionStats;
 use lol_core::character::CharacterReady;
 use lol_core::damage::{DamageType, EventDamageCreate};
 use lol_core::life::Health;
 use lol_core::navigation::navigation::NavigationDebug;
 use lol_core::skill::{CoolDown, Skill, SkillRecastWindow, Skills, is_skill_ready};
+use lol_core::summoner::ConfigSummonerSpells;
 use lol_core::team::Team;
 
 use crate::reward::{FioraRewardContext, FioraVsRivenRewardModel, RewardModel};
@@ -41,6 +43,10 @@ impl Default for ChampionInitialSkillLevels {
     }
 }
 
+/// 环境配置的召唤师技能选择（键为小写英雄名），每次生成英雄实体时挂上
+#[derive(Resource, Debug, Clone, Default)]
+pub struct EnvSummonerSpells(pub HashMap<String, ConfigSummonerSpells>);
+
 /// 剑姬 vs 瑞雯对战环境的公共 ECS 引擎基底。
 /// 封装完整的 Bevy App 实例、实体句柄与生命周期管理。
 pub struct FioraRivenBaseEnv {
@@ -464,6 +470,8 @@ impl FioraRivenEnvBuilder {
             (fc, rc, fs, rs)
         };
 
+        app.insert_resource(EnvSummonerSpells(self.config.summoner_spells.clone()));
+
         // 生成英雄实体
         let (fiora, riven) = spawn_champions_world(
             app.world_mut(),
@@ -857,6 +865,14 @@ pub fn spawn_champions_world(
 
     let riven = riven_builder.id();
 
+    if let Some(summoners) = world.get_resource::<EnvSummonerSpells>().cloned() {
+        for (name, entity) in [("fiora", fiora), ("riven", riven)] {
+            if let Some(config) = summoners.0.get(name) {
+                world.entity_mut(entity).insert(*config);
+            }
+        }
+    }
+
     (fiora, riven)
 }
 
@@ -945,6 +961,14 @@ pub fn setup_custom_skill_levels_world(
 }
 
 /// Helper function to check if a 3D position is aligned with the vital's direction quadrant relative to target.
+/// 读取英雄的 (助攻数, 累计赏金)，用于计算单步增量。
+pub fn assist_bounty_snapshot(world: &World, entity: Entity) -> (f32, f32) {
+    world
+        .get::<ChampionStats>(entity)
+        .map(|stats| (stats.assists as f32, stats.bounty_gold))
+        .unwrap_or_default()
+}
+
 pub fn is_position_aligned_with_vital(fpos: Vec3, rpos: Vec3, obs: &FioraVsRivenObs) -> bool {
     let delta_x = fpos.x - rpos.x;
     let delta_z = fpos.z - rpos.z;
@@ -1021,7 +1045,7 @@ pub fn unpause_virtual_time(world: &mut World) {
 #[cfg(test)]
 mod tests {
     use bevy::prelude::{App, Vec3};
-    use lol_core::damage::{DamageResult, DamageType, EventDamageCreate};
+    use lol_core::damage::{DamageKind, DamageResult, DamageType, EventDamageCreate};
 
     use super::*;
 
@@ -1165,6 +1189,7 @@ mod tests {
             damage_type: DamageType::True,
             damage_result: damage_result(),
             tag: None,
+            kind: DamageKind::Other,
         });
         assert!(app.world().resource::<VitalBreakTracker>().hit);
 
@@ -1175,6 +1200,7 @@ mod tests {
             damage_type: DamageType::Physical,
             damage_result: damage_result(),
             tag: None,
+            kind: DamageKind::Other,
         });
         assert!(!app.world().resource::<VitalBreakTracker>().hit);
 
@@ -1184,6 +1210,7 @@ mod tests {
             damage_type: DamageType::True,
             damage_result: damage_result(),
             tag: None,
+            kind: DamageKind::Other,
         });
         assert!(!app.world().resource::<VitalBreakTracker>().hit);
     }
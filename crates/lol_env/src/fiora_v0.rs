@@ -87,6 +87,7 @@ impl FioraVsRivenEnv {
         Self::with_config(EnvConfig {
             max_steps,
             render_mode: RenderMode::Headless,
+            ..Default::default()
         })
     }
 
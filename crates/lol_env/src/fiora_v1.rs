@@ -124,6 +124,7 @@ impl FioraVsRivenRealEnv {
         Self::with_config(EnvConfig {
             max_steps,
             render_mode: RenderMode::Headless,
+            ..Default::default()
         })
     }
 
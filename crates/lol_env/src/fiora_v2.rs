@@ -8,11 +8,12 @@ use lol_rl_protocol::{ActionSpace, ObsFeaturePayload, RewardFormulaSpec, RewardT
 
 pub use crate::fiora_riven_common::{
     ATTACK_MASK_DISTANCE, AttackEventTracker, FioraRivenBaseEnv, FioraRivenEntities,
-    VitalBreakTracker, reset_episode_world, setup_skill_levels_world, unpause_virtual_time,
+    VitalBreakTracker, assist_bounty_snapshot, reset_episode_world, setup_skill_levels_world,
+    unpause_virtual_time,
 };
 pub use crate::flash_plugin::{
-    FLASH_COOLDOWN_SECS, FLASH_DISTANCE, FlashCooldown, dispatch_flash, extract_flash_obs,
-    register_flash_plugin, tick_flash_cooldown,
+    FLASH_COOLDOWN_SECS, FLASH_DISTANCE, dispatch_flash, extract_flash_obs, register_flash_plugin,
+    reset_summoner_spells,
 };
 use crate::modifier_obs::{ModifierNameId, ModifierSlotObs, extract_entity_modifiers};
 use crate::obs_plugins::{extract_attack_state, extract_champion_base, extract_skill_cds};
@@ -362,6 +363,10 @@ pub struct FioraV2RewardContext {
     pub curr_riven_hp: f32,
     pub riven_max_hp: f32,
     pub elapsed_secs: f32,
+    /// 本步新增助攻数
+    pub assists: f32,
+    /// 本步新增赏金
+    pub bounty_gold: f32,
 }
 
 pub struct FioraV2RewardModel;
@@ -415,6 +420,8 @@ impl RewardModel for FioraV2RewardModel {
             ("is_kill".to_string(), is_kill),
             ("elapsed_secs".to_string(), ctx.elapsed_secs),
             ("step_tick".to_string(), 1.0),
+            ("assists".to_string(), ctx.assists),
+            ("bounty_gold".to_string(), ctx.bounty_gold),
         ])
     }
 }
@@ -424,11 +431,7 @@ impl RewardModel for FioraV2RewardModel {
 /// 统一的有头/无头世界初始化与重置逻辑（重设瑞雯 10000 血量并重置剑姬闪现）
 pub fn setup_v2_fiora_riven_world(fiora: Entity, riven: Entity, world: &mut World) {
     setup_v2_riven_health_world(world, riven);
-    if let Some(mut flash) = world.get_mut::<FlashCooldown>(fiora) {
-        flash.reset();
-    } else {
-        world.entity_mut(fiora).insert(FlashCooldown::default());
-    }
+    reset_summoner_spells(world, fiora);
 }
 
 pub struct FioraV2Env {
@@ -459,6 +462,7 @@ impl FioraV2Env {
         Self::with_config(EnvConfig {
             max_steps,
             render_mode: RenderMode::Headless,
+            ..Default::default()
         })
     }
 
@@ -915,18 +919,15 @@ pub fn dispatch_action_world(
         FioraV2DiscreteAction::CastE => {
             world.trigger(CommandAction {
                 entity: fiora,
-                action: Action::Skill {
-                    index: 2,
-                    point: Vec2::new(fpos.x, fpos.z),
-                },
+                action: Action::SkillSelf { index: 2 },
             });
         }
         FioraV2DiscreteAction::CastR => {
             world.trigger(CommandAction {
                 entity: fiora,
-                action: Action::Skill {
+                action: Action::SkillUnit {
                     index: 3,
-                    point: Vec2::new(rpos.x, rpos.z),
+                    target: riven,
                 },
             });
         }
@@ -1023,6 +1024,7 @@ pub fn step_v2_world(
     let prev_obs = get_v2_obs_from_world(app.world(), fiora, riven);
     let prev_riven_hp = prev_obs.riven_hp;
     let prev_fpos = prev_obs.fiora_pos;
+    let prev_bounty = assist_bounty_snapshot(app.world(), fiora);
 
     if let Some(mut tracker) = app.world_mut().get_resource_mut::<VitalBreakTracker>() {
         tracker.hit = false;
@@ -1038,6 +1040,7 @@ pub fn step_v2_world(
     let obs = get_v2_obs_from_world(app.world(), fiora, riven);
     let curr_riven_hp = obs.riven_hp;
     let curr_fpos = obs.fiora_pos;
+    let curr_bounty = assist_bounty_snapshot(app.world(), fiora);
 
     let tracker_hit = app.world().resource::<VitalBreakTracker>().hit;
     let had_active_vital = prev_obs
@@ -1063,6 +1066,8 @@ pub fn step_v2_world(
         curr_riven_hp,
         riven_max_hp: prev_obs.riven_max_hp,
         elapsed_secs: step_count as f32 * (10.0 / 60.0),
+        assists: curr_bounty.0 - prev_bounty.0,
+        bounty_gold: curr_bounty.1 - prev_bounty.1,
     };
 
     let model = FioraV2RewardModel;
@@ -1,103 +1,84 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use bevy::prelude::*;
+use lol_core::skill::SkillTarget;
+use lol_core::summoner::{
+    CommandSummonerSpell, EventSummonerSpellCast, PluginSummonerSpell, SummonerSpellKind,
+    SummonerSpells,
+};
 
-/// 默认闪现冷却时间（秒）
+/// 默认闪现冷却时间（秒），与 lol_core 召唤师技能默认值一致
 pub const FLASH_COOLDOWN_SECS: f32 = 300.0;
 /// 默认闪现位移距离
 pub const FLASH_DISTANCE: f32 = 300.0;
 
-/// 闪现冷却组件。
-/// 挂载在英雄实体上，通过 [`tick_flash_cooldown`] 系统在 `FixedUpdate` 中推进计时。
-#[derive(Component, Default, Debug, Clone)]
-pub struct FlashCooldown(pub Option<Timer>);
-
-impl FlashCooldown {
-    pub fn is_ready(&self) -> bool {
-        self.0.as_ref().map_or(true, |t| t.is_finished())
-    }
-
-    pub fn remaining_secs(&self) -> f32 {
-        self.0
-            .as_ref()
-            .map(|t| {
-                if t.is_finished() {
-                    0.0
-                } else {
-                    t.remaining_secs()
-                }
-            })
-            .unwrap_or(0.0)
-    }
-
-    /// 启动闪现冷却（默认 300 秒）
-    pub fn start(&mut self) {
-        self.0 = Some(Timer::from_seconds(FLASH_COOLDOWN_SECS, TimerMode::Once));
-    }
-
-    /// 启动指定时长的冷却
-    pub fn start_duration(&mut self, duration_secs: f32) {
-        self.0 = Some(Timer::from_seconds(duration_secs, TimerMode::Once));
-    }
-
-    /// 重置冷却为就绪
-    pub fn reset(&mut self) {
-        self.0 = None;
+/// 注册召唤师技能插件（完整 `PluginCore` 已包含时跳过）。
+pub fn register_flash_plugin(app: &mut App) {
+    if !app.is_plugin_added::<PluginSummonerSpell>() {
+        app.add_plugins(PluginSummonerSpell);
     }
 }
 
-/// FixedUpdate 中推进所有实体的闪现冷却计时器。
-pub fn tick_flash_cooldown(time: Res<Time<Fixed>>, mut q: Query<&mut FlashCooldown>) {
-    for mut flash in q.iter_mut() {
-        if let Some(timer) = flash.0.as_mut() {
-            timer.tick(time.delta());
-            if timer.is_finished() {
-                flash.0 = None;
-            }
-        }
+/// 回合起点复位召唤师技能：没有则挂默认配置（闪现 + 点燃），有则清空冷却。
+pub fn reset_summoner_spells(world: &mut World, entity: Entity) {
+    if let Some(mut summoners) = world.get_mut::<SummonerSpells>(entity) {
+        summoners.reset_cooldowns();
+    } else {
+        world.entity_mut(entity).insert(SummonerSpells::default());
     }
 }
 
-/// 注册闪现插件系统到 App。
-pub fn register_flash_plugin(app: &mut App) {
-    app.add_systems(FixedUpdate, tick_flash_cooldown);
-}
-
 /// 从 ECS World 提取指定实体的闪现状态 `(is_ready, remaining_secs)`。
 pub fn extract_flash_obs(world: &World, entity: Entity) -> (bool, f32) {
     world
-        .get::<FlashCooldown>(entity)
+        .get::<SummonerSpells>(entity)
+        .and_then(|s| s.get(s.find(SummonerSpellKind::Flash)?))
         .map(|f| (f.is_ready(), f.remaining_secs()))
         .unwrap_or((true, 0.0))
 }
 
-/// 派发闪现物理位移并启动冷却。
-/// 如果实体没有 FlashCooldown 组件，则自动插入并启动。
+/// 沿 `direction` 闪现 `distance`（走 lol_core 召唤师技能管线，受冷却与控制约束）。
+/// 以本次是否收到 [`EventSummonerSpellCast`] 判定施放成功，不受无冷却模式影响。
 pub fn dispatch_flash(world: &mut World, entity: Entity, direction: Vec3, distance: f32) -> bool {
-    let is_ready = world
-        .get::<FlashCooldown>(entity)
-        .map(|f| f.is_ready())
-        .unwrap_or(true);
-
-    if !is_ready {
-        return false;
+    if world.get::<SummonerSpells>(entity).is_none() {
+        world.entity_mut(entity).insert(SummonerSpells::default());
     }
+    let Some(slot) = world
+        .get::<SummonerSpells>(entity)
+        .and_then(|s| s.find(SummonerSpellKind::Flash))
+    else {
+        return false;
+    };
+    let Some(position) = world.get::<Transform>(entity).map(|t| t.translation) else {
+        return false;
+    };
 
     let dir_normalized = if direction.length_squared() > 1e-4 {
         direction.normalize()
     } else {
         Vec3::X
     };
-
-    if let Some(mut transform) = world.get_mut::<Transform>(entity) {
-        transform.translation += dir_normalized * distance;
-    }
-
-    if let Some(mut flash) = world.get_mut::<FlashCooldown>(entity) {
-        flash.start();
-    } else {
-        let mut flash = FlashCooldown::default();
-        flash.start();
-        world.entity_mut(entity).insert(flash);
-    }
-
-    true
+    let point = (position + dir_normalized * distance).xz();
+
+    let cast = Arc::new(AtomicBool::new(false));
+    let observer = {
+        let cast = cast.clone();
+        world
+            .add_observer(move |trigger: On<EventSummonerSpellCast>| {
+                if trigger.event_target() == entity && trigger.kind == SummonerSpellKind::Flash {
+                    cast.store(true, Ordering::Relaxed);
+                }
+            })
+            .id()
+    };
+    world.trigger(CommandSummonerSpell {
+        entity,
+        slot,
+        target: SkillTarget::Point(point),
+    });
+    world.flush();
+    world.despawn(observer);
+
+    cast.load(Ordering::Relaxed)
 }
@@ -29,8 +29,8 @@ pub use fiora_v2::{
     FioraV2RewardModel, V2_OBS_DIM, V2_OBS_DISTANCE_IDX, V2_OBS_DISTANCE_SCALE,
 };
 pub use flash_plugin::{
-    FLASH_COOLDOWN_SECS, FLASH_DISTANCE, FlashCooldown, dispatch_flash, extract_flash_obs,
-    register_flash_plugin, tick_flash_cooldown,
+    FLASH_COOLDOWN_SECS, FLASH_DISTANCE, dispatch_flash, extract_flash_obs, register_flash_plugin,
+    reset_summoner_spells,
 };
 pub use obs_plugins::{
     AttackStateObs, ChampionBaseObs, SkillCdObs, extract_attack_state, extract_champion_base,
@@ -2,8 +2,12 @@ use bevy::prelude::*;
 use lol_champions::fiora::e::BuffFioraE;
 use lol_champions::fiora::passive::Vital;
 use lol_champions::fiora::r::BuffFioraR;
-use lol_core::base::buff::Buffs;
+use lol_core::base::buff::active_buffs;
 use lol_core::base::direction::Direction;
+use lol_core::buffs::common_buffs::BuffMoveSpeed;
+use lol_core::buffs::on_hit::BuffOnHitCounter;
+use lol_core::buffs::shield_magic::BuffShieldMagic;
+use lol_core::buffs::shield_white::BuffShieldWhite;
 
 /// Modifier 类型注册表（枚举 ID 经网络 embedding 表映射为特征向量）
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -17,10 +21,14 @@ pub enum ModifierNameId {
     FioraRVitalNorth = 4,
     FioraRVitalSouth = 5,
     FioraBuffE = 6,
+    MoveSpeed = 7,
+    OnHitCounter = 8,
+    ShieldWhite = 9,
+    ShieldMagic = 10,
 }
 
 impl ModifierNameId {
-    pub const COUNT: usize = 7;
+    pub const COUNT: usize = 11;
 
     pub fn to_f32(self) -> f32 {
         (self as u16) as f32
@@ -34,11 +42,35 @@ impl ModifierNameId {
             4 => Self::FioraRVitalNorth,
             5 => Self::FioraRVitalSouth,
             6 => Self::FioraBuffE,
+            7 => Self::MoveSpeed,
+            8 => Self::OnHitCounter,
+            9 => Self::ShieldWhite,
+            10 => Self::ShieldMagic,
             _ => Self::None,
         }
     }
+
+    /// buff 名到 modifier 类型；未登记的 buff 不进入观测
+    pub fn from_buff_name(name: &str) -> Option<Self> {
+        match name {
+            "FioraE" => Some(Self::FioraBuffE),
+            "MoveSpeed" => Some(Self::MoveSpeed),
+            "OnHitCounter" => Some(Self::OnHitCounter),
+            "ShieldWhite" => Some(Self::ShieldWhite),
+            "ShieldMagic" => Some(Self::ShieldMagic),
+            _ => None,
+        }
+    }
 }
 
+/// 大招四要害：方向、类型与方向参数
+const FIORA_R_VITALS: [(Direction, ModifierNameId, f32, f32); 4] = [
+    (Direction::X, ModifierNameId::FioraRVitalEast, 1.0, 0.0),
+    (Direction::NegX, ModifierNameId::FioraRVitalWest, -1.0, 0.0),
+    (Direction::Z, ModifierNameId::FioraRVitalNorth, 0.0, 1.0),
+    (Direction::NegZ, ModifierNameId::FioraRVitalSouth, 0.0, -1.0),
+];
+
 /// 单个 modifier 槽位的观测表示（5维：name_id, remaining_duration, stack_count, param0, param1）
 /// OpenAI Five Appendix E: (remaining duration, stack count, modifier name categorical embedding)
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
@@ -91,68 +123,62 @@ pub fn extract_entity_modifiers(
         });
     }
 
-    // 2. 检查 Buffs 列表
-    if let Some(buffs) = world.get::<Buffs>(entity) {
-        for buff_entity in buffs.iter() {
-            // 大招四破绽
-            if let Some(buff_r) = world.get::<BuffFioraR>(buff_entity) {
-                let is_active = buff_r.is_active();
-                let dur = buff_r.remove_timer.remaining_secs() / 8.0;
-                let active_f = if is_active { 1.0 } else { 0.0 };
+    // 2. 活跃 buff：剩余时间按总时长归一化、层数取 buff 层数，只有参数按 buff 类型各自填写
+    for buff in active_buffs(world, entity) {
+        let remaining_duration = match (buff.remaining, buff.duration) {
+            (Some(remaining), Some(duration)) if duration > 0.0 => remaining / duration,
+            _ => 0.0,
+        };
 
-                if buff_r.vitals.contains(&Direction::X) && slots.len() < max_slots {
-                    slots.push(ModifierSlotObs {
-                        name_id: ModifierNameId::FioraRVitalEast,
-                        remaining_duration: dur,
-                        stack_count: active_f,
-                        param0: 1.0,
-                        param1: 0.0,
-                    });
-                }
-                if buff_r.vitals.contains(&Direction::NegX) && slots.len() < max_slots {
-                    slots.push(ModifierSlotObs {
-                        name_id: ModifierNameId::FioraRVitalWest,
-                        remaining_duration: dur,
-                        stack_count: active_f,
-                        param0: -1.0,
-                        param1: 0.0,
-                    });
-                }
-                if buff_r.vitals.contains(&Direction::Z) && slots.len() < max_slots {
+        // 大招四要害各占一个槽位，层数位记录要害是否已可击破
+        if let Some(buff_r) = world.get::<BuffFioraR>(buff.entity) {
+            let active = if buff_r.is_active() { 1.0 } else { 0.0 };
+            for (direction, name_id, param0, param1) in FIORA_R_VITALS {
+                if buff_r.vitals.contains(&direction) {
                     slots.push(ModifierSlotObs {
-                        name_id: ModifierNameId::FioraRVitalNorth,
-                        remaining_duration: dur,
-                        stack_count: active_f,
-                        param0: 0.0,
-                        param1: 1.0,
-                    });
-                }
-                if buff_r.vitals.contains(&Direction::NegZ) && slots.len() < max_slots {
-                    slots.push(ModifierSlotObs {
-                        name_id: ModifierNameId::FioraRVitalSouth,
-                        remaining_duration: dur,
-                        stack_count: active_f,
-                        param0: 0.0,
-                        param1: -1.0,
+                        name_id,
+                        remaining_duration,
+                        stack_count: active,
+                        param0,
+                        param1,
                     });
                 }
             }
+            continue;
+        }
 
-            // E 技能强化
-            if let Some(buff_e) = world.get::<BuffFioraE>(buff_entity) {
-                if slots.len() < max_slots {
-                    slots.push(ModifierSlotObs {
-                        name_id: ModifierNameId::FioraBuffE,
-                        remaining_duration: 0.0,
-                        stack_count: buff_e.left as f32,
-                        param0: 0.0,
-                        param1: 0.0,
-                    });
-                }
-            }
+        let Some(name_id) = ModifierNameId::from_buff_name(buff.name) else {
+            continue;
+        };
+        let mut slot = ModifierSlotObs {
+            name_id,
+            remaining_duration,
+            stack_count: buff.stacks as f32,
+            ..Default::default()
+        };
+        let buff_ref = world.entity(buff.entity);
+        // 自带计数的 buff 以剩余次数作层数
+        if let Some(buff_e) = buff_ref.get::<BuffFioraE>() {
+            slot.stack_count = buff_e.left as f32;
+        }
+        if let Some(counter) = buff_ref.get::<BuffOnHitCounter>() {
+            slot.stack_count = counter.remaining as f32;
+        }
+        if let Some(move_speed) = buff_ref.get::<BuffMoveSpeed>() {
+            slot.param0 = move_speed.bonus_percent;
+        }
+        // 护盾参数：剩余护盾比例
+        if let Some(shield) = buff_ref.get::<BuffShieldWhite>() {
+            slot.param0 = shield.current / shield.max.max(1.0);
+        }
+        if let Some(shield) = buff_ref.get::<BuffShieldMagic>() {
+            slot.param0 = shield.current / shield.max.max(1.0);
         }
+        slots.push(slot);
     }
 
+    slots.truncate(max_slots);
+
     // 0-padding 补齐固定长度
     while slots.len() < max_slots {
         slots.push(ModifierSlotObs::default());
@@ -163,6 +189,8 @@ pub fn extract_entity_modifiers(
 
 #[cfg(test)]
 mod tests {
+    use lol_core::base::buff::{BuffOf, BuffRegistry, apply_buff};
+
     use super::*;
 
     #[test]
@@ -198,4 +226,42 @@ mod tests {
             assert_eq!(slot.name_id, ModifierNameId::None);
         }
     }
+
+    #[test]
+    fn test_modifier_buff_dispatch_by_name() {
+        let mut world = World::new();
+        let mut registry = BuffRegistry::default();
+        registry.register_timer::<BuffFioraE>(|b| &b.timer, |b| &mut b.timer);
+        world.insert_resource(registry);
+        let entity = world.spawn_empty().id();
+        world.spawn((BuffFioraE::default(), BuffOf(entity)));
+
+        let slots = extract_entity_modifiers(&world, entity, 2);
+        assert_eq!(slots[0].name_id, ModifierNameId::FioraBuffE);
+        assert_eq!(
+            slots[0].remaining_duration, 1.0,
+            "登记的计时器应给出剩余时间"
+        );
+        assert_eq!(slots[0].stack_count, 2.0);
+        assert_eq!(slots[1].name_id, ModifierNameId::None);
+    }
+
+    #[test]
+    fn test_modifier_slots_follow_buff_descriptor() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        let mut shield = BuffShieldWhite::new(200.0);
+        shield.current = 50.0;
+        let descriptor = BuffShieldWhite::descriptor().with_duration(2.0);
+        apply_buff(&mut world, entity, descriptor.clone(), shield.clone());
+        apply_buff(&mut world, entity, descriptor, shield);
+
+        let slots = extract_entity_modifiers(&world, entity, 3);
+        assert_eq!(slots[0].name_id, ModifierNameId::ShieldWhite);
+        assert_eq!(slots[0].remaining_duration, 1.0);
+        assert_eq!(slots[0].stack_count, 1.0, "层数取自 buff 描述");
+        assert_eq!(slots[0].param0, 0.25, "护盾参数为剩余比例");
+        assert_eq!(slots[1].name_id, ModifierNameId::ShieldWhite);
+        assert_eq!(slots[2].name_id, ModifierNameId::None);
+    }
 }
@@ -7,11 +7,12 @@ use lol_rl_protocol::{ActionSpace, ObsFeaturePayload, RewardFormulaSpec, RewardT
 
 pub use crate::fiora_riven_common::{
     ATTACK_MASK_DISTANCE, AttackEventTracker, FioraRivenBaseEnv, FioraRivenEntities,
-    VitalBreakTracker, reset_episode_world, setup_skill_levels_world, unpause_virtual_time,
+    VitalBreakTracker, assist_bounty_snapshot, reset_episode_world, setup_skill_levels_world,
+    unpause_virtual_time,
 };
 pub use crate::flash_plugin::{
-    FLASH_COOLDOWN_SECS, FLASH_DISTANCE, FlashCooldown, dispatch_flash, extract_flash_obs,
-    register_flash_plugin, tick_flash_cooldown,
+    FLASH_COOLDOWN_SECS, FLASH_DISTANCE, dispatch_flash, extract_flash_obs, register_flash_plugin,
+    reset_summoner_spells,
 };
 use crate::modifier_obs::{ModifierNameId, ModifierSlotObs, extract_entity_modifiers};
 use crate::obs_plugins::{extract_attack_state, extract_champion_base, extract_skill_cds};
@@ -33,11 +34,7 @@ pub fn setup_solo_v0_health_world(world: &mut World, fiora: Entity, riven: Entit
             hp.value = SOLO_V0_CHAMPION_HP;
             hp.max = SOLO_V0_CHAMPION_HP;
         }
-        if let Some(mut flash) = world.get_mut::<FlashCooldown>(champion) {
-            flash.reset();
-        } else {
-            world.entity_mut(champion).insert(FlashCooldown::default());
-        }
+        reset_summoner_spells(world, champion);
     }
 }
 
@@ -378,6 +375,7 @@ impl SoloV0Env {
         Self::with_config(EnvConfig {
             max_steps,
             render_mode: RenderMode::Headless,
+            ..Default::default()
         })
     }
 
@@ -953,10 +951,7 @@ pub fn dispatch_single_action(
         SoloV0DiscreteAction::CastW => {
             world.trigger(CommandAction {
                 entity: self_entity,
-                action: Action::Skill {
-                    index: 1,
-                    point: Vec2::new(spos.x, spos.z),
-                },
+                action: Action::SkillSelf { index: 1 },
             });
         }
         SoloV0DiscreteAction::CastE => {
@@ -971,9 +966,9 @@ pub fn dispatch_single_action(
         SoloV0DiscreteAction::CastR => {
             world.trigger(CommandAction {
                 entity: self_entity,
-                action: Action::Skill {
+                action: Action::SkillUnit {
                     index: 3,
-                    point: Vec2::new(tpos.x, tpos.z),
+                    target: target_entity,
                 },
             });
         }
@@ -1007,6 +1002,8 @@ pub fn step_solo_v0_world(
     let prev_r_obs = get_ego_obs_from_world(app.world(), riven, fiora, 1.0);
     let prev_f_hp = prev_f_obs.self_hp;
     let prev_r_hp = prev_r_obs.self_hp;
+    let prev_f_bounty = assist_bounty_snapshot(app.world(), fiora);
+    let prev_r_bounty = assist_bounty_snapshot(app.world(), riven);
 
     if let Some(mut tracker) = app.world_mut().get_resource_mut::<VitalBreakTracker>() {
         tracker.hit = false;
@@ -1024,6 +1021,8 @@ pub fn step_solo_v0_world(
     let curr_r_obs = get_ego_obs_from_world(app.world(), riven, fiora, 1.0);
     let curr_f_hp = curr_f_obs.self_hp;
     let curr_r_hp = curr_r_obs.self_hp;
+    let curr_f_bounty = assist_bounty_snapshot(app.world(), fiora);
+    let curr_r_bounty = assist_bounty_snapshot(app.world(), riven);
 
     let fiora_dmg_dealt = (prev_r_hp - curr_r_hp).max(0.0) / 1000.0;
     let riven_dmg_dealt = (prev_f_hp - curr_f_hp).max(0.0) / 1000.0;
@@ -1063,6 +1062,8 @@ pub fn step_solo_v0_world(
             "is_kill_win".to_string(),
             if fiora_killed { 1.0 } else { 0.0 },
         ),
+        ("assists".to_string(), curr_f_bounty.0 - prev_f_bounty.0),
+        ("bounty_gold".to_string(), curr_f_bounty.1 - prev_f_bounty.1),
     ]);
 
     let r_vars = HashMap::from([
@@ -1073,6 +1074,8 @@ pub fn step_solo_v0_world(
             "is_kill_win".to_string(),
             if riven_killed { 1.0 } else { 0.0 },
         ),
+        ("assists".to_string(), curr_r_bounty.0 - prev_r_bounty.0),
+        ("bounty_gold".to_string(), curr_r_bounty.1 - prev_r_bounty.1),
     ]);
 
     (
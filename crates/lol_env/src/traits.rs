@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use bevy::app::App;
 use bevy::ecs::world::World;
 use bevy::math::Vec2;
+use lol_core::summoner::ConfigSummonerSpells;
 use lol_rl_protocol::{ActionSpace, ObsFeaturePayload, RewardFormulaSpec};
 
 /// Controls whether the Env runs headless (for training) or with a window (for visualization).
@@ -23,6 +24,8 @@ pub enum RenderMode {
 pub struct EnvConfig {
     pub max_steps: usize,
     pub render_mode: RenderMode,
+    /// 按英雄名（小写，如 `fiora`）选择召唤师技能；未列出的英雄用默认闪现 + 点燃
+    pub summoner_spells: HashMap<String, ConfigSummonerSpells>,
 }
 
 impl Default for EnvConfig {
@@ -30,6 +33,7 @@ impl Default for EnvConfig {
         Self {
             max_steps: 0,
             render_mode: RenderMode::Headless,
+            summoner_spells: HashMap::new(),
         }
     }
 }
@@ -228,7 +232,7 @@ pub trait VisualEnvironment: RlEnvironment {
     ///
     /// **单一事实来源**：headless `RlEnvironment::reset`、无头构造路径与
     /// `reset_world` 都应调用此方法，避免各 reset 配方各自手写导致漏项
-    /// （例如可视化 reset 重建实体后丢失 FlashCooldown 导致闪现永远无冷却）。
+    /// （例如可视化 reset 重建实体后丢失 SummonerSpells 导致闪现永远无冷却）。
     fn on_episode_ready(&mut self, _world: &mut World) {}
 
     fn get_current_obs_all(&self, world: &World) -> Vec<Self::Obs>;
@@ -240,6 +240,8 @@ fn test_fiora_v2_reward_model_step_penalty_and_damage_norm() {
         curr_riven_hp: 500.0,
         riven_max_hp: 500.0,
         elapsed_secs: 5.0,
+        assists: 0.0,
+        bounty_gold: 0.0,
     };
     let (reward_step, _, _) = model.evaluate(&ctx_step);
     assert!((reward_step - (-0.001)).abs() < 1e-5);
@@ -253,10 +255,14 @@ fn test_fiora_v2_reward_model_step_penalty_and_damage_norm() {
         curr_riven_hp: 0.0,
         riven_max_hp: 500.0,
         elapsed_secs: 2.0,
+        assists: 1.0,
+        bounty_gold: 300.0,
     };
-    let (reward_kill, breakdown, _) = model.evaluate(&ctx_kill);
+    let (reward_kill, breakdown, vars) = model.evaluate(&ctx_kill);
     assert!((reward_kill - 2.499).abs() < 1e-4);
     assert_eq!(breakdown.len(), 3);
+    assert_eq!(vars.get("assists"), Some(&1.0));
+    assert_eq!(vars.get("bounty_gold"), Some(&300.0));
 }
 
 #[test]
@@ -10,6 +10,7 @@ fn test_multi_agent_selfplay_signatures_and_throughput() {
     let mut env = SoloV0Env::with_config(EnvConfig {
         max_steps: 50,
         render_mode: RenderMode::Headless,
+        ..Default::default()
     });
 
     let initial_obs = env.reset();
@@ -38,6 +39,7 @@ fn test_multi_agent_parallel_envs_batch_throughput() {
         EnvConfig {
             max_steps: 20,
             render_mode: RenderMode::Headless,
+            ..Default::default()
         },
     );
 
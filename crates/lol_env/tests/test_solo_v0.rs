@@ -10,6 +10,7 @@ fn test_solo_v0_real_map_and_level_one_laning() {
     let mut env = SoloV0Env::with_config(EnvConfig {
         max_steps: 50,
         render_mode: RenderMode::Headless,
+        ..Default::default()
     });
 
     // 1. 验证真实地图配置
@@ -106,8 +106,13 @@ pub struct StartGameInput {
 
 /// AI 决策环启动器（解耦凭证解析）：桌面注入桌面 runner，云端注入云端 runner。
 /// `None` 表示不启动 AI（纯观战/回放）。
-pub type AgentRunner =
-    Arc<dyn Fn(i32, Vec<AgentConfig>) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+///
+/// 参数依次为 WS 端口、agent 角色令牌（进程未启用鉴权时为 `None`）与 agent 阵容。
+pub type AgentRunner = Arc<
+    dyn Fn(i32, Option<String>, Vec<AgentConfig>) -> Pin<Box<dyn Future<Output = ()> + Send>>
+        + Send
+        + Sync,
+>;
 
 /// 游戏进程管理器。
 pub struct GameProcessManager {
@@ -139,6 +144,15 @@ impl GameProcessManager {
 
         let id = input.id;
 
+        // 启用鉴权的进程：为 AI 决策环签发绑定场景 agent 的令牌（须在 spawn 前写入配置）。
+        let run_agents = !input.scenario_agents.is_empty() && self.agent_runner.is_some();
+        let agent_token = match input.spawn.ws_auth.as_mut() {
+            Some(auth) if run_agents => {
+                Some(auth.issue_agent(input.scenario_agents.iter().map(|agent| agent.id.clone())))
+            }
+            _ => None,
+        };
+
         // 2. 启动 Bevy 进程（失败回滚端口）
         if let Err(e) = self.launcher.launch(port, &input.spawn).await {
             let mut state = self.state.lock().await;
@@ -158,12 +172,12 @@ impl GameProcessManager {
         }
 
         // 4. (可选) 启动 AI 决策环
-        if !input.scenario_agents.is_empty() {
+        if run_agents {
             if let Some(runner) = &self.agent_runner {
                 let runner = runner.clone();
                 let agents = input.scenario_agents.clone();
                 tokio::spawn(async move {
-                    runner(port, agents).await;
+                    runner(port, agent_token, agents).await;
                 });
             }
         }
@@ -269,6 +283,7 @@ mod tests {
             cwd: None,
             rust_log: None,
             log_db: None,
+            ws_auth: None,
         }
     }
 
@@ -299,6 +314,40 @@ mod tests {
         assert_eq!(state.processes[0].id, id);
     }
 
+    #[tokio::test]
+    async fn start_issues_agent_token_when_auth_enabled() {
+        let launcher = Arc::new(FakeLauncher {
+            launch_fail: StdMutex::new(false),
+            killed: StdMutex::new(vec![]),
+        });
+        let (token_tx, mut token_rx) = tokio::sync::mpsc::unbounded_channel();
+        let runner: AgentRunner = Arc::new(move |_port, token, _agents| {
+            let _ = token_tx.send(token);
+            Box::pin(async {})
+        });
+        let mgr = GameProcessManager::new(launcher, Some(runner));
+
+        let mut spawn = spawn_req();
+        spawn.ws_auth = Some(lol_client::auth::WsAuthConfig::default());
+        mgr.start(StartGameInput {
+            id: Uuid::new_v4(),
+            spawn,
+            scenario_agents: vec![AgentConfig {
+                id: "blue".into(),
+                champion: "Riven".into(),
+                team: "Order".into(),
+                prompt: String::new(),
+                model: None,
+                provider_id: None,
+            }],
+        })
+        .await
+        .unwrap();
+
+        let token = token_rx.recv().await.unwrap();
+        assert!(token.is_some_and(|t| t.len() == 32));
+    }
+
     #[tokio::test]
     async fn start_launch_failure_releases_port() {
         let (mgr, _) = manager(true);
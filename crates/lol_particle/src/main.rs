@@ -23,9 +23,8 @@ use lol_base_render::particle::{
 use lol_core::map::{MAP_HEIGHT, MAP_WIDTH, PluginMap};
 use lol_particle::PluginParticle;
 use lol_render::map::PluginRenderMap;
-use lol_rpc::{CommandWsRequest, RpcAppExt, respond};
+use lol_rpc::{CommandWsRequest, RpcAppExt, cmd, respond};
 use lol_server::PluginWsServer;
-use serde::Deserialize;
 use serde_json::json;
 
 #[derive(Parser)]
@@ -106,8 +105,8 @@ impl Plugin for PluginParticleRpc {
     fn build(&self, app: &mut App) {
         app.init_resource::<PlayingVfx>();
 
-        app.register_rpc::<PlayParticleParams>("play_particle");
-        app.register_rpc::<StopParticleParams>("stop_particle");
+        app.register_rpc::<cmd::PlayParticle>();
+        app.register_rpc::<cmd::StopParticle>();
 
         app.add_observer(on_play_particle);
         app.add_observer(on_stop_particle);
@@ -116,15 +115,6 @@ impl Plugin for PluginParticleRpc {
     }
 }
 
-#[derive(Deserialize)]
-struct PlayParticleParams {
-    /// 一段 ConfigVfxSystemDefinition 的 RON 字符串（由 apps/client 从英雄粒子配置中提取）
-    def: String,
-}
-
-#[derive(Deserialize)]
-struct StopParticleParams {}
-
 /// 当前播放状态：因为播放输入是临时 RON，所以为每次播放分配自增 hash 注册定义，
 /// 切换/停止时用该 hash 找回并 despawn。
 #[derive(Resource, Default)]
@@ -146,7 +136,7 @@ fn setup_stage(mut commands: Commands) {
 }
 
 fn on_play_particle(
-    event: On<CommandWsRequest<PlayParticleParams>>,
+    event: On<CommandWsRequest<cmd::PlayParticle>>,
     q_anchor: Query<Entity, With<ParticleAnchor>>,
     asset_server: Res<AssetServer>,
     mut assets_def: ResMut<Assets<ConfigVfxSystemDefinition>>,
@@ -206,7 +196,7 @@ fn on_play_particle(
 }
 
 fn on_stop_particle(
-    event: On<CommandWsRequest<StopParticleParams>>,
+    event: On<CommandWsRequest<cmd::StopParticle>>,
     q_anchor: Query<Entity, With<ParticleAnchor>>,
     mut playing: ResMut<PlayingVfx>,
     mut commands: Commands,
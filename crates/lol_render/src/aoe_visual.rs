@@ -6,6 +6,9 @@
 //!   Transform.scale 只承载生命周期相位因子（生长/爆发/褪去）。
 //! - `aoe_visual_sync_system`：`AoEVisual.alpha` 变化时更新材质 base_color 的 alpha。
 //!
+//! - `on_terrain_wall_created`：技能地形墙创建时按多边形构建地面 mesh 挂到墙体实体上，
+//!   墙体到期销毁时随之消失。
+//!
 //! mesh 为双面 winding（每三角形正反各一份），避免单面剔除导致地面贴花从一侧不可见。
 
 use bevy::asset::RenderAssetUsages;
@@ -13,6 +16,7 @@ use bevy::mesh::{Indices, PrimitiveTopology};
 use bevy::prelude::*;
 use lol_core::action::damage::DamageShape;
 use lol_core::action::delayed_damage::AoEVisual;
+use lol_core::navigation::terrain::EventTerrainWallCreated;
 
 /// 扇形/圆盘/环形的分段数（越大越圆滑）
 const SEGMENTS: usize = 32;
@@ -23,6 +27,7 @@ pub struct PluginAoEVisual;
 impl Plugin for PluginAoEVisual {
     fn build(&self, app: &mut App) {
         app.add_systems(Update, (aoe_visual_spawn_system, aoe_visual_sync_system));
+        app.add_observer(on_terrain_wall_created);
     }
 }
 
@@ -58,6 +63,55 @@ fn aoe_visual_sync_system(
     }
 }
 
+fn on_terrain_wall_created(
+    trigger: On<EventTerrainWallCreated>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let (positions, indices) = polygon_geometry(&trigger.polygon);
+    if indices.is_empty() {
+        return;
+    }
+    let normals = vec![[0.0, 1.0, 0.0]; positions.len()];
+    let uvs = vec![[0.0, 0.0]; positions.len()];
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_indices(Indices::U32(double_wind(&indices)));
+
+    let material = StandardMaterial {
+        base_color: Color::srgba(0.6, 0.85, 1.0, 0.8),
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        ..Default::default()
+    };
+    commands.entity(trigger.event_target()).try_insert((
+        Mesh3d(meshes.add(mesh)),
+        MeshMaterial3d(materials.add(material)),
+    ));
+}
+
+/// 凸多边形扇形三角化，顶点相对质心（墙体实体位于质心）
+fn polygon_geometry(polygon: &[Vec2]) -> (Vec<[f32; 3]>, Vec<u32>) {
+    if polygon.len() < 3 {
+        return (Vec::new(), Vec::new());
+    }
+    let centroid = polygon.iter().copied().sum::<Vec2>() / polygon.len() as f32;
+    let positions = polygon
+        .iter()
+        .map(|p| [p.x - centroid.x, 0.0, p.y - centroid.y])
+        .collect::<Vec<_>>();
+    let indices = (1..polygon.len() as u32 - 1)
+        .flat_map(|i| [0, i, i + 1])
+        .collect();
+    (positions, indices)
+}
+
 /// 按 `DamageShape` 构建 XZ 平面（y=0）flat mesh，尺寸为形状真实大小。
 fn build_shape_mesh(shape: &DamageShape) -> Mesh {
     let (positions, indices) = shape_geometry(shape);
@@ -90,6 +144,8 @@ fn shape_geometry(shape: &DamageShape) -> (Vec<[f32; 3]>, Vec<u32>) {
             length,
             start_distance,
         } => rectangle_geometry(*width, *length, *start_distance),
+        // 单体与世界坐标线段不以施法者为原点，不绘制
+        DamageShape::Unit { .. } | DamageShape::Line { .. } => (Vec::new(), Vec::new()),
     }
 }
 
@@ -180,6 +236,19 @@ fn double_wind(indices: &[u32]) -> Vec<u32> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn polygon_geometry_is_centered_fan() {
+        let square = [
+            Vec2::new(100.0, 100.0),
+            Vec2::new(300.0, 100.0),
+            Vec2::new(300.0, 150.0),
+            Vec2::new(100.0, 150.0),
+        ];
+        let (positions, indices) = polygon_geometry(&square);
+        assert_eq!(positions[0], [-100.0, 0.0, -25.0]);
+        assert_eq!(indices, vec![0, 1, 2, 0, 2, 3]);
+    }
+
     #[test]
     fn disc_geometry_counts() {
         let (positions, indices) = disc_geometry(325.0);
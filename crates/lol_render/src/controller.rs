@@ -28,6 +28,7 @@ pub struct SelfPlayer;
 pub struct Controller {
     attack_key: KeyCode,
     stop_key: KeyCode,
+    recall_key: KeyCode,
     skill_key_map: HashMap<usize, KeyCode>,
 }
 
@@ -36,6 +37,7 @@ impl Default for Controller {
         Self {
             attack_key: KeyCode::KeyA,
             stop_key: KeyCode::KeyS,
+            recall_key: KeyCode::KeyB,
             skill_key_map: HashMap::from([
                 (0, KeyCode::KeyQ),
                 (1, KeyCode::KeyW),
@@ -53,6 +55,9 @@ impl Controller {
     pub fn stop_key(&self) -> KeyCode {
         self.stop_key
     }
+    pub fn recall_key(&self) -> KeyCode {
+        self.recall_key
+    }
     pub fn iter_skill_keys(&self) -> Iter<'_, usize, KeyCode> {
         self.skill_key_map.iter()
     }
@@ -141,6 +146,9 @@ pub fn on_key_pressed(
         } else if res_input.just_pressed(controller.stop_key()) {
             debug!("检测到停止键 {:?} 按下", controller.stop_key());
             Some(Action::Stop)
+        } else if res_input.just_pressed(controller.recall_key()) {
+            debug!("检测到回城键 {:?} 按下", controller.recall_key());
+            Some(Action::Recall)
         } else {
             let mut action = None;
             for (skill_id, key) in controller.iter_skill_keys() {
@@ -3,7 +3,7 @@ use bevy::asset::RecursiveDependencyLoadState;
 use bevy::ecs::entity::EntityHashMap;
 use bevy::prelude::*;
 use bevy::world_serialization::{WorldInstanceReady, WorldInstanceSpawnError};
-use lol_base::character::{ConfigSkin, Skin};
+use lol_base::character::{ConfigSkin, LoadedSkin, Skin};
 use lol_base_render::animation::AnimationConfigOf;
 
 pub fn update_skin_scale(mut query: Query<(&Skin, &mut Transform)>) {
@@ -57,6 +57,9 @@ pub fn try_load_config_skin_characters(
         commands
             .entity(entity)
             .remove::<ConfigSkin>()
+            .insert(LoadedSkin {
+                skin: config.skin.clone(),
+            })
             .observe(migrate_animation_graph_handle);
 
         loaded_count += 1;
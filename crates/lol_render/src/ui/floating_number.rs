@@ -1,8 +1,10 @@
-use bevy::color::palettes::css::{BLUE, GOLD, RED, WHITE};
+use bevy::color::palettes::css::{BLUE, GOLD, LIME, RED, WHITE};
 use bevy::prelude::*;
 use lol_core::base::gold::EventGoldGain;
 use lol_core::base::level::EventExperienceGain;
 use lol_core::damage::{DamageType, EventDamageCreate};
+use lol_core::entities::champion::Champion;
+use lol_core::heal::EventHealApplied;
 use rand::Rng;
 
 use crate::camera::CameraState;
@@ -38,6 +40,7 @@ impl Plugin for PluginUIFloatingNumber {
     fn build(&self, app: &mut App) {
         app.add_systems(Update, update_floating_numbers);
         app.add_observer(on_event_damage_create);
+        app.add_observer(on_event_heal_applied);
         app.add_observer(on_event_gold_gain);
         app.add_observer(on_event_experience_gain);
     }
@@ -103,7 +106,7 @@ fn on_event_damage_create(
     trigger: On<EventDamageCreate>,
     mut commands: Commands,
     global_transform: Query<&GlobalTransform>,
-    q_champion: Query<(), With<lol_core::entities::champion::Champion>>,
+    q_champion: Query<(), With<Champion>>,
 ) {
     // 只显示英雄受到伤害的数字（过滤打到小兵等非英雄目标）
     let target_entity = trigger.event_target();
@@ -155,6 +158,56 @@ fn on_event_damage_create(
     ));
 }
 
+/// 监听治疗结算事件并创建治疗飘字
+///
+/// 只显示英雄实际回复的生命（不含溢出），小额治疗（如持续回复的零头）不显示
+fn on_event_heal_applied(
+    trigger: On<EventHealApplied>,
+    mut commands: Commands,
+    global_transform: Query<&GlobalTransform>,
+    q_champion: Query<(), With<Champion>>,
+) {
+    let target_entity = trigger.event_target();
+    if q_champion.get(target_entity).is_err() {
+        return;
+    }
+
+    let heal = trigger.heal_result.final_heal;
+    if heal < 1.0 {
+        return;
+    }
+
+    let Ok(target_transform) = global_transform.get(target_entity) else {
+        return;
+    };
+
+    let font_size = (14.0 + heal * 0.05).clamp(14.0, 30.0);
+
+    commands.spawn((
+        Text::new(format!("+{:.0}", heal)),
+        TextFont {
+            font_size: FontSize::Px(font_size),
+            ..default()
+        },
+        TextColor(Color::Srgba(LIME)),
+        Node {
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        FloatingNumber {
+            lifetime: 0.0,
+            max_lifetime: 1.0,
+            start_position: target_transform.translation(),
+            current_x_offset: 0.0,
+            current_y_offset: 0.0,
+            velocity_x: rand::rng().random_range(-50.0..50.0),
+            velocity_y: 400.0,
+            gravity: -1200.0,
+            final_scale: 0.6,
+        },
+    ));
+}
+
 /// 监听金币增益事件并创建金币飘字
 ///
 /// 只在玩家（SelfPlayer）击杀时显示，位置在死者死亡位置
@@ -1,3 +1,6 @@
+use bevy::color::palettes::css::{
+    DEEP_SKY_BLUE, GOLD, GRAY, LIGHT_GREEN, ORANGE, ORANGE_RED, PLUM, SKY_BLUE, WHITE, YELLOW,
+};
 use bevy::prelude::*;
 use lol_base::spell::Spell;
 use lol_base_render::ui::LOLEnumData::AtlasData;
@@ -7,10 +10,13 @@ use lol_base_render::ui::{
 };
 use lol_core::base::level::Level;
 use lol_core::character::CharacterReady;
+use lol_core::damage::{AbilityPower, Damage};
 use lol_core::skill::{
-    CommandSkillLevelUp, CoolDown, PassiveSkill, Skill, SkillPoints, SkillRecastWindow, Skills,
-    is_skill_ready,
+    CommandSkillLevelUp, CoolDown, PassiveSkill, Skill, SkillCalcQuery, SkillPoints,
+    SkillRecastWindow, Skills, SpellTooltip, TooltipStyle, is_skill_ready, render_spell_tooltip,
+    spell_tooltip_template,
 };
+use lol_core::summoner::SummonerSpells;
 
 use crate::controller::SelfPlayer;
 use crate::ui::button::update_button;
@@ -22,6 +28,7 @@ pub struct PluginUISkill;
 
 impl Plugin for PluginUISkill {
     fn build(&self, app: &mut App) {
+        app.init_resource::<HoveredSkillSlot>();
         app.add_systems(
             Update,
             (
@@ -31,6 +38,7 @@ impl Plugin for PluginUISkill {
                 ),
                 (
                     setup_skill_level_up_buttons,
+                    setup_skill_tooltip_hover,
                     update_passive_skill_icon,
                     update_active_skill_icons,
                 )
@@ -39,15 +47,32 @@ impl Plugin for PluginUISkill {
                             .and_then(any_match_filter::<(With<SelfPlayer>, With<CharacterReady>)>)
                             .and_then(run_once),
                     ),
-                (update_skill_cooldown, update_skill_rank_pips).run_if(
-                    in_state(UIState::Loaded)
-                        .and_then(any_match_filter::<(With<SelfPlayer>, With<CharacterReady>)>),
-                ),
+                (
+                    update_skill_cooldown,
+                    update_summoner_spell_cooldown,
+                    update_skill_rank_pips,
+                    update_skill_tooltip,
+                )
+                    .run_if(
+                        in_state(UIState::Loaded)
+                            .and_then(any_match_filter::<(With<SelfPlayer>, With<CharacterReady>)>),
+                    ),
             ),
         );
     }
 }
 
+/// 鼠标悬停的技能槽位（champion_spells 下标）
+#[derive(Resource, Default)]
+struct HoveredSkillSlot(Option<usize>);
+
+/// 技能提示面板，记录当前显示的内容以便数值变化时才重建
+#[derive(Component)]
+struct SkillTooltipPanel {
+    slot: usize,
+    tooltip: SpellTooltip,
+}
+
 fn update_passive_skill_icon(
     mut commands: Commands,
     q_skill: Query<&Skill>,
@@ -192,7 +217,51 @@ fn update_single_skill_cooldown(
     res_ui_element_entity: &UIElementEntity,
     q_ui_text_state: &mut Query<(&mut UiTextState, &mut Visibility)>,
 ) {
-    let disabled_entity = res_ui_element_entity.get_entity(&slot_def.overlay_disabled.unwrap());
+    if skill.level == 0 {
+        update_slot_cooldown_display(
+            commands,
+            slot_def,
+            res_ui_element_entity,
+            q_ui_text_state,
+            None,
+            true,
+        );
+        return;
+    }
+
+    // 存在未过期的重施窗口时（如锐雯 Q 的多段重施），技能仍可释放下一段，
+    // 应显示为就绪而非冷却倒计时——即使主冷却已在计时。
+    let remaining = (!is_skill_ready(cooldown, recast)).then(|| {
+        cooldown
+            .timer
+            .as_ref()
+            .map(|v| v.remaining_secs())
+            .unwrap_or(0.0)
+    });
+    update_slot_cooldown_display(
+        commands,
+        slot_def,
+        res_ui_element_entity,
+        q_ui_text_state,
+        remaining,
+        remaining.is_some(),
+    );
+}
+
+/// 按冷却剩余秒数刷新一个技能槽位的倒计时文字与禁用遮罩：
+/// `remaining` 为 None 时隐藏倒计时，`disabled` 控制禁用遮罩与边框。
+fn update_slot_cooldown_display(
+    commands: &mut Commands,
+    slot_def: &LOLSpellSlotDetailedUiDefinition,
+    res_ui_element_entity: &UIElementEntity,
+    q_ui_text_state: &mut Query<(&mut UiTextState, &mut Visibility)>,
+    remaining: Option<f32>,
+    disabled: bool,
+) {
+    let Some(overlay_disabled) = slot_def.overlay_disabled.as_ref() else {
+        return;
+    };
+    let disabled_entity = res_ui_element_entity.get_entity(overlay_disabled);
     let ui_entity = res_ui_element_entity.get_entity(&slot_def.cooldown);
     let border_enabled_entity = res_ui_element_entity.get_entity(&slot_def.border_enabled);
     let border_disabled_entity = res_ui_element_entity.get_entity(&slot_def.border_disabled);
@@ -201,42 +270,27 @@ fn update_single_skill_cooldown(
         return;
     };
 
-    if skill.level == 0 {
-        if text_state.text != "" {
-            text_state.text = "".to_string();
+    match remaining {
+        Some(remaining) => {
+            let text_val = remaining.ceil().to_string();
+            if text_state.text != text_val {
+                text_state.text = text_val;
+            }
+            if matches!(*visibility, Visibility::Hidden) {
+                *visibility = Visibility::Inherited;
+            }
         }
-        if matches!(*visibility, Visibility::Inherited) {
-            *visibility = Visibility::Hidden;
+        None => {
+            if text_state.text != "" {
+                text_state.text = "".to_string();
+            }
+            if matches!(*visibility, Visibility::Inherited) {
+                *visibility = Visibility::Hidden;
+            }
         }
-        commands
-            .entity(disabled_entity)
-            .insert(Visibility::Inherited);
-        commands
-            .entity(border_disabled_entity)
-            .insert(Visibility::Inherited);
-        commands
-            .entity(border_enabled_entity)
-            .insert(Visibility::Hidden);
-        return;
     }
 
-    // 存在未过期的重施窗口时（如锐雯 Q 的多段重施），技能仍可释放下一段，
-    // 应显示为就绪而非冷却倒计时——即使主冷却已在计时。
-    let show_cooldown = !is_skill_ready(cooldown, recast);
-
-    if show_cooldown {
-        let remaining = cooldown
-            .timer
-            .as_ref()
-            .map(|v| v.remaining_secs())
-            .unwrap_or(0.0);
-        let text_val = remaining.ceil().to_string();
-        if text_state.text != text_val {
-            text_state.text = text_val;
-        }
-        if matches!(*visibility, Visibility::Hidden) {
-            *visibility = Visibility::Inherited;
-        }
+    if disabled {
         commands
             .entity(disabled_entity)
             .insert(Visibility::Inherited);
@@ -247,12 +301,6 @@ fn update_single_skill_cooldown(
             .entity(border_enabled_entity)
             .insert(Visibility::Hidden);
     } else {
-        if text_state.text != "" {
-            text_state.text = "".to_string();
-        }
-        if matches!(*visibility, Visibility::Inherited) {
-            *visibility = Visibility::Hidden;
-        }
         commands.entity(disabled_entity).insert(Visibility::Hidden);
         commands
             .entity(border_enabled_entity)
@@ -449,6 +497,37 @@ fn update_skill_cooldown(
     }
 }
 
+fn update_summoner_spell_cooldown(
+    mut commands: Commands,
+    q_summoners: Query<&SummonerSpells, (With<SelfPlayer>, Changed<SummonerSpells>)>,
+    res_player_frame_vc: Res<LOLPlayerFrameViewController>,
+    res_ui_element_entity: Res<UIElementEntity>,
+    mut q_ui_text_state: Query<(&mut UiTextState, &mut Visibility)>,
+) {
+    let Ok(summoners) = q_summoners.single() else {
+        return;
+    };
+
+    for (slot, spell) in summoners.slots.iter().enumerate() {
+        let Some(slot_def) = res_player_frame_vc
+            .abilities_ui_data
+            .summoner_spells
+            .get(slot)
+        else {
+            break;
+        };
+        let remaining = (!spell.is_ready()).then(|| spell.remaining_secs());
+        update_slot_cooldown_display(
+            &mut commands,
+            slot_def,
+            &res_ui_element_entity,
+            &mut q_ui_text_state,
+            remaining,
+            remaining.is_some(),
+        );
+    }
+}
+
 fn update_skill_rank_pips(
     mut commands: Commands,
     q_changed_skill: Query<(Entity, &Skill), Changed<Skill>>,
@@ -574,3 +653,135 @@ fn setup_skill_level_up_buttons(
         );
     }
 }
+
+fn setup_skill_tooltip_hover(
+    mut commands: Commands,
+    res_player_frame_vc: Res<LOLPlayerFrameViewController>,
+    res_ui_element_entity: Res<UIElementEntity>,
+) {
+    for (index, slot_def) in res_player_frame_vc
+        .abilities_ui_data
+        .champion_spells
+        .iter()
+        .enumerate()
+    {
+        let Some(content_element) = slot_def.content_element else {
+            continue;
+        };
+        commands
+            .entity(res_ui_element_entity.get_entity(&content_element))
+            .insert(Pickable::default())
+            .observe(
+                move |_: On<Pointer<Over>>, mut hovered: ResMut<HoveredSkillSlot>| {
+                    hovered.0 = Some(index);
+                },
+            )
+            .observe(
+                move |_: On<Pointer<Out>>, mut hovered: ResMut<HoveredSkillSlot>| {
+                    if hovered.0 == Some(index) {
+                        hovered.0 = None;
+                    }
+                },
+            );
+    }
+}
+
+/// 悬停技能槽位时按当前技能等级与英雄属性渲染提示文本；数值变化（升级、出装、叠层）时重建面板
+fn update_skill_tooltip(
+    mut commands: Commands,
+    hovered: Res<HoveredSkillSlot>,
+    q_self: Query<(Entity, &Skills, Option<&Damage>, Option<&AbilityPower>), With<SelfPlayer>>,
+    q_skill: Query<&Skill>,
+    q_panel: Query<(Entity, &SkillTooltipPanel)>,
+    res_assets_spell_object: Res<Assets<Spell>>,
+    calc: SkillCalcQuery,
+) {
+    let tooltip = hovered.0.and_then(|slot| {
+        let (entity, skills, damage, ap) = q_self.single().ok()?;
+        let skill = q_skill.get(*skills.get(slot)?).ok()?;
+        let spell = res_assets_spell_object.get(&skill.spell)?;
+        let template = spell_tooltip_template(spell)?;
+        // 与技能伤害结算一致：stat 2 为攻击力，stat 0 为法术强度
+        let tooltip = render_spell_tooltip(
+            template,
+            spell,
+            skill.level.max(1),
+            &calc.caster(entity),
+            |stat| match stat {
+                2 => damage.map_or(0.0, |d| d.0),
+                0 => ap.map_or(0.0, |ap| ap.0),
+                _ => 0.0,
+            },
+        );
+        Some((slot, tooltip))
+    });
+
+    let current = q_panel.single().ok();
+    if let (Some((slot, tooltip)), Some((_, panel))) = (&tooltip, current)
+        && panel.slot == *slot
+        && panel.tooltip == *tooltip
+    {
+        return;
+    }
+    if let Some((panel_entity, _)) = current {
+        commands.entity(panel_entity).despawn();
+    }
+    let Some((slot, tooltip)) = tooltip else {
+        return;
+    };
+
+    let spans: Vec<_> = tooltip
+        .spans
+        .iter()
+        .map(|span| {
+            (
+                TextSpan::new(span.text.clone()),
+                TextColor(tooltip_style_color(span.style)),
+            )
+        })
+        .collect();
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(35.0),
+                bottom: Val::Px(170.0),
+                max_width: Val::Px(420.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.05, 0.08, 0.92)),
+            ZIndex(100),
+            Pickable::IGNORE,
+            Text::default(),
+            TextFont {
+                font_size: FontSize::Px(14.0),
+                ..default()
+            },
+            TextColor(Color::Srgba(WHITE)),
+            SkillTooltipPanel { slot, tooltip },
+        ))
+        .with_children(|parent| {
+            for span in spans {
+                parent.spawn(span);
+            }
+        });
+}
+
+fn tooltip_style_color(style: TooltipStyle) -> Color {
+    Color::Srgba(match style {
+        TooltipStyle::Plain => WHITE,
+        TooltipStyle::PhysicalDamage => ORANGE,
+        TooltipStyle::MagicDamage => DEEP_SKY_BLUE,
+        TooltipStyle::TrueDamage => WHITE,
+        TooltipStyle::Healing => LIGHT_GREEN,
+        TooltipStyle::Shield => GRAY,
+        TooltipStyle::Status => PLUM,
+        TooltipStyle::Speed => YELLOW,
+        TooltipStyle::ScaleAD => ORANGE_RED,
+        TooltipStyle::ScaleAP => SKY_BLUE,
+        TooltipStyle::ScaleHealth => LIGHT_GREEN,
+        TooltipStyle::ScaleMana => SKY_BLUE,
+        TooltipStyle::Keyword | TooltipStyle::SpellName => GOLD,
+    })
+}
@@ -113,6 +113,7 @@ fn start_visual_runner_for_env<E: VisualEnvironment>(
     let env = E::with_config(EnvConfig {
         max_steps: 0,
         render_mode: RenderMode::WindowCustomLoop,
+        ..Default::default()
     });
     let env_max_steps = env.max_steps();
 
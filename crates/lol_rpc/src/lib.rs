@@ -1,63 +1,127 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex};
 
 use bevy::prelude::*;
+pub use lol_client::auth::ControlScope;
 use lol_client::protocol::WsResponse;
+pub use lol_client::rpc::{RpcCommand, RpcCommandInfo, cmd};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
+/// 非 WS 来源（进程内调用、测试）的连接 ID。
+pub const LOCAL_CONNECTION: u64 = 0;
+
+/// 一次 RPC 请求，按命令契约 `C` 区分事件类型；`params` 即契约中的参数类型，
+/// 服务端不再另写一份参数定义。
 #[derive(Event)]
-pub struct CommandWsRequest<T> {
+pub struct CommandWsRequest<C: RpcCommand> {
+    /// 发起请求的 WS 连接 ID（由 `lol_server` 分配），用于定向推送。
+    pub conn_id: u64,
+    /// 发起连接可操控的英雄范围；操控类命令须据此校验目标英雄。
+    pub scope: ControlScope,
     pub id: u64,
-    pub params: T,
+    pub params: C::Params,
     pub response: Arc<Mutex<Option<WsResponse>>>,
 }
 
+type RpcHandler = Arc<
+    dyn Fn(&mut World, u64, ControlScope, u64, Value, &Arc<Mutex<Option<WsResponse>>>)
+        + Send
+        + Sync,
+>;
+
 /// 运行期 RPC 注册表：各业务模块在自身 `Plugin::build` 中注册命令，
 /// 避免所有参数类型集中定义在本 crate。
-#[derive(Resource, Default)]
+///
+/// 命令名与参数 / 返回值 Schema 取自 `lol_client::rpc` 的命令契约，
+/// 注册表同时记录这些描述，供内建的 `list_commands` 查询。
+#[derive(Resource)]
 pub struct RpcRegistry {
-    handlers: HashMap<
-        &'static str,
-        Arc<dyn Fn(&mut World, u64, Value, &Arc<Mutex<Option<WsResponse>>>) + Send + Sync>,
-    >,
+    handlers: HashMap<&'static str, RpcHandler>,
+    infos: BTreeMap<&'static str, RpcCommandInfo>,
+}
+
+impl Default for RpcRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            handlers: HashMap::new(),
+            infos: BTreeMap::new(),
+        };
+        registry.insert::<cmd::ListCommands>(Arc::new(
+            |world, _conn_id, _scope, id, _params, response| {
+                let infos = world
+                    .get_resource::<RpcRegistry>()
+                    .map(RpcRegistry::commands)
+                    .unwrap_or_default();
+                *response.lock().unwrap_or_else(|err| err.into_inner()) =
+                    Some(match serde_json::to_value(infos) {
+                        Ok(data) => WsResponse::ok_with_data(id, data),
+                        Err(e) => WsResponse::err(id, e.to_string()),
+                    });
+            },
+        ));
+        registry
+    }
 }
 
 impl RpcRegistry {
-    /// 注册一条命令：将命令名 `name` 映射到 `trigger::<T>`，
-    /// `T` 在闭包内被擦除为动态分发。
-    pub fn register<T: DeserializeOwned + Send + Sync + 'static>(&mut self, name: &'static str) {
-        self.handlers.insert(
-            name,
-            Arc::new(|world, id, params, response| {
-                trigger::<T>(world, id, params, response);
-            }),
-        );
+    /// 注册一条命令：将契约 `C` 的命令名映射到 `trigger::<C>`，在闭包内被擦除为动态分发。
+    /// 参数按 `C::Params` 解析，Schema 与解析用的是同一个类型。
+    pub fn register<C: RpcCommand>(&mut self) {
+        self.insert::<C>(Arc::new(|world, conn_id, scope, id, params, response| {
+            trigger::<C>(world, conn_id, scope, id, params, response);
+        }));
+    }
+
+    fn insert<C: RpcCommand>(&mut self, handler: RpcHandler) {
+        self.handlers.insert(C::NAME, handler);
+        self.infos.insert(C::NAME, RpcCommandInfo::of::<C>());
+    }
+
+    /// 已注册命令的描述，按命令名排序。
+    pub fn commands(&self) -> Vec<RpcCommandInfo> {
+        self.infos.values().cloned().collect()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.handlers.contains_key(name)
     }
 }
 
 /// Bevy App 扩展：让各插件就地注册 RPC 命令。
 pub trait RpcAppExt {
-    /// 注册一条 RPC 命令（首次调用会初始化 `RpcRegistry` 资源）。
-    fn register_rpc<T: DeserializeOwned + Send + Sync + 'static>(&mut self, name: &'static str);
+    /// 按契约 `C` 注册一条 RPC 命令，处理函数监听 `On<CommandWsRequest<C>>`
+    /// （首次调用会初始化 `RpcRegistry` 资源）。
+    fn register_rpc<C: RpcCommand>(&mut self);
 }
 
 impl RpcAppExt for App {
-    fn register_rpc<T: DeserializeOwned + Send + Sync + 'static>(&mut self, name: &'static str) {
+    fn register_rpc<C: RpcCommand>(&mut self) {
         self.init_resource::<RpcRegistry>();
         self.world_mut()
             .resource_mut::<RpcRegistry>()
-            .register::<T>(name);
+            .register::<C>();
+    }
+}
+
+/// 解析请求参数。无参数命令的契约参数为 `()`，除 `null` 外也接受空对象 `{}`。
+fn parse_params<P: DeserializeOwned>(params: Value) -> Result<P, serde_json::Error> {
+    let empty_object = params.as_object().is_some_and(|m| m.is_empty());
+    match serde_json::from_value(params) {
+        Err(e) if empty_object => serde_json::from_value(Value::Null).map_err(|_| e),
+        result => result,
     }
 }
 
-pub fn trigger<T: DeserializeOwned + Send + Sync + 'static>(
+pub fn trigger<C: RpcCommand>(
     world: &mut World,
+    conn_id: u64,
+    scope: ControlScope,
     id: u64,
     params: Value,
     response: &Arc<Mutex<Option<WsResponse>>>,
 ) {
-    let params: T = match serde_json::from_value(params) {
+    let params: C::Params = match parse_params(params) {
         Ok(p) => p,
         Err(e) => {
             *response.lock().unwrap_or_else(|err| err.into_inner()) =
@@ -65,20 +129,35 @@ pub fn trigger<T: DeserializeOwned + Send + Sync + 'static>(
             return;
         }
     };
-    world.trigger(CommandWsRequest {
+    world.trigger(CommandWsRequest::<C> {
+        conn_id,
+        scope,
         id,
         params,
         response: response.clone(),
     });
 }
 
-pub fn dispatch(world: &mut World, id: u64, cmd: &str, params: Value) -> WsResponse {
+/// 以不限操控范围分发一条命令（进程内调用、开放模式）。
+pub fn dispatch(world: &mut World, conn_id: u64, id: u64, cmd: &str, params: Value) -> WsResponse {
+    dispatch_scoped(world, conn_id, ControlScope::Any, id, cmd, params)
+}
+
+/// 分发一条命令，`scope` 为发起连接可操控的英雄范围。
+pub fn dispatch_scoped(
+    world: &mut World,
+    conn_id: u64,
+    scope: ControlScope,
+    id: u64,
+    cmd: &str,
+    params: Value,
+) -> WsResponse {
     let response: Arc<Mutex<Option<WsResponse>>> = Arc::new(Mutex::new(None));
     let handler = world
         .get_resource::<RpcRegistry>()
         .and_then(|r| r.handlers.get(cmd).cloned());
     if let Some(h) = handler {
-        h(world, id, params, &response);
+        h(world, conn_id, scope, id, params, &response);
     }
 
     let lock = response.lock().unwrap_or_else(|e| e.into_inner());
@@ -88,7 +167,7 @@ pub fn dispatch(world: &mut World, id: u64, cmd: &str, params: Value) -> WsRespo
 
 // ── Shared Helpers ──
 
-pub fn respond<T>(event: &CommandWsRequest<T>, result: Result<Value, String>) {
+pub fn respond<C: RpcCommand>(event: &CommandWsRequest<C>, result: Result<Value, String>) {
     if let Ok(mut lock) = event.response.lock() {
         *lock = Some(match result {
             Ok(data) => WsResponse::ok_with_data(event.id, data),
@@ -141,4 +220,41 @@ mod tests {
         // Test fallback failing
         assert!(resolve_target(None, exists, || None).is_err());
     }
+
+    #[test]
+    fn list_commands_reports_registered_schemas() {
+        let mut world = World::new();
+        let mut registry = RpcRegistry::default();
+        registry.register::<cmd::GetTime>();
+        world.insert_resource(registry);
+
+        let resp = dispatch(
+            &mut world,
+            LOCAL_CONNECTION,
+            1,
+            "list_commands",
+            Value::Null,
+        );
+        assert!(resp.ok);
+        let infos: Vec<RpcCommandInfo> = serde_json::from_value(resp.data.unwrap()).unwrap();
+        let names: Vec<_> = infos.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, ["get_time", "list_commands"]);
+        assert!(infos[0].result_schema["properties"]["time"].is_object());
+
+        let resp = dispatch(&mut world, LOCAL_CONNECTION, 2, "no_such_cmd", Value::Null);
+        assert!(!resp.ok);
+    }
+
+    #[test]
+    fn params_parse_as_contract_type() {
+        let set_speed: lol_client::rpc::SetSpeedParams =
+            parse_params(serde_json::json!({ "speed": 2.0 })).unwrap();
+        assert_eq!(set_speed.speed, 2.0);
+        assert!(parse_params::<lol_client::rpc::SetSpeedParams>(Value::Null).is_err());
+
+        // 无参数命令接受 null 与空对象，拒绝其它形态。
+        parse_params::<()>(Value::Null).unwrap();
+        parse_params::<()>(serde_json::json!({})).unwrap();
+        assert!(parse_params::<()>(serde_json::json!({ "x": 1 })).is_err());
+    }
 }
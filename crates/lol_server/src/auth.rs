@@ -0,0 +1,171 @@
+//! WS 连接级鉴权与限流。
+//!
+//! 握手阶段按令牌确定连接角色（见 [`lol_client::auth`]），之后每条请求先过令牌桶限流，
+//! 再按 RPC 契约中的最低角色校验作用域；被拒请求由传输层直接回复，不进入 Bevy 分发。
+//! 操控类命令针对的具体英雄由 Bevy 侧处理函数按连接的 [`ControlScope`] 校验。
+
+use std::time::Instant;
+
+use lol_client::auth::{ControlScope, WsAuthConfig, WsRateLimit, WsRole, token_from_query};
+use lol_client::rpc::required_role;
+
+use crate::protocol::{CMD_SET_ENCODING, WsErrorCode, WsResponse};
+
+/// 单连接令牌桶。
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    limit: WsRateLimit,
+    tokens: f32,
+    last: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(limit: WsRateLimit, now: Instant) -> Self {
+        Self {
+            limit,
+            tokens: limit.burst as f32,
+            last: now,
+        }
+    }
+
+    /// 尝试消耗一个令牌；桶空时返回 false。
+    pub fn try_acquire(&mut self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.last).as_secs_f32();
+        self.last = now;
+        self.tokens = (self.tokens + elapsed * self.limit.per_second).min(self.limit.burst as f32);
+        if self.tokens < 1.0 {
+            return false;
+        }
+        self.tokens -= 1.0;
+        true
+    }
+}
+
+/// 已通过握手的连接的访问控制状态。
+#[derive(Debug, Clone)]
+pub struct ConnectionGuard {
+    pub role: WsRole,
+    /// 可操控的英雄范围，随每条请求转交 Bevy 侧校验。
+    pub scope: ControlScope,
+    limiter: Option<RateLimiter>,
+}
+
+impl ConnectionGuard {
+    /// 开放模式（未配置鉴权）：完全权限、不限流，与启用鉴权前的行为一致。
+    pub fn open() -> Self {
+        Self {
+            role: WsRole::Debug,
+            scope: ControlScope::Any,
+            limiter: None,
+        }
+    }
+
+    /// 按握手 URI 的 query 与 `Authorization: Bearer` 头确定角色；令牌缺失或无效返回 Err。
+    pub fn authenticate(
+        auth: &WsAuthConfig,
+        query: Option<&str>,
+        authorization: Option<&str>,
+        now: Instant,
+    ) -> Result<Self, String> {
+        let token = query
+            .and_then(token_from_query)
+            .or_else(|| authorization.and_then(|h| h.strip_prefix("Bearer ")))
+            .ok_or_else(|| "缺少访问令牌".to_string())?;
+        let token = token.trim();
+        let role = auth
+            .role_of(token)
+            .ok_or_else(|| "访问令牌无效".to_string())?;
+        Ok(Self {
+            role,
+            scope: auth.scope_of(token),
+            limiter: Some(RateLimiter::new(auth.rate_limit, now)),
+        })
+    }
+
+    /// 校验一条请求；放行返回 None，拒绝返回应直接回给客户端的响应。
+    ///
+    /// 不在 RPC 契约中的命令按 [`WsRole::Debug`] 处理；`set_encoding` 对所有角色开放。
+    pub fn check(&mut self, id: u64, cmd: &str, now: Instant) -> Option<WsResponse> {
+        if let Some(limiter) = self.limiter.as_mut()
+            && !limiter.try_acquire(now)
+        {
+            return Some(WsResponse::rejected(
+                id,
+                WsErrorCode::RateLimited,
+                "请求过于频繁，请稍后重试".to_string(),
+            ));
+        }
+        if cmd == CMD_SET_ENCODING {
+            return None;
+        }
+        let required = required_role(cmd).unwrap_or(WsRole::Debug);
+        if self.role.allows(required) {
+            None
+        } else {
+            Some(WsResponse::rejected(
+                id,
+                WsErrorCode::Forbidden,
+                format!("当前连接角色 {:?} 无权调用 {}", self.role, cmd),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn rate_limiter_refills_over_time() {
+        let start = Instant::now();
+        let mut limiter = RateLimiter::new(
+            WsRateLimit {
+                per_second: 2.0,
+                burst: 2,
+            },
+            start,
+        );
+        assert!(limiter.try_acquire(start));
+        assert!(limiter.try_acquire(start));
+        assert!(!limiter.try_acquire(start));
+        assert!(limiter.try_acquire(start + Duration::from_millis(500)));
+        assert!(!limiter.try_acquire(start + Duration::from_millis(600)));
+    }
+
+    #[test]
+    fn agent_cannot_call_debug_commands() {
+        let now = Instant::now();
+        let mut auth = WsAuthConfig::default();
+        let agent = auth.issue_agent(["blue_1".to_string()]);
+        let spectator = auth.issue(WsRole::Spectator);
+        let relay = auth.issue(WsRole::Relay);
+
+        assert!(ConnectionGuard::authenticate(&auth, None, None, now).is_err());
+        assert!(ConnectionGuard::authenticate(&auth, Some("token=nope"), None, now).is_err());
+
+        let query = format!("token={agent}");
+        let mut guard = ConnectionGuard::authenticate(&auth, Some(&query), None, now).unwrap();
+        assert!(guard.check(1, "action", now).is_none());
+        let denied = guard.check(2, "god_mode", now).unwrap();
+        assert_eq!(denied.code, Some(WsErrorCode::Forbidden));
+        assert!(guard.check(3, "set_encoding", now).is_none());
+        assert!(guard.check(7, "subscribe_world", now).is_some());
+        assert!(guard.scope.allows(Some("blue_1")));
+        assert!(!guard.scope.allows(Some("red_1")));
+
+        let header = format!("Bearer {spectator}");
+        let mut guard = ConnectionGuard::authenticate(&auth, None, Some(&header), now).unwrap();
+        assert!(guard.check(4, "observe", now).is_none());
+        assert!(guard.check(5, "action", now).is_some());
+        assert!(guard.check(8, "subscribe_world", now).is_some());
+
+        let query = format!("token={relay}");
+        let mut guard = ConnectionGuard::authenticate(&auth, Some(&query), None, now).unwrap();
+        assert!(guard.check(9, "subscribe_world", now).is_none());
+        assert!(guard.check(10, "action", now).is_some());
+
+        assert!(ConnectionGuard::open().check(6, "god_mode", now).is_none());
+    }
+}
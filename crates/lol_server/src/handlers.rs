@@ -1,8 +1,16 @@
 use bevy::prelude::*;
+use lol_client::auth::ControlScope;
 
 use crate::protocol::WsResponse;
 
 /// Dispatch a WS command by broadcasting a generic Bevy Event.
-pub fn dispatch(world: &mut World, id: u64, cmd: String, params: serde_json::Value) -> WsResponse {
-    lol_rpc::dispatch(world, id, &cmd, params)
+pub fn dispatch(
+    world: &mut World,
+    conn_id: u64,
+    scope: ControlScope,
+    id: u64,
+    cmd: String,
+    params: serde_json::Value,
+) -> WsResponse {
+    lol_rpc::dispatch_scoped(world, conn_id, scope, id, &cmd, params)
 }
@@ -1,13 +1,17 @@
+pub mod auth;
 pub mod handlers;
 pub mod protocol;
 pub mod server;
 
 use async_channel::Receiver;
 use bevy::prelude::*;
+use lol_client::auth::{WS_AUTH_ENV, WsAuthConfig};
 use lol_core::match_events::{MatchEventChannel, MatchEventOut};
 
 /// 通用 WS 服务基础设施：因为粒子渲染 server 等非对局进程也需要 WS RPC 能力，
 /// 所以把「启动监听 + 轮询命令分发」抽为独立插件，不含任何对局事件语义。
+///
+/// 鉴权配置取自环境变量 [`WS_AUTH_ENV`]（托管对局由启动方注入），未设置时保持开放。
 pub struct PluginWsServer {
     pub ws_port: u16,
 }
@@ -32,7 +36,15 @@ struct WsServerPort(u16);
 /// 通过 .after() 确保 DebugWsChannel 已插入，所以不能用匿名闭包。
 fn startup_ws_server(world: &mut World) {
     let port = world.resource::<WsServerPort>().0;
-    server::start(world, port);
+    // 配置存在但无法解析时宁可拒绝所有连接，也不退化为开放模式。
+    let auth = WsAuthConfig::from_env().unwrap_or_else(|e| {
+        error!("{}，WS 服务将拒绝所有连接", e);
+        Some(WsAuthConfig::default())
+    });
+    if auth.is_some() {
+        info!("WS 服务已启用令牌鉴权（{}）", WS_AUTH_ENV);
+    }
+    server::start(world, port, auth);
 }
 
 pub struct PluginServer {
@@ -1,36 +1,131 @@
 #[cfg(not(target_arch = "wasm32"))]
 use std::sync::Arc;
 #[cfg(not(target_arch = "wasm32"))]
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(not(target_arch = "wasm32"))]
 use std::thread;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
 
 use async_channel::{Receiver, Sender};
 use bevy::prelude::*;
 #[cfg(not(target_arch = "wasm32"))]
 use futures_util::{SinkExt, StreamExt};
+use lol_client::auth::{ControlScope, WsAuthConfig};
+#[cfg(not(target_arch = "wasm32"))]
+use lol_client::codec;
 #[cfg(not(target_arch = "wasm32"))]
 use tokio::net::TcpListener;
 #[cfg(not(target_arch = "wasm32"))]
 use tokio::sync::Mutex as TokioMutex;
 #[cfg(not(target_arch = "wasm32"))]
-use tokio_tungstenite::accept_async;
+use tokio_tungstenite::accept_hdr_async;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+#[cfg(not(target_arch = "wasm32"))]
+use tokio_tungstenite::tungstenite::http::{StatusCode, header::AUTHORIZATION};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::auth::ConnectionGuard;
 
-use crate::protocol::{WsEvent, WsRequest};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::protocol::{CMD_SET_ENCODING, SetEncodingParams, WsEncoding};
+use crate::protocol::{WsEvent, WsRequest, WsResponse};
+
+/// 一条来自 WS 连接的命令。
+#[derive(Debug, Clone)]
+pub struct WsCommand {
+    /// 发起连接的 ID（从 1 开始分配；`lol_rpc::LOCAL_CONNECTION` 表示进程内来源）。
+    pub conn_id: u64,
+    pub id: u64,
+    pub cmd: String,
+    pub params: serde_json::Value,
+    /// 发起连接可操控的英雄范围（见 [`ConnectionGuard`](crate::auth::ConnectionGuard)）。
+    pub scope: ControlScope,
+}
+
+/// 发往 WS 客户端的消息（未编码）。每个连接按自身协商的编码序列化。
+#[derive(Debug, Clone)]
+pub enum WsServerMessage {
+    Response(WsResponse),
+    Event(WsEvent),
+}
+
+/// 出站消息及其投递范围：`conn_id` 为 None 时广播给所有连接。
+#[derive(Debug, Clone)]
+pub struct WsOutbound {
+    pub conn_id: Option<u64>,
+    pub message: WsServerMessage,
+}
+
+/// WS 连接断开通知，由 [`poll_commands`] 在 Bevy 侧触发，
+/// 持有连接级状态（如观测订阅）的模块据此清理。
+#[derive(Event, Debug, Clone, Copy)]
+pub struct WsConnectionClosed {
+    pub conn_id: u64,
+}
 
 /// Channel for Bevy ← WS communication.
 #[derive(Resource)]
 pub struct DebugWsChannel {
     /// Receives commands from WS connections.
-    pub cmd_rx: Receiver<(u64, String, serde_json::Value)>,
-    /// Sends JSON-serialized WsResponse / WsEvent strings to all connected WS clients.
-    pub out_tx: Sender<String>,
+    pub cmd_rx: Receiver<WsCommand>,
+    /// Sends WsResponse / WsEvent to connected WS clients (broadcast or targeted).
+    pub out_tx: Sender<WsOutbound>,
+    /// Receives IDs of closed connections.
+    pub closed_rx: Receiver<u64>,
+}
+
+impl DebugWsChannel {
+    /// 广播事件给所有连接。
+    pub fn broadcast(&self, event: WsEvent) {
+        let _ = self.out_tx.try_send(WsOutbound {
+            conn_id: None,
+            message: WsServerMessage::Event(event),
+        });
+    }
+
+    /// 仅向指定连接推送事件。
+    pub fn send_to(&self, conn_id: u64, event: WsEvent) {
+        let _ = self.out_tx.try_send(WsOutbound {
+            conn_id: Some(conn_id),
+            message: WsServerMessage::Event(event),
+        });
+    }
+}
+
+/// 单个连接写任务的输入。
+#[cfg(not(target_arch = "wasm32"))]
+enum ClientOut {
+    Message(Arc<WsServerMessage>),
+    /// 先以当前编码写出回执，再切换到新编码。
+    SwitchEncoding(WsEncoding, WsResponse),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn encode_message(
+    encoding: WsEncoding,
+    message: &WsServerMessage,
+) -> Result<tokio_tungstenite::tungstenite::Message, String> {
+    match message {
+        WsServerMessage::Response(resp) => codec::encode(encoding, resp),
+        WsServerMessage::Event(event) => codec::encode(encoding, event),
+    }
 }
 
 /// Start the tokio WS server on a background thread.
 /// Inserts DebugWsChannel into the world so Bevy systems can poll it.
+///
+/// 每个连接默认使用 JSON 文本帧；收到 `set_encoding` 后由传输层直接切换
+/// 该连接的出站编码（不进入 Bevy RPC 分发）。入站帧按帧类型解码，两种编码均可。
+///
+/// `auth` 为 Some 时握手须携带有效令牌（否则以 HTTP 401 拒绝），每条请求再经
+/// [`ConnectionGuard`] 限流与角色作用域校验；为 None 时保持开放。
 #[cfg(not(target_arch = "wasm32"))]
-pub fn start(world: &mut World, port: u16) {
-    let (cmd_tx, cmd_rx) = async_channel::unbounded::<(u64, String, serde_json::Value)>();
-    let (out_tx, out_rx) = async_channel::unbounded::<String>();
+pub fn start(world: &mut World, port: u16, auth: Option<WsAuthConfig>) {
+    let (cmd_tx, cmd_rx) = async_channel::unbounded::<WsCommand>();
+    let (out_tx, out_rx) = async_channel::unbounded::<WsOutbound>();
+    let (closed_tx, closed_rx) = async_channel::unbounded::<u64>();
 
     thread::spawn(move || {
         let rt = tokio::runtime::Runtime::new().expect("failed to create tokio runtime");
@@ -39,19 +134,28 @@ pub fn start(world: &mut World, port: u16) {
                 .await
                 .expect("failed to bind WS port");
 
-            // Connected clients' write halves.
-            let clients: Arc<TokioMutex<Vec<tokio::sync::mpsc::UnboundedSender<String>>>> =
-                Arc::new(TokioMutex::new(vec![]));
+            // Connected clients' (conn_id, write queue).
+            let clients: Arc<
+                TokioMutex<Vec<(u64, tokio::sync::mpsc::UnboundedSender<ClientOut>)>>,
+            > = Arc::new(TokioMutex::new(vec![]));
+            let next_conn_id = Arc::new(AtomicU64::new(1));
+            let auth = Arc::new(auth);
 
-            // Fan-out task: reads from async_channel, sends to every connected client.
+            // Fan-out task: reads from async_channel, routes to the target client(s).
             let clients_fanout = clients.clone();
             tokio::spawn(async move {
                 loop {
-                    let Ok(msg) = out_rx.recv().await else {
+                    let Ok(out) = out_rx.recv().await else {
                         break;
                     };
+                    let message = Arc::new(out.message);
                     let mut clients = clients_fanout.lock().await;
-                    clients.retain(|tx| tx.send(msg.clone()).is_ok());
+                    clients.retain(|(conn_id, tx)| {
+                        if out.conn_id.is_some_and(|target| target != *conn_id) {
+                            return true;
+                        }
+                        tx.send(ClientOut::Message(message.clone())).is_ok()
+                    });
                 }
             });
 
@@ -60,40 +164,111 @@ pub fn start(world: &mut World, port: u16) {
                 let Ok((stream, _)) = listener.accept().await else {
                     continue;
                 };
-                let Ok(ws_stream) = accept_async(stream).await else {
+                let mut guard = None;
+                let handshake =
+                    |req: &Request, resp: Response| -> Result<Response, ErrorResponse> {
+                        let Some(config) = &*auth else {
+                            guard = Some(ConnectionGuard::open());
+                            return Ok(resp);
+                        };
+                        let authorization = req
+                            .headers()
+                            .get(AUTHORIZATION)
+                            .and_then(|v| v.to_str().ok());
+                        match ConnectionGuard::authenticate(
+                            config,
+                            req.uri().query(),
+                            authorization,
+                            Instant::now(),
+                        ) {
+                            Ok(g) => {
+                                guard = Some(g);
+                                Ok(resp)
+                            }
+                            Err(reason) => {
+                                warn!("拒绝 WS 连接: {}", reason);
+                                let mut rejection = ErrorResponse::new(Some(reason));
+                                *rejection.status_mut() = StatusCode::UNAUTHORIZED;
+                                Err(rejection)
+                            }
+                        }
+                    };
+                let Ok(ws_stream) = accept_hdr_async(stream, handshake).await else {
+                    continue;
+                };
+                let Some(mut guard) = guard else {
                     continue;
                 };
 
+                let conn_id = next_conn_id.fetch_add(1, Ordering::SeqCst);
                 let (mut write, mut read) = ws_stream.split();
-                let (client_tx, mut client_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+                let (client_tx, mut client_rx) =
+                    tokio::sync::mpsc::unbounded_channel::<ClientOut>();
 
-                clients.lock().await.push(client_tx);
+                clients.lock().await.push((conn_id, client_tx.clone()));
 
                 let cmd_tx = cmd_tx.clone();
+                let closed_tx = closed_tx.clone();
 
                 // Read task: WS messages → command channel.
                 tokio::spawn(async move {
                     while let Some(Ok(msg)) = read.next().await {
-                        let text = match msg.to_text() {
-                            Ok(t) => t,
-                            Err(_) => continue,
+                        let req: WsRequest = match codec::decode(&msg) {
+                            Some(Ok(r)) => r,
+                            _ => continue,
                         };
-                        let req: WsRequest = match serde_json::from_str(text) {
-                            Ok(r) => r,
-                            Err(_) => continue,
-                        };
-                        let _ = cmd_tx.send((req.id, req.cmd, req.params)).await;
+                        if let Some(rejection) = guard.check(req.id, &req.cmd, Instant::now()) {
+                            let _ = client_tx.send(ClientOut::Message(Arc::new(
+                                WsServerMessage::Response(rejection),
+                            )));
+                            continue;
+                        }
+                        if req.cmd == CMD_SET_ENCODING {
+                            match serde_json::from_value::<SetEncodingParams>(req.params) {
+                                Ok(p) => {
+                                    let _ = client_tx.send(ClientOut::SwitchEncoding(
+                                        p.encoding,
+                                        WsResponse::ok(req.id),
+                                    ));
+                                }
+                                Err(e) => {
+                                    let resp = WsResponse::err(req.id, format!("无效参数: {}", e));
+                                    let _ = client_tx.send(ClientOut::Message(Arc::new(
+                                        WsServerMessage::Response(resp),
+                                    )));
+                                }
+                            }
+                            continue;
+                        }
+                        let _ = cmd_tx
+                            .send(WsCommand {
+                                conn_id,
+                                id: req.id,
+                                cmd: req.cmd,
+                                params: req.params,
+                                scope: guard.scope.clone(),
+                            })
+                            .await;
                     }
+                    let _ = closed_tx.send(conn_id).await;
                 });
 
-                // Write task: client_rx → WS frames.
+                // Write task: client_rx → WS frames, encoded per connection.
                 tokio::spawn(async move {
-                    while let Some(msg) = client_rx.recv().await {
-                        if write
-                            .send(tokio_tungstenite::tungstenite::Message::Text(msg.into()))
-                            .await
-                            .is_err()
-                        {
+                    let mut encoding = WsEncoding::Json;
+                    while let Some(out) = client_rx.recv().await {
+                        let frame = match out {
+                            ClientOut::Message(message) => encode_message(encoding, &message),
+                            ClientOut::SwitchEncoding(next, ack) => {
+                                let frame = codec::encode(encoding, &ack);
+                                encoding = next;
+                                frame
+                            }
+                        };
+                        let Ok(frame) = frame else {
+                            continue;
+                        };
+                        if write.send(frame).await.is_err() {
                             break;
                         }
                     }
@@ -102,30 +277,40 @@ pub fn start(world: &mut World, port: u16) {
         });
     });
 
-    world.insert_resource(DebugWsChannel { cmd_rx, out_tx });
+    world.insert_resource(DebugWsChannel {
+        cmd_rx,
+        out_tx,
+        closed_rx,
+    });
 }
 
 /// On Web, we only use channels for communication.
 #[cfg(target_arch = "wasm32")]
-pub fn start(world: &mut World, _port: u16) {
-    let (cmd_tx, cmd_rx) = async_channel::unbounded::<(u64, String, serde_json::Value)>();
-    let (out_tx, out_rx) = async_channel::unbounded::<String>();
+pub fn start(world: &mut World, _port: u16, _auth: Option<WsAuthConfig>) {
+    let (cmd_tx, cmd_rx) = async_channel::unbounded::<WsCommand>();
+    let (out_tx, out_rx) = async_channel::unbounded::<WsOutbound>();
+    let (closed_tx, closed_rx) = async_channel::unbounded::<u64>();
 
     // On Web, the out_rx could be polled by a UI system or wasm-bindgen bridge.
     // For now, we just keep the channels available.
     // We need to keep cmd_tx somewhere if we want to send commands to Bevy from JS.
 
-    world.insert_resource(DebugWsChannel { cmd_rx, out_tx });
+    world.insert_resource(DebugWsChannel {
+        cmd_rx,
+        out_tx,
+        closed_rx,
+    });
     // To prevent out_rx from being dropped (and out_tx failing), we might need to store it or handle it.
     // However, if nothing reads from it, it's fine as long as we don't expect events to be delivered yet.
     // We'll leak it or ignore it for now to satisfy the "use channel" requirement.
     std::mem::forget(out_rx);
     std::mem::forget(cmd_tx);
+    std::mem::forget(closed_tx);
 }
 
 /// 用于在自定义步进模式下，主线程暂存从通道中同步阻塞读取出来的命令包。
 #[derive(Resource, Default)]
-pub struct PendingCommands(pub Vec<(u64, String, serde_json::Value)>);
+pub struct PendingCommands(pub Vec<WsCommand>);
 
 /// Bevy Update system — poll incoming commands and dispatch to handlers.
 /// Runs every frame; non-blocking via try_recv.
@@ -137,37 +322,55 @@ pub fn poll_commands(world: &mut World) {
         cmds.append(&mut pending.0);
     }
 
-    // 2. 再读取通道中的新命令
-    let cmd_rx = world
+    // 2. 再读取通道中的新命令与断开通知
+    let channels = world
         .get_resource::<DebugWsChannel>()
-        .map(|ch| ch.cmd_rx.clone());
+        .map(|ch| (ch.cmd_rx.clone(), ch.closed_rx.clone()));
 
-    if let Some(cmd_rx) = cmd_rx {
+    let mut closed = Vec::new();
+    if let Some((cmd_rx, closed_rx)) = channels {
         while let Ok(cmd_packet) = cmd_rx.try_recv() {
             cmds.push(cmd_packet);
         }
+        while let Ok(conn_id) = closed_rx.try_recv() {
+            closed.push(conn_id);
+        }
     }
 
-    // 3. 分发并响应所有命令
-    for (id, cmd, params) in cmds {
-        let response = crate::handlers::dispatch(world, id, cmd, params);
-        let out_tx = world
-            .get_resource::<DebugWsChannel>()
-            .map(|ch| ch.out_tx.clone());
-
-        if let Some(out_tx) = out_tx {
-            if let Ok(json) = serde_json::to_string(&response) {
-                let _ = out_tx.try_send(json);
-            }
+    // 3. 分发并把响应定向回发起连接
+    for WsCommand {
+        conn_id,
+        id,
+        cmd,
+        params,
+        scope,
+    } in cmds
+    {
+        let response = crate::handlers::dispatch(world, conn_id, scope, id, cmd, params);
+        if let Some(ch) = world.get_resource::<DebugWsChannel>() {
+            let _ = ch.out_tx.try_send(WsOutbound {
+                conn_id: Some(conn_id),
+                message: WsServerMessage::Response(response),
+            });
         }
     }
+
+    // 4. 断开的连接放在命令之后处理，保证其最后的命令仍被执行
+    for conn_id in closed {
+        world.trigger(WsConnectionClosed { conn_id });
+    }
 }
 
 /// Send a WsEvent to all connected WS clients.
 pub fn send_event(world: &World, event: WsEvent) {
     if let Some(ch) = world.get_resource::<DebugWsChannel>() {
-        if let Ok(json) = serde_json::to_string(&event) {
-            let _ = ch.out_tx.try_send(json);
-        }
+        ch.broadcast(event);
+    }
+}
+
+/// Send a WsEvent to a single WS client.
+pub fn send_event_to(world: &World, conn_id: u64, event: WsEvent) {
+    if let Some(ch) = world.get_resource::<DebugWsChannel>() {
+        ch.send_to(conn_id, event);
     }
 }
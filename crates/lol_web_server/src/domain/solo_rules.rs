@@ -7,6 +7,7 @@
 //! 规则（与 docs/product/match/product.md §3.C 一致）：
 //! 先达成任一即胜——拿一血 / 推掉对方一塔 / 补刀满 100；
 //! 若游戏超过 15 分钟仍未分胜负，则按补刀数判定胜负（多者胜，相等为平局）。
+//! 可选变体 [`SoloRule::first_plate_wins`]：击破首块防御塔镀层即视同一塔。
 //!
 //! "先到先得"语义由调用方按事件到达顺序逐个喂入实现：每条事件只可能让状态前进，
 //! [`evaluate`] 在状态首次满足任一胜利条件时返回 [`SoloVerdict`]，之后调用方应停止。
@@ -22,6 +23,9 @@ pub struct SoloRule {
     pub cs_target: u32,
     /// 超时阈值（秒）。超时后按补刀数判胜负。
     pub time_limit_secs: f64,
+    /// "一镀层"变体：首个击破对方防御塔镀层的一方获胜。默认关闭。
+    #[serde(default)]
+    pub first_plate_wins: bool,
 }
 
 impl Default for SoloRule {
@@ -29,6 +33,7 @@ impl Default for SoloRule {
         Self {
             cs_target: 100,
             time_limit_secs: 15.0 * 60.0,
+            first_plate_wins: false,
         }
     }
 }
@@ -42,9 +47,25 @@ pub struct SoloState {
     /// Order 方已摧毁的对方防御塔数（首次 0→1 即一塔）。
     pub order_towers: u32,
     pub chaos_towers: u32,
+    /// Order 方击破的对方防御塔镀层数。
+    pub order_plates: u32,
+    pub chaos_plates: u32,
     /// 补刀数（Creep Score）。
     pub order_cs: u32,
     pub chaos_cs: u32,
+    /// 元素龙击杀数（含拿到龙魂前后的所有元素龙，不含远古龙）。
+    pub order_dragons: u32,
+    pub chaos_dragons: u32,
+    /// 远古龙 / 峡谷先锋 / 纳什男爵击杀数。
+    pub order_elders: u32,
+    pub chaos_elders: u32,
+    pub order_heralds: u32,
+    pub chaos_heralds: u32,
+    pub order_barons: u32,
+    pub chaos_barons: u32,
+    /// 是否已拿到龙魂。
+    pub order_dragon_soul: bool,
+    pub chaos_dragon_soul: bool,
     /// 对局已进行秒数。
     pub elapsed_secs: u32,
     /// 一旦命中胜负条件，置为 true；调用方应停止喂事件。
@@ -59,6 +80,8 @@ pub enum SoloWinReason {
     FirstBlood,
     /// 一塔。
     FirstTower,
+    /// 一镀层（需开启 `first_plate_wins`）。
+    FirstPlate,
     /// 补刀达到阈值。
     CsTarget,
     /// 超时后按补刀数判定。
@@ -110,6 +133,22 @@ pub fn evaluate(state: &SoloState, rule: &SoloRule) -> Option<SoloVerdict> {
         });
     }
 
+    // 一镀层变体：任一方首次击破对方镀层。
+    if rule.first_plate_wins {
+        if state.order_plates > 0 {
+            return Some(SoloVerdict {
+                winner: Winner::Order,
+                reason: SoloWinReason::FirstPlate,
+            });
+        }
+        if state.chaos_plates > 0 {
+            return Some(SoloVerdict {
+                winner: Winner::Chaos,
+                reason: SoloWinReason::FirstPlate,
+            });
+        }
+    }
+
     // 100 刀：补刀达到阈值。
     if state.order_cs >= rule.cs_target {
         return Some(SoloVerdict {
@@ -274,10 +313,31 @@ mod tests {
         let rule = SoloRule {
             cs_target: 50,
             time_limit_secs: 600.0,
+            first_plate_wins: false,
         };
         let mut s = state();
         s.order_cs = 50;
         let v = evaluate(&s, &rule).unwrap();
         assert_eq!(v.reason, SoloWinReason::CsTarget);
     }
+
+    #[test]
+    fn first_plate_ignored_by_default() {
+        let mut s = state();
+        s.chaos_plates = 1;
+        assert_eq!(evaluate(&s, &rule()), None);
+    }
+
+    #[test]
+    fn first_plate_variant_wins() {
+        let rule = SoloRule {
+            first_plate_wins: true,
+            ..SoloRule::default()
+        };
+        let mut s = state();
+        s.chaos_plates = 1;
+        let v = evaluate(&s, &rule).unwrap();
+        assert_eq!(v.winner, Winner::Chaos);
+        assert_eq!(v.reason, SoloWinReason::FirstPlate);
+    }
 }
@@ -2,16 +2,18 @@
 
 use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
 use axum::extract::{Path, Query, State};
-use axum::http::HeaderMap;
 use axum::http::header::AUTHORIZATION;
+use axum::http::{HeaderMap, StatusCode};
 use jsonwebtoken::{DecodingKey, Validation, decode};
 use lol_web_protocol::match_::{Match, MatchEvent};
 use serde::Deserialize;
+use tokio::sync::broadcast::error::RecvError;
 use uuid::Uuid;
 
 use super::response::{ApiResponse, api_error};
 use super::{AppState, AuthUser, JwtClaims};
 use crate::service::match_supervisor::{SpectatorMessage, get_broadcasters};
+use crate::service::spectator_gateway::{SpectateMessage, spectator_gateway};
 
 #[derive(Deserialize)]
 pub struct ListMatchesQuery {
@@ -99,6 +101,21 @@ pub async fn get_match_events_ws(
     Path(id): Path<Uuid>,
     Query(q): Query<WsEventsQuery>,
 ) -> impl axum::response::IntoResponse {
+    let user_id = match authorize_match_ws(&headers, q.token.clone(), &s, id).await {
+        Ok(user_id) => user_id,
+        Err(status) => return status_response(status),
+    };
+
+    ws.on_upgrade(move |socket| handle_ws_events(socket, s, id, user_id, q.from_seq.unwrap_or(0)))
+}
+
+/// 对局 WS 的握手鉴权：从 Header 或 Query 取 JWT 并校验对局可访问，返回 user_id。
+async fn authorize_match_ws(
+    headers: &HeaderMap,
+    query_token: Option<String>,
+    s: &AppState,
+    id: Uuid,
+) -> Result<i32, StatusCode> {
     // 1. 从 Header 或 Query 参数中获取并验证 Token
     let token = if let Some(auth_header) = headers.get(AUTHORIZATION).and_then(|v| v.to_str().ok())
     {
@@ -106,44 +123,34 @@ pub async fn get_match_events_ws(
             .strip_prefix("Bearer ")
             .unwrap_or(auth_header)
             .to_string()
-    } else if let Some(token_param) = q.token.clone() {
+    } else if let Some(token_param) = query_token {
         token_param
     } else {
-        return axum::response::Response::builder()
-            .status(axum::http::StatusCode::UNAUTHORIZED)
-            .body(axum::body::Body::empty())
-            .unwrap();
+        return Err(StatusCode::UNAUTHORIZED);
     };
 
     let secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| "moon-lol-secret-key-12345".into());
-    let claims = match decode::<JwtClaims>(
+    let claims = decode::<JwtClaims>(
         &token,
         &DecodingKey::from_secret(secret.as_bytes()),
         &Validation::default(),
-    ) {
-        Ok(data) => data.claims,
-        Err(_) => {
-            return axum::response::Response::builder()
-                .status(axum::http::StatusCode::UNAUTHORIZED)
-                .body(axum::body::Body::empty())
-                .unwrap();
-        }
-    };
+    )
+    .map_err(|_| StatusCode::UNAUTHORIZED)?
+    .claims;
 
     // 2. 校验对局是否存在且可访问
-    let user_id = claims.user_id;
-    match s.match_service.get(user_id, id).await {
-        Ok(_) => {}
-        Err(_) => {
-            return axum::response::Response::builder()
-                .status(axum::http::StatusCode::FORBIDDEN)
-                .body(axum::body::Body::empty())
-                .unwrap();
-        }
-    }
+    s.match_service
+        .get(claims.user_id, id)
+        .await
+        .map_err(|_| StatusCode::FORBIDDEN)?;
+    Ok(claims.user_id)
+}
 
-    // 3. 升级 WS 连接
-    ws.on_upgrade(move |socket| handle_ws_events(socket, s, id, user_id, q.from_seq.unwrap_or(0)))
+fn status_response(status: StatusCode) -> axum::response::Response {
+    axum::response::Response::builder()
+        .status(status)
+        .body(axum::body::Body::empty())
+        .unwrap()
 }
 
 async fn handle_ws_events(
@@ -225,3 +232,72 @@ async fn handle_ws_events(
         }
     }
 }
+
+#[derive(Deserialize)]
+pub struct WsSpectateQuery {
+    pub token: Option<String>,
+}
+
+/// 观战 WS：先推当前关键帧，再推延迟转播的增量帧（见 [`crate::service::spectator_gateway`]）。
+pub async fn get_match_spectate_ws(
+    ws: WebSocketUpgrade,
+    headers: HeaderMap,
+    State(s): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(q): Query<WsSpectateQuery>,
+) -> impl axum::response::IntoResponse {
+    if let Err(status) = authorize_match_ws(&headers, q.token, &s, id).await {
+        return status_response(status);
+    }
+    ws.on_upgrade(move |socket| handle_ws_spectate(socket, id))
+}
+
+async fn handle_ws_spectate(mut socket: WebSocket, match_id: Uuid) {
+    let gateway = spectator_gateway();
+    let mut last_seq = None;
+    // 每轮从关键帧开始；接收端积压掉帧（Lagged）时重新加入，从最新关键帧追上。
+    loop {
+        let Some((keyframe, mut rx)) = gateway.join(match_id) else {
+            let close_msg = SpectateMessage::Close {
+                reason: "match is not being broadcast".to_string(),
+            };
+            send_json(&mut socket, &close_msg).await;
+            return;
+        };
+        if let Some(frame) = keyframe {
+            last_seq = Some(frame.seq);
+            if !send_json(&mut socket, &SpectateMessage::Frame(frame)).await {
+                return;
+            }
+        }
+
+        loop {
+            match rx.recv().await {
+                Ok(msg) => {
+                    if let SpectateMessage::Frame(frame) = &msg {
+                        if last_seq.is_some_and(|seq| frame.seq <= seq) {
+                            continue;
+                        }
+                        last_seq = Some(frame.seq);
+                    }
+                    if !send_json(&mut socket, &msg).await {
+                        return;
+                    }
+                    if let SpectateMessage::Close { .. } = msg {
+                        return;
+                    }
+                }
+                Err(RecvError::Lagged(_)) => break,
+                Err(RecvError::Closed) => return,
+            }
+        }
+    }
+}
+
+/// 以 JSON 文本帧发送；连接已断开返回 false。
+async fn send_json<T: serde::Serialize>(socket: &mut WebSocket, msg: &T) -> bool {
+    match serde_json::to_string(msg) {
+        Ok(txt) => socket.send(Message::Text(txt.into())).await.is_ok(),
+        Err(_) => true,
+    }
+}
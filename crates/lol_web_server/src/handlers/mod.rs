@@ -241,6 +241,10 @@ pub fn create_router(state: AppState) -> Router {
             "/api/matches/:id/events/ws",
             get(match_::get_match_events_ws),
         )
+        .route(
+            "/api/matches/:id/spectate/ws",
+            get(match_::get_match_spectate_ws),
+        )
         .route("/api/matches/:id/stop", post(match_::stop_match))
         // Local Game
         .route("/api/local/start", post(local_game::local_start))
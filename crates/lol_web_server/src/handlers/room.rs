@@ -169,6 +169,7 @@ pub async fn start_room_match(
                 scenario_id: None,
                 win_condition: None,
                 scenario_agents: Vec::new(),
+                spectator_delay_secs: None,
             },
         )
         .await
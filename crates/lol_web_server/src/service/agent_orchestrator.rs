@@ -16,7 +16,7 @@ use lol_agent_runtime::{
     AgentConfig, CredentialResolver, NoopSink, PlatformEnv, ProviderCredentials,
     ResolvedCredentials, resolve_credentials, run_orchestrator,
 };
-use lol_client::{GameClient, start_ws_client};
+use lol_client::{GameClient, start_ws_client_with_token};
 use tracing::{info, warn};
 use uuid::Uuid;
 
@@ -83,8 +83,10 @@ impl CredentialResolver for WebCredentialResolver {
 /// 无 LLM 凭据或无 agent 配置时静默返回（不报错），与 Tauri 行为一致。
 /// `agents` 通常来自场景定义（`Scenario::agents` JSON）。
 /// `owner_id` 用于按 provider_id 解析该用户的供应商凭证。
+/// `ws_token` 为本局签发的 agent 角色令牌。
 pub async fn run_agent_orchestrator(
     ws_port: i32,
+    ws_token: String,
     agents: Vec<AgentConfig>,
     owner_id: i32,
     providers: Arc<dyn ModelProviderService>,
@@ -94,7 +96,7 @@ pub async fn run_agent_orchestrator(
         ws_port
     );
 
-    let session = match start_ws_client(ws_port as u16, None).await {
+    let session = match start_ws_client_with_token(ws_port as u16, Some(&ws_token), None).await {
         Ok(s) => s,
         Err(e) => {
             warn!("[Web Orchestrator] ws={} 连接 Bevy WS 失败: {}", ws_port, e);
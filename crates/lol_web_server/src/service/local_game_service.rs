@@ -9,6 +9,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use lol_agent_runtime::AgentConfig;
+use lol_client::auth::{WsAuthConfig, WsRole};
 use lol_game_process_manager::{
     GameProcessManager, ManagedProcess, ManagerError, ProcessLauncher, StartGameInput,
 };
@@ -20,7 +21,7 @@ use crate::domain::{ServiceError, ServiceResult};
 use crate::repository::match_repo::{MatchInput, MatchRepo};
 use crate::service::match_service::MatchService;
 use crate::service::model_provider_service::ModelProviderService;
-use crate::service::{agent_orchestrator, match_supervisor};
+use crate::service::{agent_orchestrator, match_supervisor, spectator_gateway};
 
 /// 本地对局启动输入。
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -31,6 +32,10 @@ pub struct LocalStartInput {
     /// 场景 agent 阵容：非空时启动 AI 决策环（接入进程内 rmcp 工具层）。
     #[serde(default)]
     pub scenario_agents: Vec<AgentConfig>,
+    /// 期望的观战延迟（秒）。实际延迟不低于对局形态的默认值（见 [`spectator_gateway::spectator_delay`]），
+    /// 更小的值被忽略。
+    #[serde(default)]
+    pub spectator_delay_secs: Option<u64>,
 }
 
 #[async_trait]
@@ -99,10 +104,20 @@ impl LocalGameService for LocalGameServiceImpl {
             )
             .await?;
 
-        // 2. 启动 Bevy 进程（端口池分配 + spawn + 进程表登记），委托 GameProcessManager
+        // 2. 签发本局 WS 令牌：supervisor 只读观战；AI 决策环仅 agent 权限（无调试 / 作弊面），
+        //    且只能操控场景中声明的 agent；观战 relay 持 relay 令牌订阅全量世界快照。
+        let mut ws_auth = WsAuthConfig::default();
+        let supervisor_token = ws_auth.issue(WsRole::Spectator);
+        let agent_token =
+            ws_auth.issue_agent(input.scenario_agents.iter().map(|agent| agent.id.clone()));
+        let relay_token = ws_auth.issue(WsRole::Relay);
+
+        // 3. 启动 Bevy 进程（端口池分配 + spawn + 进程表登记），委托 GameProcessManager
+        let mut spawn = cloud_spawn_request();
+        spawn.ws_auth = Some(ws_auth);
         let start_input = StartGameInput {
             id: match_record.id,
-            spawn: cloud_spawn_request(),
+            spawn,
             scenario_agents: Vec::new(), // 云端 AI 环在下方按 owner_id 显式 spawn
         };
         let (_proc_id, port) = match self.manager.start(start_input).await {
@@ -121,7 +136,7 @@ impl LocalGameService for LocalGameServiceImpl {
             }
         };
 
-        // 3. 更新 match 端口 + 状态为 running
+        // 4. 更新 match 端口 + 状态为 running
         self.match_repo
             .update_ports(match_record.id, Some(port), Some(port))
             .await?;
@@ -129,14 +144,22 @@ impl LocalGameService for LocalGameServiceImpl {
             .update_status(match_record.id, MatchStatus::Pending, MatchStatus::Running)
             .await?;
 
-        // 4. 启动 match supervisor：订阅 Bevy WS，套用 SOLO 胜负规则并落库。
+        // 5. 启动 match supervisor：订阅 Bevy WS，套用 SOLO 胜负规则并落库。
         let match_id = match_record.id;
         let match_service = self.match_service.clone();
         tokio::spawn(async move {
-            match_supervisor::run_supervisor(match_id, port, match_service).await;
+            match_supervisor::run_supervisor(match_id, port, supervisor_token, match_service).await;
+        });
+
+        // 5.1 启动观战 relay：延迟转播世界快照，观众经网关扇出，不直连 Bevy 进程。
+        let spectator_delay =
+            spectator_gateway::spectator_delay(match_record.form, input.spectator_delay_secs);
+        tokio::spawn(async move {
+            spectator_gateway::run_spectator_relay(match_id, port, relay_token, spectator_delay)
+                .await;
         });
 
-        // 5. 启动 AI Agent 决策环：仅当配置了场景 agent 时接入进程内 rmcp 工具层。
+        // 6. 启动 AI Agent 决策环：仅当配置了场景 agent 时接入进程内 rmcp 工具层。
         //    无凭据时编排环内部静默跳过。
         let scenario_agents = input.scenario_agents.clone();
         if !scenario_agents.is_empty() {
@@ -144,6 +167,7 @@ impl LocalGameService for LocalGameServiceImpl {
             tokio::spawn(async move {
                 agent_orchestrator::run_agent_orchestrator(
                     port,
+                    agent_token,
                     scenario_agents,
                     owner_id,
                     providers,
@@ -212,6 +236,7 @@ fn cloud_spawn_request() -> lol_client::launch::BevySpawnRequest {
         cwd: None,
         rust_log: None,
         log_db: None,
+        ws_auth: None, // 由 start 按局签发
     }
 }
 
@@ -4,7 +4,7 @@
 //! 设计（见 docs/product/match/arch.md）：
 //! - 胜负判定在 web server 侧，不在 Bevy 进程内。
 //! - Bevy 经 WS 推送 `match_event` 事件（champion_kill / turret_destroyed /
-//!   cs_threshold / time_progress），本 supervisor 维护 [`SoloState`]，
+//!   turret_plate_destroyed / cs_threshold / time_progress），本 supervisor 维护 [`SoloState`]，
 //!   每条事件后调 [`solo_rules::evaluate`] 判定。
 //! - 命中胜负 → 调 [`MatchService::finish_internal`] 落库；同时把每条事件
 //!   [`MatchService::append_event_internal`] 写入 match_events 供 observe 轮询。
@@ -15,7 +15,7 @@
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex, OnceLock};
 
-use lol_client::{WsEvent, start_ws_client};
+use lol_client::{WsEvent, start_ws_client_with_token};
 use serde_json::Value;
 use tokio::sync::{broadcast, mpsc};
 use tracing::{debug, info, warn};
@@ -44,10 +44,39 @@ pub fn get_broadcasters() -> &'static Mutex<HashMap<Uuid, broadcast::Sender<Spec
 #[derive(serde::Deserialize, Debug)]
 #[serde(tag = "type", rename_all = "snake_case")]
 enum MatchEventPayload {
-    ChampionKill { killer_team: TeamSer },
-    TurretDestroyed { killer_team: TeamSer },
-    CsThreshold { team: TeamSer, cs: u32 },
-    TimeProgress { elapsed_secs: f64 },
+    ChampionKill {
+        killer_team: TeamSer,
+    },
+    TurretDestroyed {
+        killer_team: TeamSer,
+    },
+    TurretPlateDestroyed {
+        killer_team: TeamSer,
+    },
+    CsThreshold {
+        team: TeamSer,
+        cs: u32,
+    },
+    TimeProgress {
+        elapsed_secs: f64,
+    },
+    DragonKilled {
+        killer_team: TeamSer,
+        element: String,
+    },
+    DragonSoul {
+        team: TeamSer,
+        element: String,
+    },
+    ElderDragonKilled {
+        killer_team: TeamSer,
+    },
+    RiftHeraldKilled {
+        killer_team: TeamSer,
+    },
+    BaronKilled {
+        killer_team: TeamSer,
+    },
 }
 
 /// Team 的宽松解析：兼容 "Order"/"order" 等大小写。
@@ -88,7 +117,14 @@ impl TeamSide {
 ///
 /// 连接 `ws://127.0.0.1:<ws_port>`，循环读取事件直到 WS 关闭或胜负落库。
 /// 由 [`LocalGameService`](crate::service::local_game_service) 在启动子进程后 spawn。
-pub async fn run_supervisor(match_id: Uuid, ws_port: i32, match_service: Arc<dyn MatchService>) {
+///
+/// `ws_token` 为本局签发的 spectator 角色令牌：supervisor 只需读事件。
+pub async fn run_supervisor(
+    match_id: Uuid,
+    ws_port: i32,
+    ws_token: String,
+    match_service: Arc<dyn MatchService>,
+) {
     info!(
         "[supervisor] 启动 match {} supervisor，连接 ws://127.0.0.1:{}",
         match_id, ws_port
@@ -96,7 +132,9 @@ pub async fn run_supervisor(match_id: Uuid, ws_port: i32, match_service: Arc<dyn
 
     // 复用 lol_client 的 WS 连接（含 30s 重试）与事件 channel。
     let (event_tx, mut event_rx) = mpsc::channel::<Value>(64);
-    if let Err(e) = start_ws_client(ws_port as u16, Some(event_tx)).await {
+    if let Err(e) =
+        start_ws_client_with_token(ws_port as u16, Some(&ws_token), Some(event_tx)).await
+    {
         warn!(
             "[supervisor] match {} 无法连接 Bevy WS: {}，supervisor 退出",
             match_id, e
@@ -218,6 +256,18 @@ fn advance_state(state: &mut SoloState, payload: &MatchEventPayload) -> (String,
                 (state.elapsed_secs as i64) * 1000,
             )
         }
+        MatchEventPayload::TurretPlateDestroyed { killer_team } => {
+            if let Some(side) = killer_team.to_winner_side() {
+                match side {
+                    TeamSide::Order => state.order_plates += 1,
+                    TeamSide::Chaos => state.chaos_plates += 1,
+                }
+            }
+            (
+                "turret_plate_destroyed".to_string(),
+                (state.elapsed_secs as i64) * 1000,
+            )
+        }
         MatchEventPayload::CsThreshold { team, cs } => {
             if let Some(side) = team.to_winner_side() {
                 match side {
@@ -234,6 +284,66 @@ fn advance_state(state: &mut SoloState, payload: &MatchEventPayload) -> (String,
             state.elapsed_secs = *elapsed_secs as u32;
             ("time_progress".to_string(), (*elapsed_secs * 1000.0) as i64)
         }
+        MatchEventPayload::DragonKilled { killer_team, .. } => {
+            if let Some(side) = killer_team.to_winner_side() {
+                match side {
+                    TeamSide::Order => state.order_dragons += 1,
+                    TeamSide::Chaos => state.chaos_dragons += 1,
+                }
+            }
+            (
+                "dragon_killed".to_string(),
+                (state.elapsed_secs as i64) * 1000,
+            )
+        }
+        MatchEventPayload::DragonSoul { team, .. } => {
+            if let Some(side) = team.to_winner_side() {
+                match side {
+                    TeamSide::Order => state.order_dragon_soul = true,
+                    TeamSide::Chaos => state.chaos_dragon_soul = true,
+                }
+            }
+            (
+                "dragon_soul".to_string(),
+                (state.elapsed_secs as i64) * 1000,
+            )
+        }
+        MatchEventPayload::ElderDragonKilled { killer_team } => {
+            if let Some(side) = killer_team.to_winner_side() {
+                match side {
+                    TeamSide::Order => state.order_elders += 1,
+                    TeamSide::Chaos => state.chaos_elders += 1,
+                }
+            }
+            (
+                "elder_dragon_killed".to_string(),
+                (state.elapsed_secs as i64) * 1000,
+            )
+        }
+        MatchEventPayload::RiftHeraldKilled { killer_team } => {
+            if let Some(side) = killer_team.to_winner_side() {
+                match side {
+                    TeamSide::Order => state.order_heralds += 1,
+                    TeamSide::Chaos => state.chaos_heralds += 1,
+                }
+            }
+            (
+                "rift_herald_killed".to_string(),
+                (state.elapsed_secs as i64) * 1000,
+            )
+        }
+        MatchEventPayload::BaronKilled { killer_team } => {
+            if let Some(side) = killer_team.to_winner_side() {
+                match side {
+                    TeamSide::Order => state.order_barons += 1,
+                    TeamSide::Chaos => state.chaos_barons += 1,
+                }
+            }
+            (
+                "baron_killed".to_string(),
+                (state.elapsed_secs as i64) * 1000,
+            )
+        }
     }
 }
 
@@ -267,6 +377,20 @@ mod tests {
         assert_eq!(s.chaos_towers, 1);
     }
 
+    #[test]
+    fn advance_turret_plate_counts_plates() {
+        let mut s = SoloState::default();
+        let (et, _) = advance_state(
+            &mut s,
+            &MatchEventPayload::TurretPlateDestroyed {
+                killer_team: TeamSer::Named("Order".into()),
+            },
+        );
+        assert_eq!(et, "turret_plate_destroyed");
+        assert_eq!(s.order_plates, 1);
+        assert_eq!(s.order_towers, 0);
+    }
+
     #[test]
     fn advance_cs_threshold_takes_max() {
         let mut s = SoloState::default();
@@ -294,6 +418,38 @@ mod tests {
         assert_eq!(ms, 900000);
     }
 
+    #[test]
+    fn advance_epic_objectives_count_per_team() {
+        let mut s = SoloState::default();
+        let payload: MatchEventPayload = serde_json::from_value(serde_json::json!({
+            "type": "dragon_killed",
+            "killer_team": "Chaos",
+            "element": "Infernal",
+        }))
+        .unwrap();
+        let (et, _) = advance_state(&mut s, &payload);
+        assert_eq!(et, "dragon_killed");
+        assert_eq!(s.chaos_dragons, 1);
+
+        advance_state(
+            &mut s,
+            &MatchEventPayload::DragonSoul {
+                team: TeamSer::Named("Chaos".into()),
+                element: "Infernal".into(),
+            },
+        );
+        let (et, _) = advance_state(
+            &mut s,
+            &MatchEventPayload::BaronKilled {
+                killer_team: TeamSer::Named("Order".into()),
+            },
+        );
+        assert_eq!(et, "baron_killed");
+        assert!(s.chaos_dragon_soul);
+        assert_eq!(s.order_barons, 1);
+        assert_eq!(s.order_dragons, 0);
+    }
+
     #[test]
     fn first_blood_after_champion_kill() {
         let mut s = SoloState::default();
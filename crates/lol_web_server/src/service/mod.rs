@@ -17,6 +17,7 @@ pub mod rank_service;
 pub mod room_service;
 pub mod scenario_service;
 pub mod spawn_preset_service;
+pub mod spectator_gateway;
 pub mod user_service;
 
 pub use admin_service::{AdminMetrics, AdminService, AdminServiceImpl};
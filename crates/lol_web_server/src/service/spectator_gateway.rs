@@ -0,0 +1,371 @@
+//! 观战网关：每个托管对局一个 relay task，从 Bevy 子进程订阅全量世界快照，
+//! 经延迟缓冲后以「关键帧 + 增量」向任意多个观众扇出。
+//!
+//! 设计：
+//! - Bevy 进程只服务一条 `subscribe_world` 订阅（relay 自身），观众数量不影响游戏进程负载。
+//! - 快照进入 [`DelayBuffer`]，到期（收到后满 `delay`）才放行，防止排位赛观战透视（ghosting）。
+//! - 放行的第一帧为完整快照，之后为相对上一帧的 JSON Merge Patch（[`lol_client::codec::diff`]）。
+//!   网关始终持有当前已放行的完整状态，中途加入或积压掉帧的观众从这份关键帧追上。
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use lol_client::codec;
+use lol_client::rpc::SubscribeWorldParams;
+use lol_client::{GameClient, WsEvent, start_ws_client_with_token};
+use serde_json::Value;
+use tokio::sync::{broadcast, mpsc};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::domain::match_::MatchForm;
+
+/// 排位赛默认观战延迟。
+pub const RANK_SPECTATOR_DELAY: Duration = Duration::from_secs(30);
+
+/// relay 检查延迟缓冲、放行到期快照的间隔。
+const RELEASE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 按对局形态取默认观战延迟：排位赛延迟，其余实时。
+pub fn default_delay(form: MatchForm) -> Duration {
+    match form {
+        MatchForm::Rank => RANK_SPECTATOR_DELAY,
+        MatchForm::Local | MatchForm::Room => Duration::ZERO,
+    }
+}
+
+/// 本局实际观战延迟：服务端按对局形态取下限，请求方只能调大、不能调小。
+pub fn spectator_delay(form: MatchForm, requested_secs: Option<u64>) -> Duration {
+    let floor = default_delay(form);
+    requested_secs
+        .map(Duration::from_secs)
+        .map_or(floor, |requested| requested.max(floor))
+}
+
+/// 推给观众的一帧。`keyframe` 为 true 时 `data` 是完整 [`lol_client::spectate::WorldSnapshot`]，
+/// 否则是相对上一帧（`seq - 1`）的 JSON Merge Patch。
+#[derive(serde::Serialize, Clone, Debug, PartialEq)]
+pub struct SpectatorFrame {
+    pub seq: u64,
+    pub keyframe: bool,
+    pub data: Value,
+}
+
+#[derive(serde::Serialize, Clone, Debug)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum SpectateMessage {
+    Frame(SpectatorFrame),
+    Close { reason: String },
+}
+
+/// 快照延迟缓冲：按收到时间排队，满 `delay` 后按序放行并转成帧。
+#[derive(Debug)]
+pub struct DelayBuffer {
+    delay: Duration,
+    pending: VecDeque<(Instant, Value)>,
+    /// 已放行的最新完整快照及其帧序号。
+    current: Option<(u64, Value)>,
+}
+
+impl DelayBuffer {
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            pending: VecDeque::new(),
+            current: None,
+        }
+    }
+
+    pub fn push(&mut self, received_at: Instant, snapshot: Value) {
+        self.pending.push_back((received_at, snapshot));
+    }
+
+    /// 放行所有在 `now` 时已满延迟的快照；与上一帧无变化的快照不产生帧、不消耗序号。
+    pub fn release(&mut self, now: Instant) -> Vec<SpectatorFrame> {
+        let mut frames = Vec::new();
+        while let Some((received_at, _)) = self.pending.front() {
+            if now.saturating_duration_since(*received_at) < self.delay {
+                break;
+            }
+            let Some((_, snapshot)) = self.pending.pop_front() else {
+                break;
+            };
+            let frame = match &self.current {
+                None => SpectatorFrame {
+                    seq: 0,
+                    keyframe: true,
+                    data: snapshot.clone(),
+                },
+                Some((seq, last)) => {
+                    let Some(patch) = codec::diff(last, &snapshot) else {
+                        continue;
+                    };
+                    SpectatorFrame {
+                        seq: seq + 1,
+                        keyframe: false,
+                        data: patch,
+                    }
+                }
+            };
+            self.current = Some((frame.seq, snapshot));
+            frames.push(frame);
+        }
+        frames
+    }
+
+    /// 当前已放行状态的关键帧（序号与最近一帧相同），供中途加入的观众追赶。
+    pub fn keyframe(&self) -> Option<SpectatorFrame> {
+        self.current.as_ref().map(|(seq, snapshot)| SpectatorFrame {
+            seq: *seq,
+            keyframe: true,
+            data: snapshot.clone(),
+        })
+    }
+
+    /// 是否已无待放行的快照。
+    pub fn is_drained(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+/// 单个对局的转播源。
+pub struct MatchFeed {
+    buffer: Mutex<DelayBuffer>,
+    tx: broadcast::Sender<SpectateMessage>,
+}
+
+impl MatchFeed {
+    fn new(delay: Duration) -> Self {
+        let (tx, _) = broadcast::channel(256);
+        Self {
+            buffer: Mutex::new(DelayBuffer::new(delay)),
+            tx,
+        }
+    }
+
+    pub fn push(&self, received_at: Instant, snapshot: Value) {
+        self.buffer.lock().unwrap().push(received_at, snapshot);
+    }
+
+    /// 放行到期快照并广播。持锁广播，保证 [`SpectatorGateway::join`] 拿到的关键帧
+    /// 与之后收到的第一帧序号相接。
+    pub fn release(&self, now: Instant) {
+        let mut buffer = self.buffer.lock().unwrap();
+        for frame in buffer.release(now) {
+            let _ = self.tx.send(SpectateMessage::Frame(frame));
+        }
+    }
+
+    pub fn is_drained(&self) -> bool {
+        self.buffer.lock().unwrap().is_drained()
+    }
+
+    fn close(&self, reason: &str) {
+        let _ = self.tx.send(SpectateMessage::Close {
+            reason: reason.to_string(),
+        });
+    }
+}
+
+/// 所有对局的转播源。
+#[derive(Default)]
+pub struct SpectatorGateway {
+    feeds: Mutex<HashMap<Uuid, Arc<MatchFeed>>>,
+}
+
+impl SpectatorGateway {
+    pub fn open(&self, match_id: Uuid, delay: Duration) -> Arc<MatchFeed> {
+        let feed = Arc::new(MatchFeed::new(delay));
+        self.feeds.lock().unwrap().insert(match_id, feed.clone());
+        feed
+    }
+
+    /// 广播结束消息并移除转播源。
+    pub fn close(&self, match_id: Uuid, reason: &str) {
+        if let Some(feed) = self.feeds.lock().unwrap().remove(&match_id) {
+            feed.close(reason);
+        }
+    }
+
+    /// 加入观战：返回当前关键帧（尚未放行任何快照时为 None）与后续帧的接收端。
+    /// 对局未在转播返回 None。
+    pub fn join(
+        &self,
+        match_id: Uuid,
+    ) -> Option<(Option<SpectatorFrame>, broadcast::Receiver<SpectateMessage>)> {
+        let feed = self.feeds.lock().unwrap().get(&match_id).cloned()?;
+        let buffer = feed.buffer.lock().unwrap();
+        Some((buffer.keyframe(), feed.tx.subscribe()))
+    }
+}
+
+static GATEWAY: OnceLock<SpectatorGateway> = OnceLock::new();
+
+pub fn spectator_gateway() -> &'static SpectatorGateway {
+    GATEWAY.get_or_init(SpectatorGateway::default)
+}
+
+/// 启动一个对局的观战 relay task。
+///
+/// 以 relay 令牌（[`WsRole::Relay`](lol_client::auth::WsRole::Relay)）连接
+/// `ws://127.0.0.1:<ws_port>` 并订阅不受视野限制的世界快照，直到 WS 关闭且
+/// 延迟缓冲中的快照全部放行。由 [`LocalGameService`](crate::service::local_game_service)
+/// 在启动子进程后 spawn。
+pub async fn run_spectator_relay(match_id: Uuid, ws_port: i32, ws_token: String, delay: Duration) {
+    let (event_tx, mut event_rx) = mpsc::channel::<Value>(256);
+    let session =
+        match start_ws_client_with_token(ws_port as u16, Some(&ws_token), Some(event_tx)).await {
+            Ok(session) => session,
+            Err(e) => {
+                warn!(
+                    "[spectator] match {} 无法连接 Bevy WS: {}，relay 退出",
+                    match_id, e
+                );
+                return;
+            }
+        };
+    let client = GameClient::new(session);
+    if let Err(e) = client
+        .rpc()
+        .subscribe_world(SubscribeWorldParams::default())
+        .await
+    {
+        warn!("[spectator] match {} 订阅世界快照失败: {}", match_id, e);
+        return;
+    }
+
+    info!(
+        "[spectator] match {} 开始转播，延迟 {}s",
+        match_id,
+        delay.as_secs()
+    );
+    let gateway = spectator_gateway();
+    let feed = gateway.open(match_id, delay);
+    let mut interval = tokio::time::interval(RELEASE_INTERVAL);
+    let mut live = true;
+    loop {
+        tokio::select! {
+            msg = event_rx.recv(), if live => match msg {
+                Some(val) => {
+                    if let Ok(env) = serde_json::from_value::<WsEvent>(val)
+                        && env.event == "world_snapshot"
+                        && let Some(snapshot) = env.data.get("snapshot")
+                    {
+                        feed.push(Instant::now(), snapshot.clone());
+                    }
+                }
+                // channel 关闭即 Bevy 进程退出；继续按延迟放行剩余快照。
+                None => live = false,
+            },
+            _ = interval.tick() => {
+                feed.release(Instant::now());
+                if !live && feed.is_drained() {
+                    break;
+                }
+            }
+        }
+    }
+
+    gateway.close(match_id, "match ended");
+    info!("[spectator] match {} 转播结束", match_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn snapshot(tick: u64, hp: f32) -> Value {
+        json!({ "tick": tick, "entities": { "1": { "health": hp } } })
+    }
+
+    #[test]
+    fn requested_delay_cannot_undercut_form_default() {
+        assert_eq!(
+            spectator_delay(MatchForm::Rank, Some(0)),
+            RANK_SPECTATOR_DELAY
+        );
+        assert_eq!(spectator_delay(MatchForm::Rank, None), RANK_SPECTATOR_DELAY);
+        assert_eq!(
+            spectator_delay(MatchForm::Rank, Some(60)),
+            Duration::from_secs(60)
+        );
+        assert_eq!(spectator_delay(MatchForm::Local, None), Duration::ZERO);
+        assert_eq!(
+            spectator_delay(MatchForm::Local, Some(5)),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn releases_only_after_delay() {
+        let start = Instant::now();
+        let mut buffer = DelayBuffer::new(Duration::from_secs(30));
+        buffer.push(start, snapshot(1, 100.0));
+        buffer.push(start + Duration::from_secs(1), snapshot(2, 90.0));
+
+        assert!(buffer.release(start + Duration::from_secs(29)).is_empty());
+        assert!(buffer.keyframe().is_none());
+
+        let frames = buffer.release(start + Duration::from_secs(30));
+        assert_eq!(frames.len(), 1);
+        assert!(frames[0].keyframe);
+        assert_eq!(frames[0].data, snapshot(1, 100.0));
+        assert!(!buffer.is_drained());
+
+        let frames = buffer.release(start + Duration::from_secs(31));
+        assert_eq!(
+            frames,
+            vec![SpectatorFrame {
+                seq: 1,
+                keyframe: false,
+                data: json!({ "tick": 2, "entities": { "1": { "health": 90.0 } } }),
+            }]
+        );
+        assert!(buffer.is_drained());
+    }
+
+    #[test]
+    fn late_joiner_catches_up_from_keyframe() {
+        let start = Instant::now();
+        let mut buffer = DelayBuffer::new(Duration::ZERO);
+        buffer.push(start, snapshot(1, 100.0));
+        buffer.push(start, snapshot(2, 100.0));
+        buffer.push(start, snapshot(3, 80.0));
+        let frames = buffer.release(start);
+        assert_eq!(frames.len(), 3);
+
+        // 关键帧等于依次应用所有帧后的状态，序号与最后一帧相同。
+        let mut rebuilt = Value::Null;
+        for frame in &frames {
+            codec::apply_patch(&mut rebuilt, &frame.data);
+        }
+        let keyframe = buffer.keyframe().unwrap();
+        assert_eq!(keyframe.seq, 2);
+        assert_eq!(keyframe.data, rebuilt);
+        assert_eq!(keyframe.data, snapshot(3, 80.0));
+    }
+
+    #[test]
+    fn gateway_join_reports_keyframe_and_close() {
+        let gateway = SpectatorGateway::default();
+        let id = Uuid::new_v4();
+        assert!(gateway.join(id).is_none());
+
+        let feed = gateway.open(id, Duration::ZERO);
+        let (keyframe, mut early) = gateway.join(id).unwrap();
+        assert!(keyframe.is_none());
+
+        let now = Instant::now();
+        feed.push(now, snapshot(1, 100.0));
+        feed.release(now);
+        let (keyframe, mut late) = gateway.join(id).unwrap();
+        assert_eq!(keyframe.unwrap().seq, 0);
+        assert!(matches!(early.try_recv(), Ok(SpectateMessage::Frame(f)) if f.keyframe));
+
+        gateway.close(id, "done");
+        assert!(matches!(late.try_recv(), Ok(SpectateMessage::Close { .. })));
+        assert!(gateway.join(id).is_none());
+    }
+}
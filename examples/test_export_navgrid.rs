@@ -52,6 +52,7 @@ fn make_test_grid() -> ConfigNavigationGrid {
         height_samples: vec![vec![0.0; GRID_SIZE]; GRID_SIZE],
         occupied_cells: Default::default(),
         exclude_cells: Default::default(),
+        terrain_cells: Default::default(),
     }
 }
 
@@ -118,10 +118,10 @@ fn main() {
                 loop {
                     match cmd_rx.recv_blocking() {
                         Ok(cmd_packet) => {
-                            let is_rl_step = cmd_packet.1 == "rl_step";
+                            let is_rl_step = cmd_packet.cmd == "rl_step";
                             let frames = if is_rl_step {
                                 cmd_packet
-                                    .2
+                                    .params
                                     .get("frames")
                                     .and_then(|f| f.as_u64())
                                     .unwrap_or(6) as usize